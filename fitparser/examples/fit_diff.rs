@@ -0,0 +1,82 @@
+//! Compare the messages and field values of two FIT files
+use fitparser::diff::{diff, RecordDiff};
+use std::error::Error;
+use std::fs::File;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Print the records that were added, removed or changed between two FIT files. Records are
+/// compared by message kind and timestamp, ignoring how they are encoded. The exit code is 1 if
+/// the files differ.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "fit_diff")]
+struct Cli {
+    /// Original FIT file
+    #[structopt(name = "LEFT", parse(from_os_str))]
+    left: PathBuf,
+
+    /// FIT file to compare with the original
+    #[structopt(name = "RIGHT", parse(from_os_str))]
+    right: PathBuf,
+
+    /// Treat numeric values that differ by no more than this amount as equal
+    #[structopt(short, long, default_value = "0")]
+    tolerance: f64,
+
+    /// Print each difference as a JSON object on its own line instead of text
+    #[structopt(long)]
+    json: bool,
+}
+
+fn run() -> Result<bool, Box<dyn Error>> {
+    let opt = Cli::from_args();
+
+    let left = fitparser::from_reader(&mut File::open(&opt.left)?)?;
+    let right = fitparser::from_reader(&mut File::open(&opt.right)?)?;
+    let diffs = diff(&left, &right, opt.tolerance);
+    for record in &diffs {
+        if opt.json {
+            println!("{}", serde_json::to_string(record)?);
+            continue;
+        }
+        match record {
+            RecordDiff::Removed { index, kind } => println!("- {} #{}", kind, index),
+            RecordDiff::Added { index, kind } => println!("+ {} #{}", kind, index),
+            RecordDiff::Changed {
+                left_index,
+                right_index,
+                kind,
+                fields,
+            } => {
+                println!("~ {} #{} -> #{}", kind, left_index, right_index);
+                for field in fields {
+                    let show = |v: Option<&fitparser::Value>| {
+                        v.map_or_else(|| String::from("<none>"), |v| v.to_string())
+                    };
+                    print!(
+                        "    {}: {} -> {}",
+                        field.name(),
+                        show(field.left()),
+                        show(field.right())
+                    );
+                    match field.delta() {
+                        Some(delta) => println!(" ({:+})", delta),
+                        None => println!(),
+                    }
+                }
+            }
+        }
+    }
+    Ok(diffs.is_empty())
+}
+
+fn main() {
+    std::process::exit(match run() {
+        Ok(true) => 0,
+        Ok(false) => 1,
+        Err(err) => {
+            eprintln!("{}", err);
+            2
+        }
+    });
+}
@@ -0,0 +1,61 @@
+//! Join activities recorded in several parts into a single FIT file
+use fitparser::analysis::merge_activities;
+use fitparser::de::{from_reader_with_options, DecodeOption};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Merge the records of multiple activity FIT files, such as a ride recorded in two parts after
+/// a device crash, into one FIT file with recomputed lap and session totals
+#[derive(Debug, StructOpt)]
+#[structopt(name = "fit_merge")]
+struct Cli {
+    /// FIT files to merge, they are ordered by their first timestamp
+    #[structopt(name = "FILE", parse(from_os_str), required = true, min_values = 2)]
+    files: Vec<PathBuf>,
+
+    /// Output FIT file, using a "-" will write the merged file to STDOUT
+    #[structopt(short, long, parse(from_os_str))]
+    output: PathBuf,
+
+    /// Skip checking the header and data section CRC values
+    #[structopt(long)]
+    no_crc_check: bool,
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let opt = Cli::from_args();
+
+    let mut decode_opts = HashSet::new();
+    if opt.no_crc_check {
+        decode_opts.insert(DecodeOption::SkipHeaderCrcValidation);
+        decode_opts.insert(DecodeOption::SkipDataCrcValidation);
+    }
+
+    let mut activities = Vec::new();
+    for file in &opt.files {
+        let mut fp = File::open(file)?;
+        activities.push(from_reader_with_options(&mut fp, &decode_opts)?);
+    }
+    let data = fitparser::to_bytes(&merge_activities(&activities)?)?;
+
+    if opt.output.as_os_str() == "-" {
+        io::stdout().write_all(&data)?;
+    } else {
+        File::create(&opt.output)?.write_all(&data)?;
+    }
+    Ok(())
+}
+
+fn main() {
+    std::process::exit(match run() {
+        Ok(_) => 0,
+        Err(err) => {
+            eprintln!("{}", err);
+            1
+        }
+    });
+}
@@ -0,0 +1,56 @@
+//! Repair damaged FIT files so other tools will accept them
+use fitparser::de::repair;
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Rewrite a damaged FIT file, such as one left behind by a device crash, by recalculating the
+/// header data size and checksums and removing any trailing bytes that can't be parsed
+#[derive(Debug, StructOpt)]
+#[structopt(name = "fit_repair")]
+struct Cli {
+    /// FIT file to repair
+    #[structopt(name = "FILE", parse(from_os_str))]
+    file: PathBuf,
+
+    /// Output FIT file, using a "-" will write the repaired file to STDOUT
+    #[structopt(short, long, parse(from_os_str))]
+    output: PathBuf,
+
+    /// Remove data messages that can't be decoded instead of truncating the file at them
+    #[structopt(long)]
+    drop_invalid_records: bool,
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let opt = Cli::from_args();
+
+    let mut data = Vec::new();
+    File::open(&opt.file)?.read_to_end(&mut data)?;
+    let (repaired, summary) = repair(&data, opt.drop_invalid_records)?;
+    eprintln!(
+        "Repaired {} FIT file(s), dropped {} message(s) and removed {} trailing byte(s)",
+        summary.file_count(),
+        summary.dropped_messages(),
+        summary.truncated_bytes()
+    );
+
+    if opt.output.as_os_str() == "-" {
+        io::stdout().write_all(&repaired)?;
+    } else {
+        File::create(&opt.output)?.write_all(&repaired)?;
+    }
+    Ok(())
+}
+
+fn main() {
+    std::process::exit(match run() {
+        Ok(_) => 0,
+        Err(err) => {
+            eprintln!("{}", err);
+            1
+        }
+    });
+}
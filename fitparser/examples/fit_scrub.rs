@@ -0,0 +1,74 @@
+//! Remove personal data from a FIT file before sharing it publicly
+use fitparser::de::{from_reader_with_options, DecodeOption};
+use fitparser::scrub::{scrub, ScrubOptions};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Write a copy of a FIT file without serial numbers, the user profile or the positions near the
+/// start of the activity, with the timestamps shifted to start on 2000-01-01. Developer fields are
+/// not written to the output file.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "fit_scrub")]
+struct Cli {
+    /// FIT file to scrub
+    #[structopt(name = "FILE", parse(from_os_str))]
+    file: PathBuf,
+
+    /// Output FIT file, using a "-" will write the scrubbed file to STDOUT
+    #[structopt(short, long, parse(from_os_str))]
+    output: PathBuf,
+
+    /// Remove positions closer than this many meters to the starting position
+    #[structopt(long, default_value = "500")]
+    privacy_radius: f64,
+
+    /// Keep the original timestamps instead of shifting them
+    #[structopt(long)]
+    keep_timestamps: bool,
+
+    /// Skip checking the header and data section CRC values
+    #[structopt(long)]
+    no_crc_check: bool,
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let opt = Cli::from_args();
+
+    let mut decode_opts = HashSet::new();
+    if opt.no_crc_check {
+        decode_opts.insert(DecodeOption::SkipHeaderCrcValidation);
+        decode_opts.insert(DecodeOption::SkipDataCrcValidation);
+    }
+
+    let mut fp = File::open(&opt.file)?;
+    let records = from_reader_with_options(&mut fp, &decode_opts)?;
+    let mut scrub_opts = ScrubOptions {
+        privacy_radius: opt.privacy_radius,
+        ..ScrubOptions::default()
+    };
+    if opt.keep_timestamps {
+        scrub_opts.epoch = None;
+    }
+    let data = fitparser::to_bytes(&scrub(&records, &scrub_opts))?;
+
+    if opt.output.as_os_str() == "-" {
+        io::stdout().write_all(&data)?;
+    } else {
+        File::create(&opt.output)?.write_all(&data)?;
+    }
+    Ok(())
+}
+
+fn main() {
+    std::process::exit(match run() {
+        Ok(_) => 0,
+        Err(err) => {
+            eprintln!("{}", err);
+            1
+        }
+    });
+}
@@ -0,0 +1,95 @@
+//! Correct the timestamps of a FIT file recorded with a wrong clock or time zone
+use chrono::Duration;
+use fitparser::analysis::shift_timestamps;
+use fitparser::de::{from_reader_with_options, DecodeOption};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Shift every timestamp of a FIT file by an offset and write the corrected records as a FIT
+/// file or JSON. Offsets are given in seconds or as [-]HH:MM[:SS].
+#[derive(Debug, StructOpt)]
+#[structopt(name = "fit_shift_time")]
+struct Cli {
+    /// FIT file to correct
+    #[structopt(name = "FILE", parse(from_os_str))]
+    file: PathBuf,
+
+    /// Output file, using a "-" will write the corrected file to STDOUT
+    #[structopt(short, long, parse(from_os_str))]
+    output: PathBuf,
+
+    /// Amount to shift the timestamps by, negative values move them earlier
+    #[structopt(long, default_value = "0", allow_hyphen_values = true, parse(try_from_str = parse_offset))]
+    offset: Duration,
+
+    /// UTC offset of the time zone the activity was recorded in, used to set the local timestamps
+    #[structopt(long, allow_hyphen_values = true, parse(try_from_str = parse_offset))]
+    utc_offset: Option<Duration>,
+
+    /// Output format
+    #[structopt(short, long, default_value = "fit", possible_values = &["fit", "json"])]
+    format: String,
+
+    /// Skip checking the header and data section CRC values
+    #[structopt(long)]
+    no_crc_check: bool,
+}
+
+/// Parse an offset given in seconds or as [-]HH:MM[:SS]
+fn parse_offset(s: &str) -> Result<Duration, String> {
+    let err = || format!("invalid offset: {}", s);
+    let (sign, value) = match s.strip_prefix('-') {
+        Some(value) => (-1, value),
+        None => (1, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let parts = value
+        .split(':')
+        .map(|p| p.parse::<i64>().map_err(|_| err()))
+        .collect::<Result<Vec<i64>, String>>()?;
+    let seconds = match parts.as_slice() {
+        [seconds] => *seconds,
+        [hours, minutes] => hours * 3600 + minutes * 60,
+        [hours, minutes, seconds] => hours * 3600 + minutes * 60 + seconds,
+        _ => return Err(err()),
+    };
+    Ok(Duration::seconds(sign * seconds))
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let opt = Cli::from_args();
+
+    let mut decode_opts = HashSet::new();
+    if opt.no_crc_check {
+        decode_opts.insert(DecodeOption::SkipHeaderCrcValidation);
+        decode_opts.insert(DecodeOption::SkipDataCrcValidation);
+    }
+
+    let mut fp = File::open(&opt.file)?;
+    let records = from_reader_with_options(&mut fp, &decode_opts)?;
+    let records = shift_timestamps(&records, opt.offset, opt.utc_offset);
+    let data = match opt.format.as_str() {
+        "json" => serde_json::to_vec(&records)?,
+        _ => fitparser::to_bytes(&records)?,
+    };
+
+    if opt.output.as_os_str() == "-" {
+        io::stdout().write_all(&data)?;
+    } else {
+        File::create(&opt.output)?.write_all(&data)?;
+    }
+    Ok(())
+}
+
+fn main() {
+    std::process::exit(match run() {
+        Ok(_) => 0,
+        Err(err) => {
+            eprintln!("{}", err);
+            1
+        }
+    });
+}
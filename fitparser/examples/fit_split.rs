@@ -0,0 +1,72 @@
+//! Split a multi-session activity into a FIT file for each session or lap
+use fitparser::analysis::{split_activity, SplitBy};
+use fitparser::de::{from_reader_with_options, DecodeOption};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Split the records of an activity, such as a triathlon, into separate FIT files for each
+/// session or lap. The files are named after the input file with the number of the part appended.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "fit_split")]
+struct Cli {
+    /// FIT file to split
+    #[structopt(name = "FILE", parse(from_os_str))]
+    file: PathBuf,
+
+    /// Message used to divide the activity
+    #[structopt(long, default_value = "session", possible_values = &["session", "lap"], parse(try_from_str = parse_split_by))]
+    by: SplitBy,
+
+    /// Directory to write the files to, defaults to the directory of the input file
+    #[structopt(short, long, parse(from_os_str))]
+    output: Option<PathBuf>,
+
+    /// Skip checking the header and data section CRC values
+    #[structopt(long)]
+    no_crc_check: bool,
+}
+
+fn parse_split_by(s: &str) -> Result<SplitBy, String> {
+    match s {
+        "session" => Ok(SplitBy::Session),
+        "lap" => Ok(SplitBy::Lap),
+        _ => Err(format!("Unknown split message: {}", s)),
+    }
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let opt = Cli::from_args();
+
+    let mut decode_opts = HashSet::new();
+    if opt.no_crc_check {
+        decode_opts.insert(DecodeOption::SkipHeaderCrcValidation);
+        decode_opts.insert(DecodeOption::SkipDataCrcValidation);
+    }
+
+    let mut fp = File::open(&opt.file)?;
+    let records = from_reader_with_options(&mut fp, &decode_opts)?;
+    let directory = match opt.output {
+        Some(directory) => directory,
+        None => opt.file.parent().map(PathBuf::from).unwrap_or_default(),
+    };
+    let stem = opt.file.file_stem().unwrap_or_default().to_string_lossy();
+    for (i, part) in split_activity(&records, opt.by).iter().enumerate() {
+        let path = directory.join(format!("{}_{}.fit", stem, i + 1));
+        File::create(&path)?.write_all(&fitparser::to_bytes(part)?)?;
+    }
+    Ok(())
+}
+
+fn main() {
+    std::process::exit(match run() {
+        Ok(_) => 0,
+        Err(err) => {
+            eprintln!("{}", err);
+            1
+        }
+    });
+}
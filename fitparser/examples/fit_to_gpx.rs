@@ -0,0 +1,130 @@
+//! Read one or more FIT files and export the recorded positions as GPX tracks
+use fitparser::de::{from_reader_with_options, DecodeOption};
+use fitparser::export::gpx;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+
+/// Parse FIT formatted files and output the positions of their record messages as GPX 1.1 tracks
+#[derive(Debug, StructOpt)]
+#[structopt(name = "fit_to_gpx")]
+struct Cli {
+    /// FIT files to convert to GPX
+    #[structopt(name = "FILE", parse(from_os_str))]
+    files: Vec<PathBuf>,
+
+    /// Output location, if not provided the GPX file will be output alongside the input file. If a
+    /// directory is provided all FIT files will be written there using the same filename but with
+    /// a '.gpx' extension. If multiple FIT files are provided and the output path isn't a
+    /// directory the track will store a segment for each file in the order they were read. Using
+    /// a "-" as the output file name will result in all content being printed to STDOUT.
+    #[structopt(short, long, parse(from_os_str))]
+    output: Option<PathBuf>,
+
+    /// Skip checking the header and data section CRC values
+    #[structopt(long)]
+    no_crc_check: bool,
+}
+
+#[derive(Clone, Debug)]
+enum OutputLocation {
+    Inplace,
+    LocalDirectory(PathBuf),
+    LocalFile(PathBuf),
+    Stdout,
+}
+
+impl OutputLocation {
+    fn new(location: PathBuf) -> Self {
+        if location.is_dir() {
+            OutputLocation::LocalDirectory(location)
+        } else if location.as_os_str() == "-" {
+            OutputLocation::Stdout
+        } else {
+            OutputLocation::LocalFile(location)
+        }
+    }
+
+    fn write_gpx_file(
+        &self,
+        filename: &Path,
+        data: Vec<fitparser::FitDataRecord>,
+    ) -> Result<(), Box<dyn Error>> {
+        let outname = match self {
+            Self::Inplace => filename.with_extension("gpx"),
+            Self::LocalDirectory(dest) => dest
+                .clone()
+                .join(filename.file_name().unwrap())
+                .with_extension("gpx"),
+            Self::LocalFile(dest) => dest.clone(),
+            Self::Stdout => {
+                gpx::to_writer(&data, &mut io::stdout())?;
+                return Ok(());
+            }
+        };
+        let mut fp = File::create(outname)?;
+        gpx::to_writer(&data, &mut fp)?;
+        Ok(())
+    }
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let opt = Cli::from_args();
+
+    // set any decode options, unknown data is never exported
+    let mut decode_opts = HashSet::new();
+    decode_opts.insert(DecodeOption::DropUnknownFields);
+    decode_opts.insert(DecodeOption::DropUnknownMessages);
+    if opt.no_crc_check {
+        decode_opts.insert(DecodeOption::SkipHeaderCrcValidation);
+        decode_opts.insert(DecodeOption::SkipDataCrcValidation);
+    }
+
+    // define parsed and serialized data output location
+    let output_loc = opt
+        .output
+        .map_or(OutputLocation::Inplace, OutputLocation::new);
+    let collect_all = matches!(output_loc, OutputLocation::LocalFile(_));
+
+    // read from STDIN if no files were given
+    if opt.files.is_empty() {
+        let mut stdin = io::stdin();
+        let data = from_reader_with_options(&mut stdin, &decode_opts)?;
+        output_loc.write_gpx_file(&PathBuf::from("<stdin>"), data)?;
+        return Ok(());
+    }
+
+    // Read each FIT file and output it
+    let mut all_fit_data: Vec<fitparser::FitDataRecord> = Vec::new();
+    for file in opt.files {
+        // open file and parse data
+        let mut fp = File::open(&file)?;
+        let mut data = from_reader_with_options(&mut fp, &decode_opts)?;
+
+        // output a single fit file's data into a single output file
+        if collect_all {
+            all_fit_data.append(&mut data);
+        } else {
+            output_loc.write_gpx_file(&file, data)?;
+        }
+    }
+    // output fit data from all files into a single file
+    if collect_all {
+        output_loc.write_gpx_file(&PathBuf::new(), all_fit_data)?;
+    }
+
+    Ok(())
+}
+
+fn main() {
+    std::process::exit(match run() {
+        Ok(_) => 0,
+        Err(err) => {
+            eprintln!("{}", err);
+            1
+        }
+    });
+}
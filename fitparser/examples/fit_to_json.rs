@@ -1,31 +1,152 @@
-//! Read one or more FIT files and dump their contents as JSON
-use fitparser;
-use fitparser::de::{from_reader_with_options, DecodeOption};
+//! Read one or more FIT files and dump their contents as JSON or one of the other supported
+//! output formats
+use fitparser::analysis::{utc_offset, SessionSummary};
+use fitparser::de::{
+    from_bytes_with_options, from_bytes_with_recovery, DecodeOption, DecodePreset, FitObject,
+    FitStreamProcessor, Units,
+};
+#[cfg(feature = "arrow")]
+use fitparser::export::arrow;
+#[cfg(feature = "sqlite")]
+use fitparser::export::sqlite;
+use fitparser::export::{csv, geojson, influx, kml};
+use fitparser::profile::MesgNum;
+use fitparser::ser::{FieldCase, SerializationOptions, TimestampFormat, TimestampZone};
+use fitparser::{FitDataRecord, Value};
 use serde::Serialize;
 use std::collections::{BTreeMap, HashSet};
+use std::convert::TryInto;
 use std::error::Error;
-use std::fs::File;
-use std::io;
+use std::fmt;
+use std::fs::{self, File};
 use std::io::prelude::*;
+use std::io::{self, IsTerminal};
+use std::ops::Deref;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
 use structopt::StructOpt;
 
-/// Parse FIT formatted files and output their data in the JSON format
+/// Parse FIT formatted files and output their data in the JSON format, or any other supported
+/// output format
 #[derive(Debug, StructOpt)]
 #[structopt(name = "fit_to_json")]
 struct Cli {
-    /// FIT files to convert to JSON
+    /// FIT files to convert, using a "-" or not providing any files will read the FIT file from
+    /// STDIN. Glob patterns such as "activities/2023-*.fit" are expanded if the shell doesn't.
     #[structopt(name = "FILE", parse(from_os_str))]
     files: Vec<PathBuf>,
 
-    /// Output location, if not provided the JSON file will be output alongside the input file. If a
-    /// directory is provided all FIT files will be written there using the same filename but with
-    /// a '.json' extension. If multiple FIT files are provided and the output path isn't a
-    /// directory the JSON array will store all records present in the order they were read. Using
-    /// a "-" as the output file name will result in all content being printed to STDOUT.
+    /// Convert all FIT files found in directories given as input and their subdirectories, the
+    /// directory structure is kept when writing to an output directory
+    #[structopt(short, long)]
+    recursive: bool,
+
+    /// Number of files converted in parallel, defaults to the number of CPUs
+    #[structopt(short, long)]
+    jobs: Option<usize>,
+
+    /// Stop at the first file that fails to convert instead of converting the remaining files
+    #[structopt(long)]
+    fail_fast: bool,
+
+    /// Don't show the progress of batch conversions or any log messages, failed files are still
+    /// printed
+    #[structopt(short, long, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Print warnings from the parser, such as CRC mismatches, use "-vv" to also print unknown
+    /// and skipped messages and fields
+    #[structopt(short, long, parse(from_occurrences))]
+    verbose: u8,
+
+    /// Output location, if not provided the output file will be output alongside the input file. If
+    /// a directory is provided all FIT files will be written there using the same filename but with
+    /// the extension of the output format. If multiple FIT files are provided and the output path
+    /// isn't a directory the output will store all records present in the order they were read.
+    /// Using a "-" as the output file name will result in all content being printed to STDOUT, which
+    /// is also the default when reading from STDIN.
     #[structopt(short, long, parse(from_os_str))]
     output: Option<PathBuf>,
 
+    /// Output format, "ndjson" writes each record as a JSON object on its own line and "csv" uses
+    /// the same layout as the FitCSVTool provided with the FIT SDK. The "kml" format exports the
+    /// recorded track along with start, stop and lap placemarks, the "geojson"
+    /// format stores the track as a FeatureCollection with points for each lap and event and
+    /// "influx" writes the record and monitoring messages using the InfluxDB line protocol.
+    /// Using "parquet" will write the record messages as a Parquet file, this requires the
+    /// `arrow` feature. The "sqlite" format writes all files into the database given as the
+    /// output location, creating a table for each message kind, this requires the `sqlite`
+    /// feature.
+    #[structopt(
+        short,
+        long,
+        default_value = "json",
+        possible_values = &["json", "ndjson", "csv", "kml", "geojson", "influx", "parquet", "sqlite"]
+    )]
+    format: OutputFormat,
+
+    /// Indent the JSON output to make it easier to read, NDJSON output is never indented
+    #[structopt(long)]
+    pretty: bool,
+
+    /// Output the bare value of each field without its units, units are taken from the profile
+    /// or the developer field description
+    #[structopt(long)]
+    no_units: bool,
+
+    /// Time zone used to write timestamps, "activity" uses the time zone the activity was
+    /// recorded in based on the `local_timestamp` of the activity message and falls back to the
+    /// system time zone
+    #[structopt(
+        long,
+        default_value = "system",
+        possible_values = &["system", "utc", "activity"],
+        parse(try_from_str = parse_time_zone)
+    )]
+    time_zone: TimeZone,
+
+    /// Format of the timestamps in the JSON output, "unix" and "unix-ms" write the seconds or
+    /// milliseconds since the Unix epoch and "fit" writes the seconds since 1989-12-31 stored in
+    /// the file
+    #[structopt(
+        long,
+        default_value = "rfc3339",
+        possible_values = &["rfc3339", "unix", "unix-ms", "fit"],
+        parse(try_from_str = parse_timestamp_format)
+    )]
+    timestamp_format: TimestampFormat,
+
+    /// Casing of the field names in the JSON output, "camel" and "pascal" match the names used
+    /// by the FIT SDK such as `heartRate` and `HeartRate`
+    #[structopt(
+        long,
+        default_value = "snake",
+        possible_values = &["snake", "camel", "pascal"],
+        parse(try_from_str = parse_field_case)
+    )]
+    field_case: FieldCase,
+
+    /// Only output messages of this kind, such as "record", can be given multiple times
+    #[structopt(
+        long = "include-type",
+        name = "INCLUDE",
+        number_of_values = 1,
+        parse(try_from_str = parse_mesg_num)
+    )]
+    include_types: Vec<MesgNum>,
+
+    /// Skip messages of this kind, such as "file_creator", can be given multiple times
+    #[structopt(
+        long = "exclude-type",
+        name = "EXCLUDE",
+        number_of_values = 1,
+        parse(try_from_str = parse_mesg_num)
+    )]
+    exclude_types: Vec<MesgNum>,
+
     /// Drop fields and messages that aren't defined in the profile
     #[structopt(long)]
     drop_unknown: bool,
@@ -34,6 +155,33 @@ struct Cli {
     #[structopt(long)]
     numeric_enums: bool,
 
+    /// Write the variant name of each numeric enum value next to it in the JSON output
+    #[structopt(long, requires = "numeric-enums")]
+    enum_names: bool,
+
+    /// Write fields whose value is invalid, such as `0xFF` for a `uint8`, as null values so
+    /// every record of a message kind has the same fields in the JSON output
+    #[structopt(long)]
+    include_invalid: bool,
+
+    /// Output the raw values stored in the file without applying the scale and offset or
+    /// converting enums and timestamps
+    #[structopt(long)]
+    raw_values: bool,
+
+    /// Unit system used for distance, altitude, speed and temperature values
+    #[structopt(
+        long,
+        default_value = "metric",
+        possible_values = &["metric", "statute", "nautical"],
+        parse(try_from_str = parse_units)
+    )]
+    units: Units,
+
+    /// Convert positions stored in semicircles into decimal degrees
+    #[structopt(long)]
+    degrees: bool,
+
     /// Keep generic subfield names in the output instead of the specific resolved name
     #[structopt(long)]
     keep_generic_names: bool,
@@ -42,31 +190,602 @@ struct Cli {
     #[structopt(long)]
     keep_composite_fields: bool,
 
+    /// Start from a named set of decode options, strict follows the FIT protocol exactly while
+    /// permissive decodes as much of a damaged file as possible
+    #[structopt(
+        long,
+        possible_values = &["strict", "permissive"],
+        parse(try_from_str = parse_preset)
+    )]
+    preset: Option<DecodePreset>,
+
     /// Skip checking the header and data section CRC values
     #[structopt(long)]
     no_crc_check: bool,
+
+    /// Print a warning for header and data section CRC values that don't match instead of
+    /// failing to parse the file
+    #[structopt(long, conflicts_with = "no-crc-check")]
+    warn_crc: bool,
+
+    /// Output the records decoded before an error, such as the end of a truncated file, instead
+    /// of failing. The error is printed as a warning.
+    #[structopt(long)]
+    recover: bool,
+
+    /// Output a JSON overview of each FIT file with the file type, device, sport, start time,
+    /// duration, distance and number of messages of each kind instead of the records
+    #[structopt(long, conflicts_with = "format")]
+    summary: bool,
+
+    /// Output the header and `file_id` message of each FIT file as JSON, the remaining messages
+    /// are skipped without being decoded
+    #[structopt(long, conflicts_with_all = &["format", "summary"])]
+    header_only: bool,
+
+    /// Output each FIT file as an object with its header, records and CRC instead of a single
+    /// array with the records of every file
+    #[structopt(long, conflicts_with_all = &["format", "summary", "header-only"])]
+    with_header: bool,
+
+    /// Output a listing of every object in the files with their position and bytes, the
+    /// definition used by each data message and its decoded values. Decoding errors are written
+    /// at the end of the listing.
+    #[structopt(
+        long,
+        conflicts_with_all = &["format", "summary", "header-only", "with-header"]
+    )]
+    debug_dump: bool,
+
+    /// Print a JSON Schema describing the JSON output for the given output options instead of
+    /// converting any files, this requires the `schema` feature
+    #[structopt(long, conflicts_with_all = &["format", "summary", "header-only", "with-header"])]
+    json_schema: bool,
 }
 
-/// Alternate serialization format
+/// Overview of a single FIT file, values are taken from the `file_id` and `session` messages
+/// falling back to the values computed from the `record` messages
 #[derive(Clone, Debug, Serialize)]
-struct FitDataMap {
-    kind: fitparser::profile::MesgNum,
-    fields: BTreeMap<String, fitparser::ValueWithUnits>,
+struct FileSummary {
+    file_type: Option<Value>,
+    manufacturer: Option<Value>,
+    product: Option<Value>,
+    sport: Option<Value>,
+    start_time: Option<Value>,
+    duration: Option<f64>,
+    distance: Option<f64>,
+    messages: BTreeMap<String, usize>,
 }
 
-impl FitDataMap {
-    fn new(record: fitparser::FitDataRecord) -> Self {
-        FitDataMap {
-            kind: record.kind(),
-            fields: record
-                .into_vec()
-                .into_iter()
-                .map(|f| (f.name().to_owned(), fitparser::ValueWithUnits::from(f)))
-                .collect(),
+impl FileSummary {
+    fn new(records: &[FitDataRecord]) -> Self {
+        let find = |kind: MesgNum, names: &[&str]| {
+            records
+                .iter()
+                .filter(|r| r.kind() == kind)
+                .find_map(|r| names.iter().find_map(|n| r.field(n)))
+                .map(|f| f.value().clone())
+        };
+        let number = |value: Option<Value>| -> Option<f64> { value?.try_into().ok() };
+        let computed = SessionSummary::from_records(records);
+
+        let mut messages = BTreeMap::new();
+        for record in records {
+            *messages.entry(record.kind().to_string()).or_insert(0) += 1;
+        }
+        FileSummary {
+            file_type: find(MesgNum::FileId, &["type"]),
+            manufacturer: find(MesgNum::FileId, &["manufacturer"]),
+            product: find(MesgNum::FileId, &["garmin_product", "product"]),
+            sport: find(MesgNum::Session, &["sport"]).or_else(|| find(MesgNum::Sport, &["sport"])),
+            start_time: find(MesgNum::Session, &["start_time"])
+                .or_else(|| computed.start_time().map(Value::Timestamp)),
+            duration: number(find(MesgNum::Session, &["total_elapsed_time"]))
+                .or_else(|| computed.start_time().map(|_| computed.total_elapsed_time())),
+            distance: number(find(MesgNum::Session, &["total_distance"]))
+                .or_else(|| computed.total_distance()),
+            messages,
         }
     }
 }
 
+/// Header and `file_id` message of a single FIT file
+#[derive(Clone, Debug, Serialize)]
+struct HeaderInfo {
+    header_size: u8,
+    protocol_version: f32,
+    profile_version: f32,
+    data_size: u32,
+    file_type: Option<Value>,
+    manufacturer: Option<Value>,
+    product: Option<Value>,
+    serial_number: Option<Value>,
+    time_created: Option<Value>,
+}
+
+impl HeaderInfo {
+    /// Read the header of each FIT file in the buffer, only the `file_id` message is decoded and
+    /// the data size stored in the header is used to skip to the next file
+    fn read_all(data: &[u8], decode_opts: &HashSet<DecodeOption>) -> fitparser::Result<Vec<Self>> {
+        let mut headers = Vec::new();
+        let mut input = data;
+        while !input.is_empty() {
+            let mut processor = FitStreamProcessor::with_options(decode_opts);
+            processor.set_message_filter(&[MesgNum::FileId]);
+            let (mut messages, header) = match processor.deserialize_next(input)? {
+                (remaining, FitObject::Header(header)) => (remaining, header),
+                _ => break,
+            };
+            let data_size = header.data_size() as usize;
+            input = input
+                .get(header.header_size() as usize + data_size + 2..)
+                .unwrap_or_default();
+
+            messages = &messages[..data_size.min(messages.len())];
+            let mut file_id = None;
+            while file_id.is_none() && !messages.is_empty() {
+                let (remaining, obj) = processor.deserialize_next(messages)?;
+                if let FitObject::DataMessage(msg) = obj {
+                    let record = processor.decode_message(msg)?;
+                    if record.kind() == MesgNum::FileId {
+                        file_id = Some(record);
+                    }
+                }
+                messages = remaining;
+            }
+            let field = |name: &str| {
+                let field = file_id.as_ref().and_then(|r| r.field(name));
+                field.map(|f| f.value().clone())
+            };
+            headers.push(HeaderInfo {
+                header_size: header.header_size(),
+                protocol_version: header.protocol_ver_enc(),
+                profile_version: header.profile_ver_enc(),
+                data_size: header.data_size(),
+                file_type: field("type"),
+                manufacturer: field("manufacturer"),
+                product: field("garmin_product").or_else(|| field("product")),
+                serial_number: field("serial_number"),
+                time_created: field("time_created"),
+            });
+        }
+        Ok(headers)
+    }
+}
+
+/// Options used to decode the FIT files and convert them into the output format
+struct ConvertOptions {
+    decode_opts: HashSet<DecodeOption>,
+    pretty: bool,
+    include_units: bool,
+    time_zone: TimeZone,
+    timestamp_format: TimestampFormat,
+    field_case: FieldCase,
+    enum_names: bool,
+    include_invalid: bool,
+    include_types: Vec<MesgNum>,
+    exclude_types: Vec<MesgNum>,
+}
+
+impl ConvertOptions {
+    /// Check if messages of the given kind are written to the output
+    fn is_included(&self, kind: MesgNum) -> bool {
+        (self.include_types.is_empty() || self.include_types.contains(&kind))
+            && !self.exclude_types.contains(&kind)
+    }
+
+    /// Return the options used to serialize records as JSON, records are serialized as a
+    /// name: {value, units} map
+    fn serialization_options(&self, records: &[FitDataRecord]) -> SerializationOptions {
+        let timestamp_zone = match self.time_zone {
+            TimeZone::System => TimestampZone::Decoded,
+            TimeZone::Utc => TimestampZone::Utc,
+            TimeZone::Activity => {
+                utc_offset(records).map_or(TimestampZone::Decoded, TimestampZone::UtcOffset)
+            }
+        };
+        SerializationOptions {
+            flatten_fields: true,
+            include_units: self.include_units,
+            numeric_enums: self
+                .decode_opts
+                .contains(&DecodeOption::ReturnNumericEnumValues),
+            timestamp_zone,
+            timestamp_format: self.timestamp_format,
+            field_case: self.field_case,
+            enum_names: self.enum_names,
+            include_invalid_fields: self.include_invalid,
+        }
+    }
+
+    /// Serialize the value as JSON, indenting it if requested
+    fn to_json<T: Serialize>(&self, value: &T) -> serde_json::Result<String> {
+        if self.pretty {
+            serde_json::to_string_pretty(value)
+        } else {
+            serde_json::to_string(value)
+        }
+    }
+
+    /// Decode the records of one or more FIT files, only keeping the included message kinds
+    fn decode_records(&self, data: &[u8]) -> fitparser::Result<Vec<fitparser::FitDataRecord>> {
+        let mut records = decode_records(data, &self.decode_opts)?;
+        records.retain(|r| self.is_included(r.kind()));
+        Ok(records)
+    }
+
+    /// Remove the rows of message kinds that aren't included from the CSV output, the message
+    /// name is stored in the third column of each row following the header
+    fn filter_csv(&self, content: String) -> String {
+        let mut lines = content.lines();
+        let mut output = lines.next().map_or(String::new(), |h| format!("{}\n", h));
+        for line in lines {
+            let kind = line.split(',').nth(2).and_then(|k| k.parse().ok());
+            if kind.is_none_or(|k| self.is_included(k)) {
+                output.push_str(line);
+                output.push('\n');
+            }
+        }
+        output
+    }
+}
+
+/// Decode the records of one or more FIT files, logging the error that stopped decoding when
+/// the `RecoverRecords` option is set. CRC mismatches are logged by the decoder itself when the
+/// `WarnOnCrcMismatch` option is set.
+fn decode_records(
+    data: &[u8],
+    decode_opts: &HashSet<DecodeOption>,
+) -> fitparser::Result<Vec<fitparser::FitDataRecord>> {
+    if decode_opts.contains(&DecodeOption::RecoverRecords) {
+        let (records, err) = from_bytes_with_recovery(data, decode_opts);
+        if let Some(err) = err {
+            log::warn!("{}", err);
+        }
+        return Ok(records);
+    }
+    from_bytes_with_options(data, decode_opts)
+}
+
+/// Write bytes as rows of 16 hex values, each row is indented to line up under the object
+fn hex_rows(bytes: &[u8]) -> String {
+    let mut output = String::new();
+    for row in bytes.chunks(16) {
+        let hex: Vec<String> = row.iter().map(|b| format!("{:02x}", b)).collect();
+        output.push_str(&format!("    {}\n", hex.join(" ")));
+    }
+    output
+}
+
+/// List every object of one or more FIT files along with its position and bytes, data messages
+/// include the definition they are parsed with and the decoded value and bytes of each field.
+/// Decoding stops at the first error, which is written at the end of the listing.
+fn debug_dump(data: &[u8], decode_opts: &HashSet<DecodeOption>) -> String {
+    let mut processor = FitStreamProcessor::with_options(decode_opts);
+    processor.add_option(DecodeOption::KeepRawBytes);
+    let mut output = String::new();
+    let mut input = data;
+    while !input.is_empty() {
+        let position = data.len() - input.len();
+        let (remaining, obj) = match processor.deserialize_next(input) {
+            Ok(next) => next,
+            Err(err) => {
+                output.push_str(&format!("{:#010x} error: {}\n", position, err));
+                break;
+            }
+        };
+        let bytes = &input[..input.len() - remaining.len()];
+        input = remaining;
+        match obj {
+            FitObject::Header(header) => output.push_str(&format!(
+                "{:#010x} header: size {}, protocol {}, profile {}, data size {}, crc {}\n",
+                position,
+                header.header_size(),
+                header.protocol_ver_enc(),
+                header.profile_ver_enc(),
+                header.data_size(),
+                header
+                    .crc()
+                    .map_or_else(|| String::from("none"), |c| format!("{:#06x}", c))
+            )),
+            FitObject::DefinitionMessage(definition) => {
+                output.push_str(&format!(
+                    "{:#010x} definition: local {}, global {} ({}), {:?} endian\n",
+                    position,
+                    definition.local_message_number(),
+                    definition.global_message_number(),
+                    MesgNum::from(definition.global_message_number()),
+                    definition.byte_order()
+                ));
+                for field in definition.field_definitions() {
+                    output.push_str(&format!(
+                        "  field {}: {} bytes, {:?}\n",
+                        field.field_definition_number(),
+                        field.size(),
+                        field.base_type()
+                    ));
+                }
+                for field in definition.developer_field_definitions() {
+                    output.push_str(&format!(
+                        "  developer {} field {}: {} bytes\n",
+                        field.developer_data_index(),
+                        field.field_number(),
+                        field.size()
+                    ));
+                }
+            }
+            FitObject::DataMessage(msg) => {
+                output.push_str(&format!(
+                    "{:#010x} data: local {}, global {}",
+                    position,
+                    msg.local_message_number(),
+                    msg.global_message_number()
+                ));
+                if let Some(offset) = msg.time_offset() {
+                    output.push_str(&format!(", compressed timestamp offset {}", offset));
+                }
+                output.push('\n');
+                output.push_str(&hex_rows(bytes));
+                let record = match processor.decode_message(msg) {
+                    Ok(record) => record,
+                    Err(err) => {
+                        output.push_str(&format!("{:#010x} error: {}\n", position, err));
+                        break;
+                    }
+                };
+                output.push_str(&format!("  {}\n", record.kind()));
+                for field in record.fields() {
+                    let raw =
+                        record
+                            .raw_message()
+                            .and_then(|raw| match field.developer_data_index() {
+                                Some(index) => raw.developer_field_bytes(index, field.number()),
+                                None => raw.field_bytes(field.number()),
+                            });
+                    let hex: Vec<String> = raw
+                        .unwrap_or_default()
+                        .iter()
+                        .map(|b| format!("{:02x}", b))
+                        .collect();
+                    let mut value = field.value().to_string();
+                    if !field.units().is_empty() {
+                        value = format!("{} {}", value, field.units());
+                    }
+                    output.push_str(&format!(
+                        "  {} ({}) = {} [{}]\n",
+                        field.name(),
+                        field.number(),
+                        value,
+                        hex.join(" ")
+                    ));
+                }
+                continue;
+            }
+            FitObject::Crc(crc) => {
+                processor.reset();
+                output.push_str(&format!("{:#010x} crc: {:#06x}\n", position, crc));
+            }
+        }
+        output.push_str(&hex_rows(bytes));
+    }
+    output
+}
+
+/// Supported output formats
+#[derive(Clone, Copy, Debug)]
+enum OutputFormat {
+    Json,
+    NdJson,
+    /// Overview of each FIT file selected using `--summary`
+    Summary,
+    /// Header of each FIT file selected using `--header-only`
+    Header,
+    /// Header, records and CRC of each FIT file selected using `--with-header`
+    Files,
+    /// Listing of every object in the files selected using `--debug-dump`
+    DebugDump,
+    Csv,
+    Kml,
+    GeoJson,
+    Influx,
+    #[cfg(feature = "arrow")]
+    Parquet,
+    #[cfg(feature = "sqlite")]
+    Sqlite,
+}
+
+impl OutputFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::NdJson => "ndjson",
+            Self::Summary | Self::Header | Self::Files => "json",
+            Self::DebugDump => "txt",
+            Self::Csv => "csv",
+            Self::Kml => "kml",
+            Self::GeoJson => "geojson",
+            Self::Influx => "lp",
+            #[cfg(feature = "arrow")]
+            Self::Parquet => "parquet",
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite => "sqlite",
+        }
+    }
+
+    /// Convert the bytes of one or more FIT files into the output format
+    fn convert(
+        &self,
+        data: &[u8],
+        opts: &ConvertOptions,
+    ) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+        let records = || opts.decode_records(data);
+        let content = match self {
+            Self::Json => {
+                let records = records()?;
+                let ser_opts = opts.serialization_options(&records);
+                opts.to_json(&ser_opts.records(&records))?
+            }
+            Self::NdJson => {
+                let records = records()?;
+                let ser_opts = opts.serialization_options(&records);
+                let lines = records
+                    .iter()
+                    .map(|record| serde_json::to_string(&ser_opts.record(record)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                lines.join("\n")
+            }
+            Self::Summary => {
+                let summaries: Vec<FileSummary> =
+                    fitparser::de::parse_all_with_options(data, &opts.decode_opts)?
+                        .iter()
+                        .map(|f| FileSummary::new(f.records()))
+                        .collect();
+                opts.to_json(&summaries)?
+            }
+            Self::Header => opts.to_json(&HeaderInfo::read_all(data, &opts.decode_opts)?)?,
+            Self::DebugDump => debug_dump(data, &opts.decode_opts),
+            Self::Files => {
+                let mut files = fitparser::de::parse_all_with_options(data, &opts.decode_opts)?;
+                let ser_opts: Vec<SerializationOptions> = files
+                    .iter()
+                    .map(|f| opts.serialization_options(f.records()))
+                    .collect();
+                files
+                    .iter_mut()
+                    .for_each(|f| f.retain_records(|r| opts.is_included(r.kind())));
+                let output: Vec<_> = ser_opts
+                    .iter()
+                    .zip(&files)
+                    .map(|(o, f)| o.file(f))
+                    .collect();
+                opts.to_json(&output)?
+            }
+            Self::Csv => opts.filter_csv(csv::to_string(data, &opts.decode_opts)?),
+            Self::Kml => kml::to_string(&records()?),
+            Self::GeoJson => geojson::to_string(&records()?),
+            Self::Influx => influx::to_string(&records()?),
+            #[cfg(feature = "arrow")]
+            Self::Parquet => {
+                let mut output = Vec::new();
+                if let Some(batch) = arrow::to_record_batch(&records()?, MesgNum::Record)? {
+                    arrow::write_parquet(&batch, &mut output)?;
+                }
+                return Ok(output);
+            }
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite => return Err("sqlite output is written directly to a database".into()),
+        };
+        Ok(content.into_bytes())
+    }
+
+    /// Check if the output format is binary data that can't be printed to STDOUT as text
+    fn is_binary(&self) -> bool {
+        match self {
+            #[cfg(feature = "arrow")]
+            Self::Parquet => true,
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "ndjson" => Ok(Self::NdJson),
+            "csv" => Ok(Self::Csv),
+            "kml" => Ok(Self::Kml),
+            "geojson" => Ok(Self::GeoJson),
+            "influx" => Ok(Self::Influx),
+            #[cfg(feature = "arrow")]
+            "parquet" => Ok(Self::Parquet),
+            #[cfg(not(feature = "arrow"))]
+            "parquet" => Err(String::from("parquet output requires the arrow feature")),
+            #[cfg(feature = "sqlite")]
+            "sqlite" => Ok(Self::Sqlite),
+            #[cfg(not(feature = "sqlite"))]
+            "sqlite" => Err(String::from("sqlite output requires the sqlite feature")),
+            _ => Err(format!("unknown output format: {}", s)),
+        }
+    }
+}
+
+/// Parse the name of a message kind
+fn parse_mesg_num(s: &str) -> Result<MesgNum, String> {
+    s.parse()
+        .map_err(|_| format!("unknown message type: {}", s))
+}
+
+/// Time zone used to write timestamps
+#[derive(Clone, Copy, Debug)]
+enum TimeZone {
+    System,
+    Utc,
+    Activity,
+}
+
+/// Print the JSON Schema of the records written using the options
+#[cfg(feature = "schema")]
+fn print_json_schema(opts: &ConvertOptions) -> Result<(), Box<dyn Error>> {
+    let schema = fitparser::schema::json_schema(&opts.serialization_options(&[]));
+    println!("{}", opts.to_json(&schema)?);
+    Ok(())
+}
+
+/// Print the JSON Schema of the records written using the options
+#[cfg(not(feature = "schema"))]
+fn print_json_schema(_: &ConvertOptions) -> Result<(), Box<dyn Error>> {
+    Err("--json-schema requires the schema feature".into())
+}
+
+/// Parse the name of a time zone option
+fn parse_time_zone(s: &str) -> Result<TimeZone, String> {
+    match s {
+        "system" => Ok(TimeZone::System),
+        "utc" => Ok(TimeZone::Utc),
+        "activity" => Ok(TimeZone::Activity),
+        _ => Err(format!("unknown time zone: {}", s)),
+    }
+}
+
+/// Parse the name of a timestamp format
+fn parse_timestamp_format(s: &str) -> Result<TimestampFormat, String> {
+    match s {
+        "rfc3339" => Ok(TimestampFormat::Rfc3339),
+        "unix" => Ok(TimestampFormat::UnixSeconds),
+        "unix-ms" => Ok(TimestampFormat::UnixMillis),
+        "fit" => Ok(TimestampFormat::FitSeconds),
+        _ => Err(format!("unknown timestamp format: {}", s)),
+    }
+}
+
+/// Parse the name of a field name casing
+fn parse_field_case(s: &str) -> Result<FieldCase, String> {
+    match s {
+        "snake" => Ok(FieldCase::Snake),
+        "camel" => Ok(FieldCase::Camel),
+        "pascal" => Ok(FieldCase::Pascal),
+        _ => Err(format!("unknown field case: {}", s)),
+    }
+}
+
+/// Parse the name of a unit system
+fn parse_units(s: &str) -> Result<Units, String> {
+    match s {
+        "metric" => Ok(Units::Metric),
+        "statute" => Ok(Units::Statute),
+        "nautical" => Ok(Units::Nautical),
+        _ => Err(format!("unknown unit system: {}", s)),
+    }
+}
+
+/// Parse the name of a decode preset
+fn parse_preset(s: &str) -> Result<DecodePreset, String> {
+    s.parse().map_err(|e: fitparser::Error| e.to_string())
+}
+
 #[derive(Clone, Debug)]
 enum OutputLocation {
     Inplace,
@@ -86,40 +805,249 @@ impl OutputLocation {
         }
     }
 
-    fn write_json_file(
+    /// Write the converted contents of a FIT file
+    fn write_file(
         &self,
-        filename: &Path,
-        data: Vec<fitparser::FitDataRecord>,
+        file: &InputFile,
+        content: &[u8],
+        format: OutputFormat,
     ) -> Result<(), Box<dyn Error>> {
-        // convert data to a name: {value, units} map before serializing
-        let data: Vec<FitDataMap> = data.into_iter().map(FitDataMap::new).collect();
-        let json = serde_json::to_string(&data)?;
-
+        // data read from STDIN has no file to write alongside so it is printed instead
         let outname = match self {
-            Self::Inplace => filename.with_extension("json"),
-            Self::LocalDirectory(dest) => dest
-                .clone()
-                .join(filename.file_name().unwrap())
-                .with_extension("json"),
+            Self::Inplace if !file.is_stdin() => file.path.with_extension(format.extension()),
+            Self::LocalDirectory(dest) => {
+                let outname = dest.join(&file.name).with_extension(format.extension());
+                if let Some(parent) = outname.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                outname
+            }
             Self::LocalFile(dest) => dest.clone(),
-            Self::Stdout => {
-                println!("{}", json);
-                return Ok(());
+            Self::Inplace | Self::Stdout => {
+                let mut stdout = io::stdout();
+                let result = if format.is_binary() {
+                    stdout.write_all(content)
+                } else {
+                    writeln!(stdout, "{}", String::from_utf8_lossy(content))
+                };
+                // the reader of a pipeline closing early, e.g. `head`, isn't an error
+                return match result {
+                    Err(e) if e.kind() != io::ErrorKind::BrokenPipe => Err(Box::new(e)),
+                    _ => Ok(()),
+                };
             }
         };
         let mut fp = File::create(outname)?;
-        match fp.write_all(json.as_bytes()) {
+        match fp.write_all(content) {
             Ok(_) => Ok(()),
             Err(e) => Err(Box::new(e)),
         }
     }
 }
 
+/// A FIT file to read, STDIN is read if the path is "-"
+#[derive(Clone, Debug, Default)]
+struct InputFile {
+    path: PathBuf,
+    /// Path of the output file relative to an output directory, without the extension
+    name: PathBuf,
+}
+
+/// Contents of an input file
+type FileData = Box<dyn Deref<Target = [u8]> + Send>;
+
+impl fmt::Display for InputFile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_stdin() {
+            write!(f, "STDIN")
+        } else {
+            write!(f, "{}", self.path.display())
+        }
+    }
+}
+
+impl InputFile {
+    fn is_stdin(&self) -> bool {
+        self.path.as_os_str() == "-"
+    }
+
+    /// Read the contents of the FIT file, files are memory mapped instead of copied when the
+    /// `mmap` feature is enabled
+    fn read(&self) -> io::Result<FileData> {
+        let mut data = Vec::new();
+        if self.is_stdin() {
+            io::stdin().read_to_end(&mut data)?;
+        } else {
+            // SAFETY: the input files are only read, the caller must not modify or truncate them
+            // while they are being converted
+            #[cfg(feature = "mmap")]
+            return Ok(Box::new(unsafe { fitparser::de::Mmap::open(&self.path)? }));
+            #[cfg(not(feature = "mmap"))]
+            File::open(&self.path)?.read_to_end(&mut data)?;
+        }
+        Ok(Box::new(data))
+    }
+}
+
+/// Files to read, STDIN is read if no files were given. Directories are searched for FIT files
+/// when `recursive` is set, keeping their structure in the output names.
+fn input_files(files: &[PathBuf], recursive: bool) -> io::Result<Vec<InputFile>> {
+    if files.is_empty() {
+        return Ok(vec![InputFile {
+            path: PathBuf::from("-"),
+            name: PathBuf::from("stdin"),
+        }]);
+    }
+    let mut inputs = Vec::new();
+    for file in &expand_patterns(files)? {
+        if recursive && file.is_dir() {
+            find_fit_files(file, Path::new(""), &mut inputs)?;
+        } else if file.is_dir() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} is a directory, use --recursive", file.display()),
+            ));
+        } else if file.as_os_str() == "-" {
+            inputs.push(InputFile {
+                path: file.clone(),
+                name: PathBuf::from("stdin"),
+            });
+        } else {
+            inputs.push(InputFile {
+                path: file.clone(),
+                name: file.file_name().map(PathBuf::from).unwrap_or_default(),
+            });
+        }
+    }
+    Ok(inputs)
+}
+
+/// Expand glob patterns that weren't expanded by the shell, as is the case on Windows. Paths
+/// that exist are used as is and patterns that don't match any file are an error.
+fn expand_patterns(files: &[PathBuf]) -> io::Result<Vec<PathBuf>> {
+    let mut expanded = Vec::new();
+    for file in files {
+        let pattern = file.to_string_lossy();
+        if file.exists() || !pattern.contains(&['*', '?', '['][..]) {
+            expanded.push(file.clone());
+            continue;
+        }
+        let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidInput, msg);
+        let paths = glob::glob(&pattern)
+            .map_err(|e| invalid(format!("invalid pattern {}: {}", pattern, e)))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| invalid(e.to_string()))?;
+        if paths.is_empty() {
+            return Err(invalid(format!("no files match the pattern {}", pattern)));
+        }
+        expanded.extend(paths);
+    }
+    Ok(expanded)
+}
+
+/// Add all files with a .fit extension in the directory and its subdirectories, in the order of
+/// their path
+fn find_fit_files(dir: &Path, name: &Path, inputs: &mut Vec<InputFile>) -> io::Result<()> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|e| e.map(|e| e.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+    for path in entries {
+        let name = name.join(path.file_name().unwrap_or_default());
+        if path.is_dir() {
+            find_fit_files(&path, &name, inputs)?;
+        } else if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("fit"))
+        {
+            inputs.push(InputFile { path, name });
+        }
+    }
+    Ok(())
+}
+
+/// Write each FIT file of a possibly chained FIT file into the database as a separate file
+#[cfg(feature = "sqlite")]
+fn write_chained_files(
+    conn: &rusqlite::Connection,
+    data: &[u8],
+    opts: &ConvertOptions,
+) -> Result<(), Box<dyn Error>> {
+    for fit_file in fitparser::de::parse_all_with_options(data, &opts.decode_opts)? {
+        let records: Vec<_> = fit_file
+            .records()
+            .iter()
+            .filter(|r| opts.is_included(r.kind()))
+            .cloned()
+            .collect();
+        sqlite::write_records(conn, &records)?;
+    }
+    Ok(())
+}
+
+/// Write the records of each FIT file into a SQLite database
+#[cfg(feature = "sqlite")]
+fn write_database(
+    output: Option<PathBuf>,
+    files: &[InputFile],
+    opts: &ConvertOptions,
+    mut progress: Progress,
+) -> Result<(), Box<dyn Error>> {
+    let database = match output {
+        Some(path) if path.as_os_str() != "-" && !path.is_dir() => path,
+        _ => return Err("sqlite output requires a database file as the output location".into()),
+    };
+    let conn = rusqlite::Connection::open(database)?;
+
+    for file in files {
+        let result = file
+            .read()
+            .map_err(Box::from)
+            .and_then(|data| write_chained_files(&conn, &data, opts));
+        progress.update(file, result)?;
+    }
+    progress.finish()
+}
+
+/// Logger printing the messages of the parser to STDERR
+struct StderrLogger;
+
+impl log::Log for StderrLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!(
+                "{}: {}",
+                record.level().as_str().to_lowercase(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
 fn run() -> Result<(), Box<dyn Error>> {
     let opt = Cli::from_args();
 
-    // set any decode options
-    let mut decode_opts = HashSet::new();
+    log::set_logger(&LOGGER)?;
+    log::set_max_level(match (opt.quiet, opt.verbose) {
+        (true, _) => log::LevelFilter::Off,
+        // the warnings requested by these options are printed without "-v"
+        (false, 0) if opt.warn_crc || opt.recover => log::LevelFilter::Warn,
+        (false, 0) => log::LevelFilter::Error,
+        (false, 1) => log::LevelFilter::Warn,
+        (false, 2) => log::LevelFilter::Debug,
+        (false, _) => log::LevelFilter::Trace,
+    });
+
+    // set any decode options, starting with the options of the preset
+    let mut decode_opts = opt.preset.map_or_else(HashSet::new, DecodePreset::options);
     if opt.drop_unknown {
         decode_opts.insert(DecodeOption::DropUnknownFields);
         decode_opts.insert(DecodeOption::DropUnknownMessages);
@@ -133,10 +1061,66 @@ fn run() -> Result<(), Box<dyn Error>> {
     if opt.numeric_enums {
         decode_opts.insert(DecodeOption::ReturnNumericEnumValues);
     }
+    if opt.raw_values {
+        decode_opts.insert(DecodeOption::ReturnRawValues);
+    }
+    if opt.include_invalid {
+        decode_opts.insert(DecodeOption::KeepRawBytes);
+    }
+    if opt.units != Units::Metric {
+        decode_opts.insert(DecodeOption::ConvertUnits(opt.units));
+    }
+    if opt.degrees {
+        decode_opts.insert(DecodeOption::ConvertSemicircles);
+    }
     if opt.no_crc_check {
         decode_opts.insert(DecodeOption::SkipHeaderCrcValidation);
         decode_opts.insert(DecodeOption::SkipDataCrcValidation);
     }
+    if opt.warn_crc {
+        decode_opts.insert(DecodeOption::WarnOnCrcMismatch);
+    }
+    if opt.recover {
+        decode_opts.insert(DecodeOption::RecoverRecords);
+    }
+
+    let opts = ConvertOptions {
+        decode_opts,
+        pretty: opt.pretty,
+        include_units: !opt.no_units,
+        time_zone: opt.time_zone,
+        timestamp_format: opt.timestamp_format,
+        field_case: opt.field_case,
+        enum_names: opt.enum_names,
+        include_invalid: opt.include_invalid,
+        include_types: opt.include_types,
+        exclude_types: opt.exclude_types,
+    };
+
+    let format = if opt.summary {
+        OutputFormat::Summary
+    } else if opt.header_only {
+        OutputFormat::Header
+    } else if opt.with_header {
+        OutputFormat::Files
+    } else if opt.debug_dump {
+        OutputFormat::DebugDump
+    } else {
+        opt.format
+    };
+
+    if opt.json_schema {
+        return print_json_schema(&opts);
+    }
+
+    let files = input_files(&opt.files, opt.recursive)?;
+    let mut progress = Progress::new(files.len(), opt.quiet, opt.fail_fast);
+
+    // records are written directly into the database instead of an output file
+    #[cfg(feature = "sqlite")]
+    if let OutputFormat::Sqlite = format {
+        return write_database(opt.output, &files, &opts, progress);
+    }
 
     // define parsed and serialized data output location
     let output_loc = opt
@@ -144,34 +1128,143 @@ fn run() -> Result<(), Box<dyn Error>> {
         .map_or(OutputLocation::Inplace, OutputLocation::new);
     let collect_all = matches!(output_loc, OutputLocation::LocalFile(_));
 
-    // read from STDIN if no files were given
-    if opt.files.is_empty() {
-        let mut stdin = io::stdin();
-        let data = from_reader_with_options(&mut stdin, &decode_opts)?;
-        output_loc.write_json_file(&PathBuf::from("<stdin>"), data)?;
-        return Ok(());
+    // output a single fit file's data into a single output file
+    if !collect_all {
+        let jobs = opt.jobs.unwrap_or_else(|| {
+            thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get)
+        });
+        return convert_files(&output_loc, &files, format, &opts, jobs, progress);
     }
 
-    // Read each FIT file and output it
-    let mut all_fit_data: Vec<fitparser::FitDataRecord> = Vec::new();
-    for file in opt.files {
-        // open file and parse data
-        let mut fp = File::open(&file)?;
-        let mut data = from_reader_with_options(&mut fp, &decode_opts)?;
+    // output fit data from all files into a single file, chained FIT files are supported by the
+    // parser
+    let mut all_fit_data: Vec<u8> = Vec::new();
+    for file in &files {
+        let result = file
+            .read()
+            .map(|data| all_fit_data.extend_from_slice(&data));
+        progress.update(file, result.map_err(Box::from))?;
+    }
+    let content = format
+        .convert(&all_fit_data, &opts)
+        .map_err(|e| e as Box<dyn Error>)?;
+    output_loc.write_file(&InputFile::default(), &content, format)?;
 
-        // output a single fit file's data into a single output file
-        if collect_all {
-            all_fit_data.append(&mut data);
-        } else {
-            output_loc.write_json_file(&file, data)?;
+    progress.finish()
+}
+
+/// Convert each FIT file using a pool of worker threads, the output is written in the same order
+/// as the input files as soon as all of the previous files have been written
+fn convert_files(
+    output_loc: &OutputLocation,
+    files: &[InputFile],
+    format: OutputFormat,
+    opts: &ConvertOptions,
+    jobs: usize,
+    mut progress: Progress,
+) -> Result<(), Box<dyn Error>> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, files.len().max(1)) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let file = match files.get(index) {
+                    Some(file) => file,
+                    None => break,
+                };
+                let result = file
+                    .read()
+                    .map_err(Box::from)
+                    .and_then(|data| format.convert(&data, opts));
+                // the receiver is gone once an error was returned
+                if sender.send((index, result)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        // results arrive in the order the conversions finish so they're sorted by index
+        let mut pending = BTreeMap::new();
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&progress.completed) {
+                let file = &files[progress.completed];
+                let result = result
+                    .map_err(|e| e as Box<dyn Error>)
+                    .and_then(|content| output_loc.write_file(file, &content, format));
+                progress.update(file, result)?;
+            }
+        }
+        progress.finish()
+    })
+}
+
+/// Progress of a batch conversion, shown on a single line of STDERR that is redrawn after each
+/// file when STDERR is a terminal. Files that fail to convert are printed and the remaining files
+/// are still converted unless the batch fails fast.
+struct Progress {
+    total: usize,
+    completed: usize,
+    failed: usize,
+    visible: bool,
+    fail_fast: bool,
+}
+
+impl Progress {
+    fn new(total: usize, quiet: bool, fail_fast: bool) -> Self {
+        Progress {
+            total,
+            completed: 0,
+            failed: 0,
+            visible: !quiet && total > 1 && io::stderr().is_terminal(),
+            fail_fast,
         }
     }
-    // output fit data from all files into a single file
-    if collect_all {
-        output_loc.write_json_file(&PathBuf::new(), all_fit_data)?;
+
+    /// Record the result of converting a file, the error is returned if the batch should stop
+    fn update(
+        &mut self,
+        file: &InputFile,
+        result: Result<(), Box<dyn Error>>,
+    ) -> Result<(), Box<dyn Error>> {
+        self.completed += 1;
+        if let Err(err) = result {
+            self.clear();
+            let err = format!("{}: {}", file, err);
+            if self.fail_fast || self.total == 1 {
+                return Err(err.into());
+            }
+            self.failed += 1;
+            eprintln!("error: {}", err);
+        }
+        if self.visible {
+            eprint!("\r\x1b[K[{}/{}] {}", self.completed, self.total, file);
+            if self.failed > 0 {
+                eprint!(" ({} failed)", self.failed);
+            }
+        }
+        Ok(())
     }
 
-    Ok(())
+    /// Erase the progress line and return an error summarizing the failed files
+    fn finish(&self) -> Result<(), Box<dyn Error>> {
+        self.clear();
+        match self.failed {
+            0 => Ok(()),
+            failed => Err(format!("{} of {} files failed to convert", failed, self.total).into()),
+        }
+    }
+
+    /// Erase the progress line
+    fn clear(&self) {
+        if self.visible {
+            eprint!("\r\x1b[K");
+        }
+    }
 }
 
 fn main() {
@@ -0,0 +1,61 @@
+//! Check FIT files for problems and report them in a machine-readable format
+use fitparser::de::{validate, Problem};
+use serde::Serialize;
+use std::error::Error;
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Validate the header, checksums, definition messages and field values of FIT files against the
+/// FIT protocol and profile. The exit code is 1 if any problems were found.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "fit_verify")]
+struct Cli {
+    /// FIT files to validate
+    #[structopt(name = "FILE", parse(from_os_str), required = true)]
+    files: Vec<PathBuf>,
+
+    /// Print each problem as a JSON object on its own line instead of text
+    #[structopt(long)]
+    json: bool,
+}
+
+/// A problem along with the file it was found in
+#[derive(Debug, Serialize)]
+struct FileProblem<'a> {
+    file: String,
+    #[serde(flatten)]
+    problem: &'a Problem,
+}
+
+fn run() -> Result<bool, Box<dyn Error>> {
+    let opt = Cli::from_args();
+
+    let mut valid = true;
+    for file in &opt.files {
+        let mut data = Vec::new();
+        File::open(file)?.read_to_end(&mut data)?;
+        for problem in &validate(&data) {
+            valid = false;
+            if opt.json {
+                let file = file.display().to_string();
+                println!("{}", serde_json::to_string(&FileProblem { file, problem })?);
+            } else {
+                println!("{}: {}", file.display(), problem);
+            }
+        }
+    }
+    Ok(valid)
+}
+
+fn main() {
+    std::process::exit(match run() {
+        Ok(true) => 0,
+        Ok(false) => 1,
+        Err(err) => {
+            eprintln!("{}", err);
+            2
+        }
+    });
+}
@@ -0,0 +1,213 @@
+//! Convert the route or track of a GPX file into a FIT course
+use chrono::{DateTime, Local};
+use fitparser::course::CourseBuilder;
+use fitparser::profile::field_types::{CoursePoint, Sport};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Convert the track points of a GPX file, or the route points when it has no tracks, into a FIT
+/// course that can be navigated on a device. The distance is measured along the points and the
+/// timestamps assume a constant speed.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "gpx_to_fit")]
+struct Cli {
+    /// GPX file to convert, using a "-" will read from STDIN
+    #[structopt(name = "FILE", parse(from_os_str))]
+    file: PathBuf,
+
+    /// Output file, if not provided the course is written alongside the input file with a '.fit'
+    /// extension. Using a "-", or reading from STDIN, will write the FIT file to STDOUT.
+    #[structopt(short, long, parse(from_os_str))]
+    output: Option<PathBuf>,
+
+    /// Name of the course, defaults to the name of the track or route
+    #[structopt(long)]
+    name: Option<String>,
+
+    /// Sport of the course using the names defined by the FIT profile, e.g. cycling or running
+    #[structopt(long, default_value = "cycling", parse(try_from_str = parse_sport))]
+    sport: Sport,
+
+    /// Speed in m/s used to compute the timestamps of the course
+    #[structopt(long, default_value = "5.0")]
+    speed: f64,
+
+    /// Start time of the course as an RFC 3339 timestamp, defaults to the current time
+    #[structopt(long, parse(try_from_str = parse_start_time))]
+    start_time: Option<DateTime<Local>>,
+
+    /// Don't write the waypoints of the GPX file as course points
+    #[structopt(long)]
+    no_waypoints: bool,
+}
+
+fn parse_sport(s: &str) -> Result<Sport, String> {
+    s.parse().map_err(|_| format!("unknown sport: {}", s))
+}
+
+fn parse_start_time(s: &str) -> Result<DateTime<Local>, String> {
+    DateTime::parse_from_rfc3339(s)
+        .map(|t| t.with_timezone(&Local))
+        .map_err(|e| e.to_string())
+}
+
+/// Position read from a GPX file along with the name and type of waypoints
+#[derive(Debug, Default)]
+struct Point {
+    latitude: f64,
+    longitude: f64,
+    altitude: Option<f64>,
+    name: Option<String>,
+    kind: Option<String>,
+}
+
+/// Points and name read from a GPX file
+#[derive(Debug, Default)]
+struct Gpx {
+    name: Option<String>,
+    track: Vec<Point>,
+    route: Vec<Point>,
+    waypoints: Vec<Point>,
+}
+
+/// Read the lat and lon attributes of a point element
+fn read_point(element: &BytesStart) -> Result<Point, Box<dyn Error>> {
+    let mut point = Point::default();
+    for attr in element.attributes() {
+        let attr = attr?;
+        match attr.key.as_ref() {
+            b"lat" => point.latitude = std::str::from_utf8(&attr.value)?.parse()?,
+            b"lon" => point.longitude = std::str::from_utf8(&attr.value)?.parse()?,
+            _ => {}
+        }
+    }
+    Ok(point)
+}
+
+/// Parse the track, route and waypoints of a GPX document
+fn parse_gpx(data: &str) -> Result<Gpx, Box<dyn Error>> {
+    let mut reader = Reader::from_str(data);
+    reader.trim_text(true);
+    let mut gpx = Gpx::default();
+    let mut elements: Vec<Vec<u8>> = Vec::new();
+    let mut point: Option<Point> = None;
+    loop {
+        match reader.read_event()? {
+            Event::Start(element) => {
+                let name = element.local_name().as_ref().to_vec();
+                if matches!(name.as_slice(), b"trkpt" | b"rtept" | b"wpt") {
+                    point = Some(read_point(&element)?);
+                }
+                elements.push(name);
+            }
+            Event::Empty(element) => {
+                let point = read_point(&element)?;
+                match element.local_name().as_ref() {
+                    b"trkpt" => gpx.track.push(point),
+                    b"rtept" => gpx.route.push(point),
+                    b"wpt" => gpx.waypoints.push(point),
+                    _ => {}
+                }
+            }
+            Event::Text(text) => {
+                let text = text.unescape()?.into_owned();
+                let parent = elements
+                    .len()
+                    .checked_sub(2)
+                    .map(|i| elements[i].as_slice());
+                match (elements.last().map(Vec::as_slice), parent, point.as_mut()) {
+                    (Some(b"ele"), _, Some(point)) => point.altitude = Some(text.parse()?),
+                    (Some(b"name"), _, Some(point)) => point.name = Some(text),
+                    (Some(b"type"), _, Some(point)) => point.kind = Some(text),
+                    (Some(b"name"), Some(b"trk" | b"rte"), None) => {
+                        gpx.name.get_or_insert(text);
+                    }
+                    _ => {}
+                }
+            }
+            Event::End(_) => {
+                let name = elements.pop().unwrap_or_default();
+                let done = match name.as_slice() {
+                    b"trkpt" => Some(&mut gpx.track),
+                    b"rtept" => Some(&mut gpx.route),
+                    b"wpt" => Some(&mut gpx.waypoints),
+                    _ => None,
+                };
+                if let Some(points) = done {
+                    points.extend(point.take());
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(gpx)
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let opt = Cli::from_args();
+
+    let mut data = String::new();
+    if opt.file.as_os_str() == "-" {
+        io::stdin().read_to_string(&mut data)?;
+    } else {
+        File::open(&opt.file)?.read_to_string(&mut data)?;
+    }
+    let gpx = parse_gpx(&data)?;
+
+    let name = opt
+        .name
+        .clone()
+        .or(gpx.name)
+        .or_else(|| {
+            opt.file
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+        })
+        .unwrap_or_default();
+    let start_time = opt.start_time.unwrap_or_else(Local::now);
+    let mut builder = CourseBuilder::new(&name, opt.sport, start_time).with_speed(opt.speed);
+    let points = if gpx.track.is_empty() {
+        gpx.route
+    } else {
+        gpx.track
+    };
+    for point in points {
+        builder = builder.point(point.latitude, point.longitude, point.altitude);
+    }
+    if !opt.no_waypoints {
+        // the waypoint type is used when it matches a course point type, e.g. left or summit
+        for point in gpx.waypoints {
+            let kind = point
+                .kind
+                .and_then(|k| k.to_lowercase().parse().ok())
+                .unwrap_or(CoursePoint::Generic);
+            let name = point.name.unwrap_or_default();
+            builder = builder.cue(point.latitude, point.longitude, kind, &name);
+        }
+    }
+    let data = builder.to_bytes()?;
+
+    match opt.output {
+        Some(output) if output.as_os_str() == "-" => io::stdout().write_all(&data)?,
+        Some(output) => File::create(output)?.write_all(&data)?,
+        None if opt.file.as_os_str() == "-" => io::stdout().write_all(&data)?,
+        None => File::create(opt.file.with_extension("fit"))?.write_all(&data)?,
+    }
+    Ok(())
+}
+
+fn main() {
+    std::process::exit(match run() {
+        Ok(_) => 0,
+        Err(err) => {
+            eprintln!("{}", err);
+            1
+        }
+    });
+}
@@ -0,0 +1,58 @@
+//! Convert the JSON output of fit_to_json back into a FIT file
+use fitparser::FitDataRecord;
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Encode records serialized as JSON into a FIT file. The input can be a JSON array of records
+/// or one record per line, using either the field array or the field map written by
+/// fit_to_json.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "json_to_fit")]
+struct Cli {
+    /// JSON file to convert, using a "-" will read from STDIN
+    #[structopt(name = "FILE", parse(from_os_str))]
+    file: PathBuf,
+
+    /// Output file, using a "-" will write the FIT file to STDOUT
+    #[structopt(short, long, parse(from_os_str))]
+    output: PathBuf,
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let opt = Cli::from_args();
+
+    let mut input = Vec::new();
+    if opt.file.as_os_str() == "-" {
+        io::stdin().read_to_end(&mut input)?;
+    } else {
+        File::open(&opt.file)?.read_to_end(&mut input)?;
+    }
+    let records: Vec<FitDataRecord> = if input.trim_ascii_start().starts_with(b"[") {
+        serde_json::from_slice(&input)?
+    } else {
+        serde_json::Deserializer::from_slice(&input)
+            .into_iter()
+            .collect::<Result<_, _>>()?
+    };
+    let data = fitparser::to_bytes(&records)?;
+
+    if opt.output.as_os_str() == "-" {
+        io::stdout().write_all(&data)?;
+    } else {
+        File::create(&opt.output)?.write_all(&data)?;
+    }
+    Ok(())
+}
+
+fn main() {
+    std::process::exit(match run() {
+        Ok(_) => 0,
+        Err(err) => {
+            eprintln!("{}", err);
+            1
+        }
+    });
+}
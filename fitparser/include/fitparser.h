@@ -0,0 +1,45 @@
+/*
+ * C declarations for the fitparser FFI layer, build the crate with the `ffi` feature as a
+ * cdylib or staticlib to use these functions.
+ *
+ * Strings returned by the accessor functions are owned by the FitRecords handle and remain
+ * valid until it is released with fit_records_free.
+ */
+#ifndef FITPARSER_H
+#define FITPARSER_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Opaque handle storing the records parsed from a FIT file */
+typedef struct FitRecords FitRecords;
+
+/* Parse a FIT file stored in a buffer, NULL is returned on failure and error is set to a
+ * message that must be released with fit_string_free (error may be NULL) */
+FitRecords *fit_parse_buffer(const uint8_t *data, size_t len, char **error);
+void fit_records_free(FitRecords *records);
+void fit_string_free(char *value);
+
+/* Record accessors */
+size_t fit_records_len(const FitRecords *records);
+uint16_t fit_record_kind(const FitRecords *records, size_t record);
+const char *fit_record_kind_name(const FitRecords *records, size_t record);
+size_t fit_record_field_count(const FitRecords *records, size_t record);
+
+/* Field accessors */
+const char *fit_field_name(const FitRecords *records, size_t record, size_t field);
+uint8_t fit_field_number(const FitRecords *records, size_t record, size_t field);
+bool fit_field_value_f64(const FitRecords *records, size_t record, size_t field, double *value);
+const char *fit_field_value_string(const FitRecords *records, size_t record, size_t field);
+const char *fit_field_units(const FitRecords *records, size_t record, size_t field);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* FITPARSER_H */
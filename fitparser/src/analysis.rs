@@ -0,0 +1,1057 @@
+//! Compute activity summaries directly from the `record` messages of a FIT file, this is useful
+//! for files where the `session` message is missing or contains incorrect values. Activities can
+//! also be merged, split and have their timestamps corrected, and the data recorded by each
+//! `record` message extracted into aligned streams for plotting.
+use crate::compat::prelude::*;
+use crate::de::{BaseType, FitFileView};
+use crate::error::{ErrorKind, Result};
+use crate::export::{numeric_field, semicircles_field, timestamp_field};
+use crate::profile::MesgNum;
+use crate::{FitDataField, FitDataRecord, TimestampTz, Value};
+use chrono::{DateTime, Duration};
+use nom::number::Endianness;
+
+/// Speed in m/s below which the athlete is considered to be stopped
+const MOVING_SPEED_THRESHOLD: f64 = 0.5;
+
+/// Running count, total and maximum of a field across records
+#[derive(Clone, Copy, Debug, Default)]
+struct Stats {
+    count: usize,
+    total: f64,
+    max: Option<f64>,
+}
+
+impl Stats {
+    fn add(&mut self, value: Option<f64>) {
+        if let Some(value) = value {
+            self.count += 1;
+            self.total += value;
+            self.max = Some(self.max.map_or(value, |max| max.max(value)));
+        }
+    }
+
+    fn avg(&self) -> Option<f64> {
+        if self.count > 0 {
+            Some(self.total / self.count as f64)
+        } else {
+            None
+        }
+    }
+}
+
+/// Summary of an activity computed from its `record` messages
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SessionSummary {
+    start_time: Option<DateTime<TimestampTz>>,
+    end_time: Option<DateTime<TimestampTz>>,
+    total_elapsed_time: f64,
+    total_moving_time: f64,
+    total_distance: Option<f64>,
+    avg_heart_rate: Option<f64>,
+    max_heart_rate: Option<f64>,
+    avg_power: Option<f64>,
+    max_power: Option<f64>,
+    avg_speed: Option<f64>,
+    max_speed: Option<f64>,
+    total_ascent: Option<f64>,
+    total_descent: Option<f64>,
+}
+
+impl SessionSummary {
+    /// Compute the summary from the `record` messages, all other messages are ignored. The
+    /// enhanced speed and altitude fields are used in place of the standard fields when present.
+    pub fn from_records(records: &[FitDataRecord]) -> Self {
+        let mut summary = SessionSummary::default();
+        let mut heart_rate = Stats::default();
+        let mut power = Stats::default();
+        let mut speed = Stats::default();
+        let mut last: Option<(DateTime<TimestampTz>, Option<f64>)> = None;
+        let mut last_altitude: Option<f64> = None;
+
+        for record in records.iter().filter(|r| r.kind() == MesgNum::Record) {
+            heart_rate.add(numeric_field(record, "heart_rate"));
+            power.add(numeric_field(record, "power"));
+            let record_speed =
+                numeric_field(record, "enhanced_speed").or_else(|| numeric_field(record, "speed"));
+            speed.add(record_speed);
+
+            let distance = numeric_field(record, "distance");
+            if distance.is_some() {
+                summary.total_distance = distance;
+            }
+
+            let altitude = numeric_field(record, "enhanced_altitude")
+                .or_else(|| numeric_field(record, "altitude"));
+            if let (Some(altitude), Some(last_altitude)) = (altitude, last_altitude) {
+                let delta = altitude - last_altitude;
+                *summary.total_ascent.get_or_insert(0.0) += delta.max(0.0);
+                *summary.total_descent.get_or_insert(0.0) -= delta.min(0.0);
+            }
+            last_altitude = altitude.or(last_altitude);
+
+            // time between records is moving time when the speed at the end of the interval,
+            // or the speed derived from the change in distance, is above the threshold
+            let timestamp = match timestamp_field(record, "timestamp") {
+                Some(timestamp) => timestamp,
+                None => continue,
+            };
+            summary.start_time = summary.start_time.or(Some(timestamp));
+            summary.end_time = Some(timestamp);
+            if let Some((last_timestamp, last_distance)) = last {
+                let elapsed = (timestamp - last_timestamp).num_milliseconds() as f64 / 1000.0;
+                let interval_speed = record_speed.or_else(|| match (distance, last_distance) {
+                    (Some(distance), Some(last_distance)) if elapsed > 0.0 => {
+                        Some((distance - last_distance) / elapsed)
+                    }
+                    _ => None,
+                });
+                if interval_speed.is_none_or(|v| v >= MOVING_SPEED_THRESHOLD) {
+                    summary.total_moving_time += elapsed;
+                }
+            }
+            if let Some(start_time) = summary.start_time {
+                summary.total_elapsed_time =
+                    (timestamp - start_time).num_milliseconds() as f64 / 1000.0;
+            }
+            last = Some((timestamp, distance.or(last.and_then(|l| l.1))));
+        }
+
+        summary.avg_heart_rate = heart_rate.avg();
+        summary.max_heart_rate = heart_rate.max;
+        summary.avg_power = power.avg();
+        summary.max_power = power.max;
+        summary.max_speed = speed.max;
+        summary.avg_speed = match summary.total_distance {
+            Some(distance) if summary.total_moving_time > 0.0 => {
+                Some(distance / summary.total_moving_time)
+            }
+            _ => speed.avg(),
+        };
+        summary
+    }
+
+    /// Return the timestamp of the first record
+    pub fn start_time(&self) -> Option<DateTime<TimestampTz>> {
+        self.start_time
+    }
+
+    /// Return the timestamp of the last record
+    pub fn end_time(&self) -> Option<DateTime<TimestampTz>> {
+        self.end_time
+    }
+
+    /// Return the time between the first and last record in seconds
+    pub fn total_elapsed_time(&self) -> f64 {
+        self.total_elapsed_time
+    }
+
+    /// Return the time spent moving in seconds, intervals without a speed or distance are
+    /// counted as moving
+    pub fn total_moving_time(&self) -> f64 {
+        self.total_moving_time
+    }
+
+    /// Return the distance of the last record in meters
+    pub fn total_distance(&self) -> Option<f64> {
+        self.total_distance
+    }
+
+    /// Return the average heart rate in bpm
+    pub fn avg_heart_rate(&self) -> Option<f64> {
+        self.avg_heart_rate
+    }
+
+    /// Return the maximum heart rate in bpm
+    pub fn max_heart_rate(&self) -> Option<f64> {
+        self.max_heart_rate
+    }
+
+    /// Return the average power in watts
+    pub fn avg_power(&self) -> Option<f64> {
+        self.avg_power
+    }
+
+    /// Return the maximum power in watts
+    pub fn max_power(&self) -> Option<f64> {
+        self.max_power
+    }
+
+    /// Return the average speed in m/s, this is the distance divided by the moving time when
+    /// both are available
+    pub fn avg_speed(&self) -> Option<f64> {
+        self.avg_speed
+    }
+
+    /// Return the maximum speed in m/s
+    pub fn max_speed(&self) -> Option<f64> {
+        self.max_speed
+    }
+
+    /// Return the sum of all altitude increases between records in meters
+    pub fn total_ascent(&self) -> Option<f64> {
+        self.total_ascent
+    }
+
+    /// Return the sum of all altitude decreases between records in meters
+    pub fn total_descent(&self) -> Option<f64> {
+        self.total_descent
+    }
+}
+
+/// Message kinds that are merged from every activity in timestamp order, all other messages
+/// except the session and activity are only kept from the first activity
+const STREAM_KINDS: &[MesgNum] = &[
+    MesgNum::Record,
+    MesgNum::Event,
+    MesgNum::Lap,
+    MesgNum::Length,
+];
+
+/// Merge activities recorded in several parts, such as a ride that was split after a device
+/// crash, into the records of a single activity that can be encoded using `to_bytes`.
+///
+/// The activities are ordered by their first timestamp. Records, events, laps and lengths from
+/// every activity are merged in timestamp order, records that overlap a previous activity are
+/// dropped and distances are offset when an activity restarts from zero. The totals of each lap
+/// are recomputed from the merged records and a single session using the first session as a
+/// template is written, the timer time of the session is the sum of the timer time of each part.
+pub fn merge_activities(activities: &[Vec<FitDataRecord>]) -> Result<Vec<FitDataRecord>> {
+    let mut activities: Vec<&Vec<FitDataRecord>> =
+        activities.iter().filter(|a| !a.is_empty()).collect();
+    activities.sort_by_key(|a| a.iter().find_map(|r| timestamp_field(r, "timestamp")));
+    let first = match activities.first() {
+        Some(first) => first,
+        None => return Err(ErrorKind::ValueError(String::from("No activities to merge")).into()),
+    };
+
+    let mut output: Vec<FitDataRecord> = first
+        .iter()
+        .filter(|r| {
+            !STREAM_KINDS.contains(&r.kind())
+                && r.kind() != MesgNum::Session
+                && r.kind() != MesgNum::Activity
+        })
+        .cloned()
+        .collect();
+    let mut stream: Vec<(DateTime<TimestampTz>, FitDataRecord)> = Vec::new();
+    let mut timer_time = 0.0;
+    let mut end_time: Option<DateTime<TimestampTz>> = None;
+    let mut end_distance = 0.0;
+    for activity in &activities {
+        let summary = SessionSummary::from_records(activity);
+        timer_time += activity
+            .iter()
+            .filter(|r| r.kind() == MesgNum::Session)
+            .filter_map(|r| numeric_field(r, "total_timer_time"))
+            .reduce(|a, b| a + b)
+            .unwrap_or_else(|| summary.total_elapsed_time());
+
+        // offset the distance of records if the activity starts counting from zero again
+        let start_distance = activity
+            .iter()
+            .filter(|r| r.kind() == MesgNum::Record)
+            .find_map(|r| numeric_field(r, "distance"));
+        let offset = match start_distance {
+            Some(distance) if distance < end_distance => end_distance,
+            _ => 0.0,
+        };
+
+        let previous_end_time = end_time;
+        let mut timestamp = None;
+        for record in activity.iter().filter(|r| STREAM_KINDS.contains(&r.kind())) {
+            timestamp = timestamp_field(record, "timestamp").or(timestamp);
+            let timestamp = match timestamp {
+                Some(timestamp) => timestamp,
+                None => continue,
+            };
+            if record.kind() != MesgNum::Record {
+                stream.push((timestamp, record.clone()));
+                continue;
+            }
+            if previous_end_time.is_some_and(|t| timestamp <= t) {
+                continue;
+            }
+            let distance = numeric_field(record, "distance").map(|d| Value::Float64(d + offset));
+            stream.push((
+                timestamp,
+                with_values(record.clone(), &[("distance", distance)]),
+            ));
+            end_time = Some(timestamp);
+        }
+        end_distance = summary
+            .total_distance()
+            .map_or(end_distance, |d| d + offset);
+    }
+    stream.sort_by_key(|(timestamp, _)| *timestamp);
+
+    let records: Vec<FitDataRecord> = stream
+        .iter()
+        .filter(|(_, r)| r.kind() == MesgNum::Record)
+        .map(|(_, r)| r.clone())
+        .collect();
+    let mut lap_count = 0u16;
+    for (timestamp, record) in stream {
+        if record.kind() != MesgNum::Lap {
+            output.push(record);
+            continue;
+        }
+        let start_time = timestamp_field(&record, "start_time").unwrap_or(timestamp);
+        let start = records
+            .partition_point(|r| timestamp_field(r, "timestamp").is_some_and(|t| t < start_time));
+        let end = records
+            .partition_point(|r| timestamp_field(r, "timestamp").is_some_and(|t| t <= timestamp));
+        let summary = SessionSummary::from_records(&records[start..end.max(start)]);
+        let start_distance = records[..start]
+            .iter()
+            .rev()
+            .find_map(|r| numeric_field(r, "distance"))
+            .unwrap_or(0.0);
+        let mut values = summary_values(&summary, summary.total_elapsed_time());
+        values.push((
+            "total_distance",
+            summary
+                .total_distance()
+                .map(|d| Value::Float64(d - start_distance)),
+        ));
+        values.push(("message_index", Some(Value::UInt16(lap_count))));
+        output.push(with_values(record, &values));
+        lap_count += 1;
+    }
+
+    // write a single session and activity covering every part
+    let summary = SessionSummary::from_records(&records);
+    let session = first
+        .iter()
+        .find(|r| r.kind() == MesgNum::Session)
+        .cloned()
+        .unwrap_or_else(|| FitDataRecord::new(MesgNum::Session));
+    let mut values = summary_values(&summary, timer_time);
+    values.push((
+        "total_distance",
+        summary.total_distance().map(Value::Float64),
+    ));
+    values.push(("start_time", summary.start_time().map(Value::Timestamp)));
+    values.push(("timestamp", summary.end_time().map(Value::Timestamp)));
+    values.push(("first_lap_index", Some(Value::UInt16(0))));
+    values.push(("num_laps", Some(Value::UInt16(lap_count))));
+    output.push(with_values(session, &values));
+    if let Some(activity) = activities
+        .iter()
+        .rev()
+        .find_map(|a| a.iter().find(|r| r.kind() == MesgNum::Activity))
+    {
+        let values = [
+            ("timestamp", summary.end_time().map(Value::Timestamp)),
+            ("total_timer_time", Some(Value::Float64(timer_time))),
+            ("num_sessions", Some(Value::UInt16(1))),
+        ];
+        output.push(with_values(activity.clone(), &values));
+    }
+
+    Ok(output)
+}
+
+/// Message used to divide an activity when splitting it into separate files
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SplitBy {
+    /// Write a file for each session, such as each leg of a triathlon
+    Session,
+    /// Write a file for each lap
+    Lap,
+}
+
+/// Split an activity into the records of a separate activity for each session or lap, each
+/// part can be encoded using `to_bytes`.
+///
+/// Every part contains the messages that aren't part of the record stream, such as the
+/// `file_id`, followed by the records, events and lengths recorded between the start time and
+/// timestamp of the session or lap. Laps are renumbered and each part contains a single session
+/// and activity message, when splitting by lap the session totals are computed from the records.
+pub fn split_activity(records: &[FitDataRecord], split_by: SplitBy) -> Vec<Vec<FitDataRecord>> {
+    let header: Vec<FitDataRecord> = records
+        .iter()
+        .filter(|r| {
+            !STREAM_KINDS.contains(&r.kind())
+                && r.kind() != MesgNum::Session
+                && r.kind() != MesgNum::Activity
+        })
+        .cloned()
+        .collect();
+    let mut stream: Vec<(DateTime<TimestampTz>, &FitDataRecord)> = Vec::new();
+    let mut timestamp = None;
+    for record in records.iter().filter(|r| STREAM_KINDS.contains(&r.kind())) {
+        timestamp = timestamp_field(record, "timestamp").or(timestamp);
+        if let Some(timestamp) = timestamp {
+            stream.push((timestamp, record));
+        }
+    }
+    stream.sort_by_key(|(timestamp, _)| *timestamp);
+    let sessions: Vec<&FitDataRecord> = records
+        .iter()
+        .filter(|r| r.kind() == MesgNum::Session)
+        .collect();
+    let activity = records.iter().find(|r| r.kind() == MesgNum::Activity);
+
+    let kind = match split_by {
+        SplitBy::Session => MesgNum::Session,
+        SplitBy::Lap => MesgNum::Lap,
+    };
+    let mut files = Vec::new();
+    for part in records.iter().filter(|r| r.kind() == kind) {
+        let end_time = match timestamp_field(part, "timestamp") {
+            Some(end_time) => end_time,
+            None => continue,
+        };
+        let start_time = timestamp_field(part, "start_time").unwrap_or(end_time);
+        let in_part = |t: &DateTime<TimestampTz>| start_time <= *t && *t <= end_time;
+
+        // a lap ending at the start time of a session belongs to the previous session
+        let mut output = header.clone();
+        let mut lap_count = 0u16;
+        for (timestamp, record) in stream.iter().filter(|(t, _)| in_part(t)) {
+            if record.kind() != MesgNum::Lap {
+                output.push((*record).clone());
+            } else if (split_by == SplitBy::Lap && core::ptr::eq(*record, part))
+                || (split_by == SplitBy::Session && *timestamp > start_time)
+            {
+                let value = Some(Value::UInt16(lap_count));
+                output.push(with_values((*record).clone(), &[("message_index", value)]));
+                lap_count += 1;
+            }
+        }
+
+        let mut values = vec![
+            ("message_index", Some(Value::UInt16(0))),
+            ("first_lap_index", Some(Value::UInt16(0))),
+            ("num_laps", Some(Value::UInt16(lap_count))),
+        ];
+        let session = match split_by {
+            SplitBy::Session => part.clone(),
+            SplitBy::Lap => {
+                let part_records: Vec<FitDataRecord> = stream
+                    .iter()
+                    .filter(|(t, r)| r.kind() == MesgNum::Record && in_part(t))
+                    .map(|(_, r)| (*r).clone())
+                    .collect();
+                let summary = SessionSummary::from_records(&part_records);
+                let timer_time = numeric_field(part, "total_timer_time")
+                    .unwrap_or_else(|| summary.total_elapsed_time());
+                values.extend(summary_values(&summary, timer_time));
+                for name in &["start_time", "timestamp", "total_distance"] {
+                    let value = part.fields().iter().find(|f| f.name() == *name);
+                    values.push((name, value.map(|f| f.value().clone())));
+                }
+                sessions
+                    .iter()
+                    .find(|s| {
+                        let start = timestamp_field(s, "start_time");
+                        let end = timestamp_field(s, "timestamp");
+                        start.is_some_and(|t| t <= end_time) && end.is_some_and(|t| end_time <= t)
+                    })
+                    .map_or_else(|| FitDataRecord::new(MesgNum::Session), |s| (*s).clone())
+            }
+        };
+        let session = with_values(session, &values);
+        if let Some(activity) = activity {
+            let timer_time = numeric_field(&session, "total_timer_time").map(Value::Float64);
+            let values = [
+                ("timestamp", Some(Value::Timestamp(end_time))),
+                ("total_timer_time", timer_time),
+                ("num_sessions", Some(Value::UInt16(1))),
+            ];
+            output.push(session);
+            output.push(with_values(activity.clone(), &values));
+        } else {
+            output.push(session);
+        }
+        files.push(output);
+    }
+    files
+}
+
+/// Shift every timestamp of the records by an offset, such as when the clock of the device was
+/// wrong. Local timestamps, e.g. `activity.local_timestamp`, are shifted by the same amount unless
+/// the UTC offset of the time zone the activity was recorded in is given, in which case they are
+/// set from the shifted `timestamp` of their message.
+pub fn shift_timestamps(
+    records: &[FitDataRecord],
+    offset: Duration,
+    utc_offset: Option<Duration>,
+) -> Vec<FitDataRecord> {
+    records
+        .iter()
+        .map(|record| {
+            let kind = record.kind();
+            let timestamp = timestamp_field(record, "timestamp").map(|t| t + offset);
+            let mut shifted = FitDataRecord::new(kind);
+            for field in record.fields() {
+                let value = match (field.value(), timestamp, utc_offset) {
+                    (Value::Timestamp(val), _, _) => Value::Timestamp(*val + offset),
+                    (Value::LocalTimestamp(_), Some(timestamp), Some(utc_offset)) => {
+                        Value::LocalTimestamp(timestamp.naive_utc() + utc_offset)
+                    }
+                    (Value::LocalTimestamp(val), _, _) => Value::LocalTimestamp(*val + offset),
+                    _ => {
+                        shifted.push(field.clone());
+                        continue;
+                    }
+                };
+                shifted.push(match field.developer_data_index() {
+                    Some(index) => FitDataField::new_developer_field(
+                        field.name.clone(),
+                        field.number(),
+                        index,
+                        value,
+                        field.units.clone(),
+                    ),
+                    None => FitDataField::new(
+                        field.name.clone(),
+                        field.number(),
+                        value,
+                        field.units.clone(),
+                    ),
+                });
+            }
+            shifted
+        })
+        .collect()
+}
+
+/// Return the UTC offset of the time zone an activity was recorded in, computed from the
+/// `timestamp` and `local_timestamp` of the `activity` message
+pub fn utc_offset(records: &[FitDataRecord]) -> Option<Duration> {
+    let activity = records.iter().find(|r| r.kind() == MesgNum::Activity)?;
+    let utc = timestamp_field(activity, "timestamp")?.naive_utc();
+    let local = activity
+        .field("local_timestamp")?
+        .value()
+        .as_local_timestamp()?;
+    Some(local - utc)
+}
+
+/// Return the RR intervals in seconds of the `hrv` messages in a FIT file as a single series in
+/// the order they were recorded. Each message holds up to five intervals and unused intervals are
+/// padded with 0xFFFF, the decoder drops the whole array for these messages so the intervals are
+/// read from the message bytes instead and the padding is left out.
+pub fn rr_intervals(data: &[u8]) -> Result<Vec<f64>> {
+    let mut intervals = Vec::new();
+    for message in FitFileView::new(data).messages() {
+        let message = message?;
+        if message.kind() != MesgNum::Hrv {
+            continue;
+        }
+        let field = match message.field(0) {
+            Some(field) if field.base_type() == BaseType::UInt16 => field,
+            _ => continue,
+        };
+        let scale = field.info().map_or(1.0, |info| info.scale());
+        for bytes in field.as_bytes().chunks_exact(2) {
+            let bytes = [bytes[0], bytes[1]];
+            let time = match message.definition().byte_order() {
+                Endianness::Big => u16::from_be_bytes(bytes),
+                _ => u16::from_le_bytes(bytes),
+            };
+            if time != 0xFFFF {
+                intervals.push(f64::from(time) / scale);
+            }
+        }
+    }
+    Ok(intervals)
+}
+
+/// How values missing from a record are filled in when extracting streams
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GapFill {
+    /// Leave the value missing
+    Missing,
+    /// Repeat the previous value of the stream
+    Previous,
+    /// Interpolate between the values before and after the gap using the record timestamps,
+    /// values at the start or end of the stream are left missing
+    Linear,
+}
+
+/// Options used to extract streams from the `record` messages
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StreamOptions {
+    /// How missing values are filled in
+    pub fill: GapFill,
+    /// Longest gap in seconds that is filled, values missing for longer such as while the
+    /// activity was paused are left missing. Gaps of any length are filled when this is None.
+    pub max_gap: Option<f64>,
+}
+
+impl Default for StreamOptions {
+    fn default() -> Self {
+        StreamOptions {
+            fill: GapFill::Missing,
+            max_gap: None,
+        }
+    }
+}
+
+/// Data streams extracted from the `record` messages of an activity, every stream holds one
+/// value per record so the streams can be plotted against each other. Records without a
+/// timestamp are skipped.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Streams {
+    timestamp: Vec<DateTime<TimestampTz>>,
+    time: Vec<f64>,
+    distance: Vec<Option<f64>>,
+    heart_rate: Vec<Option<f64>>,
+    power: Vec<Option<f64>>,
+    cadence: Vec<Option<f64>>,
+    speed: Vec<Option<f64>>,
+    altitude: Vec<Option<f64>>,
+    position: Vec<Option<(f64, f64)>>,
+}
+
+impl Streams {
+    /// Extract the streams from the `record` messages leaving missing values empty
+    pub fn from_records(records: &[FitDataRecord]) -> Self {
+        Self::with_options(records, &StreamOptions::default())
+    }
+
+    /// Extract the streams from the `record` messages filling in missing values as requested.
+    /// The enhanced speed and altitude fields are used in place of the standard fields when
+    /// present.
+    pub fn with_options(records: &[FitDataRecord], options: &StreamOptions) -> Self {
+        let mut streams = Streams::default();
+        let mut latitude = Vec::new();
+        let mut longitude = Vec::new();
+        for record in records.iter().filter(|r| r.kind() == MesgNum::Record) {
+            let timestamp = match timestamp_field(record, "timestamp") {
+                Some(timestamp) => timestamp,
+                None => continue,
+            };
+            let start = *streams.timestamp.first().unwrap_or(&timestamp);
+            streams.timestamp.push(timestamp);
+            streams
+                .time
+                .push((timestamp - start).num_milliseconds() as f64 / 1000.0);
+            streams.distance.push(numeric_field(record, "distance"));
+            streams.heart_rate.push(numeric_field(record, "heart_rate"));
+            streams.power.push(numeric_field(record, "power"));
+            streams.cadence.push(numeric_field(record, "cadence"));
+            streams.speed.push(
+                numeric_field(record, "enhanced_speed").or_else(|| numeric_field(record, "speed")),
+            );
+            streams.altitude.push(
+                numeric_field(record, "enhanced_altitude")
+                    .or_else(|| numeric_field(record, "altitude")),
+            );
+            // both coordinates are needed for a position
+            let lat = semicircles_field(record, "position_lat");
+            let long = semicircles_field(record, "position_long");
+            let both = lat.is_some() && long.is_some();
+            latitude.push(lat.filter(|_| both));
+            longitude.push(long.filter(|_| both));
+        }
+
+        let time = streams.time.clone();
+        for stream in [
+            &mut streams.distance,
+            &mut streams.heart_rate,
+            &mut streams.power,
+            &mut streams.cadence,
+            &mut streams.speed,
+            &mut streams.altitude,
+            &mut latitude,
+            &mut longitude,
+        ] {
+            fill_gaps(stream, &time, options);
+        }
+        streams.position = latitude
+            .into_iter()
+            .zip(longitude)
+            .map(|(lat, long)| lat.zip(long))
+            .collect();
+        streams
+    }
+
+    /// Return the number of values in each stream
+    pub fn len(&self) -> usize {
+        self.timestamp.len()
+    }
+
+    /// Check if no records were extracted
+    pub fn is_empty(&self) -> bool {
+        self.timestamp.is_empty()
+    }
+
+    /// Return the timestamp of each record
+    pub fn timestamp(&self) -> &[DateTime<TimestampTz>] {
+        &self.timestamp
+    }
+
+    /// Return the time of each record in seconds since the first record
+    pub fn time(&self) -> &[f64] {
+        &self.time
+    }
+
+    /// Return the distance in meters
+    pub fn distance(&self) -> &[Option<f64>] {
+        &self.distance
+    }
+
+    /// Return the heart rate in bpm
+    pub fn heart_rate(&self) -> &[Option<f64>] {
+        &self.heart_rate
+    }
+
+    /// Return the power in watts
+    pub fn power(&self) -> &[Option<f64>] {
+        &self.power
+    }
+
+    /// Return the cadence in rpm
+    pub fn cadence(&self) -> &[Option<f64>] {
+        &self.cadence
+    }
+
+    /// Return the speed in m/s
+    pub fn speed(&self) -> &[Option<f64>] {
+        &self.speed
+    }
+
+    /// Return the altitude in meters
+    pub fn altitude(&self) -> &[Option<f64>] {
+        &self.altitude
+    }
+
+    /// Return the latitude and longitude in degrees
+    pub fn position(&self) -> &[Option<(f64, f64)>] {
+        &self.position
+    }
+}
+
+/// Fill in the missing values of a stream, `time` holds the time of each value in seconds
+fn fill_gaps(values: &mut [Option<f64>], time: &[f64], options: &StreamOptions) {
+    let max_gap = options.max_gap.unwrap_or(f64::INFINITY);
+    let mut last: Option<usize> = None;
+    for i in 0..values.len() {
+        match (values[i], last) {
+            (None, Some(prev))
+                if options.fill == GapFill::Previous && time[i] - time[prev] <= max_gap =>
+            {
+                values[i] = values[prev];
+            }
+            (Some(value), Some(prev)) if options.fill == GapFill::Linear => {
+                let (start, elapsed) = (time[prev], time[i] - time[prev]);
+                if i > prev + 1 && elapsed > 0.0 && elapsed <= max_gap {
+                    let from = values[prev].unwrap_or(value);
+                    for j in prev + 1..i {
+                        let ratio = (time[j] - start) / elapsed;
+                        values[j] = Some(from + (value - from) * ratio);
+                    }
+                }
+                last = Some(i);
+            }
+            (Some(_), _) => last = Some(i),
+            _ => {}
+        }
+    }
+}
+
+/// Field values shared by laps and sessions that are set from a summary
+fn summary_values(summary: &SessionSummary, timer_time: f64) -> Vec<(&'static str, Option<Value>)> {
+    let float = |value: Option<f64>| value.map(Value::Float64);
+    vec![
+        (
+            "total_elapsed_time",
+            Some(Value::Float64(summary.total_elapsed_time())),
+        ),
+        ("total_timer_time", Some(Value::Float64(timer_time))),
+        ("avg_heart_rate", float(summary.avg_heart_rate())),
+        ("max_heart_rate", float(summary.max_heart_rate())),
+        ("avg_power", float(summary.avg_power())),
+        ("max_power", float(summary.max_power())),
+        ("avg_speed", float(summary.avg_speed())),
+        ("max_speed", float(summary.max_speed())),
+        ("enhanced_avg_speed", float(summary.avg_speed())),
+        ("enhanced_max_speed", float(summary.max_speed())),
+        ("total_ascent", float(summary.total_ascent())),
+        ("total_descent", float(summary.total_descent())),
+    ]
+}
+
+/// Replace the value of fields in a record, fields that are missing are added using their
+/// profile definition. Values that are None leave the field unchanged.
+fn with_values(record: FitDataRecord, values: &[(&str, Option<Value>)]) -> FitDataRecord {
+    let kind = record.kind();
+    let mut fields = record.into_vec();
+    for (name, value) in values {
+        let value = match value {
+            Some(value) => value.clone(),
+            None => continue,
+        };
+        if let Some(field) = fields.iter_mut().find(|f| f.name() == *name) {
+            *field = FitDataField::new(
+                field.name.clone(),
+                field.number(),
+                value,
+                field.units.clone(),
+            );
+        } else if let Some(info) = kind.field_info().iter().find(|f| f.name() == *name) {
+            fields.push(FitDataField::new(
+                info.name(),
+                info.def_number(),
+                value,
+                info.units(),
+            ));
+        }
+    }
+    let mut record = FitDataRecord::new(kind);
+    record.extend(fields);
+    record
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+    use crate::profile::TimestampField;
+
+    #[test]
+    fn summary_from_records() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+        let summary = SessionSummary::from_records(&records);
+        let session = records
+            .iter()
+            .find(|r| r.kind() == MesgNum::Session)
+            .unwrap();
+        assert_eq!(summary.start_time(), timestamp_field(session, "start_time"));
+        // the session includes the fraction of a second after the last record
+        let elapsed = numeric_field(session, "total_elapsed_time").unwrap();
+        assert!((summary.total_elapsed_time() - elapsed).abs() < 1.0);
+        assert_eq!(
+            summary.total_distance(),
+            numeric_field(session, "total_distance")
+        );
+        assert!(summary.total_moving_time() <= summary.total_elapsed_time());
+        assert!(summary.avg_speed().is_some());
+        assert!(summary.total_ascent().is_some());
+        assert_eq!(summary.avg_power(), None);
+
+        assert_eq!(SessionSummary::from_records(&[]), SessionSummary::default());
+    }
+
+    #[test]
+    fn merge_split_activity() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+        let distance = |r: &FitDataRecord| numeric_field(r, "distance");
+
+        // split after the tenth record, the second part restarts the distance from zero
+        let first = records[..13].to_vec();
+        let offset = distance(&records[12]).unwrap();
+        let mut second = records[..2].to_vec();
+        second.extend(records[13..].iter().map(|r| {
+            let value = distance(r).map(|d| Value::Float64(d - offset));
+            with_values(r.clone(), &[("distance", value)])
+        }));
+
+        let merged = merge_activities(&[second, first]).unwrap();
+        let kinds = |kind: MesgNum| merged.iter().filter(|r| r.kind() == kind).count();
+        assert_eq!(kinds(MesgNum::FileId), 1);
+        assert_eq!(kinds(MesgNum::Record), 14);
+        assert_eq!(kinds(MesgNum::Session), 1);
+        assert_eq!(kinds(MesgNum::Lap), 1);
+        let last = merged
+            .iter()
+            .rfind(|r| r.kind() == MesgNum::Record)
+            .unwrap();
+        assert!((distance(last).unwrap() - 5.73).abs() < 1e-9);
+        let session = merged
+            .iter()
+            .find(|r| r.kind() == MesgNum::Session)
+            .unwrap();
+        assert_eq!(numeric_field(session, "num_laps"), Some(1.0));
+        assert!((numeric_field(session, "total_distance").unwrap() - 5.73).abs() < 1e-9);
+
+        // the merged records can be encoded and decoded again
+        let encoded = crate::encode::to_bytes(&merged).unwrap();
+        assert_eq!(from_bytes(&encoded).unwrap().len(), merged.len());
+        assert!(merge_activities(&[]).is_err());
+    }
+
+    #[test]
+    fn split_by_session_and_lap() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+        let time = |r: &FitDataRecord| timestamp_field(r, "timestamp").map(Value::Timestamp);
+        let count = |records: &[FitDataRecord], kind: MesgNum| {
+            records.iter().filter(|r| r.kind() == kind).count()
+        };
+
+        let files = split_activity(&records, SplitBy::Session);
+        assert_eq!(files.len(), 1);
+        assert_eq!(count(&files[0], MesgNum::Record), 14);
+        assert_eq!(count(&files[0], MesgNum::Lap), 1);
+
+        // replace the lap with two laps divided at the seventh record message
+        let lap = &records[18];
+        let mut split = records[..18].to_vec();
+        split.push(with_values(
+            lap.clone(),
+            &[("timestamp", time(&records[9]))],
+        ));
+        split.push(with_values(
+            lap.clone(),
+            &[("start_time", time(&records[9]))],
+        ));
+        split.extend(records[19..].iter().cloned());
+        let files = split_activity(&split, SplitBy::Lap);
+        assert_eq!(files.len(), 2);
+        assert_eq!(count(&files[0], MesgNum::Record), 7);
+        // the record at the end of the first lap is kept in both files
+        assert_eq!(count(&files[1], MesgNum::Record), 8);
+        for file in &files {
+            assert_eq!(count(file, MesgNum::FileId), 1);
+            assert_eq!(count(file, MesgNum::Lap), 1);
+            let session = file.iter().find(|r| r.kind() == MesgNum::Session).unwrap();
+            assert_eq!(numeric_field(session, "num_laps"), Some(1.0));
+            let encoded = crate::encode::to_bytes(file).unwrap();
+            assert_eq!(from_bytes(&encoded).unwrap().len(), file.len());
+        }
+        let session = files[0]
+            .iter()
+            .find(|r| r.kind() == MesgNum::Session)
+            .unwrap();
+        assert_eq!(
+            timestamp_field(session, "timestamp"),
+            timestamp_field(&records[9], "timestamp")
+        );
+    }
+
+    #[test]
+    fn shift_activity_timestamps() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+        let activity = records
+            .iter()
+            .position(|r| r.kind() == MesgNum::Activity)
+            .unwrap();
+        let local = |r: &FitDataRecord| {
+            r.field("local_timestamp")
+                .and_then(|f| f.value().as_local_timestamp())
+                .map(|t| TimestampField::local_from_wall_clock(&t).as_i64())
+        };
+        let utc = |r: &FitDataRecord| {
+            timestamp_field(r, "timestamp").map(|t| TimestampField::utc_from_date_time(&t).as_i64())
+        };
+
+        assert!(local(&records[activity]).is_some());
+        assert_eq!(utc_offset(&records), Some(Duration::hours(-4)));
+
+        let shifted = shift_timestamps(&records, Duration::hours(1), None);
+        assert_eq!(shifted.len(), records.len());
+        assert_eq!(
+            utc(&shifted[activity]),
+            utc(&records[activity]).map(|t| t + 3600)
+        );
+        assert_eq!(
+            local(&shifted[activity]),
+            local(&records[activity]).map(|t| t + 3600)
+        );
+        assert_eq!(
+            timestamp_field(&shifted[0], "time_created"),
+            timestamp_field(&records[0], "time_created").map(|t| t + Duration::hours(1))
+        );
+
+        let shifted = shift_timestamps(&records, Duration::zero(), Some(Duration::hours(-5)));
+        assert_eq!(utc(&shifted[activity]), utc(&records[activity]));
+        assert_eq!(
+            local(&shifted[activity]),
+            utc(&records[activity]).map(|t| t - 5 * 3600)
+        );
+        let encoded = crate::encode::to_bytes(&shifted).unwrap();
+        assert_eq!(
+            local(&from_bytes(&encoded).unwrap()[activity]),
+            local(&shifted[activity])
+        );
+    }
+
+    #[test]
+    fn hrv_rr_intervals() {
+        let hrv = |times: &[f64]| {
+            let mut record = FitDataRecord::new(MesgNum::Hrv);
+            let times = times.iter().map(|t| Value::Float64(*t)).collect();
+            record.set_field_value("time", Value::Array(times)).unwrap();
+            record
+        };
+        // 65.535 s is stored as 0xFFFF, the padding of unused intervals
+        let records = vec![
+            hrv(&[0.812, 0.804, 0.798, 0.801, 0.82]),
+            hrv(&[0.833, 0.79, 65.535, 65.535, 65.535]),
+            hrv(&[0.785, 65.535, 65.535, 65.535, 65.535]),
+        ];
+        let data = crate::encode::to_bytes(&records).unwrap();
+        assert_eq!(
+            rr_intervals(&data).unwrap(),
+            [0.812, 0.804, 0.798, 0.801, 0.82, 0.833, 0.79, 0.785]
+        );
+        // the decoder drops the padded arrays
+        assert!(from_bytes(&data).unwrap()[1].field("time").is_none());
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        assert!(rr_intervals(data).unwrap().is_empty());
+    }
+
+    #[test]
+    fn extract_streams() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+        let streams = Streams::from_records(&records);
+        assert_eq!(streams.len(), 14);
+        assert_eq!(streams.time()[13], 13.0);
+        assert_eq!(streams.distance()[13], Some(5.73));
+        assert!(streams.heart_rate().iter().all(Option::is_none));
+        let (lat, long) = streams.position()[0].unwrap();
+        assert!((lat - 41.513926).abs() < 1e-6);
+        assert!((long + 73.148591).abs() < 1e-6);
+        assert!(Streams::from_records(&[]).is_empty());
+
+        // remove the distance and position of the fifth and sixth record
+        let mut gaps: Vec<FitDataRecord> = records
+            .iter()
+            .filter(|r| r.kind() == MesgNum::Record)
+            .cloned()
+            .collect();
+        for record in &mut gaps[4..6] {
+            let mut stripped = FitDataRecord::new(MesgNum::Record);
+            stripped.extend(
+                record
+                    .fields()
+                    .iter()
+                    .filter(|f| f.name() != "distance" && f.name() != "position_long")
+                    .cloned()
+                    .collect(),
+            );
+            *record = stripped;
+        }
+        let extract = |fill: GapFill, max_gap: Option<f64>| {
+            Streams::with_options(&gaps, &StreamOptions { fill, max_gap })
+        };
+
+        let streams = extract(GapFill::Missing, None);
+        assert_eq!(
+            streams.distance()[3..7],
+            [Some(0.21), None, None, Some(0.41)]
+        );
+        assert_eq!(streams.position()[4], None);
+        let streams = extract(GapFill::Previous, None);
+        assert_eq!(streams.distance()[4..6], [Some(0.21), Some(0.21)]);
+        assert_eq!(streams.position()[5], streams.position()[3]);
+        let streams = extract(GapFill::Linear, None);
+        let distance = streams.distance()[4].unwrap();
+        assert!((distance - (0.21 + 0.2 / 3.0)).abs() < 1e-9);
+        assert!(streams.position()[4].is_some());
+        // the gap between the fourth and seventh record is three seconds
+        let streams = extract(GapFill::Linear, Some(2.0));
+        assert_eq!(streams.distance()[4], None);
+        let streams = extract(GapFill::Previous, Some(1.0));
+        assert_eq!(streams.distance()[4..6], [Some(0.21), None]);
+    }
+}
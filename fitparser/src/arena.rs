@@ -0,0 +1,214 @@
+//! Store decoded records in a bump arena so all of the data for a parse is freed at once. Batch
+//! jobs parsing many files can reuse a single arena, resetting it between files, instead of
+//! dropping every record, field, string and array individually.
+use crate::compat::prelude::*;
+use crate::compat::HashSet;
+use crate::de::{DecodeOption, FitStreamProcessor};
+use crate::error::Result;
+use crate::profile::MesgNum;
+use crate::{FitDataField, FitDataRecord, SharedStr, Value};
+use bumpalo::collections::Vec as BumpVec;
+pub use bumpalo::Bump;
+
+/// Value of a field stored in an arena. Strings and arrays are allocated in the arena, every
+/// other value is stored inline.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ArenaValue<'a> {
+    /// Value without any heap allocated data, this is never a `Value::String` or `Value::Array`
+    Scalar(Value),
+    /// UTF-8 format string data
+    String(&'a str),
+    /// Array of values
+    Array(&'a [ArenaValue<'a>]),
+}
+
+impl<'a> ArenaValue<'a> {
+    /// Copy a value into the arena
+    fn new_in(value: &Value, arena: &'a Bump) -> Self {
+        match value {
+            Value::String(val) => ArenaValue::String(arena.alloc_str(val)),
+            Value::Array(vals) => {
+                let vals =
+                    BumpVec::from_iter_in(vals.iter().map(|v| Self::new_in(v, arena)), arena);
+                ArenaValue::Array(vals.into_bump_slice())
+            }
+            value => ArenaValue::Scalar(value.clone()),
+        }
+    }
+
+    /// Convert the value into an owned value
+    pub fn to_value(&self) -> Value {
+        match self {
+            ArenaValue::Scalar(val) => val.clone(),
+            ArenaValue::String(val) => Value::String((*val).into()),
+            ArenaValue::Array(vals) => Value::Array(vals.iter().map(Self::to_value).collect()),
+        }
+    }
+}
+
+/// Field of a record stored in an arena
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArenaField<'a> {
+    name: &'a str,
+    number: u8,
+    developer_data_index: Option<u8>,
+    value: ArenaValue<'a>,
+    units: &'a str,
+}
+
+impl<'a> ArenaField<'a> {
+    /// Return the field name as defined in the FIT profile
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Return the field definition number
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// Return the developer data index if this is a developer field
+    pub fn developer_data_index(&self) -> Option<u8> {
+        self.developer_data_index
+    }
+
+    /// Return a reference to the stored value
+    pub fn value(&self) -> &ArenaValue<'a> {
+        &self.value
+    }
+
+    /// Return units associated with the value
+    pub fn units(&self) -> &'a str {
+        self.units
+    }
+}
+
+/// Record stored in an arena, the fields borrow from the arena so they are freed when it is
+/// reset or dropped
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArenaRecord<'a> {
+    kind: MesgNum,
+    fields: &'a [ArenaField<'a>],
+}
+
+impl<'a> ArenaRecord<'a> {
+    /// Copy a decoded record into the arena, names defined by the profile aren't copied
+    pub fn new_in(record: &FitDataRecord, arena: &'a Bump) -> Self {
+        let copy_str = |s: &SharedStr| -> &'a str {
+            match s {
+                SharedStr::Static(s) => s,
+                SharedStr::Shared(s) => arena.alloc_str(s),
+            }
+        };
+        let fields = record.fields().iter().map(|f| ArenaField {
+            name: copy_str(&f.name),
+            number: f.number(),
+            developer_data_index: f.developer_data_index(),
+            value: ArenaValue::new_in(f.value(), arena),
+            units: copy_str(&f.units),
+        });
+        ArenaRecord {
+            kind: record.kind(),
+            fields: BumpVec::from_iter_in(fields, arena).into_bump_slice(),
+        }
+    }
+
+    /// Return the kind of record, this value is defined by the FIT profile
+    pub fn kind(&self) -> MesgNum {
+        self.kind
+    }
+
+    /// Get all fields as a slice
+    pub fn fields(&self) -> &'a [ArenaField<'a>] {
+        self.fields
+    }
+
+    /// Find a field by name
+    pub fn field(&self, name: &str) -> Option<&'a ArenaField<'a>> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+
+    /// Convert the record into an owned record
+    pub fn to_record(&self) -> FitDataRecord {
+        let mut record = FitDataRecord::new(self.kind);
+        record.extend(
+            self.fields
+                .iter()
+                .map(|f| match f.developer_data_index {
+                    Some(index) => FitDataField::new_developer_field(
+                        String::from(f.name),
+                        f.number,
+                        index,
+                        f.value.to_value(),
+                        String::from(f.units),
+                    ),
+                    None => FitDataField::new(
+                        String::from(f.name),
+                        f.number,
+                        f.value.to_value(),
+                        String::from(f.units),
+                    ),
+                })
+                .collect(),
+        );
+        record
+    }
+}
+
+/// Deserialize a FIT file stored as an array of bytes storing the decoded data messages in the
+/// arena, each record is copied into the arena as soon as it is decoded
+pub fn from_bytes_in<'a>(buffer: &[u8], arena: &'a Bump) -> Result<&'a [ArenaRecord<'a>]> {
+    from_bytes_in_with_options(buffer, &HashSet::new(), arena)
+}
+
+/// Deserialize a FIT file stored as an array of bytes with additional decode options storing the
+/// decoded data messages in the arena
+pub fn from_bytes_in_with_options<'a>(
+    buffer: &[u8],
+    options: &HashSet<DecodeOption>,
+    arena: &'a Bump,
+) -> Result<&'a [ArenaRecord<'a>]> {
+    let mut records = BumpVec::new_in(arena);
+    FitStreamProcessor::with_options(options).visit(buffer, &mut |record: FitDataRecord| {
+        records.push(ArenaRecord::new_in(&record, arena));
+        Ok(())
+    })?;
+    Ok(records.into_bump_slice())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+
+    #[test]
+    fn decode_into_arena() {
+        let mut arena = Bump::new();
+        for data in [
+            &include_bytes!("../tests/fixtures/Activity.fit")[..],
+            &include_bytes!("../tests/fixtures/DeveloperData.fit")[..],
+        ] {
+            let expected = from_bytes(data).unwrap();
+            let records = from_bytes_in(data, &arena).unwrap();
+            assert_eq!(records.len(), expected.len());
+            for (record, exp) in records.iter().zip(&expected) {
+                assert_eq!(record.kind(), exp.kind());
+                let owned = record.to_record();
+                for (field, exp) in owned.fields().iter().zip(exp.fields()) {
+                    assert_eq!(field.name(), exp.name());
+                    assert_eq!(field.developer_data_index(), exp.developer_data_index());
+                    assert_eq!(field.value(), exp.value());
+                    assert_eq!(field.units(), exp.units());
+                }
+            }
+            // all of the records are freed at once before parsing the next file
+            arena.reset();
+        }
+
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let records = from_bytes_in(data, &arena).unwrap();
+        let file_id = records[0].field("type").unwrap();
+        assert_eq!(file_id.value(), &ArenaValue::String("activity"));
+        assert!(records[0].field("missing").is_none());
+    }
+}
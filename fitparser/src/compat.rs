@@ -0,0 +1,16 @@
+//! Re-exports of the allocation and collection types used by the crate so the decoder can be
+//! built without the standard library. When the `std` feature is disabled the ordered
+//! collections from `alloc` are used in place of the hashed collections.
+pub use alloc::collections::VecDeque;
+#[cfg(not(feature = "std"))]
+pub use alloc::collections::{BTreeMap as HashMap, BTreeSet as HashSet};
+pub use alloc::sync::Arc;
+#[cfg(feature = "std")]
+pub use std::collections::{HashMap, HashSet};
+
+/// Items normally provided by the standard library prelude
+pub mod prelude {
+    pub use alloc::boxed::Box;
+    pub use alloc::string::{String, ToString};
+    pub use alloc::vec::Vec;
+}
@@ -0,0 +1,278 @@
+//! Build course files from a route. The records contain the `file_id`, `course`, `lap`,
+//! `record` and `course_point` messages along with the timer events and can be encoded using
+//! `to_bytes` to navigate the route on a device.
+use crate::error::{ErrorKind, Result};
+use crate::export::degrees_to_semicircles;
+use crate::profile::field_types::{CoursePoint, File, Manufacturer, Sport};
+use crate::profile::MesgNum;
+use crate::scrub::distance;
+use crate::{FitDataRecord, TimestampTz, Value};
+use chrono::{DateTime, Duration};
+
+/// Speed in m/s used to compute the timestamps of the track points when none is set
+const DEFAULT_SPEED: f64 = 5.0;
+
+/// Position along the route in degrees with an optional altitude in meters
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct TrackPoint {
+    latitude: f64,
+    longitude: f64,
+    altitude: Option<f64>,
+}
+
+/// Cue shown when reaching a position of the route, such as a turn or a summit
+#[derive(Clone, Debug, PartialEq)]
+struct Cue {
+    latitude: f64,
+    longitude: f64,
+    kind: CoursePoint,
+    name: String,
+}
+
+/// Builds the records of a course file. The distance of each track point is measured along the
+/// route and the timestamps assume the route is followed at a constant speed starting at the
+/// start time, devices use these for the virtual partner.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseBuilder {
+    name: String,
+    sport: Sport,
+    start_time: DateTime<TimestampTz>,
+    speed: f64,
+    points: Vec<TrackPoint>,
+    cues: Vec<Cue>,
+}
+
+impl CourseBuilder {
+    /// Create a course without any track points
+    pub fn new(name: &str, sport: Sport, start_time: DateTime<TimestampTz>) -> Self {
+        CourseBuilder {
+            name: String::from(name),
+            sport,
+            start_time,
+            speed: DEFAULT_SPEED,
+            points: Vec::new(),
+            cues: Vec::new(),
+        }
+    }
+
+    /// Set the speed in m/s used to compute the timestamps, the default is 5 m/s
+    pub fn with_speed(mut self, speed: f64) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Add a track point to the end of the route using a position in degrees and an optional
+    /// altitude in meters
+    pub fn point(mut self, latitude: f64, longitude: f64, altitude: Option<f64>) -> Self {
+        self.points.push(TrackPoint {
+            latitude,
+            longitude,
+            altitude,
+        });
+        self
+    }
+
+    /// Add a course point at a position in degrees, its distance and timestamp are taken from
+    /// the closest track point
+    pub fn cue(mut self, latitude: f64, longitude: f64, kind: CoursePoint, name: &str) -> Self {
+        self.cues.push(Cue {
+            latitude,
+            longitude,
+            kind,
+            name: String::from(name),
+        });
+        self
+    }
+
+    /// Return the number of track points
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Check if the route has no track points
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Build the records of the course. An error is returned when the route has less than two
+    /// track points, the speed isn't positive or a value can't be stored in its field.
+    pub fn build(&self) -> Result<Vec<FitDataRecord>> {
+        if self.points.len() < 2 {
+            return Err(ErrorKind::ValueError(String::from(
+                "a course needs at least two track points",
+            ))
+            .into());
+        }
+        if self.speed.is_nan() || self.speed <= 0.0 {
+            return Err(
+                ErrorKind::ValueError(format!("invalid course speed {}", self.speed)).into(),
+            );
+        }
+
+        // distance along the route and time of each track point
+        let mut distances = vec![0.0];
+        for pair in self.points.windows(2) {
+            let last = distances[distances.len() - 1];
+            let from = (pair[0].latitude, pair[0].longitude);
+            distances.push(last + distance(from, (pair[1].latitude, pair[1].longitude)));
+        }
+        let time_at = |distance: f64| {
+            let millis = (distance / self.speed * 1000.0) as i64;
+            Value::Timestamp(self.start_time + Duration::milliseconds(millis))
+        };
+        let total_distance = distances[distances.len() - 1];
+        let end_time = time_at(total_distance);
+        let (first, last) = (&self.points[0], &self.points[self.points.len() - 1]);
+
+        let mut file_id = FitDataRecord::new(MesgNum::FileId);
+        file_id.set_field_value("type", enum_value(File::Course))?;
+        file_id.set_field_value("manufacturer", enum_value(Manufacturer::Development))?;
+        file_id.set_field_value("product", Value::UInt16(0))?;
+        file_id.set_field_value("time_created", Value::Timestamp(self.start_time))?;
+
+        let mut course = FitDataRecord::new(MesgNum::Course);
+        course.set_field_value("name", Value::String(self.name.clone()))?;
+        course.set_field_value("sport", enum_value(self.sport))?;
+
+        let mut lap = FitDataRecord::new(MesgNum::Lap);
+        lap.set_field_value("timestamp", end_time.clone())?;
+        lap.set_field_value("start_time", Value::Timestamp(self.start_time))?;
+        set_position(&mut lap, "start_position", first.latitude, first.longitude)?;
+        set_position(&mut lap, "end_position", last.latitude, last.longitude)?;
+        let elapsed = total_distance / self.speed;
+        lap.set_field_value("total_elapsed_time", Value::Float64(elapsed))?;
+        lap.set_field_value("total_timer_time", Value::Float64(elapsed))?;
+        lap.set_field_value("total_distance", Value::Float64(total_distance))?;
+
+        let mut records = vec![file_id, course, lap];
+        records.push(timer_event(Value::Timestamp(self.start_time), "start")?);
+        for (point, distance) in self.points.iter().zip(&distances) {
+            let mut record = FitDataRecord::new(MesgNum::Record);
+            record.set_field_value("timestamp", time_at(*distance))?;
+            set_position(&mut record, "position", point.latitude, point.longitude)?;
+            record.set_field_value("distance", Value::Float64(*distance))?;
+            if let Some(altitude) = point.altitude {
+                record.set_field_value("altitude", Value::Float64(altitude))?;
+            }
+            records.push(record);
+        }
+        for (index, cue) in self.cues.iter().enumerate() {
+            let position = (cue.latitude, cue.longitude);
+            let closest = self
+                .points
+                .iter()
+                .map(|p| distance(position, (p.latitude, p.longitude)))
+                .enumerate()
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map_or(0, |(i, _)| i);
+            let mut record = FitDataRecord::new(MesgNum::CoursePoint);
+            record.set_field_value("message_index", Value::UInt16(index as u16))?;
+            record.set_field_value("timestamp", time_at(distances[closest]))?;
+            set_position(&mut record, "position", cue.latitude, cue.longitude)?;
+            record.set_field_value("distance", Value::Float64(distances[closest]))?;
+            record.set_field_value("type", enum_value(cue.kind))?;
+            record.set_field_value("name", Value::String(cue.name.clone()))?;
+            records.push(record);
+        }
+        records.push(timer_event(end_time, "stop_disable_all")?);
+        Ok(records)
+    }
+
+    /// Build the records and encode them into a FIT file
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        crate::encode::to_bytes(&self.build()?)
+    }
+}
+
+/// Convert an enum into the variant name used by the decoder
+fn enum_value<T: ToString>(value: T) -> Value {
+    Value::String(value.to_string())
+}
+
+/// Set the latitude and longitude fields starting with the prefix from a position in degrees
+fn set_position(
+    record: &mut FitDataRecord,
+    prefix: &str,
+    latitude: f64,
+    longitude: f64,
+) -> Result<()> {
+    let latitude = Value::SInt32(degrees_to_semicircles(latitude));
+    let longitude = Value::SInt32(degrees_to_semicircles(longitude));
+    record.set_field_value(&format!("{}_lat", prefix), latitude)?;
+    record.set_field_value(&format!("{}_long", prefix), longitude)
+}
+
+/// Create a timer event starting or stopping the course
+fn timer_event(timestamp: Value, event_type: &str) -> Result<FitDataRecord> {
+    let mut event = FitDataRecord::new(MesgNum::Event);
+    event.set_field_value("timestamp", timestamp)?;
+    event.set_field_value("event", Value::String(String::from("timer")))?;
+    event.set_field_value("event_type", Value::String(String::from(event_type)))?;
+    Ok(event)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+    use crate::export::numeric_field;
+    use chrono::TimeZone;
+
+    #[test]
+    fn build_course() {
+        let start = TimestampTz.timestamp_opt(1_600_000_000, 0).unwrap();
+        let data = CourseBuilder::new("Loop", Sport::Cycling, start)
+            .with_speed(10.0)
+            .point(59.9, 10.7, Some(12.0))
+            .point(59.901, 10.7, None)
+            .point(59.901, 10.702, Some(20.0))
+            .cue(59.9011, 10.7001, CoursePoint::Right, "Turn right")
+            .to_bytes()
+            .unwrap();
+
+        let records = from_bytes(&data).unwrap();
+        let kinds: Vec<MesgNum> = records.iter().map(FitDataRecord::kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                MesgNum::FileId,
+                MesgNum::Course,
+                MesgNum::Lap,
+                MesgNum::Event,
+                MesgNum::Record,
+                MesgNum::Record,
+                MesgNum::Record,
+                MesgNum::CoursePoint,
+                MesgNum::Event,
+            ]
+        );
+        let value =
+            |record: &FitDataRecord, name: &str| record.field(name).unwrap().value().clone();
+        assert_eq!(value(&records[0], "type"), Value::String("course".into()));
+        assert_eq!(value(&records[1], "name"), Value::String("Loop".into()));
+
+        // the first leg is about 111 meters north and the second about 111 meters east
+        let distance = numeric_field(&records[5], "distance").unwrap();
+        assert!((distance - 111.2).abs() < 0.5);
+        let total = numeric_field(&records[2], "total_distance").unwrap();
+        assert!((total - 222.7).abs() < 0.5);
+        assert_eq!(
+            value(&records[5], "timestamp"),
+            Value::Timestamp(start + Duration::seconds(11))
+        );
+        // the altitude is expanded into the enhanced altitude when decoding
+        assert_eq!(
+            value(&records[4], "enhanced_altitude"),
+            Value::Float64(12.0)
+        );
+        assert!(records[5].field("enhanced_altitude").is_none());
+
+        let cue = &records[7];
+        assert_eq!(value(cue, "type"), Value::String("right".into()));
+        assert_eq!(value(cue, "name"), Value::String("Turn right".into()));
+        assert_eq!(numeric_field(cue, "distance"), Some(distance));
+
+        let short = CourseBuilder::new("Short", Sport::Running, start).point(59.9, 10.7, None);
+        assert!(short.build().is_err());
+    }
+}
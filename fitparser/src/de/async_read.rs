@@ -0,0 +1,149 @@
+//! Deserialize FIT data from a source that implements tokio's AsyncRead, this module requires
+//! the `async` feature.
+use super::{DecodeOption, RecordBuffer};
+use crate::error::{ErrorKind, Result};
+use crate::profile::MesgNum;
+use crate::FitDataRecord;
+use futures_core::Stream;
+use std::collections::HashSet;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, ReadBuf};
+
+/// Stream of the decoded data messages of one or more FIT files stored in a source that
+/// implements AsyncRead. Data is read from the source as needed so only the message being
+/// parsed is held in memory.
+pub struct FitRecordStream<T: AsyncRead + Unpin> {
+    source: T,
+    buffer: RecordBuffer,
+    done: bool,
+}
+
+impl<T: AsyncRead + Unpin> FitRecordStream<T> {
+    /// Create a stream of the records in the source
+    pub fn new(source: T) -> Self {
+        Self::with_options(source, &HashSet::new())
+    }
+
+    /// Create a stream of the records in the source with additional decode options
+    pub fn with_options(source: T, options: &HashSet<DecodeOption>) -> Self {
+        FitRecordStream {
+            source,
+            buffer: RecordBuffer::with_options(options),
+            done: false,
+        }
+    }
+
+    /// Only return the data messages matching the provided kinds, the fields of all other data
+    /// messages are skipped without being decoded.
+    pub fn set_message_filter(&mut self, kinds: &[MesgNum]) {
+        self.buffer.processor.set_message_filter(kinds);
+    }
+
+    /// Decode the next data record, None is returned once the source is exhausted
+    pub async fn next_record(&mut self) -> Option<Result<FitDataRecord>> {
+        std::future::poll_fn(|cx| Pin::new(&mut *self).poll_next(cx)).await
+    }
+}
+
+impl<T: AsyncRead + Unpin> Stream for FitRecordStream<T> {
+    type Item = Result<FitDataRecord>;
+
+    /// Decode the next data record, the stream ends after the first error is returned
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+        loop {
+            let err = match this.buffer.next_record() {
+                Ok(rec) => return Poll::Ready(Some(Ok(rec))),
+                Err(e) => match *e {
+                    ErrorKind::UnexpectedEof(..) => e,
+                    _ => {
+                        this.done = true;
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                },
+            };
+
+            // read more data from the source
+            let is_empty = this.buffer.is_empty();
+            let mut read_buf = ReadBuf::new(this.buffer.unfilled());
+            let nbytes = match Pin::new(&mut this.source).poll_read(cx, &mut read_buf) {
+                Poll::Ready(Ok(())) => read_buf.filled().len(),
+                Poll::Ready(Err(e)) => {
+                    this.buffer.commit(0);
+                    this.done = true;
+                    return Poll::Ready(Some(Err(e.into())));
+                }
+                Poll::Pending => {
+                    this.buffer.commit(0);
+                    return Poll::Pending;
+                }
+            };
+            this.buffer.commit(nbytes);
+            if nbytes == 0 {
+                this.done = true;
+                return if is_empty {
+                    Poll::Ready(None)
+                } else {
+                    Poll::Ready(Some(Err(err)))
+                };
+            }
+        }
+    }
+}
+
+/// Deserialize a FIT file stored in a source that implements AsyncRead, with additional
+/// decode options
+pub async fn parse_async_with_options<T: AsyncRead + Unpin>(
+    source: &mut T,
+    options: &HashSet<DecodeOption>,
+) -> Result<Vec<FitDataRecord>> {
+    let mut stream = FitRecordStream::with_options(source, options);
+    let mut records = Vec::new();
+    while let Some(rec) = stream.next_record().await {
+        records.push(rec?);
+    }
+    Ok(records)
+}
+
+/// Deserialize a FIT file stored in a source that implements AsyncRead.
+pub async fn parse_async<T: AsyncRead + Unpin>(source: &mut T) -> Result<Vec<FitDataRecord>> {
+    parse_async_with_options(source, &HashSet::new()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+
+    #[tokio::test]
+    async fn parse_async_activity() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let mut source = &data[..];
+        let records = parse_async(&mut source).await.unwrap();
+        assert_eq!(records.len(), 22);
+    }
+
+    #[tokio::test]
+    async fn stream_matches_from_bytes() {
+        let data = include_bytes!("../../tests/fixtures/sample_mulitple_header.fit");
+        let expected = from_bytes(data).unwrap();
+        let mut stream = FitRecordStream::new(&data[..]);
+        let mut count = 0;
+        while let Some(rec) = stream.next_record().await {
+            assert_eq!(rec.unwrap().kind(), expected[count].kind());
+            count += 1;
+        }
+        assert_eq!(count, expected.len());
+    }
+
+    #[tokio::test]
+    async fn stream_truncated_file_errors() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let mut source = &data[..data.len() - 10];
+        assert!(parse_async(&mut source).await.is_err());
+    }
+}
@@ -1,34 +1,167 @@
 //! Helper functions and structures needed to decode a FIT file using the defined profile.
-use super::parser::FitDataMessage;
-use super::DecodeOption;
+use super::developer::DeveloperFieldRegistry;
+use super::parser::{BaseType, FitDataMessage};
+use super::{units, DecodeOption};
+use crate::compat::prelude::*;
+use crate::compat::{Arc, HashMap, HashSet};
 use crate::error::Result;
-use crate::profile::{MesgNum, TimestampField};
-use crate::{FitDataField, FitDataRecord, Value};
-use std::collections::{HashMap, HashSet};
-use std::convert::{From, TryInto};
+use crate::profile::{self, CustomProfile, FieldDataType, MesgNum, TimestampField};
+use crate::{FitDataField, FitDataRecord, SharedStr, Value};
+use core::convert::{From, TryInto};
+
+/// Fields storing the lower bits of a timestamp relative to the last full timestamp, along with
+/// the number of bits stored and the number of seconds per unit
+const PARTIAL_TIMESTAMPS: [(&str, u32, i64); 2] =
+    [("timestamp_16", 16, 1), ("timestamp_min_8", 8, 60)];
+
+/// Check if a field stores a full or partial timestamp, these are parsed for messages skipped by
+/// the message filter since they advance the reference timestamp of the messages after them
+pub(crate) fn is_timestamp_field(mesg_num: MesgNum, number: u8) -> bool {
+    number == 253
+        || mesg_num.field_info().iter().any(|f| {
+            f.def_number() == number
+                && !f.is_subfield()
+                && PARTIAL_TIMESTAMPS
+                    .iter()
+                    .any(|(name, ..)| f.name() == *name)
+        })
+}
+
+/// Field descriptions keyed by the developer data index and field number
+pub type DeveloperFields = HashMap<(u8, u8), DeveloperFieldDescription>;
+
+/// Information from a field description message needed to decode a developer field
+#[derive(Clone, Debug)]
+pub struct DeveloperFieldDescription {
+    name: SharedStr,
+    base_type: BaseType,
+    field_type: FieldDataType,
+    scale: f64,
+    offset: f64,
+    units: SharedStr,
+    application_id: Option<[u8; 16]>,
+}
+
+impl DeveloperFieldDescription {
+    /// Create the description from the raw fields of a field description message, the units and
+    /// field type of the native field are used when the message refers to one.
+    pub(crate) fn from_fields(
+        fields: &HashMap<u8, Value>,
+        application_ids: &HashMap<u8, [u8; 16]>,
+    ) -> Option<((u8, u8), Self)> {
+        let number =
+            |n: u8| -> Option<i64> { fields.get(&n).and_then(|v| v.clone().try_into().ok()) };
+        let string = |n: u8| match fields.get(&n) {
+            Some(Value::String(val)) => Some(val.clone()),
+            Some(Value::Array(vals)) => vals.iter().find_map(|v| match v {
+                Value::String(val) => Some(val.clone()),
+                _ => None,
+            }),
+            _ => None,
+        };
+        let developer_data_index = number(0)? as u8;
+        let field_number = number(1)? as u8;
+        let base_type = BaseType::from(number(2)? as u8);
+        let native = match (number(14), number(15)) {
+            (Some(mesg_num), Some(field_num)) => MesgNum::from(mesg_num as u16)
+                .field_info()
+                .iter()
+                .find(|f| f.def_number() == field_num as u8 && !f.is_subfield()),
+            _ => None,
+        };
+        let description = DeveloperFieldDescription {
+            name: SharedStr::from(string(3).unwrap_or_else(|| {
+                format!("developer_field_{}_{}", developer_data_index, field_number)
+            })),
+            base_type,
+            field_type: native.map_or(base_field_type(base_type), |f| f.field_type()),
+            scale: number(6).map_or(1.0, |v| v as f64),
+            offset: number(7).map_or(0.0, |v| v as f64),
+            units: string(8)
+                .map(SharedStr::from)
+                .or_else(|| native.map(|f| SharedStr::from(f.units())))
+                .unwrap_or_default(),
+            application_id: application_ids.get(&developer_data_index).copied(),
+        };
+        Some(((developer_data_index, field_number), description))
+    }
+
+    /// Base type the field value is stored as
+    pub(crate) fn base_type(&self) -> BaseType {
+        self.base_type
+    }
+
+    /// Field type used to convert the raw value
+    pub(crate) fn field_type(&self) -> FieldDataType {
+        self.field_type
+    }
+
+    /// Scale applied to the raw value
+    pub(crate) fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Offset applied to the raw value
+    pub(crate) fn offset(&self) -> f64 {
+        self.offset
+    }
+}
+
+/// Field type used to convert a developer field value without a native field
+fn base_field_type(base_type: BaseType) -> FieldDataType {
+    match base_type {
+        BaseType::Enum => FieldDataType::UInt8,
+        BaseType::SInt8 => FieldDataType::SInt8,
+        BaseType::UInt8 => FieldDataType::UInt8,
+        BaseType::SInt16 => FieldDataType::SInt16,
+        BaseType::UInt16 => FieldDataType::UInt16,
+        BaseType::SInt32 => FieldDataType::SInt32,
+        BaseType::UInt32 => FieldDataType::UInt32,
+        BaseType::String => FieldDataType::String,
+        BaseType::Float32 => FieldDataType::Float32,
+        BaseType::Float64 => FieldDataType::Float64,
+        BaseType::UInt8z => FieldDataType::UInt8z,
+        BaseType::UInt16z => FieldDataType::UInt16z,
+        BaseType::UInt32z => FieldDataType::UInt32z,
+        BaseType::Byte => FieldDataType::Byte,
+        BaseType::SInt64 => FieldDataType::SInt64,
+        BaseType::UInt64 => FieldDataType::UInt64,
+        BaseType::UInt64z => FieldDataType::UInt64z,
+    }
+}
 
 /// Decodes a raw FitDataMessage using the defined profile. Additional logic is used to handle
 /// values that need to accumlate across multiple messages as well as applying the
 /// time offset to the current base timestamp.
 /// time offset to the current base timestamp.
 pub struct Decoder {
-    base_timestamp: TimestampField,
+    base_timestamp: Option<TimestampField>,
     accumulate_fields: HashMap<u32, Value>,
+    developer_fields: Arc<DeveloperFields>,
+    application_ids: HashMap<u8, [u8; 16]>,
+    profile: Option<Arc<CustomProfile>>,
+    developer_decoders: Option<Arc<DeveloperFieldRegistry>>,
 }
 
 impl Decoder {
     /// Create a new decoder
     pub fn new() -> Self {
         Decoder {
-            base_timestamp: TimestampField::Utc(0),
+            base_timestamp: None,
             accumulate_fields: HashMap::new(),
+            developer_fields: Arc::new(HashMap::new()),
+            application_ids: HashMap::new(),
+            profile: None,
+            developer_decoders: None,
         }
     }
 
-    /// Reset accumation related fields
+    /// Reset accumation related fields and the developer field descriptions
     pub fn reset(&mut self) {
-        self.base_timestamp = TimestampField::Utc(0);
+        self.base_timestamp = None;
         self.accumulate_fields = HashMap::new();
+        self.developer_fields = Arc::new(HashMap::new());
+        self.application_ids = HashMap::new();
     }
 
     /// Decode a raw FIT data message by applying the defined profile
@@ -43,51 +176,273 @@ impl Decoder {
         // check if we have a real timestamp field to set the reference
         // field id 253 always appears to be a timestamp with the type
         // FieldDataType::DateTime
-        if let Some(value) = message.fields().get(&253) {
-            self.base_timestamp = TimestampField::Utc(value.clone().try_into().unwrap_or(0));
-        }
+        self.set_reference_timestamp(&message);
+        self.update_developer_fields(&message);
+        let partial_timestamp = self.resolve_partial_timestamp(&message);
 
-        // process raw data
-        let mut fields =
-            mesg_num.decode_message(message.fields_mut(), &mut self.accumulate_fields, options)?;
+        // process raw data, fields defined by a custom profile take priority
+        let mut fields = match &self.profile {
+            Some(profile) => profile.decode_fields(
+                message.global_message_number(),
+                message.fields_mut(),
+                options,
+            )?,
+            None => Vec::new(),
+        };
+        if self.profile.is_some() && options.contains(&DecodeOption::UseCustomProfileOnly) {
+            if !options.contains(&DecodeOption::DropUnknownFields) {
+                fields.extend(
+                    core::mem::take(message.fields_mut())
+                        .into_iter()
+                        .map(|(num, value)| profile::unknown_field(num, value)),
+                );
+            }
+        } else {
+            fields.extend(mesg_num.decode_message(
+                message.fields_mut(),
+                &mut self.accumulate_fields,
+                options,
+            )?);
+        }
         fields.sort_by_key(|f| f.number());
+        if !MesgNum::is_named_variant(mesg_num.as_i64()) {
+            log::debug!("message {} isn't defined in the profile", mesg_num.as_u16());
+        } else {
+            for field in fields
+                .iter()
+                .filter(|f| f.name().starts_with("unknown_field_"))
+            {
+                log::debug!(
+                    "field {} of {} messages isn't defined in the profile",
+                    field.number(),
+                    mesg_num
+                );
+            }
+        }
         record.extend(fields);
 
-        // Add a timestamp field if we have a time offset
+        // Add a timestamp field if we have a time offset, compressed timestamps can only be
+        // expanded once a message containing a full timestamp has been seen
+        if let Some(timestamp) = message.time_offset().and_then(|t| self.update_timestamp(t)) {
+            let value = if options.contains(&DecodeOption::ReturnRawValues) {
+                Value::UInt32(timestamp.as_i64() as u32)
+            } else {
+                Value::from(timestamp)
+            };
+            record.push(FitDataField::new("timestamp", 253, value, "s"));
+        }
+
+        // Add a timestamp field for messages only storing the lower bits of the timestamp, such
+        // as monitoring messages, relative to the last full timestamp
+        if let Some(timestamp) = partial_timestamp.filter(|_| record.field("timestamp").is_none()) {
+            let value = if options.contains(&DecodeOption::ReturnRawValues) {
+                Value::UInt32(timestamp.as_i64() as u32)
+            } else {
+                Value::from(timestamp)
+            };
+            record.push(FitDataField::new("timestamp", 253, value, "s"));
+        }
+
+        // process developer fields using the field descriptions seen so far
+        for field in message.developer_fields() {
+            let definition = field.definition();
+            let key = (definition.developer_data_index(), definition.field_number());
+            let description = match self.developer_fields.get(&key) {
+                Some(description) => description,
+                None => {
+                    log::debug!(
+                        "skipping field {} of developer {} without a field description",
+                        key.1,
+                        key.0
+                    );
+                    continue;
+                }
+            };
+            if let Some(value) = field.value(description.base_type) {
+                let field = profile::data_field_with_info(
+                    definition.field_number(),
+                    description.name.clone(),
+                    description.field_type,
+                    description.scale,
+                    description.offset,
+                    description.units.clone(),
+                    value,
+                    options,
+                )?;
+                let field = FitDataField::new_developer_field(
+                    field.name.clone(),
+                    field.number(),
+                    definition.developer_data_index(),
+                    field.into_value(),
+                    description.units.clone(),
+                );
+                // registered decoders replace the generic field unless raw values are requested
+                let decoded = self
+                    .developer_decoders
+                    .as_ref()
+                    .filter(|_| !options.contains(&DecodeOption::ReturnRawValues))
+                    .and_then(|r| r.find(description.application_id.as_ref(), key.0))
+                    .and_then(|d| d.decode(&field));
+                match decoded {
+                    Some(fields) => record.extend(fields),
+                    None => record.push(field),
+                }
+            }
+        }
+
+        // convert the values from the metric units and semicircles used by the profile if requested
+        let units = options.iter().find_map(|opt| match opt {
+            DecodeOption::ConvertUnits(units) => Some(*units),
+            _ => None,
+        });
+        let semicircles = options.contains(&DecodeOption::ConvertSemicircles);
+        if options.contains(&DecodeOption::ReturnRawValues) || (units.is_none() && !semicircles) {
+            return Ok(record);
+        }
+        units::convert_record(record, units.unwrap_or(units::Units::Metric), semicircles)
+    }
+
+    /// Set the custom profile used to decode messages before applying the bundled profile
+    pub fn set_profile(&mut self, profile: Option<Arc<CustomProfile>>) {
+        self.profile = profile;
+    }
+
+    /// Set the decoders used for the developer fields of known applications
+    pub fn set_developer_decoders(&mut self, decoders: Option<Arc<DeveloperFieldRegistry>>) {
+        self.developer_decoders = decoders;
+    }
+
+    /// Fetch the developer field descriptions used to decode developer fields
+    pub fn developer_fields(&self) -> Arc<DeveloperFields> {
+        Arc::clone(&self.developer_fields)
+    }
+
+    /// Set the developer field descriptions used to decode developer fields
+    pub fn set_developer_fields(&mut self, developer_fields: Arc<DeveloperFields>) {
+        self.developer_fields = developer_fields;
+    }
+
+    /// Store the field description from a field description message, a developer data ID
+    /// message removes all descriptions previously defined for its developer data index.
+    fn update_developer_fields(&mut self, message: &FitDataMessage) {
+        let mesg_num = MesgNum::from(message.global_message_number());
+        if mesg_num == MesgNum::FieldDescription {
+            if let Some((key, description)) =
+                DeveloperFieldDescription::from_fields(message.fields(), &self.application_ids)
+            {
+                Arc::make_mut(&mut self.developer_fields).insert(key, description);
+            }
+        } else if mesg_num == MesgNum::DeveloperDataId {
+            let index: Option<i64> = message
+                .fields()
+                .get(&3)
+                .and_then(|v| v.clone().try_into().ok());
+            if let Some(index) = index {
+                // the application ID is stored as an array of bytes
+                let application_id = match message.fields().get(&1) {
+                    Some(Value::Array(vals)) if vals.len() == 16 => {
+                        let mut application_id = [0; 16];
+                        for (byte, val) in application_id.iter_mut().zip(vals) {
+                            *byte = val.clone().try_into().map_or(0, |v: i64| v as u8);
+                        }
+                        Some(application_id)
+                    }
+                    _ => None,
+                };
+                match application_id {
+                    Some(id) => self.application_ids.insert(index as u8, id),
+                    None => self.application_ids.remove(&(index as u8)),
+                };
+                if self.developer_fields.keys().any(|k| k.0 == index as u8) {
+                    Arc::make_mut(&mut self.developer_fields).retain(|k, _| k.0 != index as u8);
+                }
+            }
+        }
+    }
+
+    /// Fetch the timestamp used as the reference for compressed timestamp headers
+    pub fn base_timestamp(&self) -> Option<TimestampField> {
+        self.base_timestamp
+    }
+
+    /// Set the timestamp used as the reference for compressed timestamp headers
+    pub fn set_base_timestamp(&mut self, timestamp: Option<TimestampField>) {
+        self.base_timestamp = timestamp;
+    }
+
+    /// Advance the reference timestamp and developer field descriptions using a message without
+    /// decoding its fields
+    pub fn track_message(&mut self, message: &FitDataMessage) {
+        self.set_reference_timestamp(message);
+        self.update_developer_fields(message);
+        self.resolve_partial_timestamp(message);
         if let Some(time_offset) = message.time_offset() {
-            record.push(FitDataField::new(
-                String::from("timestamp"),
-                253,
-                self.update_timestamp(time_offset),
-                String::new(),
-            ));
+            self.update_timestamp(time_offset);
         }
+    }
 
-        // TODO: process developer fields
+    /// Use the timestamp field of the message as the reference if it is present
+    fn set_reference_timestamp(&mut self, message: &FitDataMessage) {
+        if let Some(value) = message.fields().get(&253) {
+            self.base_timestamp = Some(TimestampField::Utc(value.clone().try_into().unwrap_or(0)));
+        }
+    }
 
-        Ok(record)
+    /// Resolve the `timestamp_16` or `timestamp_min_8` field of a message without a full
+    /// timestamp into an absolute timestamp using the lower bits of the reference timestamp, the
+    /// reference is advanced to the resolved timestamp. Nothing is returned if there is no
+    /// reference timestamp.
+    fn resolve_partial_timestamp(&mut self, message: &FitDataMessage) -> Option<TimestampField> {
+        if message.fields().contains_key(&253) {
+            return None;
+        }
+        let mesg_num = MesgNum::from(message.global_message_number());
+        let (offset, bits, seconds) =
+            PARTIAL_TIMESTAMPS.iter().find_map(|(name, bits, secs)| {
+                let info = mesg_num
+                    .field_info()
+                    .iter()
+                    .find(|f| f.name() == *name && !f.is_subfield())?;
+                let offset: i64 = message
+                    .fields()
+                    .get(&info.def_number())?
+                    .clone()
+                    .try_into()
+                    .ok()?;
+                Some((offset, *bits, *secs))
+            })?;
+        let base_timestamp = self.base_timestamp?;
+        // the difference wraps around when the lower bits roll over
+        let base = base_timestamp.as_i64() / seconds;
+        let value = (base + ((offset - base) & ((1 << bits) - 1))) * seconds;
+        let wrap = |value| match base_timestamp {
+            TimestampField::Local(_) => TimestampField::Local(value),
+            TimestampField::Utc(_) => TimestampField::Utc(value),
+        };
+        // timestamps in minutes can be earlier than the reference within the same minute
+        self.base_timestamp = Some(wrap(value.max(base_timestamp.as_i64())));
+        Some(wrap(value))
     }
 
-    /// Update the timestamp with a new offset and return the value
-    fn update_timestamp(&mut self, offset: u8) -> Value {
+    /// Update the timestamp with a new offset and return it, nothing is returned if there is no
+    /// reference timestamp to apply the offset to
+    fn update_timestamp(&mut self, offset: u8) -> Option<TimestampField> {
+        let base_timestamp = self.base_timestamp?;
         let offset: i64 = offset as i64;
         let mask: i64 = 31; // last 5 significant bits of value
-        let mut value = offset + (self.base_timestamp.as_i64() & !mask);
+        let mut value = offset + (base_timestamp.as_i64() & !mask);
         // account for rollover if needed
-        if offset < (self.base_timestamp.as_i64() & mask) {
+        if offset < (base_timestamp.as_i64() & mask) {
             value += 32;
         }
 
         // update stored value and return
-        match self.base_timestamp {
-            TimestampField::Local(_) => {
-                self.base_timestamp = TimestampField::Local(value);
-            }
-            TimestampField::Utc(_) => {
-                self.base_timestamp = TimestampField::Utc(value);
-            }
-        }
+        let timestamp = match base_timestamp {
+            TimestampField::Local(_) => TimestampField::Local(value),
+            TimestampField::Utc(_) => TimestampField::Utc(value),
+        };
+        self.base_timestamp = Some(timestamp);
 
-        Value::from(self.base_timestamp)
+        Some(timestamp)
     }
 }
@@ -0,0 +1,165 @@
+//! Decode developer fields of known applications, such as Connect IQ data fields, into richer
+//! values than the generic decode of their base type. Decoders are registered using the
+//! application ID of the `developer_data_id` message or the developer data index.
+use crate::compat::prelude::*;
+use crate::compat::{Arc, HashMap};
+use crate::error::{ErrorKind, Result};
+use crate::FitDataField;
+use core::fmt;
+
+/// Decodes the developer fields of an application. The field is decoded using its field
+/// description first and the decoder returns the fields to store in its place, e.g. a packed
+/// value can be unpacked into several fields. Returning None keeps the generic field.
+pub trait DeveloperFieldDecoder: Send + Sync {
+    /// Decode a developer field
+    fn decode(&self, field: &FitDataField) -> Option<Vec<FitDataField>>;
+}
+
+impl<F> DeveloperFieldDecoder for F
+where
+    F: Fn(&FitDataField) -> Option<Vec<FitDataField>> + Send + Sync,
+{
+    fn decode(&self, field: &FitDataField) -> Option<Vec<FitDataField>> {
+        self(field)
+    }
+}
+
+/// Decoders used for the developer fields of a FIT file, a decoder registered for the
+/// application ID takes priority over one registered for the developer data index.
+#[derive(Clone, Default)]
+pub struct DeveloperFieldRegistry {
+    applications: HashMap<[u8; 16], Arc<dyn DeveloperFieldDecoder>>,
+    indexes: HashMap<u8, Arc<dyn DeveloperFieldDecoder>>,
+}
+
+impl DeveloperFieldRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a decoder for the fields of an application ID
+    pub fn register_application<D: DeveloperFieldDecoder + 'static>(
+        &mut self,
+        application_id: [u8; 16],
+        decoder: D,
+    ) {
+        self.applications.insert(application_id, Arc::new(decoder));
+    }
+
+    /// Register a decoder for the fields of a developer data index, this is useful for files
+    /// that don't store an application ID
+    pub fn register_index<D: DeveloperFieldDecoder + 'static>(&mut self, index: u8, decoder: D) {
+        self.indexes.insert(index, Arc::new(decoder));
+    }
+
+    /// Check if no decoders are registered
+    pub fn is_empty(&self) -> bool {
+        self.applications.is_empty() && self.indexes.is_empty()
+    }
+
+    /// Find the decoder for a developer field using the application ID of its developer
+    pub(crate) fn find(
+        &self,
+        application_id: Option<&[u8; 16]>,
+        index: u8,
+    ) -> Option<&dyn DeveloperFieldDecoder> {
+        application_id
+            .and_then(|id| self.applications.get(id))
+            .or_else(|| self.indexes.get(&index))
+            .map(|d| d.as_ref())
+    }
+}
+
+impl fmt::Debug for DeveloperFieldRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeveloperFieldRegistry")
+            .field(
+                "applications",
+                &self.applications.keys().collect::<Vec<_>>(),
+            )
+            .field("indexes", &self.indexes.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Parse an application ID written as a UUID, e.g. `a26e5358-7526-4582-af6f-b7d2e4b1ba9a`, into
+/// the bytes stored by the `developer_data_id` message
+pub fn parse_application_id(uuid: &str) -> Result<[u8; 16]> {
+    let invalid = || ErrorKind::ValueError(format!("invalid application ID {}", uuid));
+    let digits: Vec<char> = uuid.chars().filter(|c| *c != '-').collect();
+    if digits.len() != 32 {
+        return Err(invalid().into());
+    }
+    let mut application_id = [0; 16];
+    for (byte, pair) in application_id.iter_mut().zip(digits.chunks(2)) {
+        let pair: String = pair.iter().collect();
+        *byte = u8::from_str_radix(&pair, 16).map_err(|_| invalid())?;
+    }
+    Ok(application_id)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::FitStreamProcessor;
+    use crate::profile::MesgNum;
+    use crate::{FitDataRecord, Value};
+    use core::convert::TryFrom;
+
+    const APPLICATION_ID: &str = "01010203-0508-0d15-2237-5990e97962db";
+
+    /// Decode the records of a file using the registered decoders
+    fn decode(registry: DeveloperFieldRegistry) -> Vec<FitDataRecord> {
+        let data = include_bytes!("../../tests/fixtures/DeveloperData.fit");
+        let mut processor = FitStreamProcessor::new();
+        processor.set_developer_decoders(registry);
+        let mut input = &data[..];
+        let mut records = Vec::new();
+        while !input.is_empty() {
+            let (remaining, obj) = processor.deserialize_next(input).unwrap();
+            if let crate::de::FitObject::DataMessage(msg) = obj {
+                records.push(processor.decode_message(msg).unwrap());
+            }
+            input = remaining;
+        }
+        records
+    }
+
+    #[test]
+    fn registered_decoders() {
+        let id = parse_application_id(APPLICATION_ID).unwrap();
+        assert_eq!(id[..4], [1, 1, 2, 3]);
+        assert!(parse_application_id("0101").is_err());
+        assert!(parse_application_id("zz010203-0508-0d15-2237-5990e97962db").is_err());
+
+        // the doughnuts are split into whole and half doughnuts
+        let mut registry = DeveloperFieldRegistry::new();
+        registry.register_application(id, |field: &FitDataField| {
+            let count = i64::try_from(field.value().clone()).ok()?;
+            let field = |name: &'static str, value| {
+                FitDataField::new_developer_field(name, 0, 0, Value::SInt64(value), "doughnuts")
+            };
+            Some(vec![field("whole", count / 2), field("half", count % 2)])
+        });
+        registry.register_index(0, |_: &FitDataField| Some(Vec::new()));
+        let records = decode(registry);
+        let record = records
+            .iter()
+            .filter(|r| r.kind() == MesgNum::Record)
+            .nth(2)
+            .unwrap();
+        assert!(record.field("doughnuts_earned").is_none());
+        assert_eq!(record.field("whole").unwrap().value(), &Value::SInt64(1));
+        assert_eq!(record.field("half").unwrap().value(), &Value::SInt64(1));
+
+        // the index is used when no decoder is registered for the application
+        let mut registry = DeveloperFieldRegistry::new();
+        registry.register_index(0, |_: &FitDataField| Some(Vec::new()));
+        let records = decode(registry);
+        assert!(records.iter().all(|r| r
+            .fields()
+            .iter()
+            .all(|f| f.developer_data_index().is_none())));
+    }
+}
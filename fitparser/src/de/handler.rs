@@ -0,0 +1,330 @@
+//! Drive a handler with the objects of a FIT file as they are decoded, so applications can build
+//! their own representation of the data without collecting the records first. Closures taking a
+//! record can be used as handlers, and `FitPushParser` accepts the bytes of a file as they arrive
+//! so only the object being parsed is held in memory.
+use super::parser::{FitDefinitionMessage, FitFileHeader};
+use super::{DecodeOption, FitObject, FitStreamProcessor};
+use crate::compat::prelude::*;
+use crate::compat::HashSet;
+use crate::error::{ErrorKind, Result};
+use crate::FitDataRecord;
+#[cfg(feature = "std")]
+use std::io::Read;
+
+/// Receives the objects of a FIT file in the order they are read. Returning an error from any
+/// method stops decoding and the error is returned to the caller.
+pub trait MessageHandler {
+    /// Called for the header at the start of each FIT file
+    fn on_header(&mut self, header: &FitFileHeader) -> Result<()> {
+        let _ = header;
+        Ok(())
+    }
+
+    /// Called for each definition message, the fields of the data messages using its local
+    /// message number are laid out by it
+    fn on_definition(&mut self, definition: &FitDefinitionMessage) -> Result<()> {
+        let _ = definition;
+        Ok(())
+    }
+
+    /// Called for each decoded data message, messages removed by the message filter or the
+    /// `DropUnknownMessages` option are skipped
+    fn on_data(&mut self, record: FitDataRecord) -> Result<()>;
+
+    /// Called for the checksum at the end of each FIT file
+    fn on_crc(&mut self, crc: u16) -> Result<()> {
+        let _ = crc;
+        Ok(())
+    }
+}
+
+impl<F: FnMut(FitDataRecord) -> Result<()>> MessageHandler for F {
+    fn on_data(&mut self, record: FitDataRecord) -> Result<()> {
+        self(record)
+    }
+}
+
+/// Decode a FIT file stored as an array of bytes passing each object to the handler
+pub fn visit_bytes<H: MessageHandler + ?Sized>(buffer: &[u8], handler: &mut H) -> Result<()> {
+    visit_bytes_with_options(buffer, &HashSet::new(), handler)
+}
+
+/// Decode a FIT file stored as an array of bytes with additional decode options passing each
+/// object to the handler
+pub fn visit_bytes_with_options<H: MessageHandler + ?Sized>(
+    buffer: &[u8],
+    options: &HashSet<DecodeOption>,
+    handler: &mut H,
+) -> Result<()> {
+    FitStreamProcessor::with_options(options).visit(buffer, handler)
+}
+
+/// Decode the bytes using the processor, decoding stops without an error at the first invalid
+/// object when the `RecoverRecords` option is set.
+pub(super) fn visit<H: MessageHandler + ?Sized>(
+    processor: &mut FitStreamProcessor,
+    mut buffer: &[u8],
+    handler: &mut H,
+) -> Result<()> {
+    while !buffer.is_empty() {
+        let (remaining, obj) = match processor.deserialize_next(buffer) {
+            Ok(next) => next,
+            Err(_) if processor.options().contains(&DecodeOption::RecoverRecords) => break,
+            Err(err) => return Err(err),
+        };
+        match obj {
+            FitObject::Crc(crc) => {
+                processor.reset();
+                handler.on_crc(crc)?;
+            }
+            FitObject::Header(header) => handler.on_header(&header)?,
+            FitObject::DataMessage(msg) => {
+                let record = match processor.decode_message(msg) {
+                    Ok(record) => record,
+                    Err(_) if processor.options().contains(&DecodeOption::RecoverRecords) => break,
+                    Err(err) => return Err(err),
+                };
+                if processor.keep_record(&record) {
+                    handler.on_data(record)?;
+                }
+            }
+            FitObject::DefinitionMessage(definition) => handler.on_definition(&definition)?,
+        }
+        buffer = remaining;
+    }
+    Ok(())
+}
+
+/// Pass a deserialized object to the handler, decoding data messages first
+fn dispatch<H: MessageHandler + ?Sized>(
+    processor: &mut FitStreamProcessor,
+    obj: FitObject,
+    handler: &mut H,
+) -> Result<()> {
+    match obj {
+        FitObject::Crc(crc) => {
+            processor.reset();
+            handler.on_crc(crc)
+        }
+        FitObject::Header(header) => handler.on_header(&header),
+        FitObject::DataMessage(msg) => {
+            let record = processor.decode_message(msg)?;
+            if processor.keep_record(&record) {
+                handler.on_data(record)
+            } else {
+                Ok(())
+            }
+        }
+        FitObject::DefinitionMessage(definition) => handler.on_definition(&definition),
+    }
+}
+
+/// Push based parser that decodes the bytes of one or more FIT files as they are provided,
+/// passing each object to a handler. Bytes of an incomplete object are kept until the rest of
+/// it is pushed, so memory use doesn't grow with the size of the file.
+#[derive(Default)]
+pub struct FitPushParser {
+    processor: FitStreamProcessor,
+    buffer: Vec<u8>,
+}
+
+impl FitPushParser {
+    /// Create the parser
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create the parser with additional decode options
+    pub fn with_options(options: &HashSet<DecodeOption>) -> Self {
+        FitPushParser {
+            processor: FitStreamProcessor::with_options(options),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Return the processor used to decode the data, e.g. to set a message filter or profile
+    pub fn processor_mut(&mut self) -> &mut FitStreamProcessor {
+        &mut self.processor
+    }
+
+    /// Decode every complete object in the data pushed so far, the remaining bytes are kept
+    /// until more data is pushed
+    pub fn push<H: MessageHandler + ?Sized>(&mut self, data: &[u8], handler: &mut H) -> Result<()> {
+        self.buffer.extend_from_slice(data);
+        let mut position = 0;
+        let result = loop {
+            let input = &self.buffer[position..];
+            if input.is_empty() {
+                break Ok(());
+            }
+            match self.processor.deserialize_next(input) {
+                Ok((remaining, obj)) => {
+                    position += input.len() - remaining.len();
+                    if let Err(err) = dispatch(&mut self.processor, obj, handler) {
+                        break Err(err);
+                    }
+                }
+                Err(err) => match *err {
+                    ErrorKind::UnexpectedEof(..) => break Ok(()),
+                    _ => break Err(err),
+                },
+            }
+        };
+        self.buffer.drain(..position);
+        result
+    }
+
+    /// Check that the data ended after a complete object, returning the number of bytes of the
+    /// incomplete object as an error otherwise
+    pub fn finish(self) -> Result<()> {
+        if self.buffer.is_empty() {
+            Ok(())
+        } else {
+            Err(ErrorKind::ValueError(format!(
+                "data ended with {} bytes of an incomplete object",
+                self.buffer.len()
+            ))
+            .into())
+        }
+    }
+}
+
+/// Decode the FIT files in a source that implements io::Read passing each object to the
+/// handler, the source is read in chunks so arbitrarily large chained files can be processed.
+#[cfg(feature = "std")]
+pub fn visit_reader<T: Read, H: MessageHandler + ?Sized>(source: T, handler: &mut H) -> Result<()> {
+    visit_reader_with_options(source, &HashSet::new(), handler)
+}
+
+/// Decode the FIT files in a source that implements io::Read with additional decode options
+/// passing each object to the handler
+#[cfg(feature = "std")]
+pub fn visit_reader_with_options<T: Read, H: MessageHandler + ?Sized>(
+    mut source: T,
+    options: &HashSet<DecodeOption>,
+    handler: &mut H,
+) -> Result<()> {
+    let mut parser = FitPushParser::with_options(options);
+    let mut chunk = vec![0; super::READ_CHUNK_SIZE];
+    loop {
+        let nbytes = match source.read(&mut chunk) {
+            Ok(0) => return parser.finish(),
+            Ok(n) => n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+        parser.push(&chunk[..nbytes], handler)?;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::compat::HashMap;
+    use crate::de::from_bytes;
+    use crate::error::ErrorKind;
+    use crate::profile::MesgNum;
+
+    /// Count the objects of each FIT file
+    #[derive(Default)]
+    struct Counter {
+        headers: usize,
+        definitions: usize,
+        crcs: usize,
+        kinds: HashMap<MesgNum, usize>,
+    }
+
+    impl MessageHandler for Counter {
+        fn on_header(&mut self, _: &FitFileHeader) -> Result<()> {
+            self.headers += 1;
+            Ok(())
+        }
+
+        fn on_definition(&mut self, _: &FitDefinitionMessage) -> Result<()> {
+            self.definitions += 1;
+            Ok(())
+        }
+
+        fn on_data(&mut self, record: FitDataRecord) -> Result<()> {
+            *self.kinds.entry(record.kind()).or_default() += 1;
+            Ok(())
+        }
+
+        fn on_crc(&mut self, _: u16) -> Result<()> {
+            self.crcs += 1;
+            Ok(())
+        }
+    }
+
+    /// Stop after the first record message
+    struct FirstRecord(Option<FitDataRecord>);
+
+    impl MessageHandler for FirstRecord {
+        fn on_data(&mut self, record: FitDataRecord) -> Result<()> {
+            if record.kind() != MesgNum::Record {
+                return Ok(());
+            }
+            self.0 = Some(record);
+            Err(ErrorKind::ValueError(String::from("done")).into())
+        }
+    }
+
+    #[test]
+    fn drive_handlers() {
+        let data = include_bytes!("../../tests/fixtures/sample_mulitple_header.fit");
+        let expected = from_bytes(data).unwrap();
+        let mut counter = Counter::default();
+        visit_bytes(data, &mut counter).unwrap();
+        assert!(counter.headers > 1);
+        assert_eq!(counter.crcs, counter.headers);
+        assert!(counter.definitions > 0);
+        assert_eq!(counter.kinds.values().sum::<usize>(), expected.len());
+        let records = expected.iter().filter(|r| r.kind() == MesgNum::Record);
+        assert_eq!(counter.kinds[&MesgNum::Record], records.count());
+
+        let mut first = FirstRecord(None);
+        assert!(visit_bytes(data, &mut first).is_err());
+        let record = first.0.unwrap();
+        let expected = expected
+            .iter()
+            .find(|r| r.kind() == MesgNum::Record)
+            .unwrap();
+        assert_eq!(record.fields().len(), expected.fields().len());
+    }
+
+    #[test]
+    fn push_callbacks() {
+        let data = include_bytes!("../../tests/fixtures/sample_mulitple_header.fit");
+        let expected = from_bytes(data).unwrap();
+
+        // push a few bytes at a time so objects are split between pushes
+        let mut records = Vec::new();
+        let mut parser = FitPushParser::new();
+        for chunk in data.chunks(7) {
+            parser
+                .push(chunk, &mut |record: FitDataRecord| {
+                    records.push(record.kind());
+                    Ok(())
+                })
+                .unwrap();
+        }
+        parser.finish().unwrap();
+        let kinds: Vec<MesgNum> = expected.iter().map(|r| r.kind()).collect();
+        assert_eq!(records, kinds);
+
+        let mut count = 0;
+        visit_reader(&data[..], &mut |_: FitDataRecord| {
+            count += 1;
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(count, expected.len());
+
+        let mut parser = FitPushParser::new();
+        parser
+            .push(&data[..100], &mut |_: FitDataRecord| Ok(()))
+            .unwrap();
+        assert!(parser.finish().is_err());
+        assert!(visit_reader(&data[..100], &mut |_: FitDataRecord| Ok(())).is_err());
+    }
+}
@@ -0,0 +1,418 @@
+//! Index the data messages of a FIT file so their fields can be decoded on demand. Building the
+//! index only parses message headers, definitions and timestamps, the remaining field values are
+//! decoded when a record is requested. Sources implementing `Read + Seek` can be indexed without
+//! loading the file into memory, each record is read from the source when it is decoded.
+use super::decode::{Decoder, DeveloperFields};
+use super::parser::{self, FitDefinitionMessage};
+use super::{DecodeOption, Deserializer, FitObject};
+use crate::compat::prelude::*;
+use crate::compat::{Arc, HashSet};
+use crate::error::{ErrorKind, Result};
+use crate::profile::{MesgNum, TimestampField};
+use crate::FitDataRecord;
+use core::ops::Range;
+#[cfg(feature = "std")]
+use std::io::{Read, Seek, SeekFrom};
+
+/// Location of a single data message within the FIT file along with the state needed to decode
+/// it independently of the other messages.
+#[derive(Clone, Debug)]
+pub struct IndexedMessage {
+    kind: MesgNum,
+    range: Range<usize>,
+    definition: Arc<FitDefinitionMessage>,
+    base_timestamp: Option<TimestampField>,
+    developer_fields: Arc<DeveloperFields>,
+}
+
+impl IndexedMessage {
+    /// Return the kind of message
+    pub fn kind(&self) -> MesgNum {
+        self.kind
+    }
+
+    /// Return the byte range of the message, including the header byte
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Return the definition message used to parse the message
+    pub fn definition(&self) -> &FitDefinitionMessage {
+        &self.definition
+    }
+}
+
+/// Builds the index of data messages from consecutive parts of a FIT file
+struct Indexer {
+    deserializer: Deserializer,
+    decoder: Decoder,
+    messages: Vec<IndexedMessage>,
+}
+
+impl Indexer {
+    fn new(options: &HashSet<DecodeOption>) -> Self {
+        let mut deserializer = Deserializer::new();
+        deserializer.options_mut().extend(options.iter().copied());
+        // skip the fields of every data message, only the timestamps are parsed
+        deserializer.set_message_filter(Some(HashSet::new()));
+        Indexer {
+            deserializer,
+            decoder: Decoder::new(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Index the messages in the input starting at an offset in the file, returning the number
+    /// of bytes consumed. When `partial` is set an incomplete message at the end of the input
+    /// is left for the next call instead of returning an error.
+    fn index(&mut self, buffer: &[u8], offset: usize, partial: bool) -> Result<usize> {
+        let mut input = buffer;
+        while !input.is_empty() {
+            let start = offset + buffer.len() - input.len();
+            let (remaining, obj) = match self.deserializer.deserialize_next(input) {
+                Ok(next) => next,
+                Err(err) => match *err {
+                    ErrorKind::UnexpectedEof(..) if partial => break,
+                    _ => return Err(err),
+                },
+            };
+            match obj {
+                FitObject::Crc(..) => {
+                    self.deserializer.reset();
+                    self.decoder.reset();
+                }
+                FitObject::Header(..) => {}
+                FitObject::DataMessage(msg) => {
+                    let definition = &self.deserializer.definitions[&msg.local_message_number()];
+                    self.messages.push(IndexedMessage {
+                        kind: MesgNum::from(msg.global_message_number()),
+                        range: start..(offset + buffer.len() - remaining.len()),
+                        definition: Arc::clone(definition),
+                        base_timestamp: self.decoder.base_timestamp(),
+                        developer_fields: self.decoder.developer_fields(),
+                    });
+                    self.decoder.track_message(&msg);
+                }
+                FitObject::DefinitionMessage(..) => {}
+            }
+            input = remaining;
+        }
+        Ok(buffer.len() - input.len())
+    }
+}
+
+/// Decode the bytes of an indexed data message
+fn decode_message(
+    input: &[u8],
+    message: &IndexedMessage,
+    options: &HashSet<DecodeOption>,
+) -> Result<FitDataRecord> {
+    let (_, msg) = parser::data_message(input, &message.definition).map_err(|e| match e {
+        nom::Err::Error(err) | nom::Err::Failure(err) => {
+            ErrorKind::ParseError(message.range.start, err.code)
+        }
+        nom::Err::Incomplete(needed) => ErrorKind::UnexpectedEof(needed),
+    })?;
+
+    let mut decoder = Decoder::new();
+    decoder.set_base_timestamp(message.base_timestamp);
+    decoder.set_developer_fields(Arc::clone(&message.developer_fields));
+    decoder.decode_message(msg, options)
+}
+
+/// Check if a message is stored entirely within a byte range
+fn in_range(message: &IndexedMessage, range: &Range<usize>) -> bool {
+    message.range.start >= range.start && message.range.end <= range.end
+}
+
+/// Index of the data messages stored in one or more FIT files. Each record is decoded on demand
+/// using `decode`, fields that accumulate across messages only use the value stored in the
+/// message being decoded.
+#[derive(Clone, Debug)]
+pub struct FitIndex<'a> {
+    buffer: &'a [u8],
+    options: HashSet<DecodeOption>,
+    messages: Vec<IndexedMessage>,
+}
+
+impl<'a> FitIndex<'a> {
+    /// Index the data messages of a FIT file stored as an array of bytes
+    pub fn new(buffer: &'a [u8]) -> Result<Self> {
+        Self::with_options(buffer, &HashSet::new())
+    }
+
+    /// Index the data messages of a FIT file stored as an array of bytes with additional decode
+    /// options, the options are also used when decoding records.
+    pub fn with_options(buffer: &'a [u8], options: &HashSet<DecodeOption>) -> Result<Self> {
+        let mut indexer = Indexer::new(options);
+        indexer.index(buffer, 0, false)?;
+        Ok(FitIndex {
+            buffer,
+            options: options.clone(),
+            messages: indexer.messages,
+        })
+    }
+
+    /// Return the indexed data messages in the order they are stored
+    pub fn messages(&self) -> &[IndexedMessage] {
+        &self.messages
+    }
+
+    /// Return the number of indexed data messages
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Check if the index contains no data messages
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Decode the fields of an indexed data message into a FIT data record
+    pub fn decode(&self, message: &IndexedMessage) -> Result<FitDataRecord> {
+        let input = self
+            .buffer
+            .get(message.range())
+            .ok_or(ErrorKind::ParseError(
+                message.range.start,
+                nom::error::ErrorKind::Eof,
+            ))?;
+        decode_message(input, message, &self.options)
+    }
+
+    /// Decode every indexed data message of the provided kind
+    pub fn decode_kind(&self, kind: MesgNum) -> Result<Vec<FitDataRecord>> {
+        self.messages
+            .iter()
+            .filter(|m| m.kind == kind)
+            .map(|m| self.decode(m))
+            .collect()
+    }
+
+    /// Decode every indexed data message stored entirely within a byte range of the file
+    pub fn decode_range(&self, range: Range<usize>) -> Result<Vec<FitDataRecord>> {
+        self.messages
+            .iter()
+            .filter(|m| in_range(m, &range))
+            .map(|m| self.decode(m))
+            .collect()
+    }
+}
+
+/// Index of the data messages stored in a source implementing `Read + Seek`, such as a file too
+/// large to load into memory. The index is built by reading the source once from the start and
+/// each record is read from the source again when it is decoded.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct FitSeekIndex<R> {
+    source: R,
+    options: HashSet<DecodeOption>,
+    messages: Vec<IndexedMessage>,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read + Seek> FitSeekIndex<R> {
+    /// Index the data messages of a FIT file stored in the source
+    pub fn new(source: R) -> Result<Self> {
+        Self::with_options(source, &HashSet::new())
+    }
+
+    /// Index the data messages of a FIT file stored in the source with additional decode
+    /// options, the options are also used when decoding records.
+    pub fn with_options(mut source: R, options: &HashSet<DecodeOption>) -> Result<Self> {
+        source.seek(SeekFrom::Start(0))?;
+        let mut indexer = Indexer::new(options);
+        let mut buffer = Vec::new();
+        let mut chunk = vec![0; super::READ_CHUNK_SIZE];
+        let mut offset = 0;
+        loop {
+            let nbytes = match source.read(&mut chunk) {
+                Ok(n) => n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            };
+            // the data left at the end of the source must be a complete message
+            let partial = nbytes > 0;
+            buffer.extend_from_slice(&chunk[..nbytes]);
+            let consumed = indexer.index(&buffer, offset, partial)?;
+            buffer.drain(..consumed);
+            offset += consumed;
+            if !partial {
+                break;
+            }
+        }
+        Ok(FitSeekIndex {
+            source,
+            options: options.clone(),
+            messages: indexer.messages,
+        })
+    }
+
+    /// Return the indexed data messages in the order they are stored
+    pub fn messages(&self) -> &[IndexedMessage] {
+        &self.messages
+    }
+
+    /// Return the number of indexed data messages
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Check if the index contains no data messages
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Read the fields of an indexed data message from the source and decode them into a FIT
+    /// data record
+    pub fn decode(&mut self, message: &IndexedMessage) -> Result<FitDataRecord> {
+        read_message(&mut self.source, message, &self.options)
+    }
+
+    /// Decode every indexed data message of the provided kind
+    pub fn decode_kind(&mut self, kind: MesgNum) -> Result<Vec<FitDataRecord>> {
+        let (source, options) = (&mut self.source, &self.options);
+        self.messages
+            .iter()
+            .filter(|m| m.kind == kind)
+            .map(|m| read_message(source, m, options))
+            .collect()
+    }
+
+    /// Decode every indexed data message stored entirely within a byte range of the source
+    pub fn decode_range(&mut self, range: Range<usize>) -> Result<Vec<FitDataRecord>> {
+        let (source, options) = (&mut self.source, &self.options);
+        self.messages
+            .iter()
+            .filter(|m| in_range(m, &range))
+            .map(|m| read_message(source, m, options))
+            .collect()
+    }
+
+    /// Return the source of the index
+    pub fn into_inner(self) -> R {
+        self.source
+    }
+}
+
+/// Read an indexed data message from a source and decode it
+#[cfg(feature = "std")]
+fn read_message<R: Read + Seek>(
+    source: &mut R,
+    message: &IndexedMessage,
+    options: &HashSet<DecodeOption>,
+) -> Result<FitDataRecord> {
+    let mut input = vec![0; message.range.len()];
+    source.seek(SeekFrom::Start(message.range.start as u64))?;
+    source.read_exact(&mut input)?;
+    decode_message(&input, message, options)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+    use std::io::Cursor;
+
+    #[test]
+    fn index_and_decode_on_demand() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let expected = from_bytes(data).unwrap();
+        let index = FitIndex::new(data).unwrap();
+        assert_eq!(index.len(), expected.len());
+        for (msg, exp) in index.messages().iter().zip(expected.iter()) {
+            assert_eq!(msg.kind(), exp.kind());
+        }
+
+        let record = index.decode(&index.messages()[5]).unwrap();
+        assert_eq!(record.kind(), expected[5].kind());
+        assert_eq!(record.fields().len(), expected[5].fields().len());
+        for (field, exp) in record.fields().iter().zip(expected[5].fields()) {
+            assert_eq!(field.name(), exp.name());
+            assert_eq!(field.value(), exp.value());
+        }
+
+        let laps = index.decode_kind(MesgNum::Lap).unwrap();
+        assert_eq!(laps.len(), 1);
+    }
+
+    #[test]
+    fn seekable_index() {
+        let data = include_bytes!("../../tests/fixtures/sample_mulitple_header.fit");
+        let expected = FitIndex::new(data).unwrap();
+        let mut index = FitSeekIndex::new(Cursor::new(&data[..])).unwrap();
+        assert_eq!(index.len(), expected.len());
+        for (msg, exp) in index.messages().iter().zip(expected.messages()) {
+            assert_eq!(msg.kind(), exp.kind());
+            assert_eq!(msg.range(), exp.range());
+        }
+
+        let message = index.messages()[100].clone();
+        let record = index.decode(&message).unwrap();
+        let exp = expected.decode(&expected.messages()[100]).unwrap();
+        for (field, exp) in record.fields().iter().zip(exp.fields()) {
+            assert_eq!(field.name(), exp.name());
+            assert_eq!(field.value(), exp.value());
+        }
+        let laps = index.decode_kind(MesgNum::Lap).unwrap();
+        assert_eq!(
+            laps.len(),
+            expected.decode_kind(MesgNum::Lap).unwrap().len()
+        );
+
+        // only the messages stored entirely within the range are decoded
+        let range = 1000..5000;
+        let records = index.decode_range(range.clone()).unwrap();
+        assert_eq!(records.len(), expected.decode_range(range).unwrap().len());
+        assert!(!records.is_empty());
+
+        let truncated = &data[..data.len() - 3];
+        assert!(FitSeekIndex::new(Cursor::new(truncated)).is_err());
+    }
+
+    #[test]
+    fn index_resolves_timestamp_16() {
+        use crate::Value;
+        let base = 1_000_000_000_i64;
+        let mut records = vec![FitDataRecord::new(MesgNum::MonitoringInfo)];
+        records[0]
+            .set_field_value("timestamp", Value::from(TimestampField::Utc(base)))
+            .unwrap();
+        // each step is within the range of timestamp_16 but the total isn't, so every skipped
+        // message needs to advance the reference timestamp
+        for step in 1..=4 {
+            let mut record = FitDataRecord::new(MesgNum::Monitoring);
+            let value = Value::UInt16(((base + step * 40_000) & 0xFFFF) as u16);
+            record.set_field_value("timestamp_16", value).unwrap();
+            records.push(record);
+        }
+        let data = crate::encode::to_bytes(&records).unwrap();
+        let index = FitIndex::new(&data).unwrap();
+        for (step, msg) in index.messages()[1..].iter().enumerate() {
+            let record = index.decode(msg).unwrap();
+            let expected = TimestampField::Utc(base + (step as i64 + 1) * 40_000);
+            assert_eq!(
+                record.field_value("timestamp"),
+                Some(&Value::from(expected))
+            );
+        }
+    }
+
+    #[test]
+    fn index_matches_timestamps() {
+        let timestamp = |r: &FitDataRecord| {
+            r.fields()
+                .iter()
+                .find(|f| f.name() == "timestamp")
+                .map(|f| f.value().clone())
+        };
+        let data = include_bytes!("../../tests/fixtures/sample_mulitple_header.fit");
+        let expected = from_bytes(data).unwrap();
+        let index = FitIndex::new(data).unwrap();
+        assert_eq!(index.len(), expected.len());
+        for (msg, exp) in index.messages().iter().zip(expected.iter()) {
+            let record = index.decode(msg).unwrap();
+            assert_eq!(timestamp(&record), timestamp(exp));
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Limits on the resources used while decoding, so untrusted files can only use a bounded amount
+//! of memory and time. Decoding stops with `ErrorKind::LimitExceeded` as soon as a limit is
+//! exceeded.
+use super::parser::FitDefinitionMessage;
+use crate::compat::prelude::*;
+use crate::error::{ErrorKind, Result};
+use crate::{FitDataField, FitDataRecord, Value};
+use core::mem::size_of;
+
+/// Limits enforced while decoding, a limit set to None is not enforced. The limits apply to
+/// everything decoded by a processor, including every file of a chained FIT file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DecodeLimits {
+    /// Maximum number of data messages decoded
+    pub max_records: Option<usize>,
+    /// Maximum size of a data message in bytes, this is checked when the definition message is
+    /// read so oversized messages are never parsed
+    pub max_message_size: Option<usize>,
+    /// Maximum length of a string value in bytes
+    pub max_string_length: Option<usize>,
+    /// Maximum number of bytes allocated for the decoded records, estimated from the size of
+    /// their fields along with the contents of strings and arrays
+    pub max_allocated_bytes: Option<usize>,
+}
+
+/// Resources used so far by a processor
+#[derive(Clone, Copy, Debug, Default)]
+pub(super) struct Usage {
+    records: usize,
+    allocated_bytes: usize,
+}
+
+impl Usage {
+    /// Check the size of the data messages described by a definition message
+    pub(super) fn check_definition(
+        limits: &DecodeLimits,
+        definition: &FitDefinitionMessage,
+    ) -> Result<()> {
+        match limits.max_message_size {
+            Some(max) if definition.data_message_size() > max => Err(exceeded(format!(
+                "message size of {} bytes is larger than {} bytes",
+                definition.data_message_size(),
+                max
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Add a decoded record to the resources used
+    pub(super) fn add_record(
+        &mut self,
+        limits: &DecodeLimits,
+        record: &FitDataRecord,
+    ) -> Result<()> {
+        self.records += 1;
+        if let Some(max) = limits.max_records.filter(|max| self.records > *max) {
+            return Err(exceeded(format!("more than {} records", max)));
+        }
+        if let Some(max) = limits.max_string_length {
+            let values = record.fields().iter().map(FitDataField::value);
+            if let Some(len) = values
+                .filter_map(longest_string)
+                .max()
+                .filter(|len| *len > max)
+            {
+                return Err(exceeded(format!(
+                    "string of {} bytes is longer than {} bytes",
+                    len, max
+                )));
+            }
+        }
+        if let Some(max) = limits.max_allocated_bytes {
+            self.allocated_bytes += size_of::<FitDataRecord>()
+                + record
+                    .fields()
+                    .iter()
+                    .map(|f| size_of::<FitDataField>() + heap_size(f.value()))
+                    .sum::<usize>();
+            if self.allocated_bytes > max {
+                return Err(exceeded(format!("more than {} bytes allocated", max)));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Create the error returned when a limit is exceeded
+fn exceeded(message: String) -> crate::Error {
+    ErrorKind::LimitExceeded(message).into()
+}
+
+/// Return the length of the longest string stored in the value
+fn longest_string(value: &Value) -> Option<usize> {
+    match value {
+        Value::String(val) => Some(val.len()),
+        Value::Array(vals) => vals.iter().filter_map(longest_string).max(),
+        _ => None,
+    }
+}
+
+/// Return the number of bytes allocated by the value outside of its own size
+fn heap_size(value: &Value) -> usize {
+    match value {
+        Value::String(val) => val.len(),
+        Value::Array(vals) => vals.iter().map(|v| size_of::<Value>() + heap_size(v)).sum(),
+        _ => 0,
+    }
+}
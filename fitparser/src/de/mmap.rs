@@ -0,0 +1,187 @@
+//! Decode FIT files directly from a read only memory mapping instead of copying them into memory
+//! first. Mappings are only supported on unix targets, other targets read the file into a buffer.
+use super::{from_bytes_with_options, DecodeOption};
+use crate::compat::HashSet;
+use crate::error::Result;
+use crate::FitDataRecord;
+use std::fs::File;
+use std::io;
+use std::ops::Deref;
+use std::path::Path;
+
+/// Read only view of the contents of a file, the mapping is removed when the value is dropped.
+/// The file must not be modified while it is mapped, which is why creating one is unsafe.
+#[derive(Debug)]
+pub struct Mmap {
+    #[cfg(unix)]
+    ptr: *mut libc::c_void,
+    #[cfg(unix)]
+    len: usize,
+    #[cfg(not(unix))]
+    data: Vec<u8>,
+}
+
+// the mapping is read only and owned by the value so it can be shared between threads
+unsafe impl Send for Mmap {}
+unsafe impl Sync for Mmap {}
+
+impl Mmap {
+    /// Map the contents of an open file
+    ///
+    /// # Safety
+    ///
+    /// The file must not be truncated or modified by this or any other process while the mapping
+    /// is alive, changes to a private mapping are visible through the returned slice which
+    /// breaks the guarantees of `&[u8]` and truncating it raises `SIGBUS` when the data is read.
+    #[cfg(unix)]
+    pub unsafe fn map(file: &File) -> io::Result<Self> {
+        use std::os::unix::io::AsRawFd;
+
+        let len = file.metadata()?.len() as usize;
+        if len == 0 {
+            // empty mappings aren't allowed, there is nothing to map anyway
+            return Ok(Mmap {
+                ptr: core::ptr::null_mut(),
+                len,
+            });
+        }
+        let ptr = unsafe {
+            libc::mmap(
+                core::ptr::null_mut(),
+                len,
+                libc::PROT_READ,
+                libc::MAP_PRIVATE,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Mmap { ptr, len })
+    }
+
+    /// Read the contents of an open file since mappings aren't supported on this target
+    ///
+    /// # Safety
+    ///
+    /// Reading the file is always safe, the function is unsafe to match the unix version.
+    #[cfg(not(unix))]
+    pub unsafe fn map(file: &File) -> io::Result<Self> {
+        use std::io::Read;
+
+        let mut data = Vec::new();
+        (&*file).read_to_end(&mut data)?;
+        Ok(Mmap { data })
+    }
+
+    /// Open and map the file at the provided path
+    ///
+    /// # Safety
+    ///
+    /// See [`Mmap::map`], the file must not be modified while it is mapped.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::map(&File::open(path)?)
+    }
+}
+
+impl Deref for Mmap {
+    type Target = [u8];
+
+    #[cfg(unix)]
+    fn deref(&self) -> &[u8] {
+        if self.len == 0 {
+            return &[];
+        }
+        unsafe { core::slice::from_raw_parts(self.ptr as *const u8, self.len) }
+    }
+
+    #[cfg(not(unix))]
+    fn deref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl AsRef<[u8]> for Mmap {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+#[cfg(unix)]
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            unsafe {
+                libc::munmap(self.ptr, self.len);
+            }
+        }
+    }
+}
+
+/// Deserialize a FIT file by memory mapping an open file
+///
+/// # Safety
+///
+/// See [`Mmap::map`], the file must not be modified until the function returns.
+pub unsafe fn parse_mmap(file: &File) -> Result<Vec<FitDataRecord>> {
+    parse_mmap_with_options(file, &HashSet::new())
+}
+
+/// Deserialize a FIT file by memory mapping an open file with additional decode options
+///
+/// # Safety
+///
+/// See [`Mmap::map`], the file must not be modified until the function returns.
+pub unsafe fn parse_mmap_with_options(
+    file: &File,
+    options: &HashSet<DecodeOption>,
+) -> Result<Vec<FitDataRecord>> {
+    let mmap = Mmap::map(file)?;
+    from_bytes_with_options(&mmap, options)
+}
+
+/// Deserialize the FIT file at the provided path by memory mapping it
+///
+/// # Safety
+///
+/// See [`Mmap::map`], the file must not be modified until the function returns.
+pub unsafe fn parse_path<P: AsRef<Path>>(path: P) -> Result<Vec<FitDataRecord>> {
+    parse_path_with_options(path, &HashSet::new())
+}
+
+/// Deserialize the FIT file at the provided path by memory mapping it with additional decode
+/// options
+///
+/// # Safety
+///
+/// See [`Mmap::map`], the file must not be modified until the function returns.
+pub unsafe fn parse_path_with_options<P: AsRef<Path>>(
+    path: P,
+    options: &HashSet<DecodeOption>,
+) -> Result<Vec<FitDataRecord>> {
+    parse_mmap_with_options(&File::open(path)?, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+
+    #[test]
+    fn parse_mapped_file() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/Activity.fit");
+        // SAFETY: the fixture isn't modified by the tests
+        let mmap = unsafe { Mmap::open(path).unwrap() };
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        assert_eq!(&mmap[..], &data[..]);
+
+        let records = unsafe { parse_path(path).unwrap() };
+        let expected = from_bytes(data).unwrap();
+        assert_eq!(records.len(), expected.len());
+        for (record, exp) in records.iter().zip(&expected) {
+            assert_eq!(record.kind(), exp.kind());
+            assert_eq!(record.fields().len(), exp.fields().len());
+        }
+    }
+}
@@ -1,38 +1,303 @@
 //! Deserialize a stream of FIT file data into the serde data model by parsing the file and
 //! applying the packaged FIT profile to the data.
-use crate::error::{ErrorKind, Result};
-use crate::profile::MesgNum;
+use crate::compat::prelude::*;
+use crate::compat::{Arc, HashMap, HashSet};
+use crate::error::{with_context, DecodeContext, ErrorKind, Result};
+use crate::profile::field_types::File;
+use crate::profile::{CustomProfile, MesgNum};
 use crate::FitDataRecord;
-use nom::number::complete::le_u16;
-use std::collections::{HashMap, HashSet};
+use core::convert::TryFrom;
+use core::fmt;
+use core::sync::atomic::{AtomicBool, Ordering};
+use nom::number::streaming::le_u16;
+use serde::Deserialize;
+#[cfg(feature = "std")]
 use std::io::Read;
-use std::sync::Arc;
 
+#[cfg(feature = "async")]
+mod async_read;
+#[cfg(feature = "async")]
+pub use async_read::{parse_async, parse_async_with_options, FitRecordStream};
 mod crc;
-use crc::{caculate_crc, update_crc};
+pub(crate) use crc::{caculate_crc, update_crc};
 mod decode;
 use decode::Decoder;
+pub(crate) use decode::DeveloperFieldDescription;
+mod developer;
+pub use developer::{parse_application_id, DeveloperFieldDecoder, DeveloperFieldRegistry};
+mod handler;
+pub use handler::{visit_bytes, visit_bytes_with_options, FitPushParser, MessageHandler};
+#[cfg(feature = "std")]
+pub use handler::{visit_reader, visit_reader_with_options};
+mod index;
+mod limits;
+#[cfg(feature = "std")]
+pub use index::FitSeekIndex;
+pub use index::{FitIndex, IndexedMessage};
+pub use limits::DecodeLimits;
+use limits::Usage;
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "mmap")]
+pub use mmap::{parse_mmap, parse_mmap_with_options, parse_path, parse_path_with_options, Mmap};
 mod parser;
-pub use parser::{FitDataMessage, FitDefinitionMessage, FitFileHeader};
+mod raw;
+pub use raw::RawMessage;
+mod repair;
+pub use repair::{repair, RepairSummary};
+mod units;
+mod validate;
+pub use parser::{
+    BaseType, DeveloperFieldData, DeveloperFieldDefinition, FieldDefinition, FitDataMessage,
+    FitDefinitionMessage, FitFileHeader,
+};
+pub use units::Units;
+pub use validate::{validate, Problem, ProblemKind};
+mod view;
+pub use view::{ArrayRef, FieldView, FitFileView, MessageView, MessageViews, ValueRef};
+mod warnings;
+pub use warnings::{Warning, WarningKind};
 
 /// Decoding options for the deserializer
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum DecodeOption {
-    /// Drop message fields that don't exist in the generated profile
+    /// Drop message fields that don't exist in the generated profile, by default they are kept
+    /// as `unknown_field_<num>` with the raw value of the base type
     DropUnknownFields,
-    /// Drop entire messages that don't exist in the generated profile
+    /// Drop entire messages that don't exist in the generated profile, by default they are kept
+    /// using the global message number as the kind
     DropUnknownMessages,
     /// Keep the original field that's value under went component extraction
     /// by default they are replaced by the expanded fields
     KeepCompositeFields,
     /// Return the numeric value instead of string name for all enums
     ReturnNumericEnumValues,
+    /// Return the raw values stored in the file without applying the scale and offset or
+    /// converting enums and timestamps. Units are unchanged so they describe the converted value.
+    ReturnRawValues,
     /// Ignore header section checksum value if it exists
     SkipHeaderCrcValidation,
     /// Ignore data section checksum value
     SkipDataCrcValidation,
+    /// Return the records decoded before an error occurs instead of failing, this is useful for
+    /// truncated files. The error can be fetched using `from_bytes_with_recovery`.
+    RecoverRecords,
+    /// Continue parsing when a header or data checksum doesn't match, the mismatches are
+    /// recorded and can be fetched from `FitStreamProcessor::take_crc_mismatches`
+    WarnOnCrcMismatch,
     /// Keep the generic name when resolving subfields in the FIT profile
     UseGenericSubFieldName,
+    /// Only use the profile set by `FitStreamProcessor::set_profile` to decode messages, fields
+    /// it doesn't define are treated as unknown. This pins the field names to the SDK version
+    /// the profile was exported from instead of the bundled profile.
+    UseCustomProfileOnly,
+    /// Convert distance, altitude, speed and temperature values into the unit system, the units
+    /// of the field are updated to match. This is ignored when returning raw values.
+    ConvertUnits(Units),
+    /// Convert positions stored in semicircles, e.g. `position_lat` and `position_long`, into
+    /// decimal degrees. This is ignored when returning raw values.
+    ConvertSemicircles,
+    /// Keep the bytes of each data message along with the definition messages and file header
+    /// preceding it, records that aren't modified are written back out unchanged by the encoder.
+    /// An unmodified file is reproduced byte for byte as long as it isn't chained and doesn't
+    /// end with definition messages.
+    PreserveLayout,
+    /// Collect data quality issues that don't stop decoding, such as unknown messages, enum
+    /// values without a variant and strings that aren't valid UTF-8. They can be fetched from
+    /// `FitStreamProcessor::take_warnings`, CRC mismatches are included when using
+    /// `WarnOnCrcMismatch`.
+    CollectWarnings,
+    /// Return the first warning `CollectWarnings` would collect as an `ErrorKind::Warning` error
+    FailOnWarnings,
+    /// Keep the bytes and position of each data message on the decoded record, see
+    /// `FitDataRecord::raw_message`. Unlike `PreserveLayout` they are kept when the record is
+    /// modified.
+    KeepRawBytes,
+}
+
+/// Named sets of decode options for users that need opposite defaults
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DecodePreset {
+    /// Follow the FIT protocol exactly, decoding fails on checksum mismatches and on any
+    /// anomaly that would be a warning such as unknown messages or invalid strings
+    Strict,
+    /// Decode as much as possible like most consumer tools, checksum mismatches are recorded
+    /// instead of failing and the records decoded before an error are returned
+    Permissive,
+}
+
+impl DecodePreset {
+    /// Return the decode options of the preset, more options can be added to the set
+    pub fn options(self) -> HashSet<DecodeOption> {
+        let options: &[DecodeOption] = match self {
+            DecodePreset::Strict => &[DecodeOption::FailOnWarnings],
+            DecodePreset::Permissive => &[
+                DecodeOption::RecoverRecords,
+                DecodeOption::WarnOnCrcMismatch,
+            ],
+        };
+        options.iter().copied().collect()
+    }
+}
+
+impl core::str::FromStr for DecodePreset {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "strict" => Ok(DecodePreset::Strict),
+            "permissive" => Ok(DecodePreset::Permissive),
+            _ => Err(ErrorKind::ValueError(format!("unknown decode preset: {}", s)).into()),
+        }
+    }
+}
+
+/// Describes a checksum stored in the file that doesn't match the calculated value, these are
+/// recorded instead of returning an error when using `DecodeOption::WarnOnCrcMismatch`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CrcMismatch {
+    position: usize,
+    expected: u16,
+    calculated: u16,
+    header: bool,
+}
+
+impl CrcMismatch {
+    /// Position in the byte stream following the checksum
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Checksum value stored in the file
+    pub fn expected(&self) -> u16 {
+        self.expected
+    }
+
+    /// Checksum value calculated from the parsed bytes
+    pub fn calculated(&self) -> u16 {
+        self.calculated
+    }
+
+    /// True if the checksum belongs to the file header instead of the data section
+    pub fn is_header(&self) -> bool {
+        self.header
+    }
+}
+
+/// Describes the error that stopped decoding when recovering the records of a damaged file
+#[derive(Debug)]
+pub struct RecoveryError {
+    position: usize,
+    error: crate::Error,
+}
+
+impl RecoveryError {
+    /// Position in the byte stream of the object that failed to decode
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Return the error that stopped decoding
+    pub fn error(&self) -> &ErrorKind {
+        &self.error
+    }
+
+    /// Consume the value and return the error that stopped decoding
+    pub fn into_error(self) -> crate::Error {
+        self.error
+    }
+}
+
+impl fmt::Display for RecoveryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "decoding stopped at position {:#x}: {}",
+            self.position, self.error
+        )
+    }
+}
+
+/// Shared flag used to stop decoding from another thread or a message handler, e.g. once a time
+/// or size budget is exceeded. Decoding returns `ErrorKind::Cancelled` before reading the next
+/// object after the token is cancelled.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Create a token that isn't cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop every decoder using this token or one of its clones
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Check if the token has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Data messages of a single FIT file within a chained FIT file, see `parse_all`. Files can be
+/// deserialized from the form written by `ser::SerializationOptions::file`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct FitFile {
+    header: FitFileHeader,
+    records: Vec<FitDataRecord>,
+    crc: Option<u16>,
+}
+
+impl FitFile {
+    /// Return the header of the FIT file
+    pub fn header(&self) -> &FitFileHeader {
+        &self.header
+    }
+
+    /// Return the decoded data messages of the FIT file
+    pub fn records(&self) -> &[FitDataRecord] {
+        &self.records
+    }
+
+    /// Return the decoded data messages of a single kind in the order they are stored
+    pub fn records_by_kind(&self, kind: MesgNum) -> Vec<&FitDataRecord> {
+        self.records.iter().filter(|r| r.kind() == kind).collect()
+    }
+
+    /// Group the decoded data messages by kind, this avoids scanning all of the records when
+    /// several kinds of messages are needed
+    pub fn group_by_kind(&self) -> HashMap<MesgNum, Vec<&FitDataRecord>> {
+        let mut groups: HashMap<MesgNum, Vec<&FitDataRecord>> = HashMap::new();
+        for record in &self.records {
+            groups.entry(record.kind()).or_default().push(record);
+        }
+        groups
+    }
+
+    /// Return the decoded data messages so their fields can be edited, see
+    /// `FitDataRecord::set_field_value`
+    pub fn records_mut(&mut self) -> &mut [FitDataRecord] {
+        &mut self.records
+    }
+
+    /// Only keep the data messages that the predicate returns true for
+    pub fn retain_records<F: FnMut(&FitDataRecord) -> bool>(&mut self, f: F) {
+        self.records.retain(f)
+    }
+
+    /// Return the data section CRC, this is missing if the file ended before the CRC
+    pub fn crc(&self) -> Option<u16> {
+        self.crc
+    }
+
+    /// Consume the FIT file returning the decoded data messages
+    pub fn into_records(self) -> Vec<FitDataRecord> {
+        self.records
+    }
 }
 
 /// Stores a FIT file object (header, message or CRC)
@@ -55,6 +320,9 @@ struct Deserializer {
     /// Track the current set of FIT message definitions, these are what allows the format to
     /// be self describing.
     definitions: HashMap<u8, Arc<FitDefinitionMessage>>,
+    /// Global message numbers of the data messages to parse, the fields of all other data
+    /// messages are skipped over when set
+    message_filter: Option<HashSet<u16>>,
     /// Stores the current position in the byte stream, this is needed for error generation and
     /// checking the state of the parser
     position: usize,
@@ -63,6 +331,10 @@ struct Deserializer {
     end_of_messages: usize,
     /// Stores the current CRC value
     crc: u16,
+    /// Checksum mismatches found when the `WarnOnCrcMismatch` option is set
+    crc_mismatches: Vec<CrcMismatch>,
+    /// Number of data messages deserialized from the stream
+    data_messages: usize,
 }
 
 impl Deserializer {
@@ -71,9 +343,12 @@ impl Deserializer {
         Deserializer {
             options: HashSet::new(),
             definitions: HashMap::new(),
+            message_filter: None,
             position: 0,
             end_of_messages: 0,
             crc: 0,
+            crc_mismatches: Vec::new(),
+            data_messages: 0,
         }
     }
 
@@ -87,6 +362,16 @@ impl Deserializer {
         &mut self.options
     }
 
+    /// Fetch the global message numbers of the data messages being parsed
+    fn message_filter(&self) -> Option<&HashSet<u16>> {
+        self.message_filter.as_ref()
+    }
+
+    /// Limit the data messages parsed to the provided global message numbers
+    fn set_message_filter(&mut self, filter: Option<HashSet<u16>>) {
+        self.message_filter = filter;
+    }
+
     /// Clear the definition messages used to decode data messages and reset the CRC value. This
     /// can be called between distinct FIT files but if they are properly formed it should not be
     /// necessary since new definitions will replace the old in the mapping.
@@ -116,6 +401,10 @@ impl Deserializer {
     fn deserialize_header<'de>(&mut self, input: &'de [u8]) -> Result<(&'de [u8], FitObject)> {
         let (remaining, header) =
             parser::fit_file_header(input).map_err(|e| self.to_parse_err(e))?;
+        // a damaged header size would be used to slice the input when checking the CRC
+        if !header.has_valid_size() || header.header_size() as usize > input.len() {
+            return Err(ErrorKind::ParseError(self.position, nom::error::ErrorKind::Verify).into());
+        }
         self.end_of_messages =
             self.position + header.header_size() as usize + header.data_size() as usize;
         self.position += header.header_size() as usize;
@@ -132,6 +421,21 @@ impl Deserializer {
                 .contains(&DecodeOption::SkipHeaderCrcValidation)
                 && checksum != crc_value
             {
+                if self.options.contains(&DecodeOption::WarnOnCrcMismatch) {
+                    log::warn!(
+                        "header CRC mismatch at byte {}, expected {:#06x} but calculated {:#06x}",
+                        self.position,
+                        crc_value,
+                        checksum
+                    );
+                    self.crc_mismatches.push(CrcMismatch {
+                        position: self.position,
+                        expected: crc_value,
+                        calculated: checksum,
+                        header: true,
+                    });
+                    return Ok((remaining, FitObject::Header(header)));
+                }
                 return Err(Box::new(ErrorKind::InvalidCrc((
                     Vec::from(remaining),
                     FitObject::Header(header),
@@ -153,6 +457,21 @@ impl Deserializer {
         let (input, crc) = le_u16(input).map_err(|e| self.to_parse_err(e))?;
         self.position += 2;
         if !self.options.contains(&DecodeOption::SkipDataCrcValidation) && crc != self.crc {
+            if self.options.contains(&DecodeOption::WarnOnCrcMismatch) {
+                log::warn!(
+                    "data CRC mismatch at byte {}, expected {:#06x} but calculated {:#06x}",
+                    self.position,
+                    crc,
+                    self.crc
+                );
+                self.crc_mismatches.push(CrcMismatch {
+                    position: self.position,
+                    expected: crc,
+                    calculated: self.crc,
+                    header: false,
+                });
+                return Ok((input, FitObject::Crc(crc)));
+            }
             return Err(Box::new(ErrorKind::InvalidCrc((
                 Vec::from(input),
                 FitObject::Crc(crc),
@@ -168,12 +487,15 @@ impl Deserializer {
         // parse a single message of either variety
         let init_len = input.len();
         let (remaining, message) =
-            parser::fit_message(input, &self.definitions).map_err(|e| self.to_parse_err(e))?;
+            parser::fit_message(input, &self.definitions, self.message_filter())
+                .map_err(|e| self.to_parse_err(e))?;
         // update CRC with the consumed bytes
         self.crc = update_crc(self.crc, &input[0..(input.len() - remaining.len())]);
 
         match message {
-            parser::FitMessage::Data(message) => {
+            parser::FitMessage::Data(mut message) => {
+                message.set_location(self.position, self.data_messages);
+                self.data_messages += 1;
                 self.position += init_len - remaining.len();
                 Ok((remaining, FitObject::DataMessage(message)))
             }
@@ -191,6 +513,34 @@ impl Deserializer {
         }
     }
 
+    /// Describe where deserializing the next object of the input failed, data messages are
+    /// identified by the local message number stored in their header byte
+    fn error_context(&self, input: &[u8]) -> DecodeContext {
+        let mut context = DecodeContext {
+            offset: Some(self.position),
+            ..DecodeContext::default()
+        };
+        let in_messages = self.position > 0 && self.position < self.end_of_messages;
+        let local_number = input.first().filter(|_| in_messages).and_then(|b| {
+            if b & 0x80 != 0 {
+                // compressed timestamp headers are only used by data messages
+                Some((b >> 5) & 0x03)
+            } else if b & 0x40 == 0 {
+                Some(b & 0x0F)
+            } else {
+                None
+            }
+        });
+        if let Some(local_number) = local_number {
+            context.record_index = Some(self.data_messages);
+            context.kind = self
+                .definitions
+                .get(&local_number)
+                .map(|d| MesgNum::from(d.global_message_number()));
+        }
+        context
+    }
+
     /// Inject the byte stream position into the Error when converting a nom parsing error. This
     /// is not easy to get using the vanilla From trait since we need outside information.
     fn to_parse_err(&self, err: nom::Err<nom::error::Error<&[u8]>>) -> crate::Error {
@@ -206,10 +556,63 @@ impl Deserializer {
     }
 }
 
+/// Bytes of a data message and the objects preceding it in the file, these are kept when using
+/// `DecodeOption::PreserveLayout` so the encoder can reproduce the original file.
+#[derive(Clone, Debug)]
+pub(crate) struct MessageLayout {
+    /// File header preceding the message, only set for the first message of a file
+    pub(crate) header: Option<Vec<u8>>,
+    /// Definition messages preceding the message along with their bytes
+    pub(crate) definitions: Vec<(Arc<FitDefinitionMessage>, Vec<u8>)>,
+    /// Definition message used to parse the message
+    pub(crate) definition: Arc<FitDefinitionMessage>,
+    /// Bytes of the data message including the header byte
+    pub(crate) message: Vec<u8>,
+}
+
+/// Return the numbers of the fields that were dropped from a record because their value was
+/// invalid, this is only known for records decoded with `DecodeOption::PreserveLayout` or
+/// `DecodeOption::KeepRawBytes`. Fields set on the record after decoding aren't returned.
+pub(crate) fn invalid_field_numbers(record: &FitDataRecord) -> Vec<u8> {
+    let (bytes, definition) = match (&record.layout, &record.raw) {
+        (Some(layout), _) => (&layout.message[..], &*layout.definition),
+        (None, Some(raw)) => (raw.bytes(), raw.definition()),
+        (None, None) => return Vec::new(),
+    };
+    let message = match parser::data_message(bytes, definition) {
+        Ok((_, message)) => message,
+        Err(_) => return Vec::new(),
+    };
+    definition
+        .field_definitions()
+        .iter()
+        .map(|f| f.field_definition_number())
+        .filter(|n| !message.fields().contains_key(n))
+        .filter(|n| {
+            !record
+                .fields()
+                .iter()
+                .any(|f| f.number() == *n && f.developer_data_index().is_none())
+        })
+        .collect()
+}
+
 /// Deserialize and decode a stream of bytes
 pub struct FitStreamProcessor {
     decoder: Decoder,
     deserializer: Deserializer,
+    /// Header read since the last data message when preserving the layout
+    header: Option<Vec<u8>>,
+    /// Definition messages read since the last data message when preserving the layout
+    definitions: Vec<(Arc<FitDefinitionMessage>, Vec<u8>)>,
+    /// Last data message read and its definition when preserving the layout
+    message: Option<(Arc<FitDefinitionMessage>, Vec<u8>)>,
+    /// Last data message read when keeping the raw bytes
+    raw: Option<RawMessage>,
+    cancellation: Option<CancellationToken>,
+    limits: DecodeLimits,
+    usage: Usage,
+    warnings: Vec<Warning>,
 }
 
 impl Default for FitStreamProcessor {
@@ -217,6 +620,14 @@ impl Default for FitStreamProcessor {
         FitStreamProcessor {
             decoder: Decoder::new(),
             deserializer: Deserializer::new(),
+            header: None,
+            definitions: Vec::new(),
+            message: None,
+            raw: None,
+            cancellation: None,
+            limits: DecodeLimits::default(),
+            usage: Usage::default(),
+            warnings: Vec::new(),
         }
     }
 }
@@ -227,6 +638,13 @@ impl FitStreamProcessor {
         Self::default()
     }
 
+    /// Create the processor with additional decode options
+    pub fn with_options(options: &HashSet<DecodeOption>) -> Self {
+        let mut processor = Self::new();
+        options.iter().for_each(|o| processor.add_option(*o));
+        processor
+    }
+
     /// Add a decoding option to the processor
     pub fn add_option(&mut self, opt: DecodeOption) {
         self.deserializer.options_mut().insert(opt);
@@ -242,63 +660,411 @@ impl FitStreamProcessor {
         self.deserializer.options()
     }
 
+    /// Only parse the fields of data messages matching the provided kinds, all other data messages
+    /// are skipped without decoding their values. Any timestamp stored in a skipped message,
+    /// including `timestamp_16` and `timestamp_min_8`, is still used as the reference for
+    /// compressed and partial timestamps.
+    pub fn set_message_filter(&mut self, kinds: &[MesgNum]) {
+        let filter = kinds.iter().map(|k| k.as_u16()).collect();
+        self.deserializer.set_message_filter(Some(filter));
+    }
+
+    /// Remove the message filter so all data messages are parsed
+    pub fn clear_message_filter(&mut self) {
+        self.deserializer.set_message_filter(None);
+    }
+
+    /// Check if a decoded record should be returned based on the message filter and the
+    /// `DropUnknownMessages` option. Filtered and unknown messages are still decoded by the
+    /// caller to keep the timestamp reference correct.
+    fn keep_record(&self, record: &FitDataRecord) -> bool {
+        let kind = record.kind();
+        if let Some(filter) = self.deserializer.message_filter() {
+            if !filter.contains(&kind.as_u16()) {
+                return false;
+            }
+        }
+        if self.options().contains(&DecodeOption::DropUnknownMessages)
+            && !MesgNum::is_named_variant(kind.as_i64())
+        {
+            log::debug!("dropping unknown message {}", kind.as_u16());
+            return false;
+        }
+        true
+    }
+
+    /// Decode messages using a profile loaded at runtime, fields it defines take priority over
+    /// the bundled profile.
+    pub fn set_profile(&mut self, profile: CustomProfile) {
+        self.decoder.set_profile(Some(Arc::new(profile)));
+    }
+
+    /// Remove the custom profile so only the bundled profile is used
+    pub fn clear_profile(&mut self) {
+        self.decoder.set_profile(None);
+    }
+
+    /// Decode the developer fields of known applications using the registered decoders
+    pub fn set_developer_decoders(&mut self, decoders: DeveloperFieldRegistry) {
+        self.decoder
+            .set_developer_decoders(Some(Arc::new(decoders)));
+    }
+
+    /// Remove the developer field decoders so developer fields use their base type
+    pub fn clear_developer_decoders(&mut self) {
+        self.decoder.set_developer_decoders(None);
+    }
+
+    /// Stop deserializing objects with `ErrorKind::Cancelled` once the token is cancelled
+    pub fn set_cancellation_token(&mut self, token: CancellationToken) {
+        self.cancellation = Some(token);
+    }
+
+    /// Enforce limits on the resources used while decoding, they apply to every object
+    /// decoded from now on including the objects of following chained files
+    pub fn set_limits(&mut self, limits: DecodeLimits) {
+        self.limits = limits;
+        self.usage = Usage::default();
+    }
+
+    /// Return the checksum mismatches found so far when using `DecodeOption::WarnOnCrcMismatch`,
+    /// removing them from the processor.
+    pub fn take_crc_mismatches(&mut self) -> Vec<CrcMismatch> {
+        core::mem::take(&mut self.deserializer.crc_mismatches)
+    }
+
+    /// Check if warnings are collected or returned as errors
+    fn collects_warnings(&self) -> bool {
+        self.options().contains(&DecodeOption::CollectWarnings)
+            || self.options().contains(&DecodeOption::FailOnWarnings)
+    }
+
+    /// Store the warnings found, the first one is returned as an error when using
+    /// `DecodeOption::FailOnWarnings`
+    fn add_warnings(&mut self, warnings: Vec<Warning>) -> Result<()> {
+        let mut warnings = warnings.into_iter();
+        if self.options().contains(&DecodeOption::FailOnWarnings) {
+            if let Some(warning) = warnings.next() {
+                return Err(ErrorKind::Warning(warning).into());
+            }
+        }
+        if self.options().contains(&DecodeOption::CollectWarnings) {
+            self.warnings.extend(warnings);
+        }
+        Ok(())
+    }
+
+    /// Return the warnings found so far when using `DecodeOption::CollectWarnings`, removing
+    /// them from the processor.
+    pub fn take_warnings(&mut self) -> Vec<Warning> {
+        core::mem::take(&mut self.warnings)
+    }
+
     /// Reset the decoder state and definition messages in use, this should be called at the end of
     /// each FIT file to ensure the accumlator fields in the decoder will produce the right values
     /// per file.
     pub fn reset(&mut self) {
         self.decoder.reset();
         self.deserializer.reset();
+        self.definitions.clear();
+        self.message = None;
+        self.raw = None;
     }
 
     /// Deserialize a FitObject from the byte stream.
     pub fn deserialize_next<'de>(&mut self, input: &'de [u8]) -> Result<(&'de [u8], FitObject)> {
-        self.deserializer.deserialize_next(input)
+        if self.cancellation.as_ref().is_some_and(|t| t.is_cancelled()) {
+            return Err(ErrorKind::Cancelled.into());
+        }
+        let position = self.deserializer.position;
+        let mismatches = self.deserializer.crc_mismatches.len();
+        let (remaining, obj) = self
+            .deserializer
+            .deserialize_next(input)
+            .map_err(|e| with_context(e, self.deserializer.error_context(input)))?;
+        if let FitObject::DefinitionMessage(definition) = &obj {
+            Usage::check_definition(&self.limits, definition).map_err(|e| {
+                let context = DecodeContext {
+                    offset: Some(position),
+                    kind: Some(MesgNum::from(definition.global_message_number())),
+                    ..DecodeContext::default()
+                };
+                with_context(e, context)
+            })?;
+        }
+        if self.collects_warnings() {
+            let mismatches = &self.deserializer.crc_mismatches[mismatches..];
+            let warnings = mismatches.iter().map(warnings::crc_mismatch).collect();
+            self.add_warnings(warnings)?;
+        }
+        if self.options().contains(&DecodeOption::PreserveLayout) {
+            let bytes = input[..(input.len() - remaining.len())].to_vec();
+            match &obj {
+                FitObject::Crc(..) => {}
+                FitObject::Header(..) => {
+                    self.header = Some(bytes);
+                    self.definitions.clear();
+                }
+                FitObject::DataMessage(msg) => {
+                    let definition = &self.deserializer.definitions[&msg.local_message_number()];
+                    self.message = Some((Arc::clone(definition), bytes));
+                }
+                FitObject::DefinitionMessage(def) => {
+                    self.definitions.push((Arc::clone(def), bytes))
+                }
+            }
+        }
+        if let FitObject::DataMessage(msg) = &obj {
+            if self.options().contains(&DecodeOption::KeepRawBytes) {
+                let bytes = input[..(input.len() - remaining.len())].to_vec();
+                let definition = &self.deserializer.definitions[&msg.local_message_number()];
+                self.raw = Some(RawMessage::new(position, bytes, Arc::clone(definition)));
+            }
+        }
+        Ok((remaining, obj))
     }
 
-    /// Decode a FIT data message into a FIT data record using the defined FIT profile.
+    /// Decode the bytes of a FIT file passing each object read to the handler, the processor
+    /// is reset at the end of each FIT file.
+    pub fn visit<H: MessageHandler + ?Sized>(
+        &mut self,
+        buffer: &[u8],
+        handler: &mut H,
+    ) -> Result<()> {
+        handler::visit(self, buffer, handler)
+    }
+
+    /// Decode a FIT data message into a FIT data record using the defined FIT profile. When
+    /// preserving the layout the message is expected to be the last one deserialized.
     pub fn decode_message(&mut self, msg: FitDataMessage) -> Result<FitDataRecord> {
-        self.decoder
+        let context = DecodeContext {
+            offset: msg.position(),
+            record_index: msg.index(),
+            kind: Some(MesgNum::from(msg.global_message_number())),
+            field: None,
+        };
+        let collect = self.collects_warnings();
+        let location = (msg.position(), msg.index());
+        let mut warnings = Vec::new();
+        if let Some(definition) = self
+            .deserializer
+            .definitions
+            .get(&msg.local_message_number())
+            .filter(|_| collect)
+        {
+            warnings::check_message(&msg, definition, &mut warnings);
+        }
+        let mut record = self
+            .decoder
             .decode_message(msg, self.deserializer.options())
+            .and_then(|record| {
+                self.usage.add_record(&self.limits, &record)?;
+                Ok(record)
+            })
+            .map_err(|e| with_context(e, context))?;
+        // filtered messages only parse their timestamp so they aren't checked
+        if collect && self.keep_record(&record) {
+            let check_enums = !self.options().contains(&DecodeOption::ReturnRawValues)
+                && !self
+                    .options()
+                    .contains(&DecodeOption::ReturnNumericEnumValues);
+            warnings::check_record(&record, location, check_enums, &mut warnings);
+            self.add_warnings(warnings)?;
+        }
+        if let Some((definition, message)) = self.message.take() {
+            record.layout = Some(Arc::new(MessageLayout {
+                header: self.header.take(),
+                definitions: core::mem::take(&mut self.definitions),
+                definition,
+                message,
+            }));
+        }
+        record.raw = self.raw.take().map(Arc::new);
+        Ok(record)
     }
 }
 
 /// Deserialize a FIT file stored as an array of bytes and return the decoded data messages,
 /// with additional decode options
 pub fn from_bytes_with_options(
-    mut buffer: &[u8],
+    buffer: &[u8],
     options: &HashSet<DecodeOption>,
 ) -> Result<Vec<FitDataRecord>> {
-    let mut processor = FitStreamProcessor::new();
-    let mut records = Vec::new();
+    from_bytes_impl(&mut FitStreamProcessor::with_options(options), buffer)
+}
+
+/// Deserialize a FIT file stored as an array of bytes returning only the data messages of the
+/// provided kinds, the fields of all other messages are skipped without being decoded.
+pub fn from_bytes_with_filter(
+    buffer: &[u8],
+    options: &HashSet<DecodeOption>,
+    kinds: &[MesgNum],
+) -> Result<Vec<FitDataRecord>> {
+    let mut processor = FitStreamProcessor::with_options(options);
+    processor.set_message_filter(kinds);
+    from_bytes_impl(&mut processor, buffer)
+}
+
+/// Deserialize a FIT file stored as an array of bytes returning the decoded data messages along
+/// with any checksum mismatches, `DecodeOption::WarnOnCrcMismatch` is always enabled.
+pub fn from_bytes_with_crc_mismatches(
+    buffer: &[u8],
+    options: &HashSet<DecodeOption>,
+) -> Result<(Vec<FitDataRecord>, Vec<CrcMismatch>)> {
+    let mut processor = FitStreamProcessor::with_options(options);
+    processor.add_option(DecodeOption::WarnOnCrcMismatch);
+    let records = from_bytes_impl(&mut processor, buffer)?;
+    Ok((records, processor.take_crc_mismatches()))
+}
+
+/// Deserialize a FIT file stored as an array of bytes returning the decoded data messages along
+/// with the warnings found, `DecodeOption::CollectWarnings` is always enabled.
+pub fn from_bytes_with_warnings(
+    buffer: &[u8],
+    options: &HashSet<DecodeOption>,
+) -> Result<(Vec<FitDataRecord>, Vec<Warning>)> {
+    let mut processor = FitStreamProcessor::with_options(options);
+    processor.add_option(DecodeOption::CollectWarnings);
+    let records = from_bytes_impl(&mut processor, buffer)?;
+    Ok((records, processor.take_warnings()))
+}
+
+/// Deserialize a FIT file stored as an array of bytes, decoding stops with
+/// `ErrorKind::Cancelled` once the token is cancelled
+pub fn from_bytes_with_cancellation(
+    buffer: &[u8],
+    options: &HashSet<DecodeOption>,
+    token: CancellationToken,
+) -> Result<Vec<FitDataRecord>> {
+    let mut processor = FitStreamProcessor::with_options(options);
+    processor.set_cancellation_token(token);
+    from_bytes_impl(&mut processor, buffer)
+}
 
-    options.iter().for_each(|o| processor.add_option(*o));
+/// Deserialize a FIT file stored as an array of bytes, decoding stops with
+/// `ErrorKind::LimitExceeded` as soon as one of the limits is exceeded
+pub fn from_bytes_with_limits(
+    buffer: &[u8],
+    options: &HashSet<DecodeOption>,
+    limits: DecodeLimits,
+) -> Result<Vec<FitDataRecord>> {
+    let mut processor = FitStreamProcessor::with_options(options);
+    processor.set_limits(limits);
+    from_bytes_impl(&mut processor, buffer)
+}
+
+/// Deserialize a FIT file stored as an array of bytes returning every record decoded before an
+/// error occurs, along with the error and where it occurred. Files that end early due to a
+/// device crash are the common case for this.
+pub fn from_bytes_with_recovery(
+    buffer: &[u8],
+    options: &HashSet<DecodeOption>,
+) -> (Vec<FitDataRecord>, Option<RecoveryError>) {
+    let mut processor = FitStreamProcessor::with_options(options);
+    processor.add_option(DecodeOption::RecoverRecords);
+    decode_records(&mut processor, buffer)
+}
+
+/// Deserialize a chained FIT file stored as an array of bytes, returning the data messages of
+/// each FIT file separately. A chained FIT file is several complete FIT files, each with their own
+/// header and CRC, written back to back. Definition messages, accumulated values and developer
+/// field descriptions don't carry over from one file to the next.
+pub fn parse_all(buffer: &[u8]) -> Result<Vec<FitFile>> {
+    parse_all_with_options(buffer, &HashSet::new())
+}
+
+/// Deserialize a chained FIT file stored as an array of bytes with additional decode options,
+/// returning the data messages of each FIT file separately. When `DecodeOption::RecoverRecords`
+/// is set the files decoded before an error are returned, including the incomplete file.
+pub fn parse_all_with_options(
+    buffer: &[u8],
+    options: &HashSet<DecodeOption>,
+) -> Result<Vec<FitFile>> {
+    let mut processor = FitStreamProcessor::with_options(options);
+    let mut files: Vec<FitFile> = Vec::new();
+    let mut buffer = buffer;
     while !buffer.is_empty() {
-        let (buf, obj) = processor.deserialize_next(buffer)?;
-        match obj {
-            FitObject::Crc(..) => processor.reset(),
-            FitObject::Header(..) => {}
-            FitObject::DataMessage(msg) => {
-                let rec = processor.decode_message(msg)?;
-                // drop the unknown messages if desired but we still need to
-                // decode them just incase the header contains a time-offset
-                // otherwise we'll get incorrect timestamps down the line
-                if processor
-                    .options()
-                    .contains(&DecodeOption::DropUnknownMessages)
-                {
-                    if MesgNum::is_named_variant(rec.kind().as_i64()) {
+        let result = processor.deserialize_next(buffer).and_then(|(buf, obj)| {
+            match obj {
+                FitObject::Crc(crc) => {
+                    processor.reset();
+                    if let Some(file) = files.last_mut() {
+                        file.crc = Some(crc);
+                    }
+                }
+                FitObject::Header(header) => files.push(FitFile {
+                    header,
+                    records: Vec::new(),
+                    crc: None,
+                }),
+                FitObject::DataMessage(msg) => {
+                    let rec = processor.decode_message(msg)?;
+                    if processor.keep_record(&rec) {
+                        if let Some(file) = files.last_mut() {
+                            file.records.push(rec);
+                        }
+                    }
+                }
+                FitObject::DefinitionMessage(..) => {}
+            }
+            Ok(buf)
+        });
+        match result {
+            Ok(buf) => buffer = buf,
+            Err(_) if processor.options().contains(&DecodeOption::RecoverRecords) => break,
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(files)
+}
+
+/// Deserialize the bytes of a FIT file using the processor, the error is only returned if the
+/// `RecoverRecords` option isn't set.
+fn from_bytes_impl(
+    processor: &mut FitStreamProcessor,
+    buffer: &[u8],
+) -> Result<Vec<FitDataRecord>> {
+    match decode_records(processor, buffer) {
+        (_, Some(err)) if !processor.options().contains(&DecodeOption::RecoverRecords) => {
+            Err(err.into_error())
+        }
+        (records, _) => Ok(records),
+    }
+}
+
+/// Decode records until the buffer is exhausted or an error occurs
+fn decode_records(
+    processor: &mut FitStreamProcessor,
+    mut buffer: &[u8],
+) -> (Vec<FitDataRecord>, Option<RecoveryError>) {
+    let mut records = Vec::new();
+    while !buffer.is_empty() {
+        let position = processor.deserializer.position;
+        let result = processor.deserialize_next(buffer).and_then(|(buf, obj)| {
+            match obj {
+                FitObject::Crc(..) => processor.reset(),
+                FitObject::Header(..) => {}
+                FitObject::DataMessage(msg) => {
+                    let rec = processor.decode_message(msg)?;
+                    // drop the unknown or filtered messages if desired but we still need to
+                    // decode them just incase the header contains a time-offset
+                    // otherwise we'll get incorrect timestamps down the line
+                    if processor.keep_record(&rec) {
                         records.push(rec);
                     }
-                } else {
-                    records.push(rec);
                 }
+                FitObject::DefinitionMessage(..) => {}
             }
-            FitObject::DefinitionMessage(..) => {}
+            Ok(buf)
+        });
+        match result {
+            Ok(buf) => buffer = buf,
+            Err(error) => return (records, Some(RecoveryError { position, error })),
         }
-        buffer = buf;
     }
 
-    Ok(records)
+    (records, None)
 }
 
 /// Deserialize a FIT file stored as an array of bytes and return the decoded data messages.
@@ -306,17 +1072,852 @@ pub fn from_bytes(buffer: &[u8]) -> Result<Vec<FitDataRecord>> {
     from_bytes_with_options(buffer, &HashSet::new())
 }
 
-/// Deserialize a FIT file stored in a source that implements io::Read, with additional decode options
+/// Return the type of a FIT file by reading only its header and `file_id` message, the messages
+/// after it aren't decoded and the file checksum isn't validated.
+pub fn peek_file_type(buffer: &[u8]) -> Result<File> {
+    for msg in FitFileView::new(buffer).messages() {
+        let msg = msg?;
+        if msg.kind() != MesgNum::FileId {
+            continue;
+        }
+        return match msg.field(0).and_then(|f| f.value()) {
+            Some(ValueRef::Scalar(value)) => Ok(File::from(i64::try_from(value)?)),
+            _ => Err(ErrorKind::ValueError(String::from("file_id message has no type")).into()),
+        };
+    }
+    Err(ErrorKind::ValueError(String::from("no file_id message found")).into())
+}
+
+/// Deserialize a FIT file stored in a source that implements io::Read, with additional decode
+/// options. The source is read incrementally so pipes and network streams are decoded as the
+/// data arrives instead of buffering the entire payload.
+#[cfg(feature = "std")]
 pub fn from_reader_with_options<T: Read>(
     source: &mut T,
     options: &HashSet<DecodeOption>,
 ) -> Result<Vec<FitDataRecord>> {
-    let mut buffer = Vec::new();
-    source.read_to_end(&mut buffer)?;
-    from_bytes_with_options(&buffer, options)
+    collect_records(FitRecordIter::with_options(source, options))
+}
+
+/// Deserialize a FIT file stored in a source that implements io::Read returning only the data
+/// messages of the provided kinds
+#[cfg(feature = "std")]
+pub fn from_reader_with_filter<T: Read>(
+    source: &mut T,
+    options: &HashSet<DecodeOption>,
+    kinds: &[MesgNum],
+) -> Result<Vec<FitDataRecord>> {
+    let mut iter = FitRecordIter::with_options(source, options);
+    iter.set_message_filter(kinds);
+    collect_records(iter)
+}
+
+/// Collect the records of an iterator, the error is only returned if the `RecoverRecords`
+/// option isn't set.
+#[cfg(feature = "std")]
+fn collect_records<T: Read>(iter: FitRecordIter<T>) -> Result<Vec<FitDataRecord>> {
+    let recover = iter
+        .buffer
+        .processor
+        .options()
+        .contains(&DecodeOption::RecoverRecords);
+    let mut records = Vec::new();
+    for record in iter {
+        match record {
+            Ok(record) => records.push(record),
+            Err(_) if recover => break,
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(records)
 }
 
 /// Deserialize a FIT file stored in a source that implements io::Read.
+#[cfg(feature = "std")]
 pub fn from_reader<T: Read>(source: &mut T) -> Result<Vec<FitDataRecord>> {
     from_reader_with_options(source, &HashSet::new())
 }
+
+/// Number of bytes requested from a source each time more data is needed
+#[cfg(feature = "std")]
+const READ_CHUNK_SIZE: usize = 8192;
+
+/// Holds partially read data from a source and the processor used to decode it, this is
+/// shared by the blocking and async record readers.
+#[cfg(feature = "std")]
+struct RecordBuffer {
+    processor: FitStreamProcessor,
+    buffer: Vec<u8>,
+    /// Position of the first byte in the buffer that hasn't been deserialized
+    position: usize,
+    /// Number of bytes in the buffer that were filled by the source
+    filled: usize,
+}
+
+#[cfg(feature = "std")]
+impl RecordBuffer {
+    /// Create an empty buffer with additional decode options
+    fn with_options(options: &HashSet<DecodeOption>) -> Self {
+        RecordBuffer {
+            processor: FitStreamProcessor::with_options(options),
+            buffer: Vec::new(),
+            position: 0,
+            filled: 0,
+        }
+    }
+
+    /// True if every byte read from the source has been deserialized
+    fn is_empty(&self) -> bool {
+        self.position == self.filled
+    }
+
+    /// Drop the consumed portion of the buffer and return space for the source to read into,
+    /// `commit` must be called afterwards with the number of bytes read.
+    fn unfilled(&mut self) -> &mut [u8] {
+        self.buffer.drain(..self.position);
+        self.filled -= self.position;
+        self.position = 0;
+        self.buffer.resize(self.filled + READ_CHUNK_SIZE, 0);
+        &mut self.buffer[self.filled..]
+    }
+
+    /// Mark bytes returned by `unfilled` as read from the source
+    fn commit(&mut self, nbytes: usize) {
+        self.filled += nbytes;
+        self.buffer.truncate(self.filled);
+    }
+
+    /// Deserialize objects until a data record is produced, an `UnexpectedEof` error is
+    /// returned when more data needs to be read from the source.
+    fn next_record(&mut self) -> Result<FitDataRecord> {
+        loop {
+            let input = &self.buffer[self.position..self.filled];
+            let (remaining, obj) = self.processor.deserialize_next(input)?;
+            self.position += input.len() - remaining.len();
+            match obj {
+                FitObject::Crc(..) => self.processor.reset(),
+                FitObject::Header(..) => {}
+                FitObject::DataMessage(msg) => {
+                    let rec = self.processor.decode_message(msg)?;
+                    // unknown messages are still decoded to keep the timestamp reference correct
+                    if self.processor.keep_record(&rec) {
+                        return Ok(rec);
+                    }
+                }
+                FitObject::DefinitionMessage(..) => {}
+            }
+        }
+    }
+}
+
+/// Iterator over the decoded data messages of one or more FIT files stored in a source that
+/// implements io::Read. Data is read from the source as needed so only the message being
+/// parsed is held in memory.
+#[cfg(feature = "std")]
+pub struct FitRecordIter<T: Read> {
+    source: T,
+    buffer: RecordBuffer,
+    done: bool,
+}
+
+#[cfg(feature = "std")]
+impl<T: Read> FitRecordIter<T> {
+    /// Create an iterator over the records in the source
+    pub fn new(source: T) -> Self {
+        Self::with_options(source, &HashSet::new())
+    }
+
+    /// Create an iterator over the records in the source with additional decode options
+    pub fn with_options(source: T, options: &HashSet<DecodeOption>) -> Self {
+        FitRecordIter {
+            source,
+            buffer: RecordBuffer::with_options(options),
+            done: false,
+        }
+    }
+
+    /// Only return the data messages matching the provided kinds, the fields of all other data
+    /// messages are skipped without being decoded.
+    pub fn set_message_filter(&mut self, kinds: &[MesgNum]) {
+        self.buffer.processor.set_message_filter(kinds);
+    }
+
+    /// Stop decoding with `ErrorKind::Cancelled` once the token is cancelled
+    pub fn set_cancellation_token(&mut self, token: CancellationToken) {
+        self.buffer.processor.set_cancellation_token(token);
+    }
+
+    /// Enforce limits on the resources used while decoding
+    pub fn set_limits(&mut self, limits: DecodeLimits) {
+        self.buffer.processor.set_limits(limits);
+    }
+
+    /// Read another chunk of data from the source returning the number of bytes read
+    fn fill_buffer(&mut self) -> Result<usize> {
+        let nbytes = loop {
+            match self.source.read(self.buffer.unfilled()) {
+                Ok(n) => break n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    self.buffer.commit(0);
+                    return Err(e.into());
+                }
+            }
+        };
+        self.buffer.commit(nbytes);
+        Ok(nbytes)
+    }
+
+    /// Decode the next record reading from the source as needed, None is returned once the
+    /// source is exhausted.
+    fn next_record(&mut self) -> Result<Option<FitDataRecord>> {
+        loop {
+            match self.buffer.next_record() {
+                Ok(rec) => return Ok(Some(rec)),
+                Err(e) => match *e {
+                    ErrorKind::UnexpectedEof(..) => {
+                        let is_empty = self.buffer.is_empty();
+                        if self.fill_buffer()? > 0 {
+                            continue;
+                        } else if is_empty {
+                            return Ok(None);
+                        } else {
+                            return Err(e);
+                        }
+                    }
+                    _ => return Err(e),
+                },
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Read> Iterator for FitRecordIter<T> {
+    type Item = Result<FitDataRecord>;
+
+    /// Decode the next data record, the iterator ends after the first error is returned
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_record() {
+            Ok(Some(rec)) => Some(Ok(rec)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Iterate over the data messages of a FIT file stored in a source that implements io::Read,
+/// records are decoded as they are read instead of parsing the entire file up front.
+#[cfg(feature = "std")]
+pub fn from_reader_iter<T: Read>(source: T) -> FitRecordIter<T> {
+    FitRecordIter::new(source)
+}
+
+/// Iterate over the data messages of a FIT file stored in a source that implements io::Read,
+/// with additional decode options
+#[cfg(feature = "std")]
+pub fn from_reader_iter_with_options<T: Read>(
+    source: T,
+    options: &HashSet<DecodeOption>,
+) -> FitRecordIter<T> {
+    FitRecordIter::with_options(source, options)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    /// Reader that returns a few bytes at a time to exercise refilling the buffer mid-message
+    struct ChunkedReader<'a> {
+        data: &'a [u8],
+        chunk_size: usize,
+    }
+
+    impl<'a> Read for ChunkedReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = self.chunk_size.min(buf.len()).min(self.data.len());
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    /// Wrap the bytes of FIT messages with a header and data CRC
+    fn fit_file_from_messages(messages: &[u8]) -> Vec<u8> {
+        let mut data = vec![12, 0x10, 0x00, 0x00];
+        data.extend_from_slice(&(messages.len() as u32).to_le_bytes());
+        data.extend_from_slice(b".FIT");
+        data.extend_from_slice(messages);
+        let crc = caculate_crc(&data);
+        data.extend_from_slice(&crc.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn accumulated_components() {
+        use crate::Value;
+        // record definition with the 8 bit cycles and 16 bit compressed_accumulated_power fields
+        let mut messages = vec![0x40, 0x00, 0x00, 0x14, 0x00, 0x02, 0x12, 0x01, 0x02];
+        messages.extend_from_slice(&[0x1C, 0x02, 0x84]);
+        // both counters roll over on the second message
+        messages.extend_from_slice(&[0x00, 250, 0x00, 0xFF]);
+        messages.extend_from_slice(&[0x00, 4, 0x10, 0x00]);
+        messages.extend_from_slice(&[0x00, 20, 0x20, 0x00]);
+
+        let records = from_bytes(&fit_file_from_messages(&messages)).unwrap();
+        let values = |name: &str| -> Vec<Value> {
+            records
+                .iter()
+                .filter_map(|r| r.fields().iter().find(|f| f.name() == name))
+                .map(|f| f.value().clone())
+                .collect()
+        };
+        assert_eq!(
+            values("total_cycles"),
+            vec![Value::UInt64(250), Value::UInt64(260), Value::UInt64(276)]
+        );
+        assert_eq!(
+            values("accumulated_power"),
+            vec![
+                Value::UInt64(0xFF00),
+                Value::UInt64(0x10010),
+                Value::UInt64(0x10020)
+            ]
+        );
+    }
+
+    #[test]
+    fn component_expansion() {
+        use crate::Value;
+        let field = |record: &FitDataRecord, name: &str| {
+            record
+                .fields()
+                .iter()
+                .find(|f| f.name() == name)
+                .map(|f| f.value().clone())
+        };
+        // record definition with the 3 byte compressed_speed_distance field
+        let mut messages = vec![0x40, 0x00, 0x00, 0x14, 0x00, 0x01, 0x08, 0x03, 0x0D];
+        // speed of 1234 and distance of 160 packed into 12 bits each
+        messages.extend_from_slice(&[0x00, 0xD2, 0x04, 0x0A]);
+        // event definition with the event and data fields
+        messages.extend_from_slice(&[0x41, 0x00, 0x00, 0x15, 0x00, 0x02]);
+        messages.extend_from_slice(&[0x00, 0x01, 0x00, 0x03, 0x04, 0x86]);
+        // rear gear change packing the gear numbers and teeth counts into the data field
+        messages.extend_from_slice(&[0x01, 43, 0x05, 0x0B, 0x02, 0x34]);
+
+        let records = from_bytes(&fit_file_from_messages(&messages)).unwrap();
+        assert_eq!(
+            field(&records[0], "enhanced_speed"),
+            Some(Value::Float64(12.34))
+        );
+        assert_eq!(field(&records[0], "distance"), Some(Value::Float64(10.0)));
+        assert_eq!(field(&records[0], "compressed_speed_distance"), None);
+        assert_eq!(field(&records[1], "rear_gear_num"), Some(Value::UInt64(5)));
+        assert_eq!(field(&records[1], "rear_gear"), Some(Value::UInt64(11)));
+        assert_eq!(field(&records[1], "front_gear_num"), Some(Value::UInt64(2)));
+        assert_eq!(field(&records[1], "front_gear"), Some(Value::UInt64(52)));
+    }
+
+    #[test]
+    fn compressed_timestamp_headers() {
+        use crate::profile::TimestampField;
+        use crate::Value;
+        // timestamp with the 5 least significant bits set to 30
+        let timestamp: u32 = 1_000_000_030;
+        let mut messages = Vec::new();
+        // record definition for local message 1 without a timestamp field
+        messages.extend_from_slice(&[0x41, 0x00, 0x00, 0x14, 0x00, 0x01, 0x03, 0x01, 0x02]);
+        // compressed timestamp before a reference timestamp exists
+        messages.extend_from_slice(&[0x80 | 0x20 | 5, 90]);
+        // record definition for local message 0 with timestamp and heart rate fields
+        messages.extend_from_slice(&[0x40, 0x00, 0x00, 0x14, 0x00, 0x02]);
+        messages.extend_from_slice(&[0xFD, 0x04, 0x86, 0x03, 0x01, 0x02]);
+        messages.push(0x00);
+        messages.extend_from_slice(&timestamp.to_le_bytes());
+        messages.push(100);
+        // offsets of 31 and then 2 which rolls over the 5 bit counter
+        messages.extend_from_slice(&[0x80 | 0x20 | 31, 101, 0x80 | 0x20 | 2, 102]);
+
+        let records = from_bytes(&fit_file_from_messages(&messages)).unwrap();
+        let timestamps: Vec<Option<&Value>> = records
+            .iter()
+            .map(|r| {
+                r.fields()
+                    .iter()
+                    .find(|f| f.name() == "timestamp")
+                    .map(|f| f.value())
+            })
+            .collect();
+        let expected = |offset: u32| Value::from(TimestampField::Utc((timestamp + offset) as i64));
+        assert_eq!(timestamps.len(), 4);
+        assert_eq!(timestamps[0], None);
+        assert_eq!(timestamps[1], Some(&expected(0)));
+        assert_eq!(timestamps[2], Some(&expected(1)));
+        assert_eq!(timestamps[3], Some(&expected(4)));
+        assert!(records[3].fields().iter().any(|f| f.units() == "s"));
+    }
+
+    #[test]
+    fn parse_all_chained_files() {
+        let data = include_bytes!("../../tests/fixtures/sample_mulitple_header.fit");
+        let expected = from_bytes(data).unwrap();
+        let files = parse_all(data).unwrap();
+        assert!(files.len() > 1);
+        assert!(files.iter().all(|f| f.crc().is_some()));
+        assert!(files.iter().all(|f| !f.records().is_empty()));
+        let records: Vec<FitDataRecord> =
+            files.into_iter().flat_map(|f| f.into_records()).collect();
+        assert_eq!(records.len(), expected.len());
+        for (rec, exp) in records.iter().zip(expected.iter()) {
+            assert_eq!(rec.kind(), exp.kind());
+        }
+
+        let files = parse_all(include_bytes!("../../tests/fixtures/Activity.fit")).unwrap();
+        assert_eq!(files.len(), 1);
+        let records = files[0].records_by_kind(MesgNum::Record);
+        assert!(!records.is_empty());
+        assert!(records.iter().all(|r| r.kind() == MesgNum::Record));
+        let groups = files[0].group_by_kind();
+        assert_eq!(groups[&MesgNum::Record].len(), records.len());
+        assert_eq!(groups[&MesgNum::Session].len(), 1);
+    }
+
+    #[test]
+    fn iter_matches_from_bytes() {
+        let data = include_bytes!("../../tests/fixtures/sample_mulitple_header.fit");
+        let expected = from_bytes(data).unwrap();
+        let records: Vec<FitDataRecord> =
+            from_reader_iter(&data[..]).collect::<Result<_>>().unwrap();
+        assert_eq!(records.len(), expected.len());
+        for (rec, exp) in records.iter().zip(expected.iter()) {
+            assert_eq!(rec.kind(), exp.kind());
+            assert_eq!(rec.fields().len(), exp.fields().len());
+        }
+    }
+
+    #[test]
+    fn iter_with_small_reads() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let reader = ChunkedReader {
+            data,
+            chunk_size: 7,
+        };
+        let records: Vec<FitDataRecord> = from_reader_iter(reader).collect::<Result<_>>().unwrap();
+        assert_eq!(records.len(), 22);
+    }
+
+    #[test]
+    fn reader_with_small_reads() {
+        let data = include_bytes!("../../tests/fixtures/sample_mulitple_header.fit");
+        let expected = from_bytes(data).unwrap();
+        let mut reader = ChunkedReader {
+            data,
+            chunk_size: 64,
+        };
+        let records = from_reader(&mut reader).unwrap();
+        assert_eq!(records.len(), expected.len());
+
+        // truncated files are an error unless the records are recovered
+        let data = &data[..data.len() / 2];
+        let recovered = from_bytes_with_recovery(data, &HashSet::new()).0;
+        assert!(from_reader(&mut &data[..]).is_err());
+        let mut options = HashSet::new();
+        options.insert(DecodeOption::RecoverRecords);
+        let records = from_reader_with_options(&mut &data[..], &options).unwrap();
+        assert_eq!(records.len(), recovered.len());
+        let records =
+            from_reader_with_filter(&mut &data[..], &options, &[MesgNum::Record]).unwrap();
+        assert!(records.iter().all(|r| r.kind() == MesgNum::Record));
+    }
+
+    #[test]
+    fn cancel_decoding() {
+        let data = include_bytes!("../../tests/fixtures/sample_mulitple_header.fit");
+        let token = CancellationToken::new();
+        assert!(from_bytes_with_cancellation(data, &HashSet::new(), token.clone()).is_ok());
+        token.cancel();
+        let err = from_bytes_with_cancellation(data, &HashSet::new(), token).unwrap_err();
+        assert!(matches!(*err, ErrorKind::Cancelled));
+
+        // stop once a budget of records is exceeded
+        let token = CancellationToken::new();
+        let mut processor = FitStreamProcessor::new();
+        processor.set_cancellation_token(token.clone());
+        let mut count = 0;
+        let result = processor.visit(data, &mut |_: FitDataRecord| {
+            count += 1;
+            if count == 10 {
+                token.cancel();
+            }
+            Ok(())
+        });
+        assert!(matches!(*result.unwrap_err(), ErrorKind::Cancelled));
+        assert_eq!(count, 10);
+
+        let token = CancellationToken::new();
+        let mut iter = from_reader_iter(&data[..]);
+        iter.set_cancellation_token(token.clone());
+        assert!(iter.next().unwrap().is_ok());
+        token.cancel();
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn enforce_limits() {
+        let data = include_bytes!("../../tests/fixtures/sample_mulitple_header.fit");
+        let expected = from_bytes(data).unwrap();
+        let decode = |limits| from_bytes_with_limits(data, &HashSet::new(), limits);
+        let limit_exceeded = |result: Result<Vec<FitDataRecord>>| {
+            matches!(result.unwrap_err().root(), ErrorKind::LimitExceeded(..))
+        };
+
+        let limits = DecodeLimits {
+            max_records: Some(expected.len()),
+            max_message_size: Some(255),
+            max_string_length: Some(64),
+            max_allocated_bytes: Some(64 * 1024 * 1024),
+        };
+        assert_eq!(decode(limits).unwrap().len(), expected.len());
+        assert!(limit_exceeded(decode(DecodeLimits {
+            max_records: Some(10),
+            ..DecodeLimits::default()
+        })));
+        assert!(limit_exceeded(decode(DecodeLimits {
+            max_message_size: Some(8),
+            ..DecodeLimits::default()
+        })));
+        assert!(limit_exceeded(decode(DecodeLimits {
+            max_allocated_bytes: Some(4096),
+            ..DecodeLimits::default()
+        })));
+
+        // the field description message stores the name and units of the developer field
+        let developer_data = include_bytes!("../../tests/fixtures/DeveloperData.fit");
+        let strings = |limit| {
+            let limits = DecodeLimits {
+                max_string_length: Some(limit),
+                ..DecodeLimits::default()
+            };
+            from_bytes_with_limits(developer_data, &HashSet::new(), limits)
+        };
+        assert!(strings(64).is_ok());
+        assert!(limit_exceeded(strings(4)));
+
+        // the records decoded before the limit are returned when recovering records
+        let mut processor = FitStreamProcessor::with_options(&HashSet::new());
+        processor.add_option(DecodeOption::RecoverRecords);
+        processor.set_limits(DecodeLimits {
+            max_records: Some(10),
+            ..DecodeLimits::default()
+        });
+        let (records, err) = decode_records(&mut processor, data);
+        assert_eq!(records.len(), 10);
+        assert!(matches!(
+            err.unwrap().error().root(),
+            ErrorKind::LimitExceeded(..)
+        ));
+    }
+
+    #[test]
+    fn decode_presets() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let strict = "strict".parse::<DecodePreset>().unwrap().options();
+        let permissive = "permissive".parse::<DecodePreset>().unwrap().options();
+        assert!("lenient".parse::<DecodePreset>().is_err());
+        assert!(from_bytes_with_options(data, &strict).is_ok());
+
+        // enum values without a variant fail strict decoding
+        let mut event = FitDataRecord::new(MesgNum::Event);
+        event
+            .set_field_value("event", crate::Value::Enum(250))
+            .unwrap();
+        let encoded = crate::encode::to_bytes(&[event]).unwrap();
+        let err = from_bytes_with_options(&encoded, &strict).unwrap_err();
+        match &*err {
+            ErrorKind::Warning(warning) => {
+                assert_eq!(warning.kind(), WarningKind::UnknownEnumValue)
+            }
+            err => panic!("unexpected error {}", err),
+        }
+        assert_eq!(
+            from_bytes_with_options(&encoded, &permissive)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        // damaged files are decoded as far as possible
+        let mut damaged = data.to_vec();
+        let len = damaged.len();
+        damaged[len - 1] ^= 0xFF;
+        assert!(from_bytes_with_options(&damaged, &strict).is_err());
+        let expected = from_bytes(data).unwrap();
+        let records = from_bytes_with_options(&damaged, &permissive).unwrap();
+        assert_eq!(records.len(), expected.len());
+        let records = from_bytes_with_options(&data[..len / 2], &permissive).unwrap();
+        assert!(!records.is_empty() && records.len() < expected.len());
+    }
+
+    #[test]
+    fn error_context() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let mut processor = FitStreamProcessor::new();
+        let mut input = &data[..];
+        let mut messages = Vec::new();
+        while !input.is_empty() {
+            let (remaining, obj) = processor.deserialize_next(input).unwrap();
+            if let FitObject::DataMessage(msg) = obj {
+                messages.push(msg);
+            }
+            input = remaining;
+        }
+        let message = &messages[5];
+        assert_eq!(message.index(), Some(5));
+        let position = message.position().unwrap();
+
+        // the limit is exceeded while decoding the sixth data message
+        let err = from_bytes_with_limits(
+            data,
+            &HashSet::new(),
+            DecodeLimits {
+                max_records: Some(5),
+                ..DecodeLimits::default()
+            },
+        )
+        .unwrap_err();
+        let context = err.context().unwrap();
+        assert_eq!(context.offset(), Some(position));
+        assert_eq!(context.record_index(), Some(5));
+        let kind = MesgNum::from(message.global_message_number());
+        assert_eq!(context.kind(), Some(kind));
+        assert!(err.to_string().contains(&format!("offset {:#x}", position)));
+
+        // point the header of the message to a local message number without a definition
+        let mut data = data.to_vec();
+        data[position] = 0x0F;
+        let err = from_bytes(&data).unwrap_err();
+        assert!(matches!(
+            err.root(),
+            ErrorKind::MissingDefinitionMessage(15, _)
+        ));
+        let context = err.context().unwrap();
+        assert_eq!(context.offset(), Some(position));
+        assert_eq!(context.record_index(), Some(5));
+        assert_eq!(context.kind(), None);
+
+        // errors controlling the decode aren't wrapped
+        assert!(matches!(
+            *from_bytes(&data[..100]).unwrap_err(),
+            ErrorKind::UnexpectedEof(..)
+        ));
+    }
+
+    #[test]
+    fn filter_message_kinds() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let expected: Vec<FitDataRecord> = from_bytes(data)
+            .unwrap()
+            .into_iter()
+            .filter(|r| r.kind() == MesgNum::Record)
+            .collect();
+        let records = from_bytes_with_filter(data, &HashSet::new(), &[MesgNum::Record]).unwrap();
+        assert_eq!(records.len(), expected.len());
+        for (rec, exp) in records.iter().zip(expected.iter()) {
+            assert_eq!(rec.kind(), MesgNum::Record);
+            assert_eq!(rec.fields().len(), exp.fields().len());
+            assert_eq!(
+                rec.fields()
+                    .iter()
+                    .find(|f| f.name() == "timestamp")
+                    .map(|f| f.value()),
+                exp.fields()
+                    .iter()
+                    .find(|f| f.name() == "timestamp")
+                    .map(|f| f.value())
+            );
+        }
+
+        let mut iter = from_reader_iter(&data[..]);
+        iter.set_message_filter(&[MesgNum::Lap, MesgNum::Session]);
+        let records: Vec<FitDataRecord> = iter.collect::<Result<_>>().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].kind(), MesgNum::Lap);
+        assert_eq!(records[1].kind(), MesgNum::Session);
+    }
+
+    const CUSTOM_TYPES: &str = "Type Name,Base Type,Value Name,Value
+mesg_num,uint16,,
+,,file_id,0
+";
+
+    const CUSTOM_MESSAGES: &str = "Message Name,Field Def #,Field Name,Field Type
+file_id,,,
+,1,maker,uint16
+";
+
+    /// Decode the first data message of a file
+    fn first_record(processor: &mut FitStreamProcessor, mut buffer: &[u8]) -> FitDataRecord {
+        loop {
+            let (buf, obj) = processor.deserialize_next(buffer).unwrap();
+            if let FitObject::DataMessage(msg) = obj {
+                return processor.decode_message(msg).unwrap();
+            }
+            buffer = buf;
+        }
+    }
+
+    #[test]
+    fn decode_with_custom_profile() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let mut processor = FitStreamProcessor::new();
+        processor.set_profile(CustomProfile::from_csv(CUSTOM_TYPES, CUSTOM_MESSAGES).unwrap());
+        let record = first_record(&mut processor, data);
+        let field = record.fields().iter().find(|f| f.number() == 1).unwrap();
+        assert_eq!(field.name(), "maker");
+        assert_eq!(field.value(), &crate::Value::UInt16(15));
+        assert!(record.fields().iter().any(|f| f.name() == "serial_number"));
+    }
+
+    #[test]
+    fn decode_with_custom_profile_only() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let mut processor = FitStreamProcessor::new();
+        processor.add_option(DecodeOption::UseCustomProfileOnly);
+        processor.set_profile(CustomProfile::from_csv(CUSTOM_TYPES, CUSTOM_MESSAGES).unwrap());
+        let record = first_record(&mut processor, data);
+        assert!(record.fields().iter().any(|f| f.name() == "maker"));
+        assert!(record
+            .fields()
+            .iter()
+            .any(|f| f.name() == "unknown_field_3"));
+        assert!(!record.fields().iter().any(|f| f.name() == "serial_number"));
+    }
+
+    #[test]
+    fn resolve_timestamp_16() {
+        let data = include_bytes!("../../tests/fixtures/MonitoringFile.fit");
+        let records = from_bytes(data).unwrap();
+        let monitoring: Vec<&FitDataRecord> = records
+            .iter()
+            .filter(|r| r.kind() == MesgNum::Monitoring)
+            .collect();
+        let timestamps: Vec<i64> = monitoring
+            .iter()
+            .map(|r| r.field("timestamp").unwrap().value().clone())
+            .map(|v| v.as_utc_timestamp().unwrap().timestamp())
+            .collect();
+        // the first value rolls over from the timestamp of the monitoring_info message
+        assert_eq!(timestamps[0], 1419687780);
+        assert!(timestamps.windows(2).all(|w| w[0] <= w[1]));
+        for (record, timestamp) in monitoring.iter().zip(&timestamps) {
+            if let Some(field) = record.field("timestamp_16") {
+                let value = i64::try_from(field.value().clone()).unwrap();
+                assert_eq!(value, (timestamp - 631065600) & 0xFFFF);
+            }
+        }
+    }
+
+    #[test]
+    fn filter_monitoring_file() {
+        let data = include_bytes!("../../tests/fixtures/MonitoringFile.fit");
+        let expected: Vec<FitDataRecord> = from_bytes(data)
+            .unwrap()
+            .into_iter()
+            .filter(|r| r.kind() == MesgNum::Monitoring)
+            .collect();
+        let records =
+            from_bytes_with_filter(data, &HashSet::new(), &[MesgNum::Monitoring]).unwrap();
+        assert_eq!(records.len(), expected.len());
+        for (rec, exp) in records.iter().zip(expected.iter()) {
+            assert_eq!(rec.field_value("timestamp"), exp.field_value("timestamp"));
+            assert_eq!(rec.fields().len(), exp.fields().len());
+        }
+    }
+
+    #[test]
+    fn resolve_timestamp_min_8() {
+        use crate::profile::TimestampField;
+        use crate::Value;
+        let base = 1_000_000_030_i64;
+        let minutes = base / 60;
+        let mut records = vec![FitDataRecord::new(MesgNum::MonitoringInfo)];
+        records[0]
+            .set_field_value("timestamp", Value::from(TimestampField::Utc(base)))
+            .unwrap();
+        // the first value is within the minute of the reference and the last rolls over
+        for offset in [0, 3, 200] {
+            let mut record = FitDataRecord::new(MesgNum::Monitoring);
+            let value = Value::UInt8(((minutes + offset) & 0xFF) as u8);
+            record.set_field_value("timestamp_min_8", value).unwrap();
+            record
+                .set_field_value("heart_rate", Value::UInt8(60))
+                .unwrap();
+            records.push(record);
+        }
+        let decoded = from_bytes(&crate::encode::to_bytes(&records).unwrap()).unwrap();
+        let timestamps: Vec<Value> = decoded[1..]
+            .iter()
+            .map(|r| r.field("timestamp").unwrap().value().clone())
+            .collect();
+        let expected: Vec<Value> = [0, 3, 200]
+            .iter()
+            .map(|offset| Value::from(TimestampField::Utc((minutes + offset) * 60)))
+            .collect();
+        assert_eq!(timestamps, expected);
+    }
+
+    #[test]
+    fn damaged_header_size() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        for header_size in [0, 1, 0xFF] {
+            let mut damaged = data.to_vec();
+            damaged[0] = header_size;
+            assert!(from_bytes(&damaged).is_err());
+            assert!(from_reader(&mut &damaged[..]).is_err());
+            assert!(parse_all(&damaged).is_err());
+            assert!(from_bytes_with_recovery(&damaged, &HashSet::new())
+                .1
+                .is_some());
+            assert!(FitIndex::new(&damaged).is_err());
+            assert!(FitSeekIndex::new(std::io::Cursor::new(&damaged)).is_err());
+            assert!(FitFileView::new(&damaged).messages().any(|m| m.is_err()));
+            assert!(peek_file_type(&damaged).is_err());
+            assert!(crate::analysis::rr_intervals(&damaged).is_err());
+            // a truncated header doesn't underflow the number of bytes needed
+            assert!(from_bytes(&damaged[..8]).is_err());
+        }
+    }
+
+    #[test]
+    fn peek_type() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        assert_eq!(peek_file_type(data).unwrap(), File::Activity);
+        let data = include_bytes!("../../tests/fixtures/MonitoringFile.fit");
+        assert_eq!(peek_file_type(data).unwrap(), File::MonitoringB);
+        let data = include_bytes!("../../tests/fixtures/WorkoutIndividualSteps.fit");
+        assert_eq!(peek_file_type(data).unwrap(), File::Workout);
+        // only the start of the file is needed
+        let data = include_bytes!("../../tests/fixtures/Settings.fit");
+        assert_eq!(peek_file_type(&data[..64]).unwrap(), File::Settings);
+        assert!(peek_file_type(&data[..8]).is_err());
+    }
+
+    #[test]
+    fn iter_truncated_file_errors() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let mut iter = from_reader_iter(&data[..data.len() - 10]);
+        assert!(iter.by_ref().any(|r| r.is_err()));
+        assert!(iter.next().is_none());
+    }
+}
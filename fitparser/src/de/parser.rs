@@ -1,7 +1,14 @@
 //! Helper functions and structures needed to parse a FIT file.
+use super::decode::is_timestamp_field;
+use crate::compat::prelude::*;
+use crate::compat::{Arc, HashMap, HashSet};
+use crate::profile::MesgNum;
 use crate::Value;
+use core::convert::From;
+use core::fmt::Display;
 use nom::bytes::streaming::{tag, take};
 use nom::combinator::cond;
+use nom::error::{Error, ErrorKind};
 use nom::multi::count;
 use nom::number::streaming::{
     f32, f64, i16, i32, i64, le_i8, le_u16, le_u32, le_u8, u16, u32, u64,
@@ -9,14 +16,11 @@ use nom::number::streaming::{
 use nom::number::Endianness;
 use nom::sequence::tuple;
 use nom::{Err, IResult, Needed};
-use std::collections::HashMap;
-use std::convert::From;
-use std::fmt::Display;
-use std::sync::Arc;
+use serde::{Deserialize, Serialize};
 
 /// Define an is_valid function needed for parsing here, this function is not needed for normal use
 impl Value {
-    fn is_valid(&self) -> bool {
+    pub(crate) fn is_valid(&self) -> bool {
         match self {
             Value::Enum(val) => *val != 0xFF,
             Value::SInt8(val) => *val != 0x7F,
@@ -26,7 +30,7 @@ impl Value {
             Value::SInt32(val) => *val != 0x7FFF_FFFF,
             Value::UInt32(val) => *val != 0xFFFF_FFFF,
             Value::String(val) => !val.contains('\0'),
-            Value::Timestamp(_) => true, // timestamps are always valid
+            Value::Timestamp(_) | Value::LocalTimestamp(_) => true, // timestamps are always valid
             Value::Float32(val) => val.is_finite(),
             Value::Float64(val) => val.is_finite(),
             Value::UInt8z(val) => *val != 0x0,
@@ -56,7 +60,7 @@ impl Value {
 /// data_size = u32
 /// literal ".FIT" = [u8; 4]
 /// CRC = u16 (if the header_size is 14 bytes)
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FitFileHeader {
     /// Length of header in bytes, should be either 12 or 14
     header_size: u8,
@@ -76,17 +80,17 @@ impl FitFileHeader {
         self.header_size
     }
 
-    /// Return the header size of the FIT file
+    /// Return the protocol version the FIT file was written with, e.g. 2.0
     pub fn protocol_ver_enc(&self) -> f32 {
         self.protocol_ver_enc
     }
 
-    /// Return the header size of the FIT file
+    /// Return the profile version the FIT file was written with, e.g. 21.94
     pub fn profile_ver_enc(&self) -> f32 {
         self.profile_ver_enc
     }
 
-    /// Return the header size of the FIT file
+    /// Return the length of the data records section declared by the header in bytes
     pub fn data_size(&self) -> u32 {
         self.data_size
     }
@@ -95,6 +99,18 @@ impl FitFileHeader {
     pub fn crc(&self) -> Option<u16> {
         self.crc
     }
+
+    /// Check if the header stores a CRC, 14 byte headers may set it to 0 when it wasn't
+    /// calculated
+    pub fn has_crc(&self) -> bool {
+        self.crc.is_some_and(|crc| crc != 0)
+    }
+
+    /// Check that the header size is one of the 12 or 14 bytes defined by the protocol, the size
+    /// is read from the file so it needs to be checked before it's used to slice the input
+    pub(crate) fn has_valid_size(&self) -> bool {
+        self.header_size == 12 || self.header_size == 14
+    }
 }
 
 /// Type of FIT message being read as specified by the header byte
@@ -129,7 +145,7 @@ pub enum FitMessage {
 /// contained in the record header, and a Global Message Number (mesg_num) that relates to the
 /// global FIT message. Although 1 byte is available for the number of fields and 1 byte is
 /// available for the field size, no single message may be defined that is larger than 255 bytes.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct FitDefinitionMessage {
     byte_order: Endianness,
     local_message_number: u8,
@@ -139,6 +155,23 @@ pub struct FitDefinitionMessage {
 }
 
 impl FitDefinitionMessage {
+    /// Create a definition message from its component parts
+    pub fn new(
+        byte_order: Endianness,
+        local_message_number: u8,
+        global_message_number: u16,
+        field_definitions: Vec<FieldDefinition>,
+        developer_field_definitions: Vec<DeveloperFieldDefinition>,
+    ) -> Self {
+        FitDefinitionMessage {
+            byte_order,
+            local_message_number,
+            global_message_number,
+            field_definitions,
+            developer_field_definitions,
+        }
+    }
+
     /// The byte order of the data fields in the associated FIT data message
     pub fn byte_order(&self) -> Endianness {
         self.byte_order
@@ -185,37 +218,166 @@ impl FitDefinitionMessage {
 /// particular local message type are considered to be using the format described by the definition
 /// message of matching local message type. All FIT messages and their respective FIT fields are
 /// listed in the global FIT profile. Each Field Definition consists of 3 bytes.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct FieldDefinition {
     field_definition_number: u8, //  could possibly be an enum (ie. field_type) but this is per-message type
     size: u8, // which might make things messy (i.e. umpteen different enums of enums)
     base_type: BaseType,
 }
 
+impl FieldDefinition {
+    /// Create a field definition, the size is the total number of bytes used by the field
+    pub fn new(field_definition_number: u8, size: u8, base_type: BaseType) -> Self {
+        FieldDefinition {
+            field_definition_number,
+            size,
+            base_type,
+        }
+    }
+
+    /// Field number defined in the FIT profile for the global message
+    pub fn field_definition_number(&self) -> u8 {
+        self.field_definition_number
+    }
+
+    /// Size of the field in bytes
+    pub fn size(&self) -> u8 {
+        self.size
+    }
+
+    /// Base type used to store the field value
+    pub fn base_type(&self) -> BaseType {
+        self.base_type
+    }
+}
+
 /// Developer data fields allow for files to define the meaning of data without requiring changes to
 /// the FIT profile being used. Rather than having information like Field Name, Units, and Base Type
 /// encoded into the profile this information is included in 2 special global messages that act as
 /// meta-data for the decode process. The developer data field description is used to map data
 /// within a data message to the appropriate meta-data.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DeveloperFieldDefinition {
     field_number: u8,
     size: u8,
     developer_data_index: u8,
 }
 
+impl DeveloperFieldDefinition {
+    /// Create a developer field definition
+    pub fn new(field_number: u8, size: u8, developer_data_index: u8) -> Self {
+        DeveloperFieldDefinition {
+            field_number,
+            size,
+            developer_data_index,
+        }
+    }
+
+    /// Field number used to look up the matching field description message
+    pub fn field_number(&self) -> u8 {
+        self.field_number
+    }
+
+    /// Size of the developer field in bytes
+    pub fn size(&self) -> u8 {
+        self.size
+    }
+
+    /// Developer data index used to look up the matching developer data ID message
+    pub fn developer_data_index(&self) -> u8 {
+        self.developer_data_index
+    }
+}
+
+/// Raw bytes of a developer field stored in a data message. The bytes are converted into a value
+/// using the base type defined in the matching field description message.
+#[derive(Clone, Debug)]
+pub struct DeveloperFieldData {
+    definition: DeveloperFieldDefinition,
+    byte_order: Endianness,
+    data: Vec<u8>,
+}
+
+impl DeveloperFieldData {
+    /// Create the developer field from its definition and the raw bytes of its value
+    pub(crate) fn new(
+        definition: DeveloperFieldDefinition,
+        byte_order: Endianness,
+        data: Vec<u8>,
+    ) -> Self {
+        DeveloperFieldData {
+            definition,
+            byte_order,
+            data,
+        }
+    }
+
+    /// Definition of the developer field within the definition message
+    pub fn definition(&self) -> &DeveloperFieldDefinition {
+        &self.definition
+    }
+
+    /// Raw bytes of the field
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Convert the raw bytes into a value of the provided base type, None is returned if the
+    /// value is invalid or the size of the data doesn't agree with the base type.
+    pub fn value(&self, base_type: BaseType) -> Option<Value> {
+        data_field_value(
+            &self.data,
+            base_type,
+            self.byte_order,
+            self.data.len() as u8,
+        )
+        .ok()
+        .and_then(|(_, v)| v)
+    }
+}
+
 /// Stores a vector of raw fields described by the preceding Definition message, a Definition message
 /// must come before any Data message. The data here will be transfomed into a FitDataRecord using
 /// the information from its defintion message and the MessageInfo struct from the FIT profile
 #[derive(Clone, Debug)]
 pub struct FitDataMessage {
+    local_message_number: u8,
     global_message_number: u16,
     time_offset: Option<u8>,
     fields: HashMap<u8, Value>,
-    developer_fields: Vec<Value>,
+    developer_fields: Vec<DeveloperFieldData>,
+    position: Option<usize>,
+    index: Option<usize>,
 }
 
 impl FitDataMessage {
+    /// Create a data message from a mapping of <field_number, Value>
+    pub fn new(global_message_number: u16, fields: HashMap<u8, Value>) -> Self {
+        FitDataMessage {
+            local_message_number: 0,
+            global_message_number,
+            time_offset: None,
+            fields,
+            developer_fields: Vec::new(),
+            position: None,
+            index: None,
+        }
+    }
+
+    /// Add the raw developer fields written after the fields of the message
+    pub(crate) fn with_developer_fields(
+        mut self,
+        developer_fields: Vec<DeveloperFieldData>,
+    ) -> Self {
+        self.developer_fields = developer_fields;
+        self
+    }
+
+    /// Local message number of the definition message used to parse the data
+    pub fn local_message_number(&self) -> u8 {
+        self.local_message_number
+    }
+
     /// Global message number defined in the FIT profile, set by definition message
     pub fn global_message_number(&self) -> u16 {
         self.global_message_number
@@ -236,39 +398,72 @@ impl FitDataMessage {
         &mut self.fields
     }
 
-    #[allow(dead_code)]
     /// Developer field data
-    pub fn developer_fields(&self) -> &[Value] {
+    pub fn developer_fields(&self) -> &[DeveloperFieldData] {
         &self.developer_fields
     }
+
+    /// Byte offset of the message from the start of the stream, None if the message wasn't
+    /// deserialized from a stream
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// Index of the message among the data messages of the stream, counting from 0
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Store where the message was read from
+    pub(super) fn set_location(&mut self, position: usize, index: usize) {
+        self.position = Some(position);
+        self.index = Some(index);
+    }
 }
 
 /// Base types defined by the FIT protocol. The "z" variants have a different invalid value
 /// than the versions without the suffix.
-#[derive(Clone, Copy, Debug)]
-enum BaseType {
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BaseType {
+    /// Enumerated value stored in a single byte
     Enum = 0x00,
+    /// Signed 8 bit integer
     SInt8 = 0x01,
+    /// Unsigned 8 bit integer
     UInt8 = 0x02,
+    /// Signed 16 bit integer
     SInt16 = 0x83,
+    /// Unsigned 16 bit integer
     UInt16 = 0x84,
+    /// Signed 32 bit integer
     SInt32 = 0x85,
+    /// Unsigned 32 bit integer
     UInt32 = 0x86,
+    /// Null terminated UTF-8 string
     String = 0x07,
+    /// 32 bit floating point value
     Float32 = 0x88,
+    /// 64 bit floating point value
     Float64 = 0x89,
+    /// Unsigned 8 bit integer with an invalid value of 0
     UInt8z = 0x0A,
+    /// Unsigned 16 bit integer with an invalid value of 0
     UInt16z = 0x8B,
+    /// Unsigned 32 bit integer with an invalid value of 0
     UInt32z = 0x8C,
+    /// Array of bytes
     Byte = 0x0D,
+    /// Signed 64 bit integer
     SInt64 = 0x8E,
+    /// Unsigned 64 bit integer
     UInt64 = 0x8F,
+    /// Unsigned 64 bit integer with an invalid value of 0
     UInt64z = 0x90,
 }
 
 impl BaseType {
     /// The size for fixed width numeric values, for variable Llength types it's the smallest chunk size.
-    fn size(&self) -> u8 {
+    pub fn size(&self) -> u8 {
         match *self {
             BaseType::Enum => 1,
             BaseType::SInt8 => 1,
@@ -328,7 +523,9 @@ pub fn fit_file_header(input: &[u8]) -> IResult<&[u8], FitFileHeader> {
         Err(Err::Incomplete(_)) => {
             // output a correct "needed" value, assume 14 bytes as default since that's preferred
             Err(Err::Incomplete(Needed::new(
-                input.first().map_or(14, |v| *v as usize - input.len()),
+                input
+                    .first()
+                    .map_or(14, |v| (*v as usize).saturating_sub(input.len())),
             )))
         }
         Err(r) => Err(r),
@@ -363,26 +560,27 @@ fn split_decimal_to_float<T: Display>(left: T, right: T) -> f32 {
     format!("{}.{}", left, right).parse().unwrap()
 }
 
-/// Parse a FIT data or definition message
+/// Parse a FIT data or definition message. When a message filter is provided the fields of data
+/// messages with a global message number outside of the filter are skipped, except for the
+/// timestamps which are needed to decode the compressed and partial timestamps of later messages.
 pub fn fit_message<'a>(
     input: &'a [u8],
     definitions: &HashMap<u8, Arc<FitDefinitionMessage>>,
+    message_filter: Option<&HashSet<u16>>,
 ) -> IResult<&'a [u8], FitMessage> {
     // parse a single message of either variety
     let (input, header) = message_header(input)?;
     match header.message_type {
         FitMessageType::Data => {
             if let Some(def_mesg) = definitions.get(&header.local_message_number) {
-                let (input, (fields, developer_fields)) = data_message_fields(input, def_mesg)?;
-                Ok((
-                    input,
-                    FitMessage::Data(FitDataMessage {
-                        fields,
-                        developer_fields,
-                        global_message_number: def_mesg.global_message_number,
-                        time_offset: header.time_offset,
-                    }),
-                ))
+                // developer field descriptions are always parsed since they are needed to decode
+                // the developer fields of any message that is kept
+                let global_message_number = def_mesg.global_message_number;
+                let skip = message_filter.is_some_and(|f| !f.contains(&global_message_number))
+                    && global_message_number != MesgNum::FieldDescription.as_u16()
+                    && global_message_number != MesgNum::DeveloperDataId.as_u16();
+                let (input, message) = data_message_body(input, &header, def_mesg, skip)?;
+                Ok((input, FitMessage::Data(message)))
             } else {
                 // this is technically is an Error but nom can't represent it well
                 Ok((
@@ -398,6 +596,42 @@ pub fn fit_message<'a>(
     }
 }
 
+/// Parse a single FIT data message using the definition message it was written with
+pub fn data_message<'a>(
+    input: &'a [u8],
+    def_mesg: &FitDefinitionMessage,
+) -> IResult<&'a [u8], FitDataMessage> {
+    let (input, header) = message_header(input)?;
+    if header.message_type != FitMessageType::Data
+        || header.local_message_number != def_mesg.local_message_number
+    {
+        return Err(Err::Error(Error::new(input, ErrorKind::Verify)));
+    }
+    data_message_body(input, &header, def_mesg, false)
+}
+
+/// Parse the fields of a data message following its header
+fn data_message_body<'a>(
+    input: &'a [u8],
+    header: &FitMessageHeader,
+    def_mesg: &FitDefinitionMessage,
+    skip: bool,
+) -> IResult<&'a [u8], FitDataMessage> {
+    let (input, (fields, developer_fields)) = data_message_fields(input, def_mesg, skip)?;
+    Ok((
+        input,
+        FitDataMessage {
+            fields,
+            developer_fields,
+            local_message_number: header.local_message_number,
+            global_message_number: def_mesg.global_message_number,
+            time_offset: header.time_offset,
+            position: None,
+            index: None,
+        },
+    ))
+}
+
 /// Parse the header of a single FIT message
 fn message_header(input: &[u8]) -> IResult<&[u8], FitMessageHeader> {
     let (input, msg_header_byte) = le_u8(input)?;
@@ -480,8 +714,8 @@ fn field_definition(input: &[u8]) -> IResult<&[u8], FieldDefinition> {
     // byte array since the field value is undefined. This prevents a potential add-overflow
     // panic in the `data_field_value` function.
     if size % base_type.size() != 0 {
-        eprintln!(
-            "ERROR: field size: {} is not a multiple of the base type {:?} (size {}) parsing as a byte array",
+        log::warn!(
+            "field size: {} is not a multiple of the base type {:?} (size {}) parsing as a byte array",
             size,
             base_type,
             base_type.size()
@@ -519,13 +753,14 @@ fn developer_field_definition(input: &[u8]) -> IResult<&[u8], DeveloperFieldDefi
 fn data_message_fields<'a>(
     input: &'a [u8],
     def_mesg: &FitDefinitionMessage,
-) -> IResult<&'a [u8], (HashMap<u8, Value>, Vec<Value>)> {
-    match data_message_fields_impl(input, def_mesg) {
+    skip: bool,
+) -> IResult<&'a [u8], (HashMap<u8, Value>, Vec<DeveloperFieldData>)> {
+    match data_message_fields_impl(input, def_mesg, skip) {
         Ok(r) => Ok(r),
         Err(Err::Incomplete(_)) => {
             // output a correct "needed" value, subtract one because we've already parsed the header
             Err(Err::Incomplete(Needed::new(
-                def_mesg.data_message_size() as usize - input.len() - 1,
+                def_mesg.data_message_size() - input.len() - 1,
             )))
         }
         Err(r) => Err(r),
@@ -533,15 +768,23 @@ fn data_message_fields<'a>(
 }
 
 /// Function to actually parse the data fields, the public function wraps incomplete errors to provide
-/// an accurate number for the bytes "needed" if we hit an incomplete error
+/// an accurate number for the bytes "needed" if we hit an incomplete error. Skipped messages only
+/// parse the timestamp fields, including the partial `timestamp_16` and `timestamp_min_8`.
 #[allow(clippy::type_complexity)]
 fn data_message_fields_impl<'a>(
     input: &'a [u8],
     def_mesg: &FitDefinitionMessage,
-) -> IResult<&'a [u8], (HashMap<u8, Value>, Vec<Value>)> {
+    skip: bool,
+) -> IResult<&'a [u8], (HashMap<u8, Value>, Vec<DeveloperFieldData>)> {
     let mut fields = HashMap::new();
     let mut input = input;
+    let mesg_num = MesgNum::from(def_mesg.global_message_number);
     for field_def in &def_mesg.field_definitions {
+        if skip && !is_timestamp_field(mesg_num, field_def.field_definition_number) {
+            let (i, _) = take(field_def.size)(input)?;
+            input = i;
+            continue;
+        }
         let (i, value) = data_field_value(
             input,
             field_def.base_type,
@@ -553,25 +796,58 @@ fn data_message_fields_impl<'a>(
         }
         input = i;
     }
-    // store developer data as a byte array since we don't handle these fields in the final data
-    // output yet
+    // store developer data as raw bytes since the base type is defined by a field description
+    // message which is handled by the decoder
     let mut developer_fields = Vec::new();
+    if skip {
+        let size = def_mesg
+            .developer_field_definitions
+            .iter()
+            .fold(0, |l, f| l + f.size as usize);
+        let (input, _) = take(size)(input)?;
+        return Ok((input, (fields, developer_fields)));
+    }
     for field_def in &def_mesg.developer_field_definitions {
-        let (i, value) = data_field_value(
-            input,
-            BaseType::Byte,
-            def_mesg.byte_order, // TODO: I don't know how to handle this since byte swapping
-            field_def.size,      // the whole thing as needed might not be valid if the field isn't
-        )?; // a single integer value.
-        if let Some(value) = value {
-            developer_fields.push(value);
-        }
+        let (i, data) = take(field_def.size)(input)?;
+        developer_fields.push(DeveloperFieldData {
+            definition: field_def.clone(),
+            byte_order: def_mesg.byte_order,
+            data: data.to_vec(),
+        });
         input = i;
     }
 
     Ok((input, (fields, developer_fields)))
 }
 
+/// Parse a single value of a numeric base type, string fields are parsed as bytes since their
+/// length is defined by the field size.
+pub(crate) fn base_type_value(
+    input: &[u8],
+    base_type: BaseType,
+    byte_order: Endianness,
+) -> IResult<&[u8], Value> {
+    match base_type {
+        BaseType::Enum => le_u8(input).map(|(i, v)| (i, Value::Enum(v))),
+        BaseType::SInt8 => le_i8(input).map(|(i, v)| (i, Value::SInt8(v))),
+        BaseType::UInt8 => le_u8(input).map(|(i, v)| (i, Value::UInt8(v))),
+        BaseType::SInt16 => i16(byte_order)(input).map(|(i, v)| (i, Value::SInt16(v))),
+        BaseType::UInt16 => u16(byte_order)(input).map(|(i, v)| (i, Value::UInt16(v))),
+        BaseType::SInt32 => i32(byte_order)(input).map(|(i, v)| (i, Value::SInt32(v))),
+        BaseType::UInt32 => u32(byte_order)(input).map(|(i, v)| (i, Value::UInt32(v))),
+        BaseType::String => le_u8(input).map(|(i, v)| (i, Value::UInt8(v))),
+        BaseType::Float32 => f32(byte_order)(input).map(|(i, v)| (i, Value::Float32(v))),
+        BaseType::Float64 => f64(byte_order)(input).map(|(i, v)| (i, Value::Float64(v))),
+        BaseType::UInt8z => le_u8(input).map(|(i, v)| (i, Value::UInt8z(v))),
+        BaseType::UInt16z => u16(byte_order)(input).map(|(i, v)| (i, Value::UInt16z(v))),
+        BaseType::UInt32z => u32(byte_order)(input).map(|(i, v)| (i, Value::UInt32z(v))),
+        BaseType::Byte => le_u8(input).map(|(i, v)| (i, Value::UInt8(v))),
+        BaseType::SInt64 => i64(byte_order)(input).map(|(i, v)| (i, Value::SInt64(v))),
+        BaseType::UInt64 => u64(byte_order)(input).map(|(i, v)| (i, Value::UInt64(v))),
+        BaseType::UInt64z => u64(byte_order)(input).map(|(i, v)| (i, Value::UInt64z(v))),
+    }
+}
+
 /// Parse a single raw data value.
 ///
 /// This can panic if the size is greater than `255 - base_type.size()` but that should only
@@ -588,41 +864,24 @@ fn data_field_value(
     let mut values: Vec<Value> = Vec::new();
 
     while bytes_consumed < size {
-        let (i, value) = match base_type {
-            BaseType::Enum => le_u8(input).map(|(i, v)| (i, Value::Enum(v)))?,
-            BaseType::SInt8 => le_i8(input).map(|(i, v)| (i, Value::SInt8(v)))?,
-            BaseType::UInt8 => le_u8(input).map(|(i, v)| (i, Value::UInt8(v)))?,
-            BaseType::SInt16 => i16(byte_order)(input).map(|(i, v)| (i, Value::SInt16(v)))?,
-            BaseType::UInt16 => u16(byte_order)(input).map(|(i, v)| (i, Value::UInt16(v)))?,
-            BaseType::SInt32 => i32(byte_order)(input).map(|(i, v)| (i, Value::SInt32(v)))?,
-            BaseType::UInt32 => u32(byte_order)(input).map(|(i, v)| (i, Value::UInt32(v)))?,
-            BaseType::String => {
-                // consume the field as defined by its size and then locate the first NUL byte
-                // and ignore everything after it when converting to a string
-                let (input, field_value) = take(size as usize)(input)?;
-                let mut value = Vec::new();
-                for char in field_value {
-                    if *char == 0u8 {
-                        break;
-                    }
-                    value.push(*char);
-                }
-                if let Ok(value) = String::from_utf8(value) {
-                    return Ok((input, Some(Value::String(value))));
-                } else {
-                    return Ok((input, None));
+        if base_type == BaseType::String {
+            // consume the field as defined by its size and then locate the first NUL byte
+            // and ignore everything after it when converting to a string
+            let (input, field_value) = take(size as usize)(input)?;
+            let mut value = Vec::new();
+            for char in field_value {
+                if *char == 0u8 {
+                    break;
                 }
+                value.push(*char);
             }
-            BaseType::Float32 => f32(byte_order)(input).map(|(i, v)| (i, Value::Float32(v)))?,
-            BaseType::Float64 => f64(byte_order)(input).map(|(i, v)| (i, Value::Float64(v)))?,
-            BaseType::UInt8z => le_u8(input).map(|(i, v)| (i, Value::UInt8z(v)))?,
-            BaseType::UInt16z => u16(byte_order)(input).map(|(i, v)| (i, Value::UInt16z(v)))?,
-            BaseType::UInt32z => u32(byte_order)(input).map(|(i, v)| (i, Value::UInt32z(v)))?,
-            BaseType::Byte => le_u8(input).map(|(i, v)| (i, Value::UInt8(v)))?,
-            BaseType::SInt64 => i64(byte_order)(input).map(|(i, v)| (i, Value::SInt64(v)))?,
-            BaseType::UInt64 => u64(byte_order)(input).map(|(i, v)| (i, Value::UInt64(v)))?,
-            BaseType::UInt64z => u64(byte_order)(input).map(|(i, v)| (i, Value::UInt64z(v)))?,
-        };
+            if let Ok(value) = String::from_utf8(value) {
+                return Ok((input, Some(Value::String(value))));
+            } else {
+                return Ok((input, None));
+            }
+        }
+        let (i, value) = base_type_value(input, base_type, byte_order)?;
         bytes_consumed += base_type.size();
         values.push(value);
         input = i;
@@ -660,6 +919,18 @@ mod tests {
         assert_eq!(hdr.profile_ver_enc, 1.0);
         assert_eq!(hdr.data_size, 757);
         assert_eq!(hdr.crc, None);
+        assert!(!hdr.has_crc());
+
+        // 14 byte headers may store a CRC of 0 when it isn't calculated
+        let mut data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit").to_vec();
+        let (_, hdr) = fit_file_header(&data).unwrap();
+        assert_eq!(hdr.header_size, 14);
+        assert!(hdr.has_crc());
+        data[12] = 0x00;
+        data[13] = 0x00;
+        let (_, hdr) = fit_file_header(&data).unwrap();
+        assert_eq!(hdr.crc, Some(0));
+        assert!(!hdr.has_crc());
     }
 
     #[test]
@@ -668,7 +939,7 @@ mod tests {
         let sl = &data[12..];
         let (_, hdr) = message_header(sl).unwrap();
 
-        assert_eq!(hdr.contains_developer_data, false);
+        assert!(!hdr.contains_developer_data);
         assert_eq!(hdr.local_message_number, 0);
         assert_eq!(hdr.message_type, FitMessageType::Definition);
         assert_eq!(hdr.time_offset, None);
@@ -682,32 +953,31 @@ mod tests {
         let (rem, val) = data_field_value(&data, BaseType::UInt8, Endianness::Native, 1).unwrap();
         match val {
             Some(v) => assert_eq!(v, Value::UInt8(0x01)),
-            None => assert!(false, "No value returned."),
+            None => panic!("No value returned."),
         }
         assert_eq!(rem, &[0xFF]);
 
         // parse off an invalid byte
         let (rem, val) = data_field_value(rem, BaseType::UInt8, Endianness::Native, 1).unwrap();
-        match val {
-            Some(_) => assert!(false, "None should be returned for invalid bytes."),
-            None => {}
+        if val.is_some() {
+            panic!("None should be returned for invalid bytes.");
         }
-        assert_eq!(rem, &[]);
+        assert!(rem.is_empty());
 
         // parse two byte values with defined endianess
         let (rem, val) = data_field_value(&data, BaseType::UInt16, Endianness::Big, 2).unwrap();
         match val {
             Some(v) => assert_eq!(v, Value::UInt16(0x01FF)),
-            None => assert!(false, "No value returned."),
+            None => panic!("No value returned."),
         }
-        assert_eq!(rem, &[]);
+        assert!(rem.is_empty());
 
         let (rem, val) = data_field_value(&data, BaseType::UInt16, Endianness::Little, 2).unwrap();
         match val {
             Some(v) => assert_eq!(v, Value::UInt16(0xFF01)),
-            None => assert!(false, "No value returned."),
+            None => panic!("No value returned."),
         }
-        assert_eq!(rem, &[]);
+        assert!(rem.is_empty());
     }
 
     #[test]
@@ -726,26 +996,21 @@ mod tests {
                     Value::UInt8(0x03)
                 ])
             ),
-            None => assert!(false, "No value returned."),
+            None => panic!("No value returned."),
         }
         assert_eq!(rem, &[0xFF]);
 
         // parse off an invalid byte
         let (rem, val) = data_field_value(&data, BaseType::UInt8, Endianness::Native, 5).unwrap();
-        match val {
-            Some(_) => assert!(false, "None should be returned for invalid bytes."),
-            None => {}
+        if val.is_some() {
+            panic!("None should be returned for invalid bytes.");
         }
-        assert_eq!(rem, &[]);
+        assert!(rem.is_empty());
 
-        match val {
-            Some(_) => assert!(
-                false,
-                "None should be returned for array with an invalid size."
-            ),
-            None => {}
+        if val.is_some() {
+            panic!("None should be returned for array with an invalid size.");
         }
-        assert_eq!(rem, &[]);
+        assert!(rem.is_empty());
     }
 
     #[test]
@@ -756,16 +1021,15 @@ mod tests {
         let (rem, val) = data_field_value(&data, BaseType::String, Endianness::Native, 8).unwrap();
         match val {
             Some(v) => assert_eq!(v, Value::String(String::from("GARMIN"))),
-            None => assert!(false, "No value returned."),
+            None => panic!("No value returned."),
         }
         assert_eq!(rem, &[0xFF]);
 
         // parse invalid UTF8 string
         let data = [71, 195, 40, 77, 73, 78, 0, 63, 255];
         let (rem, val) = data_field_value(&data, BaseType::String, Endianness::Native, 8).unwrap();
-        match val {
-            Some(_) => assert!(false, "None should be returned for invalid string."),
-            None => {}
+        if val.is_some() {
+            panic!("None should be returned for invalid string.");
         }
         assert_eq!(rem, &[0xFF]);
 
@@ -774,7 +1038,7 @@ mod tests {
         let (rem, val) = data_field_value(&data, BaseType::String, Endianness::Native, 8).unwrap();
         match val {
             Some(v) => assert_eq!(v, Value::String(String::from("GARM"))),
-            None => assert!(false, "No value returned."),
+            None => panic!("No value returned."),
         }
         assert_eq!(rem, &[0xFF]);
     }
@@ -784,9 +1048,6 @@ mod tests {
     fn data_field_value_test_size_mismatch_array_value() {
         // try and parse an array with a size that isn't a multiple of the base type
         let data: Vec<u8> = (0..=255).collect();
-        match data_field_value(&data, BaseType::UInt16, Endianness::Native, 255) {
-            Ok(..) => {}
-            Err(..) => {}
-        };
+        let _ = data_field_value(&data, BaseType::UInt16, Endianness::Native, 255);
     }
 }
@@ -0,0 +1,135 @@
+//! Bytes of the data messages records are decoded from, kept when using
+//! `DecodeOption::KeepRawBytes` so debugging tools and custom decoders can re-interpret fields
+//! the profile doesn't describe correctly.
+use super::parser::FitDefinitionMessage;
+use crate::compat::prelude::*;
+use crate::compat::Arc;
+
+/// Bytes of a data message along with the definition message used to parse it
+#[derive(Clone, Debug, PartialEq)]
+pub struct RawMessage {
+    position: usize,
+    bytes: Vec<u8>,
+    definition: Arc<FitDefinitionMessage>,
+}
+
+impl RawMessage {
+    pub(super) fn new(
+        position: usize,
+        bytes: Vec<u8>,
+        definition: Arc<FitDefinitionMessage>,
+    ) -> Self {
+        RawMessage {
+            position,
+            bytes,
+            definition,
+        }
+    }
+
+    /// Return the byte offset of the message from the start of the stream
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Return the bytes of the message, starting with the message header byte
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Return the number of bytes of the message including the header byte
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Check if the message doesn't store any bytes, this is never the case for parsed messages
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Return the definition message describing the fields of the message
+    pub fn definition(&self) -> &FitDefinitionMessage {
+        &self.definition
+    }
+
+    /// Return the bytes of a field using its definition number, the bytes are stored in the byte
+    /// order of the definition message
+    pub fn field_bytes(&self, number: u8) -> Option<&[u8]> {
+        let mut start = 1;
+        for field in self.definition.field_definitions() {
+            let end = start + field.size() as usize;
+            if field.field_definition_number() == number {
+                return self.bytes.get(start..end);
+            }
+            start = end;
+        }
+        None
+    }
+
+    /// Return the bytes of a developer field using its developer data index and field number
+    pub fn developer_field_bytes(&self, developer_data_index: u8, number: u8) -> Option<&[u8]> {
+        let mut start = 1 + self
+            .definition
+            .field_definitions()
+            .iter()
+            .map(|f| f.size() as usize)
+            .sum::<usize>();
+        for field in self.definition.developer_field_definitions() {
+            let end = start + field.size() as usize;
+            if field.developer_data_index() == developer_data_index
+                && field.field_number() == number
+            {
+                return self.bytes.get(start..end);
+            }
+            start = end;
+        }
+        None
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::compat::HashSet;
+    use crate::de::{from_bytes, from_bytes_with_options, DecodeOption};
+    use crate::profile::MesgNum;
+    use crate::Value;
+    use nom::number::Endianness;
+
+    #[test]
+    fn keep_raw_bytes() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        assert!(from_bytes(data).unwrap()[0].raw_message().is_none());
+
+        let options: HashSet<_> = [DecodeOption::KeepRawBytes].iter().copied().collect();
+        let mut records = from_bytes_with_options(data, &options).unwrap();
+        for record in &records {
+            let raw = record.raw_message().unwrap();
+            assert_eq!(raw.len(), raw.definition().data_message_size());
+            assert_eq!(
+                &data[raw.position()..raw.position() + raw.len()],
+                raw.bytes()
+            );
+        }
+
+        // latitudes are stored as a signed 32 bit number of semicircles in the byte order of the
+        // definition message
+        let record = records
+            .iter_mut()
+            .find(|r| r.kind() == MesgNum::Record)
+            .unwrap();
+        let raw = record.raw_message().unwrap().clone();
+        let bytes = raw.field_bytes(0).unwrap();
+        let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+        let latitude = match raw.definition().byte_order() {
+            Endianness::Big => i32::from_be_bytes(bytes),
+            _ => i32::from_le_bytes(bytes),
+        };
+        let value = record.field("position_lat").unwrap().value();
+        assert_eq!(value, &Value::SInt32(latitude));
+        assert!(raw.field_bytes(200).is_none());
+        assert!(raw.developer_field_bytes(0, 0).is_none());
+
+        // the bytes are kept when the record is modified
+        record.remove_field("position_lat");
+        assert_eq!(record.raw_message(), Some(&raw));
+    }
+}
@@ -0,0 +1,245 @@
+//! Repair damaged FIT files by rewriting the header data size and checksums, truncating bytes
+//! that can't be parsed and optionally dropping data messages that fail to decode.
+use super::decode::Decoder;
+use super::parser::{self, FitMessage};
+use super::{caculate_crc, update_crc};
+use crate::compat::prelude::*;
+use crate::compat::{Arc, HashMap, HashSet};
+use crate::encode::writer;
+use crate::error::{ErrorKind, Result};
+
+/// Describes the changes made to a FIT file by `repair`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RepairSummary {
+    file_count: usize,
+    dropped_messages: usize,
+    truncated_bytes: usize,
+}
+
+impl RepairSummary {
+    /// Number of FIT files written, chained FIT files are repaired separately
+    pub fn file_count(&self) -> usize {
+        self.file_count
+    }
+
+    /// Number of data messages removed because they couldn't be decoded
+    pub fn dropped_messages(&self) -> usize {
+        self.dropped_messages
+    }
+
+    /// Number of bytes removed from the end of the buffer because they couldn't be parsed
+    pub fn truncated_bytes(&self) -> usize {
+        self.truncated_bytes
+    }
+}
+
+/// Repair a FIT file stored as an array of bytes, returning the repaired file along with a
+/// summary of the changes. The messages of each file are parsed until the end of the buffer or
+/// the first message that can't be parsed, the remaining bytes are discarded and the header data
+/// size and checksums are recalculated. Data messages that fail to decode also truncate the file
+/// unless `drop_invalid_records` is set, in which case only that message is removed.
+///
+/// An error is only returned if the buffer doesn't start with a valid FIT header, including a
+/// header size other than 12 or 14 bytes.
+pub fn repair(buffer: &[u8], drop_invalid_records: bool) -> Result<(Vec<u8>, RepairSummary)> {
+    let mut output = Vec::with_capacity(buffer.len());
+    let mut summary = RepairSummary::default();
+    let mut start = 0;
+    while start < buffer.len() {
+        let input = &buffer[start..];
+        let (remaining, header) = match parser::fit_file_header(input) {
+            Ok(result) => result,
+            // anything following a repaired file that isn't a FIT file is trailing garbage
+            Err(_) if summary.file_count > 0 => break,
+            Err(nom::Err::Error(err)) | Err(nom::Err::Failure(err)) => {
+                return Err(ErrorKind::ParseError(start, err.code).into())
+            }
+            Err(nom::Err::Incomplete(needed)) => {
+                return Err(ErrorKind::UnexpectedEof(needed).into())
+            }
+        };
+        // the layout of the file can't be trusted when the header size is damaged
+        if !header.has_valid_size() {
+            if summary.file_count > 0 {
+                break;
+            }
+            return Err(ErrorKind::ParseError(start, nom::error::ErrorKind::Verify).into());
+        }
+        let file = FileLayout {
+            header_size: input.len() - remaining.len(),
+            data_size: header.data_size() as usize,
+            has_header_crc: header.crc().unwrap_or(0) > 0,
+        };
+        let (messages, length, dropped) = file.repair_messages(input, drop_invalid_records);
+
+        // keep the protocol and profile versions of the original header
+        let mut output_file = writer::fit_file_header(
+            input[1],
+            u16::from_le_bytes([input[2], input[3]]),
+            messages.len() as u32,
+        );
+        // a header CRC of 0 is treated as missing so the header is included in the data CRC
+        let crc = if output_file[12..14] == [0, 0] {
+            update_crc(update_crc(0, &output_file), &messages)
+        } else {
+            update_crc(0, &messages)
+        };
+        output_file.extend_from_slice(&messages);
+        output_file.extend_from_slice(&crc.to_le_bytes());
+        output.extend_from_slice(&output_file);
+
+        summary.file_count += 1;
+        summary.dropped_messages += dropped;
+        start += length;
+    }
+    summary.truncated_bytes = buffer.len() - start;
+
+    Ok((output, summary))
+}
+
+/// Layout of a FIT file as described by its header
+struct FileLayout {
+    header_size: usize,
+    data_size: usize,
+    has_header_crc: bool,
+}
+
+impl FileLayout {
+    /// Parse the messages of the file returning the bytes to keep, the number of bytes consumed
+    /// including the header and CRC and the number of data messages dropped.
+    fn repair_messages(&self, input: &[u8], drop_invalid_records: bool) -> (Vec<u8>, usize, usize) {
+        let mut definitions = HashMap::new();
+        let mut decoder = Decoder::new();
+        let mut messages = Vec::new();
+        let mut dropped = 0;
+        let mut position = self.header_size;
+        while position < input.len() {
+            if self.is_end_of_file(input, position) {
+                return (messages, position + 2, dropped);
+            }
+            let (remaining, message) =
+                match parser::fit_message(&input[position..], &definitions, None) {
+                    Ok(result) => result,
+                    Err(_) => break,
+                };
+            let length = input.len() - position - remaining.len();
+            match message {
+                FitMessage::Definition(definition) => {
+                    definitions.insert(definition.local_message_number(), Arc::new(definition));
+                    messages.extend_from_slice(&input[position..position + length]);
+                }
+                FitMessage::Data(message) => match decoder.decode_message(message, &HashSet::new())
+                {
+                    Ok(_) => messages.extend_from_slice(&input[position..position + length]),
+                    Err(_) if drop_invalid_records => dropped += 1,
+                    Err(_) => break,
+                },
+                FitMessage::MissingDefinitionMessage(_) => break,
+            }
+            position += length;
+        }
+
+        (messages, position, dropped)
+    }
+
+    /// Check if the messages end at the position and are followed by the data CRC. Since the data
+    /// size of damaged files can't be trusted the end is also found by another FIT file following
+    /// the CRC, or a valid CRC at the end of the buffer.
+    fn is_end_of_file(&self, input: &[u8], position: usize) -> bool {
+        let stored = match input.get(position..position + 2) {
+            Some(crc) => u16::from_le_bytes([crc[0], crc[1]]),
+            None => return false,
+        };
+        let next = &input[position + 2..];
+        if parser::fit_file_header(next).is_ok() {
+            return true;
+        }
+        let at_data_size = position == self.header_size + self.data_size;
+        if !at_data_size && !next.is_empty() {
+            return false;
+        }
+        // the header is only included in the data CRC if it doesn't have its own CRC
+        let crc = if self.has_header_crc {
+            caculate_crc(&input[self.header_size..position])
+        } else {
+            caculate_crc(&input[..position])
+        };
+        stored == crc || (at_data_size && next.is_empty())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+
+    #[test]
+    fn repair_valid_file() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let (repaired, summary) = repair(data, false).unwrap();
+        assert_eq!(summary.file_count(), 1);
+        assert_eq!(summary.dropped_messages(), 0);
+        assert_eq!(summary.truncated_bytes(), 0);
+        assert_eq!(
+            from_bytes(&repaired).unwrap().len(),
+            from_bytes(data).unwrap().len()
+        );
+    }
+
+    #[test]
+    fn repair_truncated_file() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let expected = from_bytes(data).unwrap();
+        // cut the file in the middle of a message and append some garbage
+        let mut damaged = data[..data.len() - 10].to_vec();
+        damaged.extend_from_slice(&[0xFF; 5]);
+        assert!(from_bytes(&damaged).is_err());
+
+        let (repaired, summary) = repair(&damaged, false).unwrap();
+        assert_eq!(summary.file_count(), 1);
+        assert!(summary.truncated_bytes() > 5);
+        let records = from_bytes(&repaired).unwrap();
+        assert_eq!(records.len(), expected.len() - 1);
+        assert_eq!(records[0].kind(), expected[0].kind());
+
+        assert!(repair(&damaged[14..], false).is_err());
+    }
+
+    #[test]
+    fn repair_damaged_header_size() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        for header_size in [0, 1, 0xFF] {
+            let mut damaged = data.to_vec();
+            damaged[0] = header_size;
+            assert!(repair(&damaged, false).is_err());
+
+            // a damaged header after a repaired file is treated as trailing garbage
+            let mut chained = data.to_vec();
+            chained.extend_from_slice(&damaged);
+            let (_, summary) = repair(&chained, false).unwrap();
+            assert_eq!(summary.file_count(), 1);
+            assert_eq!(summary.truncated_bytes(), data.len());
+        }
+    }
+
+    #[test]
+    fn repair_chained_file() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let mut chained = data.to_vec();
+        // zero out the data size and CRC of the first file
+        chained[4..8].copy_from_slice(&[0; 4]);
+        let last = chained.len() - 2;
+        chained[last..].copy_from_slice(&[0; 2]);
+        chained.extend_from_slice(data);
+
+        let (repaired, summary) = repair(&chained, false).unwrap();
+        assert_eq!(summary.file_count(), 2);
+        assert_eq!(summary.truncated_bytes(), 0);
+        // the 12 byte headers are replaced with 14 byte headers
+        assert_eq!(repaired.len(), chained.len() + 4);
+        assert_eq!(
+            from_bytes(&repaired).unwrap().len(),
+            2 * from_bytes(data).unwrap().len()
+        );
+    }
+}
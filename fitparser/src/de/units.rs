@@ -0,0 +1,178 @@
+//! Convert decoded values from the metric units used by the FIT profile into other unit systems.
+use crate::compat::prelude::*;
+use crate::error::Result;
+use crate::{FitDataField, FitDataRecord, Value};
+use core::convert::TryInto;
+
+/// Unit system used for distance, altitude, speed and temperature values
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub enum Units {
+    /// Meters, meters per second and degrees Celsius as defined by the profile
+    Metric,
+    /// Miles, feet, miles per hour and degrees Fahrenheit
+    Statute,
+    /// Nautical miles, feet, knots and degrees Celsius
+    Nautical,
+}
+
+/// Scale, offset and units used to convert a metric value
+struct Conversion {
+    scale: f64,
+    offset: f64,
+    units: &'static str,
+}
+
+impl Conversion {
+    fn new(scale: f64, offset: f64, units: &'static str) -> Self {
+        Conversion {
+            scale,
+            offset,
+            units,
+        }
+    }
+
+    /// Find the conversion for a field, lengths are converted to miles or nautical miles when
+    /// the field stores a distance and feet otherwise, e.g. altitude and ascent.
+    fn find(name: &str, units: &str, system: Units, semicircles: bool) -> Option<Self> {
+        let is_distance = name.contains("distance");
+        match (units, system) {
+            ("semicircles", _) if semicircles => {
+                Some(Self::new(180.0 / 2_147_483_648.0, 0.0, "degrees"))
+            }
+            (_, Units::Metric) => None,
+            ("m", Units::Statute) if is_distance => Some(Self::new(1.0 / 1609.344, 0.0, "mi")),
+            ("m", Units::Nautical) if is_distance => Some(Self::new(1.0 / 1852.0, 0.0, "nmi")),
+            ("m", _) => Some(Self::new(1.0 / 0.3048, 0.0, "ft")),
+            ("km", Units::Statute) => Some(Self::new(1.0 / 1.609344, 0.0, "mi")),
+            ("km", Units::Nautical) => Some(Self::new(1.0 / 1.852, 0.0, "nmi")),
+            ("m/s", Units::Statute) => Some(Self::new(3600.0 / 1609.344, 0.0, "mph")),
+            ("m/s", Units::Nautical) => Some(Self::new(3600.0 / 1852.0, 0.0, "kn")),
+            ("C", Units::Statute) => Some(Self::new(1.8, 32.0, "F")),
+            _ => None,
+        }
+    }
+
+    /// Convert a numeric value or array of values, other values are returned unmodified
+    fn apply(&self, value: Value) -> Result<Value> {
+        match value {
+            Value::Array(vals) => vals
+                .into_iter()
+                .map(|v| self.apply(v))
+                .collect::<Result<Vec<Value>>>()
+                .map(Value::Array),
+            Value::String(_) | Value::Timestamp(_) | Value::LocalTimestamp(_) => Ok(value),
+            value => {
+                let val: f64 = value.try_into()?;
+                Ok(Value::Float64(val * self.scale + self.offset))
+            }
+        }
+    }
+}
+
+/// Convert the fields of a record into the unit system, semicircles are converted into degrees
+/// when requested
+pub(crate) fn convert_record(
+    record: FitDataRecord,
+    system: Units,
+    semicircles: bool,
+) -> Result<FitDataRecord> {
+    let mut converted = FitDataRecord::new(record.kind());
+    for field in record.into_vec() {
+        let conversion = match Conversion::find(field.name(), field.units(), system, semicircles) {
+            Some(conversion) => conversion,
+            None => {
+                converted.push(field);
+                continue;
+            }
+        };
+        let name = field.name.clone();
+        let number = field.number();
+        let developer_data_index = field.developer_data_index();
+        let value = conversion.apply(field.into_value())?;
+        let units = conversion.units;
+        converted.push(match developer_data_index {
+            Some(index) => FitDataField::new_developer_field(name, number, index, value, units),
+            None => FitDataField::new(name, number, value, units),
+        });
+    }
+    Ok(converted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::MesgNum;
+
+    #[test]
+    fn convert_units() {
+        let mut record = FitDataRecord::new(MesgNum::Record);
+        record.push(FitDataField::new(
+            String::from("distance"),
+            5,
+            Value::Float64(1609.344),
+            String::from("m"),
+        ));
+        record.push(FitDataField::new(
+            String::from("altitude"),
+            2,
+            Value::Float64(3.048),
+            String::from("m"),
+        ));
+        record.push(FitDataField::new(
+            String::from("temperature"),
+            13,
+            Value::SInt8(100),
+            String::from("C"),
+        ));
+        record.push(FitDataField::new(
+            String::from("heart_rate"),
+            3,
+            Value::UInt8(120),
+            String::from("bpm"),
+        ));
+
+        let converted = convert_record(record.clone(), Units::Statute, false).unwrap();
+        let fields: Vec<(&str, &Value, &str)> = converted
+            .fields()
+            .iter()
+            .map(|f| (f.name(), f.value(), f.units()))
+            .collect();
+        assert_eq!(fields[0], ("distance", &Value::Float64(1.0), "mi"));
+        assert_eq!(fields[1].2, "ft");
+        let altitude: f64 = fields[1].1.clone().try_into().unwrap();
+        assert!((altitude - 10.0).abs() < 1e-9);
+        assert_eq!(fields[2], ("temperature", &Value::Float64(212.0), "F"));
+        assert_eq!(fields[3], ("heart_rate", &Value::UInt8(120), "bpm"));
+
+        let converted = convert_record(record, Units::Nautical, false).unwrap();
+        assert_eq!(converted.fields()[0].units(), "nmi");
+        assert_eq!(converted.fields()[2].value(), &Value::SInt8(100));
+    }
+
+    #[test]
+    fn convert_semicircles() {
+        let mut record = FitDataRecord::new(MesgNum::Record);
+        record.push(FitDataField::new(
+            String::from("position_lat"),
+            0,
+            Value::SInt32(495280430),
+            String::from("semicircles"),
+        ));
+        record.push(FitDataField::new(
+            String::from("distance"),
+            5,
+            Value::Float64(1609.344),
+            String::from("m"),
+        ));
+
+        let converted = convert_record(record.clone(), Units::Metric, false).unwrap();
+        assert_eq!(converted.fields()[0].value(), &Value::SInt32(495280430));
+
+        let converted = convert_record(record, Units::Metric, true).unwrap();
+        let lat = &converted.fields()[0];
+        assert_eq!(lat.units(), "degrees");
+        let lat: f64 = lat.value().clone().try_into().unwrap();
+        assert!((lat - 41.513926).abs() < 1e-6);
+        assert_eq!(converted.fields()[1].value(), &Value::Float64(1609.344));
+    }
+}
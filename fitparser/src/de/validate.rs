@@ -0,0 +1,335 @@
+//! Check a FIT file for problems without stopping at the first one, similar to a linter. The
+//! header, checksums, definition messages and the values of every decoded field are checked
+//! against the FIT protocol and profile.
+use super::caculate_crc;
+use super::decode::Decoder;
+use super::parser::{self, BaseType, FitMessage};
+use super::warnings::unknown_enum_type;
+use crate::compat::prelude::*;
+use crate::compat::{Arc, HashMap, HashSet};
+use crate::profile::MesgNum;
+use crate::{encode, FitDataRecord};
+use core::fmt;
+use serde::Serialize;
+
+/// Kinds of problems found by `validate`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProblemKind {
+    /// The file header couldn't be parsed or has an unexpected size
+    InvalidHeader,
+    /// The checksum stored in the header doesn't match the header bytes
+    HeaderCrcMismatch,
+    /// The checksum following the data section doesn't match the data
+    DataCrcMismatch,
+    /// The data size stored in the header doesn't match the messages in the file
+    DataSizeMismatch,
+    /// A data message uses a local message number that hasn't been defined
+    MissingDefinition,
+    /// A field definition uses an unknown base type or a size that isn't a multiple of it
+    InvalidFieldSize,
+    /// A message couldn't be parsed or decoded
+    InvalidMessage,
+    /// A field value isn't valid for the type defined by the profile
+    InvalidValue,
+}
+
+/// A problem found in a FIT file and the position of the object it was found in
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Problem {
+    kind: ProblemKind,
+    position: usize,
+    message: String,
+}
+
+impl Problem {
+    fn new(kind: ProblemKind, position: usize, message: String) -> Self {
+        Problem {
+            kind,
+            position,
+            message,
+        }
+    }
+
+    /// Return the kind of problem
+    pub fn kind(&self) -> ProblemKind {
+        self.kind
+    }
+
+    /// Return the position in the byte stream of the header, message or checksum with the problem
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Return a description of the problem
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}: {}", self.position, self.message)
+    }
+}
+
+/// Validate a FIT file stored as an array of bytes, returning every problem found. Each file of
+/// a chained FIT file is checked, parsing a file stops at the first message that can't be parsed
+/// since the following messages can't be located.
+pub fn validate(buffer: &[u8]) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let mut start = 0;
+    while start < buffer.len() {
+        match validate_file(&buffer[start..], start, &mut problems) {
+            Some(length) => start += length,
+            None => break,
+        }
+    }
+    problems
+}
+
+/// Validate a single FIT file starting at the beginning of the input, returning its length
+/// including the header and checksum if the next file can be located.
+fn validate_file(input: &[u8], start: usize, problems: &mut Vec<Problem>) -> Option<usize> {
+    let (_, header) = match parser::fit_file_header(input) {
+        Ok(result) => result,
+        Err(_) => {
+            problems.push(Problem::new(
+                ProblemKind::InvalidHeader,
+                start,
+                String::from("the file header can't be parsed"),
+            ));
+            return None;
+        }
+    };
+    let header_size = header.header_size() as usize;
+    if header_size != 12 && header_size != 14 {
+        problems.push(Problem::new(
+            ProblemKind::InvalidHeader,
+            start,
+            format!("header size is {} bytes instead of 12 or 14", header_size),
+        ));
+        // the rest of the file can't be located without a usable header size
+        if header_size < 12 || header_size > input.len() {
+            return None;
+        }
+    }
+    // a header CRC of 0 is treated as missing so the header is included in the data CRC
+    let has_header_crc = header.crc().unwrap_or(0) > 0;
+    if let Some(crc) = header.crc().filter(|_| has_header_crc) {
+        let calculated = caculate_crc(&input[..header_size - 2]);
+        if crc != calculated {
+            problems.push(Problem::new(
+                ProblemKind::HeaderCrcMismatch,
+                start,
+                format!(
+                    "header CRC is {:#06x} but the calculated value is {:#06x}",
+                    crc, calculated
+                ),
+            ));
+        }
+    }
+
+    let data_end = header_size + header.data_size() as usize;
+    if data_end + 2 > input.len() {
+        problems.push(Problem::new(
+            ProblemKind::DataSizeMismatch,
+            start,
+            format!(
+                "data size of {} bytes exceeds the {} bytes following the header",
+                header.data_size(),
+                input.len().saturating_sub(header_size)
+            ),
+        ));
+    }
+    validate_messages(
+        &input[..data_end.min(input.len())],
+        header_size,
+        start,
+        problems,
+    )?;
+
+    match input.get(data_end..data_end + 2) {
+        Some(crc) => {
+            let crc = u16::from_le_bytes([crc[0], crc[1]]);
+            let calculated = if has_header_crc {
+                caculate_crc(&input[header_size..data_end])
+            } else {
+                caculate_crc(&input[..data_end])
+            };
+            if crc != calculated {
+                problems.push(Problem::new(
+                    ProblemKind::DataCrcMismatch,
+                    start + data_end,
+                    format!(
+                        "data CRC is {:#06x} but the calculated value is {:#06x}",
+                        crc, calculated
+                    ),
+                ));
+            }
+            Some(data_end + 2)
+        }
+        None => None,
+    }
+}
+
+/// Check the messages of a file's data section, nothing is returned if a message couldn't be
+/// parsed.
+fn validate_messages(
+    input: &[u8],
+    header_size: usize,
+    start: usize,
+    problems: &mut Vec<Problem>,
+) -> Option<()> {
+    let mut definitions = HashMap::new();
+    let mut decoder = Decoder::new();
+    let mut position = header_size;
+    while position < input.len() {
+        let offset = start + position;
+        let (remaining, message) = match parser::fit_message(&input[position..], &definitions, None)
+        {
+            Ok(result) => result,
+            Err(_) => {
+                problems.push(Problem::new(
+                    ProblemKind::InvalidMessage,
+                    offset,
+                    String::from("message can't be parsed"),
+                ));
+                return None;
+            }
+        };
+        let length = input.len() - position - remaining.len();
+        match message {
+            FitMessage::Definition(definition) => {
+                check_field_definitions(&input[position..position + length], offset, problems);
+                definitions.insert(definition.local_message_number(), Arc::new(definition));
+            }
+            FitMessage::Data(message) => match decoder.decode_message(message, &HashSet::new()) {
+                Ok(record) => check_values(&record, offset, problems),
+                Err(err) => problems.push(Problem::new(
+                    ProblemKind::InvalidMessage,
+                    offset,
+                    format!("message can't be decoded: {}", err),
+                )),
+            },
+            FitMessage::MissingDefinitionMessage(local_message_number) => {
+                problems.push(Problem::new(
+                    ProblemKind::MissingDefinition,
+                    offset,
+                    format!(
+                        "local message number {} is used before it is defined",
+                        local_message_number
+                    ),
+                ));
+                return None;
+            }
+        }
+        position += length;
+    }
+    Some(())
+}
+
+/// Check the base type and size of each field definition in the raw bytes of a definition
+/// message, the parser replaces invalid definitions with byte arrays.
+fn check_field_definitions(message: &[u8], position: usize, problems: &mut Vec<Problem>) {
+    let global_message_number = match message[2] {
+        1 => u16::from_be_bytes([message[3], message[4]]),
+        _ => u16::from_le_bytes([message[3], message[4]]),
+    };
+    let kind = MesgNum::from(global_message_number);
+    let number_of_fields = message[5] as usize;
+    for field in message[6..6 + 3 * number_of_fields].chunks(3) {
+        let (number, size, base_type_field) = (field[0], field[1], field[2]);
+        let base_type = BaseType::from(base_type_field);
+        let message = if base_type as u8 != base_type_field & 0x9f {
+            format!(
+                "field {} of {} messages has an unknown base type {:#04x}",
+                number, kind, base_type_field
+            )
+        } else if size % base_type.size() != 0 {
+            format!(
+                "field {} of {} messages has a size of {} which isn't a multiple of the {:?} size",
+                number, kind, size, base_type
+            )
+        } else {
+            continue;
+        };
+        problems.push(Problem::new(
+            ProblemKind::InvalidFieldSize,
+            position,
+            message,
+        ));
+    }
+}
+
+/// Check that the values of a decoded record are valid for the fields defined by the profile
+fn check_values(record: &FitDataRecord, position: usize, problems: &mut Vec<Problem>) {
+    for field in record.fields() {
+        let info = match record.kind().get_field_info(field.number(), field.name()) {
+            Some(info) if field.developer_data_index().is_none() => info,
+            _ => continue,
+        };
+        let message = if let Some(field_type) = unknown_enum_type(record.kind(), field) {
+            format!(
+                "{} is not a valid {:?} value for field {} of {} messages",
+                field.value(),
+                field_type,
+                field.name(),
+                record.kind()
+            )
+        } else if let Err(err) = encode::check_field_value(info, field.value()) {
+            format!(
+                "field {} of {} messages: {}",
+                field.name(),
+                record.kind(),
+                err
+            )
+        } else {
+            continue;
+        };
+        problems.push(Problem::new(ProblemKind::InvalidValue, position, message));
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_valid_file() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        assert_eq!(validate(data), Vec::new());
+    }
+
+    #[test]
+    fn validate_damaged_file() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let mut damaged = data.to_vec();
+        let last = damaged.len() - 1;
+        damaged[last] ^= 0xFF;
+        let problems = validate(&damaged);
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].kind(), ProblemKind::DataCrcMismatch);
+        assert_eq!(problems[0].position(), data.len() - 2);
+
+        damaged.truncate(100);
+        let problems = validate(&damaged);
+        assert_eq!(problems[0].kind(), ProblemKind::DataSizeMismatch);
+        assert_eq!(problems.len(), 2);
+        assert_eq!(problems[1].kind(), ProblemKind::InvalidMessage);
+
+        // the rest of the file is only checked when the header size fits in the input
+        for header_size in [0, 0xFF] {
+            let mut damaged = data.to_vec();
+            damaged[0] = header_size;
+            let problems = validate(&damaged);
+            assert_eq!(problems[0].kind(), ProblemKind::InvalidHeader);
+            assert_eq!(problems[0].position(), 0);
+
+            damaged.truncate(100);
+            let problems = validate(&damaged);
+            assert_eq!(problems.len(), 1);
+            assert_eq!(problems[0].kind(), ProblemKind::InvalidHeader);
+        }
+    }
+}
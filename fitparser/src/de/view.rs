@@ -0,0 +1,370 @@
+//! Inspect the data messages of a FIT file without copying their contents. Strings and arrays
+//! borrow from the input buffer and fields are only parsed when they are accessed, the values are
+//! the raw values stored in the file without the profile applied.
+use super::parser::{self, BaseType, DeveloperFieldDefinition};
+use super::parser::{FitDefinitionMessage, FitFileHeader};
+use super::{DecodeOption, Deserializer, FitObject};
+use crate::compat::prelude::*;
+use crate::compat::{Arc, HashSet};
+use crate::error::{ErrorKind, Result};
+use crate::profile::{FieldInfo, MesgNum};
+use crate::Value;
+use nom::number::Endianness;
+
+/// Value of a field borrowing strings and arrays from the input buffer
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValueRef<'a> {
+    /// Single numeric or enum value, these are copied out of the buffer since they don't allocate
+    Scalar(Value),
+    /// String up to the first NUL byte of the field
+    String(&'a str),
+    /// Array of numeric values of the same base type
+    Array(ArrayRef<'a>),
+}
+
+impl<'a> ValueRef<'a> {
+    /// Convert the value into an owned value
+    pub fn to_value(&self) -> Value {
+        match self {
+            ValueRef::Scalar(value) => value.clone(),
+            ValueRef::String(value) => Value::String(String::from(*value)),
+            ValueRef::Array(array) => Value::Array(array.iter().collect()),
+        }
+    }
+}
+
+/// Array of numeric values stored in a field, the values are parsed as they are accessed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ArrayRef<'a> {
+    data: &'a [u8],
+    base_type: BaseType,
+    byte_order: Endianness,
+}
+
+impl<'a> ArrayRef<'a> {
+    /// Return the base type of the array values
+    pub fn base_type(&self) -> BaseType {
+        self.base_type
+    }
+
+    /// Return the number of values in the array
+    pub fn len(&self) -> usize {
+        self.data.len() / self.base_type.size() as usize
+    }
+
+    /// Check if the array contains no values
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the value at the provided index
+    pub fn get(&self, index: usize) -> Option<Value> {
+        let size = self.base_type.size() as usize;
+        let bytes = self.data.get(index * size..(index + 1) * size)?;
+        parser::base_type_value(bytes, self.base_type, self.byte_order)
+            .ok()
+            .map(|(_, v)| v)
+    }
+
+    /// Iterate over the values of the array
+    pub fn iter(&self) -> impl Iterator<Item = Value> + 'a {
+        let array = *self;
+        (0..array.len()).filter_map(move |i| array.get(i))
+    }
+
+    /// Return the raw bytes of the array, this is most useful for byte arrays
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+/// Field of a data message along with its raw bytes
+#[derive(Clone, Copy, Debug)]
+pub struct FieldView<'a> {
+    kind: MesgNum,
+    number: u8,
+    base_type: BaseType,
+    byte_order: Endianness,
+    data: &'a [u8],
+}
+
+impl<'a> FieldView<'a> {
+    /// Return the field number
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// Return the base type used to store the field
+    pub fn base_type(&self) -> BaseType {
+        self.base_type
+    }
+
+    /// Return the profile definition of the field, subfields are not resolved
+    pub fn info(&self) -> Option<&'static FieldInfo> {
+        self.kind
+            .field_info()
+            .iter()
+            .find(|f| f.def_number() == self.number() && !f.is_subfield())
+    }
+
+    /// Return the name of the field defined by the profile
+    pub fn name(&self) -> Option<&'static str> {
+        self.info().map(FieldInfo::name)
+    }
+
+    /// Return the raw bytes of the field
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Return the raw value of the field, None is returned if the value is invalid
+    pub fn value(&self) -> Option<ValueRef<'a>> {
+        let base_type = self.base_type();
+        if base_type == BaseType::String {
+            let end = self
+                .data
+                .iter()
+                .position(|b| *b == 0)
+                .unwrap_or(self.data.len());
+            return core::str::from_utf8(&self.data[..end])
+                .ok()
+                .map(ValueRef::String);
+        }
+        let array = ArrayRef {
+            data: self.data,
+            base_type,
+            byte_order: self.byte_order,
+        };
+        match array.len() {
+            0 => None,
+            1 => array.get(0).filter(Value::is_valid).map(ValueRef::Scalar),
+            len => {
+                // arrays are only valid when every value is, matching the owned decoder
+                let valid = array.iter().filter(Value::is_valid).count() == len;
+                valid.then_some(ValueRef::Array(array))
+            }
+        }
+    }
+}
+
+/// Data message borrowing its bytes from the input buffer
+#[derive(Clone, Debug)]
+pub struct MessageView<'a> {
+    kind: MesgNum,
+    position: usize,
+    data: &'a [u8],
+    time_offset: Option<u8>,
+    definition: Arc<FitDefinitionMessage>,
+}
+
+impl<'a> MessageView<'a> {
+    /// Return the kind of message
+    pub fn kind(&self) -> MesgNum {
+        self.kind
+    }
+
+    /// Return the position of the message in the buffer, including the header byte
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Return the time offset of a compressed timestamp header
+    pub fn time_offset(&self) -> Option<u8> {
+        self.time_offset
+    }
+
+    /// Return the definition message used to parse the message
+    pub fn definition(&self) -> &FitDefinitionMessage {
+        &self.definition
+    }
+
+    /// Iterate over the fields of the message in the order they are stored
+    pub fn fields(&self) -> impl Iterator<Item = FieldView<'a>> + '_ {
+        let data = self.data;
+        let mut offset = 0;
+        self.definition
+            .field_definitions()
+            .iter()
+            .map(move |definition| {
+                let start = offset;
+                offset += definition.size() as usize;
+                FieldView {
+                    kind: self.kind,
+                    number: definition.field_definition_number(),
+                    base_type: definition.base_type(),
+                    byte_order: self.definition.byte_order(),
+                    data: &data[start..offset],
+                }
+            })
+    }
+
+    /// Return the field with the provided number
+    pub fn field(&self, number: u8) -> Option<FieldView<'a>> {
+        self.fields().find(|f| f.number() == number)
+    }
+
+    /// Return the field with the provided profile name
+    pub fn field_by_name(&self, name: &str) -> Option<FieldView<'a>> {
+        self.fields().find(|f| f.name() == Some(name))
+    }
+
+    /// Iterate over the developer fields of the message along with their raw bytes, the base
+    /// type of each field is defined by a field description message
+    pub fn developer_fields(&self) -> impl Iterator<Item = (&DeveloperFieldDefinition, &'a [u8])> {
+        let data = self.data;
+        let mut offset = self
+            .definition
+            .field_definitions()
+            .iter()
+            .fold(0, |l, f| l + f.size() as usize);
+        self.definition
+            .developer_field_definitions()
+            .iter()
+            .map(move |definition| {
+                let start = offset;
+                offset += definition.size() as usize;
+                (definition, &data[start..offset])
+            })
+    }
+}
+
+/// View of the data messages stored in one or more FIT files. Checksums are validated as the
+/// messages are iterated over, use `FitIndex` or the deserializer when the profile needs to be
+/// applied to the values.
+#[derive(Clone, Debug)]
+pub struct FitFileView<'a> {
+    buffer: &'a [u8],
+    options: HashSet<DecodeOption>,
+}
+
+impl<'a> FitFileView<'a> {
+    /// Create a view of a FIT file stored as an array of bytes
+    pub fn new(buffer: &'a [u8]) -> Self {
+        Self::with_options(buffer, &HashSet::new())
+    }
+
+    /// Create a view of a FIT file with additional decode options, only the options related to
+    /// checksum validation are used.
+    pub fn with_options(buffer: &'a [u8], options: &HashSet<DecodeOption>) -> Self {
+        FitFileView {
+            buffer,
+            options: options.clone(),
+        }
+    }
+
+    /// Parse the header of the first FIT file in the buffer
+    pub fn header(&self) -> Result<FitFileHeader> {
+        parser::fit_file_header(self.buffer)
+            .map(|(_, header)| header)
+            .map_err(|e| match e {
+                nom::Err::Error(err) | nom::Err::Failure(err) => {
+                    ErrorKind::ParseError(0, err.code).into()
+                }
+                nom::Err::Incomplete(needed) => ErrorKind::UnexpectedEof(needed).into(),
+            })
+    }
+
+    /// Iterate over the data messages in the order they are stored, iteration stops after the
+    /// first error
+    pub fn messages(&self) -> MessageViews<'a> {
+        let mut deserializer = Deserializer::new();
+        deserializer
+            .options_mut()
+            .extend(self.options.iter().copied());
+        // skip the fields of every data message, they are parsed when accessed
+        deserializer.set_message_filter(Some(HashSet::new()));
+        MessageViews {
+            buffer: self.buffer,
+            input: self.buffer,
+            deserializer,
+        }
+    }
+}
+
+/// Iterator over the data messages of a `FitFileView`
+pub struct MessageViews<'a> {
+    buffer: &'a [u8],
+    input: &'a [u8],
+    deserializer: Deserializer,
+}
+
+impl<'a> Iterator for MessageViews<'a> {
+    type Item = Result<MessageView<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.input.is_empty() {
+            let start = self.buffer.len() - self.input.len();
+            let (remaining, obj) = match self.deserializer.deserialize_next(self.input) {
+                Ok(result) => result,
+                Err(err) => {
+                    self.input = &[];
+                    return Some(Err(err));
+                }
+            };
+            let message = &self.input[..self.input.len() - remaining.len()];
+            self.input = remaining;
+            match obj {
+                FitObject::Crc(..) => self.deserializer.reset(),
+                FitObject::DataMessage(msg) => {
+                    let definition = &self.deserializer.definitions[&msg.local_message_number()];
+                    return Some(Ok(MessageView {
+                        kind: MesgNum::from(msg.global_message_number()),
+                        position: start,
+                        data: &message[1..],
+                        time_offset: msg.time_offset(),
+                        definition: Arc::clone(definition),
+                    }));
+                }
+                FitObject::Header(..) | FitObject::DefinitionMessage(..) => {}
+            }
+        }
+        None
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+
+    #[test]
+    fn view_matches_decoded_records() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let expected = from_bytes(data).unwrap();
+        let view = FitFileView::new(data);
+        let messages: Vec<MessageView> = view.messages().collect::<Result<_>>().unwrap();
+        assert_eq!(messages.len(), expected.len());
+        for (msg, exp) in messages.iter().zip(&expected) {
+            assert_eq!(msg.kind(), exp.kind());
+        }
+
+        let file_id = &messages[0];
+        let manufacturer = file_id.field_by_name("manufacturer").unwrap();
+        assert_eq!(
+            manufacturer.value(),
+            Some(ValueRef::Scalar(Value::UInt16(15)))
+        );
+        let record = messages
+            .iter()
+            .find(|m| m.kind() == MesgNum::Record)
+            .unwrap();
+        let lat = record.field_by_name("position_lat").unwrap();
+        assert_eq!(lat.value().unwrap().to_value(), Value::SInt32(495280430));
+    }
+
+    #[test]
+    fn view_borrows_strings() {
+        let data = include_bytes!("../../tests/fixtures/DeveloperData.fit");
+        let view = FitFileView::new(data);
+        let mut strings = 0;
+        for msg in view.messages() {
+            for field in msg.unwrap().fields() {
+                if let Some(ValueRef::String(value)) = field.value() {
+                    assert!(data.as_ptr_range().contains(&value.as_ptr()));
+                    strings += 1;
+                }
+            }
+        }
+        assert!(strings > 0);
+    }
+}
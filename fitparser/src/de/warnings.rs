@@ -0,0 +1,228 @@
+//! Data quality issues found while decoding that don't stop the parse, they are collected when
+//! using `DecodeOption::CollectWarnings` so callers can log them along with the records.
+use super::parser::FitDefinitionMessage;
+use super::{BaseType, CrcMismatch, FitDataMessage};
+use crate::compat::prelude::*;
+use crate::profile::{FieldDataType, MesgNum};
+use crate::{FitDataField, FitDataRecord, Value};
+use core::fmt;
+use serde::Serialize;
+
+/// Kinds of warnings found while decoding
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarningKind {
+    /// The global message number isn't defined by the profile
+    UnknownMessage,
+    /// An enum value doesn't match any of the variants defined by the profile
+    UnknownEnumValue,
+    /// A string field doesn't contain valid UTF-8, the field is dropped from the record
+    InvalidString,
+    /// A checksum didn't match while using `DecodeOption::WarnOnCrcMismatch`
+    CrcMismatch,
+}
+
+/// A warning found while decoding and where it was found
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Warning {
+    kind: WarningKind,
+    position: Option<usize>,
+    record_index: Option<usize>,
+    message: String,
+}
+
+impl Warning {
+    /// Return the kind of warning
+    pub fn kind(&self) -> WarningKind {
+        self.kind
+    }
+
+    /// Return the position in the byte stream of the message or checksum with the issue
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// Return the index of the data message with the issue, counting from 0
+    pub fn record_index(&self) -> Option<usize> {
+        self.record_index
+    }
+
+    /// Return a description of the issue
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some(position) => write!(f, "{:#x}: {}", position, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Create the warning for a checksum mismatch
+pub(super) fn crc_mismatch(mismatch: &CrcMismatch) -> Warning {
+    let section = if mismatch.is_header() {
+        "header"
+    } else {
+        "data"
+    };
+    Warning {
+        kind: WarningKind::CrcMismatch,
+        position: Some(mismatch.position()),
+        record_index: None,
+        message: format!(
+            "{} CRC mismatch, expected {:#06x} but calculated {:#06x}",
+            section,
+            mismatch.expected(),
+            mismatch.calculated()
+        ),
+    }
+}
+
+/// Find the string fields of a data message that were dropped because they aren't valid UTF-8,
+/// the parser only drops string values that can't be converted
+pub(super) fn check_message(
+    message: &FitDataMessage,
+    definition: &FitDefinitionMessage,
+    warnings: &mut Vec<Warning>,
+) {
+    let kind = MesgNum::from(message.global_message_number());
+    for field in definition.field_definitions() {
+        let number = field.field_definition_number();
+        if field.base_type() == BaseType::String
+            && field.size() > 0
+            && !message.fields().contains_key(&number)
+        {
+            warnings.push(Warning {
+                kind: WarningKind::InvalidString,
+                position: message.position(),
+                record_index: message.index(),
+                message: format!("field {} of {} messages isn't valid UTF-8", number, kind),
+            });
+        }
+    }
+}
+
+/// Check a decoded record for messages and enum values that aren't defined by the profile, enum
+/// values are only checked when they are converted into the variant names
+pub(super) fn check_record(
+    record: &FitDataRecord,
+    location: (Option<usize>, Option<usize>),
+    check_enums: bool,
+    warnings: &mut Vec<Warning>,
+) {
+    let (position, record_index) = location;
+    let mut warn = |kind, message| {
+        warnings.push(Warning {
+            kind,
+            position,
+            record_index,
+            message,
+        })
+    };
+    if !MesgNum::is_named_variant(record.kind().as_i64()) {
+        warn(
+            WarningKind::UnknownMessage,
+            format!("message {} isn't defined in the profile", record.kind()),
+        );
+        return;
+    }
+    if !check_enums {
+        return;
+    }
+    for field in record.fields() {
+        if let Some(field_type) = unknown_enum_type(record.kind(), field) {
+            warn(
+                WarningKind::UnknownEnumValue,
+                format!(
+                    "{} is not a valid {:?} value for field {} of {} messages",
+                    field.value(),
+                    field_type,
+                    field.name(),
+                    record.kind()
+                ),
+            );
+        }
+    }
+}
+
+/// Return the type of an enum field if its value doesn't match any of the named variants
+pub(super) fn unknown_enum_type(kind: MesgNum, field: &FitDataField) -> Option<FieldDataType> {
+    let info = kind
+        .get_field_info(field.number(), field.name())
+        .filter(|_| field.developer_data_index().is_none())?;
+    // enum values without a variant are returned as plain numbers, types with a larger
+    // base type such as `manufacturer` use numbers for values that aren't named
+    let is_enum = info.field_type().base_type() == BaseType::Enum
+        && !matches!(info.field_type(), FieldDataType::Bool);
+    if is_enum && !matches!(field.value(), Value::String(_) | Value::Array(_)) {
+        Some(info.field_type())
+    } else {
+        None
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::compat::HashSet;
+    use crate::de::{from_bytes_with_warnings, DecodeOption};
+    use crate::encode::to_bytes;
+
+    #[test]
+    fn collect_warnings() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let (_, warnings) = from_bytes_with_warnings(data, &HashSet::new()).unwrap();
+        assert!(warnings.is_empty());
+
+        let mut file_id = FitDataRecord::new(MesgNum::FileId);
+        file_id
+            .set_field_value("type", Value::String("activity".into()))
+            .unwrap();
+        file_id
+            .set_field_value("product_name", Value::String("bike".into()))
+            .unwrap();
+        let mut event = FitDataRecord::new(MesgNum::Event);
+        event.set_field_value("event", Value::Enum(250)).unwrap();
+        let mut unknown = FitDataRecord::new(MesgNum::from(0x1234_u16));
+        unknown.push(FitDataField::new("unknown_field_0", 0, Value::UInt8(1), ""));
+        let mut data = to_bytes(&[file_id, event, unknown]).unwrap();
+
+        // replace the product name with invalid UTF-8 and break the data checksum
+        let start = data.windows(4).position(|w| w == b"bike").unwrap();
+        data[start] = 0xFF;
+        let len = data.len();
+        data[len - 1] ^= 0xFF;
+        let options = [DecodeOption::WarnOnCrcMismatch].iter().copied().collect();
+        let (records, warnings) = from_bytes_with_warnings(&data, &options).unwrap();
+        assert_eq!(records.len(), 3);
+        assert!(records[0].field("product_name").is_none());
+        let kinds: Vec<WarningKind> = warnings.iter().map(Warning::kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                WarningKind::InvalidString,
+                WarningKind::UnknownEnumValue,
+                WarningKind::UnknownMessage,
+                WarningKind::CrcMismatch
+            ]
+        );
+        assert_eq!(warnings[1].record_index(), Some(1));
+        assert!(warnings[1].message().contains("event"));
+        assert_eq!(warnings[3].position(), Some(len));
+
+        // enum values aren't checked when they are returned as numbers
+        let options = [
+            DecodeOption::ReturnNumericEnumValues,
+            DecodeOption::SkipDataCrcValidation,
+        ]
+        .iter()
+        .copied()
+        .collect();
+        let (_, warnings) = from_bytes_with_warnings(&data, &options).unwrap();
+        assert_eq!(warnings.len(), 2);
+    }
+}
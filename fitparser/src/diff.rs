@@ -0,0 +1,296 @@
+//! Compare the decoded records of two FIT files at the message and field level. Encoding details
+//! such as local message numbers, field order and definitions are ignored so files written by
+//! different encoders can be compared.
+use crate::compat::prelude::*;
+use crate::compat::HashMap;
+use crate::profile::MesgNum;
+use crate::{FitDataField, FitDataRecord, Value};
+use core::convert::TryInto;
+use serde::Serialize;
+
+/// A difference between the records of two FIT files, indexes refer to the position of the
+/// record in the original list of records
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum RecordDiff {
+    /// Record that only exists in the right file
+    Added {
+        /// Index of the record in the right file
+        index: usize,
+        /// Kind of the record
+        kind: MesgNum,
+    },
+    /// Record that only exists in the left file
+    Removed {
+        /// Index of the record in the left file
+        index: usize,
+        /// Kind of the record
+        kind: MesgNum,
+    },
+    /// Record that exists in both files with different field values
+    Changed {
+        /// Index of the record in the left file
+        left_index: usize,
+        /// Index of the record in the right file
+        right_index: usize,
+        /// Kind of the record
+        kind: MesgNum,
+        /// Fields that were added, removed or changed
+        fields: Vec<FieldDiff>,
+    },
+}
+
+/// Values of a field that differs between two records, a missing value means the field only
+/// exists in one of the records
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FieldDiff {
+    name: String,
+    left: Option<Value>,
+    right: Option<Value>,
+    delta: Option<f64>,
+}
+
+impl FieldDiff {
+    /// Return the name of the field
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return the value of the field in the left record
+    pub fn left(&self) -> Option<&Value> {
+        self.left.as_ref()
+    }
+
+    /// Return the value of the field in the right record
+    pub fn right(&self) -> Option<&Value> {
+        self.right.as_ref()
+    }
+
+    /// Return the right value minus the left value if both values are numeric
+    pub fn delta(&self) -> Option<f64> {
+        self.delta
+    }
+}
+
+/// Compare the records of two FIT files. Records are paired by kind, using the timestamp of the
+/// records when every record of the kind has one and the order of the records otherwise.
+/// Numeric values that differ by no more than `tolerance` are treated as equal.
+pub fn diff(left: &[FitDataRecord], right: &[FitDataRecord], tolerance: f64) -> Vec<RecordDiff> {
+    let mut kinds: Vec<MesgNum> = Vec::new();
+    for record in left.iter().chain(right) {
+        if !kinds.contains(&record.kind()) {
+            kinds.push(record.kind());
+        }
+    }
+    let left_kinds = group_by_kind(left);
+    let right_kinds = group_by_kind(right);
+
+    let mut diffs = Vec::new();
+    for kind in kinds {
+        let lhs = left_kinds.get(&kind).map_or(&[][..], |r| r.as_slice());
+        let rhs = right_kinds.get(&kind).map_or(&[][..], |r| r.as_slice());
+        for pair in pair_records(lhs, rhs) {
+            match pair {
+                (Some(l), None) => diffs.push(RecordDiff::Removed { index: l, kind }),
+                (None, Some(r)) => diffs.push(RecordDiff::Added { index: r, kind }),
+                (Some(l), Some(r)) => {
+                    let fields = diff_fields(&left[l], &right[r], tolerance);
+                    if !fields.is_empty() {
+                        diffs.push(RecordDiff::Changed {
+                            left_index: l,
+                            right_index: r,
+                            kind,
+                            fields,
+                        });
+                    }
+                }
+                (None, None) => {}
+            }
+        }
+    }
+    // report the differences in the order of the records in the files
+    diffs.sort_by_key(|d| match d {
+        RecordDiff::Removed { index, .. } => (*index, 0),
+        RecordDiff::Changed {
+            left_index,
+            right_index,
+            ..
+        } => (*left_index.max(right_index), 1),
+        RecordDiff::Added { index, .. } => (*index, 2),
+    });
+    diffs
+}
+
+/// Group the indexes of the records by kind
+fn group_by_kind(records: &[FitDataRecord]) -> HashMap<MesgNum, Vec<(usize, &FitDataRecord)>> {
+    let mut groups: HashMap<MesgNum, Vec<(usize, &FitDataRecord)>> = HashMap::new();
+    for (index, record) in records.iter().enumerate() {
+        groups
+            .entry(record.kind())
+            .or_default()
+            .push((index, record));
+    }
+    groups
+}
+
+/// Pair the records of a single kind returning the indexes of the records, unpaired records
+/// are returned with a missing index for the other file
+fn pair_records(
+    left: &[(usize, &FitDataRecord)],
+    right: &[(usize, &FitDataRecord)],
+) -> Vec<(Option<usize>, Option<usize>)> {
+    let timestamp = |r: &FitDataRecord| r.field("timestamp").map(|f| f.value().clone());
+    let timestamped = left
+        .iter()
+        .chain(right)
+        .all(|(_, r)| timestamp(r).is_some());
+    if !timestamped {
+        let count = left.len().max(right.len());
+        return (0..count)
+            .map(|i| (left.get(i).map(|l| l.0), right.get(i).map(|r| r.0)))
+            .collect();
+    }
+
+    // both lists are in file order so they can be merged by timestamp, records with duplicate
+    // timestamps are paired in order
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() || j < right.len() {
+        let order = match (left.get(i), right.get(j)) {
+            (Some(l), Some(r)) => timestamp(l.1).partial_cmp(&timestamp(r.1)),
+            (Some(_), None) => Some(core::cmp::Ordering::Less),
+            _ => Some(core::cmp::Ordering::Greater),
+        };
+        match order {
+            Some(core::cmp::Ordering::Less) => {
+                pairs.push((Some(left[i].0), None));
+                i += 1;
+            }
+            Some(core::cmp::Ordering::Greater) => {
+                pairs.push((None, Some(right[j].0)));
+                j += 1;
+            }
+            _ => {
+                pairs.push((Some(left[i].0), Some(right[j].0)));
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    pairs
+}
+
+/// Compare the fields of two records by name
+fn diff_fields(left: &FitDataRecord, right: &FitDataRecord, tolerance: f64) -> Vec<FieldDiff> {
+    let find = |record: &FitDataRecord, name: &str| {
+        record
+            .fields()
+            .iter()
+            .find(|f| f.name() == name)
+            .map(FitDataField::value)
+            .cloned()
+    };
+    let mut names: Vec<&str> = left.fields().iter().map(|f| f.name()).collect();
+    for field in right.fields() {
+        if !names.contains(&field.name()) {
+            names.push(field.name());
+        }
+    }
+
+    let mut fields = Vec::new();
+    for name in names {
+        let (lhs, rhs) = (find(left, name), find(right, name));
+        let delta = match (&lhs, &rhs) {
+            (Some(l), Some(r)) => numeric_delta(l, r),
+            _ => None,
+        };
+        let equal = match (&lhs, &rhs) {
+            (Some(l), Some(r)) => values_equal(l, r, tolerance),
+            _ => false,
+        };
+        if !equal {
+            fields.push(FieldDiff {
+                name: String::from(name),
+                left: lhs,
+                right: rhs,
+                delta,
+            });
+        }
+    }
+    fields
+}
+
+/// Difference between two numeric values, timestamps, strings and arrays don't have a delta
+fn numeric_delta(left: &Value, right: &Value) -> Option<f64> {
+    match (left, right) {
+        (Value::Timestamp(_), _) | (Value::LocalTimestamp(_), _) => None,
+        (_, Value::Timestamp(_)) | (_, Value::LocalTimestamp(_)) => None,
+        (Value::String(_), _) | (Value::Array(_), _) => None,
+        (_, Value::String(_)) | (_, Value::Array(_)) => None,
+        _ => {
+            let l: f64 = left.clone().try_into().ok()?;
+            let r: f64 = right.clone().try_into().ok()?;
+            Some(r - l)
+        }
+    }
+}
+
+/// Compare two values, numbers are equal if they differ by no more than the tolerance and arrays
+/// are compared element by element
+fn values_equal(left: &Value, right: &Value, tolerance: f64) -> bool {
+    match (left, right) {
+        (Value::Array(l), Value::Array(r)) => {
+            l.len() == r.len() && l.iter().zip(r).all(|(l, r)| values_equal(l, r, tolerance))
+        }
+        _ => match numeric_delta(left, right) {
+            Some(delta) => delta <= tolerance && -delta <= tolerance,
+            None => left == right,
+        },
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+
+    #[test]
+    fn diff_activity() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let left = from_bytes(data).unwrap();
+        assert_eq!(diff(&left, &left, 0.0), Vec::new());
+
+        let mut right = left.clone();
+        let index = right
+            .iter()
+            .position(|r| r.kind() == MesgNum::Record && r.field("position_lat").is_some())
+            .unwrap();
+        let record = &mut right[index];
+        let lat = record.field("position_lat").unwrap().value().clone();
+        let lat: f64 = lat.try_into().unwrap();
+        record
+            .set_field_value("position_lat", Value::SInt32(lat as i32 + 2))
+            .unwrap();
+        right.remove(index + 1);
+
+        let diffs = diff(&left, &right, 1.0);
+        assert_eq!(diffs.len(), 2);
+        match &diffs[0] {
+            RecordDiff::Changed { kind, fields, .. } => {
+                assert_eq!(*kind, MesgNum::Record);
+                assert_eq!(fields.len(), 1);
+                assert_eq!(fields[0].name(), "position_lat");
+                assert_eq!(fields[0].delta(), Some(2.0));
+            }
+            d => panic!("unexpected difference {:?}", d),
+        }
+        assert_eq!(
+            diffs[1],
+            RecordDiff::Removed {
+                index: index + 1,
+                kind: MesgNum::Record
+            }
+        );
+        assert_eq!(diff(&left, &right, 2.0).len(), 1);
+    }
+}
@@ -0,0 +1,554 @@
+//! Serialize FIT data records into a FIT file by reversing the packaged FIT profile and
+//! generating the definition messages needed to describe each record.
+use crate::compat::prelude::*;
+use crate::compat::{HashMap, HashSet};
+use crate::de::{
+    caculate_crc, update_crc, BaseType, DeveloperFieldData, DeveloperFieldDefinition,
+    DeveloperFieldDescription, FieldDefinition, FitDataMessage, FitDefinitionMessage,
+    MessageLayout,
+};
+use crate::error::{ErrorKind, Result};
+use crate::profile::{self, MesgNum, TimestampField, VERSION};
+use crate::{FitDataField, FitDataRecord, Value};
+use core::convert::TryInto;
+use nom::number::Endianness;
+#[cfg(feature = "std")]
+use std::io::Write;
+
+pub(crate) mod writer;
+
+/// FIT protocol version 2.0 encoded as major << 4 | minor
+const PROTOCOL_VERSION: u8 = 0x20;
+
+/// Local message numbers range from 0 to 15 in a normal message header
+const LOCAL_MESSAGE_COUNT: usize = 16;
+
+/// Manages the serialization of FIT data records into a FIT data stream.
+struct Serializer {
+    /// Track the definition message assigned to each local message number, a new definition
+    /// message is only written when a record doesn't match any existing layout.
+    definitions: Vec<Option<FitDefinitionMessage>>,
+    /// Local message number that will be replaced next once all numbers are in use
+    next_local_message_number: usize,
+    /// Stores the serialized definition and data messages
+    buffer: Vec<u8>,
+    /// File header of the first record when it was decoded with its layout preserved
+    header: Option<Vec<u8>>,
+    /// Developer data indices with a developer data ID message written
+    developer_data_ids: HashSet<u8>,
+    /// Field descriptions written so far keyed by the developer data index and field number
+    developer_fields: HashMap<(u8, u8), DeveloperFieldDescription>,
+}
+
+impl Serializer {
+    /// Create the serializer with an empty state
+    fn new() -> Self {
+        Serializer {
+            definitions: Vec::with_capacity(LOCAL_MESSAGE_COUNT),
+            next_local_message_number: 0,
+            buffer: Vec::new(),
+            header: None,
+            developer_data_ids: HashSet::new(),
+            developer_fields: HashMap::new(),
+        }
+    }
+
+    /// Serialize a data record, writing a definition message first if required
+    fn serialize_record(&mut self, record: &FitDataRecord) -> Result<()> {
+        if let Some(layout) = &record.layout {
+            self.serialize_layout(layout);
+            if is_developer_data_message(record.kind()) {
+                if let Ok((_, fields)) = encode_record(record) {
+                    self.track_developer_data(record.kind(), &fields);
+                }
+            }
+            return Ok(());
+        }
+        let (field_definitions, fields) = encode_record(record)?;
+        let developer_fields = self.encode_developer_fields(record)?;
+        let developer_field_definitions: Vec<DeveloperFieldDefinition> = developer_fields
+            .iter()
+            .map(|f| f.definition().clone())
+            .collect();
+        let global_message_number = record.kind().as_u16();
+        let local_message_number = match self.definitions.iter().position(|d| {
+            d.as_ref().is_some_and(|d| {
+                d.global_message_number() == global_message_number
+                    && d.field_definitions() == field_definitions.as_slice()
+                    && d.developer_field_definitions() == developer_field_definitions.as_slice()
+            })
+        }) {
+            Some(n) => n,
+            None => self.serialize_definition(
+                global_message_number,
+                field_definitions,
+                developer_field_definitions,
+            ),
+        };
+
+        writer::data_message(
+            &mut self.buffer,
+            self.definitions[local_message_number].as_ref().unwrap(),
+            &FitDataMessage::new(global_message_number, fields.clone())
+                .with_developer_fields(developer_fields),
+        )?;
+        self.track_developer_data(record.kind(), &fields);
+        Ok(())
+    }
+
+    /// Convert the developer fields of a record into their raw bytes, the developer data ID and
+    /// field description messages are written first for fields that haven't been described.
+    fn encode_developer_fields(
+        &mut self,
+        record: &FitDataRecord,
+    ) -> Result<Vec<DeveloperFieldData>> {
+        let mut developer_fields = Vec::new();
+        for field in record.fields() {
+            let index = match field.developer_data_index() {
+                Some(index) => index,
+                None => continue,
+            };
+            let key = (index, field.number());
+            if !self.developer_fields.contains_key(&key) {
+                self.serialize_field_description(index, field)?;
+            }
+            let description = &self.developer_fields[&key];
+            let value = profile::raw_value(
+                field.name(),
+                description.field_type(),
+                description.scale(),
+                description.offset(),
+                field.value().clone(),
+            )?;
+            let size = field_size(&value, description.base_type())?;
+            let mut data = Vec::new();
+            writer::data_field_value(
+                &mut data,
+                Some(&value),
+                description.base_type(),
+                false,
+                size,
+            )?;
+            developer_fields.push(DeveloperFieldData::new(
+                DeveloperFieldDefinition::new(field.number(), size, index),
+                Endianness::Little,
+                data,
+            ));
+        }
+
+        Ok(developer_fields)
+    }
+
+    /// Write the messages describing a developer field, the base type is determined from the
+    /// value of the field.
+    fn serialize_field_description(&mut self, index: u8, field: &FitDataField) -> Result<()> {
+        if !self.developer_data_ids.contains(&index) {
+            let mut data_id = FitDataRecord::new(MesgNum::DeveloperDataId);
+            data_id.set_field_value("developer_data_index", Value::UInt8(index))?;
+            self.serialize_record(&data_id)?;
+        }
+        let (base_type, _) = unknown_field_value(field.value());
+        let mut description = FitDataRecord::new(MesgNum::FieldDescription);
+        description.set_field_value("developer_data_index", Value::UInt8(index))?;
+        description.set_field_value("field_definition_number", Value::UInt8(field.number()))?;
+        description.set_field_value("fit_base_type_id", Value::UInt8(base_type as u8))?;
+        description.set_field_value("field_name", Value::String(field.name().to_string()))?;
+        if !field.units().is_empty() {
+            description.set_field_value("units", Value::String(field.units().to_string()))?;
+        }
+        self.serialize_record(&description)
+    }
+
+    /// Keep track of the developer data IDs and field descriptions written, mirroring the way
+    /// the decoder uses them.
+    fn track_developer_data(&mut self, kind: MesgNum, fields: &HashMap<u8, Value>) {
+        if kind == MesgNum::FieldDescription {
+            if let Some((key, description)) =
+                DeveloperFieldDescription::from_fields(fields, &HashMap::new())
+            {
+                self.developer_fields.insert(key, description);
+            }
+        } else if kind == MesgNum::DeveloperDataId {
+            let index: Option<i64> = fields.get(&3).and_then(|v| v.clone().try_into().ok());
+            if let Some(index) = index {
+                self.developer_data_ids.insert(index as u8);
+                self.developer_fields.retain(|k, _| k.0 != index as u8);
+            }
+        }
+    }
+
+    /// Write the original bytes of an unmodified record along with the definition messages that
+    /// preceded it, its definition is written again if another record replaced it.
+    fn serialize_layout(&mut self, layout: &MessageLayout) {
+        if self.buffer.is_empty() && self.header.is_none() {
+            self.header = layout.header.clone();
+        }
+        for (def_mesg, bytes) in &layout.definitions {
+            self.buffer.extend_from_slice(bytes);
+            self.set_definition((**def_mesg).clone());
+        }
+        let local_message_number = layout.definition.local_message_number() as usize;
+        if self.definitions.get(local_message_number) != Some(&Some((*layout.definition).clone())) {
+            writer::definition_message(&mut self.buffer, &layout.definition);
+            self.set_definition((*layout.definition).clone());
+        }
+        self.buffer.extend_from_slice(&layout.message);
+    }
+
+    /// Assign a definition message to its local message number
+    fn set_definition(&mut self, def_mesg: FitDefinitionMessage) {
+        let local_message_number = def_mesg.local_message_number() as usize;
+        if local_message_number >= self.definitions.len() {
+            self.definitions.resize(local_message_number + 1, None);
+        }
+        self.definitions[local_message_number] = Some(def_mesg);
+    }
+
+    /// Assign a definition message to the next available local message number and write it
+    fn serialize_definition(
+        &mut self,
+        global_message_number: u16,
+        field_definitions: Vec<FieldDefinition>,
+        developer_field_definitions: Vec<DeveloperFieldDefinition>,
+    ) -> usize {
+        let local_message_number = self.next_local_message_number;
+        let def_mesg = FitDefinitionMessage::new(
+            Endianness::Little,
+            local_message_number as u8,
+            global_message_number,
+            field_definitions,
+            developer_field_definitions,
+        );
+        writer::definition_message(&mut self.buffer, &def_mesg);
+        self.set_definition(def_mesg);
+        self.next_local_message_number = (local_message_number + 1) % LOCAL_MESSAGE_COUNT;
+
+        local_message_number
+    }
+
+    /// Wrap the serialized messages with a file header and the data CRC
+    fn finish(self) -> Result<Vec<u8>> {
+        if self.buffer.len() > u32::MAX as usize {
+            return Err(ErrorKind::ValueError(format!(
+                "Data size of {} bytes is too large for a FIT file",
+                self.buffer.len()
+            ))
+            .into());
+        }
+        let data_size = (self.buffer.len() as u32).to_le_bytes();
+        let mut output = match self.header {
+            // keep the size, versions and header CRC use of the original file header
+            Some(mut header) => {
+                header[4..8].copy_from_slice(&data_size);
+                if header.len() >= 14 && header[12..14] != [0, 0] {
+                    let crc = caculate_crc(&header[..12]);
+                    header[12..14].copy_from_slice(&crc.to_le_bytes());
+                }
+                header
+            }
+            None => writer::fit_file_header(
+                PROTOCOL_VERSION,
+                profile_version(),
+                self.buffer.len() as u32,
+            ),
+        };
+        // a header CRC of 0 is treated as missing so the header is included in the data CRC
+        let crc = if output.len() < 14 || output[12..14] == [0, 0] {
+            update_crc(update_crc(0, &output), &self.buffer)
+        } else {
+            update_crc(0, &self.buffer)
+        };
+        output.extend_from_slice(&self.buffer);
+        output.extend_from_slice(&crc.to_le_bytes());
+
+        Ok(output)
+    }
+}
+
+/// Convert the profile version string into the value stored in the file header
+fn profile_version() -> u16 {
+    let mut parts = VERSION.split('.').map(|p| p.parse::<u16>().unwrap_or(0));
+    let major = parts.next().unwrap_or(0);
+    let minor = parts.next().unwrap_or(0);
+    // minor versions past 99 are encoded using a scale of 1000 instead of 100
+    if minor > 99 {
+        major * 1000 + minor
+    } else {
+        major * 100 + minor
+    }
+}
+
+/// Check if a message defines the developer fields of the messages that follow it
+fn is_developer_data_message(kind: MesgNum) -> bool {
+    kind == MesgNum::DeveloperDataId || kind == MesgNum::FieldDescription
+}
+
+/// Convert a data record into the field definitions and raw values needed to write it, the
+/// developer fields are encoded separately using the field descriptions written before them.
+fn encode_record(record: &FitDataRecord) -> Result<(Vec<FieldDefinition>, HashMap<u8, Value>)> {
+    let mut field_definitions = Vec::new();
+    let mut fields = HashMap::new();
+    for field in record.fields() {
+        if field.developer_data_index().is_some() {
+            continue;
+        }
+        // a field number can only be written once, this can occur when subfields or
+        // expanded components share a number with another field
+        if fields.contains_key(&field.number()) {
+            continue;
+        }
+        let (base_type, value) = match record.kind().get_field_info(field.number(), field.name()) {
+            Some(info) => (
+                info.field_type().base_type(),
+                profile::raw_value_with_info(info, field.value().clone())?,
+            ),
+            None => unknown_field_value(field.value()),
+        };
+        field_definitions.push(FieldDefinition::new(
+            field.number(),
+            field_size(&value, base_type)?,
+            base_type,
+        ));
+        fields.insert(field.number(), value);
+    }
+
+    Ok((field_definitions, fields))
+}
+
+/// Check that a value can be written for a field defined by the profile
+pub(crate) fn check_field_value(info: &profile::FieldInfo, value: &Value) -> Result<()> {
+    let base_type = info.field_type().base_type();
+    let value = profile::raw_value_with_info(info, value.clone())?;
+    let size = field_size(&value, base_type)?;
+    writer::data_field_value(&mut Vec::new(), Some(&value), base_type, false, size)
+}
+
+/// Determine the base type of a field that doesn't exist in the profile from its value
+fn unknown_field_value(value: &Value) -> (BaseType, Value) {
+    let base_type = match value {
+        Value::Enum(_) => BaseType::Enum,
+        Value::SInt8(_) => BaseType::SInt8,
+        Value::UInt8(_) => BaseType::UInt8,
+        Value::SInt16(_) => BaseType::SInt16,
+        Value::UInt16(_) => BaseType::UInt16,
+        Value::SInt32(_) => BaseType::SInt32,
+        Value::UInt32(_) => BaseType::UInt32,
+        Value::String(_) => BaseType::String,
+        Value::Timestamp(val) => {
+            return (
+                BaseType::UInt32,
+                Value::SInt64(TimestampField::utc_from_date_time(val).as_i64()),
+            );
+        }
+        Value::LocalTimestamp(val) => {
+            return (
+                BaseType::UInt32,
+                Value::SInt64(TimestampField::local_from_wall_clock(val).as_i64()),
+            );
+        }
+        Value::Float32(_) => BaseType::Float32,
+        Value::Float64(_) => BaseType::Float64,
+        Value::UInt8z(_) => BaseType::UInt8z,
+        Value::UInt16z(_) => BaseType::UInt16z,
+        Value::UInt32z(_) => BaseType::UInt32z,
+        Value::Byte(_) => BaseType::Byte,
+        Value::SInt64(_) => BaseType::SInt64,
+        Value::UInt64(_) => BaseType::UInt64,
+        Value::UInt64z(_) => BaseType::UInt64z,
+        Value::Array(vals) => vals
+            .first()
+            .map_or(BaseType::Byte, |v| unknown_field_value(v).0),
+    };
+    (base_type, value.clone())
+}
+
+/// Calculate the number of bytes needed to store a value, strings include a NUL terminator
+fn field_size(value: &Value, base_type: BaseType) -> Result<u8> {
+    let size = match value {
+        Value::String(val) => val.len() + 1,
+        Value::Array(vals) => vals.len() * base_type.size() as usize,
+        _ => base_type.size() as usize,
+    };
+    if size > u8::MAX as usize {
+        return Err(ErrorKind::ValueError(format!(
+            "Field value {} requires {} bytes, the maximum field size is 255 bytes",
+            value, size
+        ))
+        .into());
+    }
+    Ok(size as u8)
+}
+
+/// Serialize FIT data records into the bytes of a single FIT file
+pub fn to_bytes(records: &[FitDataRecord]) -> Result<Vec<u8>> {
+    let mut serializer = Serializer::new();
+    for record in records {
+        serializer.serialize_record(record)?;
+    }
+    serializer.finish()
+}
+
+/// Serialize FIT data records as a single FIT file into a destination that implements io::Write
+#[cfg(feature = "std")]
+pub fn to_writer<T: Write>(records: &[FitDataRecord], dest: &mut T) -> Result<()> {
+    dest.write_all(&to_bytes(records)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+    use std::convert::TryInto;
+
+    /// Verify that records match after being written and parsed again, values are compared
+    /// as floats with a tolerance to account for the rounding of scaled values
+    fn assert_round_trip(data: &[u8]) {
+        let records = from_bytes(data).unwrap();
+        let encoded = to_bytes(&records).unwrap();
+        let decoded = from_bytes(&encoded).unwrap();
+        assert_eq!(records.len(), decoded.len());
+        for (rec, other) in records.iter().zip(decoded.iter()) {
+            assert_eq!(rec.kind(), other.kind());
+            assert_eq!(rec.fields().len(), other.fields().len(), "{:?}", rec);
+            for (fld, other_fld) in rec.fields().iter().zip(other.fields().iter()) {
+                assert_eq!(fld.name(), other_fld.name());
+                assert_eq!(fld.number(), other_fld.number());
+                let val: Result<f64> = fld.value().clone().try_into();
+                let other_val: Result<f64> = other_fld.value().clone().try_into();
+                match (val, other_val) {
+                    (Ok(val), Ok(other_val)) => {
+                        assert!((val - other_val).abs() < 1e-3, "{} != {}", fld, other_fld)
+                    }
+                    _ => assert_eq!(fld.value(), other_fld.value()),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn profile_version_test() {
+        assert_eq!(profile_version(), 21105);
+    }
+
+    #[test]
+    fn encode_activity() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        assert_round_trip(data);
+    }
+
+    #[test]
+    fn encode_settings() {
+        let data = include_bytes!("../../tests/fixtures/Settings.fit");
+        assert_round_trip(data);
+    }
+
+    #[test]
+    fn encode_garmin_fenix_5_bike() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        assert_round_trip(data);
+    }
+
+    #[test]
+    fn encode_developer_data() {
+        let data = include_bytes!("../../tests/fixtures/DeveloperData.fit");
+        assert_round_trip(data);
+    }
+
+    #[test]
+    fn encode_undescribed_developer_field() {
+        let mut record = FitDataRecord::new(profile::MesgNum::Record);
+        record
+            .set_field_value("heart_rate", Value::UInt8(140))
+            .unwrap();
+        record.push(FitDataField::new_developer_field(
+            "doughnuts_earned",
+            0,
+            0,
+            Value::Float32(1.5),
+            "doughnuts",
+        ));
+        let decoded = from_bytes(&to_bytes(&[record.clone(), record]).unwrap()).unwrap();
+        let kinds: Vec<_> = decoded.iter().map(|r| r.kind()).collect();
+        assert_eq!(
+            kinds,
+            [
+                profile::MesgNum::DeveloperDataId,
+                profile::MesgNum::FieldDescription,
+                profile::MesgNum::Record,
+                profile::MesgNum::Record,
+            ]
+        );
+        for record in &decoded[2..] {
+            let field = record.field("doughnuts_earned").unwrap();
+            assert_eq!(field.developer_data_index(), Some(0));
+            assert_eq!(field.value(), &Value::Float32(1.5));
+            assert_eq!(field.units(), "doughnuts");
+        }
+    }
+
+    #[test]
+    fn encode_long_variant_names() {
+        // the variant names are long enough that the generated match arms wrap
+        let mut settings = FitDataRecord::new(profile::MesgNum::DeviceSettings);
+        let mode = Value::String(String::from("key_and_messages_and_smart_notifications"));
+        settings
+            .set_field_value("backlight_mode", mode.clone())
+            .unwrap();
+        let mut step = FitDataRecord::new(profile::MesgNum::WorkoutStep);
+        let duration = Value::String(String::from("repeat_until_power_last_lap_less_than"));
+        step.set_field_value("duration_type", duration.clone())
+            .unwrap();
+
+        let decoded = from_bytes(&to_bytes(&[settings, step]).unwrap()).unwrap();
+        assert_eq!(decoded[0].field_value("backlight_mode"), Some(&mode));
+        assert_eq!(decoded[1].field_value("duration_type"), Some(&duration));
+    }
+
+    #[test]
+    fn encode_reuses_definition_messages() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+        let mut serializer = Serializer::new();
+        for record in &records {
+            serializer.serialize_record(record).unwrap();
+        }
+        assert!(serializer.definitions.len() < records.len());
+    }
+
+    #[test]
+    fn encode_preserved_layout() {
+        let mut options = crate::compat::HashSet::new();
+        options.insert(crate::de::DecodeOption::PreserveLayout);
+        let files: [&[u8]; 10] = [
+            include_bytes!("../../tests/fixtures/Activity.fit"),
+            include_bytes!("../../tests/fixtures/DeveloperData.fit"),
+            include_bytes!("../../tests/fixtures/MonitoringFile.fit"),
+            include_bytes!("../../tests/fixtures/Settings.fit"),
+            include_bytes!("../../tests/fixtures/WeightScaleMultiUser.fit"),
+            include_bytes!("../../tests/fixtures/WeightScaleSingleUser.fit"),
+            include_bytes!("../../tests/fixtures/WorkoutCustomTargetValues.fit"),
+            include_bytes!("../../tests/fixtures/WorkoutIndividualSteps.fit"),
+            include_bytes!("../../tests/fixtures/WorkoutRepeatSteps.fit"),
+            include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit"),
+        ];
+        for data in files.iter() {
+            let records = crate::de::from_bytes_with_options(data, &options).unwrap();
+            assert_eq!(to_bytes(&records).unwrap().as_slice(), *data);
+        }
+
+        // modified records are encoded again and the others keep their layout
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let mut records = crate::de::from_bytes_with_options(data, &options).unwrap();
+        records[4]
+            .set_field_value("heart_rate", Value::UInt8(140))
+            .unwrap();
+        records.remove(5);
+        let decoded = from_bytes(&to_bytes(&records).unwrap()).unwrap();
+        assert_eq!(decoded.len(), records.len());
+        assert!(decoded[4].field("heart_rate").is_some());
+        for (rec, other) in records.iter().zip(decoded.iter()) {
+            assert_eq!(rec.fields().len(), other.fields().len());
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! Helper functions needed to write FIT file objects as bytes.
+use crate::compat::prelude::*;
+use crate::de::{caculate_crc, BaseType, FitDataMessage, FitDefinitionMessage};
+use crate::error::{ErrorKind, Result};
+use crate::Value;
+use core::convert::TryInto;
+use nom::number::Endianness;
+
+/// Write a 14 byte FIT file header, the CRC is calculated from the preceding 12 bytes.
+pub fn fit_file_header(protocol_ver_enc: u8, profile_ver_enc: u16, data_size: u32) -> Vec<u8> {
+    let mut output = Vec::with_capacity(14);
+    output.push(14);
+    output.push(protocol_ver_enc);
+    output.extend_from_slice(&profile_ver_enc.to_le_bytes());
+    output.extend_from_slice(&data_size.to_le_bytes());
+    output.extend_from_slice(b".FIT");
+    let crc = caculate_crc(&output);
+    output.extend_from_slice(&crc.to_le_bytes());
+    output
+}
+
+/// Write a definition message including the message header byte
+pub fn definition_message(output: &mut Vec<u8>, def_mesg: &FitDefinitionMessage) {
+    let mut header = 0x40 | (def_mesg.local_message_number() & 0xF);
+    if !def_mesg.developer_field_definitions().is_empty() {
+        header |= 0x20;
+    }
+    output.push(header);
+    output.push(0); // reserved byte
+    let big_endian = is_big_endian(def_mesg.byte_order());
+    output.push(big_endian as u8);
+    if big_endian {
+        output.extend_from_slice(&def_mesg.global_message_number().to_be_bytes());
+    } else {
+        output.extend_from_slice(&def_mesg.global_message_number().to_le_bytes());
+    }
+    output.push(def_mesg.field_definitions().len() as u8);
+    for field_def in def_mesg.field_definitions() {
+        output.push(field_def.field_definition_number());
+        output.push(field_def.size());
+        output.push(field_def.base_type() as u8);
+    }
+    if !def_mesg.developer_field_definitions().is_empty() {
+        output.push(def_mesg.developer_field_definitions().len() as u8);
+        for field_def in def_mesg.developer_field_definitions() {
+            output.push(field_def.field_number());
+            output.push(field_def.size());
+            output.push(field_def.developer_data_index());
+        }
+    }
+}
+
+/// Write a data message using the layout described by its definition message, fields without
+/// a value are filled in using the invalid value of the base type.
+pub fn data_message(
+    output: &mut Vec<u8>,
+    def_mesg: &FitDefinitionMessage,
+    message: &FitDataMessage,
+) -> Result<()> {
+    output.push(def_mesg.local_message_number() & 0xF);
+    let big_endian = is_big_endian(def_mesg.byte_order());
+    for field_def in def_mesg.field_definitions() {
+        data_field_value(
+            output,
+            message.fields().get(&field_def.field_definition_number()),
+            field_def.base_type(),
+            big_endian,
+            field_def.size(),
+        )?;
+    }
+    // developer data is stored as raw bytes in the byte order of the message, see the parser
+    let mut developer_fields = message.developer_fields().iter();
+    for field_def in def_mesg.developer_field_definitions() {
+        let data = developer_fields.next().map_or(&[][..], |f| f.data());
+        let size = field_def.size() as usize;
+        output.extend(data.iter().take(size));
+        output.resize(output.len() + size.saturating_sub(data.len()), 0xFF);
+    }
+
+    Ok(())
+}
+
+/// Check the byte order of a message, parsed messages will never be native
+fn is_big_endian(byte_order: Endianness) -> bool {
+    match byte_order {
+        Endianness::Big => true,
+        Endianness::Little => false,
+        Endianness::Native => cfg!(target_endian = "big"),
+    }
+}
+
+/// Write a single raw data value, array values are padded out to the field size with invalid
+/// values.
+pub fn data_field_value(
+    output: &mut Vec<u8>,
+    value: Option<&Value>,
+    base_type: BaseType,
+    big_endian: bool,
+    size: u8,
+) -> Result<()> {
+    if let BaseType::String = base_type {
+        let mut bytes = match value {
+            Some(Value::String(val)) => val.as_bytes().to_vec(),
+            Some(val) => return Err(value_error(val, base_type)),
+            None => Vec::new(),
+        };
+        if bytes.len() > size as usize {
+            return Err(ErrorKind::ValueError(format!(
+                "String of length {} does not fit in a field of {} bytes",
+                bytes.len(),
+                size
+            ))
+            .into());
+        }
+        bytes.resize(size as usize, 0);
+        output.extend_from_slice(&bytes);
+        return Ok(());
+    }
+
+    let values: &[Value] = match value {
+        Some(Value::Array(vals)) => vals,
+        Some(val) => core::slice::from_ref(val),
+        None => &[],
+    };
+    let count = (size / base_type.size()) as usize;
+    if values.len() > count {
+        return Err(ErrorKind::ValueError(format!(
+            "{} values do not fit in a field of {} bytes",
+            values.len(),
+            size
+        ))
+        .into());
+    }
+    for value in values {
+        write_value(output, value, base_type, big_endian)?;
+    }
+    let invalid = invalid_value(base_type);
+    for _ in values.len()..count {
+        write_value(output, &invalid, base_type, big_endian)?;
+    }
+
+    Ok(())
+}
+
+/// Write a numeric value as the provided base type, floating point values are rounded when a
+/// integer base type is used.
+fn write_value(
+    output: &mut Vec<u8>,
+    value: &Value,
+    base_type: BaseType,
+    big_endian: bool,
+) -> Result<()> {
+    // use macro to duplicate the same byte conversion logic for each numeric type
+    macro_rules! write_as {
+        ($typ:ty, $val:expr) => {{
+            let val: $typ = $val;
+            if big_endian {
+                output.extend_from_slice(&val.to_be_bytes());
+            } else {
+                output.extend_from_slice(&val.to_le_bytes());
+            }
+        }};
+    }
+    macro_rules! write_integer_as {
+        ($typ:ty) => {
+            write_as!(
+                $typ,
+                integer_value(value)?
+                    .try_into()
+                    .map_err(|_| value_error(value, base_type))?
+            )
+        };
+    }
+
+    match base_type {
+        BaseType::Enum | BaseType::UInt8 | BaseType::UInt8z | BaseType::Byte => {
+            write_integer_as!(u8)
+        }
+        BaseType::SInt8 => write_integer_as!(i8),
+        BaseType::SInt16 => write_integer_as!(i16),
+        BaseType::UInt16 | BaseType::UInt16z => write_integer_as!(u16),
+        BaseType::SInt32 => write_integer_as!(i32),
+        BaseType::UInt32 | BaseType::UInt32z => write_integer_as!(u32),
+        BaseType::SInt64 => write_integer_as!(i64),
+        BaseType::UInt64 | BaseType::UInt64z => write_integer_as!(u64),
+        BaseType::Float32 => match value {
+            Value::Float32(val) => write_as!(f32, *val),
+            _ => write_as!(f32, float_value(value)? as f32),
+        },
+        BaseType::Float64 => write_as!(f64, float_value(value)?),
+        BaseType::String => return Err(value_error(value, base_type)),
+    }
+
+    Ok(())
+}
+
+/// Convert a numeric value into an integer large enough to represent any base type
+fn integer_value(value: &Value) -> Result<i128> {
+    match value {
+        Value::UInt64(val) => Ok(*val as i128),
+        Value::UInt64z(val) => Ok(*val as i128),
+        Value::Float32(val) if val.is_finite() => Ok(val.round() as i128),
+        Value::Float64(val) if val.is_finite() => Ok(val.round() as i128),
+        Value::Float32(_) | Value::Float64(_) => Err(ErrorKind::ValueError(format!(
+            "Non-finite value {} cannot be converted to an integer",
+            value
+        ))
+        .into()),
+        _ => {
+            let val: i64 = value.try_into()?;
+            Ok(val as i128)
+        }
+    }
+}
+
+/// Convert a numeric value into a floating point value
+fn float_value(value: &Value) -> Result<f64> {
+    match value {
+        Value::Float32(val) => Ok(*val as f64),
+        Value::Float64(val) => Ok(*val),
+        _ => Ok(integer_value(value)? as f64),
+    }
+}
+
+/// The value used to mark a field as invalid for each base type
+fn invalid_value(base_type: BaseType) -> Value {
+    match base_type {
+        BaseType::Enum => Value::Enum(0xFF),
+        BaseType::SInt8 => Value::SInt8(0x7F),
+        BaseType::UInt8 => Value::UInt8(0xFF),
+        BaseType::SInt16 => Value::SInt16(0x7FFF),
+        BaseType::UInt16 => Value::UInt16(0xFFFF),
+        BaseType::SInt32 => Value::SInt32(0x7FFF_FFFF),
+        BaseType::UInt32 => Value::UInt32(0xFFFF_FFFF),
+        BaseType::String => Value::String(String::new()),
+        BaseType::Float32 => Value::Float32(f32::from_bits(0xFFFF_FFFF)),
+        BaseType::Float64 => Value::Float64(f64::from_bits(0xFFFF_FFFF_FFFF_FFFF)),
+        BaseType::UInt8z => Value::UInt8z(0x0),
+        BaseType::UInt16z => Value::UInt16z(0x0),
+        BaseType::UInt32z => Value::UInt32z(0x0),
+        BaseType::Byte => Value::Byte(0xFF),
+        BaseType::SInt64 => Value::SInt64(0x7FFF_FFFF_FFFF_FFFF),
+        BaseType::UInt64 => Value::UInt64(0xFFFF_FFFF_FFFF_FFFF),
+        BaseType::UInt64z => Value::UInt64z(0x0),
+    }
+}
+
+fn value_error(value: &Value, base_type: BaseType) -> crate::Error {
+    ErrorKind::ValueError(format!("{} cannot be stored as a {:?}", value, base_type)).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::de::FieldDefinition;
+    use std::collections::HashMap;
+
+    #[test]
+    fn fit_file_header_test() {
+        let data = fit_file_header(0x20, 21105, 757);
+        assert_eq!(data.len(), 14);
+        assert_eq!(
+            &data[0..12],
+            &[14, 0x20, 0x71, 0x52, 0xF5, 2, 0, 0, b'.', b'F', b'I', b'T']
+        );
+        assert_eq!(
+            u16::from_le_bytes([data[12], data[13]]),
+            caculate_crc(&data[0..12])
+        );
+    }
+
+    #[test]
+    fn definition_message_test() {
+        let def_mesg = FitDefinitionMessage::new(
+            Endianness::Big,
+            3,
+            20,
+            vec![
+                FieldDefinition::new(253, 4, BaseType::UInt32),
+                FieldDefinition::new(3, 1, BaseType::UInt8),
+            ],
+            Vec::new(),
+        );
+        let mut output = Vec::new();
+        definition_message(&mut output, &def_mesg);
+        assert_eq!(output, vec![0x43, 0, 1, 0, 20, 2, 253, 4, 0x86, 3, 1, 0x02]);
+    }
+
+    #[test]
+    fn data_message_test() {
+        let def_mesg = FitDefinitionMessage::new(
+            Endianness::Little,
+            1,
+            20,
+            vec![
+                FieldDefinition::new(253, 4, BaseType::UInt32),
+                FieldDefinition::new(0, 6, BaseType::SInt16),
+                FieldDefinition::new(1, 4, BaseType::String),
+                FieldDefinition::new(2, 1, BaseType::UInt8),
+            ],
+            Vec::new(),
+        );
+        let mut fields = HashMap::new();
+        fields.insert(253, Value::SInt64(1_000));
+        fields.insert(
+            0,
+            Value::Array(vec![Value::Float64(-1.6), Value::SInt16(2)]),
+        );
+        fields.insert(1, Value::String(String::from("ab")));
+        let message = FitDataMessage::new(20, fields);
+
+        let mut output = Vec::new();
+        data_message(&mut output, &def_mesg, &message).unwrap();
+        assert_eq!(
+            output,
+            vec![1, 0xE8, 3, 0, 0, 0xFE, 0xFF, 2, 0, 0xFF, 0x7F, b'a', b'b', 0, 0, 0xFF]
+        );
+    }
+
+    #[test]
+    fn data_field_value_out_of_range_test() {
+        let mut output = Vec::new();
+        assert!(data_field_value(
+            &mut output,
+            Some(&Value::SInt64(256)),
+            BaseType::UInt8,
+            false,
+            1
+        )
+        .is_err());
+        assert!(data_field_value(
+            &mut output,
+            Some(&Value::String(String::from("too long"))),
+            BaseType::String,
+            false,
+            4
+        )
+        .is_err());
+    }
+}
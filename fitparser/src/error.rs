@@ -1,10 +1,14 @@
-use crate::de::FitObject;
+use crate::compat::prelude::*;
+use crate::de::{FitObject, Warning};
+use crate::profile::MesgNum;
+use core::fmt;
+#[cfg(feature = "std")]
 use std::error::Error as StdError;
-use std::io;
-use std::{error, fmt};
+#[cfg(feature = "std")]
+use std::{error, io};
 
 /// The result of a deserialization operation.
-pub type Result<T> = ::std::result::Result<T, Error>;
+pub type Result<T> = ::core::result::Result<T, Error>;
 
 /// An error that can be produced during deserializing.
 pub type Error = Box<ErrorKind>;
@@ -19,6 +23,7 @@ pub enum ErrorKind {
     /// data.
     InvalidCrc((Vec<u8>, FitObject, u16, u16)),
     /// Errors tied to IO issues and not the actual parsing steps.
+    #[cfg(feature = "std")]
     Io(io::Error),
     /// If a definition mesage can't be found, postion of message and local message number
     MissingDefinitionMessage(u8, usize),
@@ -30,8 +35,109 @@ pub enum ErrorKind {
     UnexpectedEof(nom::Needed),
     /// Errors related to interactions with a Value enum
     ValueError(String),
+    /// Decoding was stopped using a `de::CancellationToken`
+    Cancelled,
+    /// One of the `de::DecodeLimits` was exceeded, the message describes the limit
+    LimitExceeded(String),
+    /// An error that occurred while decoding an object along with where it occurred in the file
+    Context(DecodeContext, Error),
+    /// A warning found while decoding with `DecodeOption::FailOnWarnings`
+    Warning(Warning),
 }
 
+impl ErrorKind {
+    /// Return where the error occurred if it is known
+    pub fn context(&self) -> Option<&DecodeContext> {
+        match self {
+            ErrorKind::Context(context, _) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Return the error without its decode context
+    pub fn root(&self) -> &ErrorKind {
+        match self {
+            ErrorKind::Context(_, err) => err.root(),
+            err => err,
+        }
+    }
+}
+
+/// Location of a decoding error, values that weren't known when the error occurred are None
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DecodeContext {
+    pub(crate) offset: Option<usize>,
+    pub(crate) record_index: Option<usize>,
+    pub(crate) kind: Option<MesgNum>,
+    pub(crate) field: Option<String>,
+}
+
+impl DecodeContext {
+    /// Return the byte offset of the object being decoded from the start of the stream
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Return the index of the data message being decoded, counting from 0
+    pub fn record_index(&self) -> Option<usize> {
+        self.record_index
+    }
+
+    /// Return the kind of message being decoded
+    pub fn kind(&self) -> Option<MesgNum> {
+        self.kind
+    }
+
+    /// Return the name of the field being decoded
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+}
+
+impl fmt::Display for DecodeContext {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(offset) = self.offset {
+            parts.push(format!("offset {:#x}", offset));
+        }
+        if let Some(index) = self.record_index {
+            parts.push(format!("record {}", index));
+        }
+        if let Some(kind) = self.kind {
+            parts.push(format!("{} message", kind));
+        }
+        if let Some(field) = &self.field {
+            parts.push(format!("field {}", field));
+        }
+        write!(fmt, "{}", parts.join(", "))
+    }
+}
+
+/// Add where an error occurred to it, values already stored by the error are kept. Errors used
+/// to control decoding, such as running out of data or a CRC mismatch, are returned unchanged so
+/// they can still be matched directly.
+pub(crate) fn with_context(mut err: Error, context: DecodeContext) -> Error {
+    match &mut *err {
+        ErrorKind::Context(existing, _) => {
+            existing.offset = existing.offset.or(context.offset);
+            existing.record_index = existing.record_index.or(context.record_index);
+            existing.kind = existing.kind.or(context.kind);
+            if existing.field.is_none() {
+                existing.field = context.field;
+            }
+            err
+        }
+        ErrorKind::InvalidCrc(..)
+        | ErrorKind::UnexpectedEof(..)
+        | ErrorKind::Cancelled
+        | ErrorKind::Warning(..) => err,
+        #[cfg(feature = "std")]
+        ErrorKind::Io(..) => err,
+        _ => ErrorKind::Context(context, err).into(),
+    }
+}
+
+#[cfg(feature = "std")]
 impl StdError for ErrorKind {
     fn cause(&self) -> Option<&dyn error::Error> {
         match *self {
@@ -42,10 +148,15 @@ impl StdError for ErrorKind {
             ErrorKind::ParseError(..) => None, // TODO, I should chain nom's error in here somehow
             ErrorKind::UnexpectedEof(..) => None,
             ErrorKind::ValueError(..) => None,
+            ErrorKind::Cancelled => None,
+            ErrorKind::LimitExceeded(..) => None,
+            ErrorKind::Context(_, ref err) => Some(err.as_ref()),
+            ErrorKind::Warning(..) => None,
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl From<io::Error> for Error {
     fn from(err: io::Error) -> Error {
         ErrorKind::Io(err).into()
@@ -54,7 +165,7 @@ impl From<io::Error> for Error {
 
 impl fmt::Display for ErrorKind {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        match &*self {
+        match self {
             ErrorKind::InvalidCrc((_, obj, exp_val, calc_val)) => match obj {
                 FitObject::Header(_) => write!(
                     fmt,
@@ -67,6 +178,7 @@ impl fmt::Display for ErrorKind {
                     exp_val, calc_val
                 ),
             },
+            #[cfg(feature = "std")]
             ErrorKind::Io(ref ioerr) => write!(fmt, "io error: {}", ioerr),
             ErrorKind::TrailingBytes(rem) => {
                 write!(fmt, "{} bytes remain past expected EOF location", rem)
@@ -89,6 +201,10 @@ impl fmt::Display for ErrorKind {
                 write!(fmt, "parser error: requires more data")
             }
             ErrorKind::ValueError(ref message) => write!(fmt, "value error: {}", message),
+            ErrorKind::Cancelled => write!(fmt, "decoding was cancelled"),
+            ErrorKind::LimitExceeded(ref message) => write!(fmt, "limit exceeded: {}", message),
+            ErrorKind::Context(ref context, ref err) => write!(fmt, "{} at {}", err, context),
+            ErrorKind::Warning(ref warning) => write!(fmt, "strict decoding failed: {}", warning),
         }
     }
 }
@@ -0,0 +1,338 @@
+//! Read the `event` messages of an activity into typed values. Timer events are paired into the
+//! periods the timer was running, which gives the timer time of an activity, and gear changes,
+//! battery alerts and auto pauses are returned in the order they were recorded.
+use crate::compat::prelude::*;
+use crate::export::{numeric_field, timestamp_field};
+use crate::profile::MesgNum;
+use crate::{FitDataRecord, TimestampTz, Value};
+use chrono::{DateTime, Duration};
+
+/// Period between a timer start event and the following stop event, the end is None when the
+/// timer was never stopped
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimerPeriod {
+    start: DateTime<TimestampTz>,
+    end: Option<DateTime<TimestampTz>>,
+}
+
+impl TimerPeriod {
+    /// Return the timestamp of the start event
+    pub fn start(&self) -> DateTime<TimestampTz> {
+        self.start
+    }
+
+    /// Return the timestamp of the stop event
+    pub fn end(&self) -> Option<DateTime<TimestampTz>> {
+        self.end
+    }
+
+    /// Return the time between the start and stop events
+    pub fn duration(&self) -> Option<Duration> {
+        self.end.map(|end| end - self.start)
+    }
+}
+
+/// Chainring or cassette changed by a gear change event
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Gear {
+    /// Front chainring
+    Front,
+    /// Rear cassette
+    Rear,
+}
+
+/// Gear selected after a front or rear gear change event
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GearChange {
+    timestamp: DateTime<TimestampTz>,
+    gear: Gear,
+    front_gear_num: Option<u8>,
+    front_gear: Option<u8>,
+    rear_gear_num: Option<u8>,
+    rear_gear: Option<u8>,
+}
+
+impl GearChange {
+    /// Return the timestamp of the event
+    pub fn timestamp(&self) -> DateTime<TimestampTz> {
+        self.timestamp
+    }
+
+    /// Return the gear that was changed
+    pub fn gear(&self) -> Gear {
+        self.gear
+    }
+
+    /// Return the number of the front gear, starting with 1 for the smallest chainring
+    pub fn front_gear_num(&self) -> Option<u8> {
+        self.front_gear_num
+    }
+
+    /// Return the number of teeth of the front gear
+    pub fn front_gear(&self) -> Option<u8> {
+        self.front_gear
+    }
+
+    /// Return the number of the rear gear, starting with 1 for the largest cog
+    pub fn rear_gear_num(&self) -> Option<u8> {
+        self.rear_gear_num
+    }
+
+    /// Return the number of teeth of the rear gear
+    pub fn rear_gear(&self) -> Option<u8> {
+        self.rear_gear
+    }
+}
+
+/// Battery level reported by a battery event or a low battery alert
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BatteryAlert {
+    timestamp: DateTime<TimestampTz>,
+    low: bool,
+    voltage: Option<f64>,
+}
+
+impl BatteryAlert {
+    /// Return the timestamp of the event
+    pub fn timestamp(&self) -> DateTime<TimestampTz> {
+        self.timestamp
+    }
+
+    /// Check if the event is a low battery alert
+    pub fn is_low(&self) -> bool {
+        self.low
+    }
+
+    /// Return the battery voltage in V
+    pub fn voltage(&self) -> Option<f64> {
+        self.voltage
+    }
+}
+
+/// Find a field by name and return its value if it is a string
+fn string_value<'a>(record: &'a FitDataRecord, name: &str) -> Option<&'a str> {
+    record.fields().iter().find_map(|f| match f.value() {
+        Value::String(val) if f.name() == name => Some(val.as_str()),
+        _ => None,
+    })
+}
+
+/// Return the timestamped `event` messages of an event kind
+fn events<'a>(
+    records: &'a [FitDataRecord],
+    event: &'a str,
+) -> impl Iterator<Item = (DateTime<TimestampTz>, &'a FitDataRecord)> + 'a {
+    records
+        .iter()
+        .filter(|r| r.kind() == MesgNum::Event)
+        .filter(move |r| string_value(r, "event") == Some(event))
+        .filter_map(|r| Some((timestamp_field(r, "timestamp")?, r)))
+}
+
+/// Check if a timer event stops the timer
+fn is_stop(record: &FitDataRecord) -> bool {
+    matches!(
+        string_value(record, "event_type"),
+        Some("stop" | "stop_all" | "stop_disable" | "stop_disable_all")
+    )
+}
+
+/// Pair the timer start and stop events into the periods the timer was running, repeated start
+/// or stop events are ignored
+pub fn timer_periods(records: &[FitDataRecord]) -> Vec<TimerPeriod> {
+    let mut periods: Vec<TimerPeriod> = Vec::new();
+    for (timestamp, record) in events(records, "timer") {
+        let running = periods.last().is_some_and(|p| p.end.is_none());
+        if string_value(record, "event_type") == Some("start") && !running {
+            periods.push(TimerPeriod {
+                start: timestamp,
+                end: None,
+            });
+        } else if is_stop(record) && running {
+            if let Some(period) = periods.last_mut() {
+                period.end = Some(timestamp);
+            }
+        }
+    }
+    periods
+}
+
+/// Return the total time the timer was running, a period without a stop event is not counted
+pub fn timer_time(records: &[FitDataRecord]) -> Duration {
+    timer_periods(records)
+        .iter()
+        .filter_map(TimerPeriod::duration)
+        .fold(Duration::zero(), |total, d| total + d)
+}
+
+/// Return the time from the first timer start event to the last timer stop event
+pub fn elapsed_time(records: &[FitDataRecord]) -> Option<Duration> {
+    let periods = timer_periods(records);
+    let end = periods.iter().rev().find_map(TimerPeriod::end)?;
+    Some(end - periods.first()?.start)
+}
+
+/// Return the periods the timer was stopped by an auto pause, from the stop event triggered by
+/// the device to the following start event
+pub fn auto_pauses(records: &[FitDataRecord]) -> Vec<TimerPeriod> {
+    let mut pauses: Vec<TimerPeriod> = Vec::new();
+    for (timestamp, record) in events(records, "timer") {
+        let paused = pauses.last().is_some_and(|p| p.end.is_none());
+        if is_stop(record) && !paused && string_value(record, "timer_trigger") == Some("auto") {
+            pauses.push(TimerPeriod {
+                start: timestamp,
+                end: None,
+            });
+        } else if string_value(record, "event_type") == Some("start") && paused {
+            if let Some(pause) = pauses.last_mut() {
+                pause.end = Some(timestamp);
+            }
+        }
+    }
+    pauses
+}
+
+/// Return the front and rear gear change events. The gears are read from the fields expanded
+/// by the decoder, or unpacked from `gear_change_data` for records that weren't decoded.
+pub fn gear_changes(records: &[FitDataRecord]) -> Vec<GearChange> {
+    let front = events(records, "front_gear_change").map(|(t, r)| (t, r, Gear::Front));
+    let rear = events(records, "rear_gear_change").map(|(t, r)| (t, r, Gear::Rear));
+    let mut changes: Vec<GearChange> = front
+        .chain(rear)
+        .map(|(timestamp, record, gear)| {
+            // gear_change_data packs the rear gear number, rear gear, front gear number and
+            // front gear into one byte each, starting with the least significant byte
+            let packed = ["gear_change_data", "data"]
+                .iter()
+                .find_map(|name| numeric_field(record, name))
+                .map(|data| data as u32);
+            let gear_field = |name: &str, byte: u32| {
+                numeric_field(record, name)
+                    .map(|v| v as u8)
+                    .or_else(|| packed.map(|data| (data >> (byte * 8)) as u8))
+                    .filter(|v| *v != u8::MAX)
+            };
+            GearChange {
+                timestamp,
+                gear,
+                front_gear_num: gear_field("front_gear_num", 2),
+                front_gear: gear_field("front_gear", 3),
+                rear_gear_num: gear_field("rear_gear_num", 0),
+                rear_gear: gear_field("rear_gear", 1),
+            }
+        })
+        .collect();
+    changes.sort_by_key(|c| c.timestamp);
+    changes
+}
+
+/// Return the battery events and low battery alerts
+pub fn battery_alerts(records: &[FitDataRecord]) -> Vec<BatteryAlert> {
+    let battery = events(records, "battery").map(|(t, r)| (t, r, false));
+    let low = events(records, "battery_low").map(|(t, r)| (t, r, true));
+    let mut alerts: Vec<BatteryAlert> = battery
+        .chain(low)
+        .map(|(timestamp, record, low)| BatteryAlert {
+            timestamp,
+            low,
+            voltage: numeric_field(record, "battery_level"),
+        })
+        .collect();
+    alerts.sort_by_key(|a| a.timestamp);
+    alerts
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+    use chrono::TimeZone;
+
+    /// Create an event at a number of seconds after the start along with extra fields
+    fn event(
+        seconds: i64,
+        event: &str,
+        event_type: &str,
+        fields: &[(&str, Value)],
+    ) -> FitDataRecord {
+        let start = TimestampTz.timestamp_opt(1_600_000_000, 0).unwrap();
+        let mut record = FitDataRecord::new(MesgNum::Event);
+        let timestamp = Value::Timestamp(start + Duration::seconds(seconds));
+        record.set_field_value("timestamp", timestamp).unwrap();
+        record
+            .set_field_value("event", Value::String(event.into()))
+            .unwrap();
+        record
+            .set_field_value("event_type", Value::String(event_type.into()))
+            .unwrap();
+        for (name, value) in fields {
+            record.set_field_value(name, value.clone()).unwrap();
+        }
+        record
+    }
+
+    #[test]
+    fn timer_events() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+        assert_eq!(timer_periods(&records).len(), 1);
+        assert_eq!(timer_time(&records), Duration::seconds(13));
+        assert_eq!(elapsed_time(&records), Some(Duration::seconds(13)));
+        assert!(auto_pauses(&records).is_empty());
+
+        let auto = || ("timer_trigger", Value::String("auto".into()));
+        let records = vec![
+            event(0, "timer", "start", &[]),
+            event(60, "timer", "stop_all", &[auto()]),
+            event(90, "timer", "start", &[auto()]),
+            event(100, "timer", "start", &[]),
+            event(300, "timer", "stop_all", &[]),
+            event(320, "timer", "start", &[]),
+        ];
+        let records = from_bytes(&crate::encode::to_bytes(&records).unwrap()).unwrap();
+        let periods = timer_periods(&records);
+        assert_eq!(periods.len(), 3);
+        assert_eq!(periods[1].duration(), Some(Duration::seconds(210)));
+        assert_eq!(periods[2].end(), None);
+        assert_eq!(timer_time(&records), Duration::seconds(270));
+        assert_eq!(elapsed_time(&records), Some(Duration::seconds(300)));
+        let pauses = auto_pauses(&records);
+        assert_eq!(pauses.len(), 1);
+        assert_eq!(pauses[0].duration(), Some(Duration::seconds(30)));
+    }
+
+    #[test]
+    fn gear_and_battery_events() {
+        let records = vec![
+            event(20, "battery_low", "marker", &[]),
+            event(
+                10,
+                "rear_gear_change",
+                "marker",
+                &[("gear_change_data", Value::UInt32(0x3402_0B05))],
+            ),
+            event(
+                5,
+                "battery",
+                "marker",
+                &[("battery_level", Value::Float64(3.7))],
+            ),
+        ];
+        let changes = gear_changes(&records);
+        let decoded = from_bytes(&crate::encode::to_bytes(&records).unwrap()).unwrap();
+        assert_eq!(changes, gear_changes(&decoded));
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].gear(), Gear::Rear);
+        assert_eq!(changes[0].front_gear_num(), Some(2));
+        assert_eq!(changes[0].front_gear(), Some(52));
+        assert_eq!(changes[0].rear_gear_num(), Some(5));
+        assert_eq!(changes[0].rear_gear(), Some(11));
+
+        let alerts = battery_alerts(&decoded);
+        assert_eq!(alerts.len(), 2);
+        assert!(!alerts[0].is_low());
+        assert_eq!(alerts[0].voltage(), Some(3.7));
+        assert!(alerts[1].is_low());
+        assert_eq!(alerts[1].voltage(), None);
+    }
+}
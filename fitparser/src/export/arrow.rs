@@ -0,0 +1,222 @@
+//! Convert FIT data records into Arrow record batches and write them as Parquet files, this
+//! module requires the `arrow` feature. A record batch is created for each message kind with a
+//! nullable column for every field name found in the messages.
+use crate::error::{ErrorKind, Result};
+use crate::profile::MesgNum;
+use crate::{FitDataRecord, Value};
+use arrow_array::{
+    ArrayRef, Float64Array, Int64Array, RecordBatch, StringArray, TimestampSecondArray,
+};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use parquet::arrow::ArrowWriter;
+use std::convert::TryInto;
+use std::io::Write;
+use std::sync::Arc;
+
+/// Column types used to store field values
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ColumnType {
+    Integer,
+    Float,
+    Timestamp,
+    String,
+}
+
+impl ColumnType {
+    /// Determine the column type needed to store a single value
+    fn from_value(value: &Value) -> Self {
+        match value {
+            Value::Timestamp(_) => ColumnType::Timestamp,
+            Value::Float32(_) | Value::Float64(_) => ColumnType::Float,
+            Value::UInt64(val) | Value::UInt64z(val) if *val > i64::MAX as u64 => ColumnType::Float,
+            // local timestamps don't have a time zone so they're kept as their wall clock time
+            Value::String(_) | Value::LocalTimestamp(_) | Value::Array(_) => ColumnType::String,
+            _ => ColumnType::Integer,
+        }
+    }
+
+    /// Combine the types of two values stored in the same column, mixed numeric values are
+    /// stored as floats and anything else falls back to a string.
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (ColumnType::Integer, ColumnType::Float) | (ColumnType::Float, ColumnType::Integer) => {
+                ColumnType::Float
+            }
+            _ => ColumnType::String,
+        }
+    }
+
+    /// Arrow data type of the column
+    fn data_type(self) -> DataType {
+        match self {
+            ColumnType::Integer => DataType::Int64,
+            ColumnType::Float => DataType::Float64,
+            ColumnType::Timestamp => DataType::Timestamp(TimeUnit::Second, Some("UTC".into())),
+            ColumnType::String => DataType::Utf8,
+        }
+    }
+}
+
+/// Convert arrow and parquet errors into a value error
+fn arrow_error<E: std::fmt::Display>(err: E) -> crate::Error {
+    ErrorKind::ValueError(format!("arrow error: {}", err)).into()
+}
+
+/// Build a column from the values of a single field, missing values are stored as nulls
+fn build_column(values: &[Option<&Value>], column_type: ColumnType) -> Result<ArrayRef> {
+    let column: ArrayRef = match column_type {
+        ColumnType::Integer => Arc::new(Int64Array::from(
+            values
+                .iter()
+                .map(|v| v.and_then(|v| v.clone().try_into().ok()))
+                .collect::<Vec<Option<i64>>>(),
+        )),
+        ColumnType::Float => Arc::new(Float64Array::from(
+            values
+                .iter()
+                .map(|v| v.and_then(|v| v.clone().try_into().ok()))
+                .collect::<Vec<Option<f64>>>(),
+        )),
+        ColumnType::Timestamp => Arc::new(
+            TimestampSecondArray::from(
+                values
+                    .iter()
+                    .map(|v| match v {
+                        Some(Value::Timestamp(val)) => Some(val.timestamp()),
+                        _ => None,
+                    })
+                    .collect::<Vec<Option<i64>>>(),
+            )
+            .with_timezone("UTC"),
+        ),
+        ColumnType::String => Arc::new(StringArray::from(
+            values
+                .iter()
+                .map(|v| v.map(|v| v.to_string()))
+                .collect::<Vec<Option<String>>>(),
+        )),
+    };
+    Ok(column)
+}
+
+/// Convert all data records of a single message kind into a record batch, None is returned if
+/// there are no records of that kind.
+pub fn to_record_batch(records: &[FitDataRecord], kind: MesgNum) -> Result<Option<RecordBatch>> {
+    let records: Vec<&FitDataRecord> = records.iter().filter(|r| r.kind() == kind).collect();
+    if records.is_empty() {
+        return Ok(None);
+    }
+
+    // columns are stored in the order their field is first seen
+    let mut names: Vec<&str> = Vec::new();
+    for record in &records {
+        for field in record.fields() {
+            if !names.contains(&field.name()) {
+                names.push(field.name());
+            }
+        }
+    }
+
+    let mut fields = Vec::with_capacity(names.len());
+    let mut columns = Vec::with_capacity(names.len());
+    for name in names {
+        let values: Vec<Option<&Value>> = records
+            .iter()
+            .map(|r| {
+                r.fields()
+                    .iter()
+                    .find(|f| f.name() == name)
+                    .map(|f| f.value())
+            })
+            .collect();
+        let column_type = values
+            .iter()
+            .flatten()
+            .map(|v| ColumnType::from_value(v))
+            .reduce(ColumnType::merge)
+            .unwrap_or(ColumnType::String);
+        fields.push(Field::new(name, column_type.data_type(), true));
+        columns.push(build_column(&values, column_type)?);
+    }
+
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+        .map(Some)
+        .map_err(arrow_error)
+}
+
+/// Convert data records into a record batch for each message kind, the batches are ordered by
+/// the first appearance of each message kind.
+pub fn to_record_batches(records: &[FitDataRecord]) -> Result<Vec<(MesgNum, RecordBatch)>> {
+    let mut kinds = Vec::new();
+    for record in records {
+        if !kinds.contains(&record.kind()) {
+            kinds.push(record.kind());
+        }
+    }
+    let mut batches = Vec::with_capacity(kinds.len());
+    for kind in kinds {
+        if let Some(batch) = to_record_batch(records, kind)? {
+            batches.push((kind, batch));
+        }
+    }
+    Ok(batches)
+}
+
+/// Write a record batch as a Parquet file into a destination that implements io::Write
+pub fn write_parquet<T: Write + Send>(batch: &RecordBatch, dest: T) -> Result<()> {
+    let mut writer = ArrowWriter::try_new(dest, batch.schema(), None).map_err(arrow_error)?;
+    writer.write(batch).map_err(arrow_error)?;
+    writer.close().map_err(arrow_error)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+    use arrow_array::Array;
+
+    #[test]
+    fn column_type_merge() {
+        assert_eq!(
+            ColumnType::Integer.merge(ColumnType::Float),
+            ColumnType::Float
+        );
+        assert_eq!(
+            ColumnType::Integer.merge(ColumnType::Timestamp),
+            ColumnType::String
+        );
+    }
+
+    #[test]
+    fn activity_record_batch() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+        let batch = to_record_batch(&records, MesgNum::Record).unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 14);
+        let schema = batch.schema();
+        let (idx, field) = schema.column_with_name("position_lat").unwrap();
+        assert_eq!(field.data_type(), &DataType::Int64);
+        let column = batch.column(idx);
+        let column = column.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(column.value(0), 495280430);
+        let (_, field) = schema.column_with_name("timestamp").unwrap();
+        assert!(matches!(field.data_type(), DataType::Timestamp(..)));
+
+        let batches = to_record_batches(&records).unwrap();
+        assert_eq!(batches[0].0, MesgNum::FileId);
+        assert!(to_record_batch(&records, MesgNum::Hr).unwrap().is_none());
+    }
+
+    #[test]
+    fn write_parquet_file() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+        let batch = to_record_batch(&records, MesgNum::Record).unwrap().unwrap();
+        let mut output = Vec::new();
+        write_parquet(&batch, &mut output).unwrap();
+        assert_eq!(&output[..4], b"PAR1");
+        assert_eq!(&output[output.len() - 4..], b"PAR1");
+    }
+}
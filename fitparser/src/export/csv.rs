@@ -0,0 +1,190 @@
+//! Write the contents of a FIT file using the CSV layout of the `FitCSVTool` provided with the
+//! FIT SDK. Each definition and data message is written as a row of `Field`, `Value` and `Units`
+//! columns, so the definition messages are read from the raw bytes instead of data records.
+use crate::compat::prelude::*;
+use crate::compat::HashSet;
+use crate::de::{DecodeOption, FitDefinitionMessage, FitObject, FitStreamProcessor};
+use crate::error::Result;
+use crate::profile::{MesgNum, TimestampField};
+use crate::{FitDataRecord, Value};
+#[cfg(feature = "std")]
+use std::io::Write;
+
+/// A single row of the CSV file
+struct Row {
+    /// Either "Definition" or "Data"
+    kind: &'static str,
+    local_message_number: u8,
+    message: String,
+    /// Stores the field, value and units columns, data values are already quoted
+    fields: Vec<(String, String, String)>,
+}
+
+impl Row {
+    /// Create a row from a definition message, the value column stores the number of elements
+    fn from_definition(def_mesg: &FitDefinitionMessage) -> Self {
+        let kind = MesgNum::from(def_mesg.global_message_number());
+        Row {
+            kind: "Definition",
+            local_message_number: def_mesg.local_message_number(),
+            message: kind.to_string(),
+            fields: def_mesg
+                .field_definitions()
+                .iter()
+                .map(|f| {
+                    let name = kind
+                        .get_field_info(f.field_definition_number(), "")
+                        .map_or("unknown", |i| i.name());
+                    let count = match f.base_type().size() {
+                        0 => 1,
+                        size => (f.size() / size).max(1),
+                    };
+                    (name.to_string(), count.to_string(), String::new())
+                })
+                .collect(),
+        }
+    }
+
+    /// Create a row from a decoded data record
+    fn from_record(local_message_number: u8, record: &FitDataRecord) -> Self {
+        Row {
+            kind: "Data",
+            local_message_number,
+            message: record.kind().to_string(),
+            fields: record
+                .fields()
+                .iter()
+                .map(|f| {
+                    (
+                        f.name().to_string(),
+                        quote(&format_value(f.value())),
+                        f.units().to_string(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Write the row, every field triplet is followed by a trailing comma
+    fn write(&self, output: &mut String) {
+        output.push_str(&format!(
+            "{},{},{},",
+            self.kind, self.local_message_number, self.message
+        ));
+        for (name, value, units) in &self.fields {
+            output.push_str(&format!("{},{},{},", name, value, units));
+        }
+        output.push('\n');
+    }
+}
+
+/// Format a value the same way `FitCSVTool` does, timestamps are written as seconds since the
+/// FIT epoch and array elements are separated using a "|"
+fn format_value(value: &Value) -> String {
+    match value {
+        Value::Timestamp(val) => TimestampField::utc_from_date_time(val).as_i64().to_string(),
+        Value::LocalTimestamp(val) => TimestampField::local_from_wall_clock(val)
+            .as_i64()
+            .to_string(),
+        Value::Array(vals) => vals
+            .iter()
+            .map(format_value)
+            .collect::<Vec<String>>()
+            .join("|"),
+        val => val.to_string(),
+    }
+}
+
+/// Wrap a value in double quotes, escaping any quotes inside of it
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\""))
+}
+
+/// Deserialize a FIT file stored as an array of bytes and convert its messages into the CSV
+/// layout used by `FitCSVTool`. The `ReturnNumericEnumValues` option can be used to match the
+/// numeric enum values written by the SDK tool.
+pub fn to_string(mut buffer: &[u8], options: &HashSet<DecodeOption>) -> Result<String> {
+    let mut processor = FitStreamProcessor::new();
+    let mut rows = Vec::new();
+    let drop_unknown = options.contains(&DecodeOption::DropUnknownMessages);
+
+    options.iter().for_each(|o| processor.add_option(*o));
+    while !buffer.is_empty() {
+        let (buf, obj) = processor.deserialize_next(buffer)?;
+        match obj {
+            FitObject::Crc(..) => processor.reset(),
+            FitObject::Header(..) => {}
+            FitObject::DefinitionMessage(def_mesg) => {
+                if !drop_unknown
+                    || MesgNum::is_named_variant(def_mesg.global_message_number() as i64)
+                {
+                    rows.push(Row::from_definition(&def_mesg));
+                }
+            }
+            FitObject::DataMessage(msg) => {
+                let local_message_number = msg.local_message_number();
+                let record = processor.decode_message(msg)?;
+                if !drop_unknown || MesgNum::is_named_variant(record.kind().as_i64()) {
+                    rows.push(Row::from_record(local_message_number, &record));
+                }
+            }
+        }
+        buffer = buf;
+    }
+
+    let max_fields = rows.iter().map(|r| r.fields.len()).max().unwrap_or(0);
+    let mut output = String::from("Type,Local Number,Message,");
+    for i in 1..=max_fields {
+        output.push_str(&format!("Field {0},Value {0},Units {0},", i));
+    }
+    output.push('\n');
+    for row in &rows {
+        row.write(&mut output);
+    }
+    Ok(output)
+}
+
+/// Deserialize a FIT file stored as an array of bytes and write its messages into a destination
+/// that implements io::Write using the CSV layout of `FitCSVTool`
+#[cfg(feature = "std")]
+pub fn to_writer<T: Write>(
+    buffer: &[u8],
+    options: &HashSet<DecodeOption>,
+    dest: &mut T,
+) -> Result<()> {
+    dest.write_all(to_string(buffer, options)?.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_value_test() {
+        let value = Value::Array(vec![Value::UInt8(1), Value::UInt8(2)]);
+        assert_eq!(format_value(&value), "1|2");
+        assert_eq!(quote("a \"b\""), "\"a \"\"b\"\"\"");
+    }
+
+    #[test]
+    fn activity_to_csv() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let mut options = HashSet::new();
+        options.insert(DecodeOption::ReturnNumericEnumValues);
+        let csv = to_string(data, &options).unwrap();
+        let mut lines = csv.lines();
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("Type,Local Number,Message,Field 1,Value 1,Units 1,"));
+        assert!(lines.next().unwrap().starts_with("Definition,0,file_id,"));
+        let data_row = lines.next().unwrap();
+        assert!(data_row.starts_with("Data,0,file_id,"));
+        assert!(data_row.contains("manufacturer,\"15\",,"));
+        let records = csv
+            .lines()
+            .filter(|l| l.starts_with("Data,") && l.contains(",record,"));
+        assert_eq!(records.count(), 14);
+    }
+}
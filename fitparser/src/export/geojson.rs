@@ -0,0 +1,188 @@
+//! Write the positions stored in FIT data records as a GeoJSON `FeatureCollection` for use in
+//! web maps. The track is stored as a `LineString` feature followed by `Point` features for
+//! the end of each lap and each event.
+use super::{format_time, lap_points, timestamp_field, track_segments, TrackPoint};
+use crate::compat::prelude::*;
+use crate::profile::MesgNum;
+use crate::FitDataRecord;
+#[cfg(feature = "std")]
+use crate::Result;
+#[cfg(feature = "std")]
+use std::io::Write;
+
+/// Format the position of a track point as a GeoJSON position array
+fn position(point: &TrackPoint) -> String {
+    match point.altitude() {
+        Some(altitude) => format!(
+            "[{:.7},{:.7},{:.1}]",
+            point.longitude(),
+            point.latitude(),
+            altitude
+        ),
+        None => format!("[{:.7},{:.7}]", point.longitude(), point.latitude()),
+    }
+}
+
+/// Quote and escape a string value
+fn json_string(value: &str) -> String {
+    let mut output = String::with_capacity(value.len() + 2);
+    output.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            c if (c as u32) < 0x20 => output.push_str(&format!("\\u{:04x}", c as u32)),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
+
+/// Format a feature, the properties are a list of already formatted JSON values
+fn feature(geometry: String, properties: &[(&str, String)]) -> String {
+    let properties: Vec<String> = properties
+        .iter()
+        .map(|(k, v)| format!("{}:{}", json_string(k), v))
+        .collect();
+    format!(
+        "{{\"type\":\"Feature\",\"geometry\":{},\"properties\":{{{}}}}}",
+        geometry,
+        properties.join(",")
+    )
+}
+
+/// Format a point feature, the time of the point is added to the properties when available
+fn point_feature(point: &TrackPoint, mut properties: Vec<(&str, String)>) -> String {
+    if let Some(timestamp) = point.timestamp() {
+        properties.push(("time", json_string(&format_time(&timestamp))));
+    }
+    feature(
+        format!("{{\"type\":\"Point\",\"coordinates\":{}}}", position(point)),
+        &properties,
+    )
+}
+
+/// Format the track as a `LineString`, a `MultiLineString` is used when the records of
+/// multiple FIT files are exported together
+fn track_feature(segments: &[Vec<TrackPoint>]) -> String {
+    let lines: Vec<String> = segments
+        .iter()
+        .map(|s| {
+            let positions: Vec<String> = s.iter().map(position).collect();
+            format!("[{}]", positions.join(","))
+        })
+        .collect();
+    let geometry = if lines.len() == 1 {
+        format!("{{\"type\":\"LineString\",\"coordinates\":{}}}", lines[0])
+    } else {
+        format!(
+            "{{\"type\":\"MultiLineString\",\"coordinates\":[{}]}}",
+            lines.join(",")
+        )
+    };
+    let mut properties = vec![("kind", json_string("track"))];
+    let times: Vec<_> = segments
+        .iter()
+        .flatten()
+        .filter_map(|p| p.timestamp())
+        .collect();
+    if let (Some(start), Some(end)) = (times.first(), times.last()) {
+        properties.push(("start_time", json_string(&format_time(start))));
+        properties.push(("end_time", json_string(&format_time(end))));
+    }
+    feature(geometry, &properties)
+}
+
+/// Convert `event` messages into point features, events don't store a position so the last
+/// track point recorded at or before the event is used.
+fn event_features(records: &[FitDataRecord], points: &[&TrackPoint]) -> Vec<String> {
+    records
+        .iter()
+        .filter(|r| r.kind() == MesgNum::Event)
+        .filter_map(|r| {
+            let timestamp = timestamp_field(r, "timestamp")?;
+            let point = points
+                .iter()
+                .take_while(|p| !matches!(p.timestamp(), Some(t) if t > timestamp))
+                .last()
+                .or_else(|| points.first())?;
+            let point = TrackPoint::new(
+                point.latitude(),
+                point.longitude(),
+                point.altitude(),
+                Some(timestamp),
+            );
+            let mut properties = vec![("kind", json_string("event"))];
+            for field in r.fields() {
+                if field.name() == "event" || field.name() == "event_type" {
+                    properties.push((field.name(), json_string(&field.value().to_string())));
+                }
+            }
+            Some(point_feature(&point, properties))
+        })
+        .collect()
+}
+
+/// Convert the positions stored in FIT data records into a GeoJSON `FeatureCollection`
+pub fn to_string(records: &[FitDataRecord]) -> String {
+    let segments = track_segments(records);
+    let mut features = Vec::new();
+    if !segments.is_empty() {
+        features.push(track_feature(&segments));
+    }
+    for (i, point) in lap_points(records).iter().enumerate() {
+        features.push(point_feature(
+            point,
+            vec![("kind", json_string("lap")), ("lap", (i + 1).to_string())],
+        ));
+    }
+    let points: Vec<&TrackPoint> = segments.iter().flatten().collect();
+    if !points.is_empty() {
+        features.extend(event_features(records, &points));
+    }
+    format!(
+        "{{\"type\":\"FeatureCollection\",\"features\":[{}]}}",
+        features.join(",")
+    )
+}
+
+/// Write the positions stored in FIT data records as a GeoJSON `FeatureCollection` into a
+/// destination that implements io::Write
+#[cfg(feature = "std")]
+pub fn to_writer<T: Write>(records: &[FitDataRecord], dest: &mut T) -> Result<()> {
+    dest.write_all(to_string(records).as_bytes())?;
+    Ok(())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+
+    #[test]
+    fn json_string_test() {
+        assert_eq!(json_string("lap"), "\"lap\"");
+        assert_eq!(json_string("a\"b\\\n"), "\"a\\\"b\\\\\\u000a\"");
+    }
+
+    #[test]
+    fn activity_to_geojson() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let geojson: serde_json::Value =
+            serde_json::from_str(&to_string(&from_bytes(data).unwrap())).unwrap();
+        assert_eq!(geojson["type"], "FeatureCollection");
+        let features = geojson["features"].as_array().unwrap();
+        assert_eq!(features[0]["geometry"]["type"], "LineString");
+        let coordinates = features[0]["geometry"]["coordinates"].as_array().unwrap();
+        assert_eq!(coordinates.len(), 14);
+        assert!((coordinates[0][0].as_f64().unwrap() + 73.1485908).abs() < 1e-6);
+        assert_eq!(features[1]["properties"]["kind"], "lap");
+        assert_eq!(features[1]["geometry"]["type"], "Point");
+        let events = features
+            .iter()
+            .filter(|f| f["properties"]["kind"] == "event")
+            .count();
+        assert_eq!(events, 3);
+    }
+}
@@ -0,0 +1,90 @@
+//! Write the positions stored in FIT `record` messages as a GPX 1.1 track.
+use super::{format_time, track_segments, TrackPoint};
+use crate::compat::prelude::*;
+use crate::FitDataRecord;
+#[cfg(feature = "std")]
+use crate::Result;
+#[cfg(feature = "std")]
+use std::io::Write;
+
+/// Start of the GPX document including the schema declarations
+const GPX_HEADER: &str = concat!(
+    "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+    "<gpx version=\"1.1\" creator=\"fitparser\" xmlns=\"http://www.topografix.com/GPX/1/1\" ",
+    "xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" ",
+    "xsi:schemaLocation=\"http://www.topografix.com/GPX/1/1 ",
+    "http://www.topografix.com/GPX/1/1/gpx.xsd\">\n",
+);
+
+/// Write a single track point element
+fn track_point(output: &mut String, point: &TrackPoint) {
+    output.push_str(&format!(
+        "      <trkpt lat=\"{:.7}\" lon=\"{:.7}\">",
+        point.latitude(),
+        point.longitude()
+    ));
+    if let Some(altitude) = point.altitude() {
+        output.push_str(&format!("<ele>{:.1}</ele>", altitude));
+    }
+    if let Some(timestamp) = point.timestamp() {
+        output.push_str(&format!("<time>{}</time>", format_time(&timestamp)));
+    }
+    output.push_str("</trkpt>\n");
+}
+
+/// Convert the positions stored in FIT data records into a GPX document containing a
+/// single track, the records of each FIT file are stored in a separate track segment.
+pub fn to_string(records: &[FitDataRecord]) -> String {
+    let mut output = String::from(GPX_HEADER);
+    output.push_str("  <trk>\n");
+    for segment in track_segments(records) {
+        output.push_str("    <trkseg>\n");
+        for point in &segment {
+            track_point(&mut output, point);
+        }
+        output.push_str("    </trkseg>\n");
+    }
+    output.push_str("  </trk>\n</gpx>\n");
+    output
+}
+
+/// Write the positions stored in FIT data records as a GPX document into a destination that
+/// implements io::Write
+#[cfg(feature = "std")]
+pub fn to_writer<T: Write>(records: &[FitDataRecord], dest: &mut T) -> Result<()> {
+    dest.write_all(to_string(records).as_bytes())?;
+    Ok(())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+
+    #[test]
+    fn track_point_test() {
+        let mut output = String::new();
+        track_point(
+            &mut output,
+            &TrackPoint::new(41.5139261, -73.1485908, None, None),
+        );
+        assert_eq!(
+            output,
+            "      <trkpt lat=\"41.5139261\" lon=\"-73.1485908\"></trkpt>\n"
+        );
+    }
+
+    #[test]
+    fn activity_to_gpx() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let gpx = to_string(&from_bytes(data).unwrap());
+        assert!(gpx.starts_with(GPX_HEADER));
+        assert!(gpx.ends_with("</gpx>\n"));
+        assert_eq!(gpx.matches("<trkseg>").count(), 1);
+        assert_eq!(gpx.matches("<trkpt ").count(), 14);
+        assert!(gpx.contains(
+            "<trkpt lat=\"41.5139261\" lon=\"-73.1485908\"><ele>278.2</ele>\
+             <time>2012-04-09T21:22:26Z</time></trkpt>"
+        ));
+    }
+}
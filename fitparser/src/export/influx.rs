@@ -0,0 +1,203 @@
+//! Write `record` and `monitoring` messages using the InfluxDB line protocol. Each message kind
+//! is written as a measurement tagged with the device that recorded it and the activity sport,
+//! timestamps are written in nanoseconds.
+use super::string_field;
+use crate::compat::prelude::*;
+use crate::profile::{MesgNum, TimestampField};
+#[cfg(feature = "std")]
+use crate::Result;
+use crate::{FitDataRecord, Value};
+use core::convert::TryInto;
+#[cfg(feature = "std")]
+use std::io::Write;
+
+/// Escape the characters that have a special meaning in measurement names, tag keys, tag values
+/// and field keys
+fn escape_key(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, ',' | '=' | ' ' | '\\') {
+            output.push('\\');
+        }
+        output.push(c);
+    }
+    output
+}
+
+/// Format a field value, integers are suffixed with an "i" and strings are quoted
+fn format_field(value: &Value) -> Option<String> {
+    let field = match value {
+        Value::Timestamp(val) => format!("{}i", val.timestamp()),
+        Value::LocalTimestamp(val) => format!("\"{}\"", val.format("%Y-%m-%dT%H:%M:%S")),
+        Value::Float32(val) if val.is_finite() => format!("{}", val),
+        Value::Float64(val) if val.is_finite() => format!("{}", val),
+        Value::Float32(_) | Value::Float64(_) => return None,
+        Value::UInt64(val) | Value::UInt64z(val) if *val > i64::MAX as u64 => format!("{}u", val),
+        Value::String(val) => format!("\"{}\"", val.replace('\\', "\\\\").replace('"', "\\\"")),
+        Value::Array(_) => format!("\"{}\"", value.to_string().replace('"', "\\\"")),
+        val => {
+            let val: i64 = val.clone().try_into().ok()?;
+            format!("{}i", val)
+        }
+    };
+    Some(field)
+}
+
+/// Tracks the last full timestamp so the compressed `timestamp_16` fields of monitoring
+/// messages can be expanded
+#[derive(Default)]
+struct TimestampTracker {
+    /// Last timestamp as seconds since the FIT epoch and seconds since the unix epoch
+    last: Option<(i64, i64)>,
+}
+
+impl TimestampTracker {
+    /// Return the unix timestamp of a record in seconds, updating the last full timestamp
+    fn timestamp(&mut self, record: &FitDataRecord) -> Option<i64> {
+        for field in record.fields() {
+            match (field.name(), field.value()) {
+                ("timestamp", Value::Timestamp(val)) => {
+                    let fit = TimestampField::utc_from_date_time(val).as_i64();
+                    self.last = Some((fit, val.timestamp()));
+                    return Some(val.timestamp());
+                }
+                ("timestamp_16", val) => {
+                    let (last_fit, last_unix) = self.last?;
+                    let ts16: i64 = val.clone().try_into().ok()?;
+                    let fit = last_fit + ((ts16 - last_fit) & 0xFFFF);
+                    return Some(last_unix + (fit - last_fit));
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// Create the tag set describing the device and activity of a FIT file
+fn tag_set(records: &[FitDataRecord]) -> String {
+    let mut tags = Vec::new();
+    if let Some(file_id) = records.iter().find(|r| r.kind() == MesgNum::FileId) {
+        for field in file_id.fields() {
+            let key = match field.name() {
+                "manufacturer" => "device",
+                "garmin_product" | "product" => "product",
+                "serial_number" => "serial_number",
+                _ => continue,
+            };
+            if !tags.iter().any(|(k, _)| *k == key) {
+                tags.push((key, field.value().to_string()));
+            }
+        }
+    }
+    let sport = records
+        .iter()
+        .filter(|r| matches!(r.kind(), MesgNum::Session | MesgNum::Sport))
+        .find_map(|r| string_field(r, "sport"));
+    if let Some(sport) = sport {
+        tags.push(("sport", sport.to_string()));
+    }
+    // tags should be sorted by key for the best performance
+    tags.sort();
+    tags.iter()
+        .map(|(k, v)| format!(",{}={}", k, escape_key(v)))
+        .collect()
+}
+
+/// Convert the `record` and `monitoring` messages stored in FIT data records into lines of the
+/// InfluxDB line protocol, messages without a timestamp are skipped.
+pub fn to_string(records: &[FitDataRecord]) -> String {
+    let tags = tag_set(records);
+    let mut tracker = TimestampTracker::default();
+    let mut output = String::new();
+    for record in records {
+        let timestamp = tracker.timestamp(record);
+        if !matches!(record.kind(), MesgNum::Record | MesgNum::Monitoring) {
+            continue;
+        }
+        let timestamp = match timestamp {
+            Some(val) => val,
+            None => continue,
+        };
+        let fields: Vec<String> = record
+            .fields()
+            .iter()
+            .filter(|f| f.name() != "timestamp" && f.name() != "timestamp_16")
+            .filter_map(|f| {
+                Some(format!(
+                    "{}={}",
+                    escape_key(f.name()),
+                    format_field(f.value())?
+                ))
+            })
+            .collect();
+        if fields.is_empty() {
+            continue;
+        }
+        output.push_str(&format!(
+            "{}{} {} {}\n",
+            escape_key(&record.kind().to_string()),
+            tags,
+            fields.join(","),
+            timestamp * 1_000_000_000
+        ));
+    }
+    output
+}
+
+/// Write the `record` and `monitoring` messages stored in FIT data records using the InfluxDB
+/// line protocol into a destination that implements io::Write
+#[cfg(feature = "std")]
+pub fn to_writer<T: Write>(records: &[FitDataRecord], dest: &mut T) -> Result<()> {
+    dest.write_all(to_string(records).as_bytes())?;
+    Ok(())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+
+    #[test]
+    fn escape_key_test() {
+        assert_eq!(escape_key("a b,c=d"), "a\\ b\\,c\\=d");
+    }
+
+    #[test]
+    fn format_field_test() {
+        assert_eq!(format_field(&Value::UInt8(3)).unwrap(), "3i");
+        assert_eq!(format_field(&Value::Float64(1.5)).unwrap(), "1.5");
+        assert_eq!(
+            format_field(&Value::String(String::from("a\"b"))).unwrap(),
+            "\"a\\\"b\""
+        );
+        assert!(format_field(&Value::Float64(f64::NAN)).is_none());
+    }
+
+    #[test]
+    fn activity_to_line_protocol() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let lines = to_string(&from_bytes(data).unwrap());
+        assert_eq!(lines.lines().count(), 14);
+        let line = lines.lines().next().unwrap();
+        assert!(line.starts_with("record,device=dynastream,"));
+        assert!(line.contains(",sport=running "));
+        assert!(line.contains("position_lat=495280430i"));
+        assert!(line.ends_with(" 1334006546000000000"));
+    }
+
+    #[test]
+    fn monitoring_file_to_line_protocol() {
+        let data = include_bytes!("../../tests/fixtures/MonitoringFile.fit");
+        let lines = to_string(&from_bytes(data).unwrap());
+        assert!(lines.lines().count() > 0);
+        assert!(lines.lines().all(|l| l.starts_with("monitoring,")));
+        // timestamp_16 values are expanded into full timestamps
+        let timestamps: Vec<i64> = lines
+            .lines()
+            .map(|l| l.rsplit(' ').next().unwrap().parse().unwrap())
+            .collect();
+        assert!(timestamps.windows(2).all(|w| w[0] <= w[1]));
+        assert!(timestamps[0] >= 1_419_660_000_000_000_000);
+    }
+}
@@ -0,0 +1,115 @@
+//! Write the positions stored in FIT `record` messages as a KML track, including placemarks
+//! marking the start and stop of the activity and the end of each lap.
+use super::{format_time, lap_points, track_segments, TrackPoint};
+use crate::compat::prelude::*;
+use crate::FitDataRecord;
+#[cfg(feature = "std")]
+use crate::Result;
+#[cfg(feature = "std")]
+use std::io::Write;
+
+/// Start of the KML document
+const KML_HEADER: &str = concat!(
+    "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+    "<kml xmlns=\"http://www.opengis.net/kml/2.2\">\n",
+    "  <Document>\n",
+);
+
+/// Format the coordinates of a track point as a KML tuple of longitude, latitude and altitude
+fn coordinates(point: &TrackPoint) -> String {
+    match point.altitude() {
+        Some(altitude) => format!(
+            "{:.7},{:.7},{:.1}",
+            point.longitude(),
+            point.latitude(),
+            altitude
+        ),
+        None => format!("{:.7},{:.7}", point.longitude(), point.latitude()),
+    }
+}
+
+/// Write a placemark marking a single position
+fn point_placemark(output: &mut String, name: &str, point: &TrackPoint) {
+    output.push_str(&format!("    <Placemark>\n      <name>{}</name>\n", name));
+    if let Some(timestamp) = point.timestamp() {
+        output.push_str(&format!(
+            "      <TimeStamp><when>{}</when></TimeStamp>\n",
+            format_time(&timestamp)
+        ));
+    }
+    output.push_str(&format!(
+        "      <Point><coordinates>{}</coordinates></Point>\n    </Placemark>\n",
+        coordinates(point)
+    ));
+}
+
+/// Write a placemark containing the line of a track segment
+fn track_placemark(output: &mut String, name: &str, segment: &[TrackPoint]) {
+    output.push_str(&format!("    <Placemark>\n      <name>{}</name>\n", name));
+    output.push_str("      <LineString>\n        <tessellate>1</tessellate>\n");
+    output.push_str("        <coordinates>\n");
+    for point in segment {
+        output.push_str(&format!("          {}\n", coordinates(point)));
+    }
+    output.push_str("        </coordinates>\n      </LineString>\n    </Placemark>\n");
+}
+
+/// Convert the positions stored in FIT data records into a KML document. Each FIT file is
+/// written as a separate track line followed by start, stop and lap placemarks.
+pub fn to_string(records: &[FitDataRecord]) -> String {
+    let segments = track_segments(records);
+    let mut output = String::from(KML_HEADER);
+    for (i, segment) in segments.iter().enumerate() {
+        if segments.len() > 1 {
+            track_placemark(&mut output, &format!("Track {}", i + 1), segment);
+        } else {
+            track_placemark(&mut output, "Track", segment);
+        }
+    }
+    if let Some(point) = segments.first().and_then(|s| s.first()) {
+        point_placemark(&mut output, "Start", point);
+    }
+    if let Some(point) = segments.last().and_then(|s| s.last()) {
+        point_placemark(&mut output, "Stop", point);
+    }
+    for (i, point) in lap_points(records).iter().enumerate() {
+        point_placemark(&mut output, &format!("Lap {}", i + 1), point);
+    }
+    output.push_str("  </Document>\n</kml>\n");
+    output
+}
+
+/// Write the positions stored in FIT data records as a KML document into a destination that
+/// implements io::Write
+#[cfg(feature = "std")]
+pub fn to_writer<T: Write>(records: &[FitDataRecord], dest: &mut T) -> Result<()> {
+    dest.write_all(to_string(records).as_bytes())?;
+    Ok(())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+
+    #[test]
+    fn coordinates_test() {
+        let point = TrackPoint::new(41.5139261, -73.1485908, Some(278.2), None);
+        assert_eq!(coordinates(&point), "-73.1485908,41.5139261,278.2");
+        let point = TrackPoint::new(41.5139261, -73.1485908, None, None);
+        assert_eq!(coordinates(&point), "-73.1485908,41.5139261");
+    }
+
+    #[test]
+    fn activity_to_kml() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let kml = to_string(&from_bytes(data).unwrap());
+        assert!(kml.starts_with(KML_HEADER));
+        assert!(kml.ends_with("</kml>\n"));
+        assert_eq!(kml.matches("<LineString>").count(), 1);
+        assert!(kml.contains("<name>Start</name>\n      <TimeStamp><when>2012-04-09T21:22:26Z"));
+        assert!(kml.contains("<name>Stop</name>"));
+        assert!(kml.contains("<name>Lap 1</name>"));
+        assert!(kml.contains("          -73.1485908,41.5139261,278.2\n"));
+    }
+}
@@ -0,0 +1,202 @@
+//! Export FIT data records into file formats supported by common mapping and training tools.
+use crate::compat::prelude::*;
+use crate::profile::MesgNum;
+use crate::{FitDataRecord, TimestampTz, Value};
+use chrono::{DateTime, SecondsFormat, Utc};
+use core::convert::{TryFrom, TryInto};
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod csv;
+pub mod geojson;
+pub mod gpx;
+pub mod influx;
+pub mod kml;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+pub mod tcx;
+
+/// Convert a position stored in semicircles into degrees
+pub fn semicircles_to_degrees(value: i32) -> f64 {
+    value as f64 * (180.0 / 2_147_483_648.0)
+}
+
+/// Convert a position in degrees into semicircles, rounding to the nearest semicircle
+pub fn degrees_to_semicircles(value: f64) -> i32 {
+    let value = value * (2_147_483_648.0 / 180.0);
+    // the cast saturates at 180 degrees which can't be represented
+    (if value < 0.0 {
+        value - 0.5
+    } else {
+        value + 0.5
+    }) as i32
+}
+
+/// A position extracted from a `record` message
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrackPoint {
+    latitude: f64,
+    longitude: f64,
+    altitude: Option<f64>,
+    timestamp: Option<DateTime<TimestampTz>>,
+}
+
+impl TrackPoint {
+    /// Create a track point from a position in degrees
+    pub fn new(
+        latitude: f64,
+        longitude: f64,
+        altitude: Option<f64>,
+        timestamp: Option<DateTime<TimestampTz>>,
+    ) -> Self {
+        TrackPoint {
+            latitude,
+            longitude,
+            altitude,
+            timestamp,
+        }
+    }
+
+    /// Extract a track point from a `record` message, None is returned if the record is
+    /// missing a position. The enhanced altitude is used in place of the altitude when present.
+    pub fn from_record(record: &FitDataRecord) -> Option<Self> {
+        if record.kind() != MesgNum::Record {
+            return None;
+        }
+        let latitude = semicircles_field(record, "position_lat")?;
+        let longitude = semicircles_field(record, "position_long")?;
+        let altitude = numeric_field(record, "enhanced_altitude")
+            .or_else(|| numeric_field(record, "altitude"));
+        let timestamp = timestamp_field(record, "timestamp");
+        Some(TrackPoint::new(latitude, longitude, altitude, timestamp))
+    }
+
+    /// Return the latitude in degrees
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Return the longitude in degrees
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Return the altitude in meters if available
+    pub fn altitude(&self) -> Option<f64> {
+        self.altitude
+    }
+
+    /// Return the time the position was recorded if available
+    pub fn timestamp(&self) -> Option<DateTime<TimestampTz>> {
+        self.timestamp
+    }
+}
+
+/// Format a timestamp as an ISO 8601 UTC time
+fn format_time(time: &DateTime<TimestampTz>) -> String {
+    time.with_timezone(&Utc)
+        .to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Find a field by name and convert its value into a float
+pub(crate) fn numeric_field(record: &FitDataRecord, name: &str) -> Option<f64> {
+    let field = record.fields().iter().find(|f| f.name() == name)?;
+    field.value().clone().try_into().ok()
+}
+
+/// Find a field by name and return its value if it is a string, enum values that were decoded
+/// into their variant name are returned too
+fn string_field<'a>(record: &'a FitDataRecord, name: &str) -> Option<&'a str> {
+    record.fields().iter().find_map(|f| match f.value() {
+        Value::String(val) if f.name() == name => Some(val.as_str()),
+        _ => None,
+    })
+}
+
+/// Find a field by name and return its value if it is a timestamp
+pub(crate) fn timestamp_field(record: &FitDataRecord, name: &str) -> Option<DateTime<TimestampTz>> {
+    record.fields().iter().find_map(|f| match f.value() {
+        Value::Timestamp(val) if f.name() == name => Some(*val),
+        _ => None,
+    })
+}
+
+/// Find a position field by name and convert its value from semicircles into degrees
+pub(crate) fn semicircles_field(record: &FitDataRecord, name: &str) -> Option<f64> {
+    let field = record.fields().iter().find(|f| f.name() == name)?;
+    if field.units() == "degrees" {
+        // already converted using `DecodeOption::ConvertSemicircles`
+        return field.value().clone().try_into().ok();
+    }
+    let value: i64 = field.value().clone().try_into().ok()?;
+    i32::try_from(value).ok().map(semicircles_to_degrees)
+}
+
+/// Extract the end position of each `lap` message
+fn lap_points(records: &[FitDataRecord]) -> Vec<TrackPoint> {
+    records
+        .iter()
+        .filter(|r| r.kind() == MesgNum::Lap)
+        .filter_map(|r| {
+            let latitude = semicircles_field(r, "end_position_lat")?;
+            let longitude = semicircles_field(r, "end_position_long")?;
+            Some(TrackPoint::new(
+                latitude,
+                longitude,
+                None,
+                timestamp_field(r, "timestamp"),
+            ))
+        })
+        .collect()
+}
+
+/// Split the positions stored in data records into segments, a new segment is started at
+/// each `file_id` message so the records of multiple FIT files can be exported together.
+pub fn track_segments(records: &[FitDataRecord]) -> Vec<Vec<TrackPoint>> {
+    let mut segments = Vec::new();
+    let mut points = Vec::new();
+    for record in records {
+        if record.kind() == MesgNum::FileId && !points.is_empty() {
+            segments.push(core::mem::take(&mut points));
+        } else if let Some(point) = TrackPoint::from_record(record) {
+            points.push(point);
+        }
+    }
+    if !points.is_empty() {
+        segments.push(points);
+    }
+    segments
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+
+    #[test]
+    fn semicircles_to_degrees_test() {
+        assert_eq!(semicircles_to_degrees(0), 0.0);
+        assert_eq!(semicircles_to_degrees(i32::MIN), -180.0);
+        assert!((semicircles_to_degrees(495280430) - 41.513926).abs() < 1e-6);
+        assert_eq!(degrees_to_semicircles(-180.0), i32::MIN);
+        let value = semicircles_to_degrees(-872696681);
+        assert_eq!(degrees_to_semicircles(value), -872696681);
+    }
+
+    #[test]
+    fn track_segments_test() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let mut records = from_bytes(data).unwrap();
+        let segments = track_segments(&records);
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].len(), 14);
+        let point = &segments[0][0];
+        assert!((point.longitude() + 73.148591).abs() < 1e-6);
+        assert!((point.altitude().unwrap() - 278.2).abs() < 1e-6);
+        assert!(point.timestamp().is_some());
+
+        // concatenated files are split into separate segments
+        records.extend(from_bytes(data).unwrap());
+        assert_eq!(track_segments(&records).len(), 2);
+    }
+}
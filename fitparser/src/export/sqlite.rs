@@ -0,0 +1,264 @@
+//! Write FIT data records into a SQLite database, this module requires the `sqlite` feature.
+//! Every FIT file is stored as a row of the `files` table, keyed by the serial number and
+//! creation time of the `file_id` message, and each message kind is written into a table of the
+//! same name with a column for every field along with a `file_id` column referencing the file.
+use crate::error::{ErrorKind, Result};
+use crate::profile::MesgNum;
+use crate::{FitDataRecord, Value};
+use rusqlite::types::{ToSqlOutput, Value as SqlValue};
+use rusqlite::{params, Connection, OptionalExtension, ToSql};
+use std::collections::{HashMap, HashSet};
+use std::convert::TryInto;
+
+/// Schema of the table storing the imported FIT files
+const FILES_TABLE: &str = "CREATE TABLE IF NOT EXISTS files (
+    id INTEGER PRIMARY KEY,
+    serial_number INTEGER,
+    time_created INTEGER,
+    manufacturer TEXT,
+    type TEXT,
+    UNIQUE (serial_number, time_created)
+)";
+
+/// Convert SQLite errors into a value error
+fn sqlite_error(err: rusqlite::Error) -> crate::Error {
+    ErrorKind::ValueError(format!("sqlite error: {}", err)).into()
+}
+
+/// Quote a table or column name
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// Wrapper used to store a field value in SQLite, timestamps are stored as seconds since the
+/// unix epoch and arrays are stored using their string representation.
+struct SqlField<'a>(&'a Value);
+
+impl SqlField<'_> {
+    /// Column type used when a column is created from this value
+    fn column_type(&self) -> &'static str {
+        match self.0 {
+            Value::Float32(_) | Value::Float64(_) => "REAL",
+            Value::String(_) | Value::Array(_) => "TEXT",
+            _ => "INTEGER",
+        }
+    }
+}
+
+impl ToSql for SqlField<'_> {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        let value = match self.0 {
+            Value::Timestamp(val) => SqlValue::Integer(val.timestamp()),
+            Value::LocalTimestamp(val) => {
+                SqlValue::Text(val.format("%Y-%m-%dT%H:%M:%S").to_string())
+            }
+            Value::Float32(val) => SqlValue::Real(*val as f64),
+            Value::Float64(val) => SqlValue::Real(*val),
+            Value::String(val) => SqlValue::Text(val.clone()),
+            Value::Array(_) => SqlValue::Text(self.0.to_string()),
+            Value::UInt64(val) | Value::UInt64z(val) if *val > i64::MAX as u64 => {
+                SqlValue::Real(*val as f64)
+            }
+            val => match val.clone().try_into() {
+                Ok(val) => SqlValue::Integer(val),
+                Err(_) => SqlValue::Null,
+            },
+        };
+        Ok(ToSqlOutput::Owned(value))
+    }
+}
+
+/// Tracks the columns of the message tables while writing records
+struct TableWriter<'a> {
+    conn: &'a Connection,
+    columns: HashMap<String, HashSet<String>>,
+}
+
+impl<'a> TableWriter<'a> {
+    fn new(conn: &'a Connection) -> Self {
+        TableWriter {
+            conn,
+            columns: HashMap::new(),
+        }
+    }
+
+    /// Create the message table if needed and add any columns missing for the record
+    fn prepare_table(&mut self, table: &str, record: &FitDataRecord) -> Result<()> {
+        if !self.columns.contains_key(table) {
+            self.conn
+                .execute(
+                    &format!(
+                        "CREATE TABLE IF NOT EXISTS {} (file_id INTEGER REFERENCES files(id))",
+                        quote_identifier(table)
+                    ),
+                    [],
+                )
+                .map_err(sqlite_error)?;
+            let mut stmt = self
+                .conn
+                .prepare(&format!("PRAGMA table_info({})", quote_identifier(table)))
+                .map_err(sqlite_error)?;
+            let columns = stmt
+                .query_map([], |row| row.get::<_, String>(1))
+                .map_err(sqlite_error)?
+                .collect::<rusqlite::Result<HashSet<String>>>()
+                .map_err(sqlite_error)?;
+            self.columns.insert(table.to_string(), columns);
+        }
+
+        let columns = self.columns.get_mut(table).unwrap();
+        for field in record.fields() {
+            if columns.contains(field.name()) {
+                continue;
+            }
+            self.conn
+                .execute(
+                    &format!(
+                        "ALTER TABLE {} ADD COLUMN {} {}",
+                        quote_identifier(table),
+                        quote_identifier(field.name()),
+                        SqlField(field.value()).column_type()
+                    ),
+                    [],
+                )
+                .map_err(sqlite_error)?;
+            columns.insert(field.name().to_string());
+        }
+        Ok(())
+    }
+
+    /// Insert a record into the table of its message kind
+    fn write_record(&mut self, file_id: i64, record: &FitDataRecord) -> Result<()> {
+        let table = record.kind().to_string();
+        self.prepare_table(&table, record)?;
+
+        // a field name can only be written once per row
+        let mut names = HashSet::new();
+        let fields: Vec<_> = record
+            .fields()
+            .iter()
+            .filter(|f| names.insert(f.name()))
+            .collect();
+        let columns: Vec<String> = fields.iter().map(|f| quote_identifier(f.name())).collect();
+        let sql = format!(
+            "INSERT INTO {} (file_id{}) VALUES (?{})",
+            quote_identifier(&table),
+            columns
+                .iter()
+                .map(|c| format!(", {}", c))
+                .collect::<String>(),
+            ", ?".repeat(columns.len())
+        );
+        let values: Vec<SqlField> = fields.iter().map(|f| SqlField(f.value())).collect();
+        let mut params: Vec<&dyn ToSql> = vec![&file_id];
+        params.extend(values.iter().map(|v| v as &dyn ToSql));
+
+        let mut stmt = self.conn.prepare_cached(&sql).map_err(sqlite_error)?;
+        stmt.execute(params.as_slice()).map_err(sqlite_error)?;
+        Ok(())
+    }
+}
+
+/// Find the value of a field in the first `file_id` message
+fn file_id_field<'a>(records: &'a [FitDataRecord], name: &str) -> Option<&'a Value> {
+    records
+        .iter()
+        .find(|r| r.kind() == MesgNum::FileId)?
+        .fields()
+        .iter()
+        .find(|f| f.name() == name)
+        .map(|f| f.value())
+}
+
+/// Write the data records of a single FIT file into the database and return its row id in the
+/// `files` table. Files that were already imported are skipped and the existing id is returned.
+pub fn write_records(conn: &Connection, records: &[FitDataRecord]) -> Result<i64> {
+    let tx = conn.unchecked_transaction().map_err(sqlite_error)?;
+    conn.execute(FILES_TABLE, []).map_err(sqlite_error)?;
+
+    let serial_number = file_id_field(records, "serial_number").map(SqlField);
+    let time_created = file_id_field(records, "time_created").map(SqlField);
+    let existing = conn
+        .query_row(
+            "SELECT id FROM files WHERE serial_number IS ?1 AND time_created IS ?2",
+            params![serial_number, time_created],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(sqlite_error)?;
+    if let Some(file_id) = existing {
+        return Ok(file_id);
+    }
+
+    conn.execute(
+        "INSERT INTO files (serial_number, time_created, manufacturer, type) \
+         VALUES (?1, ?2, ?3, ?4)",
+        params![
+            serial_number,
+            time_created,
+            file_id_field(records, "manufacturer").map(SqlField),
+            file_id_field(records, "type").map(SqlField),
+        ],
+    )
+    .map_err(sqlite_error)?;
+    let file_id = conn.last_insert_rowid();
+
+    let mut writer = TableWriter::new(conn);
+    for record in records {
+        writer.write_record(file_id, record)?;
+    }
+    tx.commit().map_err(sqlite_error)?;
+    Ok(file_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+
+    #[test]
+    fn quote_identifier_test() {
+        assert_eq!(quote_identifier("record"), "\"record\"");
+        assert_eq!(quote_identifier("a\"b"), "\"a\"\"b\"");
+    }
+
+    #[test]
+    fn write_activity() {
+        let conn = Connection::open_in_memory().unwrap();
+        let records = from_bytes(include_bytes!("../../tests/fixtures/Activity.fit")).unwrap();
+        let file_id = write_records(&conn, &records).unwrap();
+        let count: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM record WHERE file_id = ?1",
+                [file_id],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(count, 14);
+        let position_lat: i64 = conn
+            .query_row("SELECT position_lat FROM record LIMIT 1", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(position_lat, 495280430);
+
+        // importing the same file again doesn't duplicate data
+        assert_eq!(write_records(&conn, &records).unwrap(), file_id);
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM record", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 14);
+
+        // new message kinds and fields are added to the existing schema
+        let records = from_bytes(include_bytes!(
+            "../../tests/fixtures/garmin-fenix-5-bike.fit"
+        ))
+        .unwrap();
+        let other_id = write_records(&conn, &records).unwrap();
+        assert_ne!(other_id, file_id);
+        let count: i64 = conn
+            .query_row("SELECT COUNT(heart_rate) FROM record", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 19);
+    }
+}
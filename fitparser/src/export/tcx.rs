@@ -0,0 +1,353 @@
+//! Write the laps and `record` messages of a FIT activity as a TCX (Training Center XML)
+//! activity, including heart rate, cadence and power values.
+use super::{format_time, numeric_field, semicircles_field, string_field, timestamp_field};
+use crate::compat::prelude::*;
+use crate::profile::MesgNum;
+#[cfg(feature = "std")]
+use crate::Result;
+use crate::{FitDataRecord, TimestampTz};
+use chrono::DateTime;
+#[cfg(feature = "std")]
+use std::io::Write;
+
+/// Start of the TCX document including the schema declarations
+const TCX_HEADER: &str = concat!(
+    "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+    "<TrainingCenterDatabase ",
+    "xmlns=\"http://www.garmin.com/xmlschemas/TrainingCenterDatabase/v2\" ",
+    "xmlns:ns3=\"http://www.garmin.com/xmlschemas/ActivityExtension/v2\" ",
+    "xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" ",
+    "xsi:schemaLocation=\"http://www.garmin.com/xmlschemas/TrainingCenterDatabase/v2 ",
+    "http://www.garmin.com/xmlschemas/TrainingCenterDatabasev2.xsd\">\n",
+);
+
+/// A single trackpoint extracted from a `record` message
+struct Trackpoint {
+    time: DateTime<TimestampTz>,
+    position: Option<(f64, f64)>,
+    altitude: Option<f64>,
+    distance: Option<f64>,
+    heart_rate: Option<f64>,
+    cadence: Option<f64>,
+    power: Option<f64>,
+}
+
+impl Trackpoint {
+    /// Extract a trackpoint from a `record` message, TCX requires every trackpoint to have a
+    /// time so records without a timestamp are skipped.
+    fn from_record(record: &FitDataRecord) -> Option<Self> {
+        let time = timestamp_field(record, "timestamp")?;
+        let position = semicircles_field(record, "position_lat")
+            .zip(semicircles_field(record, "position_long"));
+        Some(Trackpoint {
+            time,
+            position,
+            altitude: numeric_field(record, "enhanced_altitude")
+                .or_else(|| numeric_field(record, "altitude")),
+            distance: numeric_field(record, "distance"),
+            heart_rate: numeric_field(record, "heart_rate"),
+            cadence: numeric_field(record, "cadence"),
+            power: numeric_field(record, "power"),
+        })
+    }
+
+    /// Write the trackpoint element
+    fn write(&self, output: &mut String) {
+        output.push_str("            <Trackpoint>\n");
+        output.push_str(&format!(
+            "              <Time>{}</Time>\n",
+            format_time(&self.time)
+        ));
+        if let Some((latitude, longitude)) = self.position {
+            output.push_str(&format!(
+                "              <Position><LatitudeDegrees>{:.7}</LatitudeDegrees>\
+                 <LongitudeDegrees>{:.7}</LongitudeDegrees></Position>\n",
+                latitude, longitude
+            ));
+        }
+        if let Some(altitude) = self.altitude {
+            output.push_str(&format!(
+                "              <AltitudeMeters>{:.1}</AltitudeMeters>\n",
+                altitude
+            ));
+        }
+        if let Some(distance) = self.distance {
+            output.push_str(&format!(
+                "              <DistanceMeters>{:.2}</DistanceMeters>\n",
+                distance
+            ));
+        }
+        if let Some(heart_rate) = self.heart_rate {
+            output.push_str(&format!(
+                "              <HeartRateBpm><Value>{:.0}</Value></HeartRateBpm>\n",
+                heart_rate
+            ));
+        }
+        if let Some(cadence) = self.cadence {
+            output.push_str(&format!(
+                "              <Cadence>{:.0}</Cadence>\n",
+                cadence
+            ));
+        }
+        if let Some(power) = self.power {
+            output.push_str(&format!(
+                "              <Extensions><ns3:TPX><ns3:Watts>{:.0}</ns3:Watts></ns3:TPX>\
+                 </Extensions>\n",
+                power
+            ));
+        }
+        output.push_str("            </Trackpoint>\n");
+    }
+}
+
+/// Summary values of a lap along with the trackpoints recorded during it
+struct Lap {
+    start_time: Option<DateTime<TimestampTz>>,
+    total_time: f64,
+    distance: f64,
+    max_speed: Option<f64>,
+    calories: f64,
+    avg_heart_rate: Option<f64>,
+    max_heart_rate: Option<f64>,
+    cadence: Option<f64>,
+    intensity: &'static str,
+    trigger_method: &'static str,
+    trackpoints: Vec<Trackpoint>,
+}
+
+impl Lap {
+    /// Create a lap from a `lap` message and the trackpoints recorded before it
+    fn from_record(record: &FitDataRecord, trackpoints: Vec<Trackpoint>) -> Self {
+        let intensity = match string_field(record, "intensity") {
+            Some("rest") => "Resting",
+            _ => "Active",
+        };
+        let trigger_method = match string_field(record, "lap_trigger") {
+            Some("time") => "Time",
+            Some("distance") => "Distance",
+            Some("heart_rate") => "HeartRate",
+            Some(val) if val.starts_with("position_") => "Location",
+            _ => "Manual",
+        };
+        Lap {
+            start_time: timestamp_field(record, "start_time"),
+            total_time: numeric_field(record, "total_timer_time").unwrap_or(0.0),
+            distance: numeric_field(record, "total_distance").unwrap_or(0.0),
+            max_speed: numeric_field(record, "enhanced_max_speed")
+                .or_else(|| numeric_field(record, "max_speed")),
+            calories: numeric_field(record, "total_calories").unwrap_or(0.0),
+            avg_heart_rate: numeric_field(record, "avg_heart_rate"),
+            max_heart_rate: numeric_field(record, "max_heart_rate"),
+            cadence: numeric_field(record, "avg_cadence"),
+            intensity,
+            trigger_method,
+            trackpoints,
+        }
+    }
+
+    /// Create a lap covering all trackpoints of an activity without any `lap` messages
+    fn from_trackpoints(trackpoints: Vec<Trackpoint>) -> Self {
+        let start_time = trackpoints.first().map(|p| p.time);
+        let total_time = match (start_time, trackpoints.last()) {
+            (Some(start), Some(end)) => (end.time - start).num_milliseconds() as f64 / 1000.0,
+            _ => 0.0,
+        };
+        Lap {
+            start_time,
+            total_time,
+            distance: trackpoints
+                .iter()
+                .rev()
+                .find_map(|p| p.distance)
+                .unwrap_or(0.0),
+            max_speed: None,
+            calories: 0.0,
+            avg_heart_rate: None,
+            max_heart_rate: None,
+            cadence: None,
+            intensity: "Active",
+            trigger_method: "Manual",
+            trackpoints,
+        }
+    }
+
+    /// Write the lap element, the elements must follow the order defined by the TCX schema
+    fn write(&self, output: &mut String) {
+        match self
+            .start_time
+            .or_else(|| self.trackpoints.first().map(|p| p.time))
+        {
+            Some(time) => output.push_str(&format!(
+                "      <Lap StartTime=\"{}\">\n",
+                format_time(&time)
+            )),
+            None => output.push_str("      <Lap>\n"),
+        }
+        output.push_str(&format!(
+            "        <TotalTimeSeconds>{:.3}</TotalTimeSeconds>\n",
+            self.total_time
+        ));
+        output.push_str(&format!(
+            "        <DistanceMeters>{:.2}</DistanceMeters>\n",
+            self.distance
+        ));
+        if let Some(max_speed) = self.max_speed {
+            output.push_str(&format!(
+                "        <MaximumSpeed>{:.3}</MaximumSpeed>\n",
+                max_speed
+            ));
+        }
+        output.push_str(&format!(
+            "        <Calories>{:.0}</Calories>\n",
+            self.calories
+        ));
+        if let Some(heart_rate) = self.avg_heart_rate {
+            output.push_str(&format!(
+                "        <AverageHeartRateBpm><Value>{:.0}</Value></AverageHeartRateBpm>\n",
+                heart_rate
+            ));
+        }
+        if let Some(heart_rate) = self.max_heart_rate {
+            output.push_str(&format!(
+                "        <MaximumHeartRateBpm><Value>{:.0}</Value></MaximumHeartRateBpm>\n",
+                heart_rate
+            ));
+        }
+        output.push_str(&format!(
+            "        <Intensity>{}</Intensity>\n",
+            self.intensity
+        ));
+        if let Some(cadence) = self.cadence {
+            output.push_str(&format!("        <Cadence>{:.0}</Cadence>\n", cadence));
+        }
+        output.push_str(&format!(
+            "        <TriggerMethod>{}</TriggerMethod>\n",
+            self.trigger_method
+        ));
+        if !self.trackpoints.is_empty() {
+            output.push_str("        <Track>\n");
+            for trackpoint in &self.trackpoints {
+                trackpoint.write(output);
+            }
+            output.push_str("        </Track>\n");
+        }
+        output.push_str("      </Lap>\n");
+    }
+}
+
+/// Map the FIT sport name onto one of the sports supported by TCX
+fn tcx_sport(sport: Option<&str>) -> &'static str {
+    match sport {
+        Some("running") => "Running",
+        Some("cycling") => "Biking",
+        _ => "Other",
+    }
+}
+
+/// Collect the laps of an activity, trackpoints are assigned to the next `lap` message since
+/// laps are written once they are completed. Trackpoints recorded after the final lap are added
+/// to it.
+fn collect_laps(records: &[FitDataRecord]) -> Vec<Lap> {
+    let mut laps: Vec<Lap> = Vec::new();
+    let mut trackpoints = Vec::new();
+    for record in records {
+        match record.kind() {
+            MesgNum::Record => trackpoints.extend(Trackpoint::from_record(record)),
+            MesgNum::Lap => laps.push(Lap::from_record(record, core::mem::take(&mut trackpoints))),
+            _ => {}
+        }
+    }
+    if !trackpoints.is_empty() {
+        match laps.last_mut() {
+            Some(lap) => lap.trackpoints.append(&mut trackpoints),
+            None => laps.push(Lap::from_trackpoints(trackpoints)),
+        }
+    }
+    laps
+}
+
+/// Convert the laps and records stored in FIT data records into a TCX document containing a
+/// single activity. The sport is read from the first `session` or `sport` message.
+pub fn to_string(records: &[FitDataRecord]) -> String {
+    let sport = records
+        .iter()
+        .filter(|r| matches!(r.kind(), MesgNum::Session | MesgNum::Sport))
+        .find_map(|r| string_field(r, "sport"));
+    let laps = collect_laps(records);
+    let start_time = records
+        .iter()
+        .filter(|r| r.kind() == MesgNum::Session)
+        .find_map(|r| timestamp_field(r, "start_time"))
+        .or_else(|| laps.iter().find_map(|l| l.start_time))
+        .or_else(|| {
+            laps.iter()
+                .find_map(|l| l.trackpoints.first().map(|p| p.time))
+        });
+
+    let mut output = String::from(TCX_HEADER);
+    output.push_str("  <Activities>\n");
+    if !laps.is_empty() {
+        output.push_str(&format!("    <Activity Sport=\"{}\">\n", tcx_sport(sport)));
+        if let Some(time) = start_time {
+            output.push_str(&format!("      <Id>{}</Id>\n", format_time(&time)));
+        }
+        for lap in &laps {
+            lap.write(&mut output);
+        }
+        output.push_str("    </Activity>\n");
+    }
+    output.push_str("  </Activities>\n</TrainingCenterDatabase>\n");
+    output
+}
+
+/// Write the laps and records stored in FIT data records as a TCX document into a destination
+/// that implements io::Write
+#[cfg(feature = "std")]
+pub fn to_writer<T: Write>(records: &[FitDataRecord], dest: &mut T) -> Result<()> {
+    dest.write_all(to_string(records).as_bytes())?;
+    Ok(())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+
+    #[test]
+    fn activity_to_tcx() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let tcx = to_string(&from_bytes(data).unwrap());
+        assert!(tcx.starts_with(TCX_HEADER));
+        assert!(tcx.contains("<Activity Sport=\"Running\">"));
+        assert!(tcx.contains("<Id>2012-04-09T21:22:26Z</Id>"));
+        assert_eq!(tcx.matches("<Lap ").count(), 1);
+        assert!(tcx.contains("<TotalTimeSeconds>13.749</TotalTimeSeconds>"));
+        assert_eq!(tcx.matches("<Trackpoint>").count(), 14);
+        assert!(tcx.ends_with("</TrainingCenterDatabase>\n"));
+    }
+
+    #[test]
+    fn garmin_fenix_5_bike_to_tcx() {
+        let data = include_bytes!("../../tests/fixtures/garmin-fenix-5-bike.fit");
+        let tcx = to_string(&from_bytes(data).unwrap());
+        assert!(tcx.contains("<Activity Sport=\"Biking\">"));
+        assert!(tcx.contains("<AverageHeartRateBpm><Value>101</Value></AverageHeartRateBpm>"));
+        assert!(tcx.contains("<HeartRateBpm><Value>77</Value></HeartRateBpm>"));
+        assert!(tcx.contains("<Calories>7</Calories>"));
+    }
+
+    #[test]
+    fn trackpoints_without_laps() {
+        let data = include_bytes!("../../tests/fixtures/Activity.fit");
+        let records: Vec<FitDataRecord> = from_bytes(data)
+            .unwrap()
+            .into_iter()
+            .filter(|r| r.kind() == MesgNum::Record)
+            .collect();
+        let laps = collect_laps(&records);
+        assert_eq!(laps.len(), 1);
+        assert_eq!(laps[0].trackpoints.len(), 14);
+        assert!(laps[0].total_time > 0.0);
+        assert!(to_string(&records).contains("<Activity Sport=\"Other\">"));
+    }
+}
@@ -0,0 +1,322 @@
+//! C compatible bindings used to parse FIT files from other languages, this module requires the
+//! `ffi` feature. The crate needs to be built as a cdylib or staticlib and the matching
+//! declarations are provided in `include/fitparser.h`.
+//!
+//! Parsed records are owned by an opaque `FitRecords` handle, every string returned by the
+//! accessor functions is valid until the handle is freed with `fit_records_free`.
+use crate::de::from_bytes;
+use crate::{FitDataRecord, Value};
+use std::convert::TryInto;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::ptr;
+use std::slice;
+
+/// A single data field converted into C compatible values
+struct FfiField {
+    name: CString,
+    number: u8,
+    value: Option<f64>,
+    value_string: CString,
+    units: CString,
+}
+
+/// A data record converted into C compatible values
+struct FfiRecord {
+    kind: u16,
+    kind_name: CString,
+    fields: Vec<FfiField>,
+}
+
+/// Opaque handle storing the records parsed from a FIT file
+pub struct FitRecords {
+    records: Vec<FfiRecord>,
+}
+
+/// Convert a string into a CString dropping any interior NUL bytes
+fn to_c_string<T: Into<Vec<u8>>>(value: T) -> CString {
+    let mut bytes = value.into();
+    bytes.retain(|b| *b != 0);
+    CString::new(bytes).unwrap_or_default()
+}
+
+impl From<FitDataRecord> for FfiRecord {
+    fn from(record: FitDataRecord) -> Self {
+        let kind = record.kind();
+        FfiRecord {
+            kind: kind.as_u16(),
+            kind_name: to_c_string(kind.to_string()),
+            fields: record
+                .into_vec()
+                .into_iter()
+                .map(|f| {
+                    let value: Option<f64> = match f.value() {
+                        Value::String(_) | Value::Array(_) => None,
+                        val => val.clone().try_into().ok(),
+                    };
+                    FfiField {
+                        name: to_c_string(f.name()),
+                        number: f.number(),
+                        value,
+                        value_string: to_c_string(f.value().to_string()),
+                        units: to_c_string(f.units()),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Look up a record by index from a possibly null handle
+unsafe fn get_record<'a>(records: *const FitRecords, index: usize) -> Option<&'a FfiRecord> {
+    records.as_ref().and_then(|r| r.records.get(index))
+}
+
+/// Look up a field by record and field index from a possibly null handle
+unsafe fn get_field<'a>(
+    records: *const FitRecords,
+    record: usize,
+    field: usize,
+) -> Option<&'a FfiField> {
+    get_record(records, record).and_then(|r| r.fields.get(field))
+}
+
+/// Parse a FIT file stored in a buffer, NULL is returned if the file can't be parsed. When
+/// `error` is not NULL it is set to a message describing the failure which must be released
+/// using `fit_string_free`.
+///
+/// # Safety
+///
+/// `data` must point to `len` readable bytes and `error` must be NULL or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn fit_parse_buffer(
+    data: *const u8,
+    len: usize,
+    error: *mut *mut c_char,
+) -> *mut FitRecords {
+    if !error.is_null() {
+        *error = ptr::null_mut();
+    }
+    let buffer = if data.is_null() || len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(data, len)
+    };
+    match from_bytes(buffer) {
+        Ok(records) => Box::into_raw(Box::new(FitRecords {
+            records: records.into_iter().map(FfiRecord::from).collect(),
+        })),
+        Err(e) => {
+            if !error.is_null() {
+                *error = to_c_string(e.to_string()).into_raw();
+            }
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Release the records returned by `fit_parse_buffer`
+///
+/// # Safety
+///
+/// `records` must be NULL or a handle returned by `fit_parse_buffer` that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn fit_records_free(records: *mut FitRecords) {
+    if !records.is_null() {
+        drop(Box::from_raw(records));
+    }
+}
+
+/// Release an error message returned by `fit_parse_buffer`
+///
+/// # Safety
+///
+/// `value` must be NULL or a string returned by `fit_parse_buffer` that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn fit_string_free(value: *mut c_char) {
+    if !value.is_null() {
+        drop(CString::from_raw(value));
+    }
+}
+
+/// Return the number of records stored in the handle
+///
+/// # Safety
+///
+/// `records` must be NULL or a valid handle returned by `fit_parse_buffer`.
+#[no_mangle]
+pub unsafe extern "C" fn fit_records_len(records: *const FitRecords) -> usize {
+    records.as_ref().map_or(0, |r| r.records.len())
+}
+
+/// Return the global message number of a record, 0xFFFF is returned for an invalid index
+///
+/// # Safety
+///
+/// `records` must be NULL or a valid handle returned by `fit_parse_buffer`.
+#[no_mangle]
+pub unsafe extern "C" fn fit_record_kind(records: *const FitRecords, record: usize) -> u16 {
+    get_record(records, record).map_or(0xFFFF, |r| r.kind)
+}
+
+/// Return the message name of a record, NULL is returned for an invalid index
+///
+/// # Safety
+///
+/// `records` must be NULL or a valid handle returned by `fit_parse_buffer`.
+#[no_mangle]
+pub unsafe extern "C" fn fit_record_kind_name(
+    records: *const FitRecords,
+    record: usize,
+) -> *const c_char {
+    get_record(records, record).map_or(ptr::null(), |r| r.kind_name.as_ptr())
+}
+
+/// Return the number of fields in a record
+///
+/// # Safety
+///
+/// `records` must be NULL or a valid handle returned by `fit_parse_buffer`.
+#[no_mangle]
+pub unsafe extern "C" fn fit_record_field_count(
+    records: *const FitRecords,
+    record: usize,
+) -> usize {
+    get_record(records, record).map_or(0, |r| r.fields.len())
+}
+
+/// Return the name of a field, NULL is returned for an invalid index
+///
+/// # Safety
+///
+/// `records` must be NULL or a valid handle returned by `fit_parse_buffer`.
+#[no_mangle]
+pub unsafe extern "C" fn fit_field_name(
+    records: *const FitRecords,
+    record: usize,
+    field: usize,
+) -> *const c_char {
+    get_field(records, record, field).map_or(ptr::null(), |f| f.name.as_ptr())
+}
+
+/// Return the definition number of a field, 0xFF is returned for an invalid index
+///
+/// # Safety
+///
+/// `records` must be NULL or a valid handle returned by `fit_parse_buffer`.
+#[no_mangle]
+pub unsafe extern "C" fn fit_field_number(
+    records: *const FitRecords,
+    record: usize,
+    field: usize,
+) -> u8 {
+    get_field(records, record, field).map_or(0xFF, |f| f.number)
+}
+
+/// Store the numeric value of a field in `value`, false is returned if the field isn't numeric.
+/// Timestamps are returned as seconds since the unix epoch, local timestamps are treated as UTC.
+///
+/// # Safety
+///
+/// `records` must be NULL or a valid handle returned by `fit_parse_buffer` and `value` must be
+/// NULL or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn fit_field_value_f64(
+    records: *const FitRecords,
+    record: usize,
+    field: usize,
+    value: *mut f64,
+) -> bool {
+    match get_field(records, record, field).and_then(|f| f.value) {
+        Some(val) if !value.is_null() => {
+            *value = val;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Return the value of a field formatted as a string, NULL is returned for an invalid index
+///
+/// # Safety
+///
+/// `records` must be NULL or a valid handle returned by `fit_parse_buffer`.
+#[no_mangle]
+pub unsafe extern "C" fn fit_field_value_string(
+    records: *const FitRecords,
+    record: usize,
+    field: usize,
+) -> *const c_char {
+    get_field(records, record, field).map_or(ptr::null(), |f| f.value_string.as_ptr())
+}
+
+/// Return the units of a field, NULL is returned for an invalid index
+///
+/// # Safety
+///
+/// `records` must be NULL or a valid handle returned by `fit_parse_buffer`.
+#[no_mangle]
+pub unsafe extern "C" fn fit_field_units(
+    records: *const FitRecords,
+    record: usize,
+    field: usize,
+) -> *const c_char {
+    get_field(records, record, field).map_or(ptr::null(), |f| f.units.as_ptr())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn parse_buffer_and_access_fields() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        unsafe {
+            let records = fit_parse_buffer(data.as_ptr(), data.len(), ptr::null_mut());
+            assert!(!records.is_null());
+            assert_eq!(fit_records_len(records), 22);
+            assert_eq!(fit_record_kind(records, 0), 0);
+            let name = CStr::from_ptr(fit_record_kind_name(records, 0));
+            assert_eq!(name.to_str().unwrap(), "file_id");
+
+            let nfields = fit_record_field_count(records, 0);
+            assert!(nfields > 0);
+            let names: Vec<&str> = (0..nfields)
+                .map(|i| {
+                    CStr::from_ptr(fit_field_name(records, 0, i))
+                        .to_str()
+                        .unwrap()
+                })
+                .collect();
+            let idx = names.iter().position(|n| *n == "manufacturer").unwrap();
+            let value = CStr::from_ptr(fit_field_value_string(records, 0, idx));
+            assert_eq!(value.to_str().unwrap(), "dynastream");
+            let mut number = 0.0;
+            assert!(!fit_field_value_f64(records, 0, idx, &mut number));
+
+            let idx = names.iter().position(|n| *n == "serial_number").unwrap();
+            assert!(fit_field_value_f64(records, 0, idx, &mut number));
+            assert!(number > 0.0);
+
+            // out of range indexes return sentinel values
+            assert_eq!(fit_record_kind(records, 100), 0xFFFF);
+            assert!(fit_field_name(records, 0, 100).is_null());
+            fit_records_free(records);
+        }
+    }
+
+    #[test]
+    fn parse_buffer_error() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        unsafe {
+            let mut error = ptr::null_mut();
+            let records = fit_parse_buffer(data.as_ptr(), data.len() - 10, &mut error);
+            assert!(records.is_null());
+            assert!(!error.is_null());
+            assert!(!CStr::from_ptr(error).to_bytes().is_empty());
+            fit_string_free(error);
+            assert_eq!(fit_records_len(records), 0);
+        }
+    }
+}
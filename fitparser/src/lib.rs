@@ -2,8 +2,9 @@
 //!
 //! `fitparser` is a utility to parse an ANT FIT file based on a given profile into a more
 //! useful form for consuming applications. To that end the [serde](https://github.com/serde-rs/serde)
-//! framework is used to allow the data to be serialized into any format supported by serde. This
-//! library currently does not support writing FIT files.
+//! framework is used to allow the data to be serialized into any format supported by serde. Data
+//! records can also be written back out as a FIT file using the `encode` module, or exported
+//! as GPX or TCX files using the `export` module.
 //!
 //! ## Example
 //! Open a file or pass in any other object that implements the Read
@@ -34,18 +35,66 @@
 //!
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
-use chrono::{DateTime, Local};
-use serde::Serialize;
-use std::convert;
-use std::fmt;
+#[cfg_attr(not(feature = "std"), macro_use)]
+extern crate alloc;
 
+#[cfg(not(feature = "alloc"))]
+compile_error!("fitparser requires either the `std` or `alloc` feature");
+
+use chrono::{DateTime, NaiveDateTime};
+use compat::prelude::*;
+use core::convert::{self, TryFrom};
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+pub mod analysis;
+#[cfg(feature = "arena")]
+pub mod arena;
+mod compat;
+#[cfg(feature = "std")]
+pub mod course;
 pub mod de;
+pub mod diff;
+pub mod encode;
 mod error;
+pub mod events;
+pub mod export;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod profile;
+#[cfg(feature = "python")]
+mod python;
+#[cfg(feature = "schema")]
+pub mod schema;
+#[cfg(feature = "std")]
+pub mod scrub;
+pub mod ser;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod workout;
+
+/// Time zone used for timestamp values, UTC is used in place of the local time zone when the
+/// `std` feature is disabled.
+#[cfg(feature = "std")]
+pub use chrono::Local as TimestampTz;
+/// Time zone used for timestamp values, UTC is used in place of the local time zone when the
+/// `std` feature is disabled.
+#[cfg(not(feature = "std"))]
+pub use chrono::Utc as TimestampTz;
 
-pub use de::{from_bytes, from_reader};
-pub use error::{Error, ErrorKind, Result};
+#[cfg(feature = "async")]
+pub use de::parse_async;
+pub use de::{from_bytes, parse_all, peek_file_type, FitFile};
+#[cfg(feature = "std")]
+pub use de::{from_reader, from_reader_iter};
+pub use encode::to_bytes;
+#[cfg(feature = "std")]
+pub use encode::to_writer;
+pub use error::{DecodeContext, Error, ErrorKind, Result};
 
 /// Defines a set of data derived from a FIT Data message.
 #[derive(Clone, Debug, Serialize)]
@@ -55,6 +104,13 @@ pub struct FitDataRecord {
     kind: profile::MesgNum,
     /// All the fields present in this message, a record may not have every possible field defined
     fields: Vec<FitDataField>,
+    /// Layout of the message in the file it was decoded from, this is cleared when the record is
+    /// modified since the bytes no longer match the fields
+    #[serde(skip)]
+    layout: Option<compat::Arc<de::MessageLayout>>,
+    /// Bytes of the message the record was decoded from when using `DecodeOption::KeepRawBytes`
+    #[serde(skip)]
+    raw: Option<compat::Arc<de::RawMessage>>,
 }
 
 impl FitDataRecord {
@@ -63,6 +119,8 @@ impl FitDataRecord {
         FitDataRecord {
             kind,
             fields: Vec::new(),
+            layout: None,
+            raw: None,
         }
     }
 
@@ -76,16 +134,92 @@ impl FitDataRecord {
         &self.fields
     }
 
+    /// Return the bytes of the data message the record was decoded from, these are only kept
+    /// when decoding with `DecodeOption::KeepRawBytes`
+    pub fn raw_message(&self) -> Option<&de::RawMessage> {
+        self.raw.as_deref()
+    }
+
     /// Add a field to the record
     pub fn push(&mut self, field: FitDataField) {
+        self.layout = None;
         self.fields.push(field)
     }
 
     /// Add multiple fields to the record
     pub fn extend(&mut self, fields: Vec<FitDataField>) {
+        self.layout = None;
         self.fields.extend(fields)
     }
 
+    /// Return the first field with the provided name
+    pub fn field(&self, name: &str) -> Option<&FitDataField> {
+        self.fields.iter().find(|f| f.name() == name)
+    }
+
+    /// Return the value of the first field with the provided name
+    pub fn field_value(&self, name: &str) -> Option<&Value> {
+        self.field(name).map(FitDataField::value)
+    }
+
+    /// Return the field with the provided definition number, developer fields are skipped since
+    /// their numbers are only unique within a developer data index
+    pub fn field_by_number(&self, number: u8) -> Option<&FitDataField> {
+        self.fields
+            .iter()
+            .find(|f| f.number() == number && f.developer_data_index().is_none())
+    }
+
+    /// Set the value of a field, adding it to the record if it doesn't exist. Fields defined by
+    /// the profile must be given a value that can be written using the type of the field, e.g. a
+    /// variant name for enums or a number within the range of the base type once the scale and
+    /// offset are removed. Fields that aren't defined by the profile can only be updated.
+    pub fn set_field_value(&mut self, name: &str, value: Value) -> Result<()> {
+        let position = self.fields.iter().position(|f| f.name() == name);
+        let info = match position {
+            Some(i) if self.fields[i].developer_data_index().is_some() => None,
+            Some(i) => self.kind.get_field_info(self.fields[i].number(), name),
+            None => self.kind.field_info().iter().find(|f| f.name() == name),
+        };
+        if let Some(info) = info {
+            encode::check_field_value(info, &value)?;
+        }
+        match (position, info) {
+            (Some(i), _) => self.fields[i].value = value,
+            (None, Some(info)) => self.fields.push(FitDataField::new(
+                info.name(),
+                info.def_number(),
+                value,
+                info.units(),
+            )),
+            (None, None) => {
+                return Err(ErrorKind::ValueError(format!(
+                    "{} is not a field of {} messages",
+                    name, self.kind
+                ))
+                .into())
+            }
+        }
+        self.layout = None;
+        Ok(())
+    }
+
+    /// Remove the first field with the provided name, returning it if it exists
+    pub fn remove_field(&mut self, name: &str) -> Option<FitDataField> {
+        let position = self.fields.iter().position(|f| f.name() == name)?;
+        self.layout = None;
+        Some(self.fields.remove(position))
+    }
+
+    /// Only keep the fields that the predicate returns true for
+    pub fn retain_fields<F: FnMut(&FitDataField) -> bool>(&mut self, f: F) {
+        let count = self.fields.len();
+        self.fields.retain(f);
+        if self.fields.len() != count {
+            self.layout = None;
+        }
+    }
+
     /// Consume the record and return the field vector for further processing
     pub fn into_vec(self) -> Vec<FitDataField> {
         self.fields
@@ -95,20 +229,45 @@ impl FitDataRecord {
 /// Stores a value and it's defined units which are set by the FIT profile during decoding
 #[derive(Clone, Debug, Serialize)]
 pub struct FitDataField {
-    name: String,
+    name: SharedStr,
     number: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    developer_data_index: Option<u8>,
     value: Value,
-    units: String,
+    units: SharedStr,
 }
 
 impl FitDataField {
     /// Create a new FitDataField
-    pub fn new(name: String, number: u8, value: Value, units: String) -> Self {
+    pub fn new(
+        name: impl Into<SharedStr>,
+        number: u8,
+        value: Value,
+        units: impl Into<SharedStr>,
+    ) -> Self {
+        FitDataField {
+            name: name.into(),
+            number,
+            developer_data_index: None,
+            value,
+            units: units.into(),
+        }
+    }
+
+    /// Create a new FitDataField for a developer field described by a field description message
+    pub fn new_developer_field(
+        name: impl Into<SharedStr>,
+        number: u8,
+        developer_data_index: u8,
+        value: Value,
+        units: impl Into<SharedStr>,
+    ) -> Self {
         FitDataField {
-            name,
+            name: name.into(),
             number,
+            developer_data_index: Some(developer_data_index),
             value,
-            units,
+            units: units.into(),
         }
     }
 
@@ -122,6 +281,12 @@ impl FitDataField {
         self.number
     }
 
+    /// Return the developer data index if this is a developer field, the field number is then
+    /// the one defined by the field description message
+    pub fn developer_data_index(&self) -> Option<u8> {
+        self.developer_data_index
+    }
+
     /// Return a reference to the stored value
     pub fn value(&self) -> &Value {
         &self.value
@@ -148,12 +313,122 @@ impl fmt::Display for FitDataField {
     }
 }
 
+/// String used for field names and units. Names from the profile are borrowed from the static
+/// profile and developer field names are shared by every field using them, so decoding a record
+/// doesn't allocate a copy of each name.
+#[derive(Clone, Debug)]
+pub enum SharedStr {
+    /// String defined by the profile
+    Static(&'static str),
+    /// Reference counted string created while decoding
+    Shared(compat::Arc<str>),
+}
+
+impl SharedStr {
+    /// Return the string slice
+    pub fn as_str(&self) -> &str {
+        match self {
+            SharedStr::Static(val) => val,
+            SharedStr::Shared(val) => val,
+        }
+    }
+}
+
+impl core::ops::Deref for SharedStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SharedStr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Default for SharedStr {
+    fn default() -> Self {
+        SharedStr::Static("")
+    }
+}
+
+impl PartialEq for SharedStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for SharedStr {}
+
+impl core::hash::Hash for SharedStr {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl PartialEq<str> for SharedStr {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SharedStr {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl From<&'static str> for SharedStr {
+    fn from(val: &'static str) -> Self {
+        SharedStr::Static(val)
+    }
+}
+
+impl From<String> for SharedStr {
+    fn from(val: String) -> Self {
+        SharedStr::Shared(val.into())
+    }
+}
+
+impl From<compat::Arc<str>> for SharedStr {
+    fn from(val: compat::Arc<str>) -> Self {
+        SharedStr::Shared(val)
+    }
+}
+
+impl fmt::Display for SharedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for SharedStr {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for SharedStr {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        String::deserialize(deserializer).map(SharedStr::from)
+    }
+}
+
 /// Contains arbitrary data in the defined format.
 #[derive(Clone, Debug, PartialEq, PartialOrd, Serialize)]
 #[serde(untagged)]
 pub enum Value {
     /// Timestamp field converted to the local timezone
-    Timestamp(DateTime<Local>),
+    Timestamp(DateTime<TimestampTz>),
+    /// Local date time field, the wall clock time where the data was recorded without a time zone
+    LocalTimestamp(NaiveDateTime),
     /// Unsigned 8bit integer data
     Byte(u8), // TODO: I think this should actually be a Vec<u8> type
     /// Unsigned 8bit integer that gets mapped to a FieldType enum
@@ -197,6 +472,7 @@ impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self {
             Value::Timestamp(val) => write!(f, "{}", val),
+            Value::LocalTimestamp(val) => write!(f, "{}", val),
             Value::Byte(val) => write!(f, "{}", val),
             Value::Enum(val) => write!(f, "{}", val),
             Value::SInt8(val) => write!(f, "{}", val),
@@ -219,80 +495,168 @@ impl fmt::Display for Value {
     }
 }
 
-impl convert::TryInto<f64> for Value {
-    type Error = error::Error;
+impl Value {
+    /// Return the value as a floating point number, timestamps are returned as seconds since the
+    /// Unix epoch treating local timestamps as UTC
+    pub fn as_f64(&self) -> Option<f64> {
+        f64::try_from(self).ok()
+    }
+
+    /// Return the value as an integer, timestamps are returned as seconds since the Unix epoch
+    /// treating local timestamps as UTC
+    pub fn as_i64(&self) -> Option<i64> {
+        i64::try_from(self).ok()
+    }
 
-    fn try_into(self) -> Result<f64> {
+    /// Return the value if it is a string
+    pub fn as_str(&self) -> Option<&str> {
         match self {
-            Value::Timestamp(val) => Ok(val.timestamp() as f64),
-            Value::Byte(val) => Ok(val as f64),
-            Value::Enum(val) => Ok(val as f64),
-            Value::SInt8(val) => Ok(val as f64),
-            Value::UInt8(val) => Ok(val as f64),
-            Value::UInt8z(val) => Ok(val as f64),
-            Value::SInt16(val) => Ok(val as f64),
-            Value::UInt16(val) => Ok(val as f64),
-            Value::UInt16z(val) => Ok(val as f64),
-            Value::SInt32(val) => Ok(val as f64),
-            Value::UInt32(val) => Ok(val as f64),
-            Value::UInt32z(val) => Ok(val as f64),
-            Value::SInt64(val) => Ok(val as f64),
-            Value::UInt64(val) => Ok(val as f64),
-            Value::UInt64z(val) => Ok(val as f64),
-            Value::Float32(val) => Ok(val as f64),
-            Value::Float64(val) => Ok(val),
-            Value::String(_) => {
-                Err(ErrorKind::ValueError(format!("cannot convert {} into an f64", self)).into())
-            }
-            Value::Array(_) => {
-                Err(ErrorKind::ValueError(format!("cannot convert {} into an f64", self)).into())
+            Value::String(val) => Some(val),
+            _ => None,
+        }
+    }
+
+    /// Return the value if it is a timestamp
+    pub fn as_timestamp(&self) -> Option<DateTime<TimestampTz>> {
+        match self {
+            Value::Timestamp(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    /// Return the value if it is a local date time, such as `activity.local_timestamp`
+    pub fn as_local_timestamp(&self) -> Option<NaiveDateTime> {
+        match self {
+            Value::LocalTimestamp(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    /// Return the value if it is a timestamp converted into UTC, FIT `date_time` values are
+    /// stored in UTC but decoded into the system time zone when the `std` feature is enabled
+    pub fn as_utc_timestamp(&self) -> Option<DateTime<chrono::Utc>> {
+        self.as_timestamp()
+            .map(|val| val.with_timezone(&chrono::Utc))
+    }
+
+    /// Return a numeric value in seconds as a duration, such as the `total_timer_time` of a
+    /// session, rounded to the nearest microsecond
+    pub fn as_duration(&self) -> Option<chrono::Duration> {
+        match self {
+            Value::Timestamp(_) | Value::LocalTimestamp(_) => None,
+            _ => {
+                let micros = self.as_f64()? * 1_000_000.0;
+                let micros = if micros < 0.0 {
+                    micros - 0.5
+                } else {
+                    micros + 0.5
+                };
+                Some(chrono::Duration::microseconds(micros as i64))
             }
         }
     }
 }
 
-impl convert::TryInto<i64> for Value {
-    type Error = error::Error;
+/// Values are deserialized from the generic data model since the serialized form doesn't store
+/// the type, integers become `SInt64` or `UInt64`, floats become `Float64` and strings are kept
+/// as strings. Timestamps are converted back when deserializing a `FitDataRecord`.
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        struct ValueVisitor;
 
-    fn try_into(self) -> Result<i64> {
-        match self {
-            Value::Timestamp(val) => Ok(val.timestamp()),
-            Value::Byte(val) => Ok(val as i64),
-            Value::Enum(val) => Ok(val as i64),
-            Value::SInt8(val) => Ok(val as i64),
-            Value::UInt8(val) => Ok(val as i64),
-            Value::UInt8z(val) => Ok(val as i64),
-            Value::SInt16(val) => Ok(val as i64),
-            Value::UInt16(val) => Ok(val as i64),
-            Value::UInt16z(val) => Ok(val as i64),
-            Value::SInt32(val) => Ok(val as i64),
-            Value::UInt32(val) => Ok(val as i64),
-            Value::UInt32z(val) => Ok(val as i64),
-            Value::SInt64(val) => Ok(val),
-            Value::UInt64(val) => Ok(val as i64),
-            Value::UInt64z(val) => Ok(val as i64),
-            Value::Float32(_) => {
-                Err(ErrorKind::ValueError(format!("cannot convert {} into an i64", self)).into())
+        impl<'de> serde::de::Visitor<'de> for ValueVisitor {
+            type Value = Value;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a number, string or array of values")
             }
-            Value::Float64(_) => {
-                Err(ErrorKind::ValueError(format!("cannot convert {} into an i64", self)).into())
+
+            fn visit_i64<E>(self, val: i64) -> core::result::Result<Value, E> {
+                Ok(Value::SInt64(val))
+            }
+
+            fn visit_u64<E>(self, val: u64) -> core::result::Result<Value, E> {
+                Ok(Value::UInt64(val))
+            }
+
+            fn visit_f64<E>(self, val: f64) -> core::result::Result<Value, E> {
+                Ok(Value::Float64(val))
+            }
+
+            fn visit_str<E>(self, val: &str) -> core::result::Result<Value, E> {
+                Ok(Value::String(String::from(val)))
+            }
+
+            fn visit_string<E>(self, val: String) -> core::result::Result<Value, E> {
+                Ok(Value::String(val))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> core::result::Result<Value, A::Error> {
+                let mut vals = Vec::new();
+                while let Some(val) = seq.next_element()? {
+                    vals.push(val);
+                }
+                Ok(Value::Array(vals))
             }
+        }
+
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+impl TryFrom<&Value> for f64 {
+    type Error = error::Error;
+
+    fn try_from(value: &Value) -> Result<f64> {
+        match value {
+            Value::Timestamp(val) => Ok(val.timestamp() as f64),
+            Value::LocalTimestamp(val) => Ok(val.and_utc().timestamp() as f64),
+            Value::Byte(val) => Ok(*val as f64),
+            Value::Enum(val) => Ok(*val as f64),
+            Value::SInt8(val) => Ok(*val as f64),
+            Value::UInt8(val) => Ok(*val as f64),
+            Value::UInt8z(val) => Ok(*val as f64),
+            Value::SInt16(val) => Ok(*val as f64),
+            Value::UInt16(val) => Ok(*val as f64),
+            Value::UInt16z(val) => Ok(*val as f64),
+            Value::SInt32(val) => Ok(*val as f64),
+            Value::UInt32(val) => Ok(*val as f64),
+            Value::UInt32z(val) => Ok(*val as f64),
+            Value::SInt64(val) => Ok(*val as f64),
+            Value::UInt64(val) => Ok(*val as f64),
+            Value::UInt64z(val) => Ok(*val as f64),
+            Value::Float32(val) => Ok(*val as f64),
+            Value::Float64(val) => Ok(*val),
             Value::String(_) => {
-                Err(ErrorKind::ValueError(format!("cannot convert {} into an i64", self)).into())
+                Err(ErrorKind::ValueError(format!("cannot convert {} into an f64", value)).into())
             }
             Value::Array(_) => {
-                Err(ErrorKind::ValueError(format!("cannot convert {} into an i64", self)).into())
+                Err(ErrorKind::ValueError(format!("cannot convert {} into an f64", value)).into())
             }
         }
     }
 }
 
-impl convert::TryInto<i64> for &Value {
+impl TryFrom<Value> for f64 {
     type Error = error::Error;
 
-    fn try_into(self) -> Result<i64> {
-        match self {
+    fn try_from(value: Value) -> Result<f64> {
+        f64::try_from(&value)
+    }
+}
+
+impl TryFrom<&Value> for i64 {
+    type Error = error::Error;
+
+    fn try_from(value: &Value) -> Result<i64> {
+        match value {
             Value::Timestamp(val) => Ok(val.timestamp()),
+            Value::LocalTimestamp(val) => Ok(val.and_utc().timestamp()),
             Value::Byte(val) => Ok(*val as i64),
             Value::Enum(val) => Ok(*val as i64),
             Value::SInt8(val) => Ok(*val as i64),
@@ -308,34 +672,126 @@ impl convert::TryInto<i64> for &Value {
             Value::UInt64(val) => Ok(*val as i64),
             Value::UInt64z(val) => Ok(*val as i64),
             Value::Float32(_) => {
-                Err(ErrorKind::ValueError(format!("cannot convert {} into an i64", self)).into())
+                Err(ErrorKind::ValueError(format!("cannot convert {} into an i64", value)).into())
             }
             Value::Float64(_) => {
-                Err(ErrorKind::ValueError(format!("cannot convert {} into an i64", self)).into())
+                Err(ErrorKind::ValueError(format!("cannot convert {} into an i64", value)).into())
             }
             Value::String(_) => {
-                Err(ErrorKind::ValueError(format!("cannot convert {} into an i64", self)).into())
+                Err(ErrorKind::ValueError(format!("cannot convert {} into an i64", value)).into())
             }
             Value::Array(_) => {
-                Err(ErrorKind::ValueError(format!("cannot convert {} into an i64", self)).into())
+                Err(ErrorKind::ValueError(format!("cannot convert {} into an i64", value)).into())
             }
         }
     }
 }
 
+impl TryFrom<Value> for i64 {
+    type Error = error::Error;
+
+    fn try_from(value: Value) -> Result<i64> {
+        i64::try_from(&value)
+    }
+}
+
+/// Implement conversions into smaller integer types by converting into an i64 and checking
+/// the range of the result
+macro_rules! try_from_value_for_int {
+    ($($int:ty),*) => {
+        $(
+            impl TryFrom<&Value> for $int {
+                type Error = error::Error;
+
+                fn try_from(value: &Value) -> Result<$int> {
+                    let val = i64::try_from(value)?;
+                    <$int>::try_from(val).map_err(|_| {
+                        ErrorKind::ValueError(format!(
+                            "{} is out of range for a {}",
+                            value,
+                            stringify!($int)
+                        ))
+                        .into()
+                    })
+                }
+            }
+
+            impl TryFrom<Value> for $int {
+                type Error = error::Error;
+
+                fn try_from(value: Value) -> Result<$int> {
+                    <$int>::try_from(&value)
+                }
+            }
+        )*
+    };
+}
+
+try_from_value_for_int!(i8, u8, i16, u16, i32, u32, u64);
+
+impl TryFrom<&Value> for f32 {
+    type Error = error::Error;
+
+    fn try_from(value: &Value) -> Result<f32> {
+        f64::try_from(value).map(|val| val as f32)
+    }
+}
+
+impl TryFrom<Value> for f32 {
+    type Error = error::Error;
+
+    fn try_from(value: Value) -> Result<f32> {
+        f32::try_from(&value)
+    }
+}
+
+impl TryFrom<Value> for String {
+    type Error = error::Error;
+
+    fn try_from(value: Value) -> Result<String> {
+        match value {
+            Value::String(val) => Ok(val),
+            _ => {
+                Err(ErrorKind::ValueError(format!("cannot convert {} into a string", value)).into())
+            }
+        }
+    }
+}
+
+impl TryFrom<&Value> for DateTime<TimestampTz> {
+    type Error = error::Error;
+
+    fn try_from(value: &Value) -> Result<DateTime<TimestampTz>> {
+        value.as_timestamp().ok_or_else(|| {
+            ErrorKind::ValueError(format!("cannot convert {} into a timestamp", value)).into()
+        })
+    }
+}
+
+impl TryFrom<Value> for DateTime<TimestampTz> {
+    type Error = error::Error;
+
+    fn try_from(value: Value) -> Result<DateTime<TimestampTz>> {
+        DateTime::try_from(&value)
+    }
+}
+
 /// Describes a field value along with its defined units (if any), this struct is useful for
 /// serializing data in a key-value store where the key is either the name or definition number
 /// since it can be created from a `FitDataField` with minimal data cloning.
 #[derive(Clone, Debug, Serialize)]
 pub struct ValueWithUnits {
     value: Value,
-    units: String,
+    units: SharedStr,
 }
 
 impl ValueWithUnits {
     /// Create a new value with the given information
-    pub fn new(value: Value, units: String) -> Self {
-        ValueWithUnits { value, units }
+    pub fn new(value: Value, units: impl Into<SharedStr>) -> Self {
+        ValueWithUnits {
+            value,
+            units: units.into(),
+        }
     }
 }
 
@@ -367,11 +823,90 @@ mod tests {
         assert_eq!(fit_data.len(), 22);
     }
 
+    #[test]
+    fn field_names_are_not_copied() {
+        let data = include_bytes!("../tests/fixtures/DeveloperData.fit").to_vec();
+        let fit_data = from_bytes(&data).unwrap();
+        let mut developer_names = Vec::new();
+        for field in fit_data.iter().flat_map(|r| r.fields()) {
+            match (&field.name, field.developer_data_index()) {
+                (SharedStr::Shared(name), Some(_)) => developer_names.push(name),
+                (SharedStr::Static(_), None) => {}
+                (name, _) => panic!("unexpected name {:?}", name),
+            }
+        }
+        // every developer field shares the name of its field description
+        assert_eq!(developer_names.len(), 3);
+        assert!(developer_names
+            .iter()
+            .all(|n| compat::Arc::ptr_eq(n, developer_names[0])));
+    }
+
     #[test]
     fn parse_developer_data() {
         let data = include_bytes!("../tests/fixtures/DeveloperData.fit").to_vec();
         let fit_data = from_bytes(&data).unwrap();
         assert_eq!(fit_data.len(), 6);
+
+        // developer fields are resolved using the field description message
+        let records: Vec<&FitDataRecord> = fit_data
+            .iter()
+            .filter(|r| r.kind() == profile::MesgNum::Record)
+            .collect();
+        assert_eq!(records.len(), 3);
+        for (i, record) in records.iter().enumerate() {
+            let field = record
+                .fields()
+                .iter()
+                .find(|f| f.developer_data_index().is_some())
+                .unwrap();
+            assert_eq!(field.name(), "doughnuts_earned");
+            assert_eq!(field.developer_data_index(), Some(0));
+            assert_eq!(field.value(), &Value::SInt8(i as i8 + 1));
+            assert_eq!(field.units(), "doughnuts");
+        }
+
+        // developer fields are found in the deferred index as well
+        let index = de::FitIndex::new(&data).unwrap();
+        let record = index.decode(&index.messages()[3]).unwrap();
+        assert_eq!(record.fields().len(), records[0].fields().len());
+    }
+
+    #[test]
+    fn parse_unknown_messages_and_fields() {
+        // write a message and field that aren't defined in the profile
+        let mut unknown = FitDataRecord::new(profile::MesgNum::Value(0xFF10));
+        unknown.push(FitDataField::new(
+            String::from("unknown_field_5"),
+            5,
+            Value::UInt16(42),
+            String::new(),
+        ));
+        let mut record = FitDataRecord::new(profile::MesgNum::Record);
+        record.push(FitDataField::new(
+            String::from("unknown_field_200"),
+            200,
+            Value::SInt32(-7),
+            String::new(),
+        ));
+        let data = to_bytes(&[unknown, record]).unwrap();
+
+        // unknown entries are kept with their raw base type values by default
+        let fit_data = from_bytes(&data).unwrap();
+        assert_eq!(fit_data.len(), 2);
+        assert_eq!(fit_data[0].kind(), profile::MesgNum::Value(0xFF10));
+        assert_eq!(fit_data[0].fields()[0].name(), "unknown_field_5");
+        assert_eq!(fit_data[0].fields()[0].value(), &Value::UInt16(42));
+        assert_eq!(fit_data[1].fields()[0].name(), "unknown_field_200");
+        assert_eq!(fit_data[1].fields()[0].value(), &Value::SInt32(-7));
+
+        // and can be discarded using the decode options
+        let mut options = HashSet::new();
+        options.insert(de::DecodeOption::DropUnknownMessages);
+        options.insert(de::DecodeOption::DropUnknownFields);
+        let fit_data = de::from_bytes_with_options(&data, &options).unwrap();
+        assert_eq!(fit_data.len(), 1);
+        assert!(fit_data[0].fields().is_empty());
     }
 
     #[test]
@@ -409,6 +944,370 @@ mod tests {
         assert_eq!(fit_data.len(), 6);
     }
 
+    #[test]
+    fn parse_raw_values() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit").to_vec();
+        let mut options = HashSet::new();
+        options.insert(de::DecodeOption::ReturnRawValues);
+        let raw = de::from_bytes_with_options(&data, &options).unwrap();
+        let field = |record: &FitDataRecord, name: &str| {
+            record
+                .fields()
+                .iter()
+                .find(|f| f.name() == name)
+                .cloned()
+                .unwrap()
+        };
+        // the fifth message is the second record message
+        let distance = field(&raw[4], "distance");
+        assert_eq!(distance.value(), &Value::UInt32(2));
+        assert_eq!(distance.units(), "m");
+        assert_eq!(
+            field(&raw[4], "timestamp").value(),
+            &Value::UInt32(702940947)
+        );
+        assert_eq!(field(&raw[2], "event").value(), &Value::Enum(0));
+    }
+
+    #[test]
+    fn value_accessors() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit").to_vec();
+        let fit_data = from_bytes(&data).unwrap();
+        let session = fit_data
+            .iter()
+            .find(|r| r.kind() == profile::MesgNum::Session)
+            .unwrap();
+        let value = |name| session.field_value(name).unwrap();
+        assert_eq!(value("sport").as_str(), Some("running"));
+        assert_eq!(session.field_by_number(5).unwrap().name(), "sport");
+        assert!(session.field_by_number(200).is_none());
+        assert!(session.field_value("not_a_field").is_none());
+        assert_eq!(value("sport").as_f64(), None);
+        assert_eq!(value("total_distance").as_f64(), Some(5.73));
+        assert_eq!(
+            value("total_timer_time").as_duration(),
+            Some(chrono::Duration::milliseconds(13749))
+        );
+        let start_time = value("start_time").as_timestamp().unwrap();
+        assert_eq!(value("start_time").as_i64(), Some(start_time.timestamp()));
+        let utc = value("start_time").as_utc_timestamp().unwrap();
+        assert_eq!(utc, start_time);
+        assert_eq!(utc.to_rfc3339(), "2012-04-09T21:22:26+00:00");
+
+        assert_eq!(u8::try_from(Value::UInt16(200)).unwrap(), 200);
+        assert!(u8::try_from(Value::UInt16(300)).is_err());
+        assert!(i64::try_from(Value::Float64(1.0)).is_err());
+        assert_eq!(f32::try_from(&Value::SInt8(-3)).unwrap(), -3.0);
+        assert_eq!(
+            String::try_from(Value::String(String::from("a"))).unwrap(),
+            "a"
+        );
+        assert_eq!(
+            DateTime::<TimestampTz>::try_from(value("start_time")).unwrap(),
+            start_time
+        );
+    }
+
+    #[test]
+    fn parse_typed_messages() {
+        use core::convert::TryFrom;
+        use profile::{field_types, typed};
+
+        let data = include_bytes!("../tests/fixtures/Activity.fit").to_vec();
+        let fit_data = from_bytes(&data).unwrap();
+        let file_id = typed::FileId::try_from(&fit_data[0]).unwrap();
+        assert_eq!(file_id.r#type, Some(field_types::File::Activity));
+        assert!(file_id.time_created.is_some());
+
+        // the fifth message is the second record message
+        let record = typed::Record::try_from(&fit_data[4]).unwrap();
+        assert_eq!(record.distance, Some(0.02));
+        assert_eq!(record.heart_rate, None);
+        assert!(typed::Record::try_from(&fit_data[0]).is_err());
+
+        // typed messages can be converted back into records and encoded
+        let session = fit_data
+            .iter()
+            .find(|r| r.kind() == profile::MesgNum::Session)
+            .unwrap();
+        let session = typed::Session::try_from(session).unwrap();
+        let records = [
+            FitDataRecord::from(&file_id),
+            FitDataRecord::from(&record),
+            FitDataRecord::from(&session),
+        ];
+        assert_eq!(records[1].kind(), profile::MesgNum::Record);
+        let decoded = from_bytes(&to_bytes(&records).unwrap()).unwrap();
+        assert_eq!(typed::FileId::try_from(&decoded[0]).unwrap(), file_id);
+        assert_eq!(typed::Record::try_from(&decoded[1]).unwrap(), record);
+        assert_eq!(typed::Session::try_from(&decoded[2]).unwrap(), session);
+    }
+
+    #[test]
+    fn edit_records() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit").to_vec();
+        let mut fit_file = parse_all(&data).unwrap().remove(0);
+        let records = fit_file.records_mut();
+
+        // correct a heart rate spike and the sport of the session
+        let record = &mut records[4];
+        assert!(record.field("heart_rate").is_none());
+        record
+            .set_field_value("heart_rate", Value::UInt8(140))
+            .unwrap();
+        assert_eq!(record.field("heart_rate").unwrap().units(), "bpm");
+        assert!(record
+            .set_field_value("heart_rate", Value::UInt16(300))
+            .is_err());
+        assert!(record
+            .set_field_value("not_a_field", Value::UInt8(1))
+            .is_err());
+        assert!(record.remove_field("distance").is_some());
+        assert!(record.field("distance").is_none());
+
+        let session = records
+            .iter_mut()
+            .find(|r| r.kind() == profile::MesgNum::Session)
+            .unwrap();
+        session
+            .set_field_value("sport", Value::String(String::from("swimming")))
+            .unwrap();
+        assert!(session
+            .set_field_value("sport", Value::String(String::from("not_a_sport")))
+            .is_err());
+
+        let count = fit_file.records().len();
+        fit_file.retain_records(|r| r.kind() != profile::MesgNum::Event);
+        assert!(fit_file.records().len() < count);
+
+        let decoded = from_bytes(&to_bytes(fit_file.records()).unwrap()).unwrap();
+        assert_eq!(
+            decoded
+                .iter()
+                .find_map(|r| r.field("heart_rate"))
+                .unwrap()
+                .value(),
+            &Value::UInt8(140)
+        );
+        let session = decoded
+            .iter()
+            .find(|r| r.kind() == profile::MesgNum::Session)
+            .unwrap();
+        assert_eq!(
+            session.field("sport").unwrap().value(),
+            &Value::String(String::from("swimming"))
+        );
+    }
+
+    #[test]
+    fn match_record_kinds() {
+        use core::str::FromStr;
+        use profile::MesgNum;
+
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let mut counts = (0, 0, 0);
+        for record in from_bytes(data).unwrap() {
+            match record.kind() {
+                MesgNum::Record => counts.0 += 1,
+                MesgNum::Lap | MesgNum::Session => counts.1 += 1,
+                MesgNum::Value(_) => counts.2 += 1,
+                _ => {}
+            }
+        }
+        assert!(counts.0 > 0 && counts.1 > 0);
+        assert_eq!(counts.2, 0);
+
+        // the name of the kind is used for display while unknown kinds keep their number
+        assert_eq!(MesgNum::Session.to_string(), "session");
+        assert_eq!(MesgNum::from_str("session"), Ok(MesgNum::Session));
+        assert_eq!(MesgNum::from(18_u16), MesgNum::Session);
+        assert_eq!(MesgNum::from(0x1234_u16), MesgNum::Value(0x1234));
+        assert_eq!(MesgNum::Value(0x1234).as_u16(), 0x1234);
+        assert_eq!(MesgNum::Value(0x1234).to_string(), "4660");
+    }
+
+    #[test]
+    fn round_trip_profile_messages() {
+        let string = |s: &str| Value::String(String::from(s));
+        // newer SDK, dive and e-bike messages along with the units of each field
+        let messages = [
+            (
+                profile::MesgNum::Split,
+                vec![
+                    ("split_type", string("interval_active"), ""),
+                    ("total_elapsed_time", Value::Float64(95.5), "s"),
+                    ("total_distance", Value::Float64(402.25), "m"),
+                ],
+            ),
+            (
+                profile::MesgNum::ClimbPro,
+                vec![
+                    ("climb_pro_event", string("start"), ""),
+                    ("climb_number", Value::UInt16(3), ""),
+                ],
+            ),
+            (
+                profile::MesgNum::Jump,
+                vec![
+                    ("height", Value::Float32(1.5), "m"),
+                    ("rotations", Value::UInt8(2), ""),
+                ],
+            ),
+            (
+                profile::MesgNum::TimeInZone,
+                vec![
+                    ("reference_mesg", string("session"), ""),
+                    (
+                        "time_in_hr_zone",
+                        Value::Array(vec![Value::Float64(60.0), Value::Float64(125.5)]),
+                        "s",
+                    ),
+                ],
+            ),
+            (
+                profile::MesgNum::DiveSettings,
+                vec![
+                    ("model", string("zhl_16c"), ""),
+                    ("gf_low", Value::UInt8(30), "percent"),
+                    ("water_type", string("salt"), ""),
+                    ("po2_warn", Value::Float64(1.4), "percent"),
+                ],
+            ),
+            (
+                profile::MesgNum::DiveGas,
+                vec![
+                    ("helium_content", Value::UInt8(0), "percent"),
+                    ("oxygen_content", Value::UInt8(32), "percent"),
+                    ("status", string("enabled"), ""),
+                ],
+            ),
+            (
+                profile::MesgNum::DiveSummary,
+                vec![
+                    ("reference_mesg", string("session"), ""),
+                    ("avg_depth", Value::Float64(12.345), "m"),
+                    ("max_depth", Value::Float64(18.5), "m"),
+                    ("dive_number", Value::UInt32(42), ""),
+                ],
+            ),
+            (
+                profile::MesgNum::TankSummary,
+                vec![
+                    ("start_pressure", Value::Float64(205.5), "bar"),
+                    ("end_pressure", Value::Float64(62.25), "bar"),
+                    ("volume_used", Value::Float64(1580.4), "L"),
+                ],
+            ),
+            (
+                profile::MesgNum::Record,
+                vec![
+                    ("depth", Value::Float64(17.25), "m"),
+                    ("temperature", Value::SInt8(24), "C"),
+                    ("motor_power", Value::UInt16(180), "watts"),
+                    ("battery_soc", Value::Float64(72.5), "percent"),
+                    ("ebike_battery_level", Value::UInt8(73), "percent"),
+                    ("ebike_travel_range", Value::UInt16(64), "km"),
+                ],
+            ),
+            (
+                profile::MesgNum::Session,
+                vec![
+                    ("avg_lev_motor_power", Value::UInt16(150), "watts"),
+                    ("lev_battery_consumption", Value::Float64(27.5), "percent"),
+                ],
+            ),
+            (
+                profile::MesgNum::DeviceAuxBatteryInfo,
+                vec![
+                    ("battery_voltage", Value::Float64(36.5), "V"),
+                    ("battery_status", string("good"), ""),
+                ],
+            ),
+        ];
+        let records: Vec<FitDataRecord> = messages
+            .iter()
+            .map(|(kind, fields)| {
+                let mut record = FitDataRecord::new(*kind);
+                for (name, value, _) in fields {
+                    record.set_field_value(name, value.clone()).unwrap();
+                }
+                record
+            })
+            .collect();
+
+        // the messages are decoded with their names, scaled values and units
+        let data = to_bytes(&records).unwrap();
+        let decoded = from_bytes(&data).unwrap();
+        assert_eq!(decoded.len(), messages.len());
+        for (record, (kind, fields)) in decoded.iter().zip(&messages) {
+            assert_eq!(record.kind(), *kind);
+            for (name, value, units) in fields {
+                let field = record.field(name).unwrap();
+                assert_eq!(field.value(), value, "{} {}", kind, name);
+                assert_eq!(field.units(), *units, "{} {}", kind, name);
+            }
+        }
+
+        // the values are stored using the scale of the profile
+        let mut options = HashSet::new();
+        options.insert(de::DecodeOption::ReturnRawValues);
+        let raw = de::from_bytes_with_options(&data, &options).unwrap();
+        assert_eq!(raw[0].kind().to_string(), "split");
+        assert_eq!(
+            raw[0].field_value("total_elapsed_time"),
+            Some(&Value::UInt32(95_500))
+        );
+        assert_eq!(
+            raw[0].field_value("total_distance"),
+            Some(&Value::UInt32(40_225))
+        );
+    }
+
+    #[test]
+    fn parse_workout_subfields() {
+        // the duration and target fields are named and scaled using the step's types
+        let data = include_bytes!("../tests/fixtures/WorkoutCustomTargetValues.fit").to_vec();
+        let field = |record: &FitDataRecord, name: &str| {
+            record
+                .fields()
+                .iter()
+                .find(|f| f.name() == name)
+                .map(|f| f.value().clone())
+        };
+        let steps: Vec<FitDataRecord> = from_bytes(&data)
+            .unwrap()
+            .into_iter()
+            .filter(|r| r.kind() == profile::MesgNum::WorkoutStep)
+            .collect();
+        assert_eq!(
+            field(&steps[0], "duration_time"),
+            Some(Value::Float64(60.0))
+        );
+        assert_eq!(
+            field(&steps[0], "custom_target_heart_rate_low"),
+            Some(Value::SInt64(50))
+        );
+        assert_eq!(
+            field(&steps[1], "duration_distance"),
+            Some(Value::Float64(500.0))
+        );
+        assert_eq!(field(&steps[3], "duration_hr"), Some(Value::SInt64(225)));
+        assert_eq!(field(&steps[3], "duration_value"), None);
+
+        let mut options = HashSet::new();
+        options.insert(de::DecodeOption::UseGenericSubFieldName);
+        let steps: Vec<FitDataRecord> = de::from_bytes_with_options(&data, &options)
+            .unwrap()
+            .into_iter()
+            .filter(|r| r.kind() == profile::MesgNum::WorkoutStep)
+            .collect();
+        assert_eq!(
+            field(&steps[0], "duration_value"),
+            Some(Value::Float64(60.0))
+        );
+        assert_eq!(field(&steps[0], "duration_time"), None);
+    }
+
     #[test]
     fn parse_workout_individual_steps() {
         let data = include_bytes!("../tests/fixtures/WorkoutIndividualSteps.fit").to_vec();
@@ -455,13 +1354,10 @@ mod tests {
         data[12] = 0x00;
         data[13] = 0x00;
         match de::from_bytes(&data) {
-            Ok(_) => assert!(
-                false,
-                "This test should fail without the data CRC value being recomputed to include the header."
-            ),
+            Ok(_) => panic!("This test should fail without the data CRC value being recomputed to include the header."),
             Err(e) => match *e {
                 ErrorKind::InvalidCrc(..) => {}
-                _ => assert!(false, "Incorrect error returned {:?}", e),
+                _ => panic!("Incorrect error returned {:?}", e),
             },
         }
 
@@ -480,13 +1376,10 @@ mod tests {
         data[13] = 0xFF;
         let mut options = HashSet::new();
         match de::from_bytes_with_options(&data, &options) {
-            Ok(_) => assert!(
-                false,
-                "This test should fail without the SkipHeaderCrcValidation option."
-            ),
+            Ok(_) => panic!("This test should fail without the SkipHeaderCrcValidation option."),
             Err(e) => match *e {
                 ErrorKind::InvalidCrc(..) => {}
-                _ => assert!(false, "Incorrect error returned {:?}", e),
+                _ => panic!("Incorrect error returned {:?}", e),
             },
         }
 
@@ -505,13 +1398,10 @@ mod tests {
         data[leng - 1] = 0xFF;
         let mut options = HashSet::new();
         match de::from_bytes_with_options(&data, &options) {
-            Ok(_) => assert!(
-                false,
-                "This test should fail without the SkipDataCrcValidation option."
-            ),
+            Ok(_) => panic!("This test should fail without the SkipDataCrcValidation option."),
             Err(e) => match *e {
                 ErrorKind::InvalidCrc(..) => {}
-                _ => assert!(false, "Incorrect error returned {:?}", e),
+                _ => panic!("Incorrect error returned {:?}", e),
             },
         }
 
@@ -520,4 +1410,96 @@ mod tests {
         let fit_data = de::from_bytes_with_options(&data, &options).unwrap();
         assert_eq!(fit_data.len(), 355);
     }
+
+    #[test]
+    fn parse_truncated_file_with_recovery() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let data = &data[..data.len() - 50];
+        assert!(from_bytes(data).is_err());
+
+        let (fit_data, err) = de::from_bytes_with_recovery(data, &HashSet::new());
+        let err = err.unwrap();
+        assert_eq!(fit_data.len(), 20);
+        assert!(err.position() > 0 && err.position() < data.len());
+        match err.error() {
+            ErrorKind::UnexpectedEof(..) => {}
+            _ => panic!("Incorrect error returned {:?}", err),
+        }
+
+        // the records are also returned when using the option directly
+        let mut options = HashSet::new();
+        options.insert(de::DecodeOption::RecoverRecords);
+        let fit_data = de::from_bytes_with_options(data, &options).unwrap();
+        assert_eq!(fit_data.len(), 20);
+
+        // complete files don't return an error
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let (fit_data, err) = de::from_bytes_with_recovery(data, &HashSet::new());
+        assert_eq!(fit_data.len(), 22);
+        assert!(err.is_none());
+    }
+
+    #[test]
+    fn parse_damaged_header_with_recovery() {
+        let data = include_bytes!("../tests/fixtures/sample_mulitple_header.fit");
+        let expected = parse_all(data).unwrap();
+        let second = data[0] as usize
+            + u32::from_le_bytes([data[4], data[5], data[6], data[7]]) as usize
+            + 2;
+
+        // the records of the first file are returned when the second header is damaged
+        for header_size in [0, 1, 0xFF] {
+            let mut damaged = data.to_vec();
+            damaged[second] = header_size;
+            let (fit_data, err) = de::from_bytes_with_recovery(&damaged, &HashSet::new());
+            let err = err.unwrap();
+            assert_eq!(fit_data.len(), expected[0].records().len());
+            assert_eq!(err.position(), second);
+            match err.error().root() {
+                ErrorKind::ParseError(..) => {}
+                _ => panic!("Incorrect error returned {:?}", err),
+            }
+            let mut options = HashSet::new();
+            options.insert(de::DecodeOption::RecoverRecords);
+            let files = de::parse_all_with_options(&damaged, &options).unwrap();
+            assert_eq!(files.len(), 1);
+
+            // nothing is recovered when the first header is damaged
+            damaged[0] = header_size;
+            let (fit_data, err) = de::from_bytes_with_recovery(&damaged, &HashSet::new());
+            assert!(fit_data.is_empty());
+            assert_eq!(err.unwrap().position(), 0);
+        }
+    }
+
+    #[test]
+    fn parse_with_invalid_crcs_reported() {
+        let mut data = include_bytes!("../tests/fixtures/MonitoringFile.fit").to_vec();
+        let leng = data.len();
+        data[12] = 0xFF;
+        data[13] = 0xFF;
+        data[leng - 2] = 0xFF;
+        data[leng - 1] = 0xFF;
+        let (fit_data, mismatches) =
+            de::from_bytes_with_crc_mismatches(&data, &HashSet::new()).unwrap();
+        assert_eq!(fit_data.len(), 355);
+        assert_eq!(mismatches.len(), 2);
+        assert!(mismatches[0].is_header());
+        assert_eq!(mismatches[0].expected(), 0xFFFF);
+        assert!(!mismatches[1].is_header());
+        assert_eq!(mismatches[1].expected(), 0xFFFF);
+        assert_eq!(mismatches[1].position(), leng);
+        assert_ne!(mismatches[1].calculated(), 0xFFFF);
+
+        // skipping validation takes priority over reporting the mismatch
+        let options = [
+            de::DecodeOption::SkipDataCrcValidation,
+            de::DecodeOption::SkipHeaderCrcValidation,
+        ]
+        .iter()
+        .copied()
+        .collect();
+        let (_, mismatches) = de::from_bytes_with_crc_mismatches(&data, &options).unwrap();
+        assert!(mismatches.is_empty());
+    }
 }
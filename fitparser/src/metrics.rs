@@ -0,0 +1,239 @@
+//! Compute training metrics such as normalized power, intensity factor and training stress score
+//! from the `record` messages of an activity. The records are resampled to one value per second,
+//! the value of each record is used until the next record.
+use crate::analysis::Streams;
+use crate::FitDataRecord;
+
+/// Length of the rolling average used by normalized power in seconds
+const NORMALIZED_POWER_WINDOW: usize = 30;
+
+/// Steepest grade supported by the cost of running model, steeper grades are clamped
+const MAX_GRADE: f64 = 0.45;
+
+/// Energy cost of running on flat ground in J/kg/m
+const FLAT_RUNNING_COST: f64 = 3.6;
+
+/// Data stream of the `record` messages used by the window metrics
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    /// Power in watts
+    Power,
+    /// Heart rate in bpm
+    HeartRate,
+    /// Cadence in rpm
+    Cadence,
+    /// Speed in m/s
+    Speed,
+}
+
+/// Resample a stream to one value per second since the first record
+fn samples(records: &[FitDataRecord], metric: Metric) -> Vec<Option<f64>> {
+    let streams = Streams::from_records(records);
+    let values = match metric {
+        Metric::Power => streams.power(),
+        Metric::HeartRate => streams.heart_rate(),
+        Metric::Cadence => streams.cadence(),
+        Metric::Speed => streams.speed(),
+    };
+    let time = streams.time();
+    let end = match time.last() {
+        Some(end) => *end as usize,
+        None => return Vec::new(),
+    };
+    let mut index = 0;
+    (0..=end)
+        .map(|second| {
+            while index + 1 < time.len() && time[index + 1] <= second as f64 {
+                index += 1;
+            }
+            values[index]
+        })
+        .collect()
+}
+
+/// Average each window of samples ending at every sample, missing samples are left out of the
+/// average and windows without any values are None
+fn rolling_average(samples: &[Option<f64>], window: usize) -> Vec<Option<f64>> {
+    if window == 0 || samples.len() < window {
+        return Vec::new();
+    }
+    let (mut total, mut count) = (0.0, 0);
+    let mut averages = Vec::with_capacity(samples.len() - window + 1);
+    for (i, sample) in samples.iter().enumerate() {
+        if let Some(value) = sample {
+            total += value;
+            count += 1;
+        }
+        if i >= window {
+            if let Some(value) = samples[i - window] {
+                total -= value;
+                count -= 1;
+            }
+        }
+        if i + 1 >= window {
+            averages.push(if count > 0 {
+                Some(total / count as f64)
+            } else {
+                None
+            });
+        }
+    }
+    averages
+}
+
+/// Return the rolling average of a stream over a window in seconds, there is one value for
+/// every second from the end of the first window to the last record
+pub fn window_averages(records: &[FitDataRecord], metric: Metric, window: u32) -> Vec<Option<f64>> {
+    rolling_average(&samples(records, metric), window as usize)
+}
+
+/// Return the highest average of a stream over a window in seconds, e.g. the best 5 minute
+/// power. None is returned when the activity is shorter than the window.
+pub fn max_average(records: &[FitDataRecord], metric: Metric, window: u32) -> Option<f64> {
+    window_averages(records, metric, window)
+        .into_iter()
+        .flatten()
+        .max_by(f64::total_cmp)
+}
+
+/// Return the normalized power in watts, the fourth root of the mean of the 30 second rolling
+/// average power raised to the fourth power. Missing power values are treated as 0 W.
+pub fn normalized_power(records: &[FitDataRecord]) -> Option<f64> {
+    let samples = samples(records, Metric::Power);
+    if samples.iter().all(Option::is_none) {
+        return None;
+    }
+    let samples: Vec<Option<f64>> = samples.iter().map(|v| Some(v.unwrap_or(0.0))).collect();
+    let averages = rolling_average(&samples, NORMALIZED_POWER_WINDOW);
+    if averages.is_empty() {
+        return None;
+    }
+    let total: f64 = averages.iter().flatten().map(|v| v.powi(4)).sum();
+    Some((total / averages.len() as f64).powf(0.25))
+}
+
+/// Return the intensity factor, the normalized power divided by the functional threshold power
+/// in watts
+pub fn intensity_factor(records: &[FitDataRecord], ftp: f64) -> Option<f64> {
+    normalized_power(records).map(|np| np / ftp)
+}
+
+/// Return the training stress score using the functional threshold power in watts, an hour at
+/// threshold power scores 100
+pub fn training_stress_score(records: &[FitDataRecord], ftp: f64) -> Option<f64> {
+    let np = normalized_power(records)?;
+    let duration = samples(records, Metric::Power).len() as f64;
+    Some(duration * np * (np / ftp) / (ftp * 3600.0) * 100.0)
+}
+
+/// Energy cost of running at a grade relative to running on flat ground, using the model from
+/// Minetti et al. (2002)
+fn relative_running_cost(grade: f64) -> f64 {
+    let i = grade.clamp(-MAX_GRADE, MAX_GRADE);
+    let cost = 155.4 * i.powi(5) - 30.4 * i.powi(4) - 43.3 * i.powi(3)
+        + 46.3 * i.powi(2)
+        + 19.5 * i
+        + FLAT_RUNNING_COST;
+    cost / FLAT_RUNNING_COST
+}
+
+/// Return the grade adjusted pace in seconds per kilometer, the pace of running the same effort
+/// on flat ground. The distance between records is weighted by the energy cost of its grade,
+/// records without a distance or altitude are skipped.
+pub fn grade_adjusted_pace(records: &[FitDataRecord]) -> Option<f64> {
+    let streams = Streams::from_records(records);
+    let mut last: Option<(f64, f64, f64)> = None;
+    let (mut time, mut distance) = (0.0, 0.0);
+    let points = streams
+        .time()
+        .iter()
+        .zip(streams.distance())
+        .zip(streams.altitude());
+    for ((t, d), a) in points {
+        let (d, a) = match (d, a) {
+            (Some(d), Some(a)) => (*d, *a),
+            _ => continue,
+        };
+        if let Some((last_t, last_d, last_a)) = last {
+            let delta = d - last_d;
+            if delta > 0.0 {
+                distance += delta * relative_running_cost((a - last_a) / delta);
+            }
+            time += t - last_t;
+        }
+        last = Some((*t, d, a));
+    }
+    if distance > 0.0 {
+        Some(time / (distance / 1000.0))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::MesgNum;
+    use crate::{TimestampTz, Value};
+    use chrono::{Duration, TimeZone};
+
+    /// Create a record every second using the values returned for each second
+    fn records<F: Fn(i64) -> Vec<(&'static str, f64)>>(
+        count: i64,
+        values: F,
+    ) -> Vec<FitDataRecord> {
+        let start = TimestampTz.timestamp_opt(1_600_000_000, 0).unwrap();
+        (0..count)
+            .map(|s| {
+                let mut record = FitDataRecord::new(MesgNum::Record);
+                let timestamp = Value::Timestamp(start + Duration::seconds(s));
+                record.set_field_value("timestamp", timestamp).unwrap();
+                for (name, value) in values(s) {
+                    record.set_field_value(name, Value::Float64(value)).unwrap();
+                }
+                record
+            })
+            .collect()
+    }
+
+    #[test]
+    fn power_metrics() {
+        let steady = records(3600, |_| vec![("power", 200.0)]);
+        assert!((normalized_power(&steady).unwrap() - 200.0).abs() < 1e-9);
+        assert!((intensity_factor(&steady, 250.0).unwrap() - 0.8).abs() < 1e-9);
+        assert!((training_stress_score(&steady, 250.0).unwrap() - 64.0).abs() < 1e-9);
+        assert!((training_stress_score(&steady, 200.0).unwrap() - 100.0).abs() < 1e-9);
+
+        // alternating efforts are weighted towards the hard efforts
+        let intervals = records(600, |s| {
+            vec![("power", if (s / 60) % 2 == 0 { 100.0 } else { 300.0 })]
+        });
+        assert!(normalized_power(&intervals).unwrap() > 200.0);
+        assert_eq!(max_average(&intervals, Metric::Power, 60), Some(300.0));
+        assert_eq!(max_average(&intervals, Metric::Power, 3600), None);
+        let averages = window_averages(&intervals, Metric::Power, 120);
+        assert_eq!(averages.len(), 481);
+        assert_eq!(averages[0], Some(200.0));
+
+        let no_power = records(60, |_| vec![("heart_rate", 120.0)]);
+        assert_eq!(normalized_power(&no_power), None);
+        assert_eq!(max_average(&no_power, Metric::HeartRate, 10), Some(120.0));
+    }
+
+    #[test]
+    fn grade_adjusted() {
+        // 4 m/s is a pace of 250 s/km
+        let flat = records(100, |s| {
+            vec![("distance", s as f64 * 4.0), ("altitude", 100.0)]
+        });
+        assert!((grade_adjusted_pace(&flat).unwrap() - 250.0).abs() < 1e-6);
+        let uphill = records(100, |s| {
+            vec![
+                ("distance", s as f64 * 4.0),
+                ("altitude", 100.0 + s as f64 * 0.2),
+            ]
+        });
+        assert!(grade_adjusted_pace(&uphill).unwrap() < 250.0);
+        assert_eq!(grade_adjusted_pace(&records(10, |_| vec![])), None);
+    }
+}
@@ -0,0 +1,496 @@
+//! Load an alternate FIT profile at runtime so messages and fields that were added to the FIT SDK
+//! after the bundled profile can still be decoded. The profile is read from the "Types" and
+//! "Messages" sheets of the SDK's Profile.xlsx file exported as CSV.
+//!
+//! Fields defined by the custom profile take priority over the bundled profile. Subfields are
+//! resolved using the values of their reference fields, component expansion is not supported.
+use super::{apply_scale_and_offset, TimestampField};
+use crate::compat::prelude::*;
+use crate::compat::{HashMap, HashSet};
+use crate::de::DecodeOption;
+use crate::error::{ErrorKind, Result};
+use crate::{FitDataField, SharedStr, Value};
+use core::convert::TryInto;
+#[cfg(feature = "std")]
+use std::io::Read;
+
+/// Type defined in the "Types" sheet of the profile
+#[derive(Clone, Debug)]
+struct CustomType {
+    values: HashMap<i64, String>,
+}
+
+/// Field defined in the "Messages" sheet of the profile
+#[derive(Clone, Debug)]
+struct CustomField {
+    name: SharedStr,
+    field_type: String,
+    scale: f64,
+    offset: f64,
+    units: SharedStr,
+    subfields: Vec<CustomSubField>,
+}
+
+/// Alternate definition of a field used when a reference field has one of the listed values
+#[derive(Clone, Debug)]
+struct CustomSubField {
+    field: CustomField,
+    references: Vec<(String, String)>,
+}
+
+/// Message defined in the "Messages" sheet of the profile
+#[derive(Clone, Debug)]
+struct CustomMessage {
+    name: String,
+    fields: HashMap<u8, CustomField>,
+}
+
+/// FIT profile loaded at runtime, see the module documentation for the expected format.
+#[derive(Clone, Debug, Default)]
+pub struct CustomProfile {
+    types: HashMap<String, CustomType>,
+    messages: HashMap<u16, CustomMessage>,
+}
+
+impl CustomProfile {
+    /// Load the profile from the CSV exports of the "Types" and "Messages" sheets
+    #[cfg(feature = "std")]
+    pub fn from_reader<T: Read, M: Read>(types: &mut T, messages: &mut M) -> Result<Self> {
+        let mut types_csv = String::new();
+        types.read_to_string(&mut types_csv)?;
+        let mut messages_csv = String::new();
+        messages.read_to_string(&mut messages_csv)?;
+        Self::from_csv(&types_csv, &messages_csv)
+    }
+
+    /// Load the profile from the CSV contents of the "Types" and "Messages" sheets
+    pub fn from_csv(types: &str, messages: &str) -> Result<Self> {
+        let mut profile = CustomProfile::default();
+        profile.parse_types(types)?;
+        profile.parse_messages(messages)?;
+        Ok(profile)
+    }
+
+    /// Return the name of a message defined by the profile
+    pub fn message_name(&self, mesg_num: u16) -> Option<&str> {
+        self.messages.get(&mesg_num).map(|m| m.name.as_str())
+    }
+
+    /// Return the name of a field defined by the profile
+    pub fn field_name(&self, mesg_num: u16, def_number: u8) -> Option<&str> {
+        self.messages
+            .get(&mesg_num)
+            .and_then(|m| m.fields.get(&def_number))
+            .map(|f| f.name.as_str())
+    }
+
+    /// Parse the rows of the "Types" sheet, a type is defined by a row containing the type name
+    /// followed by one row per named value.
+    fn parse_types(&mut self, csv: &str) -> Result<()> {
+        let mut current: Option<String> = None;
+        for (line_num, row) in csv_rows(csv).enumerate().skip(1) {
+            if !cell(&row, 0).is_empty() {
+                let name = cell(&row, 0).to_string();
+                self.types.insert(
+                    name.clone(),
+                    CustomType {
+                        values: HashMap::new(),
+                    },
+                );
+                current = Some(name);
+            } else if !cell(&row, 2).is_empty() {
+                let value = parse_integer(cell(&row, 3)).ok_or_else(|| {
+                    profile_error(
+                        "Types",
+                        line_num,
+                        format!("invalid value {}", cell(&row, 3)),
+                    )
+                })?;
+                let name = current.as_ref().ok_or_else(|| {
+                    profile_error(
+                        "Types",
+                        line_num,
+                        String::from("value defined before a type"),
+                    )
+                })?;
+                if let Some(t) = self.types.get_mut(name) {
+                    t.values.insert(value, cell(&row, 2).to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse the rows of the "Messages" sheet, a message is defined by a row containing the
+    /// message name followed by its fields. Rows without a field number are subfields of the
+    /// field above them.
+    fn parse_messages(&mut self, csv: &str) -> Result<()> {
+        let mesg_nums: HashMap<&str, u16> = self
+            .types
+            .get("mesg_num")
+            .map(|t| {
+                t.values
+                    .iter()
+                    .map(|(k, v)| (v.as_str(), *k as u16))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut messages = HashMap::new();
+        let mut current: Option<u16> = None;
+        let mut last_field: Option<u8> = None;
+        for (line_num, row) in csv_rows(csv).enumerate().skip(1) {
+            if !cell(&row, 0).is_empty() {
+                let name = cell(&row, 0);
+                let mesg_num = *mesg_nums.get(name).ok_or_else(|| {
+                    profile_error(
+                        "Messages",
+                        line_num,
+                        format!("message {} is not defined in mesg_num", name),
+                    )
+                })?;
+                messages.insert(
+                    mesg_num,
+                    CustomMessage {
+                        name: name.to_string(),
+                        fields: HashMap::new(),
+                    },
+                );
+                current = Some(mesg_num);
+                last_field = None;
+            } else if let Ok(def_number) = cell(&row, 1).parse::<u8>() {
+                let mesg_num = current.ok_or_else(|| {
+                    profile_error(
+                        "Messages",
+                        line_num,
+                        String::from("field defined before a message"),
+                    )
+                })?;
+                if let Some(m) = messages.get_mut(&mesg_num) {
+                    m.fields.insert(def_number, field_from_row(&row));
+                }
+                last_field = Some(def_number);
+            } else if !cell(&row, 2).is_empty() {
+                let parent = current
+                    .and_then(|n| messages.get_mut(&n))
+                    .zip(last_field)
+                    .and_then(|(m, def_number)| m.fields.get_mut(&def_number))
+                    .ok_or_else(|| {
+                        profile_error(
+                            "Messages",
+                            line_num,
+                            String::from("subfield defined before a field"),
+                        )
+                    })?;
+                let references = cell(&row, 11)
+                    .split(',')
+                    .map(|n| n.trim().to_string())
+                    .zip(cell(&row, 12).split(',').map(|v| v.trim().to_string()))
+                    .collect();
+                parent.subfields.push(CustomSubField {
+                    field: field_from_row(&row),
+                    references,
+                });
+            }
+        }
+        self.messages = messages;
+        Ok(())
+    }
+
+    /// Decode the raw fields of a message defined by the profile, the decoded fields are removed
+    /// from the mapping so the remaining fields can be decoded using the bundled profile.
+    pub(crate) fn decode_fields(
+        &self,
+        mesg_num: u16,
+        data_map: &mut HashMap<u8, Value>,
+        options: &HashSet<DecodeOption>,
+    ) -> Result<Vec<FitDataField>> {
+        let message = match self.messages.get(&mesg_num) {
+            Some(message) => message,
+            None => return Ok(Vec::new()),
+        };
+        // resolve subfields before removing any values since they depend on other fields
+        let resolved: Vec<(u8, &SharedStr, &CustomField)> = message
+            .fields
+            .iter()
+            .filter(|(def_number, _)| data_map.contains_key(def_number))
+            .map(
+                |(def_number, field)| match self.resolve_subfield(message, field, data_map) {
+                    Some(subfield) if !options.contains(&DecodeOption::UseGenericSubFieldName) => {
+                        (*def_number, &subfield.name, subfield)
+                    }
+                    Some(subfield) => (*def_number, &field.name, subfield),
+                    None => (*def_number, &field.name, field),
+                },
+            )
+            .collect();
+
+        let mut fields = Vec::new();
+        for (def_number, name, field) in resolved {
+            if let Some(value) = data_map.remove(&def_number) {
+                fields.push(FitDataField::new(
+                    name.clone(),
+                    def_number,
+                    self.convert_value(field, value, options)?,
+                    field.units.clone(),
+                ));
+            }
+        }
+        Ok(fields)
+    }
+
+    /// Find the first subfield of a field whose reference field has the required value
+    fn resolve_subfield<'a>(
+        &self,
+        message: &CustomMessage,
+        field: &'a CustomField,
+        data_map: &HashMap<u8, Value>,
+    ) -> Option<&'a CustomField> {
+        field
+            .subfields
+            .iter()
+            .find(|subfield| {
+                subfield.references.iter().any(|(ref_name, ref_value)| {
+                    self.reference_matches(message, ref_name, ref_value, data_map)
+                        .unwrap_or(false)
+                })
+            })
+            .map(|subfield| &subfield.field)
+    }
+
+    /// Check if the reference field of a subfield has the named or numeric value
+    fn reference_matches(
+        &self,
+        message: &CustomMessage,
+        ref_name: &str,
+        ref_value: &str,
+        data_map: &HashMap<u8, Value>,
+    ) -> Option<bool> {
+        let (ref_number, ref_field) = message.fields.iter().find(|(_, f)| f.name == ref_name)?;
+        let expected = self
+            .types
+            .get(&ref_field.field_type)
+            .and_then(|t| t.values.iter().find(|(_, name)| *name == ref_value))
+            .map(|(value, _)| *value)
+            .or_else(|| parse_integer(ref_value))?;
+        let actual: i64 = data_map.get(ref_number)?.try_into().ok()?;
+        Some(actual == expected)
+    }
+
+    /// Convert a raw value using the field's type, scale and offset
+    fn convert_value(
+        &self,
+        field: &CustomField,
+        value: Value,
+        options: &HashSet<DecodeOption>,
+    ) -> Result<Value> {
+        if options.contains(&DecodeOption::ReturnRawValues) {
+            return Ok(value);
+        }
+        if let Value::Array(vals) = value {
+            let vals: Result<Vec<Value>> = vals
+                .into_iter()
+                .map(|v| apply_scale_and_offset(v, field.scale, field.offset))
+                .collect();
+            return vals.map(Value::Array);
+        }
+
+        match field.field_type.as_str() {
+            "date_time" => {
+                return Ok(Value::from(TimestampField::Utc(
+                    value.try_into().unwrap_or(0),
+                )))
+            }
+            "local_date_time" => {
+                return Ok(Value::from(TimestampField::Local(
+                    value.try_into().unwrap_or(0),
+                )))
+            }
+            _ => {}
+        }
+
+        // types with named values are treated as enums, matching the bundled profile
+        match self.types.get(&field.field_type) {
+            Some(t) if !t.values.is_empty() => {
+                let val: i64 = value.try_into()?;
+                match t.values.get(&val) {
+                    Some(name) if !options.contains(&DecodeOption::ReturnNumericEnumValues) => {
+                        Ok(Value::String(name.clone()))
+                    }
+                    _ => Ok(Value::SInt64(val)),
+                }
+            }
+            _ => apply_scale_and_offset(value, field.scale, field.offset),
+        }
+    }
+}
+
+/// Build a field from a row of the "Messages" sheet, scale and offset lists only apply to
+/// components which aren't supported
+fn field_from_row(row: &[String]) -> CustomField {
+    CustomField {
+        name: SharedStr::from(cell(row, 2).to_string()),
+        field_type: cell(row, 3).to_string(),
+        scale: cell(row, 6).parse().unwrap_or(1.0),
+        offset: cell(row, 7).parse().unwrap_or(0.0),
+        units: SharedStr::from(cell(row, 8).to_string()),
+        subfields: Vec::new(),
+    }
+}
+
+/// Build an error describing an invalid row of the profile
+fn profile_error(sheet: &str, line_num: usize, message: String) -> crate::Error {
+    ErrorKind::ValueError(format!(
+        "invalid {} profile row {}: {}",
+        sheet,
+        line_num + 1,
+        message
+    ))
+    .into()
+}
+
+/// Return a trimmed cell of a row, missing cells are returned as empty strings
+fn cell(row: &[String], index: usize) -> &str {
+    row.get(index).map_or("", |c| c.trim())
+}
+
+/// Parse a decimal or hexadecimal integer
+fn parse_integer(value: &str) -> Option<i64> {
+    match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(hex) => i64::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    }
+}
+
+/// Split CSV content into rows of cells, quoted cells may contain commas, newlines and
+/// escaped quotes.
+fn csv_rows(csv: &str) -> impl Iterator<Item = Vec<String>> + '_ {
+    let mut chars = csv.chars().peekable();
+    core::iter::from_fn(move || {
+        chars.peek()?;
+        let mut row = Vec::new();
+        let mut cell = String::new();
+        let mut quoted = false;
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if quoted && chars.peek() == Some(&'"') => {
+                    chars.next();
+                    cell.push('"');
+                }
+                '"' => quoted = !quoted,
+                ',' if !quoted => row.push(core::mem::take(&mut cell)),
+                '\r' if !quoted => {}
+                '\n' if !quoted => break,
+                c => cell.push(c),
+            }
+        }
+        row.push(cell);
+        Some(row)
+    })
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    const TYPES: &str = "Type Name,Base Type,Value Name,Value,Comment
+mesg_num,uint16,,,
+,,file_id,0,
+,,new_message,0xFF00,\"Newer, unreleased message\"
+new_mode,enum,,,
+,,off,0,
+,,on,1,
+";
+
+    const MESSAGES: &str =
+        "Message Name,Field Def #,Field Name,Field Type,Array,Components,Scale,Offset,Units,Bits,Accumulate,Ref Field Name,Ref Field Value
+new_message,,,,,,,,
+,253,timestamp,date_time,,,,,s
+,0,mode,new_mode,,,,,
+,1,distance,uint32,,,100,,m
+,,subfield,uint16,,,,,
+,3,duration,uint32,,,,,
+,,duration_time,uint32,,,1000,,s,,,mode,off
+,,duration_count,uint32,,,,,,,,\"mode,mode\",\"2,on\"
+";
+
+    #[test]
+    fn parse_csv_rows() {
+        let rows: Vec<Vec<String>> = csv_rows("a,\"b,c\",\"d\"\"e\"\r\n,,\n").collect();
+        assert_eq!(rows, vec![vec!["a", "b,c", "d\"e"], vec!["", "", ""]]);
+    }
+
+    #[test]
+    fn decode_custom_message() {
+        let profile = CustomProfile::from_csv(TYPES, MESSAGES).unwrap();
+        assert_eq!(profile.message_name(0xFF00), Some("new_message"));
+        assert_eq!(profile.field_name(0xFF00, 1), Some("distance"));
+        assert_eq!(profile.field_name(0xFF00, 2), None);
+
+        let mut data_map = HashMap::new();
+        data_map.insert(0, Value::Enum(1));
+        data_map.insert(1, Value::UInt32(12345));
+        data_map.insert(2, Value::UInt8(7));
+        let mut fields = profile
+            .decode_fields(0xFF00, &mut data_map, &HashSet::new())
+            .unwrap();
+        fields.sort_by_key(|f| f.number());
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].name(), "mode");
+        assert_eq!(fields[0].value(), &Value::String(String::from("on")));
+        assert_eq!(fields[1].name(), "distance");
+        assert_eq!(fields[1].value(), &Value::Float64(123.45));
+        assert_eq!(fields[1].units(), "m");
+        // fields without a definition are left for the bundled profile
+        assert_eq!(data_map.len(), 1);
+    }
+
+    #[test]
+    fn resolve_subfields() {
+        let profile = CustomProfile::from_csv(TYPES, MESSAGES).unwrap();
+        let decode = |mode: u8, options: &HashSet<DecodeOption>| {
+            let mut data_map = HashMap::new();
+            data_map.insert(0, Value::Enum(mode));
+            data_map.insert(3, Value::UInt32(1500));
+            let fields = profile
+                .decode_fields(0xFF00, &mut data_map, options)
+                .unwrap();
+            let field = fields.into_iter().find(|f| f.number() == 3).unwrap();
+            (field.name().to_string(), field.value().clone())
+        };
+
+        let options = HashSet::new();
+        assert_eq!(
+            decode(0, &options),
+            (String::from("duration_time"), Value::Float64(1.5))
+        );
+        assert_eq!(
+            decode(1, &options),
+            (String::from("duration_count"), Value::UInt32(1500))
+        );
+        assert_eq!(
+            decode(2, &options),
+            (String::from("duration_count"), Value::UInt32(1500))
+        );
+        assert_eq!(
+            decode(3, &options),
+            (String::from("duration"), Value::UInt32(1500))
+        );
+
+        let mut options = HashSet::new();
+        options.insert(DecodeOption::UseGenericSubFieldName);
+        assert_eq!(
+            decode(0, &options),
+            (String::from("duration"), Value::Float64(1.5))
+        );
+    }
+
+    #[test]
+    fn undefined_message_number() {
+        let messages = "Message Name,Field Def #\nmissing_message,,\n";
+        assert!(CustomProfile::from_csv(TYPES, messages).is_err());
+    }
+}
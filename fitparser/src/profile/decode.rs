@@ -1,12 +1,18 @@
 //! Auto generated profile messages from FIT SDK Release: 21.105.00
 #![allow(unused_variables)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::if_same_then_else)]
 use super::field_types::*;
-use super::{calculate_cumulative_value, data_field_with_info, extract_component, unknown_field};
+use super::{
+    accumulate_component, calculate_cumulative_value, data_field_with_info, extract_component,
+    unknown_field,
+};
+use crate::compat::prelude::*;
+use crate::compat::{HashMap, HashSet, VecDeque};
 use crate::de::DecodeOption;
 use crate::error::Result;
 use crate::{FitDataField, Value};
-use std::collections::{HashMap, HashSet, VecDeque};
-use std::convert::TryInto;
+use core::convert::TryInto;
 /// FIT SDK version used to generate profile decoder
 pub const VERSION: &str = "21.105.00";
 /// Must be first message in file.
@@ -51,8 +57,7 @@ fn file_id_message(
                 if Manufacturer::FaveroElectronics.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(file_id_message_favero_product_field(
@@ -69,8 +74,7 @@ fn file_id_message(
                 } else if Manufacturer::Garmin.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(file_id_message_garmin_product_field(
@@ -87,8 +91,7 @@ fn file_id_message(
                 } else if Manufacturer::Dynastream.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(file_id_message_garmin_product_field(
@@ -105,8 +108,7 @@ fn file_id_message(
                 } else if Manufacturer::DynastreamOem.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(file_id_message_garmin_product_field(
@@ -123,8 +125,7 @@ fn file_id_message(
                 } else if Manufacturer::Tacx.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(file_id_message_garmin_product_field(
@@ -1046,8 +1047,7 @@ fn slave_device_message(
                 if Manufacturer::FaveroElectronics.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(slave_device_message_favero_product_field(
@@ -1064,8 +1064,7 @@ fn slave_device_message(
                 } else if Manufacturer::Garmin.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(slave_device_message_garmin_product_field(
@@ -1082,8 +1081,7 @@ fn slave_device_message(
                 } else if Manufacturer::Dynastream.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(slave_device_message_garmin_product_field(
@@ -1100,8 +1098,7 @@ fn slave_device_message(
                 } else if Manufacturer::DynastreamOem.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(slave_device_message_garmin_product_field(
@@ -1118,8 +1115,7 @@ fn slave_device_message(
                 } else if Manufacturer::Tacx.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(slave_device_message_garmin_product_field(
@@ -1770,8 +1766,7 @@ fn mesg_capabilities_message(
                 if MesgCount::NumPerFile.as_i64()
                     == data_map
                         .get(&2)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(mesg_capabilities_message_num_per_file_field(
@@ -1788,8 +1783,7 @@ fn mesg_capabilities_message(
                 } else if MesgCount::MaxPerFile.as_i64()
                     == data_map
                         .get(&2)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(mesg_capabilities_message_max_per_file_field(
@@ -1806,8 +1800,7 @@ fn mesg_capabilities_message(
                 } else if MesgCount::MaxPerFileType.as_i64()
                     == data_map
                         .get(&2)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(mesg_capabilities_message_max_per_file_type_field(
@@ -6935,8 +6928,7 @@ fn watchface_settings_message(
                 if WatchfaceMode::Digital.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(watchface_settings_message_digital_layout_field(
@@ -6953,8 +6945,7 @@ fn watchface_settings_message(
                 } else if WatchfaceMode::Analog.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(watchface_settings_message_analog_layout_field(
@@ -9329,8 +9320,7 @@ fn dive_settings_message(
                 if SourceType::Antplus.as_i64()
                     == data_map
                         .get(&19)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(dive_settings_message_heart_rate_antplus_device_type_field(
@@ -9347,8 +9337,7 @@ fn dive_settings_message(
                 } else if SourceType::Local.as_i64()
                     == data_map
                         .get(&19)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(dive_settings_message_heart_rate_local_device_type_field(
@@ -12945,8 +12934,7 @@ fn session_message(
                 if Sport::Running.as_i64()
                     == data_map
                         .get(&5)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(session_message_total_strides_field(
@@ -12963,8 +12951,7 @@ fn session_message(
                 } else if Sport::Walking.as_i64()
                     == data_map
                         .get(&5)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(session_message_total_strides_field(
@@ -12981,8 +12968,7 @@ fn session_message(
                 } else if Sport::Cycling.as_i64()
                     == data_map
                         .get(&5)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(session_message_total_strokes_field(
@@ -12999,8 +12985,7 @@ fn session_message(
                 } else if Sport::Swimming.as_i64()
                     == data_map
                         .get(&5)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(session_message_total_strokes_field(
@@ -13017,8 +13002,7 @@ fn session_message(
                 } else if Sport::Rowing.as_i64()
                     == data_map
                         .get(&5)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(session_message_total_strokes_field(
@@ -13035,8 +13019,7 @@ fn session_message(
                 } else if Sport::StandUpPaddleboarding.as_i64()
                     == data_map
                         .get(&5)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(session_message_total_strokes_field(
@@ -13105,7 +13088,7 @@ fn session_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_avg_speed) = extract_component(&input, 0usize, 16);
                 data_map.insert(124, enhanced_avg_speed.clone());
                 // total_distance / total_timer_time
@@ -13135,7 +13118,7 @@ fn session_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_max_speed) = extract_component(&input, 0usize, 16);
                 data_map.insert(125, enhanced_max_speed.clone());
                 fields.push(session_message_enhanced_max_speed_field(
@@ -13182,8 +13165,7 @@ fn session_message(
                 if Sport::Running.as_i64()
                     == data_map
                         .get(&5)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(session_message_avg_running_cadence_field(
@@ -13215,8 +13197,7 @@ fn session_message(
                 if Sport::Running.as_i64()
                     == data_map
                         .get(&5)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(session_message_max_running_cadence_field(
@@ -13603,7 +13584,7 @@ fn session_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_avg_altitude) =
                     extract_component(&input, 0usize, 16);
                 data_map.insert(126, enhanced_avg_altitude.clone());
@@ -13633,7 +13614,7 @@ fn session_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_max_altitude) =
                     extract_component(&input, 0usize, 16);
                 data_map.insert(128, enhanced_max_altitude.clone());
@@ -13923,7 +13904,7 @@ fn session_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_min_altitude) =
                     extract_component(&input, 0usize, 16);
                 data_map.insert(127, enhanced_min_altitude.clone());
@@ -14724,7 +14705,7 @@ fn session_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_avg_respiration_rate) =
                     extract_component(&input, 0usize, 8);
                 data_map.insert(169, enhanced_avg_respiration_rate.clone());
@@ -14754,7 +14735,7 @@ fn session_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_max_respiration_rate) =
                     extract_component(&input, 0usize, 8);
                 data_map.insert(170, enhanced_max_respiration_rate.clone());
@@ -14784,7 +14765,7 @@ fn session_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_min_respiration_rate) =
                     extract_component(&input, 0usize, 8);
                 data_map.insert(180, enhanced_min_respiration_rate.clone());
@@ -19308,8 +19289,7 @@ fn lap_message(
                 if Sport::Running.as_i64()
                     == data_map
                         .get(&25)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(lap_message_total_strides_field(
@@ -19326,8 +19306,7 @@ fn lap_message(
                 } else if Sport::Walking.as_i64()
                     == data_map
                         .get(&25)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(lap_message_total_strides_field(
@@ -19344,8 +19323,7 @@ fn lap_message(
                 } else if Sport::Cycling.as_i64()
                     == data_map
                         .get(&25)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(lap_message_total_strokes_field(
@@ -19362,8 +19340,7 @@ fn lap_message(
                 } else if Sport::Swimming.as_i64()
                     == data_map
                         .get(&25)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(lap_message_total_strokes_field(
@@ -19380,8 +19357,7 @@ fn lap_message(
                 } else if Sport::Rowing.as_i64()
                     == data_map
                         .get(&25)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(lap_message_total_strokes_field(
@@ -19398,8 +19374,7 @@ fn lap_message(
                 } else if Sport::StandUpPaddleboarding.as_i64()
                     == data_map
                         .get(&25)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(lap_message_total_strokes_field(
@@ -19468,7 +19443,7 @@ fn lap_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_avg_speed) = extract_component(&input, 0usize, 16);
                 data_map.insert(110, enhanced_avg_speed.clone());
                 fields.push(lap_message_enhanced_avg_speed_field(
@@ -19497,7 +19472,7 @@ fn lap_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_max_speed) = extract_component(&input, 0usize, 16);
                 data_map.insert(111, enhanced_max_speed.clone());
                 fields.push(lap_message_enhanced_max_speed_field(
@@ -19543,8 +19518,7 @@ fn lap_message(
                 if Sport::Running.as_i64()
                     == data_map
                         .get(&25)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(lap_message_avg_running_cadence_field(
@@ -19576,8 +19550,7 @@ fn lap_message(
                 if Sport::Running.as_i64()
                     == data_map
                         .get(&25)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(lap_message_max_running_cadence_field(
@@ -19843,7 +19816,7 @@ fn lap_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_avg_altitude) =
                     extract_component(&input, 0usize, 16);
                 data_map.insert(112, enhanced_avg_altitude.clone());
@@ -19873,7 +19846,7 @@ fn lap_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_max_altitude) =
                     extract_component(&input, 0usize, 16);
                 data_map.insert(114, enhanced_max_altitude.clone());
@@ -20137,7 +20110,7 @@ fn lap_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_min_altitude) =
                     extract_component(&input, 0usize, 16);
                 data_map.insert(113, enhanced_min_altitude.clone());
@@ -20871,7 +20844,7 @@ fn lap_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_avg_respiration_rate) =
                     extract_component(&input, 0usize, 8);
                 data_map.insert(136, enhanced_avg_respiration_rate.clone());
@@ -20901,7 +20874,7 @@ fn lap_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_max_respiration_rate) =
                     extract_component(&input, 0usize, 8);
                 data_map.insert(137, enhanced_max_respiration_rate.clone());
@@ -24799,7 +24772,7 @@ fn length_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_avg_respiration_rate) =
                     extract_component(&input, 0usize, 8);
                 data_map.insert(22, enhanced_avg_respiration_rate.clone());
@@ -24829,7 +24802,7 @@ fn length_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_max_respiration_rate) =
                     extract_component(&input, 0usize, 8);
                 data_map.insert(23, enhanced_max_respiration_rate.clone());
@@ -25525,7 +25498,7 @@ fn record_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_altitude) = extract_component(&input, 0usize, 16);
                 data_map.insert(78, enhanced_altitude.clone());
                 fields.push(record_message_enhanced_altitude_field(
@@ -25593,7 +25566,7 @@ fn record_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_speed) = extract_component(&input, 0usize, 16);
                 data_map.insert(73, enhanced_speed.clone());
                 fields.push(record_message_enhanced_speed_field(
@@ -25635,9 +25608,11 @@ fn record_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), speed) = extract_component(&input, 0usize, 12);
                 let ((input, offset), distance) = extract_component(input, offset, 12);
+                let distance =
+                    accumulate_component(accumlators, mesg_num.as_u16(), 5, distance, 12)?;
                 data_map.insert(6, speed.clone());
                 if options.contains(&DecodeOption::KeepCompositeFields) {
                     fields.push(record_message_speed_field(
@@ -25652,7 +25627,7 @@ fn record_message(
                         speed.clone(),
                     )?);
                 }
-                let input = speed.to_ne_bytes();
+                let input = speed.to_le_bytes();
                 let ((input, offset), enhanced_speed) = extract_component(&input, 0usize, 16);
                 data_map.insert(73, enhanced_speed.clone());
                 fields.push(record_message_enhanced_speed_field(
@@ -25672,7 +25647,7 @@ fn record_message(
                     accumlators,
                     options,
                     data_map,
-                    true,
+                    false,
                     16.000000,
                     0.000000,
                     "m",
@@ -25766,22 +25741,24 @@ fn record_message(
                         accumlators,
                         options,
                         data_map,
-                        true,
+                        false,
                         1.000000,
                         0.000000,
                         "cycles",
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), total_cycles) = extract_component(&input, 0usize, 8);
+                let total_cycles =
+                    accumulate_component(accumlators, mesg_num.as_u16(), 19, total_cycles, 8)?;
                 data_map.insert(19, total_cycles.clone());
                 fields.push(record_message_total_cycles_field(
                     mesg_num,
                     accumlators,
                     options,
                     data_map,
-                    true,
+                    false,
                     1.000000,
                     0.000000,
                     "cycles",
@@ -25808,22 +25785,29 @@ fn record_message(
                         accumlators,
                         options,
                         data_map,
-                        true,
+                        false,
                         1.000000,
                         0.000000,
                         "watts",
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), accumulated_power) = extract_component(&input, 0usize, 16);
+                let accumulated_power = accumulate_component(
+                    accumlators,
+                    mesg_num.as_u16(),
+                    29,
+                    accumulated_power,
+                    16,
+                )?;
                 data_map.insert(29, accumulated_power.clone());
                 fields.push(record_message_accumulated_power_field(
                     mesg_num,
                     accumlators,
                     options,
                     data_map,
-                    true,
+                    false,
                     1.000000,
                     0.000000,
                     "watts",
@@ -26486,7 +26470,7 @@ fn record_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_respiration_rate) =
                     extract_component(&input, 0usize, 8);
                 data_map.insert(108, enhanced_respiration_rate.clone());
@@ -28977,14 +28961,13 @@ fn event_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), data) = extract_component(&input, 0usize, 16);
                 data_map.insert(3, data.clone());
                 if Event::Timer.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_timer_trigger_field(
@@ -29001,8 +28984,7 @@ fn event_message(
                 } else if Event::CoursePoint.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_course_point_index_field(
@@ -29019,8 +29001,7 @@ fn event_message(
                 } else if Event::Battery.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_battery_level_field(
@@ -29037,8 +29018,7 @@ fn event_message(
                 } else if Event::VirtualPartnerPace.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_virtual_partner_speed_field(
@@ -29055,8 +29035,7 @@ fn event_message(
                 } else if Event::HrHighAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_hr_high_alert_field(
@@ -29073,8 +29052,7 @@ fn event_message(
                 } else if Event::HrLowAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_hr_low_alert_field(
@@ -29091,8 +29069,7 @@ fn event_message(
                 } else if Event::SpeedHighAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_speed_high_alert_field(
@@ -29109,8 +29086,7 @@ fn event_message(
                 } else if Event::SpeedLowAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_speed_low_alert_field(
@@ -29127,8 +29103,7 @@ fn event_message(
                 } else if Event::CadHighAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_cad_high_alert_field(
@@ -29145,8 +29120,7 @@ fn event_message(
                 } else if Event::CadLowAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_cad_low_alert_field(
@@ -29163,8 +29137,7 @@ fn event_message(
                 } else if Event::PowerHighAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_power_high_alert_field(
@@ -29181,8 +29154,7 @@ fn event_message(
                 } else if Event::PowerLowAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_power_low_alert_field(
@@ -29199,8 +29171,7 @@ fn event_message(
                 } else if Event::TimeDurationAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_time_duration_alert_field(
@@ -29217,8 +29188,7 @@ fn event_message(
                 } else if Event::DistanceDurationAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_distance_duration_alert_field(
@@ -29235,8 +29205,7 @@ fn event_message(
                 } else if Event::CalorieDurationAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_calorie_duration_alert_field(
@@ -29253,8 +29222,7 @@ fn event_message(
                 } else if Event::FitnessEquipment.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_fitness_equipment_state_field(
@@ -29271,11 +29239,28 @@ fn event_message(
                 } else if Event::SportPoint.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
-                    fields.push(event_message_sport_point_field(
+                    if options.contains(&DecodeOption::KeepCompositeFields) {
+                        fields.push(event_message_sport_point_field(
+                            mesg_num,
+                            accumlators,
+                            options,
+                            data_map,
+                            false,
+                            1.000000,
+                            0.000000,
+                            "",
+                            data.clone(),
+                        )?);
+                    }
+                    let input = data.to_le_bytes();
+                    let ((input, offset), score) = extract_component(&input, 0usize, 16);
+                    let ((input, offset), opponent_score) = extract_component(input, offset, 16);
+                    data_map.insert(7, score.clone());
+                    // Do not populate directly. Autogenerated by decoder for sport_point subfield components
+                    fields.push(event_message_score_field(
                         mesg_num,
                         accumlators,
                         options,
@@ -29284,16 +29269,48 @@ fn event_message(
                         1.000000,
                         0.000000,
                         "",
-                        data,
+                        score,
+                    )?);
+                    data_map.insert(8, opponent_score.clone());
+                    // Do not populate directly. Autogenerated by decoder for sport_point subfield components
+                    fields.push(event_message_opponent_score_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        1.000000,
+                        0.000000,
+                        "",
+                        opponent_score,
                     )?);
                 } else if Event::FrontGearChange.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
-                    fields.push(event_message_gear_change_data_field(
+                    if options.contains(&DecodeOption::KeepCompositeFields) {
+                        fields.push(event_message_gear_change_data_field(
+                            mesg_num,
+                            accumlators,
+                            options,
+                            data_map,
+                            false,
+                            1.000000,
+                            0.000000,
+                            "",
+                            data.clone(),
+                        )?);
+                    }
+                    let input = data.to_le_bytes();
+                    let ((input, offset), rear_gear_num) = extract_component(&input, 0usize, 8);
+                    let ((input, offset), rear_gear) = extract_component(input, offset, 8);
+                    let ((input, offset), front_gear_num) = extract_component(input, offset, 8);
+                    let ((input, offset), front_gear) = extract_component(input, offset, 8);
+                    data_map.insert(11, rear_gear_num.clone());
+                    // Do not populate directly. Autogenerated by decoder for gear_change subfield components. Rear gear number. 1 is innermost.
+                    fields.push(event_message_rear_gear_num_field(
                         mesg_num,
                         accumlators,
                         options,
@@ -29302,16 +29319,74 @@ fn event_message(
                         1.000000,
                         0.000000,
                         "",
-                        data,
+                        rear_gear_num,
+                    )?);
+                    data_map.insert(12, rear_gear.clone());
+                    // Do not populate directly. Autogenerated by decoder for gear_change subfield components. Number of rear teeth.
+                    fields.push(event_message_rear_gear_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        1.000000,
+                        0.000000,
+                        "",
+                        rear_gear,
+                    )?);
+                    data_map.insert(9, front_gear_num.clone());
+                    // Do not populate directly. Autogenerated by decoder for gear_change subfield components. Front gear number. 1 is innermost.
+                    fields.push(event_message_front_gear_num_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        1.000000,
+                        0.000000,
+                        "",
+                        front_gear_num,
+                    )?);
+                    data_map.insert(10, front_gear.clone());
+                    // Do not populate directly. Autogenerated by decoder for gear_change subfield components. Number of front teeth.
+                    fields.push(event_message_front_gear_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        1.000000,
+                        0.000000,
+                        "",
+                        front_gear,
                     )?);
                 } else if Event::RearGearChange.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
-                    fields.push(event_message_gear_change_data_field(
+                    if options.contains(&DecodeOption::KeepCompositeFields) {
+                        fields.push(event_message_gear_change_data_field(
+                            mesg_num,
+                            accumlators,
+                            options,
+                            data_map,
+                            false,
+                            1.000000,
+                            0.000000,
+                            "",
+                            data.clone(),
+                        )?);
+                    }
+                    let input = data.to_le_bytes();
+                    let ((input, offset), rear_gear_num) = extract_component(&input, 0usize, 8);
+                    let ((input, offset), rear_gear) = extract_component(input, offset, 8);
+                    let ((input, offset), front_gear_num) = extract_component(input, offset, 8);
+                    let ((input, offset), front_gear) = extract_component(input, offset, 8);
+                    data_map.insert(11, rear_gear_num.clone());
+                    // Do not populate directly. Autogenerated by decoder for gear_change subfield components. Rear gear number. 1 is innermost.
+                    fields.push(event_message_rear_gear_num_field(
                         mesg_num,
                         accumlators,
                         options,
@@ -29320,13 +29395,51 @@ fn event_message(
                         1.000000,
                         0.000000,
                         "",
-                        data,
+                        rear_gear_num,
+                    )?);
+                    data_map.insert(12, rear_gear.clone());
+                    // Do not populate directly. Autogenerated by decoder for gear_change subfield components. Number of rear teeth.
+                    fields.push(event_message_rear_gear_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        1.000000,
+                        0.000000,
+                        "",
+                        rear_gear,
+                    )?);
+                    data_map.insert(9, front_gear_num.clone());
+                    // Do not populate directly. Autogenerated by decoder for gear_change subfield components. Front gear number. 1 is innermost.
+                    fields.push(event_message_front_gear_num_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        1.000000,
+                        0.000000,
+                        "",
+                        front_gear_num,
+                    )?);
+                    data_map.insert(10, front_gear.clone());
+                    // Do not populate directly. Autogenerated by decoder for gear_change subfield components. Number of front teeth.
+                    fields.push(event_message_front_gear_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        1.000000,
+                        0.000000,
+                        "",
+                        front_gear,
                     )?);
                 } else if Event::RiderPositionChange.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_rider_position_field(
@@ -29343,8 +29456,7 @@ fn event_message(
                 } else if Event::CommTimeout.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_comm_timeout_field(
@@ -29361,8 +29473,7 @@ fn event_message(
                 } else if Event::DiveAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_dive_alert_field(
@@ -29379,11 +29490,33 @@ fn event_message(
                 } else if Event::RadarThreatAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
-                    fields.push(event_message_radar_threat_alert_field(
+                    if options.contains(&DecodeOption::KeepCompositeFields) {
+                        fields.push(event_message_radar_threat_alert_field(
+                            mesg_num,
+                            accumlators,
+                            options,
+                            data_map,
+                            false,
+                            1.000000,
+                            0.000000,
+                            ",,m/s,m/s",
+                            data.clone(),
+                        )?);
+                    }
+                    let input = data.to_le_bytes();
+                    let ((input, offset), radar_threat_level_max) =
+                        extract_component(&input, 0usize, 8);
+                    let ((input, offset), radar_threat_count) = extract_component(input, offset, 8);
+                    let ((input, offset), radar_threat_avg_approach_speed) =
+                        extract_component(input, offset, 8);
+                    let ((input, offset), radar_threat_max_approach_speed) =
+                        extract_component(input, offset, 8);
+                    data_map.insert(21, radar_threat_level_max.clone());
+                    // Do not populate directly. Autogenerated by decoder for threat_alert subfield components.
+                    fields.push(event_message_radar_threat_level_max_field(
                         mesg_num,
                         accumlators,
                         options,
@@ -29392,7 +29525,46 @@ fn event_message(
                         1.000000,
                         0.000000,
                         "",
-                        data,
+                        radar_threat_level_max,
+                    )?);
+                    data_map.insert(22, radar_threat_count.clone());
+                    // Do not populate directly. Autogenerated by decoder for threat_alert subfield components.
+                    fields.push(event_message_radar_threat_count_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        1.000000,
+                        0.000000,
+                        "",
+                        radar_threat_count,
+                    )?);
+                    data_map.insert(23, radar_threat_avg_approach_speed.clone());
+                    // Do not populate directly. Autogenerated by decoder for radar_threat_alert subfield components
+                    fields.push(event_message_radar_threat_avg_approach_speed_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        10.000000,
+                        0.000000,
+                        "m/s",
+                        radar_threat_avg_approach_speed,
+                    )?);
+                    data_map.insert(24, radar_threat_max_approach_speed.clone());
+                    // Do not populate directly. Autogenerated by decoder for radar_threat_alert subfield components
+                    fields.push(event_message_radar_threat_max_approach_speed_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        10.000000,
+                        0.000000,
+                        "m/s",
+                        radar_threat_max_approach_speed,
                     )?);
                 } else {
                     fields.push(event_message_data_field(
@@ -29412,8 +29584,7 @@ fn event_message(
                 if Event::Timer.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_timer_trigger_field(
@@ -29430,8 +29601,7 @@ fn event_message(
                 } else if Event::CoursePoint.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_course_point_index_field(
@@ -29448,8 +29618,7 @@ fn event_message(
                 } else if Event::Battery.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_battery_level_field(
@@ -29466,8 +29635,7 @@ fn event_message(
                 } else if Event::VirtualPartnerPace.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_virtual_partner_speed_field(
@@ -29484,8 +29652,7 @@ fn event_message(
                 } else if Event::HrHighAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_hr_high_alert_field(
@@ -29502,8 +29669,7 @@ fn event_message(
                 } else if Event::HrLowAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_hr_low_alert_field(
@@ -29520,8 +29686,7 @@ fn event_message(
                 } else if Event::SpeedHighAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_speed_high_alert_field(
@@ -29538,8 +29703,7 @@ fn event_message(
                 } else if Event::SpeedLowAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_speed_low_alert_field(
@@ -29556,8 +29720,7 @@ fn event_message(
                 } else if Event::CadHighAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_cad_high_alert_field(
@@ -29574,8 +29737,7 @@ fn event_message(
                 } else if Event::CadLowAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_cad_low_alert_field(
@@ -29592,8 +29754,7 @@ fn event_message(
                 } else if Event::PowerHighAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_power_high_alert_field(
@@ -29610,8 +29771,7 @@ fn event_message(
                 } else if Event::PowerLowAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_power_low_alert_field(
@@ -29628,8 +29788,7 @@ fn event_message(
                 } else if Event::TimeDurationAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_time_duration_alert_field(
@@ -29646,8 +29805,7 @@ fn event_message(
                 } else if Event::DistanceDurationAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_distance_duration_alert_field(
@@ -29664,8 +29822,7 @@ fn event_message(
                 } else if Event::CalorieDurationAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_calorie_duration_alert_field(
@@ -29682,8 +29839,7 @@ fn event_message(
                 } else if Event::FitnessEquipment.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_fitness_equipment_state_field(
@@ -29700,11 +29856,28 @@ fn event_message(
                 } else if Event::SportPoint.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
-                    fields.push(event_message_sport_point_field(
+                    if options.contains(&DecodeOption::KeepCompositeFields) {
+                        fields.push(event_message_sport_point_field(
+                            mesg_num,
+                            accumlators,
+                            options,
+                            data_map,
+                            false,
+                            1.000000,
+                            0.000000,
+                            "",
+                            value.clone(),
+                        )?);
+                    }
+                    let input = value.to_le_bytes();
+                    let ((input, offset), score) = extract_component(&input, 0usize, 16);
+                    let ((input, offset), opponent_score) = extract_component(input, offset, 16);
+                    data_map.insert(7, score.clone());
+                    // Do not populate directly. Autogenerated by decoder for sport_point subfield components
+                    fields.push(event_message_score_field(
                         mesg_num,
                         accumlators,
                         options,
@@ -29713,16 +29886,48 @@ fn event_message(
                         1.000000,
                         0.000000,
                         "",
-                        value,
+                        score,
+                    )?);
+                    data_map.insert(8, opponent_score.clone());
+                    // Do not populate directly. Autogenerated by decoder for sport_point subfield components
+                    fields.push(event_message_opponent_score_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        1.000000,
+                        0.000000,
+                        "",
+                        opponent_score,
                     )?);
                 } else if Event::FrontGearChange.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
-                    fields.push(event_message_gear_change_data_field(
+                    if options.contains(&DecodeOption::KeepCompositeFields) {
+                        fields.push(event_message_gear_change_data_field(
+                            mesg_num,
+                            accumlators,
+                            options,
+                            data_map,
+                            false,
+                            1.000000,
+                            0.000000,
+                            "",
+                            value.clone(),
+                        )?);
+                    }
+                    let input = value.to_le_bytes();
+                    let ((input, offset), rear_gear_num) = extract_component(&input, 0usize, 8);
+                    let ((input, offset), rear_gear) = extract_component(input, offset, 8);
+                    let ((input, offset), front_gear_num) = extract_component(input, offset, 8);
+                    let ((input, offset), front_gear) = extract_component(input, offset, 8);
+                    data_map.insert(11, rear_gear_num.clone());
+                    // Do not populate directly. Autogenerated by decoder for gear_change subfield components. Rear gear number. 1 is innermost.
+                    fields.push(event_message_rear_gear_num_field(
                         mesg_num,
                         accumlators,
                         options,
@@ -29731,16 +29936,74 @@ fn event_message(
                         1.000000,
                         0.000000,
                         "",
-                        value,
+                        rear_gear_num,
+                    )?);
+                    data_map.insert(12, rear_gear.clone());
+                    // Do not populate directly. Autogenerated by decoder for gear_change subfield components. Number of rear teeth.
+                    fields.push(event_message_rear_gear_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        1.000000,
+                        0.000000,
+                        "",
+                        rear_gear,
+                    )?);
+                    data_map.insert(9, front_gear_num.clone());
+                    // Do not populate directly. Autogenerated by decoder for gear_change subfield components. Front gear number. 1 is innermost.
+                    fields.push(event_message_front_gear_num_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        1.000000,
+                        0.000000,
+                        "",
+                        front_gear_num,
+                    )?);
+                    data_map.insert(10, front_gear.clone());
+                    // Do not populate directly. Autogenerated by decoder for gear_change subfield components. Number of front teeth.
+                    fields.push(event_message_front_gear_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        1.000000,
+                        0.000000,
+                        "",
+                        front_gear,
                     )?);
                 } else if Event::RearGearChange.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
-                    fields.push(event_message_gear_change_data_field(
+                    if options.contains(&DecodeOption::KeepCompositeFields) {
+                        fields.push(event_message_gear_change_data_field(
+                            mesg_num,
+                            accumlators,
+                            options,
+                            data_map,
+                            false,
+                            1.000000,
+                            0.000000,
+                            "",
+                            value.clone(),
+                        )?);
+                    }
+                    let input = value.to_le_bytes();
+                    let ((input, offset), rear_gear_num) = extract_component(&input, 0usize, 8);
+                    let ((input, offset), rear_gear) = extract_component(input, offset, 8);
+                    let ((input, offset), front_gear_num) = extract_component(input, offset, 8);
+                    let ((input, offset), front_gear) = extract_component(input, offset, 8);
+                    data_map.insert(11, rear_gear_num.clone());
+                    // Do not populate directly. Autogenerated by decoder for gear_change subfield components. Rear gear number. 1 is innermost.
+                    fields.push(event_message_rear_gear_num_field(
                         mesg_num,
                         accumlators,
                         options,
@@ -29749,13 +30012,51 @@ fn event_message(
                         1.000000,
                         0.000000,
                         "",
-                        value,
+                        rear_gear_num,
+                    )?);
+                    data_map.insert(12, rear_gear.clone());
+                    // Do not populate directly. Autogenerated by decoder for gear_change subfield components. Number of rear teeth.
+                    fields.push(event_message_rear_gear_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        1.000000,
+                        0.000000,
+                        "",
+                        rear_gear,
+                    )?);
+                    data_map.insert(9, front_gear_num.clone());
+                    // Do not populate directly. Autogenerated by decoder for gear_change subfield components. Front gear number. 1 is innermost.
+                    fields.push(event_message_front_gear_num_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        1.000000,
+                        0.000000,
+                        "",
+                        front_gear_num,
+                    )?);
+                    data_map.insert(10, front_gear.clone());
+                    // Do not populate directly. Autogenerated by decoder for gear_change subfield components. Number of front teeth.
+                    fields.push(event_message_front_gear_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        1.000000,
+                        0.000000,
+                        "",
+                        front_gear,
                     )?);
                 } else if Event::RiderPositionChange.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_rider_position_field(
@@ -29772,8 +30073,7 @@ fn event_message(
                 } else if Event::CommTimeout.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_comm_timeout_field(
@@ -29790,8 +30090,7 @@ fn event_message(
                 } else if Event::DiveAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(event_message_dive_alert_field(
@@ -29808,11 +30107,33 @@ fn event_message(
                 } else if Event::RadarThreatAlert.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
-                    fields.push(event_message_radar_threat_alert_field(
+                    if options.contains(&DecodeOption::KeepCompositeFields) {
+                        fields.push(event_message_radar_threat_alert_field(
+                            mesg_num,
+                            accumlators,
+                            options,
+                            data_map,
+                            false,
+                            1.000000,
+                            0.000000,
+                            ",,m/s,m/s",
+                            value.clone(),
+                        )?);
+                    }
+                    let input = value.to_le_bytes();
+                    let ((input, offset), radar_threat_level_max) =
+                        extract_component(&input, 0usize, 8);
+                    let ((input, offset), radar_threat_count) = extract_component(input, offset, 8);
+                    let ((input, offset), radar_threat_avg_approach_speed) =
+                        extract_component(input, offset, 8);
+                    let ((input, offset), radar_threat_max_approach_speed) =
+                        extract_component(input, offset, 8);
+                    data_map.insert(21, radar_threat_level_max.clone());
+                    // Do not populate directly. Autogenerated by decoder for threat_alert subfield components.
+                    fields.push(event_message_radar_threat_level_max_field(
                         mesg_num,
                         accumlators,
                         options,
@@ -29821,7 +30142,46 @@ fn event_message(
                         1.000000,
                         0.000000,
                         "",
-                        value,
+                        radar_threat_level_max,
+                    )?);
+                    data_map.insert(22, radar_threat_count.clone());
+                    // Do not populate directly. Autogenerated by decoder for threat_alert subfield components.
+                    fields.push(event_message_radar_threat_count_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        1.000000,
+                        0.000000,
+                        "",
+                        radar_threat_count,
+                    )?);
+                    data_map.insert(23, radar_threat_avg_approach_speed.clone());
+                    // Do not populate directly. Autogenerated by decoder for radar_threat_alert subfield components
+                    fields.push(event_message_radar_threat_avg_approach_speed_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        10.000000,
+                        0.000000,
+                        "m/s",
+                        radar_threat_avg_approach_speed,
+                    )?);
+                    data_map.insert(24, radar_threat_max_approach_speed.clone());
+                    // Do not populate directly. Autogenerated by decoder for radar_threat_alert subfield components
+                    fields.push(event_message_radar_threat_max_approach_speed_field(
+                        mesg_num,
+                        accumlators,
+                        options,
+                        data_map,
+                        false,
+                        10.000000,
+                        0.000000,
+                        "m/s",
+                        radar_threat_max_approach_speed,
                     )?);
                 } else {
                     fields.push(event_message_data_field(
@@ -31216,8 +31576,7 @@ fn device_info_message(
                 if SourceType::BluetoothLowEnergy.as_i64()
                     == data_map
                         .get(&25)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(device_info_message_ble_device_type_field(
@@ -31234,8 +31593,7 @@ fn device_info_message(
                 } else if SourceType::Antplus.as_i64()
                     == data_map
                         .get(&25)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(device_info_message_antplus_device_type_field(
@@ -31252,8 +31610,7 @@ fn device_info_message(
                 } else if SourceType::Ant.as_i64()
                     == data_map
                         .get(&25)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(device_info_message_ant_device_type_field(
@@ -31270,8 +31627,7 @@ fn device_info_message(
                 } else if SourceType::Local.as_i64()
                     == data_map
                         .get(&25)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(device_info_message_local_device_type_field(
@@ -31329,8 +31685,7 @@ fn device_info_message(
                 if Manufacturer::FaveroElectronics.as_i64()
                     == data_map
                         .get(&2)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(device_info_message_favero_product_field(
@@ -31347,8 +31702,7 @@ fn device_info_message(
                 } else if Manufacturer::Garmin.as_i64()
                     == data_map
                         .get(&2)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(device_info_message_garmin_product_field(
@@ -31365,8 +31719,7 @@ fn device_info_message(
                 } else if Manufacturer::Dynastream.as_i64()
                     == data_map
                         .get(&2)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(device_info_message_garmin_product_field(
@@ -31383,8 +31736,7 @@ fn device_info_message(
                 } else if Manufacturer::DynastreamOem.as_i64()
                     == data_map
                         .get(&2)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(device_info_message_garmin_product_field(
@@ -31401,8 +31753,7 @@ fn device_info_message(
                 } else if Manufacturer::Tacx.as_i64()
                     == data_map
                         .get(&2)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(device_info_message_garmin_product_field(
@@ -32592,8 +32943,7 @@ fn training_file_message(
                 if Manufacturer::FaveroElectronics.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(training_file_message_favero_product_field(
@@ -32610,8 +32960,7 @@ fn training_file_message(
                 } else if Manufacturer::Garmin.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(training_file_message_garmin_product_field(
@@ -32628,8 +32977,7 @@ fn training_file_message(
                 } else if Manufacturer::Dynastream.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(training_file_message_garmin_product_field(
@@ -32646,8 +32994,7 @@ fn training_file_message(
                 } else if Manufacturer::DynastreamOem.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(training_file_message_garmin_product_field(
@@ -32664,8 +33011,7 @@ fn training_file_message(
                 } else if Manufacturer::Tacx.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(training_file_message_garmin_product_field(
@@ -36014,8 +36360,7 @@ fn three_d_sensor_calibration_message(
                 if SensorType::Accelerometer.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(three_d_sensor_calibration_message_accel_cal_factor_field(
@@ -36032,8 +36377,7 @@ fn three_d_sensor_calibration_message(
                 } else if SensorType::Gyroscope.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(three_d_sensor_calibration_message_gyro_cal_factor_field(
@@ -36425,8 +36769,7 @@ fn one_d_sensor_calibration_message(
                 if SensorType::Barometer.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(one_d_sensor_calibration_message_baro_cal_factor_field(
@@ -39207,7 +39550,7 @@ fn jump_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_speed) = extract_component(&input, 0usize, 16);
                 data_map.insert(8, enhanced_speed.clone());
                 fields.push(jump_message_enhanced_speed_field(
@@ -42133,7 +42476,7 @@ fn segment_point_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_altitude) = extract_component(&input, 0usize, 16);
                 data_map.insert(6, enhanced_altitude.clone());
                 // Accumulated altitude along the segment at the described point
@@ -42535,8 +42878,7 @@ fn segment_lap_message(
                 if Sport::Cycling.as_i64()
                     == data_map
                         .get(&23)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(segment_lap_message_total_strokes_field(
@@ -42884,7 +43226,7 @@ fn segment_lap_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_avg_altitude) =
                     extract_component(&input, 0usize, 16);
                 data_map.insert(91, enhanced_avg_altitude.clone());
@@ -42914,7 +43256,7 @@ fn segment_lap_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_max_altitude) =
                     extract_component(&input, 0usize, 16);
                 data_map.insert(92, enhanced_max_altitude.clone());
@@ -43178,7 +43520,7 @@ fn segment_lap_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), enhanced_min_altitude) =
                     extract_component(&input, 0usize, 16);
                 data_map.insert(93, enhanced_min_altitude.clone());
@@ -47374,8 +47716,7 @@ fn workout_step_message(
                 if WktStepDuration::Time.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_time_field(
@@ -47392,8 +47733,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepetitionTime.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_time_field(
@@ -47410,8 +47750,7 @@ fn workout_step_message(
                 } else if WktStepDuration::Distance.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_distance_field(
@@ -47428,8 +47767,7 @@ fn workout_step_message(
                 } else if WktStepDuration::HrLessThan.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_hr_field(
@@ -47446,8 +47784,7 @@ fn workout_step_message(
                 } else if WktStepDuration::HrGreaterThan.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_hr_field(
@@ -47464,8 +47801,7 @@ fn workout_step_message(
                 } else if WktStepDuration::Calories.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_calories_field(
@@ -47482,8 +47818,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepeatUntilStepsCmplt.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_step_field(
@@ -47500,8 +47835,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepeatUntilTime.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_step_field(
@@ -47518,8 +47852,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepeatUntilDistance.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_step_field(
@@ -47536,8 +47869,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepeatUntilCalories.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_step_field(
@@ -47554,8 +47886,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepeatUntilHrLessThan.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_step_field(
@@ -47572,8 +47903,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepeatUntilHrGreaterThan.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_step_field(
@@ -47590,8 +47920,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepeatUntilPowerLessThan.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_step_field(
@@ -47608,8 +47937,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepeatUntilPowerGreaterThan.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_step_field(
@@ -47626,8 +47954,7 @@ fn workout_step_message(
                 } else if WktStepDuration::PowerLessThan.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_power_field(
@@ -47644,8 +47971,7 @@ fn workout_step_message(
                 } else if WktStepDuration::PowerGreaterThan.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_power_field(
@@ -47662,8 +47988,7 @@ fn workout_step_message(
                 } else if WktStepDuration::Reps.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_duration_reps_field(
@@ -47708,8 +48033,7 @@ fn workout_step_message(
                 if WktStepTarget::Speed.as_i64()
                     == data_map
                         .get(&3)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_target_speed_zone_field(
@@ -47726,8 +48050,7 @@ fn workout_step_message(
                 } else if WktStepTarget::HeartRate.as_i64()
                     == data_map
                         .get(&3)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_target_hr_zone_field(
@@ -47744,8 +48067,7 @@ fn workout_step_message(
                 } else if WktStepTarget::Cadence.as_i64()
                     == data_map
                         .get(&3)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_target_cadence_zone_field(
@@ -47762,8 +48084,7 @@ fn workout_step_message(
                 } else if WktStepTarget::Power.as_i64()
                     == data_map
                         .get(&3)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_target_power_zone_field(
@@ -47780,8 +48101,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepeatUntilStepsCmplt.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_repeat_steps_field(
@@ -47798,8 +48118,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepeatUntilTime.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_repeat_time_field(
@@ -47816,8 +48135,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepeatUntilDistance.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_repeat_distance_field(
@@ -47834,8 +48152,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepeatUntilCalories.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_repeat_calories_field(
@@ -47852,8 +48169,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepeatUntilHrLessThan.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_repeat_hr_field(
@@ -47870,8 +48186,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepeatUntilHrGreaterThan.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_repeat_hr_field(
@@ -47888,8 +48203,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepeatUntilPowerLessThan.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_repeat_power_field(
@@ -47906,8 +48220,7 @@ fn workout_step_message(
                 } else if WktStepDuration::RepeatUntilPowerGreaterThan.as_i64()
                     == data_map
                         .get(&1)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_repeat_power_field(
@@ -47924,8 +48237,7 @@ fn workout_step_message(
                 } else if WktStepTarget::SwimStroke.as_i64()
                     == data_map
                         .get(&3)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_target_stroke_type_field(
@@ -47957,8 +48269,7 @@ fn workout_step_message(
                 if WktStepTarget::Speed.as_i64()
                     == data_map
                         .get(&3)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_custom_target_speed_low_field(
@@ -47975,8 +48286,7 @@ fn workout_step_message(
                 } else if WktStepTarget::HeartRate.as_i64()
                     == data_map
                         .get(&3)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_custom_target_heart_rate_low_field(
@@ -47993,8 +48303,7 @@ fn workout_step_message(
                 } else if WktStepTarget::Cadence.as_i64()
                     == data_map
                         .get(&3)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_custom_target_cadence_low_field(
@@ -48011,8 +48320,7 @@ fn workout_step_message(
                 } else if WktStepTarget::Power.as_i64()
                     == data_map
                         .get(&3)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_custom_target_power_low_field(
@@ -48044,8 +48352,7 @@ fn workout_step_message(
                 if WktStepTarget::Speed.as_i64()
                     == data_map
                         .get(&3)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_custom_target_speed_high_field(
@@ -48062,8 +48369,7 @@ fn workout_step_message(
                 } else if WktStepTarget::HeartRate.as_i64()
                     == data_map
                         .get(&3)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_custom_target_heart_rate_high_field(
@@ -48080,8 +48386,7 @@ fn workout_step_message(
                 } else if WktStepTarget::Cadence.as_i64()
                     == data_map
                         .get(&3)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_custom_target_cadence_high_field(
@@ -48098,8 +48403,7 @@ fn workout_step_message(
                 } else if WktStepTarget::Power.as_i64()
                     == data_map
                         .get(&3)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_custom_target_power_high_field(
@@ -48235,8 +48539,7 @@ fn workout_step_message(
                 if WktStepTarget::Speed.as_i64()
                     == data_map
                         .get(&19)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_secondary_target_speed_zone_field(
@@ -48253,8 +48556,7 @@ fn workout_step_message(
                 } else if WktStepTarget::HeartRate.as_i64()
                     == data_map
                         .get(&19)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_secondary_target_hr_zone_field(
@@ -48271,8 +48573,7 @@ fn workout_step_message(
                 } else if WktStepTarget::Cadence.as_i64()
                     == data_map
                         .get(&19)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_secondary_target_cadence_zone_field(
@@ -48289,8 +48590,7 @@ fn workout_step_message(
                 } else if WktStepTarget::Power.as_i64()
                     == data_map
                         .get(&19)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_secondary_target_power_zone_field(
@@ -48307,8 +48607,7 @@ fn workout_step_message(
                 } else if WktStepTarget::SwimStroke.as_i64()
                     == data_map
                         .get(&19)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(workout_step_message_secondary_target_stroke_type_field(
@@ -48340,8 +48639,7 @@ fn workout_step_message(
                 if WktStepTarget::Speed.as_i64()
                     == data_map
                         .get(&19)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(
@@ -48360,8 +48658,7 @@ fn workout_step_message(
                 } else if WktStepTarget::HeartRate.as_i64()
                     == data_map
                         .get(&19)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(
@@ -48380,8 +48677,7 @@ fn workout_step_message(
                 } else if WktStepTarget::Cadence.as_i64()
                     == data_map
                         .get(&19)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(
@@ -48400,8 +48696,7 @@ fn workout_step_message(
                 } else if WktStepTarget::Power.as_i64()
                     == data_map
                         .get(&19)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(
@@ -48437,8 +48732,7 @@ fn workout_step_message(
                 if WktStepTarget::Speed.as_i64()
                     == data_map
                         .get(&19)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(
@@ -48457,8 +48751,7 @@ fn workout_step_message(
                 } else if WktStepTarget::HeartRate.as_i64()
                     == data_map
                         .get(&19)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(
@@ -48477,8 +48770,7 @@ fn workout_step_message(
                 } else if WktStepTarget::Cadence.as_i64()
                     == data_map
                         .get(&19)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(
@@ -48497,8 +48789,7 @@ fn workout_step_message(
                 } else if WktStepTarget::Power.as_i64()
                     == data_map
                         .get(&19)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(
@@ -50523,8 +50814,7 @@ fn schedule_message(
                 if Manufacturer::FaveroElectronics.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(schedule_message_favero_product_field(
@@ -50541,8 +50831,7 @@ fn schedule_message(
                 } else if Manufacturer::Garmin.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(schedule_message_garmin_product_field(
@@ -50559,8 +50848,7 @@ fn schedule_message(
                 } else if Manufacturer::Dynastream.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(schedule_message_garmin_product_field(
@@ -50577,8 +50865,7 @@ fn schedule_message(
                 } else if Manufacturer::DynastreamOem.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(schedule_message_garmin_product_field(
@@ -50595,8 +50882,7 @@ fn schedule_message(
                 } else if Manufacturer::Tacx.as_i64()
                     == data_map
                         .get(&0)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(schedule_message_garmin_product_field(
@@ -52700,8 +52986,7 @@ fn monitoring_message(
                 if ActivityType::Walking.as_i64()
                     == data_map
                         .get(&5)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(monitoring_message_steps_field(
@@ -52718,8 +53003,7 @@ fn monitoring_message(
                 } else if ActivityType::Running.as_i64()
                     == data_map
                         .get(&5)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(monitoring_message_steps_field(
@@ -52736,8 +53020,7 @@ fn monitoring_message(
                 } else if ActivityType::Cycling.as_i64()
                     == data_map
                         .get(&5)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(monitoring_message_strokes_field(
@@ -52754,8 +53037,7 @@ fn monitoring_message(
                 } else if ActivityType::Swimming.as_i64()
                     == data_map
                         .get(&5)
-                        .map(|v| v.try_into().ok())
-                        .flatten()
+                        .and_then(|v| v.try_into().ok())
                         .unwrap_or(-1i64)
                 {
                     fields.push(monitoring_message_strokes_field(
@@ -52972,7 +53254,7 @@ fn monitoring_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), activity_type) = extract_component(&input, 0usize, 5);
                 let ((input, offset), intensity) = extract_component(input, offset, 3);
                 data_map.insert(5, activity_type.clone());
@@ -54038,7 +54320,7 @@ fn hr_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), fractional_timestamp) = extract_component(&input, 0usize, 8);
                 data_map.insert(0, fractional_timestamp.clone());
                 fields.push(hr_message_fractional_timestamp_field(
@@ -54093,7 +54375,7 @@ fn hr_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), event_timestamp_1) = extract_component(&input, 0usize, 12);
                 let ((input, offset), event_timestamp_2) = extract_component(input, offset, 12);
                 let ((input, offset), event_timestamp_3) = extract_component(input, offset, 12);
@@ -54104,6 +54386,31 @@ fn hr_message(
                 let ((input, offset), event_timestamp_8) = extract_component(input, offset, 12);
                 let ((input, offset), event_timestamp_9) = extract_component(input, offset, 12);
                 let ((input, offset), event_timestamp_10) = extract_component(input, offset, 12);
+                let event_timestamp_1 =
+                    accumulate_component(accumlators, mesg_num.as_u16(), 9, event_timestamp_1, 12)?;
+                let event_timestamp_2 =
+                    accumulate_component(accumlators, mesg_num.as_u16(), 9, event_timestamp_2, 12)?;
+                let event_timestamp_3 =
+                    accumulate_component(accumlators, mesg_num.as_u16(), 9, event_timestamp_3, 12)?;
+                let event_timestamp_4 =
+                    accumulate_component(accumlators, mesg_num.as_u16(), 9, event_timestamp_4, 12)?;
+                let event_timestamp_5 =
+                    accumulate_component(accumlators, mesg_num.as_u16(), 9, event_timestamp_5, 12)?;
+                let event_timestamp_6 =
+                    accumulate_component(accumlators, mesg_num.as_u16(), 9, event_timestamp_6, 12)?;
+                let event_timestamp_7 =
+                    accumulate_component(accumlators, mesg_num.as_u16(), 9, event_timestamp_7, 12)?;
+                let event_timestamp_8 =
+                    accumulate_component(accumlators, mesg_num.as_u16(), 9, event_timestamp_8, 12)?;
+                let event_timestamp_9 =
+                    accumulate_component(accumlators, mesg_num.as_u16(), 9, event_timestamp_9, 12)?;
+                let event_timestamp_10 = accumulate_component(
+                    accumlators,
+                    mesg_num.as_u16(),
+                    9,
+                    event_timestamp_10,
+                    12,
+                )?;
                 let event_timestamp = Value::Array(vec![
                     event_timestamp_1,
                     event_timestamp_2,
@@ -54122,7 +54429,7 @@ fn hr_message(
                     accumlators,
                     options,
                     data_map,
-                    true,
+                    false,
                     1024.000000,
                     0.000000,
                     "s",
@@ -54952,7 +55259,7 @@ fn ant_rx_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), channel_number) = extract_component(&input, 0usize, 8);
                 let ((input, offset), data_1) = extract_component(input, offset, 8);
                 let ((input, offset), data_2) = extract_component(input, offset, 8);
@@ -55251,7 +55558,7 @@ fn ant_tx_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), channel_number) = extract_component(&input, 0usize, 8);
                 let ((input, offset), data_1) = extract_component(input, offset, 8);
                 let ((input, offset), data_2) = extract_component(input, offset, 8);
@@ -55718,7 +56025,7 @@ fn exd_data_field_configuration_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), field_id) = extract_component(&input, 0usize, 4);
                 let ((input, offset), concept_count) = extract_component(input, offset, 4);
                 data_map.insert(2, field_id.clone());
@@ -56007,7 +56314,7 @@ fn exd_data_concept_configuration_message(
                         value.clone(),
                     )?);
                 }
-                let input = value.to_ne_bytes();
+                let input = value.to_le_bytes();
                 let ((input, offset), field_id) = extract_component(&input, 0usize, 4);
                 let ((input, offset), concept_index) = extract_component(input, offset, 4);
                 data_map.insert(2, field_id.clone());
@@ -57840,9 +58147,7 @@ fn unknown_message(
 ) -> Result<Vec<FitDataField>> {
     // since it's an unknown message all the fields are unknown
     if options.contains(&DecodeOption::DropUnknownFields) {
-        {
-            return Ok(Vec::new());
-        }
+        return Ok(Vec::new());
     }
     let fields = data_map
         .iter()
@@ -3,10 +3,14 @@
 #![allow(missing_docs)]
 #![allow(dead_code)]
 #![allow(clippy::unreadable_literal)]
+#![allow(clippy::match_like_matches_macro)]
+use crate::compat::prelude::*;
+use crate::de::BaseType;
+use core::convert;
+use core::fmt;
+use core::str;
 use serde::ser::Serializer;
 use serde::Serialize;
-use std::convert;
-use std::fmt;
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum File {
     /// Read only, single file. Must be in root directory.
@@ -164,6 +168,34 @@ impl convert::From<i64> for File {
         File::from(value as u8)
     }
 }
+impl str::FromStr for File {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "device" => Ok(File::Device),
+            "settings" => Ok(File::Settings),
+            "sport" => Ok(File::Sport),
+            "activity" => Ok(File::Activity),
+            "workout" => Ok(File::Workout),
+            "course" => Ok(File::Course),
+            "schedules" => Ok(File::Schedules),
+            "weight" => Ok(File::Weight),
+            "totals" => Ok(File::Totals),
+            "goals" => Ok(File::Goals),
+            "blood_pressure" => Ok(File::BloodPressure),
+            "monitoring_a" => Ok(File::MonitoringA),
+            "activity_summary" => Ok(File::ActivitySummary),
+            "monitoring_daily" => Ok(File::MonitoringDaily),
+            "monitoring_b" => Ok(File::MonitoringB),
+            "segment" => Ok(File::Segment),
+            "segment_list" => Ok(File::SegmentList),
+            "exd_configuration" => Ok(File::ExdConfiguration),
+            "mfg_range_min" => Ok(File::MfgRangeMin),
+            "mfg_range_max" => Ok(File::MfgRangeMax),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for File {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -692,6 +724,110 @@ impl convert::From<i64> for MesgNum {
         MesgNum::from(value as u16)
     }
 }
+impl str::FromStr for MesgNum {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "file_id" => Ok(MesgNum::FileId),
+            "capabilities" => Ok(MesgNum::Capabilities),
+            "device_settings" => Ok(MesgNum::DeviceSettings),
+            "user_profile" => Ok(MesgNum::UserProfile),
+            "hrm_profile" => Ok(MesgNum::HrmProfile),
+            "sdm_profile" => Ok(MesgNum::SdmProfile),
+            "bike_profile" => Ok(MesgNum::BikeProfile),
+            "zones_target" => Ok(MesgNum::ZonesTarget),
+            "hr_zone" => Ok(MesgNum::HrZone),
+            "power_zone" => Ok(MesgNum::PowerZone),
+            "met_zone" => Ok(MesgNum::MetZone),
+            "sport" => Ok(MesgNum::Sport),
+            "goal" => Ok(MesgNum::Goal),
+            "session" => Ok(MesgNum::Session),
+            "lap" => Ok(MesgNum::Lap),
+            "record" => Ok(MesgNum::Record),
+            "event" => Ok(MesgNum::Event),
+            "device_info" => Ok(MesgNum::DeviceInfo),
+            "workout" => Ok(MesgNum::Workout),
+            "workout_step" => Ok(MesgNum::WorkoutStep),
+            "schedule" => Ok(MesgNum::Schedule),
+            "weight_scale" => Ok(MesgNum::WeightScale),
+            "course" => Ok(MesgNum::Course),
+            "course_point" => Ok(MesgNum::CoursePoint),
+            "totals" => Ok(MesgNum::Totals),
+            "activity" => Ok(MesgNum::Activity),
+            "software" => Ok(MesgNum::Software),
+            "file_capabilities" => Ok(MesgNum::FileCapabilities),
+            "mesg_capabilities" => Ok(MesgNum::MesgCapabilities),
+            "field_capabilities" => Ok(MesgNum::FieldCapabilities),
+            "file_creator" => Ok(MesgNum::FileCreator),
+            "blood_pressure" => Ok(MesgNum::BloodPressure),
+            "speed_zone" => Ok(MesgNum::SpeedZone),
+            "monitoring" => Ok(MesgNum::Monitoring),
+            "training_file" => Ok(MesgNum::TrainingFile),
+            "hrv" => Ok(MesgNum::Hrv),
+            "ant_rx" => Ok(MesgNum::AntRx),
+            "ant_tx" => Ok(MesgNum::AntTx),
+            "ant_channel_id" => Ok(MesgNum::AntChannelId),
+            "length" => Ok(MesgNum::Length),
+            "monitoring_info" => Ok(MesgNum::MonitoringInfo),
+            "pad" => Ok(MesgNum::Pad),
+            "slave_device" => Ok(MesgNum::SlaveDevice),
+            "connectivity" => Ok(MesgNum::Connectivity),
+            "weather_conditions" => Ok(MesgNum::WeatherConditions),
+            "weather_alert" => Ok(MesgNum::WeatherAlert),
+            "cadence_zone" => Ok(MesgNum::CadenceZone),
+            "hr" => Ok(MesgNum::Hr),
+            "segment_lap" => Ok(MesgNum::SegmentLap),
+            "memo_glob" => Ok(MesgNum::MemoGlob),
+            "segment_id" => Ok(MesgNum::SegmentId),
+            "segment_leaderboard_entry" => Ok(MesgNum::SegmentLeaderboardEntry),
+            "segment_point" => Ok(MesgNum::SegmentPoint),
+            "segment_file" => Ok(MesgNum::SegmentFile),
+            "workout_session" => Ok(MesgNum::WorkoutSession),
+            "watchface_settings" => Ok(MesgNum::WatchfaceSettings),
+            "gps_metadata" => Ok(MesgNum::GpsMetadata),
+            "camera_event" => Ok(MesgNum::CameraEvent),
+            "timestamp_correlation" => Ok(MesgNum::TimestampCorrelation),
+            "gyroscope_data" => Ok(MesgNum::GyroscopeData),
+            "accelerometer_data" => Ok(MesgNum::AccelerometerData),
+            "three_d_sensor_calibration" => Ok(MesgNum::ThreeDSensorCalibration),
+            "video_frame" => Ok(MesgNum::VideoFrame),
+            "obdii_data" => Ok(MesgNum::ObdiiData),
+            "nmea_sentence" => Ok(MesgNum::NmeaSentence),
+            "aviation_attitude" => Ok(MesgNum::AviationAttitude),
+            "video" => Ok(MesgNum::Video),
+            "video_title" => Ok(MesgNum::VideoTitle),
+            "video_description" => Ok(MesgNum::VideoDescription),
+            "video_clip" => Ok(MesgNum::VideoClip),
+            "ohr_settings" => Ok(MesgNum::OhrSettings),
+            "exd_screen_configuration" => Ok(MesgNum::ExdScreenConfiguration),
+            "exd_data_field_configuration" => Ok(MesgNum::ExdDataFieldConfiguration),
+            "exd_data_concept_configuration" => Ok(MesgNum::ExdDataConceptConfiguration),
+            "field_description" => Ok(MesgNum::FieldDescription),
+            "developer_data_id" => Ok(MesgNum::DeveloperDataId),
+            "magnetometer_data" => Ok(MesgNum::MagnetometerData),
+            "barometer_data" => Ok(MesgNum::BarometerData),
+            "one_d_sensor_calibration" => Ok(MesgNum::OneDSensorCalibration),
+            "time_in_zone" => Ok(MesgNum::TimeInZone),
+            "set" => Ok(MesgNum::Set),
+            "stress_level" => Ok(MesgNum::StressLevel),
+            "dive_settings" => Ok(MesgNum::DiveSettings),
+            "dive_gas" => Ok(MesgNum::DiveGas),
+            "dive_alarm" => Ok(MesgNum::DiveAlarm),
+            "exercise_title" => Ok(MesgNum::ExerciseTitle),
+            "dive_summary" => Ok(MesgNum::DiveSummary),
+            "jump" => Ok(MesgNum::Jump),
+            "split" => Ok(MesgNum::Split),
+            "climb_pro" => Ok(MesgNum::ClimbPro),
+            "tank_update" => Ok(MesgNum::TankUpdate),
+            "tank_summary" => Ok(MesgNum::TankSummary),
+            "device_aux_battery_info" => Ok(MesgNum::DeviceAuxBatteryInfo),
+            "dive_apnea_alarm" => Ok(MesgNum::DiveApneaAlarm),
+            "mfg_range_min" => Ok(MesgNum::MfgRangeMin),
+            "mfg_range_max" => Ok(MesgNum::MfgRangeMax),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for MesgNum {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -753,6 +889,16 @@ impl convert::From<i64> for Checksum {
         Checksum::from(value as u8)
     }
 }
+impl str::FromStr for Checksum {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "clear" => Ok(Checksum::Clear),
+            "ok" => Ok(Checksum::Ok),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for Checksum {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -817,6 +963,17 @@ impl convert::From<i64> for FileFlags {
         FileFlags::from(value as u8)
     }
 }
+impl str::FromStr for FileFlags {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "read" => Ok(FileFlags::Read),
+            "write" => Ok(FileFlags::Write),
+            "erase" => Ok(FileFlags::Erase),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for FileFlags {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -881,6 +1038,17 @@ impl convert::From<i64> for MesgCount {
         MesgCount::from(value as u8)
     }
 }
+impl str::FromStr for MesgCount {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "num_per_file" => Ok(MesgCount::NumPerFile),
+            "max_per_file" => Ok(MesgCount::MaxPerFile),
+            "max_per_file_type" => Ok(MesgCount::MaxPerFileType),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for MesgCount {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -934,6 +1102,15 @@ impl convert::From<i64> for DateTime {
         DateTime::from(value as u32)
     }
 }
+impl str::FromStr for DateTime {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "min" => Ok(DateTime::Min),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for DateTime {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -990,6 +1167,15 @@ impl convert::From<i64> for LocalDateTime {
         LocalDateTime::from(value as u32)
     }
 }
+impl str::FromStr for LocalDateTime {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "min" => Ok(LocalDateTime::Min),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for LocalDateTime {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1057,6 +1243,17 @@ impl convert::From<i64> for MessageIndex {
         MessageIndex::from(value as u16)
     }
 }
+impl str::FromStr for MessageIndex {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "mask" => Ok(MessageIndex::Mask),
+            "reserved" => Ok(MessageIndex::Reserved),
+            "selected" => Ok(MessageIndex::Selected),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for MessageIndex {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1112,6 +1309,15 @@ impl convert::From<i64> for DeviceIndex {
         DeviceIndex::from(value as u8)
     }
 }
+impl str::FromStr for DeviceIndex {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "creator" => Ok(DeviceIndex::Creator),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for DeviceIndex {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1171,6 +1377,16 @@ impl convert::From<i64> for Gender {
         Gender::from(value as u8)
     }
 }
+impl str::FromStr for Gender {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "female" => Ok(Gender::Female),
+            "male" => Ok(Gender::Male),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for Gender {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1412,6 +1628,53 @@ impl convert::From<i64> for Language {
         Language::from(value as u8)
     }
 }
+impl str::FromStr for Language {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "english" => Ok(Language::English),
+            "french" => Ok(Language::French),
+            "italian" => Ok(Language::Italian),
+            "german" => Ok(Language::German),
+            "spanish" => Ok(Language::Spanish),
+            "croatian" => Ok(Language::Croatian),
+            "czech" => Ok(Language::Czech),
+            "danish" => Ok(Language::Danish),
+            "dutch" => Ok(Language::Dutch),
+            "finnish" => Ok(Language::Finnish),
+            "greek" => Ok(Language::Greek),
+            "hungarian" => Ok(Language::Hungarian),
+            "norwegian" => Ok(Language::Norwegian),
+            "polish" => Ok(Language::Polish),
+            "portuguese" => Ok(Language::Portuguese),
+            "slovakian" => Ok(Language::Slovakian),
+            "slovenian" => Ok(Language::Slovenian),
+            "swedish" => Ok(Language::Swedish),
+            "russian" => Ok(Language::Russian),
+            "turkish" => Ok(Language::Turkish),
+            "latvian" => Ok(Language::Latvian),
+            "ukrainian" => Ok(Language::Ukrainian),
+            "arabic" => Ok(Language::Arabic),
+            "farsi" => Ok(Language::Farsi),
+            "bulgarian" => Ok(Language::Bulgarian),
+            "romanian" => Ok(Language::Romanian),
+            "chinese" => Ok(Language::Chinese),
+            "japanese" => Ok(Language::Japanese),
+            "korean" => Ok(Language::Korean),
+            "taiwanese" => Ok(Language::Taiwanese),
+            "thai" => Ok(Language::Thai),
+            "hebrew" => Ok(Language::Hebrew),
+            "brazilian_portuguese" => Ok(Language::BrazilianPortuguese),
+            "indonesian" => Ok(Language::Indonesian),
+            "malaysian" => Ok(Language::Malaysian),
+            "vietnamese" => Ok(Language::Vietnamese),
+            "burmese" => Ok(Language::Burmese),
+            "mongolian" => Ok(Language::Mongolian),
+            "custom" => Ok(Language::Custom),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for Language {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1499,6 +1762,22 @@ impl convert::From<i64> for LanguageBits0 {
         LanguageBits0::from(value as u8)
     }
 }
+impl str::FromStr for LanguageBits0 {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "english" => Ok(LanguageBits0::English),
+            "french" => Ok(LanguageBits0::French),
+            "italian" => Ok(LanguageBits0::Italian),
+            "german" => Ok(LanguageBits0::German),
+            "spanish" => Ok(LanguageBits0::Spanish),
+            "croatian" => Ok(LanguageBits0::Croatian),
+            "czech" => Ok(LanguageBits0::Czech),
+            "danish" => Ok(LanguageBits0::Danish),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for LanguageBits0 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1588,6 +1867,22 @@ impl convert::From<i64> for LanguageBits1 {
         LanguageBits1::from(value as u8)
     }
 }
+impl str::FromStr for LanguageBits1 {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "dutch" => Ok(LanguageBits1::Dutch),
+            "finnish" => Ok(LanguageBits1::Finnish),
+            "greek" => Ok(LanguageBits1::Greek),
+            "hungarian" => Ok(LanguageBits1::Hungarian),
+            "norwegian" => Ok(LanguageBits1::Norwegian),
+            "polish" => Ok(LanguageBits1::Polish),
+            "portuguese" => Ok(LanguageBits1::Portuguese),
+            "slovakian" => Ok(LanguageBits1::Slovakian),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for LanguageBits1 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1677,6 +1972,22 @@ impl convert::From<i64> for LanguageBits2 {
         LanguageBits2::from(value as u8)
     }
 }
+impl str::FromStr for LanguageBits2 {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "slovenian" => Ok(LanguageBits2::Slovenian),
+            "swedish" => Ok(LanguageBits2::Swedish),
+            "russian" => Ok(LanguageBits2::Russian),
+            "turkish" => Ok(LanguageBits2::Turkish),
+            "latvian" => Ok(LanguageBits2::Latvian),
+            "ukrainian" => Ok(LanguageBits2::Ukrainian),
+            "arabic" => Ok(LanguageBits2::Arabic),
+            "farsi" => Ok(LanguageBits2::Farsi),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for LanguageBits2 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1766,6 +2077,22 @@ impl convert::From<i64> for LanguageBits3 {
         LanguageBits3::from(value as u8)
     }
 }
+impl str::FromStr for LanguageBits3 {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "bulgarian" => Ok(LanguageBits3::Bulgarian),
+            "romanian" => Ok(LanguageBits3::Romanian),
+            "chinese" => Ok(LanguageBits3::Chinese),
+            "japanese" => Ok(LanguageBits3::Japanese),
+            "korean" => Ok(LanguageBits3::Korean),
+            "taiwanese" => Ok(LanguageBits3::Taiwanese),
+            "thai" => Ok(LanguageBits3::Thai),
+            "hebrew" => Ok(LanguageBits3::Hebrew),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for LanguageBits3 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1845,6 +2172,20 @@ impl convert::From<i64> for LanguageBits4 {
         LanguageBits4::from(value as u8)
     }
 }
+impl str::FromStr for LanguageBits4 {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "brazilian_portuguese" => Ok(LanguageBits4::BrazilianPortuguese),
+            "indonesian" => Ok(LanguageBits4::Indonesian),
+            "malaysian" => Ok(LanguageBits4::Malaysian),
+            "vietnamese" => Ok(LanguageBits4::Vietnamese),
+            "burmese" => Ok(LanguageBits4::Burmese),
+            "mongolian" => Ok(LanguageBits4::Mongolian),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for LanguageBits4 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -2424,6 +2765,120 @@ impl convert::From<i64> for TimeZone {
         TimeZone::from(value as u8)
     }
 }
+impl str::FromStr for TimeZone {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "almaty" => Ok(TimeZone::Almaty),
+            "bangkok" => Ok(TimeZone::Bangkok),
+            "bombay" => Ok(TimeZone::Bombay),
+            "brasilia" => Ok(TimeZone::Brasilia),
+            "cairo" => Ok(TimeZone::Cairo),
+            "cape_verde_is" => Ok(TimeZone::CapeVerdeIs),
+            "darwin" => Ok(TimeZone::Darwin),
+            "eniwetok" => Ok(TimeZone::Eniwetok),
+            "fiji" => Ok(TimeZone::Fiji),
+            "hong_kong" => Ok(TimeZone::HongKong),
+            "islamabad" => Ok(TimeZone::Islamabad),
+            "kabul" => Ok(TimeZone::Kabul),
+            "magadan" => Ok(TimeZone::Magadan),
+            "mid_atlantic" => Ok(TimeZone::MidAtlantic),
+            "moscow" => Ok(TimeZone::Moscow),
+            "muscat" => Ok(TimeZone::Muscat),
+            "newfoundland" => Ok(TimeZone::Newfoundland),
+            "samoa" => Ok(TimeZone::Samoa),
+            "sydney" => Ok(TimeZone::Sydney),
+            "tehran" => Ok(TimeZone::Tehran),
+            "tokyo" => Ok(TimeZone::Tokyo),
+            "us_alaska" => Ok(TimeZone::UsAlaska),
+            "us_atlantic" => Ok(TimeZone::UsAtlantic),
+            "us_central" => Ok(TimeZone::UsCentral),
+            "us_eastern" => Ok(TimeZone::UsEastern),
+            "us_hawaii" => Ok(TimeZone::UsHawaii),
+            "us_mountain" => Ok(TimeZone::UsMountain),
+            "us_pacific" => Ok(TimeZone::UsPacific),
+            "other" => Ok(TimeZone::Other),
+            "auckland" => Ok(TimeZone::Auckland),
+            "kathmandu" => Ok(TimeZone::Kathmandu),
+            "europe_western_wet" => Ok(TimeZone::EuropeWesternWet),
+            "europe_central_cet" => Ok(TimeZone::EuropeCentralCet),
+            "europe_eastern_eet" => Ok(TimeZone::EuropeEasternEet),
+            "jakarta" => Ok(TimeZone::Jakarta),
+            "perth" => Ok(TimeZone::Perth),
+            "adelaide" => Ok(TimeZone::Adelaide),
+            "brisbane" => Ok(TimeZone::Brisbane),
+            "tasmania" => Ok(TimeZone::Tasmania),
+            "iceland" => Ok(TimeZone::Iceland),
+            "amsterdam" => Ok(TimeZone::Amsterdam),
+            "athens" => Ok(TimeZone::Athens),
+            "barcelona" => Ok(TimeZone::Barcelona),
+            "berlin" => Ok(TimeZone::Berlin),
+            "brussels" => Ok(TimeZone::Brussels),
+            "budapest" => Ok(TimeZone::Budapest),
+            "copenhagen" => Ok(TimeZone::Copenhagen),
+            "dublin" => Ok(TimeZone::Dublin),
+            "helsinki" => Ok(TimeZone::Helsinki),
+            "lisbon" => Ok(TimeZone::Lisbon),
+            "london" => Ok(TimeZone::London),
+            "madrid" => Ok(TimeZone::Madrid),
+            "munich" => Ok(TimeZone::Munich),
+            "oslo" => Ok(TimeZone::Oslo),
+            "paris" => Ok(TimeZone::Paris),
+            "prague" => Ok(TimeZone::Prague),
+            "reykjavik" => Ok(TimeZone::Reykjavik),
+            "rome" => Ok(TimeZone::Rome),
+            "stockholm" => Ok(TimeZone::Stockholm),
+            "vienna" => Ok(TimeZone::Vienna),
+            "warsaw" => Ok(TimeZone::Warsaw),
+            "zurich" => Ok(TimeZone::Zurich),
+            "quebec" => Ok(TimeZone::Quebec),
+            "ontario" => Ok(TimeZone::Ontario),
+            "manitoba" => Ok(TimeZone::Manitoba),
+            "saskatchewan" => Ok(TimeZone::Saskatchewan),
+            "alberta" => Ok(TimeZone::Alberta),
+            "british_columbia" => Ok(TimeZone::BritishColumbia),
+            "boise" => Ok(TimeZone::Boise),
+            "boston" => Ok(TimeZone::Boston),
+            "chicago" => Ok(TimeZone::Chicago),
+            "dallas" => Ok(TimeZone::Dallas),
+            "denver" => Ok(TimeZone::Denver),
+            "kansas_city" => Ok(TimeZone::KansasCity),
+            "las_vegas" => Ok(TimeZone::LasVegas),
+            "los_angeles" => Ok(TimeZone::LosAngeles),
+            "miami" => Ok(TimeZone::Miami),
+            "minneapolis" => Ok(TimeZone::Minneapolis),
+            "new_york" => Ok(TimeZone::NewYork),
+            "new_orleans" => Ok(TimeZone::NewOrleans),
+            "phoenix" => Ok(TimeZone::Phoenix),
+            "santa_fe" => Ok(TimeZone::SantaFe),
+            "seattle" => Ok(TimeZone::Seattle),
+            "washington_dc" => Ok(TimeZone::WashingtonDc),
+            "us_arizona" => Ok(TimeZone::UsArizona),
+            "chita" => Ok(TimeZone::Chita),
+            "ekaterinburg" => Ok(TimeZone::Ekaterinburg),
+            "irkutsk" => Ok(TimeZone::Irkutsk),
+            "kaliningrad" => Ok(TimeZone::Kaliningrad),
+            "krasnoyarsk" => Ok(TimeZone::Krasnoyarsk),
+            "novosibirsk" => Ok(TimeZone::Novosibirsk),
+            "petropavlovsk_kamchatskiy" => Ok(TimeZone::PetropavlovskKamchatskiy),
+            "samara" => Ok(TimeZone::Samara),
+            "vladivostok" => Ok(TimeZone::Vladivostok),
+            "mexico_central" => Ok(TimeZone::MexicoCentral),
+            "mexico_mountain" => Ok(TimeZone::MexicoMountain),
+            "mexico_pacific" => Ok(TimeZone::MexicoPacific),
+            "cape_town" => Ok(TimeZone::CapeTown),
+            "winkhoek" => Ok(TimeZone::Winkhoek),
+            "lagos" => Ok(TimeZone::Lagos),
+            "riyahd" => Ok(TimeZone::Riyahd),
+            "venezuela" => Ok(TimeZone::Venezuela),
+            "australia_lh" => Ok(TimeZone::AustraliaLh),
+            "santiago" => Ok(TimeZone::Santiago),
+            "manual" => Ok(TimeZone::Manual),
+            "automatic" => Ok(TimeZone::Automatic),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for TimeZone {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -2485,6 +2940,17 @@ impl convert::From<i64> for DisplayMeasure {
         DisplayMeasure::from(value as u8)
     }
 }
+impl str::FromStr for DisplayMeasure {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "metric" => Ok(DisplayMeasure::Metric),
+            "statute" => Ok(DisplayMeasure::Statute),
+            "nautical" => Ok(DisplayMeasure::Nautical),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for DisplayMeasure {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -2546,6 +3012,17 @@ impl convert::From<i64> for DisplayHeart {
         DisplayHeart::from(value as u8)
     }
 }
+impl str::FromStr for DisplayHeart {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "bpm" => Ok(DisplayHeart::Bpm),
+            "max" => Ok(DisplayHeart::Max),
+            "reserve" => Ok(DisplayHeart::Reserve),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for DisplayHeart {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -2602,6 +3079,16 @@ impl convert::From<i64> for DisplayPower {
         DisplayPower::from(value as u8)
     }
 }
+impl str::FromStr for DisplayPower {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "watts" => Ok(DisplayPower::Watts),
+            "percent_ftp" => Ok(DisplayPower::PercentFtp),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for DisplayPower {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -2900,6 +3387,56 @@ impl convert::From<i64> for DisplayPosition {
         DisplayPosition::from(value as u8)
     }
 }
+impl str::FromStr for DisplayPosition {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "degree" => Ok(DisplayPosition::Degree),
+            "degree_minute" => Ok(DisplayPosition::DegreeMinute),
+            "degree_minute_second" => Ok(DisplayPosition::DegreeMinuteSecond),
+            "austrian_grid" => Ok(DisplayPosition::AustrianGrid),
+            "british_grid" => Ok(DisplayPosition::BritishGrid),
+            "dutch_grid" => Ok(DisplayPosition::DutchGrid),
+            "hungarian_grid" => Ok(DisplayPosition::HungarianGrid),
+            "finnish_grid" => Ok(DisplayPosition::FinnishGrid),
+            "german_grid" => Ok(DisplayPosition::GermanGrid),
+            "icelandic_grid" => Ok(DisplayPosition::IcelandicGrid),
+            "indonesian_equatorial" => Ok(DisplayPosition::IndonesianEquatorial),
+            "indonesian_irian" => Ok(DisplayPosition::IndonesianIrian),
+            "indonesian_southern" => Ok(DisplayPosition::IndonesianSouthern),
+            "india_zone_0" => Ok(DisplayPosition::IndiaZone0),
+            "india_zone_IA" => Ok(DisplayPosition::IndiaZoneIA),
+            "india_zone_IB" => Ok(DisplayPosition::IndiaZoneIB),
+            "india_zone_IIA" => Ok(DisplayPosition::IndiaZoneIIA),
+            "india_zone_IIB" => Ok(DisplayPosition::IndiaZoneIIB),
+            "india_zone_IIIA" => Ok(DisplayPosition::IndiaZoneIIIA),
+            "india_zone_IIIB" => Ok(DisplayPosition::IndiaZoneIIIB),
+            "india_zone_IVA" => Ok(DisplayPosition::IndiaZoneIVA),
+            "india_zone_IVB" => Ok(DisplayPosition::IndiaZoneIVB),
+            "irish_transverse" => Ok(DisplayPosition::IrishTransverse),
+            "irish_grid" => Ok(DisplayPosition::IrishGrid),
+            "loran" => Ok(DisplayPosition::Loran),
+            "maidenhead_grid" => Ok(DisplayPosition::MaidenheadGrid),
+            "mgrs_grid" => Ok(DisplayPosition::MgrsGrid),
+            "new_zealand_grid" => Ok(DisplayPosition::NewZealandGrid),
+            "new_zealand_transverse" => Ok(DisplayPosition::NewZealandTransverse),
+            "qatar_grid" => Ok(DisplayPosition::QatarGrid),
+            "modified_swedish_grid" => Ok(DisplayPosition::ModifiedSwedishGrid),
+            "swedish_grid" => Ok(DisplayPosition::SwedishGrid),
+            "south_african_grid" => Ok(DisplayPosition::SouthAfricanGrid),
+            "swiss_grid" => Ok(DisplayPosition::SwissGrid),
+            "taiwan_grid" => Ok(DisplayPosition::TaiwanGrid),
+            "united_states_grid" => Ok(DisplayPosition::UnitedStatesGrid),
+            "utm_ups_grid" => Ok(DisplayPosition::UtmUpsGrid),
+            "west_malayan" => Ok(DisplayPosition::WestMalayan),
+            "borneo_rso" => Ok(DisplayPosition::BorneoRso),
+            "estonian_grid" => Ok(DisplayPosition::EstonianGrid),
+            "latvian_grid" => Ok(DisplayPosition::LatvianGrid),
+            "swedish_ref_99_grid" => Ok(DisplayPosition::SwedishRef99Grid),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for DisplayPosition {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -2961,6 +3498,17 @@ impl convert::From<i64> for Switch {
         Switch::from(value as u8)
     }
 }
+impl str::FromStr for Switch {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "off" => Ok(Switch::Off),
+            "on" => Ok(Switch::On),
+            "auto" => Ok(Switch::Auto),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for Switch {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -3284,6 +3832,69 @@ impl convert::From<i64> for Sport {
         Sport::from(value as u8)
     }
 }
+impl str::FromStr for Sport {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "generic" => Ok(Sport::Generic),
+            "running" => Ok(Sport::Running),
+            "cycling" => Ok(Sport::Cycling),
+            "transition" => Ok(Sport::Transition),
+            "fitness_equipment" => Ok(Sport::FitnessEquipment),
+            "swimming" => Ok(Sport::Swimming),
+            "basketball" => Ok(Sport::Basketball),
+            "soccer" => Ok(Sport::Soccer),
+            "tennis" => Ok(Sport::Tennis),
+            "american_football" => Ok(Sport::AmericanFootball),
+            "training" => Ok(Sport::Training),
+            "walking" => Ok(Sport::Walking),
+            "cross_country_skiing" => Ok(Sport::CrossCountrySkiing),
+            "alpine_skiing" => Ok(Sport::AlpineSkiing),
+            "snowboarding" => Ok(Sport::Snowboarding),
+            "rowing" => Ok(Sport::Rowing),
+            "mountaineering" => Ok(Sport::Mountaineering),
+            "hiking" => Ok(Sport::Hiking),
+            "multisport" => Ok(Sport::Multisport),
+            "paddling" => Ok(Sport::Paddling),
+            "flying" => Ok(Sport::Flying),
+            "e_biking" => Ok(Sport::EBiking),
+            "motorcycling" => Ok(Sport::Motorcycling),
+            "boating" => Ok(Sport::Boating),
+            "driving" => Ok(Sport::Driving),
+            "golf" => Ok(Sport::Golf),
+            "hang_gliding" => Ok(Sport::HangGliding),
+            "horseback_riding" => Ok(Sport::HorsebackRiding),
+            "hunting" => Ok(Sport::Hunting),
+            "fishing" => Ok(Sport::Fishing),
+            "inline_skating" => Ok(Sport::InlineSkating),
+            "rock_climbing" => Ok(Sport::RockClimbing),
+            "sailing" => Ok(Sport::Sailing),
+            "ice_skating" => Ok(Sport::IceSkating),
+            "sky_diving" => Ok(Sport::SkyDiving),
+            "snowshoeing" => Ok(Sport::Snowshoeing),
+            "snowmobiling" => Ok(Sport::Snowmobiling),
+            "stand_up_paddleboarding" => Ok(Sport::StandUpPaddleboarding),
+            "surfing" => Ok(Sport::Surfing),
+            "wakeboarding" => Ok(Sport::Wakeboarding),
+            "water_skiing" => Ok(Sport::WaterSkiing),
+            "kayaking" => Ok(Sport::Kayaking),
+            "rafting" => Ok(Sport::Rafting),
+            "windsurfing" => Ok(Sport::Windsurfing),
+            "kitesurfing" => Ok(Sport::Kitesurfing),
+            "tactical" => Ok(Sport::Tactical),
+            "jumpmaster" => Ok(Sport::Jumpmaster),
+            "boxing" => Ok(Sport::Boxing),
+            "floor_climbing" => Ok(Sport::FloorClimbing),
+            "diving" => Ok(Sport::Diving),
+            "hiit" => Ok(Sport::Hiit),
+            "racket" => Ok(Sport::Racket),
+            "water_tubing" => Ok(Sport::WaterTubing),
+            "wakesurfing" => Ok(Sport::Wakesurfing),
+            "all" => Ok(Sport::All),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for Sport {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -3372,6 +3983,22 @@ impl convert::From<i64> for SportBits0 {
         SportBits0::from(value as u8)
     }
 }
+impl str::FromStr for SportBits0 {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "generic" => Ok(SportBits0::Generic),
+            "running" => Ok(SportBits0::Running),
+            "cycling" => Ok(SportBits0::Cycling),
+            "transition" => Ok(SportBits0::Transition),
+            "fitness_equipment" => Ok(SportBits0::FitnessEquipment),
+            "swimming" => Ok(SportBits0::Swimming),
+            "basketball" => Ok(SportBits0::Basketball),
+            "soccer" => Ok(SportBits0::Soccer),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SportBits0 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -3462,6 +4089,22 @@ impl convert::From<i64> for SportBits1 {
         SportBits1::from(value as u8)
     }
 }
+impl str::FromStr for SportBits1 {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "tennis" => Ok(SportBits1::Tennis),
+            "american_football" => Ok(SportBits1::AmericanFootball),
+            "training" => Ok(SportBits1::Training),
+            "walking" => Ok(SportBits1::Walking),
+            "cross_country_skiing" => Ok(SportBits1::CrossCountrySkiing),
+            "alpine_skiing" => Ok(SportBits1::AlpineSkiing),
+            "snowboarding" => Ok(SportBits1::Snowboarding),
+            "rowing" => Ok(SportBits1::Rowing),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SportBits1 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -3552,6 +4195,22 @@ impl convert::From<i64> for SportBits2 {
         SportBits2::from(value as u8)
     }
 }
+impl str::FromStr for SportBits2 {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "mountaineering" => Ok(SportBits2::Mountaineering),
+            "hiking" => Ok(SportBits2::Hiking),
+            "multisport" => Ok(SportBits2::Multisport),
+            "paddling" => Ok(SportBits2::Paddling),
+            "flying" => Ok(SportBits2::Flying),
+            "e_biking" => Ok(SportBits2::EBiking),
+            "motorcycling" => Ok(SportBits2::Motorcycling),
+            "boating" => Ok(SportBits2::Boating),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SportBits2 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -3642,6 +4301,22 @@ impl convert::From<i64> for SportBits3 {
         SportBits3::from(value as u8)
     }
 }
+impl str::FromStr for SportBits3 {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "driving" => Ok(SportBits3::Driving),
+            "golf" => Ok(SportBits3::Golf),
+            "hang_gliding" => Ok(SportBits3::HangGliding),
+            "horseback_riding" => Ok(SportBits3::HorsebackRiding),
+            "hunting" => Ok(SportBits3::Hunting),
+            "fishing" => Ok(SportBits3::Fishing),
+            "inline_skating" => Ok(SportBits3::InlineSkating),
+            "rock_climbing" => Ok(SportBits3::RockClimbing),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SportBits3 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -3732,6 +4407,22 @@ impl convert::From<i64> for SportBits4 {
         SportBits4::from(value as u8)
     }
 }
+impl str::FromStr for SportBits4 {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "sailing" => Ok(SportBits4::Sailing),
+            "ice_skating" => Ok(SportBits4::IceSkating),
+            "sky_diving" => Ok(SportBits4::SkyDiving),
+            "snowshoeing" => Ok(SportBits4::Snowshoeing),
+            "snowmobiling" => Ok(SportBits4::Snowmobiling),
+            "stand_up_paddleboarding" => Ok(SportBits4::StandUpPaddleboarding),
+            "surfing" => Ok(SportBits4::Surfing),
+            "wakeboarding" => Ok(SportBits4::Wakeboarding),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SportBits4 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -3822,6 +4513,22 @@ impl convert::From<i64> for SportBits5 {
         SportBits5::from(value as u8)
     }
 }
+impl str::FromStr for SportBits5 {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "water_skiing" => Ok(SportBits5::WaterSkiing),
+            "kayaking" => Ok(SportBits5::Kayaking),
+            "rafting" => Ok(SportBits5::Rafting),
+            "windsurfing" => Ok(SportBits5::Windsurfing),
+            "kitesurfing" => Ok(SportBits5::Kitesurfing),
+            "tactical" => Ok(SportBits5::Tactical),
+            "jumpmaster" => Ok(SportBits5::Jumpmaster),
+            "boxing" => Ok(SportBits5::Boxing),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SportBits5 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -3877,6 +4584,15 @@ impl convert::From<i64> for SportBits6 {
         SportBits6::from(value as u8)
     }
 }
+impl str::FromStr for SportBits6 {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "floor_climbing" => Ok(SportBits6::FloorClimbing),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SportBits6 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -4350,6 +5066,86 @@ impl convert::From<i64> for SubSport {
         SubSport::from(value as u8)
     }
 }
+impl str::FromStr for SubSport {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "generic" => Ok(SubSport::Generic),
+            "treadmill" => Ok(SubSport::Treadmill),
+            "street" => Ok(SubSport::Street),
+            "trail" => Ok(SubSport::Trail),
+            "track" => Ok(SubSport::Track),
+            "spin" => Ok(SubSport::Spin),
+            "indoor_cycling" => Ok(SubSport::IndoorCycling),
+            "road" => Ok(SubSport::Road),
+            "mountain" => Ok(SubSport::Mountain),
+            "downhill" => Ok(SubSport::Downhill),
+            "recumbent" => Ok(SubSport::Recumbent),
+            "cyclocross" => Ok(SubSport::Cyclocross),
+            "hand_cycling" => Ok(SubSport::HandCycling),
+            "track_cycling" => Ok(SubSport::TrackCycling),
+            "indoor_rowing" => Ok(SubSport::IndoorRowing),
+            "elliptical" => Ok(SubSport::Elliptical),
+            "stair_climbing" => Ok(SubSport::StairClimbing),
+            "lap_swimming" => Ok(SubSport::LapSwimming),
+            "open_water" => Ok(SubSport::OpenWater),
+            "flexibility_training" => Ok(SubSport::FlexibilityTraining),
+            "strength_training" => Ok(SubSport::StrengthTraining),
+            "warm_up" => Ok(SubSport::WarmUp),
+            "match" => Ok(SubSport::Match),
+            "exercise" => Ok(SubSport::Exercise),
+            "challenge" => Ok(SubSport::Challenge),
+            "indoor_skiing" => Ok(SubSport::IndoorSkiing),
+            "cardio_training" => Ok(SubSport::CardioTraining),
+            "indoor_walking" => Ok(SubSport::IndoorWalking),
+            "e_bike_fitness" => Ok(SubSport::EBikeFitness),
+            "bmx" => Ok(SubSport::Bmx),
+            "casual_walking" => Ok(SubSport::CasualWalking),
+            "speed_walking" => Ok(SubSport::SpeedWalking),
+            "bike_to_run_transition" => Ok(SubSport::BikeToRunTransition),
+            "run_to_bike_transition" => Ok(SubSport::RunToBikeTransition),
+            "swim_to_bike_transition" => Ok(SubSport::SwimToBikeTransition),
+            "atv" => Ok(SubSport::Atv),
+            "motocross" => Ok(SubSport::Motocross),
+            "backcountry" => Ok(SubSport::Backcountry),
+            "resort" => Ok(SubSport::Resort),
+            "rc_drone" => Ok(SubSport::RcDrone),
+            "wingsuit" => Ok(SubSport::Wingsuit),
+            "whitewater" => Ok(SubSport::Whitewater),
+            "skate_skiing" => Ok(SubSport::SkateSkiing),
+            "yoga" => Ok(SubSport::Yoga),
+            "pilates" => Ok(SubSport::Pilates),
+            "indoor_running" => Ok(SubSport::IndoorRunning),
+            "gravel_cycling" => Ok(SubSport::GravelCycling),
+            "e_bike_mountain" => Ok(SubSport::EBikeMountain),
+            "commuting" => Ok(SubSport::Commuting),
+            "mixed_surface" => Ok(SubSport::MixedSurface),
+            "navigate" => Ok(SubSport::Navigate),
+            "track_me" => Ok(SubSport::TrackMe),
+            "map" => Ok(SubSport::Map),
+            "single_gas_diving" => Ok(SubSport::SingleGasDiving),
+            "multi_gas_diving" => Ok(SubSport::MultiGasDiving),
+            "gauge_diving" => Ok(SubSport::GaugeDiving),
+            "apnea_diving" => Ok(SubSport::ApneaDiving),
+            "apnea_hunting" => Ok(SubSport::ApneaHunting),
+            "virtual_activity" => Ok(SubSport::VirtualActivity),
+            "obstacle" => Ok(SubSport::Obstacle),
+            "breathing" => Ok(SubSport::Breathing),
+            "sail_race" => Ok(SubSport::SailRace),
+            "ultra" => Ok(SubSport::Ultra),
+            "indoor_climbing" => Ok(SubSport::IndoorClimbing),
+            "bouldering" => Ok(SubSport::Bouldering),
+            "hiit" => Ok(SubSport::Hiit),
+            "amrap" => Ok(SubSport::Amrap),
+            "emom" => Ok(SubSport::Emom),
+            "tabata" => Ok(SubSport::Tabata),
+            "pickleball" => Ok(SubSport::Pickleball),
+            "padel" => Ok(SubSport::Padel),
+            "all" => Ok(SubSport::All),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SubSport {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -4441,6 +5237,23 @@ impl convert::From<i64> for SportEvent {
         SportEvent::from(value as u8)
     }
 }
+impl str::FromStr for SportEvent {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "uncategorized" => Ok(SportEvent::Uncategorized),
+            "geocaching" => Ok(SportEvent::Geocaching),
+            "fitness" => Ok(SportEvent::Fitness),
+            "recreation" => Ok(SportEvent::Recreation),
+            "race" => Ok(SportEvent::Race),
+            "special_event" => Ok(SportEvent::SpecialEvent),
+            "training" => Ok(SportEvent::Training),
+            "transportation" => Ok(SportEvent::Transportation),
+            "touring" => Ok(SportEvent::Touring),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SportEvent {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -4497,6 +5310,16 @@ impl convert::From<i64> for Activity {
         Activity::from(value as u8)
     }
 }
+impl str::FromStr for Activity {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "manual" => Ok(Activity::Manual),
+            "auto_multi_sport" => Ok(Activity::AutoMultiSport),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for Activity {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -4578,6 +5401,21 @@ impl convert::From<i64> for Intensity {
         Intensity::from(value as u8)
     }
 }
+impl str::FromStr for Intensity {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "active" => Ok(Intensity::Active),
+            "rest" => Ok(Intensity::Rest),
+            "warmup" => Ok(Intensity::Warmup),
+            "cooldown" => Ok(Intensity::Cooldown),
+            "recovery" => Ok(Intensity::Recovery),
+            "interval" => Ok(Intensity::Interval),
+            "other" => Ok(Intensity::Other),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for Intensity {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -4647,6 +5485,18 @@ impl convert::From<i64> for SessionTrigger {
         SessionTrigger::from(value as u8)
     }
 }
+impl str::FromStr for SessionTrigger {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "activity_end" => Ok(SessionTrigger::ActivityEnd),
+            "manual" => Ok(SessionTrigger::Manual),
+            "auto_multi_sport" => Ok(SessionTrigger::AutoMultiSport),
+            "fitness_equipment" => Ok(SessionTrigger::FitnessEquipment),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SessionTrigger {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -4728,6 +5578,21 @@ impl convert::From<i64> for AutolapTrigger {
         AutolapTrigger::from(value as u8)
     }
 }
+impl str::FromStr for AutolapTrigger {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "time" => Ok(AutolapTrigger::Time),
+            "distance" => Ok(AutolapTrigger::Distance),
+            "position_start" => Ok(AutolapTrigger::PositionStart),
+            "position_lap" => Ok(AutolapTrigger::PositionLap),
+            "position_waypoint" => Ok(AutolapTrigger::PositionWaypoint),
+            "position_marked" => Ok(AutolapTrigger::PositionMarked),
+            "off" => Ok(AutolapTrigger::Off),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for AutolapTrigger {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -4819,6 +5684,23 @@ impl convert::From<i64> for LapTrigger {
         LapTrigger::from(value as u8)
     }
 }
+impl str::FromStr for LapTrigger {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "manual" => Ok(LapTrigger::Manual),
+            "time" => Ok(LapTrigger::Time),
+            "distance" => Ok(LapTrigger::Distance),
+            "position_start" => Ok(LapTrigger::PositionStart),
+            "position_lap" => Ok(LapTrigger::PositionLap),
+            "position_waypoint" => Ok(LapTrigger::PositionWaypoint),
+            "position_marked" => Ok(LapTrigger::PositionMarked),
+            "session_end" => Ok(LapTrigger::SessionEnd),
+            "fitness_equipment" => Ok(LapTrigger::FitnessEquipment),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for LapTrigger {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -4897,6 +5779,20 @@ impl convert::From<i64> for TimeMode {
         TimeMode::from(value as u8)
     }
 }
+impl str::FromStr for TimeMode {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "hour12" => Ok(TimeMode::Hour12),
+            "hour24" => Ok(TimeMode::Hour24),
+            "military" => Ok(TimeMode::Military),
+            "hour_12_with_seconds" => Ok(TimeMode::Hour12WithSeconds),
+            "hour_24_with_seconds" => Ok(TimeMode::Hour24WithSeconds),
+            "utc" => Ok(TimeMode::Utc),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for TimeMode {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -4980,6 +5876,23 @@ impl convert::From<i64> for BacklightMode {
         BacklightMode::from(value as u8)
     }
 }
+impl str::FromStr for BacklightMode {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "off" => Ok(BacklightMode::Off),
+            "manual" => Ok(BacklightMode::Manual),
+            "key_and_messages" => Ok(BacklightMode::KeyAndMessages),
+            "auto_brightness" => Ok(BacklightMode::AutoBrightness),
+            "smart_notifications" => Ok(BacklightMode::SmartNotifications),
+            "key_and_messages_night" => Ok(BacklightMode::KeyAndMessagesNight),
+            "key_and_messages_and_smart_notifications" => {
+                Ok(BacklightMode::KeyAndMessagesAndSmartNotifications)
+            }
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for BacklightMode {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -5036,6 +5949,16 @@ impl convert::From<i64> for DateMode {
         DateMode::from(value as u8)
     }
 }
+impl str::FromStr for DateMode {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "day_month" => Ok(DateMode::DayMonth),
+            "month_day" => Ok(DateMode::MonthDay),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for DateMode {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -5089,6 +6012,15 @@ impl convert::From<i64> for BacklightTimeout {
         BacklightTimeout::from(value as u8)
     }
 }
+impl str::FromStr for BacklightTimeout {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "infinite" => Ok(BacklightTimeout::Infinite),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for BacklightTimeout {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -5408,6 +6340,59 @@ impl convert::From<i64> for Event {
         Event::from(value as u8)
     }
 }
+impl str::FromStr for Event {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "timer" => Ok(Event::Timer),
+            "workout" => Ok(Event::Workout),
+            "workout_step" => Ok(Event::WorkoutStep),
+            "power_down" => Ok(Event::PowerDown),
+            "power_up" => Ok(Event::PowerUp),
+            "off_course" => Ok(Event::OffCourse),
+            "session" => Ok(Event::Session),
+            "lap" => Ok(Event::Lap),
+            "course_point" => Ok(Event::CoursePoint),
+            "battery" => Ok(Event::Battery),
+            "virtual_partner_pace" => Ok(Event::VirtualPartnerPace),
+            "hr_high_alert" => Ok(Event::HrHighAlert),
+            "hr_low_alert" => Ok(Event::HrLowAlert),
+            "speed_high_alert" => Ok(Event::SpeedHighAlert),
+            "speed_low_alert" => Ok(Event::SpeedLowAlert),
+            "cad_high_alert" => Ok(Event::CadHighAlert),
+            "cad_low_alert" => Ok(Event::CadLowAlert),
+            "power_high_alert" => Ok(Event::PowerHighAlert),
+            "power_low_alert" => Ok(Event::PowerLowAlert),
+            "recovery_hr" => Ok(Event::RecoveryHr),
+            "battery_low" => Ok(Event::BatteryLow),
+            "time_duration_alert" => Ok(Event::TimeDurationAlert),
+            "distance_duration_alert" => Ok(Event::DistanceDurationAlert),
+            "calorie_duration_alert" => Ok(Event::CalorieDurationAlert),
+            "activity" => Ok(Event::Activity),
+            "fitness_equipment" => Ok(Event::FitnessEquipment),
+            "length" => Ok(Event::Length),
+            "user_marker" => Ok(Event::UserMarker),
+            "sport_point" => Ok(Event::SportPoint),
+            "calibration" => Ok(Event::Calibration),
+            "front_gear_change" => Ok(Event::FrontGearChange),
+            "rear_gear_change" => Ok(Event::RearGearChange),
+            "rider_position_change" => Ok(Event::RiderPositionChange),
+            "elev_high_alert" => Ok(Event::ElevHighAlert),
+            "elev_low_alert" => Ok(Event::ElevLowAlert),
+            "comm_timeout" => Ok(Event::CommTimeout),
+            "dive_alert" => Ok(Event::DiveAlert),
+            "dive_gas_switched" => Ok(Event::DiveGasSwitched),
+            "tank_pressure_reserve" => Ok(Event::TankPressureReserve),
+            "tank_pressure_critical" => Ok(Event::TankPressureCritical),
+            "tank_lost" => Ok(Event::TankLost),
+            "radar_threat_alert" => Ok(Event::RadarThreatAlert),
+            "tank_battery_low" => Ok(Event::TankBatteryLow),
+            "tank_pod_connected" => Ok(Event::TankPodConnected),
+            "tank_pod_disconnected" => Ok(Event::TankPodDisconnected),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for Event {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -5504,6 +6489,24 @@ impl convert::From<i64> for EventType {
         EventType::from(value as u8)
     }
 }
+impl str::FromStr for EventType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "start" => Ok(EventType::Start),
+            "stop" => Ok(EventType::Stop),
+            "consecutive_depreciated" => Ok(EventType::ConsecutiveDepreciated),
+            "marker" => Ok(EventType::Marker),
+            "stop_all" => Ok(EventType::StopAll),
+            "begin_depreciated" => Ok(EventType::BeginDepreciated),
+            "end_depreciated" => Ok(EventType::EndDepreciated),
+            "end_all_depreciated" => Ok(EventType::EndAllDepreciated),
+            "stop_disable" => Ok(EventType::StopDisable),
+            "stop_disable_all" => Ok(EventType::StopDisableAll),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for EventType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -5566,6 +6569,17 @@ impl convert::From<i64> for TimerTrigger {
         TimerTrigger::from(value as u8)
     }
 }
+impl str::FromStr for TimerTrigger {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "manual" => Ok(TimerTrigger::Manual),
+            "auto" => Ok(TimerTrigger::Auto),
+            "fitness_equipment" => Ok(TimerTrigger::FitnessEquipment),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for TimerTrigger {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -5634,6 +6648,18 @@ impl convert::From<i64> for FitnessEquipmentState {
         FitnessEquipmentState::from(value as u8)
     }
 }
+impl str::FromStr for FitnessEquipmentState {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "ready" => Ok(FitnessEquipmentState::Ready),
+            "in_use" => Ok(FitnessEquipmentState::InUse),
+            "paused" => Ok(FitnessEquipmentState::Paused),
+            "unknown" => Ok(FitnessEquipmentState::Unknown),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for FitnessEquipmentState {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -5700,6 +6726,18 @@ impl convert::From<i64> for Tone {
         Tone::from(value as u8)
     }
 }
+impl str::FromStr for Tone {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "off" => Ok(Tone::Off),
+            "tone" => Ok(Tone::Tone),
+            "vibrate" => Ok(Tone::Vibrate),
+            "tone_and_vibrate" => Ok(Tone::ToneAndVibrate),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for Tone {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -5766,6 +6804,18 @@ impl convert::From<i64> for Autoscroll {
         Autoscroll::from(value as u8)
     }
 }
+impl str::FromStr for Autoscroll {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "none" => Ok(Autoscroll::None),
+            "slow" => Ok(Autoscroll::Slow),
+            "medium" => Ok(Autoscroll::Medium),
+            "fast" => Ok(Autoscroll::Fast),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for Autoscroll {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -5828,6 +6878,17 @@ impl convert::From<i64> for ActivityClass {
         ActivityClass::from(value as u8)
     }
 }
+impl str::FromStr for ActivityClass {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "level_max" => Ok(ActivityClass::LevelMax),
+            "level" => Ok(ActivityClass::Level),
+            "athlete" => Ok(ActivityClass::Athlete),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for ActivityClass {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -5894,6 +6955,18 @@ impl convert::From<i64> for HrZoneCalc {
         HrZoneCalc::from(value as u8)
     }
 }
+impl str::FromStr for HrZoneCalc {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "custom" => Ok(HrZoneCalc::Custom),
+            "percent_max_hr" => Ok(HrZoneCalc::PercentMaxHr),
+            "percent_hrr" => Ok(HrZoneCalc::PercentHrr),
+            "percent_lthr" => Ok(HrZoneCalc::PercentLthr),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for HrZoneCalc {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -5950,6 +7023,16 @@ impl convert::From<i64> for PwrZoneCalc {
         PwrZoneCalc::from(value as u8)
     }
 }
+impl str::FromStr for PwrZoneCalc {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "custom" => Ok(PwrZoneCalc::Custom),
+            "percent_ftp" => Ok(PwrZoneCalc::PercentFtp),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for PwrZoneCalc {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -6159,6 +7242,49 @@ impl convert::From<i64> for WktStepDuration {
         WktStepDuration::from(value as u8)
     }
 }
+impl str::FromStr for WktStepDuration {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "time" => Ok(WktStepDuration::Time),
+            "distance" => Ok(WktStepDuration::Distance),
+            "hr_less_than" => Ok(WktStepDuration::HrLessThan),
+            "hr_greater_than" => Ok(WktStepDuration::HrGreaterThan),
+            "calories" => Ok(WktStepDuration::Calories),
+            "open" => Ok(WktStepDuration::Open),
+            "repeat_until_steps_cmplt" => Ok(WktStepDuration::RepeatUntilStepsCmplt),
+            "repeat_until_time" => Ok(WktStepDuration::RepeatUntilTime),
+            "repeat_until_distance" => Ok(WktStepDuration::RepeatUntilDistance),
+            "repeat_until_calories" => Ok(WktStepDuration::RepeatUntilCalories),
+            "repeat_until_hr_less_than" => Ok(WktStepDuration::RepeatUntilHrLessThan),
+            "repeat_until_hr_greater_than" => Ok(WktStepDuration::RepeatUntilHrGreaterThan),
+            "repeat_until_power_less_than" => Ok(WktStepDuration::RepeatUntilPowerLessThan),
+            "repeat_until_power_greater_than" => Ok(WktStepDuration::RepeatUntilPowerGreaterThan),
+            "power_less_than" => Ok(WktStepDuration::PowerLessThan),
+            "power_greater_than" => Ok(WktStepDuration::PowerGreaterThan),
+            "training_peaks_tss" => Ok(WktStepDuration::TrainingPeaksTss),
+            "repeat_until_power_last_lap_less_than" => {
+                Ok(WktStepDuration::RepeatUntilPowerLastLapLessThan)
+            }
+            "repeat_until_max_power_last_lap_less_than" => {
+                Ok(WktStepDuration::RepeatUntilMaxPowerLastLapLessThan)
+            }
+            "power_3s_less_than" => Ok(WktStepDuration::Power3sLessThan),
+            "power_10s_less_than" => Ok(WktStepDuration::Power10sLessThan),
+            "power_30s_less_than" => Ok(WktStepDuration::Power30sLessThan),
+            "power_3s_greater_than" => Ok(WktStepDuration::Power3sGreaterThan),
+            "power_10s_greater_than" => Ok(WktStepDuration::Power10sGreaterThan),
+            "power_30s_greater_than" => Ok(WktStepDuration::Power30sGreaterThan),
+            "power_lap_less_than" => Ok(WktStepDuration::PowerLapLessThan),
+            "power_lap_greater_than" => Ok(WktStepDuration::PowerLapGreaterThan),
+            "repeat_until_training_peaks_tss" => Ok(WktStepDuration::RepeatUntilTrainingPeaksTss),
+            "repetition_time" => Ok(WktStepDuration::RepetitionTime),
+            "reps" => Ok(WktStepDuration::Reps),
+            "time_only" => Ok(WktStepDuration::TimeOnly),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for WktStepDuration {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -6275,6 +7401,28 @@ impl convert::From<i64> for WktStepTarget {
         WktStepTarget::from(value as u8)
     }
 }
+impl str::FromStr for WktStepTarget {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "speed" => Ok(WktStepTarget::Speed),
+            "heart_rate" => Ok(WktStepTarget::HeartRate),
+            "open" => Ok(WktStepTarget::Open),
+            "cadence" => Ok(WktStepTarget::Cadence),
+            "power" => Ok(WktStepTarget::Power),
+            "grade" => Ok(WktStepTarget::Grade),
+            "resistance" => Ok(WktStepTarget::Resistance),
+            "power_3s" => Ok(WktStepTarget::Power3s),
+            "power_10s" => Ok(WktStepTarget::Power10s),
+            "power_30s" => Ok(WktStepTarget::Power30s),
+            "power_lap" => Ok(WktStepTarget::PowerLap),
+            "swim_stroke" => Ok(WktStepTarget::SwimStroke),
+            "speed_lap" => Ok(WktStepTarget::SpeedLap),
+            "heart_rate_lap" => Ok(WktStepTarget::HeartRateLap),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for WktStepTarget {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -6356,6 +7504,21 @@ impl convert::From<i64> for Goal {
         Goal::from(value as u8)
     }
 }
+impl str::FromStr for Goal {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "time" => Ok(Goal::Time),
+            "distance" => Ok(Goal::Distance),
+            "calories" => Ok(Goal::Calories),
+            "frequency" => Ok(Goal::Frequency),
+            "steps" => Ok(Goal::Steps),
+            "ascent" => Ok(Goal::Ascent),
+            "active_minutes" => Ok(Goal::ActiveMinutes),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for Goal {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -6432,6 +7595,20 @@ impl convert::From<i64> for GoalRecurrence {
         GoalRecurrence::from(value as u8)
     }
 }
+impl str::FromStr for GoalRecurrence {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "off" => Ok(GoalRecurrence::Off),
+            "daily" => Ok(GoalRecurrence::Daily),
+            "weekly" => Ok(GoalRecurrence::Weekly),
+            "monthly" => Ok(GoalRecurrence::Monthly),
+            "yearly" => Ok(GoalRecurrence::Yearly),
+            "custom" => Ok(GoalRecurrence::Custom),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for GoalRecurrence {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -6496,6 +7673,17 @@ impl convert::From<i64> for GoalSource {
         GoalSource::from(value as u8)
     }
 }
+impl str::FromStr for GoalSource {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "auto" => Ok(GoalSource::Auto),
+            "community" => Ok(GoalSource::Community),
+            "user" => Ok(GoalSource::User),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for GoalSource {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -6552,6 +7740,16 @@ impl convert::From<i64> for Schedule {
         Schedule::from(value as u8)
     }
 }
+impl str::FromStr for Schedule {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "workout" => Ok(Schedule::Workout),
+            "course" => Ok(Schedule::Course),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for Schedule {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -6864,6 +8062,67 @@ impl convert::From<i64> for CoursePoint {
         CoursePoint::from(value as u8)
     }
 }
+impl str::FromStr for CoursePoint {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "generic" => Ok(CoursePoint::Generic),
+            "summit" => Ok(CoursePoint::Summit),
+            "valley" => Ok(CoursePoint::Valley),
+            "water" => Ok(CoursePoint::Water),
+            "food" => Ok(CoursePoint::Food),
+            "danger" => Ok(CoursePoint::Danger),
+            "left" => Ok(CoursePoint::Left),
+            "right" => Ok(CoursePoint::Right),
+            "straight" => Ok(CoursePoint::Straight),
+            "first_aid" => Ok(CoursePoint::FirstAid),
+            "fourth_category" => Ok(CoursePoint::FourthCategory),
+            "third_category" => Ok(CoursePoint::ThirdCategory),
+            "second_category" => Ok(CoursePoint::SecondCategory),
+            "first_category" => Ok(CoursePoint::FirstCategory),
+            "hors_category" => Ok(CoursePoint::HorsCategory),
+            "sprint" => Ok(CoursePoint::Sprint),
+            "left_fork" => Ok(CoursePoint::LeftFork),
+            "right_fork" => Ok(CoursePoint::RightFork),
+            "middle_fork" => Ok(CoursePoint::MiddleFork),
+            "slight_left" => Ok(CoursePoint::SlightLeft),
+            "sharp_left" => Ok(CoursePoint::SharpLeft),
+            "slight_right" => Ok(CoursePoint::SlightRight),
+            "sharp_right" => Ok(CoursePoint::SharpRight),
+            "u_turn" => Ok(CoursePoint::UTurn),
+            "segment_start" => Ok(CoursePoint::SegmentStart),
+            "segment_end" => Ok(CoursePoint::SegmentEnd),
+            "campsite" => Ok(CoursePoint::Campsite),
+            "aid_station" => Ok(CoursePoint::AidStation),
+            "rest_area" => Ok(CoursePoint::RestArea),
+            "general_distance" => Ok(CoursePoint::GeneralDistance),
+            "service" => Ok(CoursePoint::Service),
+            "energy_gel" => Ok(CoursePoint::EnergyGel),
+            "sports_drink" => Ok(CoursePoint::SportsDrink),
+            "mile_marker" => Ok(CoursePoint::MileMarker),
+            "checkpoint" => Ok(CoursePoint::Checkpoint),
+            "shelter" => Ok(CoursePoint::Shelter),
+            "meeting_spot" => Ok(CoursePoint::MeetingSpot),
+            "overlook" => Ok(CoursePoint::Overlook),
+            "toilet" => Ok(CoursePoint::Toilet),
+            "shower" => Ok(CoursePoint::Shower),
+            "gear" => Ok(CoursePoint::Gear),
+            "sharp_curve" => Ok(CoursePoint::SharpCurve),
+            "steep_incline" => Ok(CoursePoint::SteepIncline),
+            "tunnel" => Ok(CoursePoint::Tunnel),
+            "bridge" => Ok(CoursePoint::Bridge),
+            "obstacle" => Ok(CoursePoint::Obstacle),
+            "crossing" => Ok(CoursePoint::Crossing),
+            "store" => Ok(CoursePoint::Store),
+            "transition" => Ok(CoursePoint::Transition),
+            "navaid" => Ok(CoursePoint::Navaid),
+            "transport" => Ok(CoursePoint::Transport),
+            "alert" => Ok(CoursePoint::Alert),
+            "info" => Ok(CoursePoint::Info),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for CoursePoint {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -7975,6 +9234,225 @@ impl convert::From<i64> for Manufacturer {
         Manufacturer::from(value as u16)
     }
 }
+impl str::FromStr for Manufacturer {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "garmin" => Ok(Manufacturer::Garmin),
+            "garmin_fr405_antfs" => Ok(Manufacturer::GarminFr405Antfs),
+            "zephyr" => Ok(Manufacturer::Zephyr),
+            "dayton" => Ok(Manufacturer::Dayton),
+            "idt" => Ok(Manufacturer::Idt),
+            "srm" => Ok(Manufacturer::Srm),
+            "quarq" => Ok(Manufacturer::Quarq),
+            "ibike" => Ok(Manufacturer::Ibike),
+            "saris" => Ok(Manufacturer::Saris),
+            "spark_hk" => Ok(Manufacturer::SparkHk),
+            "tanita" => Ok(Manufacturer::Tanita),
+            "echowell" => Ok(Manufacturer::Echowell),
+            "dynastream_oem" => Ok(Manufacturer::DynastreamOem),
+            "nautilus" => Ok(Manufacturer::Nautilus),
+            "dynastream" => Ok(Manufacturer::Dynastream),
+            "timex" => Ok(Manufacturer::Timex),
+            "metrigear" => Ok(Manufacturer::Metrigear),
+            "xelic" => Ok(Manufacturer::Xelic),
+            "beurer" => Ok(Manufacturer::Beurer),
+            "cardiosport" => Ok(Manufacturer::Cardiosport),
+            "a_and_d" => Ok(Manufacturer::AAndD),
+            "hmm" => Ok(Manufacturer::Hmm),
+            "suunto" => Ok(Manufacturer::Suunto),
+            "thita_elektronik" => Ok(Manufacturer::ThitaElektronik),
+            "gpulse" => Ok(Manufacturer::Gpulse),
+            "clean_mobile" => Ok(Manufacturer::CleanMobile),
+            "pedal_brain" => Ok(Manufacturer::PedalBrain),
+            "peaksware" => Ok(Manufacturer::Peaksware),
+            "saxonar" => Ok(Manufacturer::Saxonar),
+            "lemond_fitness" => Ok(Manufacturer::LemondFitness),
+            "dexcom" => Ok(Manufacturer::Dexcom),
+            "wahoo_fitness" => Ok(Manufacturer::WahooFitness),
+            "octane_fitness" => Ok(Manufacturer::OctaneFitness),
+            "archinoetics" => Ok(Manufacturer::Archinoetics),
+            "the_hurt_box" => Ok(Manufacturer::TheHurtBox),
+            "citizen_systems" => Ok(Manufacturer::CitizenSystems),
+            "magellan" => Ok(Manufacturer::Magellan),
+            "osynce" => Ok(Manufacturer::Osynce),
+            "holux" => Ok(Manufacturer::Holux),
+            "concept2" => Ok(Manufacturer::Concept2),
+            "shimano" => Ok(Manufacturer::Shimano),
+            "one_giant_leap" => Ok(Manufacturer::OneGiantLeap),
+            "ace_sensor" => Ok(Manufacturer::AceSensor),
+            "brim_brothers" => Ok(Manufacturer::BrimBrothers),
+            "xplova" => Ok(Manufacturer::Xplova),
+            "perception_digital" => Ok(Manufacturer::PerceptionDigital),
+            "bf1systems" => Ok(Manufacturer::Bf1systems),
+            "pioneer" => Ok(Manufacturer::Pioneer),
+            "spantec" => Ok(Manufacturer::Spantec),
+            "metalogics" => Ok(Manufacturer::Metalogics),
+            "4iiiis" => Ok(Manufacturer::Name4iiiis),
+            "seiko_epson" => Ok(Manufacturer::SeikoEpson),
+            "seiko_epson_oem" => Ok(Manufacturer::SeikoEpsonOem),
+            "ifor_powell" => Ok(Manufacturer::IforPowell),
+            "maxwell_guider" => Ok(Manufacturer::MaxwellGuider),
+            "star_trac" => Ok(Manufacturer::StarTrac),
+            "breakaway" => Ok(Manufacturer::Breakaway),
+            "alatech_technology_ltd" => Ok(Manufacturer::AlatechTechnologyLtd),
+            "mio_technology_europe" => Ok(Manufacturer::MioTechnologyEurope),
+            "rotor" => Ok(Manufacturer::Rotor),
+            "geonaute" => Ok(Manufacturer::Geonaute),
+            "id_bike" => Ok(Manufacturer::IdBike),
+            "specialized" => Ok(Manufacturer::Specialized),
+            "wtek" => Ok(Manufacturer::Wtek),
+            "physical_enterprises" => Ok(Manufacturer::PhysicalEnterprises),
+            "north_pole_engineering" => Ok(Manufacturer::NorthPoleEngineering),
+            "bkool" => Ok(Manufacturer::Bkool),
+            "cateye" => Ok(Manufacturer::Cateye),
+            "stages_cycling" => Ok(Manufacturer::StagesCycling),
+            "sigmasport" => Ok(Manufacturer::Sigmasport),
+            "tomtom" => Ok(Manufacturer::Tomtom),
+            "peripedal" => Ok(Manufacturer::Peripedal),
+            "wattbike" => Ok(Manufacturer::Wattbike),
+            "moxy" => Ok(Manufacturer::Moxy),
+            "ciclosport" => Ok(Manufacturer::Ciclosport),
+            "powerbahn" => Ok(Manufacturer::Powerbahn),
+            "acorn_projects_aps" => Ok(Manufacturer::AcornProjectsAps),
+            "lifebeam" => Ok(Manufacturer::Lifebeam),
+            "bontrager" => Ok(Manufacturer::Bontrager),
+            "wellgo" => Ok(Manufacturer::Wellgo),
+            "scosche" => Ok(Manufacturer::Scosche),
+            "magura" => Ok(Manufacturer::Magura),
+            "woodway" => Ok(Manufacturer::Woodway),
+            "elite" => Ok(Manufacturer::Elite),
+            "nielsen_kellerman" => Ok(Manufacturer::NielsenKellerman),
+            "dk_city" => Ok(Manufacturer::DkCity),
+            "tacx" => Ok(Manufacturer::Tacx),
+            "direction_technology" => Ok(Manufacturer::DirectionTechnology),
+            "magtonic" => Ok(Manufacturer::Magtonic),
+            "1partcarbon" => Ok(Manufacturer::Name1partcarbon),
+            "inside_ride_technologies" => Ok(Manufacturer::InsideRideTechnologies),
+            "sound_of_motion" => Ok(Manufacturer::SoundOfMotion),
+            "stryd" => Ok(Manufacturer::Stryd),
+            "icg" => Ok(Manufacturer::Icg),
+            "MiPulse" => Ok(Manufacturer::MiPulse),
+            "bsx_athletics" => Ok(Manufacturer::BsxAthletics),
+            "look" => Ok(Manufacturer::Look),
+            "campagnolo_srl" => Ok(Manufacturer::CampagnoloSrl),
+            "body_bike_smart" => Ok(Manufacturer::BodyBikeSmart),
+            "praxisworks" => Ok(Manufacturer::Praxisworks),
+            "limits_technology" => Ok(Manufacturer::LimitsTechnology),
+            "topaction_technology" => Ok(Manufacturer::TopactionTechnology),
+            "cosinuss" => Ok(Manufacturer::Cosinuss),
+            "fitcare" => Ok(Manufacturer::Fitcare),
+            "magene" => Ok(Manufacturer::Magene),
+            "giant_manufacturing_co" => Ok(Manufacturer::GiantManufacturingCo),
+            "tigrasport" => Ok(Manufacturer::Tigrasport),
+            "salutron" => Ok(Manufacturer::Salutron),
+            "technogym" => Ok(Manufacturer::Technogym),
+            "bryton_sensors" => Ok(Manufacturer::BrytonSensors),
+            "latitude_limited" => Ok(Manufacturer::LatitudeLimited),
+            "soaring_technology" => Ok(Manufacturer::SoaringTechnology),
+            "igpsport" => Ok(Manufacturer::Igpsport),
+            "thinkrider" => Ok(Manufacturer::Thinkrider),
+            "gopher_sport" => Ok(Manufacturer::GopherSport),
+            "waterrower" => Ok(Manufacturer::Waterrower),
+            "orangetheory" => Ok(Manufacturer::Orangetheory),
+            "inpeak" => Ok(Manufacturer::Inpeak),
+            "kinetic" => Ok(Manufacturer::Kinetic),
+            "johnson_health_tech" => Ok(Manufacturer::JohnsonHealthTech),
+            "polar_electro" => Ok(Manufacturer::PolarElectro),
+            "seesense" => Ok(Manufacturer::Seesense),
+            "nci_technology" => Ok(Manufacturer::NciTechnology),
+            "iqsquare" => Ok(Manufacturer::Iqsquare),
+            "leomo" => Ok(Manufacturer::Leomo),
+            "ifit_com" => Ok(Manufacturer::IfitCom),
+            "coros_byte" => Ok(Manufacturer::CorosByte),
+            "versa_design" => Ok(Manufacturer::VersaDesign),
+            "chileaf" => Ok(Manufacturer::Chileaf),
+            "cycplus" => Ok(Manufacturer::Cycplus),
+            "gravaa_byte" => Ok(Manufacturer::GravaaByte),
+            "sigeyi" => Ok(Manufacturer::Sigeyi),
+            "coospo" => Ok(Manufacturer::Coospo),
+            "geoid" => Ok(Manufacturer::Geoid),
+            "bosch" => Ok(Manufacturer::Bosch),
+            "kyto" => Ok(Manufacturer::Kyto),
+            "kinetic_sports" => Ok(Manufacturer::KineticSports),
+            "decathlon_byte" => Ok(Manufacturer::DecathlonByte),
+            "tq_systems" => Ok(Manufacturer::TqSystems),
+            "tag_heuer" => Ok(Manufacturer::TagHeuer),
+            "keiser_fitness" => Ok(Manufacturer::KeiserFitness),
+            "zwift_byte" => Ok(Manufacturer::ZwiftByte),
+            "porsche_ep" => Ok(Manufacturer::PorscheEp),
+            "development" => Ok(Manufacturer::Development),
+            "healthandlife" => Ok(Manufacturer::Healthandlife),
+            "lezyne" => Ok(Manufacturer::Lezyne),
+            "scribe_labs" => Ok(Manufacturer::ScribeLabs),
+            "zwift" => Ok(Manufacturer::Zwift),
+            "watteam" => Ok(Manufacturer::Watteam),
+            "recon" => Ok(Manufacturer::Recon),
+            "favero_electronics" => Ok(Manufacturer::FaveroElectronics),
+            "dynovelo" => Ok(Manufacturer::Dynovelo),
+            "strava" => Ok(Manufacturer::Strava),
+            "precor" => Ok(Manufacturer::Precor),
+            "bryton" => Ok(Manufacturer::Bryton),
+            "sram" => Ok(Manufacturer::Sram),
+            "navman" => Ok(Manufacturer::Navman),
+            "cobi" => Ok(Manufacturer::Cobi),
+            "spivi" => Ok(Manufacturer::Spivi),
+            "mio_magellan" => Ok(Manufacturer::MioMagellan),
+            "evesports" => Ok(Manufacturer::Evesports),
+            "sensitivus_gauge" => Ok(Manufacturer::SensitivusGauge),
+            "podoon" => Ok(Manufacturer::Podoon),
+            "life_time_fitness" => Ok(Manufacturer::LifeTimeFitness),
+            "falco_e_motors" => Ok(Manufacturer::FalcoEMotors),
+            "minoura" => Ok(Manufacturer::Minoura),
+            "cycliq" => Ok(Manufacturer::Cycliq),
+            "luxottica" => Ok(Manufacturer::Luxottica),
+            "trainer_road" => Ok(Manufacturer::TrainerRoad),
+            "the_sufferfest" => Ok(Manufacturer::TheSufferfest),
+            "fullspeedahead" => Ok(Manufacturer::Fullspeedahead),
+            "virtualtraining" => Ok(Manufacturer::Virtualtraining),
+            "feedbacksports" => Ok(Manufacturer::Feedbacksports),
+            "omata" => Ok(Manufacturer::Omata),
+            "vdo" => Ok(Manufacturer::Vdo),
+            "magneticdays" => Ok(Manufacturer::Magneticdays),
+            "hammerhead" => Ok(Manufacturer::Hammerhead),
+            "kinetic_by_kurt" => Ok(Manufacturer::KineticByKurt),
+            "shapelog" => Ok(Manufacturer::Shapelog),
+            "dabuziduo" => Ok(Manufacturer::Dabuziduo),
+            "jetblack" => Ok(Manufacturer::Jetblack),
+            "coros" => Ok(Manufacturer::Coros),
+            "virtugo" => Ok(Manufacturer::Virtugo),
+            "velosense" => Ok(Manufacturer::Velosense),
+            "cycligentinc" => Ok(Manufacturer::Cycligentinc),
+            "trailforks" => Ok(Manufacturer::Trailforks),
+            "mahle_ebikemotion" => Ok(Manufacturer::MahleEbikemotion),
+            "nurvv" => Ok(Manufacturer::Nurvv),
+            "microprogram" => Ok(Manufacturer::Microprogram),
+            "zone5cloud" => Ok(Manufacturer::Zone5cloud),
+            "greenteg" => Ok(Manufacturer::Greenteg),
+            "yamaha_motors" => Ok(Manufacturer::YamahaMotors),
+            "whoop" => Ok(Manufacturer::Whoop),
+            "gravaa" => Ok(Manufacturer::Gravaa),
+            "onelap" => Ok(Manufacturer::Onelap),
+            "monark_exercise" => Ok(Manufacturer::MonarkExercise),
+            "form" => Ok(Manufacturer::Form),
+            "decathlon" => Ok(Manufacturer::Decathlon),
+            "syncros" => Ok(Manufacturer::Syncros),
+            "heatup" => Ok(Manufacturer::Heatup),
+            "cannondale" => Ok(Manufacturer::Cannondale),
+            "true_fitness" => Ok(Manufacturer::TrueFitness),
+            "RGT_cycling" => Ok(Manufacturer::RGTCycling),
+            "vasa" => Ok(Manufacturer::Vasa),
+            "race_republic" => Ok(Manufacturer::RaceRepublic),
+            "fazua" => Ok(Manufacturer::Fazua),
+            "oreka_training" => Ok(Manufacturer::OrekaTraining),
+            "lsec" => Ok(Manufacturer::Lsec),
+            "lululemon_studio" => Ok(Manufacturer::LululemonStudio),
+            "shanyue" => Ok(Manufacturer::Shanyue),
+            "actigraphcorp" => Ok(Manufacturer::Actigraphcorp),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for Manufacturer {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -10092,6 +11570,418 @@ impl convert::From<i64> for GarminProduct {
         GarminProduct::from(value as u16)
     }
 }
+impl str::FromStr for GarminProduct {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "approach_g12_asia" => Ok(GarminProduct::ApproachG12Asia),
+            "axh01" => Ok(GarminProduct::Axh01),
+            "axb01" => Ok(GarminProduct::Axb01),
+            "axb02" => Ok(GarminProduct::Axb02),
+            "hrm2ss" => Ok(GarminProduct::Hrm2ss),
+            "dsi_alf02" => Ok(GarminProduct::DsiAlf02),
+            "hrm3ss" => Ok(GarminProduct::Hrm3ss),
+            "hrm_run_single_byte_product_id" => Ok(GarminProduct::HrmRunSingleByteProductId),
+            "bsm" => Ok(GarminProduct::Bsm),
+            "bcm" => Ok(GarminProduct::Bcm),
+            "axs01" => Ok(GarminProduct::Axs01),
+            "hrm_tri_single_byte_product_id" => Ok(GarminProduct::HrmTriSingleByteProductId),
+            "hrm4_run_single_byte_product_id" => Ok(GarminProduct::Hrm4RunSingleByteProductId),
+            "fr225_single_byte_product_id" => Ok(GarminProduct::Fr225SingleByteProductId),
+            "gen3_bsm_single_byte_product_id" => Ok(GarminProduct::Gen3BsmSingleByteProductId),
+            "gen3_bcm_single_byte_product_id" => Ok(GarminProduct::Gen3BcmSingleByteProductId),
+            "OHR" => Ok(GarminProduct::OHR),
+            "fr301_china" => Ok(GarminProduct::Fr301China),
+            "fr301_japan" => Ok(GarminProduct::Fr301Japan),
+            "fr301_korea" => Ok(GarminProduct::Fr301Korea),
+            "fr301_taiwan" => Ok(GarminProduct::Fr301Taiwan),
+            "fr405" => Ok(GarminProduct::Fr405),
+            "fr50" => Ok(GarminProduct::Fr50),
+            "fr405_japan" => Ok(GarminProduct::Fr405Japan),
+            "fr60" => Ok(GarminProduct::Fr60),
+            "dsi_alf01" => Ok(GarminProduct::DsiAlf01),
+            "fr310xt" => Ok(GarminProduct::Fr310xt),
+            "edge500" => Ok(GarminProduct::Edge500),
+            "fr110" => Ok(GarminProduct::Fr110),
+            "edge800" => Ok(GarminProduct::Edge800),
+            "edge500_taiwan" => Ok(GarminProduct::Edge500Taiwan),
+            "edge500_japan" => Ok(GarminProduct::Edge500Japan),
+            "chirp" => Ok(GarminProduct::Chirp),
+            "fr110_japan" => Ok(GarminProduct::Fr110Japan),
+            "edge200" => Ok(GarminProduct::Edge200),
+            "fr910xt" => Ok(GarminProduct::Fr910xt),
+            "edge800_taiwan" => Ok(GarminProduct::Edge800Taiwan),
+            "edge800_japan" => Ok(GarminProduct::Edge800Japan),
+            "alf04" => Ok(GarminProduct::Alf04),
+            "fr610" => Ok(GarminProduct::Fr610),
+            "fr210_japan" => Ok(GarminProduct::Fr210Japan),
+            "vector_ss" => Ok(GarminProduct::VectorSs),
+            "vector_cp" => Ok(GarminProduct::VectorCp),
+            "edge800_china" => Ok(GarminProduct::Edge800China),
+            "edge500_china" => Ok(GarminProduct::Edge500China),
+            "approach_g10" => Ok(GarminProduct::ApproachG10),
+            "fr610_japan" => Ok(GarminProduct::Fr610Japan),
+            "edge500_korea" => Ok(GarminProduct::Edge500Korea),
+            "fr70" => Ok(GarminProduct::Fr70),
+            "fr310xt_4t" => Ok(GarminProduct::Fr310xt4t),
+            "amx" => Ok(GarminProduct::Amx),
+            "fr10" => Ok(GarminProduct::Fr10),
+            "edge800_korea" => Ok(GarminProduct::Edge800Korea),
+            "swim" => Ok(GarminProduct::Swim),
+            "fr910xt_china" => Ok(GarminProduct::Fr910xtChina),
+            "fenix" => Ok(GarminProduct::Fenix),
+            "edge200_taiwan" => Ok(GarminProduct::Edge200Taiwan),
+            "edge510" => Ok(GarminProduct::Edge510),
+            "edge810" => Ok(GarminProduct::Edge810),
+            "tempe" => Ok(GarminProduct::Tempe),
+            "fr910xt_japan" => Ok(GarminProduct::Fr910xtJapan),
+            "fr620" => Ok(GarminProduct::Fr620),
+            "fr220" => Ok(GarminProduct::Fr220),
+            "fr910xt_korea" => Ok(GarminProduct::Fr910xtKorea),
+            "fr10_japan" => Ok(GarminProduct::Fr10Japan),
+            "edge810_japan" => Ok(GarminProduct::Edge810Japan),
+            "virb_elite" => Ok(GarminProduct::VirbElite),
+            "edge_touring" => Ok(GarminProduct::EdgeTouring),
+            "edge510_japan" => Ok(GarminProduct::Edge510Japan),
+            "hrm_tri" => Ok(GarminProduct::HrmTri),
+            "hrm_run" => Ok(GarminProduct::HrmRun),
+            "fr920xt" => Ok(GarminProduct::Fr920xt),
+            "edge510_asia" => Ok(GarminProduct::Edge510Asia),
+            "edge810_china" => Ok(GarminProduct::Edge810China),
+            "edge810_taiwan" => Ok(GarminProduct::Edge810Taiwan),
+            "edge1000" => Ok(GarminProduct::Edge1000),
+            "vivo_fit" => Ok(GarminProduct::VivoFit),
+            "virb_remote" => Ok(GarminProduct::VirbRemote),
+            "vivo_ki" => Ok(GarminProduct::VivoKi),
+            "fr15" => Ok(GarminProduct::Fr15),
+            "vivo_active" => Ok(GarminProduct::VivoActive),
+            "edge510_korea" => Ok(GarminProduct::Edge510Korea),
+            "fr620_japan" => Ok(GarminProduct::Fr620Japan),
+            "fr620_china" => Ok(GarminProduct::Fr620China),
+            "fr220_japan" => Ok(GarminProduct::Fr220Japan),
+            "fr220_china" => Ok(GarminProduct::Fr220China),
+            "approach_s6" => Ok(GarminProduct::ApproachS6),
+            "vivo_smart" => Ok(GarminProduct::VivoSmart),
+            "fenix2" => Ok(GarminProduct::Fenix2),
+            "epix" => Ok(GarminProduct::Epix),
+            "fenix3" => Ok(GarminProduct::Fenix3),
+            "edge1000_taiwan" => Ok(GarminProduct::Edge1000Taiwan),
+            "edge1000_japan" => Ok(GarminProduct::Edge1000Japan),
+            "fr15_japan" => Ok(GarminProduct::Fr15Japan),
+            "edge520" => Ok(GarminProduct::Edge520),
+            "edge1000_china" => Ok(GarminProduct::Edge1000China),
+            "fr620_russia" => Ok(GarminProduct::Fr620Russia),
+            "fr220_russia" => Ok(GarminProduct::Fr220Russia),
+            "vector_s" => Ok(GarminProduct::VectorS),
+            "edge1000_korea" => Ok(GarminProduct::Edge1000Korea),
+            "fr920xt_taiwan" => Ok(GarminProduct::Fr920xtTaiwan),
+            "fr920xt_china" => Ok(GarminProduct::Fr920xtChina),
+            "fr920xt_japan" => Ok(GarminProduct::Fr920xtJapan),
+            "virbx" => Ok(GarminProduct::Virbx),
+            "vivo_smart_apac" => Ok(GarminProduct::VivoSmartApac),
+            "etrex_touch" => Ok(GarminProduct::EtrexTouch),
+            "edge25" => Ok(GarminProduct::Edge25),
+            "fr25" => Ok(GarminProduct::Fr25),
+            "vivo_fit2" => Ok(GarminProduct::VivoFit2),
+            "fr225" => Ok(GarminProduct::Fr225),
+            "fr630" => Ok(GarminProduct::Fr630),
+            "fr230" => Ok(GarminProduct::Fr230),
+            "fr735xt" => Ok(GarminProduct::Fr735xt),
+            "vivo_active_apac" => Ok(GarminProduct::VivoActiveApac),
+            "vector_2" => Ok(GarminProduct::Vector2),
+            "vector_2s" => Ok(GarminProduct::Vector2s),
+            "virbxe" => Ok(GarminProduct::Virbxe),
+            "fr620_taiwan" => Ok(GarminProduct::Fr620Taiwan),
+            "fr220_taiwan" => Ok(GarminProduct::Fr220Taiwan),
+            "truswing" => Ok(GarminProduct::Truswing),
+            "d2airvenu" => Ok(GarminProduct::D2airvenu),
+            "fenix3_china" => Ok(GarminProduct::Fenix3China),
+            "fenix3_twn" => Ok(GarminProduct::Fenix3Twn),
+            "varia_headlight" => Ok(GarminProduct::VariaHeadlight),
+            "varia_taillight_old" => Ok(GarminProduct::VariaTaillightOld),
+            "edge_explore_1000" => Ok(GarminProduct::EdgeExplore1000),
+            "fr225_asia" => Ok(GarminProduct::Fr225Asia),
+            "varia_radar_taillight" => Ok(GarminProduct::VariaRadarTaillight),
+            "varia_radar_display" => Ok(GarminProduct::VariaRadarDisplay),
+            "edge20" => Ok(GarminProduct::Edge20),
+            "edge520_asia" => Ok(GarminProduct::Edge520Asia),
+            "edge520_japan" => Ok(GarminProduct::Edge520Japan),
+            "d2_bravo" => Ok(GarminProduct::D2Bravo),
+            "approach_s20" => Ok(GarminProduct::ApproachS20),
+            "vivo_smart2" => Ok(GarminProduct::VivoSmart2),
+            "edge1000_thai" => Ok(GarminProduct::Edge1000Thai),
+            "varia_remote" => Ok(GarminProduct::VariaRemote),
+            "edge25_asia" => Ok(GarminProduct::Edge25Asia),
+            "edge25_jpn" => Ok(GarminProduct::Edge25Jpn),
+            "edge20_asia" => Ok(GarminProduct::Edge20Asia),
+            "approach_x40" => Ok(GarminProduct::ApproachX40),
+            "fenix3_japan" => Ok(GarminProduct::Fenix3Japan),
+            "vivo_smart_emea" => Ok(GarminProduct::VivoSmartEmea),
+            "fr630_asia" => Ok(GarminProduct::Fr630Asia),
+            "fr630_jpn" => Ok(GarminProduct::Fr630Jpn),
+            "fr230_jpn" => Ok(GarminProduct::Fr230Jpn),
+            "hrm4_run" => Ok(GarminProduct::Hrm4Run),
+            "epix_japan" => Ok(GarminProduct::EpixJapan),
+            "vivo_active_hr" => Ok(GarminProduct::VivoActiveHr),
+            "approach_g12" => Ok(GarminProduct::ApproachG12),
+            "vivo_smart_gps_hr" => Ok(GarminProduct::VivoSmartGpsHr),
+            "vivo_smart_hr" => Ok(GarminProduct::VivoSmartHr),
+            "vivo_smart_hr_asia" => Ok(GarminProduct::VivoSmartHrAsia),
+            "vivo_smart_gps_hr_asia" => Ok(GarminProduct::VivoSmartGpsHrAsia),
+            "vivo_move" => Ok(GarminProduct::VivoMove),
+            "varia_taillight" => Ok(GarminProduct::VariaTaillight),
+            "fr235_asia" => Ok(GarminProduct::Fr235Asia),
+            "fr235_japan" => Ok(GarminProduct::Fr235Japan),
+            "varia_vision" => Ok(GarminProduct::VariaVision),
+            "vivo_fit3" => Ok(GarminProduct::VivoFit3),
+            "fenix3_korea" => Ok(GarminProduct::Fenix3Korea),
+            "fenix3_sea" => Ok(GarminProduct::Fenix3Sea),
+            "fenix3_hr" => Ok(GarminProduct::Fenix3Hr),
+            "virb_ultra_30" => Ok(GarminProduct::VirbUltra30),
+            "index_smart_scale" => Ok(GarminProduct::IndexSmartScale),
+            "fr235" => Ok(GarminProduct::Fr235),
+            "fenix3_chronos" => Ok(GarminProduct::Fenix3Chronos),
+            "oregon7xx" => Ok(GarminProduct::Oregon7xx),
+            "rino7xx" => Ok(GarminProduct::Rino7xx),
+            "epix_korea" => Ok(GarminProduct::EpixKorea),
+            "fenix3_hr_chn" => Ok(GarminProduct::Fenix3HrChn),
+            "fenix3_hr_twn" => Ok(GarminProduct::Fenix3HrTwn),
+            "fenix3_hr_jpn" => Ok(GarminProduct::Fenix3HrJpn),
+            "fenix3_hr_sea" => Ok(GarminProduct::Fenix3HrSea),
+            "fenix3_hr_kor" => Ok(GarminProduct::Fenix3HrKor),
+            "nautix" => Ok(GarminProduct::Nautix),
+            "vivo_active_hr_apac" => Ok(GarminProduct::VivoActiveHrApac),
+            "fr35" => Ok(GarminProduct::Fr35),
+            "oregon7xx_ww" => Ok(GarminProduct::Oregon7xxWw),
+            "edge_820" => Ok(GarminProduct::Edge820),
+            "edge_explore_820" => Ok(GarminProduct::EdgeExplore820),
+            "fr735xt_apac" => Ok(GarminProduct::Fr735xtApac),
+            "fr735xt_japan" => Ok(GarminProduct::Fr735xtJapan),
+            "fenix5s" => Ok(GarminProduct::Fenix5s),
+            "d2_bravo_titanium" => Ok(GarminProduct::D2BravoTitanium),
+            "varia_ut800" => Ok(GarminProduct::VariaUt800),
+            "running_dynamics_pod" => Ok(GarminProduct::RunningDynamicsPod),
+            "edge_820_china" => Ok(GarminProduct::Edge820China),
+            "edge_820_japan" => Ok(GarminProduct::Edge820Japan),
+            "fenix5x" => Ok(GarminProduct::Fenix5x),
+            "vivo_fit_jr" => Ok(GarminProduct::VivoFitJr),
+            "vivo_smart3" => Ok(GarminProduct::VivoSmart3),
+            "vivo_sport" => Ok(GarminProduct::VivoSport),
+            "edge_820_taiwan" => Ok(GarminProduct::Edge820Taiwan),
+            "edge_820_korea" => Ok(GarminProduct::Edge820Korea),
+            "edge_820_sea" => Ok(GarminProduct::Edge820Sea),
+            "fr35_hebrew" => Ok(GarminProduct::Fr35Hebrew),
+            "approach_s60" => Ok(GarminProduct::ApproachS60),
+            "fr35_apac" => Ok(GarminProduct::Fr35Apac),
+            "fr35_japan" => Ok(GarminProduct::Fr35Japan),
+            "fenix3_chronos_asia" => Ok(GarminProduct::Fenix3ChronosAsia),
+            "virb_360" => Ok(GarminProduct::Virb360),
+            "fr935" => Ok(GarminProduct::Fr935),
+            "fenix5" => Ok(GarminProduct::Fenix5),
+            "vivoactive3" => Ok(GarminProduct::Vivoactive3),
+            "edge_1030" => Ok(GarminProduct::Edge1030),
+            "fr35_sea" => Ok(GarminProduct::Fr35Sea),
+            "fr235_china_nfc" => Ok(GarminProduct::Fr235ChinaNfc),
+            "foretrex_601_701" => Ok(GarminProduct::Foretrex601701),
+            "vivo_move_hr" => Ok(GarminProduct::VivoMoveHr),
+            "vector_3" => Ok(GarminProduct::Vector3),
+            "fenix5_asia" => Ok(GarminProduct::Fenix5Asia),
+            "fenix5s_asia" => Ok(GarminProduct::Fenix5sAsia),
+            "fenix5x_asia" => Ok(GarminProduct::Fenix5xAsia),
+            "approach_z80" => Ok(GarminProduct::ApproachZ80),
+            "fr35_korea" => Ok(GarminProduct::Fr35Korea),
+            "d2charlie" => Ok(GarminProduct::D2charlie),
+            "vivo_smart3_apac" => Ok(GarminProduct::VivoSmart3Apac),
+            "vivo_sport_apac" => Ok(GarminProduct::VivoSportApac),
+            "fr935_asia" => Ok(GarminProduct::Fr935Asia),
+            "descent" => Ok(GarminProduct::Descent),
+            "vivo_fit4" => Ok(GarminProduct::VivoFit4),
+            "fr645" => Ok(GarminProduct::Fr645),
+            "fr645m" => Ok(GarminProduct::Fr645m),
+            "fr30" => Ok(GarminProduct::Fr30),
+            "fenix5s_plus" => Ok(GarminProduct::Fenix5sPlus),
+            "Edge_130" => Ok(GarminProduct::Edge130),
+            "edge_1030_asia" => Ok(GarminProduct::Edge1030Asia),
+            "vivosmart_4" => Ok(GarminProduct::Vivosmart4),
+            "vivo_move_hr_asia" => Ok(GarminProduct::VivoMoveHrAsia),
+            "approach_x10" => Ok(GarminProduct::ApproachX10),
+            "fr30_asia" => Ok(GarminProduct::Fr30Asia),
+            "vivoactive3m_w" => Ok(GarminProduct::Vivoactive3mW),
+            "fr645_asia" => Ok(GarminProduct::Fr645Asia),
+            "fr645m_asia" => Ok(GarminProduct::Fr645mAsia),
+            "edge_explore" => Ok(GarminProduct::EdgeExplore),
+            "gpsmap66" => Ok(GarminProduct::Gpsmap66),
+            "approach_s10" => Ok(GarminProduct::ApproachS10),
+            "vivoactive3m_l" => Ok(GarminProduct::Vivoactive3mL),
+            "approach_g80" => Ok(GarminProduct::ApproachG80),
+            "edge_130_asia" => Ok(GarminProduct::Edge130Asia),
+            "edge_1030_bontrager" => Ok(GarminProduct::Edge1030Bontrager),
+            "fenix5_plus" => Ok(GarminProduct::Fenix5Plus),
+            "fenix5x_plus" => Ok(GarminProduct::Fenix5xPlus),
+            "edge_520_plus" => Ok(GarminProduct::Edge520Plus),
+            "fr945" => Ok(GarminProduct::Fr945),
+            "edge_530" => Ok(GarminProduct::Edge530),
+            "edge_830" => Ok(GarminProduct::Edge830),
+            "instinct_esports" => Ok(GarminProduct::InstinctEsports),
+            "fenix5s_plus_apac" => Ok(GarminProduct::Fenix5sPlusApac),
+            "fenix5x_plus_apac" => Ok(GarminProduct::Fenix5xPlusApac),
+            "edge_520_plus_apac" => Ok(GarminProduct::Edge520PlusApac),
+            "fr235l_asia" => Ok(GarminProduct::Fr235lAsia),
+            "fr245_asia" => Ok(GarminProduct::Fr245Asia),
+            "vivo_active3m_apac" => Ok(GarminProduct::VivoActive3mApac),
+            "gen3_bsm" => Ok(GarminProduct::Gen3Bsm),
+            "gen3_bcm" => Ok(GarminProduct::Gen3Bcm),
+            "vivo_smart4_asia" => Ok(GarminProduct::VivoSmart4Asia),
+            "vivoactive4_small" => Ok(GarminProduct::Vivoactive4Small),
+            "vivoactive4_large" => Ok(GarminProduct::Vivoactive4Large),
+            "venu" => Ok(GarminProduct::Venu),
+            "marq_driver" => Ok(GarminProduct::MarqDriver),
+            "marq_aviator" => Ok(GarminProduct::MarqAviator),
+            "marq_captain" => Ok(GarminProduct::MarqCaptain),
+            "marq_commander" => Ok(GarminProduct::MarqCommander),
+            "marq_expedition" => Ok(GarminProduct::MarqExpedition),
+            "marq_athlete" => Ok(GarminProduct::MarqAthlete),
+            "descent_mk2" => Ok(GarminProduct::DescentMk2),
+            "gpsmap66i" => Ok(GarminProduct::Gpsmap66i),
+            "fenix6S_sport" => Ok(GarminProduct::Fenix6SSport),
+            "fenix6S" => Ok(GarminProduct::Fenix6S),
+            "fenix6_sport" => Ok(GarminProduct::Fenix6Sport),
+            "fenix6" => Ok(GarminProduct::Fenix6),
+            "fenix6x" => Ok(GarminProduct::Fenix6x),
+            "hrm_dual" => Ok(GarminProduct::HrmDual),
+            "hrm_pro" => Ok(GarminProduct::HrmPro),
+            "vivo_move3_premium" => Ok(GarminProduct::VivoMove3Premium),
+            "approach_s40" => Ok(GarminProduct::ApproachS40),
+            "fr245m_asia" => Ok(GarminProduct::Fr245mAsia),
+            "edge_530_apac" => Ok(GarminProduct::Edge530Apac),
+            "edge_830_apac" => Ok(GarminProduct::Edge830Apac),
+            "vivo_move3" => Ok(GarminProduct::VivoMove3),
+            "vivo_active4_small_asia" => Ok(GarminProduct::VivoActive4SmallAsia),
+            "vivo_active4_large_asia" => Ok(GarminProduct::VivoActive4LargeAsia),
+            "vivo_active4_oled_asia" => Ok(GarminProduct::VivoActive4OledAsia),
+            "swim2" => Ok(GarminProduct::Swim2),
+            "marq_driver_asia" => Ok(GarminProduct::MarqDriverAsia),
+            "marq_aviator_asia" => Ok(GarminProduct::MarqAviatorAsia),
+            "vivo_move3_asia" => Ok(GarminProduct::VivoMove3Asia),
+            "fr945_asia" => Ok(GarminProduct::Fr945Asia),
+            "vivo_active3t_chn" => Ok(GarminProduct::VivoActive3tChn),
+            "marq_captain_asia" => Ok(GarminProduct::MarqCaptainAsia),
+            "marq_commander_asia" => Ok(GarminProduct::MarqCommanderAsia),
+            "marq_expedition_asia" => Ok(GarminProduct::MarqExpeditionAsia),
+            "marq_athlete_asia" => Ok(GarminProduct::MarqAthleteAsia),
+            "instinct_solar" => Ok(GarminProduct::InstinctSolar),
+            "fr45_asia" => Ok(GarminProduct::Fr45Asia),
+            "vivoactive3_daimler" => Ok(GarminProduct::Vivoactive3Daimler),
+            "legacy_rey" => Ok(GarminProduct::LegacyRey),
+            "legacy_darth_vader" => Ok(GarminProduct::LegacyDarthVader),
+            "legacy_captain_marvel" => Ok(GarminProduct::LegacyCaptainMarvel),
+            "legacy_first_avenger" => Ok(GarminProduct::LegacyFirstAvenger),
+            "fenix6s_sport_asia" => Ok(GarminProduct::Fenix6sSportAsia),
+            "fenix6s_asia" => Ok(GarminProduct::Fenix6sAsia),
+            "fenix6_sport_asia" => Ok(GarminProduct::Fenix6SportAsia),
+            "fenix6_asia" => Ok(GarminProduct::Fenix6Asia),
+            "fenix6x_asia" => Ok(GarminProduct::Fenix6xAsia),
+            "legacy_captain_marvel_asia" => Ok(GarminProduct::LegacyCaptainMarvelAsia),
+            "legacy_first_avenger_asia" => Ok(GarminProduct::LegacyFirstAvengerAsia),
+            "legacy_rey_asia" => Ok(GarminProduct::LegacyReyAsia),
+            "legacy_darth_vader_asia" => Ok(GarminProduct::LegacyDarthVaderAsia),
+            "descent_mk2s" => Ok(GarminProduct::DescentMk2s),
+            "edge_130_plus" => Ok(GarminProduct::Edge130Plus),
+            "edge_1030_plus" => Ok(GarminProduct::Edge1030Plus),
+            "rally_200" => Ok(GarminProduct::Rally200),
+            "fr745" => Ok(GarminProduct::Fr745),
+            "venusq" => Ok(GarminProduct::Venusq),
+            "lily" => Ok(GarminProduct::Lily),
+            "marq_adventurer" => Ok(GarminProduct::MarqAdventurer),
+            "enduro" => Ok(GarminProduct::Enduro),
+            "swim2_apac" => Ok(GarminProduct::Swim2Apac),
+            "marq_adventurer_asia" => Ok(GarminProduct::MarqAdventurerAsia),
+            "fr945_lte" => Ok(GarminProduct::Fr945Lte),
+            "descent_mk2_asia" => Ok(GarminProduct::DescentMk2Asia),
+            "venu2" => Ok(GarminProduct::Venu2),
+            "venu2s" => Ok(GarminProduct::Venu2s),
+            "venu_daimler_asia" => Ok(GarminProduct::VenuDaimlerAsia),
+            "marq_golfer" => Ok(GarminProduct::MarqGolfer),
+            "venu_daimler" => Ok(GarminProduct::VenuDaimler),
+            "fr745_asia" => Ok(GarminProduct::Fr745Asia),
+            "lily_asia" => Ok(GarminProduct::LilyAsia),
+            "edge_1030_plus_asia" => Ok(GarminProduct::Edge1030PlusAsia),
+            "edge_130_plus_asia" => Ok(GarminProduct::Edge130PlusAsia),
+            "approach_s12" => Ok(GarminProduct::ApproachS12),
+            "venusq_asia" => Ok(GarminProduct::VenusqAsia),
+            "edge_1040" => Ok(GarminProduct::Edge1040),
+            "marq_golfer_asia" => Ok(GarminProduct::MarqGolferAsia),
+            "venu2_plus" => Ok(GarminProduct::Venu2Plus),
+            "fr55" => Ok(GarminProduct::Fr55),
+            "enduro_asia" => Ok(GarminProduct::EnduroAsia),
+            "instinct_2" => Ok(GarminProduct::Instinct2),
+            "fenix7s" => Ok(GarminProduct::Fenix7s),
+            "fenix7" => Ok(GarminProduct::Fenix7),
+            "fenix7x" => Ok(GarminProduct::Fenix7x),
+            "fenix7s_apac" => Ok(GarminProduct::Fenix7sApac),
+            "fenix7_apac" => Ok(GarminProduct::Fenix7Apac),
+            "fenix7x_apac" => Ok(GarminProduct::Fenix7xApac),
+            "descent_mk2s_asia" => Ok(GarminProduct::DescentMk2sAsia),
+            "approach_s42" => Ok(GarminProduct::ApproachS42),
+            "epix_gen2" => Ok(GarminProduct::EpixGen2),
+            "epix_gen2_apac" => Ok(GarminProduct::EpixGen2Apac),
+            "venu2s_asia" => Ok(GarminProduct::Venu2sAsia),
+            "venu2_asia" => Ok(GarminProduct::Venu2Asia),
+            "fr945_lte_asia" => Ok(GarminProduct::Fr945LteAsia),
+            "vivo_move_sport" => Ok(GarminProduct::VivoMoveSport),
+            "vivomove_trend" => Ok(GarminProduct::VivomoveTrend),
+            "approach_S12_asia" => Ok(GarminProduct::ApproachS12Asia),
+            "fr255_music" => Ok(GarminProduct::Fr255Music),
+            "fr255_small_music" => Ok(GarminProduct::Fr255SmallMusic),
+            "fr255" => Ok(GarminProduct::Fr255),
+            "fr255_small" => Ok(GarminProduct::Fr255Small),
+            "approach_s42_asia" => Ok(GarminProduct::ApproachS42Asia),
+            "descent_g1" => Ok(GarminProduct::DescentG1),
+            "venu2_plus_asia" => Ok(GarminProduct::Venu2PlusAsia),
+            "fr955" => Ok(GarminProduct::Fr955),
+            "fr55_asia" => Ok(GarminProduct::Fr55Asia),
+            "vivosmart_5" => Ok(GarminProduct::Vivosmart5),
+            "instinct_2_asia" => Ok(GarminProduct::Instinct2Asia),
+            "marq_gen2" => Ok(GarminProduct::MarqGen2),
+            "venusq2" => Ok(GarminProduct::Venusq2),
+            "venusq2music" => Ok(GarminProduct::Venusq2music),
+            "marq_gen2_aviator" => Ok(GarminProduct::MarqGen2Aviator),
+            "d2_air_x10" => Ok(GarminProduct::D2AirX10),
+            "hrm_pro_plus" => Ok(GarminProduct::HrmProPlus),
+            "descent_g1_asia" => Ok(GarminProduct::DescentG1Asia),
+            "tactix7" => Ok(GarminProduct::Tactix7),
+            "instinct_crossover" => Ok(GarminProduct::InstinctCrossover),
+            "edge_explore2" => Ok(GarminProduct::EdgeExplore2),
+            "tacx_neo_smart" => Ok(GarminProduct::TacxNeoSmart),
+            "tacx_neo2_smart" => Ok(GarminProduct::TacxNeo2Smart),
+            "tacx_neo2_t_smart" => Ok(GarminProduct::TacxNeo2TSmart),
+            "tacx_neo_smart_bike" => Ok(GarminProduct::TacxNeoSmartBike),
+            "tacx_satori_smart" => Ok(GarminProduct::TacxSatoriSmart),
+            "tacx_flow_smart" => Ok(GarminProduct::TacxFlowSmart),
+            "tacx_vortex_smart" => Ok(GarminProduct::TacxVortexSmart),
+            "tacx_bushido_smart" => Ok(GarminProduct::TacxBushidoSmart),
+            "tacx_genius_smart" => Ok(GarminProduct::TacxGeniusSmart),
+            "tacx_flux_flux_s_smart" => Ok(GarminProduct::TacxFluxFluxSSmart),
+            "tacx_flux2_smart" => Ok(GarminProduct::TacxFlux2Smart),
+            "tacx_magnum" => Ok(GarminProduct::TacxMagnum),
+            "edge_1040_asia" => Ok(GarminProduct::Edge1040Asia),
+            "enduro2" => Ok(GarminProduct::Enduro2),
+            "sdm4" => Ok(GarminProduct::Sdm4),
+            "edge_remote" => Ok(GarminProduct::EdgeRemote),
+            "training_center" => Ok(GarminProduct::TrainingCenter),
+            "tacx_training_app_win" => Ok(GarminProduct::TacxTrainingAppWin),
+            "tacx_training_app_mac" => Ok(GarminProduct::TacxTrainingAppMac),
+            "tacx_training_app_mac_catalyst" => Ok(GarminProduct::TacxTrainingAppMacCatalyst),
+            "tacx_training_app_android" => Ok(GarminProduct::TacxTrainingAppAndroid),
+            "tacx_training_app_ios" => Ok(GarminProduct::TacxTrainingAppIos),
+            "tacx_training_app_legacy" => Ok(GarminProduct::TacxTrainingAppLegacy),
+            "connectiq_simulator" => Ok(GarminProduct::ConnectiqSimulator),
+            "android_antplus_plugin" => Ok(GarminProduct::AndroidAntplusPlugin),
+            "connect" => Ok(GarminProduct::Connect),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for GarminProduct {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -10266,6 +12156,39 @@ impl convert::From<i64> for AntplusDeviceType {
         AntplusDeviceType::from(value as u8)
     }
 }
+impl str::FromStr for AntplusDeviceType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "antfs" => Ok(AntplusDeviceType::Antfs),
+            "bike_power" => Ok(AntplusDeviceType::BikePower),
+            "environment_sensor_legacy" => Ok(AntplusDeviceType::EnvironmentSensorLegacy),
+            "multi_sport_speed_distance" => Ok(AntplusDeviceType::MultiSportSpeedDistance),
+            "control" => Ok(AntplusDeviceType::Control),
+            "fitness_equipment" => Ok(AntplusDeviceType::FitnessEquipment),
+            "blood_pressure" => Ok(AntplusDeviceType::BloodPressure),
+            "geocache_node" => Ok(AntplusDeviceType::GeocacheNode),
+            "light_electric_vehicle" => Ok(AntplusDeviceType::LightElectricVehicle),
+            "env_sensor" => Ok(AntplusDeviceType::EnvSensor),
+            "racquet" => Ok(AntplusDeviceType::Racquet),
+            "control_hub" => Ok(AntplusDeviceType::ControlHub),
+            "muscle_oxygen" => Ok(AntplusDeviceType::MuscleOxygen),
+            "shifting" => Ok(AntplusDeviceType::Shifting),
+            "bike_light_main" => Ok(AntplusDeviceType::BikeLightMain),
+            "bike_light_shared" => Ok(AntplusDeviceType::BikeLightShared),
+            "exd" => Ok(AntplusDeviceType::Exd),
+            "bike_radar" => Ok(AntplusDeviceType::BikeRadar),
+            "bike_aero" => Ok(AntplusDeviceType::BikeAero),
+            "weight_scale" => Ok(AntplusDeviceType::WeightScale),
+            "heart_rate" => Ok(AntplusDeviceType::HeartRate),
+            "bike_speed_cadence" => Ok(AntplusDeviceType::BikeSpeedCadence),
+            "bike_cadence" => Ok(AntplusDeviceType::BikeCadence),
+            "bike_speed" => Ok(AntplusDeviceType::BikeSpeed),
+            "stride_speed_distance" => Ok(AntplusDeviceType::StrideSpeedDistance),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for AntplusDeviceType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -10335,6 +12258,18 @@ impl convert::From<i64> for AntNetwork {
         AntNetwork::from(value as u8)
     }
 }
+impl str::FromStr for AntNetwork {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "public" => Ok(AntNetwork::Public),
+            "antplus" => Ok(AntNetwork::Antplus),
+            "antfs" => Ok(AntNetwork::Antfs),
+            "private" => Ok(AntNetwork::Private),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for AntNetwork {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -10459,6 +12394,28 @@ impl convert::From<i64> for WorkoutCapabilities {
         WorkoutCapabilities::from(value as u32)
     }
 }
+impl str::FromStr for WorkoutCapabilities {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "interval" => Ok(WorkoutCapabilities::Interval),
+            "custom" => Ok(WorkoutCapabilities::Custom),
+            "fitness_equipment" => Ok(WorkoutCapabilities::FitnessEquipment),
+            "firstbeat" => Ok(WorkoutCapabilities::Firstbeat),
+            "new_leaf" => Ok(WorkoutCapabilities::NewLeaf),
+            "tcx" => Ok(WorkoutCapabilities::Tcx),
+            "speed" => Ok(WorkoutCapabilities::Speed),
+            "heart_rate" => Ok(WorkoutCapabilities::HeartRate),
+            "distance" => Ok(WorkoutCapabilities::Distance),
+            "cadence" => Ok(WorkoutCapabilities::Cadence),
+            "power" => Ok(WorkoutCapabilities::Power),
+            "grade" => Ok(WorkoutCapabilities::Grade),
+            "resistance" => Ok(WorkoutCapabilities::Resistance),
+            "protected" => Ok(WorkoutCapabilities::Protected),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for WorkoutCapabilities {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -10543,6 +12500,21 @@ impl convert::From<i64> for BatteryStatus {
         BatteryStatus::from(value as u8)
     }
 }
+impl str::FromStr for BatteryStatus {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "new" => Ok(BatteryStatus::New),
+            "good" => Ok(BatteryStatus::Good),
+            "ok" => Ok(BatteryStatus::Ok),
+            "low" => Ok(BatteryStatus::Low),
+            "critical" => Ok(BatteryStatus::Critical),
+            "charging" => Ok(BatteryStatus::Charging),
+            "unknown" => Ok(BatteryStatus::Unknown),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for BatteryStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -10602,6 +12574,16 @@ impl convert::From<i64> for HrType {
         HrType::from(value as u8)
     }
 }
+impl str::FromStr for HrType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "normal" => Ok(HrType::Normal),
+            "irregular" => Ok(HrType::Irregular),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for HrType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -10709,6 +12691,26 @@ impl convert::From<i64> for CourseCapabilities {
         CourseCapabilities::from(value as u32)
     }
 }
+impl str::FromStr for CourseCapabilities {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "processed" => Ok(CourseCapabilities::Processed),
+            "valid" => Ok(CourseCapabilities::Valid),
+            "time" => Ok(CourseCapabilities::Time),
+            "distance" => Ok(CourseCapabilities::Distance),
+            "position" => Ok(CourseCapabilities::Position),
+            "heart_rate" => Ok(CourseCapabilities::HeartRate),
+            "power" => Ok(CourseCapabilities::Power),
+            "cadence" => Ok(CourseCapabilities::Cadence),
+            "training" => Ok(CourseCapabilities::Training),
+            "navigation" => Ok(CourseCapabilities::Navigation),
+            "bikeway" => Ok(CourseCapabilities::Bikeway),
+            "aviation" => Ok(CourseCapabilities::Aviation),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for CourseCapabilities {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -10763,6 +12765,15 @@ impl convert::From<i64> for Weight {
         Weight::from(value as u16)
     }
 }
+impl str::FromStr for Weight {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "calculating" => Ok(Weight::Calculating),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for Weight {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -10818,6 +12829,15 @@ impl convert::From<i64> for WorkoutHr {
         WorkoutHr::from(value as u32)
     }
 }
+impl str::FromStr for WorkoutHr {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "bpm_offset" => Ok(WorkoutHr::BpmOffset),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for WorkoutHr {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -10873,6 +12893,15 @@ impl convert::From<i64> for WorkoutPower {
         WorkoutPower::from(value as u32)
     }
 }
+impl str::FromStr for WorkoutPower {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "watts_offset" => Ok(WorkoutPower::WattsOffset),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for WorkoutPower {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -10947,6 +12976,19 @@ impl convert::From<i64> for BpStatus {
         BpStatus::from(value as u8)
     }
 }
+impl str::FromStr for BpStatus {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "no_error" => Ok(BpStatus::NoError),
+            "error_incomplete_data" => Ok(BpStatus::ErrorIncompleteData),
+            "error_no_measurement" => Ok(BpStatus::ErrorNoMeasurement),
+            "error_data_out_of_range" => Ok(BpStatus::ErrorDataOutOfRange),
+            "error_irregular_heart_rate" => Ok(BpStatus::ErrorIrregularHeartRate),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for BpStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -11023,6 +13065,20 @@ impl convert::From<i64> for UserLocalId {
         UserLocalId::from(value as u16)
     }
 }
+impl str::FromStr for UserLocalId {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "local_min" => Ok(UserLocalId::LocalMin),
+            "local_max" => Ok(UserLocalId::LocalMax),
+            "stationary_min" => Ok(UserLocalId::StationaryMin),
+            "stationary_max" => Ok(UserLocalId::StationaryMax),
+            "portable_min" => Ok(UserLocalId::PortableMin),
+            "portable_max" => Ok(UserLocalId::PortableMax),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for UserLocalId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -11108,6 +13164,21 @@ impl convert::From<i64> for SwimStroke {
         SwimStroke::from(value as u8)
     }
 }
+impl str::FromStr for SwimStroke {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "freestyle" => Ok(SwimStroke::Freestyle),
+            "backstroke" => Ok(SwimStroke::Backstroke),
+            "breaststroke" => Ok(SwimStroke::Breaststroke),
+            "butterfly" => Ok(SwimStroke::Butterfly),
+            "drill" => Ok(SwimStroke::Drill),
+            "mixed" => Ok(SwimStroke::Mixed),
+            "im" => Ok(SwimStroke::Im),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SwimStroke {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -11201,6 +13272,23 @@ impl convert::From<i64> for ActivityType {
         ActivityType::from(value as u8)
     }
 }
+impl str::FromStr for ActivityType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "generic" => Ok(ActivityType::Generic),
+            "running" => Ok(ActivityType::Running),
+            "cycling" => Ok(ActivityType::Cycling),
+            "transition" => Ok(ActivityType::Transition),
+            "fitness_equipment" => Ok(ActivityType::FitnessEquipment),
+            "swimming" => Ok(ActivityType::Swimming),
+            "walking" => Ok(ActivityType::Walking),
+            "sedentary" => Ok(ActivityType::Sedentary),
+            "all" => Ok(ActivityType::All),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for ActivityType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -11365,6 +13453,34 @@ impl convert::From<i64> for ActivitySubtype {
         ActivitySubtype::from(value as u8)
     }
 }
+impl str::FromStr for ActivitySubtype {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "generic" => Ok(ActivitySubtype::Generic),
+            "treadmill" => Ok(ActivitySubtype::Treadmill),
+            "street" => Ok(ActivitySubtype::Street),
+            "trail" => Ok(ActivitySubtype::Trail),
+            "track" => Ok(ActivitySubtype::Track),
+            "spin" => Ok(ActivitySubtype::Spin),
+            "indoor_cycling" => Ok(ActivitySubtype::IndoorCycling),
+            "road" => Ok(ActivitySubtype::Road),
+            "mountain" => Ok(ActivitySubtype::Mountain),
+            "downhill" => Ok(ActivitySubtype::Downhill),
+            "recumbent" => Ok(ActivitySubtype::Recumbent),
+            "cyclocross" => Ok(ActivitySubtype::Cyclocross),
+            "hand_cycling" => Ok(ActivitySubtype::HandCycling),
+            "track_cycling" => Ok(ActivitySubtype::TrackCycling),
+            "indoor_rowing" => Ok(ActivitySubtype::IndoorRowing),
+            "elliptical" => Ok(ActivitySubtype::Elliptical),
+            "stair_climbing" => Ok(ActivitySubtype::StairClimbing),
+            "lap_swimming" => Ok(ActivitySubtype::LapSwimming),
+            "open_water" => Ok(ActivitySubtype::OpenWater),
+            "all" => Ok(ActivitySubtype::All),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for ActivitySubtype {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -11426,6 +13542,17 @@ impl convert::From<i64> for ActivityLevel {
         ActivityLevel::from(value as u8)
     }
 }
+impl str::FromStr for ActivityLevel {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "low" => Ok(ActivityLevel::Low),
+            "medium" => Ok(ActivityLevel::Medium),
+            "high" => Ok(ActivityLevel::High),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for ActivityLevel {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -11482,6 +13609,16 @@ impl convert::From<i64> for Side {
         Side::from(value as u8)
     }
 }
+impl str::FromStr for Side {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "right" => Ok(Side::Right),
+            "left" => Ok(Side::Left),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for Side {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -11540,6 +13677,16 @@ impl convert::From<i64> for LeftRightBalance {
         LeftRightBalance::from(value as u8)
     }
 }
+impl str::FromStr for LeftRightBalance {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "mask" => Ok(LeftRightBalance::Mask),
+            "right" => Ok(LeftRightBalance::Right),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for LeftRightBalance {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -11601,6 +13748,16 @@ impl convert::From<i64> for LeftRightBalance100 {
         LeftRightBalance100::from(value as u16)
     }
 }
+impl str::FromStr for LeftRightBalance100 {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "mask" => Ok(LeftRightBalance100::Mask),
+            "right" => Ok(LeftRightBalance100::Right),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for LeftRightBalance100 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -11662,6 +13819,16 @@ impl convert::From<i64> for LengthType {
         LengthType::from(value as u8)
     }
 }
+impl str::FromStr for LengthType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "idle" => Ok(LengthType::Idle),
+            "active" => Ok(LengthType::Active),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for LengthType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -11743,6 +13910,21 @@ impl convert::From<i64> for DayOfWeek {
         DayOfWeek::from(value as u8)
     }
 }
+impl str::FromStr for DayOfWeek {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "sunday" => Ok(DayOfWeek::Sunday),
+            "monday" => Ok(DayOfWeek::Monday),
+            "tuesday" => Ok(DayOfWeek::Tuesday),
+            "wednesday" => Ok(DayOfWeek::Wednesday),
+            "thursday" => Ok(DayOfWeek::Thursday),
+            "friday" => Ok(DayOfWeek::Friday),
+            "saturday" => Ok(DayOfWeek::Saturday),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for DayOfWeek {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -11970,6 +14152,54 @@ impl convert::From<i64> for ConnectivityCapabilities {
         ConnectivityCapabilities::from(value as u32)
     }
 }
+impl str::FromStr for ConnectivityCapabilities {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "bluetooth" => Ok(ConnectivityCapabilities::Bluetooth),
+            "bluetooth_le" => Ok(ConnectivityCapabilities::BluetoothLe),
+            "ant" => Ok(ConnectivityCapabilities::Ant),
+            "activity_upload" => Ok(ConnectivityCapabilities::ActivityUpload),
+            "course_download" => Ok(ConnectivityCapabilities::CourseDownload),
+            "workout_download" => Ok(ConnectivityCapabilities::WorkoutDownload),
+            "live_track" => Ok(ConnectivityCapabilities::LiveTrack),
+            "weather_conditions" => Ok(ConnectivityCapabilities::WeatherConditions),
+            "weather_alerts" => Ok(ConnectivityCapabilities::WeatherAlerts),
+            "gps_ephemeris_download" => Ok(ConnectivityCapabilities::GpsEphemerisDownload),
+            "explicit_archive" => Ok(ConnectivityCapabilities::ExplicitArchive),
+            "setup_incomplete" => Ok(ConnectivityCapabilities::SetupIncomplete),
+            "continue_sync_after_software_update" => {
+                Ok(ConnectivityCapabilities::ContinueSyncAfterSoftwareUpdate)
+            }
+            "connect_iq_app_download" => Ok(ConnectivityCapabilities::ConnectIqAppDownload),
+            "golf_course_download" => Ok(ConnectivityCapabilities::GolfCourseDownload),
+            "device_initiates_sync" => Ok(ConnectivityCapabilities::DeviceInitiatesSync),
+            "connect_iq_watch_app_download" => {
+                Ok(ConnectivityCapabilities::ConnectIqWatchAppDownload)
+            }
+            "connect_iq_widget_download" => Ok(ConnectivityCapabilities::ConnectIqWidgetDownload),
+            "connect_iq_watch_face_download" => {
+                Ok(ConnectivityCapabilities::ConnectIqWatchFaceDownload)
+            }
+            "connect_iq_data_field_download" => {
+                Ok(ConnectivityCapabilities::ConnectIqDataFieldDownload)
+            }
+            "connect_iq_app_managment" => Ok(ConnectivityCapabilities::ConnectIqAppManagment),
+            "swing_sensor" => Ok(ConnectivityCapabilities::SwingSensor),
+            "swing_sensor_remote" => Ok(ConnectivityCapabilities::SwingSensorRemote),
+            "incident_detection" => Ok(ConnectivityCapabilities::IncidentDetection),
+            "audio_prompts" => Ok(ConnectivityCapabilities::AudioPrompts),
+            "wifi_verification" => Ok(ConnectivityCapabilities::WifiVerification),
+            "true_up" => Ok(ConnectivityCapabilities::TrueUp),
+            "find_my_watch" => Ok(ConnectivityCapabilities::FindMyWatch),
+            "remote_manual_sync" => Ok(ConnectivityCapabilities::RemoteManualSync),
+            "live_track_auto_start" => Ok(ConnectivityCapabilities::LiveTrackAutoStart),
+            "live_track_messaging" => Ok(ConnectivityCapabilities::LiveTrackMessaging),
+            "instant_input" => Ok(ConnectivityCapabilities::InstantInput),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for ConnectivityCapabilities {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -12034,6 +14264,17 @@ impl convert::From<i64> for WeatherReport {
         WeatherReport::from(value as u8)
     }
 }
+impl str::FromStr for WeatherReport {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "current" => Ok(WeatherReport::Current),
+            "hourly_forecast" => Ok(WeatherReport::HourlyForecast),
+            "daily_forecast" => Ok(WeatherReport::DailyForecast),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for WeatherReport {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -12185,6 +14426,35 @@ impl convert::From<i64> for WeatherStatus {
         WeatherStatus::from(value as u8)
     }
 }
+impl str::FromStr for WeatherStatus {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "clear" => Ok(WeatherStatus::Clear),
+            "partly_cloudy" => Ok(WeatherStatus::PartlyCloudy),
+            "mostly_cloudy" => Ok(WeatherStatus::MostlyCloudy),
+            "rain" => Ok(WeatherStatus::Rain),
+            "snow" => Ok(WeatherStatus::Snow),
+            "windy" => Ok(WeatherStatus::Windy),
+            "thunderstorms" => Ok(WeatherStatus::Thunderstorms),
+            "wintry_mix" => Ok(WeatherStatus::WintryMix),
+            "fog" => Ok(WeatherStatus::Fog),
+            "hazy" => Ok(WeatherStatus::Hazy),
+            "hail" => Ok(WeatherStatus::Hail),
+            "scattered_showers" => Ok(WeatherStatus::ScatteredShowers),
+            "scattered_thunderstorms" => Ok(WeatherStatus::ScatteredThunderstorms),
+            "unknown_precipitation" => Ok(WeatherStatus::UnknownPrecipitation),
+            "light_rain" => Ok(WeatherStatus::LightRain),
+            "heavy_rain" => Ok(WeatherStatus::HeavyRain),
+            "light_snow" => Ok(WeatherStatus::LightSnow),
+            "heavy_snow" => Ok(WeatherStatus::HeavySnow),
+            "light_rain_snow" => Ok(WeatherStatus::LightRainSnow),
+            "heavy_rain_snow" => Ok(WeatherStatus::HeavyRainSnow),
+            "cloudy" => Ok(WeatherStatus::Cloudy),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for WeatherStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -12256,6 +14526,19 @@ impl convert::From<i64> for WeatherSeverity {
         WeatherSeverity::from(value as u8)
     }
 }
+impl str::FromStr for WeatherSeverity {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "unknown" => Ok(WeatherSeverity::Unknown),
+            "warning" => Ok(WeatherSeverity::Warning),
+            "watch" => Ok(WeatherSeverity::Watch),
+            "advisory" => Ok(WeatherSeverity::Advisory),
+            "statement" => Ok(WeatherSeverity::Statement),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for WeatherSeverity {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -12727,6 +15010,99 @@ impl convert::From<i64> for WeatherSevereType {
         WeatherSevereType::from(value as u8)
     }
 }
+impl str::FromStr for WeatherSevereType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "unspecified" => Ok(WeatherSevereType::Unspecified),
+            "tornado" => Ok(WeatherSevereType::Tornado),
+            "tsunami" => Ok(WeatherSevereType::Tsunami),
+            "hurricane" => Ok(WeatherSevereType::Hurricane),
+            "extreme_wind" => Ok(WeatherSevereType::ExtremeWind),
+            "typhoon" => Ok(WeatherSevereType::Typhoon),
+            "inland_hurricane" => Ok(WeatherSevereType::InlandHurricane),
+            "hurricane_force_wind" => Ok(WeatherSevereType::HurricaneForceWind),
+            "waterspout" => Ok(WeatherSevereType::Waterspout),
+            "severe_thunderstorm" => Ok(WeatherSevereType::SevereThunderstorm),
+            "wreckhouse_winds" => Ok(WeatherSevereType::WreckhouseWinds),
+            "les_suetes_wind" => Ok(WeatherSevereType::LesSuetesWind),
+            "avalanche" => Ok(WeatherSevereType::Avalanche),
+            "flash_flood" => Ok(WeatherSevereType::FlashFlood),
+            "tropical_storm" => Ok(WeatherSevereType::TropicalStorm),
+            "inland_tropical_storm" => Ok(WeatherSevereType::InlandTropicalStorm),
+            "blizzard" => Ok(WeatherSevereType::Blizzard),
+            "ice_storm" => Ok(WeatherSevereType::IceStorm),
+            "freezing_rain" => Ok(WeatherSevereType::FreezingRain),
+            "debris_flow" => Ok(WeatherSevereType::DebrisFlow),
+            "flash_freeze" => Ok(WeatherSevereType::FlashFreeze),
+            "dust_storm" => Ok(WeatherSevereType::DustStorm),
+            "high_wind" => Ok(WeatherSevereType::HighWind),
+            "winter_storm" => Ok(WeatherSevereType::WinterStorm),
+            "heavy_freezing_spray" => Ok(WeatherSevereType::HeavyFreezingSpray),
+            "extreme_cold" => Ok(WeatherSevereType::ExtremeCold),
+            "wind_chill" => Ok(WeatherSevereType::WindChill),
+            "cold_wave" => Ok(WeatherSevereType::ColdWave),
+            "heavy_snow_alert" => Ok(WeatherSevereType::HeavySnowAlert),
+            "lake_effect_blowing_snow" => Ok(WeatherSevereType::LakeEffectBlowingSnow),
+            "snow_squall" => Ok(WeatherSevereType::SnowSquall),
+            "lake_effect_snow" => Ok(WeatherSevereType::LakeEffectSnow),
+            "winter_weather" => Ok(WeatherSevereType::WinterWeather),
+            "sleet" => Ok(WeatherSevereType::Sleet),
+            "snowfall" => Ok(WeatherSevereType::Snowfall),
+            "snow_and_blowing_snow" => Ok(WeatherSevereType::SnowAndBlowingSnow),
+            "blowing_snow" => Ok(WeatherSevereType::BlowingSnow),
+            "snow_alert" => Ok(WeatherSevereType::SnowAlert),
+            "arctic_outflow" => Ok(WeatherSevereType::ArcticOutflow),
+            "freezing_drizzle" => Ok(WeatherSevereType::FreezingDrizzle),
+            "storm" => Ok(WeatherSevereType::Storm),
+            "storm_surge" => Ok(WeatherSevereType::StormSurge),
+            "rainfall" => Ok(WeatherSevereType::Rainfall),
+            "areal_flood" => Ok(WeatherSevereType::ArealFlood),
+            "coastal_flood" => Ok(WeatherSevereType::CoastalFlood),
+            "lakeshore_flood" => Ok(WeatherSevereType::LakeshoreFlood),
+            "excessive_heat" => Ok(WeatherSevereType::ExcessiveHeat),
+            "heat" => Ok(WeatherSevereType::Heat),
+            "weather" => Ok(WeatherSevereType::Weather),
+            "high_heat_and_humidity" => Ok(WeatherSevereType::HighHeatAndHumidity),
+            "humidex_and_health" => Ok(WeatherSevereType::HumidexAndHealth),
+            "humidex" => Ok(WeatherSevereType::Humidex),
+            "gale" => Ok(WeatherSevereType::Gale),
+            "freezing_spray" => Ok(WeatherSevereType::FreezingSpray),
+            "special_marine" => Ok(WeatherSevereType::SpecialMarine),
+            "squall" => Ok(WeatherSevereType::Squall),
+            "strong_wind" => Ok(WeatherSevereType::StrongWind),
+            "lake_wind" => Ok(WeatherSevereType::LakeWind),
+            "marine_weather" => Ok(WeatherSevereType::MarineWeather),
+            "wind" => Ok(WeatherSevereType::Wind),
+            "small_craft_hazardous_seas" => Ok(WeatherSevereType::SmallCraftHazardousSeas),
+            "hazardous_seas" => Ok(WeatherSevereType::HazardousSeas),
+            "small_craft" => Ok(WeatherSevereType::SmallCraft),
+            "small_craft_winds" => Ok(WeatherSevereType::SmallCraftWinds),
+            "small_craft_rough_bar" => Ok(WeatherSevereType::SmallCraftRoughBar),
+            "high_water_level" => Ok(WeatherSevereType::HighWaterLevel),
+            "ashfall" => Ok(WeatherSevereType::Ashfall),
+            "freezing_fog" => Ok(WeatherSevereType::FreezingFog),
+            "dense_fog" => Ok(WeatherSevereType::DenseFog),
+            "dense_smoke" => Ok(WeatherSevereType::DenseSmoke),
+            "blowing_dust" => Ok(WeatherSevereType::BlowingDust),
+            "hard_freeze" => Ok(WeatherSevereType::HardFreeze),
+            "freeze" => Ok(WeatherSevereType::Freeze),
+            "frost" => Ok(WeatherSevereType::Frost),
+            "fire_weather" => Ok(WeatherSevereType::FireWeather),
+            "flood" => Ok(WeatherSevereType::Flood),
+            "rip_tide" => Ok(WeatherSevereType::RipTide),
+            "high_surf" => Ok(WeatherSevereType::HighSurf),
+            "smog" => Ok(WeatherSevereType::Smog),
+            "air_quality" => Ok(WeatherSevereType::AirQuality),
+            "brisk_wind" => Ok(WeatherSevereType::BriskWind),
+            "air_stagnation" => Ok(WeatherSevereType::AirStagnation),
+            "low_water" => Ok(WeatherSevereType::LowWater),
+            "hydrological" => Ok(WeatherSevereType::Hydrological),
+            "special_weather" => Ok(WeatherSevereType::SpecialWeather),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for WeatherSevereType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -12804,6 +15180,20 @@ impl convert::From<i64> for StrokeType {
         StrokeType::from(value as u8)
     }
 }
+impl str::FromStr for StrokeType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "no_event" => Ok(StrokeType::NoEvent),
+            "other" => Ok(StrokeType::Other),
+            "serve" => Ok(StrokeType::Serve),
+            "forehand" => Ok(StrokeType::Forehand),
+            "backhand" => Ok(StrokeType::Backhand),
+            "smash" => Ok(StrokeType::Smash),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for StrokeType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -13054,6 +15444,54 @@ impl convert::From<i64> for BodyLocation {
         BodyLocation::from(value as u8)
     }
 }
+impl str::FromStr for BodyLocation {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "left_leg" => Ok(BodyLocation::LeftLeg),
+            "left_calf" => Ok(BodyLocation::LeftCalf),
+            "left_shin" => Ok(BodyLocation::LeftShin),
+            "left_hamstring" => Ok(BodyLocation::LeftHamstring),
+            "left_quad" => Ok(BodyLocation::LeftQuad),
+            "left_glute" => Ok(BodyLocation::LeftGlute),
+            "right_leg" => Ok(BodyLocation::RightLeg),
+            "right_calf" => Ok(BodyLocation::RightCalf),
+            "right_shin" => Ok(BodyLocation::RightShin),
+            "right_hamstring" => Ok(BodyLocation::RightHamstring),
+            "right_quad" => Ok(BodyLocation::RightQuad),
+            "right_glute" => Ok(BodyLocation::RightGlute),
+            "torso_back" => Ok(BodyLocation::TorsoBack),
+            "left_lower_back" => Ok(BodyLocation::LeftLowerBack),
+            "left_upper_back" => Ok(BodyLocation::LeftUpperBack),
+            "right_lower_back" => Ok(BodyLocation::RightLowerBack),
+            "right_upper_back" => Ok(BodyLocation::RightUpperBack),
+            "torso_front" => Ok(BodyLocation::TorsoFront),
+            "left_abdomen" => Ok(BodyLocation::LeftAbdomen),
+            "left_chest" => Ok(BodyLocation::LeftChest),
+            "right_abdomen" => Ok(BodyLocation::RightAbdomen),
+            "right_chest" => Ok(BodyLocation::RightChest),
+            "left_arm" => Ok(BodyLocation::LeftArm),
+            "left_shoulder" => Ok(BodyLocation::LeftShoulder),
+            "left_bicep" => Ok(BodyLocation::LeftBicep),
+            "left_tricep" => Ok(BodyLocation::LeftTricep),
+            "left_brachioradialis" => Ok(BodyLocation::LeftBrachioradialis),
+            "left_forearm_extensors" => Ok(BodyLocation::LeftForearmExtensors),
+            "right_arm" => Ok(BodyLocation::RightArm),
+            "right_shoulder" => Ok(BodyLocation::RightShoulder),
+            "right_bicep" => Ok(BodyLocation::RightBicep),
+            "right_tricep" => Ok(BodyLocation::RightTricep),
+            "right_brachioradialis" => Ok(BodyLocation::RightBrachioradialis),
+            "right_forearm_extensors" => Ok(BodyLocation::RightForearmExtensors),
+            "neck" => Ok(BodyLocation::Neck),
+            "throat" => Ok(BodyLocation::Throat),
+            "waist_mid_back" => Ok(BodyLocation::WaistMidBack),
+            "waist_front" => Ok(BodyLocation::WaistFront),
+            "waist_left" => Ok(BodyLocation::WaistLeft),
+            "waist_right" => Ok(BodyLocation::WaistRight),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for BodyLocation {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -13110,6 +15548,16 @@ impl convert::From<i64> for SegmentLapStatus {
         SegmentLapStatus::from(value as u8)
     }
 }
+impl str::FromStr for SegmentLapStatus {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "end" => Ok(SegmentLapStatus::End),
+            "fail" => Ok(SegmentLapStatus::Fail),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SegmentLapStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -13213,6 +15661,25 @@ impl convert::From<i64> for SegmentLeaderboardType {
         SegmentLeaderboardType::from(value as u8)
     }
 }
+impl str::FromStr for SegmentLeaderboardType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "overall" => Ok(SegmentLeaderboardType::Overall),
+            "personal_best" => Ok(SegmentLeaderboardType::PersonalBest),
+            "connections" => Ok(SegmentLeaderboardType::Connections),
+            "group" => Ok(SegmentLeaderboardType::Group),
+            "challenger" => Ok(SegmentLeaderboardType::Challenger),
+            "kom" => Ok(SegmentLeaderboardType::Kom),
+            "qom" => Ok(SegmentLeaderboardType::Qom),
+            "pr" => Ok(SegmentLeaderboardType::Pr),
+            "goal" => Ok(SegmentLeaderboardType::Goal),
+            "rival" => Ok(SegmentLeaderboardType::Rival),
+            "club_leader" => Ok(SegmentLeaderboardType::ClubLeader),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SegmentLeaderboardType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -13274,6 +15741,17 @@ impl convert::From<i64> for SegmentDeleteStatus {
         SegmentDeleteStatus::from(value as u8)
     }
 }
+impl str::FromStr for SegmentDeleteStatus {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "do_not_delete" => Ok(SegmentDeleteStatus::DoNotDelete),
+            "delete_one" => Ok(SegmentDeleteStatus::DeleteOne),
+            "delete_all" => Ok(SegmentDeleteStatus::DeleteAll),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SegmentDeleteStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -13330,6 +15808,16 @@ impl convert::From<i64> for SegmentSelectionType {
         SegmentSelectionType::from(value as u8)
     }
 }
+impl str::FromStr for SegmentSelectionType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "starred" => Ok(SegmentSelectionType::Starred),
+            "suggested" => Ok(SegmentSelectionType::Suggested),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SegmentSelectionType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -13412,6 +15900,20 @@ impl convert::From<i64> for SourceType {
         SourceType::from(value as u8)
     }
 }
+impl str::FromStr for SourceType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "ant" => Ok(SourceType::Ant),
+            "antplus" => Ok(SourceType::Antplus),
+            "bluetooth" => Ok(SourceType::Bluetooth),
+            "bluetooth_low_energy" => Ok(SourceType::BluetoothLowEnergy),
+            "wifi" => Ok(SourceType::Wifi),
+            "local" => Ok(SourceType::Local),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SourceType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -13506,6 +16008,22 @@ impl convert::From<i64> for LocalDeviceType {
         LocalDeviceType::from(value as u8)
     }
 }
+impl str::FromStr for LocalDeviceType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "gps" => Ok(LocalDeviceType::Gps),
+            "glonass" => Ok(LocalDeviceType::Glonass),
+            "gps_glonass" => Ok(LocalDeviceType::GpsGlonass),
+            "accelerometer" => Ok(LocalDeviceType::Accelerometer),
+            "barometer" => Ok(LocalDeviceType::Barometer),
+            "temperature" => Ok(LocalDeviceType::Temperature),
+            "whr" => Ok(LocalDeviceType::Whr),
+            "sensor_hub" => Ok(LocalDeviceType::SensorHub),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for LocalDeviceType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -13597,6 +16115,22 @@ impl convert::From<i64> for BleDeviceType {
         BleDeviceType::from(value as u8)
     }
 }
+impl str::FromStr for BleDeviceType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "connected_gps" => Ok(BleDeviceType::ConnectedGps),
+            "heart_rate" => Ok(BleDeviceType::HeartRate),
+            "bike_power" => Ok(BleDeviceType::BikePower),
+            "bike_speed_cadence" => Ok(BleDeviceType::BikeSpeedCadence),
+            "bike_speed" => Ok(BleDeviceType::BikeSpeed),
+            "bike_cadence" => Ok(BleDeviceType::BikeCadence),
+            "footpod" => Ok(BleDeviceType::Footpod),
+            "bike_trainer" => Ok(BleDeviceType::BikeTrainer),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for BleDeviceType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -13670,6 +16204,22 @@ impl convert::From<i64> for AntChannelId {
         AntChannelId::from(value as u32)
     }
 }
+impl str::FromStr for AntChannelId {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "ant_device_number" => Ok(AntChannelId::AntDeviceNumber),
+            "ant_device_type" => Ok(AntChannelId::AntDeviceType),
+            "ant_transmission_type_lower_nibble" => {
+                Ok(AntChannelId::AntTransmissionTypeLowerNibble)
+            }
+            "ant_extended_device_number_upper_nibble" => {
+                Ok(AntChannelId::AntExtendedDeviceNumberUpperNibble)
+            }
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for AntChannelId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -13747,6 +16297,19 @@ impl convert::From<i64> for DisplayOrientation {
         DisplayOrientation::from(value as u8)
     }
 }
+impl str::FromStr for DisplayOrientation {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "auto" => Ok(DisplayOrientation::Auto),
+            "portrait" => Ok(DisplayOrientation::Portrait),
+            "landscape" => Ok(DisplayOrientation::Landscape),
+            "portrait_flipped" => Ok(DisplayOrientation::PortraitFlipped),
+            "landscape_flipped" => Ok(DisplayOrientation::LandscapeFlipped),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for DisplayOrientation {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -13823,6 +16386,20 @@ impl convert::From<i64> for WorkoutEquipment {
         WorkoutEquipment::from(value as u8)
     }
 }
+impl str::FromStr for WorkoutEquipment {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "none" => Ok(WorkoutEquipment::None),
+            "swim_fins" => Ok(WorkoutEquipment::SwimFins),
+            "swim_kickboard" => Ok(WorkoutEquipment::SwimKickboard),
+            "swim_paddles" => Ok(WorkoutEquipment::SwimPaddles),
+            "swim_pull_buoy" => Ok(WorkoutEquipment::SwimPullBuoy),
+            "swim_snorkel" => Ok(WorkoutEquipment::SwimSnorkel),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for WorkoutEquipment {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -13889,6 +16466,18 @@ impl convert::From<i64> for WatchfaceMode {
         WatchfaceMode::from(value as u8)
     }
 }
+impl str::FromStr for WatchfaceMode {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "digital" => Ok(WatchfaceMode::Digital),
+            "analog" => Ok(WatchfaceMode::Analog),
+            "connect_iq" => Ok(WatchfaceMode::ConnectIq),
+            "disabled" => Ok(WatchfaceMode::Disabled),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for WatchfaceMode {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -13952,6 +16541,17 @@ impl convert::From<i64> for DigitalWatchfaceLayout {
         DigitalWatchfaceLayout::from(value as u8)
     }
 }
+impl str::FromStr for DigitalWatchfaceLayout {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "traditional" => Ok(DigitalWatchfaceLayout::Traditional),
+            "modern" => Ok(DigitalWatchfaceLayout::Modern),
+            "bold" => Ok(DigitalWatchfaceLayout::Bold),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for DigitalWatchfaceLayout {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -14013,6 +16613,17 @@ impl convert::From<i64> for AnalogWatchfaceLayout {
         AnalogWatchfaceLayout::from(value as u8)
     }
 }
+impl str::FromStr for AnalogWatchfaceLayout {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "minimal" => Ok(AnalogWatchfaceLayout::Minimal),
+            "traditional" => Ok(AnalogWatchfaceLayout::Traditional),
+            "modern" => Ok(AnalogWatchfaceLayout::Modern),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for AnalogWatchfaceLayout {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -14079,6 +16690,18 @@ impl convert::From<i64> for RiderPositionType {
         RiderPositionType::from(value as u8)
     }
 }
+impl str::FromStr for RiderPositionType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "seated" => Ok(RiderPositionType::Seated),
+            "standing" => Ok(RiderPositionType::Standing),
+            "transition_to_seated" => Ok(RiderPositionType::TransitionToSeated),
+            "transition_to_standing" => Ok(RiderPositionType::TransitionToStanding),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for RiderPositionType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -14145,6 +16768,18 @@ impl convert::From<i64> for PowerPhaseType {
         PowerPhaseType::from(value as u8)
     }
 }
+impl str::FromStr for PowerPhaseType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "power_phase_start_angle" => Ok(PowerPhaseType::PowerPhaseStartAngle),
+            "power_phase_end_angle" => Ok(PowerPhaseType::PowerPhaseEndAngle),
+            "power_phase_arc_length" => Ok(PowerPhaseType::PowerPhaseArcLength),
+            "power_phase_center" => Ok(PowerPhaseType::PowerPhaseCenter),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for PowerPhaseType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -14265,6 +16900,27 @@ impl convert::From<i64> for CameraEventType {
         CameraEventType::from(value as u8)
     }
 }
+impl str::FromStr for CameraEventType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "video_start" => Ok(CameraEventType::VideoStart),
+            "video_split" => Ok(CameraEventType::VideoSplit),
+            "video_end" => Ok(CameraEventType::VideoEnd),
+            "photo_taken" => Ok(CameraEventType::PhotoTaken),
+            "video_second_stream_start" => Ok(CameraEventType::VideoSecondStreamStart),
+            "video_second_stream_split" => Ok(CameraEventType::VideoSecondStreamSplit),
+            "video_second_stream_end" => Ok(CameraEventType::VideoSecondStreamEnd),
+            "video_split_start" => Ok(CameraEventType::VideoSplitStart),
+            "video_second_stream_split_start" => Ok(CameraEventType::VideoSecondStreamSplitStart),
+            "video_pause" => Ok(CameraEventType::VideoPause),
+            "video_second_stream_pause" => Ok(CameraEventType::VideoSecondStreamPause),
+            "video_resume" => Ok(CameraEventType::VideoResume),
+            "video_second_stream_resume" => Ok(CameraEventType::VideoSecondStreamResume),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for CameraEventType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -14332,6 +16988,18 @@ impl convert::From<i64> for SensorType {
         SensorType::from(value as u8)
     }
 }
+impl str::FromStr for SensorType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "accelerometer" => Ok(SensorType::Accelerometer),
+            "gyroscope" => Ok(SensorType::Gyroscope),
+            "compass" => Ok(SensorType::Compass),
+            "barometer" => Ok(SensorType::Barometer),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SensorType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -14400,6 +17068,18 @@ impl convert::From<i64> for BikeLightNetworkConfigType {
         BikeLightNetworkConfigType::from(value as u8)
     }
 }
+impl str::FromStr for BikeLightNetworkConfigType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "auto" => Ok(BikeLightNetworkConfigType::Auto),
+            "individual" => Ok(BikeLightNetworkConfigType::Individual),
+            "high_visibility" => Ok(BikeLightNetworkConfigType::HighVisibility),
+            "trail" => Ok(BikeLightNetworkConfigType::Trail),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for BikeLightNetworkConfigType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -14470,6 +17150,18 @@ impl convert::From<i64> for CommTimeoutType {
         CommTimeoutType::from(value as u16)
     }
 }
+impl str::FromStr for CommTimeoutType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "wildcard_pairing_timeout" => Ok(CommTimeoutType::WildcardPairingTimeout),
+            "pairing_timeout" => Ok(CommTimeoutType::PairingTimeout),
+            "connection_lost" => Ok(CommTimeoutType::ConnectionLost),
+            "connection_timeout" => Ok(CommTimeoutType::ConnectionTimeout),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for CommTimeoutType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -14539,6 +17231,18 @@ impl convert::From<i64> for CameraOrientationType {
         CameraOrientationType::from(value as u8)
     }
 }
+impl str::FromStr for CameraOrientationType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "camera_orientation_0" => Ok(CameraOrientationType::CameraOrientation0),
+            "camera_orientation_90" => Ok(CameraOrientationType::CameraOrientation90),
+            "camera_orientation_180" => Ok(CameraOrientationType::CameraOrientation180),
+            "camera_orientation_270" => Ok(CameraOrientationType::CameraOrientation270),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for CameraOrientationType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -14605,6 +17309,18 @@ impl convert::From<i64> for AttitudeStage {
         AttitudeStage::from(value as u8)
     }
 }
+impl str::FromStr for AttitudeStage {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "failed" => Ok(AttitudeStage::Failed),
+            "aligning" => Ok(AttitudeStage::Aligning),
+            "degraded" => Ok(AttitudeStage::Degraded),
+            "valid" => Ok(AttitudeStage::Valid),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for AttitudeStage {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -14716,6 +17432,27 @@ impl convert::From<i64> for AttitudeValidity {
         AttitudeValidity::from(value as u16)
     }
 }
+impl str::FromStr for AttitudeValidity {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "track_angle_heading_valid" => Ok(AttitudeValidity::TrackAngleHeadingValid),
+            "pitch_valid" => Ok(AttitudeValidity::PitchValid),
+            "roll_valid" => Ok(AttitudeValidity::RollValid),
+            "lateral_body_accel_valid" => Ok(AttitudeValidity::LateralBodyAccelValid),
+            "normal_body_accel_valid" => Ok(AttitudeValidity::NormalBodyAccelValid),
+            "turn_rate_valid" => Ok(AttitudeValidity::TurnRateValid),
+            "hw_fail" => Ok(AttitudeValidity::HwFail),
+            "mag_invalid" => Ok(AttitudeValidity::MagInvalid),
+            "no_gps" => Ok(AttitudeValidity::NoGps),
+            "gps_invalid" => Ok(AttitudeValidity::GpsInvalid),
+            "solution_coasting" => Ok(AttitudeValidity::SolutionCoasting),
+            "true_track_angle" => Ok(AttitudeValidity::TrueTrackAngle),
+            "magnetic_heading" => Ok(AttitudeValidity::MagneticHeading),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for AttitudeValidity {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -14790,6 +17527,19 @@ impl convert::From<i64> for AutoSyncFrequency {
         AutoSyncFrequency::from(value as u8)
     }
 }
+impl str::FromStr for AutoSyncFrequency {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "never" => Ok(AutoSyncFrequency::Never),
+            "occasionally" => Ok(AutoSyncFrequency::Occasionally),
+            "frequent" => Ok(AutoSyncFrequency::Frequent),
+            "once_a_day" => Ok(AutoSyncFrequency::OnceADay),
+            "remote" => Ok(AutoSyncFrequency::Remote),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for AutoSyncFrequency {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -14882,6 +17632,23 @@ impl convert::From<i64> for ExdLayout {
         ExdLayout::from(value as u8)
     }
 }
+impl str::FromStr for ExdLayout {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "full_screen" => Ok(ExdLayout::FullScreen),
+            "half_vertical" => Ok(ExdLayout::HalfVertical),
+            "half_horizontal" => Ok(ExdLayout::HalfHorizontal),
+            "half_vertical_right_split" => Ok(ExdLayout::HalfVerticalRightSplit),
+            "half_horizontal_bottom_split" => Ok(ExdLayout::HalfHorizontalBottomSplit),
+            "full_quarter_split" => Ok(ExdLayout::FullQuarterSplit),
+            "half_vertical_left_split" => Ok(ExdLayout::HalfVerticalLeftSplit),
+            "half_horizontal_top_split" => Ok(ExdLayout::HalfHorizontalTopSplit),
+            "dynamic" => Ok(ExdLayout::Dynamic),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for ExdLayout {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -14983,6 +17750,25 @@ impl convert::From<i64> for ExdDisplayType {
         ExdDisplayType::from(value as u8)
     }
 }
+impl str::FromStr for ExdDisplayType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "numerical" => Ok(ExdDisplayType::Numerical),
+            "simple" => Ok(ExdDisplayType::Simple),
+            "graph" => Ok(ExdDisplayType::Graph),
+            "bar" => Ok(ExdDisplayType::Bar),
+            "circle_graph" => Ok(ExdDisplayType::CircleGraph),
+            "virtual_partner" => Ok(ExdDisplayType::VirtualPartner),
+            "balance" => Ok(ExdDisplayType::Balance),
+            "string_list" => Ok(ExdDisplayType::StringList),
+            "string" => Ok(ExdDisplayType::String),
+            "simple_dynamic_icon" => Ok(ExdDisplayType::SimpleDynamicIcon),
+            "gauge" => Ok(ExdDisplayType::Gauge),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for ExdDisplayType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -15283,6 +18069,66 @@ impl convert::From<i64> for ExdDataUnits {
         ExdDataUnits::from(value as u8)
     }
 }
+impl str::FromStr for ExdDataUnits {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "no_units" => Ok(ExdDataUnits::NoUnits),
+            "laps" => Ok(ExdDataUnits::Laps),
+            "miles_per_hour" => Ok(ExdDataUnits::MilesPerHour),
+            "kilometers_per_hour" => Ok(ExdDataUnits::KilometersPerHour),
+            "feet_per_hour" => Ok(ExdDataUnits::FeetPerHour),
+            "meters_per_hour" => Ok(ExdDataUnits::MetersPerHour),
+            "degrees_celsius" => Ok(ExdDataUnits::DegreesCelsius),
+            "degrees_farenheit" => Ok(ExdDataUnits::DegreesFarenheit),
+            "zone" => Ok(ExdDataUnits::Zone),
+            "gear" => Ok(ExdDataUnits::Gear),
+            "rpm" => Ok(ExdDataUnits::Rpm),
+            "bpm" => Ok(ExdDataUnits::Bpm),
+            "degrees" => Ok(ExdDataUnits::Degrees),
+            "millimeters" => Ok(ExdDataUnits::Millimeters),
+            "meters" => Ok(ExdDataUnits::Meters),
+            "kilometers" => Ok(ExdDataUnits::Kilometers),
+            "feet" => Ok(ExdDataUnits::Feet),
+            "yards" => Ok(ExdDataUnits::Yards),
+            "kilofeet" => Ok(ExdDataUnits::Kilofeet),
+            "miles" => Ok(ExdDataUnits::Miles),
+            "time" => Ok(ExdDataUnits::Time),
+            "enum_turn_type" => Ok(ExdDataUnits::EnumTurnType),
+            "percent" => Ok(ExdDataUnits::Percent),
+            "watts" => Ok(ExdDataUnits::Watts),
+            "watts_per_kilogram" => Ok(ExdDataUnits::WattsPerKilogram),
+            "enum_battery_status" => Ok(ExdDataUnits::EnumBatteryStatus),
+            "enum_bike_light_beam_angle_mode" => Ok(ExdDataUnits::EnumBikeLightBeamAngleMode),
+            "enum_bike_light_battery_status" => Ok(ExdDataUnits::EnumBikeLightBatteryStatus),
+            "enum_bike_light_network_config_type" => {
+                Ok(ExdDataUnits::EnumBikeLightNetworkConfigType)
+            }
+            "lights" => Ok(ExdDataUnits::Lights),
+            "seconds" => Ok(ExdDataUnits::Seconds),
+            "minutes" => Ok(ExdDataUnits::Minutes),
+            "hours" => Ok(ExdDataUnits::Hours),
+            "calories" => Ok(ExdDataUnits::Calories),
+            "kilojoules" => Ok(ExdDataUnits::Kilojoules),
+            "milliseconds" => Ok(ExdDataUnits::Milliseconds),
+            "second_per_mile" => Ok(ExdDataUnits::SecondPerMile),
+            "second_per_kilometer" => Ok(ExdDataUnits::SecondPerKilometer),
+            "centimeter" => Ok(ExdDataUnits::Centimeter),
+            "enum_course_point" => Ok(ExdDataUnits::EnumCoursePoint),
+            "bradians" => Ok(ExdDataUnits::Bradians),
+            "enum_sport" => Ok(ExdDataUnits::EnumSport),
+            "inches_hg" => Ok(ExdDataUnits::InchesHg),
+            "mm_hg" => Ok(ExdDataUnits::MmHg),
+            "mbars" => Ok(ExdDataUnits::Mbars),
+            "hecto_pascals" => Ok(ExdDataUnits::HectoPascals),
+            "feet_per_min" => Ok(ExdDataUnits::FeetPerMin),
+            "meters_per_min" => Ok(ExdDataUnits::MetersPerMin),
+            "meters_per_sec" => Ok(ExdDataUnits::MetersPerSec),
+            "eight_cardinal" => Ok(ExdDataUnits::EightCardinal),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for ExdDataUnits {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -15549,6 +18395,58 @@ impl convert::From<i64> for ExdQualifiers {
         ExdQualifiers::from(value as u8)
     }
 }
+impl str::FromStr for ExdQualifiers {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "no_qualifier" => Ok(ExdQualifiers::NoQualifier),
+            "instantaneous" => Ok(ExdQualifiers::Instantaneous),
+            "average" => Ok(ExdQualifiers::Average),
+            "lap" => Ok(ExdQualifiers::Lap),
+            "maximum" => Ok(ExdQualifiers::Maximum),
+            "maximum_average" => Ok(ExdQualifiers::MaximumAverage),
+            "maximum_lap" => Ok(ExdQualifiers::MaximumLap),
+            "last_lap" => Ok(ExdQualifiers::LastLap),
+            "average_lap" => Ok(ExdQualifiers::AverageLap),
+            "to_destination" => Ok(ExdQualifiers::ToDestination),
+            "to_go" => Ok(ExdQualifiers::ToGo),
+            "to_next" => Ok(ExdQualifiers::ToNext),
+            "next_course_point" => Ok(ExdQualifiers::NextCoursePoint),
+            "total" => Ok(ExdQualifiers::Total),
+            "three_second_average" => Ok(ExdQualifiers::ThreeSecondAverage),
+            "ten_second_average" => Ok(ExdQualifiers::TenSecondAverage),
+            "thirty_second_average" => Ok(ExdQualifiers::ThirtySecondAverage),
+            "percent_maximum" => Ok(ExdQualifiers::PercentMaximum),
+            "percent_maximum_average" => Ok(ExdQualifiers::PercentMaximumAverage),
+            "lap_percent_maximum" => Ok(ExdQualifiers::LapPercentMaximum),
+            "elapsed" => Ok(ExdQualifiers::Elapsed),
+            "sunrise" => Ok(ExdQualifiers::Sunrise),
+            "sunset" => Ok(ExdQualifiers::Sunset),
+            "compared_to_virtual_partner" => Ok(ExdQualifiers::ComparedToVirtualPartner),
+            "maximum_24h" => Ok(ExdQualifiers::Maximum24h),
+            "minimum_24h" => Ok(ExdQualifiers::Minimum24h),
+            "minimum" => Ok(ExdQualifiers::Minimum),
+            "first" => Ok(ExdQualifiers::First),
+            "second" => Ok(ExdQualifiers::Second),
+            "third" => Ok(ExdQualifiers::Third),
+            "shifter" => Ok(ExdQualifiers::Shifter),
+            "last_sport" => Ok(ExdQualifiers::LastSport),
+            "moving" => Ok(ExdQualifiers::Moving),
+            "stopped" => Ok(ExdQualifiers::Stopped),
+            "estimated_total" => Ok(ExdQualifiers::EstimatedTotal),
+            "zone_9" => Ok(ExdQualifiers::Zone9),
+            "zone_8" => Ok(ExdQualifiers::Zone8),
+            "zone_7" => Ok(ExdQualifiers::Zone7),
+            "zone_6" => Ok(ExdQualifiers::Zone6),
+            "zone_5" => Ok(ExdQualifiers::Zone5),
+            "zone_4" => Ok(ExdQualifiers::Zone4),
+            "zone_3" => Ok(ExdQualifiers::Zone3),
+            "zone_2" => Ok(ExdQualifiers::Zone2),
+            "zone_1" => Ok(ExdQualifiers::Zone1),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for ExdQualifiers {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -16091,6 +18989,115 @@ impl convert::From<i64> for ExdDescriptors {
         ExdDescriptors::from(value as u8)
     }
 }
+impl str::FromStr for ExdDescriptors {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "bike_light_battery_status" => Ok(ExdDescriptors::BikeLightBatteryStatus),
+            "beam_angle_status" => Ok(ExdDescriptors::BeamAngleStatus),
+            "batery_level" => Ok(ExdDescriptors::BateryLevel),
+            "light_network_mode" => Ok(ExdDescriptors::LightNetworkMode),
+            "number_lights_connected" => Ok(ExdDescriptors::NumberLightsConnected),
+            "cadence" => Ok(ExdDescriptors::Cadence),
+            "distance" => Ok(ExdDescriptors::Distance),
+            "estimated_time_of_arrival" => Ok(ExdDescriptors::EstimatedTimeOfArrival),
+            "heading" => Ok(ExdDescriptors::Heading),
+            "time" => Ok(ExdDescriptors::Time),
+            "battery_level" => Ok(ExdDescriptors::BatteryLevel),
+            "trainer_resistance" => Ok(ExdDescriptors::TrainerResistance),
+            "trainer_target_power" => Ok(ExdDescriptors::TrainerTargetPower),
+            "time_seated" => Ok(ExdDescriptors::TimeSeated),
+            "time_standing" => Ok(ExdDescriptors::TimeStanding),
+            "elevation" => Ok(ExdDescriptors::Elevation),
+            "grade" => Ok(ExdDescriptors::Grade),
+            "ascent" => Ok(ExdDescriptors::Ascent),
+            "descent" => Ok(ExdDescriptors::Descent),
+            "vertical_speed" => Ok(ExdDescriptors::VerticalSpeed),
+            "di2_battery_level" => Ok(ExdDescriptors::Di2BatteryLevel),
+            "front_gear" => Ok(ExdDescriptors::FrontGear),
+            "rear_gear" => Ok(ExdDescriptors::RearGear),
+            "gear_ratio" => Ok(ExdDescriptors::GearRatio),
+            "heart_rate" => Ok(ExdDescriptors::HeartRate),
+            "heart_rate_zone" => Ok(ExdDescriptors::HeartRateZone),
+            "time_in_heart_rate_zone" => Ok(ExdDescriptors::TimeInHeartRateZone),
+            "heart_rate_reserve" => Ok(ExdDescriptors::HeartRateReserve),
+            "calories" => Ok(ExdDescriptors::Calories),
+            "gps_accuracy" => Ok(ExdDescriptors::GpsAccuracy),
+            "gps_signal_strength" => Ok(ExdDescriptors::GpsSignalStrength),
+            "temperature" => Ok(ExdDescriptors::Temperature),
+            "time_of_day" => Ok(ExdDescriptors::TimeOfDay),
+            "balance" => Ok(ExdDescriptors::Balance),
+            "pedal_smoothness" => Ok(ExdDescriptors::PedalSmoothness),
+            "power" => Ok(ExdDescriptors::Power),
+            "functional_threshold_power" => Ok(ExdDescriptors::FunctionalThresholdPower),
+            "intensity_factor" => Ok(ExdDescriptors::IntensityFactor),
+            "work" => Ok(ExdDescriptors::Work),
+            "power_ratio" => Ok(ExdDescriptors::PowerRatio),
+            "normalized_power" => Ok(ExdDescriptors::NormalizedPower),
+            "training_stress_Score" => Ok(ExdDescriptors::TrainingStressScore),
+            "time_on_zone" => Ok(ExdDescriptors::TimeOnZone),
+            "speed" => Ok(ExdDescriptors::Speed),
+            "laps" => Ok(ExdDescriptors::Laps),
+            "reps" => Ok(ExdDescriptors::Reps),
+            "workout_step" => Ok(ExdDescriptors::WorkoutStep),
+            "course_distance" => Ok(ExdDescriptors::CourseDistance),
+            "navigation_distance" => Ok(ExdDescriptors::NavigationDistance),
+            "course_estimated_time_of_arrival" => Ok(ExdDescriptors::CourseEstimatedTimeOfArrival),
+            "navigation_estimated_time_of_arrival" => {
+                Ok(ExdDescriptors::NavigationEstimatedTimeOfArrival)
+            }
+            "course_time" => Ok(ExdDescriptors::CourseTime),
+            "navigation_time" => Ok(ExdDescriptors::NavigationTime),
+            "course_heading" => Ok(ExdDescriptors::CourseHeading),
+            "navigation_heading" => Ok(ExdDescriptors::NavigationHeading),
+            "power_zone" => Ok(ExdDescriptors::PowerZone),
+            "torque_effectiveness" => Ok(ExdDescriptors::TorqueEffectiveness),
+            "timer_time" => Ok(ExdDescriptors::TimerTime),
+            "power_weight_ratio" => Ok(ExdDescriptors::PowerWeightRatio),
+            "left_platform_center_offset" => Ok(ExdDescriptors::LeftPlatformCenterOffset),
+            "right_platform_center_offset" => Ok(ExdDescriptors::RightPlatformCenterOffset),
+            "left_power_phase_start_angle" => Ok(ExdDescriptors::LeftPowerPhaseStartAngle),
+            "right_power_phase_start_angle" => Ok(ExdDescriptors::RightPowerPhaseStartAngle),
+            "left_power_phase_finish_angle" => Ok(ExdDescriptors::LeftPowerPhaseFinishAngle),
+            "right_power_phase_finish_angle" => Ok(ExdDescriptors::RightPowerPhaseFinishAngle),
+            "gears" => Ok(ExdDescriptors::Gears),
+            "pace" => Ok(ExdDescriptors::Pace),
+            "training_effect" => Ok(ExdDescriptors::TrainingEffect),
+            "vertical_oscillation" => Ok(ExdDescriptors::VerticalOscillation),
+            "vertical_ratio" => Ok(ExdDescriptors::VerticalRatio),
+            "ground_contact_time" => Ok(ExdDescriptors::GroundContactTime),
+            "left_ground_contact_time_balance" => Ok(ExdDescriptors::LeftGroundContactTimeBalance),
+            "right_ground_contact_time_balance" => {
+                Ok(ExdDescriptors::RightGroundContactTimeBalance)
+            }
+            "stride_length" => Ok(ExdDescriptors::StrideLength),
+            "running_cadence" => Ok(ExdDescriptors::RunningCadence),
+            "performance_condition" => Ok(ExdDescriptors::PerformanceCondition),
+            "course_type" => Ok(ExdDescriptors::CourseType),
+            "time_in_power_zone" => Ok(ExdDescriptors::TimeInPowerZone),
+            "navigation_turn" => Ok(ExdDescriptors::NavigationTurn),
+            "course_location" => Ok(ExdDescriptors::CourseLocation),
+            "navigation_location" => Ok(ExdDescriptors::NavigationLocation),
+            "compass" => Ok(ExdDescriptors::Compass),
+            "gear_combo" => Ok(ExdDescriptors::GearCombo),
+            "muscle_oxygen" => Ok(ExdDescriptors::MuscleOxygen),
+            "icon" => Ok(ExdDescriptors::Icon),
+            "compass_heading" => Ok(ExdDescriptors::CompassHeading),
+            "gps_heading" => Ok(ExdDescriptors::GpsHeading),
+            "gps_elevation" => Ok(ExdDescriptors::GpsElevation),
+            "anaerobic_training_effect" => Ok(ExdDescriptors::AnaerobicTrainingEffect),
+            "course" => Ok(ExdDescriptors::Course),
+            "off_course" => Ok(ExdDescriptors::OffCourse),
+            "glide_ratio" => Ok(ExdDescriptors::GlideRatio),
+            "vertical_distance" => Ok(ExdDescriptors::VerticalDistance),
+            "vmg" => Ok(ExdDescriptors::Vmg),
+            "ambient_pressure" => Ok(ExdDescriptors::AmbientPressure),
+            "pressure" => Ok(ExdDescriptors::Pressure),
+            "vam" => Ok(ExdDescriptors::Vam),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for ExdDescriptors {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -16172,6 +19179,21 @@ impl convert::From<i64> for AutoActivityDetect {
         AutoActivityDetect::from(value as u32)
     }
 }
+impl str::FromStr for AutoActivityDetect {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "none" => Ok(AutoActivityDetect::None),
+            "running" => Ok(AutoActivityDetect::Running),
+            "cycling" => Ok(AutoActivityDetect::Cycling),
+            "swimming" => Ok(AutoActivityDetect::Swimming),
+            "walking" => Ok(AutoActivityDetect::Walking),
+            "elliptical" => Ok(AutoActivityDetect::Elliptical),
+            "sedentary" => Ok(AutoActivityDetect::Sedentary),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for AutoActivityDetect {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -16269,6 +19291,24 @@ impl convert::From<i64> for SupportedExdScreenLayouts {
         SupportedExdScreenLayouts::from(value as u32)
     }
 }
+impl str::FromStr for SupportedExdScreenLayouts {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "full_screen" => Ok(SupportedExdScreenLayouts::FullScreen),
+            "half_vertical" => Ok(SupportedExdScreenLayouts::HalfVertical),
+            "half_horizontal" => Ok(SupportedExdScreenLayouts::HalfHorizontal),
+            "half_vertical_right_split" => Ok(SupportedExdScreenLayouts::HalfVerticalRightSplit),
+            "half_horizontal_bottom_split" => {
+                Ok(SupportedExdScreenLayouts::HalfHorizontalBottomSplit)
+            }
+            "full_quarter_split" => Ok(SupportedExdScreenLayouts::FullQuarterSplit),
+            "half_vertical_left_split" => Ok(SupportedExdScreenLayouts::HalfVerticalLeftSplit),
+            "half_horizontal_top_split" => Ok(SupportedExdScreenLayouts::HalfHorizontalTopSplit),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SupportedExdScreenLayouts {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -16403,6 +19443,31 @@ impl convert::From<i64> for FitBaseType {
         FitBaseType::from(value as u8)
     }
 }
+impl str::FromStr for FitBaseType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "enum" => Ok(FitBaseType::Enum),
+            "sint8" => Ok(FitBaseType::Sint8),
+            "uint8" => Ok(FitBaseType::Uint8),
+            "string" => Ok(FitBaseType::String),
+            "uint8z" => Ok(FitBaseType::Uint8z),
+            "byte" => Ok(FitBaseType::Byte),
+            "sint16" => Ok(FitBaseType::Sint16),
+            "uint16" => Ok(FitBaseType::Uint16),
+            "sint32" => Ok(FitBaseType::Sint32),
+            "uint32" => Ok(FitBaseType::Uint32),
+            "float32" => Ok(FitBaseType::Float32),
+            "float64" => Ok(FitBaseType::Float64),
+            "uint16z" => Ok(FitBaseType::Uint16z),
+            "uint32z" => Ok(FitBaseType::Uint32z),
+            "sint64" => Ok(FitBaseType::Sint64),
+            "uint64" => Ok(FitBaseType::Uint64),
+            "uint64z" => Ok(FitBaseType::Uint64z),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for FitBaseType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -16642,6 +19707,52 @@ impl convert::From<i64> for TurnType {
         TurnType::from(value as u8)
     }
 }
+impl str::FromStr for TurnType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "arriving_idx" => Ok(TurnType::ArrivingIdx),
+            "arriving_left_idx" => Ok(TurnType::ArrivingLeftIdx),
+            "arriving_right_idx" => Ok(TurnType::ArrivingRightIdx),
+            "arriving_via_idx" => Ok(TurnType::ArrivingViaIdx),
+            "arriving_via_left_idx" => Ok(TurnType::ArrivingViaLeftIdx),
+            "arriving_via_right_idx" => Ok(TurnType::ArrivingViaRightIdx),
+            "bear_keep_left_idx" => Ok(TurnType::BearKeepLeftIdx),
+            "bear_keep_right_idx" => Ok(TurnType::BearKeepRightIdx),
+            "continue_idx" => Ok(TurnType::ContinueIdx),
+            "exit_left_idx" => Ok(TurnType::ExitLeftIdx),
+            "exit_right_idx" => Ok(TurnType::ExitRightIdx),
+            "ferry_idx" => Ok(TurnType::FerryIdx),
+            "roundabout_45_idx" => Ok(TurnType::Roundabout45Idx),
+            "roundabout_90_idx" => Ok(TurnType::Roundabout90Idx),
+            "roundabout_135_idx" => Ok(TurnType::Roundabout135Idx),
+            "roundabout_180_idx" => Ok(TurnType::Roundabout180Idx),
+            "roundabout_225_idx" => Ok(TurnType::Roundabout225Idx),
+            "roundabout_270_idx" => Ok(TurnType::Roundabout270Idx),
+            "roundabout_315_idx" => Ok(TurnType::Roundabout315Idx),
+            "roundabout_360_idx" => Ok(TurnType::Roundabout360Idx),
+            "roundabout_neg_45_idx" => Ok(TurnType::RoundaboutNeg45Idx),
+            "roundabout_neg_90_idx" => Ok(TurnType::RoundaboutNeg90Idx),
+            "roundabout_neg_135_idx" => Ok(TurnType::RoundaboutNeg135Idx),
+            "roundabout_neg_180_idx" => Ok(TurnType::RoundaboutNeg180Idx),
+            "roundabout_neg_225_idx" => Ok(TurnType::RoundaboutNeg225Idx),
+            "roundabout_neg_270_idx" => Ok(TurnType::RoundaboutNeg270Idx),
+            "roundabout_neg_315_idx" => Ok(TurnType::RoundaboutNeg315Idx),
+            "roundabout_neg_360_idx" => Ok(TurnType::RoundaboutNeg360Idx),
+            "roundabout_generic_idx" => Ok(TurnType::RoundaboutGenericIdx),
+            "roundabout_neg_generic_idx" => Ok(TurnType::RoundaboutNegGenericIdx),
+            "sharp_turn_left_idx" => Ok(TurnType::SharpTurnLeftIdx),
+            "sharp_turn_right_idx" => Ok(TurnType::SharpTurnRightIdx),
+            "turn_left_idx" => Ok(TurnType::TurnLeftIdx),
+            "turn_right_idx" => Ok(TurnType::TurnRightIdx),
+            "uturn_left_idx" => Ok(TurnType::UturnLeftIdx),
+            "uturn_right_idx" => Ok(TurnType::UturnRightIdx),
+            "icon_inv_idx" => Ok(TurnType::IconInvIdx),
+            "icon_idx_cnt" => Ok(TurnType::IconIdxCnt),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for TurnType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -16698,6 +19809,16 @@ impl convert::From<i64> for BikeLightBeamAngleMode {
         BikeLightBeamAngleMode::from(value as u8)
     }
 }
+impl str::FromStr for BikeLightBeamAngleMode {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "manual" => Ok(BikeLightBeamAngleMode::Manual),
+            "auto" => Ok(BikeLightBeamAngleMode::Auto),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for BikeLightBeamAngleMode {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -16762,6 +19883,17 @@ impl convert::From<i64> for FitBaseUnit {
         FitBaseUnit::from(value as u16)
     }
 }
+impl str::FromStr for FitBaseUnit {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "other" => Ok(FitBaseUnit::Other),
+            "kilogram" => Ok(FitBaseUnit::Kilogram),
+            "pound" => Ok(FitBaseUnit::Pound),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for FitBaseUnit {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -16821,6 +19953,16 @@ impl convert::From<i64> for SetType {
         SetType::from(value as u8)
     }
 }
+impl str::FromStr for SetType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "rest" => Ok(SetType::Rest),
+            "active" => Ok(SetType::Active),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SetType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -17040,6 +20182,48 @@ impl convert::From<i64> for ExerciseCategory {
         ExerciseCategory::from(value as u16)
     }
 }
+impl str::FromStr for ExerciseCategory {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "bench_press" => Ok(ExerciseCategory::BenchPress),
+            "calf_raise" => Ok(ExerciseCategory::CalfRaise),
+            "cardio" => Ok(ExerciseCategory::Cardio),
+            "carry" => Ok(ExerciseCategory::Carry),
+            "chop" => Ok(ExerciseCategory::Chop),
+            "core" => Ok(ExerciseCategory::Core),
+            "crunch" => Ok(ExerciseCategory::Crunch),
+            "curl" => Ok(ExerciseCategory::Curl),
+            "deadlift" => Ok(ExerciseCategory::Deadlift),
+            "flye" => Ok(ExerciseCategory::Flye),
+            "hip_raise" => Ok(ExerciseCategory::HipRaise),
+            "hip_stability" => Ok(ExerciseCategory::HipStability),
+            "hip_swing" => Ok(ExerciseCategory::HipSwing),
+            "hyperextension" => Ok(ExerciseCategory::Hyperextension),
+            "lateral_raise" => Ok(ExerciseCategory::LateralRaise),
+            "leg_curl" => Ok(ExerciseCategory::LegCurl),
+            "leg_raise" => Ok(ExerciseCategory::LegRaise),
+            "lunge" => Ok(ExerciseCategory::Lunge),
+            "olympic_lift" => Ok(ExerciseCategory::OlympicLift),
+            "plank" => Ok(ExerciseCategory::Plank),
+            "plyo" => Ok(ExerciseCategory::Plyo),
+            "pull_up" => Ok(ExerciseCategory::PullUp),
+            "push_up" => Ok(ExerciseCategory::PushUp),
+            "row" => Ok(ExerciseCategory::Row),
+            "shoulder_press" => Ok(ExerciseCategory::ShoulderPress),
+            "shoulder_stability" => Ok(ExerciseCategory::ShoulderStability),
+            "shrug" => Ok(ExerciseCategory::Shrug),
+            "sit_up" => Ok(ExerciseCategory::SitUp),
+            "squat" => Ok(ExerciseCategory::Squat),
+            "total_body" => Ok(ExerciseCategory::TotalBody),
+            "triceps_extension" => Ok(ExerciseCategory::TricepsExtension),
+            "warm_up" => Ok(ExerciseCategory::WarmUp),
+            "run" => Ok(ExerciseCategory::Run),
+            "unknown" => Ok(ExerciseCategory::Unknown),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for ExerciseCategory {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -17264,6 +20448,67 @@ impl convert::From<i64> for BenchPressExerciseName {
         BenchPressExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for BenchPressExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "alternating_dumbbell_chest_press_on_swiss_ball" => {
+                Ok(BenchPressExerciseName::AlternatingDumbbellChestPressOnSwissBall)
+            }
+            "barbell_bench_press" => Ok(BenchPressExerciseName::BarbellBenchPress),
+            "barbell_board_bench_press" => Ok(BenchPressExerciseName::BarbellBoardBenchPress),
+            "barbell_floor_press" => Ok(BenchPressExerciseName::BarbellFloorPress),
+            "close_grip_barbell_bench_press" => {
+                Ok(BenchPressExerciseName::CloseGripBarbellBenchPress)
+            }
+            "decline_dumbbell_bench_press" => Ok(BenchPressExerciseName::DeclineDumbbellBenchPress),
+            "dumbbell_bench_press" => Ok(BenchPressExerciseName::DumbbellBenchPress),
+            "dumbbell_floor_press" => Ok(BenchPressExerciseName::DumbbellFloorPress),
+            "incline_barbell_bench_press" => Ok(BenchPressExerciseName::InclineBarbellBenchPress),
+            "incline_dumbbell_bench_press" => Ok(BenchPressExerciseName::InclineDumbbellBenchPress),
+            "incline_smith_machine_bench_press" => {
+                Ok(BenchPressExerciseName::InclineSmithMachineBenchPress)
+            }
+            "isometric_barbell_bench_press" => {
+                Ok(BenchPressExerciseName::IsometricBarbellBenchPress)
+            }
+            "kettlebell_chest_press" => Ok(BenchPressExerciseName::KettlebellChestPress),
+            "neutral_grip_dumbbell_bench_press" => {
+                Ok(BenchPressExerciseName::NeutralGripDumbbellBenchPress)
+            }
+            "neutral_grip_dumbbell_incline_bench_press" => {
+                Ok(BenchPressExerciseName::NeutralGripDumbbellInclineBenchPress)
+            }
+            "one_arm_floor_press" => Ok(BenchPressExerciseName::OneArmFloorPress),
+            "weighted_one_arm_floor_press" => Ok(BenchPressExerciseName::WeightedOneArmFloorPress),
+            "partial_lockout" => Ok(BenchPressExerciseName::PartialLockout),
+            "reverse_grip_barbell_bench_press" => {
+                Ok(BenchPressExerciseName::ReverseGripBarbellBenchPress)
+            }
+            "reverse_grip_incline_bench_press" => {
+                Ok(BenchPressExerciseName::ReverseGripInclineBenchPress)
+            }
+            "single_arm_cable_chest_press" => Ok(BenchPressExerciseName::SingleArmCableChestPress),
+            "single_arm_dumbbell_bench_press" => {
+                Ok(BenchPressExerciseName::SingleArmDumbbellBenchPress)
+            }
+            "smith_machine_bench_press" => Ok(BenchPressExerciseName::SmithMachineBenchPress),
+            "swiss_ball_dumbbell_chest_press" => {
+                Ok(BenchPressExerciseName::SwissBallDumbbellChestPress)
+            }
+            "triple_stop_barbell_bench_press" => {
+                Ok(BenchPressExerciseName::TripleStopBarbellBenchPress)
+            }
+            "wide_grip_barbell_bench_press" => {
+                Ok(BenchPressExerciseName::WideGripBarbellBenchPress)
+            }
+            "alternating_dumbbell_chest_press" => {
+                Ok(BenchPressExerciseName::AlternatingDumbbellChestPress)
+            }
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for BenchPressExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -17450,6 +20695,49 @@ impl convert::From<i64> for CalfRaiseExerciseName {
         CalfRaiseExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for CalfRaiseExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "3_way_calf_raise" => Ok(CalfRaiseExerciseName::Name3WayCalfRaise),
+            "3_way_weighted_calf_raise" => Ok(CalfRaiseExerciseName::Name3WayWeightedCalfRaise),
+            "3_way_single_leg_calf_raise" => Ok(CalfRaiseExerciseName::Name3WaySingleLegCalfRaise),
+            "3_way_weighted_single_leg_calf_raise" => {
+                Ok(CalfRaiseExerciseName::Name3WayWeightedSingleLegCalfRaise)
+            }
+            "donkey_calf_raise" => Ok(CalfRaiseExerciseName::DonkeyCalfRaise),
+            "weighted_donkey_calf_raise" => Ok(CalfRaiseExerciseName::WeightedDonkeyCalfRaise),
+            "seated_calf_raise" => Ok(CalfRaiseExerciseName::SeatedCalfRaise),
+            "weighted_seated_calf_raise" => Ok(CalfRaiseExerciseName::WeightedSeatedCalfRaise),
+            "seated_dumbbell_toe_raise" => Ok(CalfRaiseExerciseName::SeatedDumbbellToeRaise),
+            "single_leg_bent_knee_calf_raise" => {
+                Ok(CalfRaiseExerciseName::SingleLegBentKneeCalfRaise)
+            }
+            "weighted_single_leg_bent_knee_calf_raise" => {
+                Ok(CalfRaiseExerciseName::WeightedSingleLegBentKneeCalfRaise)
+            }
+            "single_leg_decline_push_up" => Ok(CalfRaiseExerciseName::SingleLegDeclinePushUp),
+            "single_leg_donkey_calf_raise" => Ok(CalfRaiseExerciseName::SingleLegDonkeyCalfRaise),
+            "weighted_single_leg_donkey_calf_raise" => {
+                Ok(CalfRaiseExerciseName::WeightedSingleLegDonkeyCalfRaise)
+            }
+            "single_leg_hip_raise_with_knee_hold" => {
+                Ok(CalfRaiseExerciseName::SingleLegHipRaiseWithKneeHold)
+            }
+            "single_leg_standing_calf_raise" => {
+                Ok(CalfRaiseExerciseName::SingleLegStandingCalfRaise)
+            }
+            "single_leg_standing_dumbbell_calf_raise" => {
+                Ok(CalfRaiseExerciseName::SingleLegStandingDumbbellCalfRaise)
+            }
+            "standing_barbell_calf_raise" => Ok(CalfRaiseExerciseName::StandingBarbellCalfRaise),
+            "standing_calf_raise" => Ok(CalfRaiseExerciseName::StandingCalfRaise),
+            "weighted_standing_calf_raise" => Ok(CalfRaiseExerciseName::WeightedStandingCalfRaise),
+            "standing_dumbbell_calf_raise" => Ok(CalfRaiseExerciseName::StandingDumbbellCalfRaise),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for CalfRaiseExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -17613,6 +20901,36 @@ impl convert::From<i64> for CardioExerciseName {
         CardioExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for CardioExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "bob_and_weave_circle" => Ok(CardioExerciseName::BobAndWeaveCircle),
+            "weighted_bob_and_weave_circle" => Ok(CardioExerciseName::WeightedBobAndWeaveCircle),
+            "cardio_core_crawl" => Ok(CardioExerciseName::CardioCoreCrawl),
+            "weighted_cardio_core_crawl" => Ok(CardioExerciseName::WeightedCardioCoreCrawl),
+            "double_under" => Ok(CardioExerciseName::DoubleUnder),
+            "weighted_double_under" => Ok(CardioExerciseName::WeightedDoubleUnder),
+            "jump_rope" => Ok(CardioExerciseName::JumpRope),
+            "weighted_jump_rope" => Ok(CardioExerciseName::WeightedJumpRope),
+            "jump_rope_crossover" => Ok(CardioExerciseName::JumpRopeCrossover),
+            "weighted_jump_rope_crossover" => Ok(CardioExerciseName::WeightedJumpRopeCrossover),
+            "jump_rope_jog" => Ok(CardioExerciseName::JumpRopeJog),
+            "weighted_jump_rope_jog" => Ok(CardioExerciseName::WeightedJumpRopeJog),
+            "jumping_jacks" => Ok(CardioExerciseName::JumpingJacks),
+            "weighted_jumping_jacks" => Ok(CardioExerciseName::WeightedJumpingJacks),
+            "ski_moguls" => Ok(CardioExerciseName::SkiMoguls),
+            "weighted_ski_moguls" => Ok(CardioExerciseName::WeightedSkiMoguls),
+            "split_jacks" => Ok(CardioExerciseName::SplitJacks),
+            "weighted_split_jacks" => Ok(CardioExerciseName::WeightedSplitJacks),
+            "squat_jacks" => Ok(CardioExerciseName::SquatJacks),
+            "weighted_squat_jacks" => Ok(CardioExerciseName::WeightedSquatJacks),
+            "triple_under" => Ok(CardioExerciseName::TripleUnder),
+            "weighted_triple_under" => Ok(CardioExerciseName::WeightedTripleUnder),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for CardioExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -17687,6 +21005,19 @@ impl convert::From<i64> for CarryExerciseName {
         CarryExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for CarryExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "bar_holds" => Ok(CarryExerciseName::BarHolds),
+            "farmers_walk" => Ok(CarryExerciseName::FarmersWalk),
+            "farmers_walk_on_toes" => Ok(CarryExerciseName::FarmersWalkOnToes),
+            "hex_dumbbell_hold" => Ok(CarryExerciseName::HexDumbbellHold),
+            "overhead_carry" => Ok(CarryExerciseName::OverheadCarry),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for CarryExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -17869,6 +21200,45 @@ impl convert::From<i64> for ChopExerciseName {
         ChopExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for ChopExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "cable_pull_through" => Ok(ChopExerciseName::CablePullThrough),
+            "cable_rotational_lift" => Ok(ChopExerciseName::CableRotationalLift),
+            "cable_woodchop" => Ok(ChopExerciseName::CableWoodchop),
+            "cross_chop_to_knee" => Ok(ChopExerciseName::CrossChopToKnee),
+            "weighted_cross_chop_to_knee" => Ok(ChopExerciseName::WeightedCrossChopToKnee),
+            "dumbbell_chop" => Ok(ChopExerciseName::DumbbellChop),
+            "half_kneeling_rotation" => Ok(ChopExerciseName::HalfKneelingRotation),
+            "weighted_half_kneeling_rotation" => Ok(ChopExerciseName::WeightedHalfKneelingRotation),
+            "half_kneeling_rotational_chop" => Ok(ChopExerciseName::HalfKneelingRotationalChop),
+            "half_kneeling_rotational_reverse_chop" => {
+                Ok(ChopExerciseName::HalfKneelingRotationalReverseChop)
+            }
+            "half_kneeling_stability_chop" => Ok(ChopExerciseName::HalfKneelingStabilityChop),
+            "half_kneeling_stability_reverse_chop" => {
+                Ok(ChopExerciseName::HalfKneelingStabilityReverseChop)
+            }
+            "kneeling_rotational_chop" => Ok(ChopExerciseName::KneelingRotationalChop),
+            "kneeling_rotational_reverse_chop" => {
+                Ok(ChopExerciseName::KneelingRotationalReverseChop)
+            }
+            "kneeling_stability_chop" => Ok(ChopExerciseName::KneelingStabilityChop),
+            "kneeling_woodchopper" => Ok(ChopExerciseName::KneelingWoodchopper),
+            "medicine_ball_wood_chops" => Ok(ChopExerciseName::MedicineBallWoodChops),
+            "power_squat_chops" => Ok(ChopExerciseName::PowerSquatChops),
+            "weighted_power_squat_chops" => Ok(ChopExerciseName::WeightedPowerSquatChops),
+            "standing_rotational_chop" => Ok(ChopExerciseName::StandingRotationalChop),
+            "standing_split_rotational_chop" => Ok(ChopExerciseName::StandingSplitRotationalChop),
+            "standing_split_rotational_reverse_chop" => {
+                Ok(ChopExerciseName::StandingSplitRotationalReverseChop)
+            }
+            "standing_stability_reverse_chop" => Ok(ChopExerciseName::StandingStabilityReverseChop),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for ChopExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -18304,6 +21674,91 @@ impl convert::From<i64> for CoreExerciseName {
         CoreExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for CoreExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "abs_jabs" => Ok(CoreExerciseName::AbsJabs),
+            "weighted_abs_jabs" => Ok(CoreExerciseName::WeightedAbsJabs),
+            "alternating_plate_reach" => Ok(CoreExerciseName::AlternatingPlateReach),
+            "barbell_rollout" => Ok(CoreExerciseName::BarbellRollout),
+            "weighted_barbell_rollout" => Ok(CoreExerciseName::WeightedBarbellRollout),
+            "body_bar_oblique_twist" => Ok(CoreExerciseName::BodyBarObliqueTwist),
+            "cable_core_press" => Ok(CoreExerciseName::CableCorePress),
+            "cable_side_bend" => Ok(CoreExerciseName::CableSideBend),
+            "side_bend" => Ok(CoreExerciseName::SideBend),
+            "weighted_side_bend" => Ok(CoreExerciseName::WeightedSideBend),
+            "crescent_circle" => Ok(CoreExerciseName::CrescentCircle),
+            "weighted_crescent_circle" => Ok(CoreExerciseName::WeightedCrescentCircle),
+            "cycling_russian_twist" => Ok(CoreExerciseName::CyclingRussianTwist),
+            "weighted_cycling_russian_twist" => Ok(CoreExerciseName::WeightedCyclingRussianTwist),
+            "elevated_feet_russian_twist" => Ok(CoreExerciseName::ElevatedFeetRussianTwist),
+            "weighted_elevated_feet_russian_twist" => {
+                Ok(CoreExerciseName::WeightedElevatedFeetRussianTwist)
+            }
+            "half_turkish_get_up" => Ok(CoreExerciseName::HalfTurkishGetUp),
+            "kettlebell_windmill" => Ok(CoreExerciseName::KettlebellWindmill),
+            "kneeling_ab_wheel" => Ok(CoreExerciseName::KneelingAbWheel),
+            "weighted_kneeling_ab_wheel" => Ok(CoreExerciseName::WeightedKneelingAbWheel),
+            "modified_front_lever" => Ok(CoreExerciseName::ModifiedFrontLever),
+            "open_knee_tucks" => Ok(CoreExerciseName::OpenKneeTucks),
+            "weighted_open_knee_tucks" => Ok(CoreExerciseName::WeightedOpenKneeTucks),
+            "side_abs_leg_lift" => Ok(CoreExerciseName::SideAbsLegLift),
+            "weighted_side_abs_leg_lift" => Ok(CoreExerciseName::WeightedSideAbsLegLift),
+            "swiss_ball_jackknife" => Ok(CoreExerciseName::SwissBallJackknife),
+            "weighted_swiss_ball_jackknife" => Ok(CoreExerciseName::WeightedSwissBallJackknife),
+            "swiss_ball_pike" => Ok(CoreExerciseName::SwissBallPike),
+            "weighted_swiss_ball_pike" => Ok(CoreExerciseName::WeightedSwissBallPike),
+            "swiss_ball_rollout" => Ok(CoreExerciseName::SwissBallRollout),
+            "weighted_swiss_ball_rollout" => Ok(CoreExerciseName::WeightedSwissBallRollout),
+            "triangle_hip_press" => Ok(CoreExerciseName::TriangleHipPress),
+            "weighted_triangle_hip_press" => Ok(CoreExerciseName::WeightedTriangleHipPress),
+            "trx_suspended_jackknife" => Ok(CoreExerciseName::TrxSuspendedJackknife),
+            "weighted_trx_suspended_jackknife" => {
+                Ok(CoreExerciseName::WeightedTrxSuspendedJackknife)
+            }
+            "u_boat" => Ok(CoreExerciseName::UBoat),
+            "weighted_u_boat" => Ok(CoreExerciseName::WeightedUBoat),
+            "windmill_switches" => Ok(CoreExerciseName::WindmillSwitches),
+            "weighted_windmill_switches" => Ok(CoreExerciseName::WeightedWindmillSwitches),
+            "alternating_slide_out" => Ok(CoreExerciseName::AlternatingSlideOut),
+            "weighted_alternating_slide_out" => Ok(CoreExerciseName::WeightedAlternatingSlideOut),
+            "ghd_back_extensions" => Ok(CoreExerciseName::GhdBackExtensions),
+            "weighted_ghd_back_extensions" => Ok(CoreExerciseName::WeightedGhdBackExtensions),
+            "overhead_walk" => Ok(CoreExerciseName::OverheadWalk),
+            "inchworm" => Ok(CoreExerciseName::Inchworm),
+            "weighted_modified_front_lever" => Ok(CoreExerciseName::WeightedModifiedFrontLever),
+            "russian_twist" => Ok(CoreExerciseName::RussianTwist),
+            "abdominal_leg_rotations" => Ok(CoreExerciseName::AbdominalLegRotations),
+            "arm_and_leg_extension_on_knees" => Ok(CoreExerciseName::ArmAndLegExtensionOnKnees),
+            "bicycle" => Ok(CoreExerciseName::Bicycle),
+            "bicep_curl_with_leg_extension" => Ok(CoreExerciseName::BicepCurlWithLegExtension),
+            "cat_cow" => Ok(CoreExerciseName::CatCow),
+            "corkscrew" => Ok(CoreExerciseName::Corkscrew),
+            "criss_cross" => Ok(CoreExerciseName::CrissCross),
+            "criss_cross_with_ball" => Ok(CoreExerciseName::CrissCrossWithBall),
+            "double_leg_stretch" => Ok(CoreExerciseName::DoubleLegStretch),
+            "knee_folds" => Ok(CoreExerciseName::KneeFolds),
+            "lower_lift" => Ok(CoreExerciseName::LowerLift),
+            "neck_pull" => Ok(CoreExerciseName::NeckPull),
+            "pelvic_clocks" => Ok(CoreExerciseName::PelvicClocks),
+            "roll_over" => Ok(CoreExerciseName::RollOver),
+            "roll_up" => Ok(CoreExerciseName::RollUp),
+            "rolling" => Ok(CoreExerciseName::Rolling),
+            "rowing_1" => Ok(CoreExerciseName::Rowing1),
+            "rowing_2" => Ok(CoreExerciseName::Rowing2),
+            "scissors" => Ok(CoreExerciseName::Scissors),
+            "single_leg_circles" => Ok(CoreExerciseName::SingleLegCircles),
+            "single_leg_stretch" => Ok(CoreExerciseName::SingleLegStretch),
+            "snake_twist_1_and_2" => Ok(CoreExerciseName::SnakeTwist1And2),
+            "swan" => Ok(CoreExerciseName::Swan),
+            "swimming" => Ok(CoreExerciseName::Swimming),
+            "teaser" => Ok(CoreExerciseName::Teaser),
+            "the_hundred" => Ok(CoreExerciseName::TheHundred),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for CoreExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -18838,6 +22293,141 @@ impl convert::From<i64> for CrunchExerciseName {
         CrunchExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for CrunchExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "bicycle_crunch" => Ok(CrunchExerciseName::BicycleCrunch),
+            "cable_crunch" => Ok(CrunchExerciseName::CableCrunch),
+            "circular_arm_crunch" => Ok(CrunchExerciseName::CircularArmCrunch),
+            "crossed_arms_crunch" => Ok(CrunchExerciseName::CrossedArmsCrunch),
+            "weighted_crossed_arms_crunch" => Ok(CrunchExerciseName::WeightedCrossedArmsCrunch),
+            "cross_leg_reverse_crunch" => Ok(CrunchExerciseName::CrossLegReverseCrunch),
+            "weighted_cross_leg_reverse_crunch" => {
+                Ok(CrunchExerciseName::WeightedCrossLegReverseCrunch)
+            }
+            "crunch_chop" => Ok(CrunchExerciseName::CrunchChop),
+            "weighted_crunch_chop" => Ok(CrunchExerciseName::WeightedCrunchChop),
+            "double_crunch" => Ok(CrunchExerciseName::DoubleCrunch),
+            "weighted_double_crunch" => Ok(CrunchExerciseName::WeightedDoubleCrunch),
+            "elbow_to_knee_crunch" => Ok(CrunchExerciseName::ElbowToKneeCrunch),
+            "weighted_elbow_to_knee_crunch" => Ok(CrunchExerciseName::WeightedElbowToKneeCrunch),
+            "flutter_kicks" => Ok(CrunchExerciseName::FlutterKicks),
+            "weighted_flutter_kicks" => Ok(CrunchExerciseName::WeightedFlutterKicks),
+            "foam_roller_reverse_crunch_on_bench" => {
+                Ok(CrunchExerciseName::FoamRollerReverseCrunchOnBench)
+            }
+            "weighted_foam_roller_reverse_crunch_on_bench" => {
+                Ok(CrunchExerciseName::WeightedFoamRollerReverseCrunchOnBench)
+            }
+            "foam_roller_reverse_crunch_with_dumbbell" => {
+                Ok(CrunchExerciseName::FoamRollerReverseCrunchWithDumbbell)
+            }
+            "foam_roller_reverse_crunch_with_medicine_ball" => {
+                Ok(CrunchExerciseName::FoamRollerReverseCrunchWithMedicineBall)
+            }
+            "frog_press" => Ok(CrunchExerciseName::FrogPress),
+            "hanging_knee_raise_oblique_crunch" => {
+                Ok(CrunchExerciseName::HangingKneeRaiseObliqueCrunch)
+            }
+            "weighted_hanging_knee_raise_oblique_crunch" => {
+                Ok(CrunchExerciseName::WeightedHangingKneeRaiseObliqueCrunch)
+            }
+            "hip_crossover" => Ok(CrunchExerciseName::HipCrossover),
+            "weighted_hip_crossover" => Ok(CrunchExerciseName::WeightedHipCrossover),
+            "hollow_rock" => Ok(CrunchExerciseName::HollowRock),
+            "weighted_hollow_rock" => Ok(CrunchExerciseName::WeightedHollowRock),
+            "incline_reverse_crunch" => Ok(CrunchExerciseName::InclineReverseCrunch),
+            "weighted_incline_reverse_crunch" => {
+                Ok(CrunchExerciseName::WeightedInclineReverseCrunch)
+            }
+            "kneeling_cable_crunch" => Ok(CrunchExerciseName::KneelingCableCrunch),
+            "kneeling_cross_crunch" => Ok(CrunchExerciseName::KneelingCrossCrunch),
+            "weighted_kneeling_cross_crunch" => Ok(CrunchExerciseName::WeightedKneelingCrossCrunch),
+            "kneeling_oblique_cable_crunch" => Ok(CrunchExerciseName::KneelingObliqueCableCrunch),
+            "knees_to_elbow" => Ok(CrunchExerciseName::KneesToElbow),
+            "leg_extensions" => Ok(CrunchExerciseName::LegExtensions),
+            "weighted_leg_extensions" => Ok(CrunchExerciseName::WeightedLegExtensions),
+            "leg_levers" => Ok(CrunchExerciseName::LegLevers),
+            "mcgill_curl_up" => Ok(CrunchExerciseName::McgillCurlUp),
+            "weighted_mcgill_curl_up" => Ok(CrunchExerciseName::WeightedMcgillCurlUp),
+            "modified_pilates_roll_up_with_ball" => {
+                Ok(CrunchExerciseName::ModifiedPilatesRollUpWithBall)
+            }
+            "weighted_modified_pilates_roll_up_with_ball" => {
+                Ok(CrunchExerciseName::WeightedModifiedPilatesRollUpWithBall)
+            }
+            "pilates_crunch" => Ok(CrunchExerciseName::PilatesCrunch),
+            "weighted_pilates_crunch" => Ok(CrunchExerciseName::WeightedPilatesCrunch),
+            "pilates_roll_up_with_ball" => Ok(CrunchExerciseName::PilatesRollUpWithBall),
+            "weighted_pilates_roll_up_with_ball" => {
+                Ok(CrunchExerciseName::WeightedPilatesRollUpWithBall)
+            }
+            "raised_legs_crunch" => Ok(CrunchExerciseName::RaisedLegsCrunch),
+            "weighted_raised_legs_crunch" => Ok(CrunchExerciseName::WeightedRaisedLegsCrunch),
+            "reverse_crunch" => Ok(CrunchExerciseName::ReverseCrunch),
+            "weighted_reverse_crunch" => Ok(CrunchExerciseName::WeightedReverseCrunch),
+            "reverse_crunch_on_a_bench" => Ok(CrunchExerciseName::ReverseCrunchOnABench),
+            "weighted_reverse_crunch_on_a_bench" => {
+                Ok(CrunchExerciseName::WeightedReverseCrunchOnABench)
+            }
+            "reverse_curl_and_lift" => Ok(CrunchExerciseName::ReverseCurlAndLift),
+            "weighted_reverse_curl_and_lift" => Ok(CrunchExerciseName::WeightedReverseCurlAndLift),
+            "rotational_lift" => Ok(CrunchExerciseName::RotationalLift),
+            "weighted_rotational_lift" => Ok(CrunchExerciseName::WeightedRotationalLift),
+            "seated_alternating_reverse_crunch" => {
+                Ok(CrunchExerciseName::SeatedAlternatingReverseCrunch)
+            }
+            "weighted_seated_alternating_reverse_crunch" => {
+                Ok(CrunchExerciseName::WeightedSeatedAlternatingReverseCrunch)
+            }
+            "seated_leg_u" => Ok(CrunchExerciseName::SeatedLegU),
+            "weighted_seated_leg_u" => Ok(CrunchExerciseName::WeightedSeatedLegU),
+            "side_to_side_crunch_and_weave" => Ok(CrunchExerciseName::SideToSideCrunchAndWeave),
+            "weighted_side_to_side_crunch_and_weave" => {
+                Ok(CrunchExerciseName::WeightedSideToSideCrunchAndWeave)
+            }
+            "single_leg_reverse_crunch" => Ok(CrunchExerciseName::SingleLegReverseCrunch),
+            "weighted_single_leg_reverse_crunch" => {
+                Ok(CrunchExerciseName::WeightedSingleLegReverseCrunch)
+            }
+            "skater_crunch_cross" => Ok(CrunchExerciseName::SkaterCrunchCross),
+            "weighted_skater_crunch_cross" => Ok(CrunchExerciseName::WeightedSkaterCrunchCross),
+            "standing_cable_crunch" => Ok(CrunchExerciseName::StandingCableCrunch),
+            "standing_side_crunch" => Ok(CrunchExerciseName::StandingSideCrunch),
+            "step_climb" => Ok(CrunchExerciseName::StepClimb),
+            "weighted_step_climb" => Ok(CrunchExerciseName::WeightedStepClimb),
+            "swiss_ball_crunch" => Ok(CrunchExerciseName::SwissBallCrunch),
+            "swiss_ball_reverse_crunch" => Ok(CrunchExerciseName::SwissBallReverseCrunch),
+            "weighted_swiss_ball_reverse_crunch" => {
+                Ok(CrunchExerciseName::WeightedSwissBallReverseCrunch)
+            }
+            "swiss_ball_russian_twist" => Ok(CrunchExerciseName::SwissBallRussianTwist),
+            "weighted_swiss_ball_russian_twist" => {
+                Ok(CrunchExerciseName::WeightedSwissBallRussianTwist)
+            }
+            "swiss_ball_side_crunch" => Ok(CrunchExerciseName::SwissBallSideCrunch),
+            "weighted_swiss_ball_side_crunch" => {
+                Ok(CrunchExerciseName::WeightedSwissBallSideCrunch)
+            }
+            "thoracic_crunches_on_foam_roller" => {
+                Ok(CrunchExerciseName::ThoracicCrunchesOnFoamRoller)
+            }
+            "weighted_thoracic_crunches_on_foam_roller" => {
+                Ok(CrunchExerciseName::WeightedThoracicCrunchesOnFoamRoller)
+            }
+            "triceps_crunch" => Ok(CrunchExerciseName::TricepsCrunch),
+            "weighted_bicycle_crunch" => Ok(CrunchExerciseName::WeightedBicycleCrunch),
+            "weighted_crunch" => Ok(CrunchExerciseName::WeightedCrunch),
+            "weighted_swiss_ball_crunch" => Ok(CrunchExerciseName::WeightedSwissBallCrunch),
+            "toes_to_bar" => Ok(CrunchExerciseName::ToesToBar),
+            "weighted_toes_to_bar" => Ok(CrunchExerciseName::WeightedToesToBar),
+            "crunch" => Ok(CrunchExerciseName::Crunch),
+            "straight_leg_crunch_with_ball" => Ok(CrunchExerciseName::StraightLegCrunchWithBall),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for CrunchExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -19149,6 +22739,84 @@ impl convert::From<i64> for CurlExerciseName {
         CurlExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for CurlExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "alternating_dumbbell_biceps_curl" => {
+                Ok(CurlExerciseName::AlternatingDumbbellBicepsCurl)
+            }
+            "alternating_dumbbell_biceps_curl_on_swiss_ball" => {
+                Ok(CurlExerciseName::AlternatingDumbbellBicepsCurlOnSwissBall)
+            }
+            "alternating_incline_dumbbell_biceps_curl" => {
+                Ok(CurlExerciseName::AlternatingInclineDumbbellBicepsCurl)
+            }
+            "barbell_biceps_curl" => Ok(CurlExerciseName::BarbellBicepsCurl),
+            "barbell_reverse_wrist_curl" => Ok(CurlExerciseName::BarbellReverseWristCurl),
+            "barbell_wrist_curl" => Ok(CurlExerciseName::BarbellWristCurl),
+            "behind_the_back_barbell_reverse_wrist_curl" => {
+                Ok(CurlExerciseName::BehindTheBackBarbellReverseWristCurl)
+            }
+            "behind_the_back_one_arm_cable_curl" => {
+                Ok(CurlExerciseName::BehindTheBackOneArmCableCurl)
+            }
+            "cable_biceps_curl" => Ok(CurlExerciseName::CableBicepsCurl),
+            "cable_hammer_curl" => Ok(CurlExerciseName::CableHammerCurl),
+            "cheating_barbell_biceps_curl" => Ok(CurlExerciseName::CheatingBarbellBicepsCurl),
+            "close_grip_ez_bar_biceps_curl" => Ok(CurlExerciseName::CloseGripEzBarBicepsCurl),
+            "cross_body_dumbbell_hammer_curl" => Ok(CurlExerciseName::CrossBodyDumbbellHammerCurl),
+            "dead_hang_biceps_curl" => Ok(CurlExerciseName::DeadHangBicepsCurl),
+            "decline_hammer_curl" => Ok(CurlExerciseName::DeclineHammerCurl),
+            "dumbbell_biceps_curl_with_static_hold" => {
+                Ok(CurlExerciseName::DumbbellBicepsCurlWithStaticHold)
+            }
+            "dumbbell_hammer_curl" => Ok(CurlExerciseName::DumbbellHammerCurl),
+            "dumbbell_reverse_wrist_curl" => Ok(CurlExerciseName::DumbbellReverseWristCurl),
+            "dumbbell_wrist_curl" => Ok(CurlExerciseName::DumbbellWristCurl),
+            "ez_bar_preacher_curl" => Ok(CurlExerciseName::EzBarPreacherCurl),
+            "forward_bend_biceps_curl" => Ok(CurlExerciseName::ForwardBendBicepsCurl),
+            "hammer_curl_to_press" => Ok(CurlExerciseName::HammerCurlToPress),
+            "incline_dumbbell_biceps_curl" => Ok(CurlExerciseName::InclineDumbbellBicepsCurl),
+            "incline_offset_thumb_dumbbell_curl" => {
+                Ok(CurlExerciseName::InclineOffsetThumbDumbbellCurl)
+            }
+            "kettlebell_biceps_curl" => Ok(CurlExerciseName::KettlebellBicepsCurl),
+            "lying_concentration_cable_curl" => Ok(CurlExerciseName::LyingConcentrationCableCurl),
+            "one_arm_preacher_curl" => Ok(CurlExerciseName::OneArmPreacherCurl),
+            "plate_pinch_curl" => Ok(CurlExerciseName::PlatePinchCurl),
+            "preacher_curl_with_cable" => Ok(CurlExerciseName::PreacherCurlWithCable),
+            "reverse_ez_bar_curl" => Ok(CurlExerciseName::ReverseEzBarCurl),
+            "reverse_grip_wrist_curl" => Ok(CurlExerciseName::ReverseGripWristCurl),
+            "reverse_grip_barbell_biceps_curl" => {
+                Ok(CurlExerciseName::ReverseGripBarbellBicepsCurl)
+            }
+            "seated_alternating_dumbbell_biceps_curl" => {
+                Ok(CurlExerciseName::SeatedAlternatingDumbbellBicepsCurl)
+            }
+            "seated_dumbbell_biceps_curl" => Ok(CurlExerciseName::SeatedDumbbellBicepsCurl),
+            "seated_reverse_dumbbell_curl" => Ok(CurlExerciseName::SeatedReverseDumbbellCurl),
+            "split_stance_offset_pinky_dumbbell_curl" => {
+                Ok(CurlExerciseName::SplitStanceOffsetPinkyDumbbellCurl)
+            }
+            "standing_alternating_dumbbell_curls" => {
+                Ok(CurlExerciseName::StandingAlternatingDumbbellCurls)
+            }
+            "standing_dumbbell_biceps_curl" => Ok(CurlExerciseName::StandingDumbbellBicepsCurl),
+            "standing_ez_bar_biceps_curl" => Ok(CurlExerciseName::StandingEzBarBicepsCurl),
+            "static_curl" => Ok(CurlExerciseName::StaticCurl),
+            "swiss_ball_dumbbell_overhead_triceps_extension" => {
+                Ok(CurlExerciseName::SwissBallDumbbellOverheadTricepsExtension)
+            }
+            "swiss_ball_ez_bar_preacher_curl" => Ok(CurlExerciseName::SwissBallEzBarPreacherCurl),
+            "twisting_standing_dumbbell_biceps_curl" => {
+                Ok(CurlExerciseName::TwistingStandingDumbbellBicepsCurl)
+            }
+            "wide_grip_ez_bar_biceps_curl" => Ok(CurlExerciseName::WideGripEzBarBicepsCurl),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for CurlExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -19311,6 +22979,45 @@ impl convert::From<i64> for DeadliftExerciseName {
         DeadliftExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for DeadliftExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "barbell_deadlift" => Ok(DeadliftExerciseName::BarbellDeadlift),
+            "barbell_straight_leg_deadlift" => Ok(DeadliftExerciseName::BarbellStraightLegDeadlift),
+            "dumbbell_deadlift" => Ok(DeadliftExerciseName::DumbbellDeadlift),
+            "dumbbell_single_leg_deadlift_to_row" => {
+                Ok(DeadliftExerciseName::DumbbellSingleLegDeadliftToRow)
+            }
+            "dumbbell_straight_leg_deadlift" => {
+                Ok(DeadliftExerciseName::DumbbellStraightLegDeadlift)
+            }
+            "kettlebell_floor_to_shelf" => Ok(DeadliftExerciseName::KettlebellFloorToShelf),
+            "one_arm_one_leg_deadlift" => Ok(DeadliftExerciseName::OneArmOneLegDeadlift),
+            "rack_pull" => Ok(DeadliftExerciseName::RackPull),
+            "rotational_dumbbell_straight_leg_deadlift" => {
+                Ok(DeadliftExerciseName::RotationalDumbbellStraightLegDeadlift)
+            }
+            "single_arm_deadlift" => Ok(DeadliftExerciseName::SingleArmDeadlift),
+            "single_leg_barbell_deadlift" => Ok(DeadliftExerciseName::SingleLegBarbellDeadlift),
+            "single_leg_barbell_straight_leg_deadlift" => {
+                Ok(DeadliftExerciseName::SingleLegBarbellStraightLegDeadlift)
+            }
+            "single_leg_deadlift_with_barbell" => {
+                Ok(DeadliftExerciseName::SingleLegDeadliftWithBarbell)
+            }
+            "single_leg_rdl_circuit" => Ok(DeadliftExerciseName::SingleLegRdlCircuit),
+            "single_leg_romanian_deadlift_with_dumbbell" => {
+                Ok(DeadliftExerciseName::SingleLegRomanianDeadliftWithDumbbell)
+            }
+            "sumo_deadlift" => Ok(DeadliftExerciseName::SumoDeadlift),
+            "sumo_deadlift_high_pull" => Ok(DeadliftExerciseName::SumoDeadliftHighPull),
+            "trap_bar_deadlift" => Ok(DeadliftExerciseName::TrapBarDeadlift),
+            "wide_grip_barbell_deadlift" => Ok(DeadliftExerciseName::WideGripBarbellDeadlift),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for DeadliftExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -19412,6 +23119,26 @@ impl convert::From<i64> for FlyeExerciseName {
         FlyeExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for FlyeExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "cable_crossover" => Ok(FlyeExerciseName::CableCrossover),
+            "decline_dumbbell_flye" => Ok(FlyeExerciseName::DeclineDumbbellFlye),
+            "dumbbell_flye" => Ok(FlyeExerciseName::DumbbellFlye),
+            "incline_dumbbell_flye" => Ok(FlyeExerciseName::InclineDumbbellFlye),
+            "kettlebell_flye" => Ok(FlyeExerciseName::KettlebellFlye),
+            "kneeling_rear_flye" => Ok(FlyeExerciseName::KneelingRearFlye),
+            "single_arm_standing_cable_reverse_flye" => {
+                Ok(FlyeExerciseName::SingleArmStandingCableReverseFlye)
+            }
+            "swiss_ball_dumbbell_flye" => Ok(FlyeExerciseName::SwissBallDumbbellFlye),
+            "arm_rotations" => Ok(FlyeExerciseName::ArmRotations),
+            "hug_a_tree" => Ok(FlyeExerciseName::HugATree),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for FlyeExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -19777,11 +23504,119 @@ impl convert::From<u16> for HipRaiseExerciseName {
         }
     }
 }
-impl convert::From<i64> for HipRaiseExerciseName {
-    fn from(value: i64) -> Self {
-        HipRaiseExerciseName::from(value as u16)
-    }
-}
+impl convert::From<i64> for HipRaiseExerciseName {
+    fn from(value: i64) -> Self {
+        HipRaiseExerciseName::from(value as u16)
+    }
+}
+impl str::FromStr for HipRaiseExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "barbell_hip_thrust_on_floor" => Ok(HipRaiseExerciseName::BarbellHipThrustOnFloor),
+            "barbell_hip_thrust_with_bench" => Ok(HipRaiseExerciseName::BarbellHipThrustWithBench),
+            "bent_knee_swiss_ball_reverse_hip_raise" => {
+                Ok(HipRaiseExerciseName::BentKneeSwissBallReverseHipRaise)
+            }
+            "weighted_bent_knee_swiss_ball_reverse_hip_raise" => {
+                Ok(HipRaiseExerciseName::WeightedBentKneeSwissBallReverseHipRaise)
+            }
+            "bridge_with_leg_extension" => Ok(HipRaiseExerciseName::BridgeWithLegExtension),
+            "weighted_bridge_with_leg_extension" => {
+                Ok(HipRaiseExerciseName::WeightedBridgeWithLegExtension)
+            }
+            "clam_bridge" => Ok(HipRaiseExerciseName::ClamBridge),
+            "front_kick_tabletop" => Ok(HipRaiseExerciseName::FrontKickTabletop),
+            "weighted_front_kick_tabletop" => Ok(HipRaiseExerciseName::WeightedFrontKickTabletop),
+            "hip_extension_and_cross" => Ok(HipRaiseExerciseName::HipExtensionAndCross),
+            "weighted_hip_extension_and_cross" => {
+                Ok(HipRaiseExerciseName::WeightedHipExtensionAndCross)
+            }
+            "hip_raise" => Ok(HipRaiseExerciseName::HipRaise),
+            "weighted_hip_raise" => Ok(HipRaiseExerciseName::WeightedHipRaise),
+            "hip_raise_with_feet_on_swiss_ball" => {
+                Ok(HipRaiseExerciseName::HipRaiseWithFeetOnSwissBall)
+            }
+            "weighted_hip_raise_with_feet_on_swiss_ball" => {
+                Ok(HipRaiseExerciseName::WeightedHipRaiseWithFeetOnSwissBall)
+            }
+            "hip_raise_with_head_on_bosu_ball" => {
+                Ok(HipRaiseExerciseName::HipRaiseWithHeadOnBosuBall)
+            }
+            "weighted_hip_raise_with_head_on_bosu_ball" => {
+                Ok(HipRaiseExerciseName::WeightedHipRaiseWithHeadOnBosuBall)
+            }
+            "hip_raise_with_head_on_swiss_ball" => {
+                Ok(HipRaiseExerciseName::HipRaiseWithHeadOnSwissBall)
+            }
+            "weighted_hip_raise_with_head_on_swiss_ball" => {
+                Ok(HipRaiseExerciseName::WeightedHipRaiseWithHeadOnSwissBall)
+            }
+            "hip_raise_with_knee_squeeze" => Ok(HipRaiseExerciseName::HipRaiseWithKneeSqueeze),
+            "weighted_hip_raise_with_knee_squeeze" => {
+                Ok(HipRaiseExerciseName::WeightedHipRaiseWithKneeSqueeze)
+            }
+            "incline_rear_leg_extension" => Ok(HipRaiseExerciseName::InclineRearLegExtension),
+            "weighted_incline_rear_leg_extension" => {
+                Ok(HipRaiseExerciseName::WeightedInclineRearLegExtension)
+            }
+            "kettlebell_swing" => Ok(HipRaiseExerciseName::KettlebellSwing),
+            "marching_hip_raise" => Ok(HipRaiseExerciseName::MarchingHipRaise),
+            "weighted_marching_hip_raise" => Ok(HipRaiseExerciseName::WeightedMarchingHipRaise),
+            "marching_hip_raise_with_feet_on_a_swiss_ball" => {
+                Ok(HipRaiseExerciseName::MarchingHipRaiseWithFeetOnASwissBall)
+            }
+            "weighted_marching_hip_raise_with_feet_on_a_swiss_ball" => {
+                Ok(HipRaiseExerciseName::WeightedMarchingHipRaiseWithFeetOnASwissBall)
+            }
+            "reverse_hip_raise" => Ok(HipRaiseExerciseName::ReverseHipRaise),
+            "weighted_reverse_hip_raise" => Ok(HipRaiseExerciseName::WeightedReverseHipRaise),
+            "single_leg_hip_raise" => Ok(HipRaiseExerciseName::SingleLegHipRaise),
+            "weighted_single_leg_hip_raise" => Ok(HipRaiseExerciseName::WeightedSingleLegHipRaise),
+            "single_leg_hip_raise_with_foot_on_bench" => {
+                Ok(HipRaiseExerciseName::SingleLegHipRaiseWithFootOnBench)
+            }
+            "weighted_single_leg_hip_raise_with_foot_on_bench" => {
+                Ok(HipRaiseExerciseName::WeightedSingleLegHipRaiseWithFootOnBench)
+            }
+            "single_leg_hip_raise_with_foot_on_bosu_ball" => {
+                Ok(HipRaiseExerciseName::SingleLegHipRaiseWithFootOnBosuBall)
+            }
+            "weighted_single_leg_hip_raise_with_foot_on_bosu_ball" => {
+                Ok(HipRaiseExerciseName::WeightedSingleLegHipRaiseWithFootOnBosuBall)
+            }
+            "single_leg_hip_raise_with_foot_on_foam_roller" => {
+                Ok(HipRaiseExerciseName::SingleLegHipRaiseWithFootOnFoamRoller)
+            }
+            "weighted_single_leg_hip_raise_with_foot_on_foam_roller" => {
+                Ok(HipRaiseExerciseName::WeightedSingleLegHipRaiseWithFootOnFoamRoller)
+            }
+            "single_leg_hip_raise_with_foot_on_medicine_ball" => {
+                Ok(HipRaiseExerciseName::SingleLegHipRaiseWithFootOnMedicineBall)
+            }
+            "weighted_single_leg_hip_raise_with_foot_on_medicine_ball" => {
+                Ok(HipRaiseExerciseName::WeightedSingleLegHipRaiseWithFootOnMedicineBall)
+            }
+            "single_leg_hip_raise_with_head_on_bosu_ball" => {
+                Ok(HipRaiseExerciseName::SingleLegHipRaiseWithHeadOnBosuBall)
+            }
+            "weighted_single_leg_hip_raise_with_head_on_bosu_ball" => {
+                Ok(HipRaiseExerciseName::WeightedSingleLegHipRaiseWithHeadOnBosuBall)
+            }
+            "weighted_clam_bridge" => Ok(HipRaiseExerciseName::WeightedClamBridge),
+            "single_leg_swiss_ball_hip_raise_and_leg_curl" => {
+                Ok(HipRaiseExerciseName::SingleLegSwissBallHipRaiseAndLegCurl)
+            }
+            "clams" => Ok(HipRaiseExerciseName::Clams),
+            "inner_thigh_circles" => Ok(HipRaiseExerciseName::InnerThighCircles),
+            "inner_thigh_side_lift" => Ok(HipRaiseExerciseName::InnerThighSideLift),
+            "leg_circles" => Ok(HipRaiseExerciseName::LegCircles),
+            "leg_lift" => Ok(HipRaiseExerciseName::LegLift),
+            "leg_lift_in_external_rotation" => Ok(HipRaiseExerciseName::LegLiftInExternalRotation),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for HipRaiseExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -20037,6 +23872,72 @@ impl convert::From<i64> for HipStabilityExerciseName {
         HipStabilityExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for HipStabilityExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "band_side_lying_leg_raise" => Ok(HipStabilityExerciseName::BandSideLyingLegRaise),
+            "dead_bug" => Ok(HipStabilityExerciseName::DeadBug),
+            "weighted_dead_bug" => Ok(HipStabilityExerciseName::WeightedDeadBug),
+            "external_hip_raise" => Ok(HipStabilityExerciseName::ExternalHipRaise),
+            "weighted_external_hip_raise" => Ok(HipStabilityExerciseName::WeightedExternalHipRaise),
+            "fire_hydrant_kicks" => Ok(HipStabilityExerciseName::FireHydrantKicks),
+            "weighted_fire_hydrant_kicks" => Ok(HipStabilityExerciseName::WeightedFireHydrantKicks),
+            "hip_circles" => Ok(HipStabilityExerciseName::HipCircles),
+            "weighted_hip_circles" => Ok(HipStabilityExerciseName::WeightedHipCircles),
+            "inner_thigh_lift" => Ok(HipStabilityExerciseName::InnerThighLift),
+            "weighted_inner_thigh_lift" => Ok(HipStabilityExerciseName::WeightedInnerThighLift),
+            "lateral_walks_with_band_at_ankles" => {
+                Ok(HipStabilityExerciseName::LateralWalksWithBandAtAnkles)
+            }
+            "pretzel_side_kick" => Ok(HipStabilityExerciseName::PretzelSideKick),
+            "weighted_pretzel_side_kick" => Ok(HipStabilityExerciseName::WeightedPretzelSideKick),
+            "prone_hip_internal_rotation" => Ok(HipStabilityExerciseName::ProneHipInternalRotation),
+            "weighted_prone_hip_internal_rotation" => {
+                Ok(HipStabilityExerciseName::WeightedProneHipInternalRotation)
+            }
+            "quadruped" => Ok(HipStabilityExerciseName::Quadruped),
+            "quadruped_hip_extension" => Ok(HipStabilityExerciseName::QuadrupedHipExtension),
+            "weighted_quadruped_hip_extension" => {
+                Ok(HipStabilityExerciseName::WeightedQuadrupedHipExtension)
+            }
+            "quadruped_with_leg_lift" => Ok(HipStabilityExerciseName::QuadrupedWithLegLift),
+            "weighted_quadruped_with_leg_lift" => {
+                Ok(HipStabilityExerciseName::WeightedQuadrupedWithLegLift)
+            }
+            "side_lying_leg_raise" => Ok(HipStabilityExerciseName::SideLyingLegRaise),
+            "weighted_side_lying_leg_raise" => {
+                Ok(HipStabilityExerciseName::WeightedSideLyingLegRaise)
+            }
+            "sliding_hip_adduction" => Ok(HipStabilityExerciseName::SlidingHipAdduction),
+            "weighted_sliding_hip_adduction" => {
+                Ok(HipStabilityExerciseName::WeightedSlidingHipAdduction)
+            }
+            "standing_adduction" => Ok(HipStabilityExerciseName::StandingAdduction),
+            "weighted_standing_adduction" => {
+                Ok(HipStabilityExerciseName::WeightedStandingAdduction)
+            }
+            "standing_cable_hip_abduction" => {
+                Ok(HipStabilityExerciseName::StandingCableHipAbduction)
+            }
+            "standing_hip_abduction" => Ok(HipStabilityExerciseName::StandingHipAbduction),
+            "weighted_standing_hip_abduction" => {
+                Ok(HipStabilityExerciseName::WeightedStandingHipAbduction)
+            }
+            "standing_rear_leg_raise" => Ok(HipStabilityExerciseName::StandingRearLegRaise),
+            "weighted_standing_rear_leg_raise" => {
+                Ok(HipStabilityExerciseName::WeightedStandingRearLegRaise)
+            }
+            "supine_hip_internal_rotation" => {
+                Ok(HipStabilityExerciseName::SupineHipInternalRotation)
+            }
+            "weighted_supine_hip_internal_rotation" => {
+                Ok(HipStabilityExerciseName::WeightedSupineHipInternalRotation)
+            }
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for HipStabilityExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -20103,6 +24004,17 @@ impl convert::From<i64> for HipSwingExerciseName {
         HipSwingExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for HipSwingExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "single_arm_kettlebell_swing" => Ok(HipSwingExerciseName::SingleArmKettlebellSwing),
+            "single_arm_dumbbell_swing" => Ok(HipSwingExerciseName::SingleArmDumbbellSwing),
+            "step_out_swing" => Ok(HipSwingExerciseName::StepOutSwing),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for HipSwingExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -20395,6 +24307,84 @@ impl convert::From<i64> for HyperextensionExerciseName {
         HyperextensionExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for HyperextensionExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "back_extension_with_opposite_arm_and_leg_reach" => {
+                Ok(HyperextensionExerciseName::BackExtensionWithOppositeArmAndLegReach)
+            }
+            "weighted_back_extension_with_opposite_arm_and_leg_reach" => {
+                Ok(HyperextensionExerciseName::WeightedBackExtensionWithOppositeArmAndLegReach)
+            }
+            "base_rotations" => Ok(HyperextensionExerciseName::BaseRotations),
+            "weighted_base_rotations" => Ok(HyperextensionExerciseName::WeightedBaseRotations),
+            "bent_knee_reverse_hyperextension" => {
+                Ok(HyperextensionExerciseName::BentKneeReverseHyperextension)
+            }
+            "weighted_bent_knee_reverse_hyperextension" => {
+                Ok(HyperextensionExerciseName::WeightedBentKneeReverseHyperextension)
+            }
+            "hollow_hold_and_roll" => Ok(HyperextensionExerciseName::HollowHoldAndRoll),
+            "weighted_hollow_hold_and_roll" => {
+                Ok(HyperextensionExerciseName::WeightedHollowHoldAndRoll)
+            }
+            "kicks" => Ok(HyperextensionExerciseName::Kicks),
+            "weighted_kicks" => Ok(HyperextensionExerciseName::WeightedKicks),
+            "knee_raises" => Ok(HyperextensionExerciseName::KneeRaises),
+            "weighted_knee_raises" => Ok(HyperextensionExerciseName::WeightedKneeRaises),
+            "kneeling_superman" => Ok(HyperextensionExerciseName::KneelingSuperman),
+            "weighted_kneeling_superman" => {
+                Ok(HyperextensionExerciseName::WeightedKneelingSuperman)
+            }
+            "lat_pull_down_with_row" => Ok(HyperextensionExerciseName::LatPullDownWithRow),
+            "medicine_ball_deadlift_to_reach" => {
+                Ok(HyperextensionExerciseName::MedicineBallDeadliftToReach)
+            }
+            "one_arm_one_leg_row" => Ok(HyperextensionExerciseName::OneArmOneLegRow),
+            "one_arm_row_with_band" => Ok(HyperextensionExerciseName::OneArmRowWithBand),
+            "overhead_lunge_with_medicine_ball" => {
+                Ok(HyperextensionExerciseName::OverheadLungeWithMedicineBall)
+            }
+            "plank_knee_tucks" => Ok(HyperextensionExerciseName::PlankKneeTucks),
+            "weighted_plank_knee_tucks" => Ok(HyperextensionExerciseName::WeightedPlankKneeTucks),
+            "side_step" => Ok(HyperextensionExerciseName::SideStep),
+            "weighted_side_step" => Ok(HyperextensionExerciseName::WeightedSideStep),
+            "single_leg_back_extension" => Ok(HyperextensionExerciseName::SingleLegBackExtension),
+            "weighted_single_leg_back_extension" => {
+                Ok(HyperextensionExerciseName::WeightedSingleLegBackExtension)
+            }
+            "spine_extension" => Ok(HyperextensionExerciseName::SpineExtension),
+            "weighted_spine_extension" => Ok(HyperextensionExerciseName::WeightedSpineExtension),
+            "static_back_extension" => Ok(HyperextensionExerciseName::StaticBackExtension),
+            "weighted_static_back_extension" => {
+                Ok(HyperextensionExerciseName::WeightedStaticBackExtension)
+            }
+            "superman_from_floor" => Ok(HyperextensionExerciseName::SupermanFromFloor),
+            "weighted_superman_from_floor" => {
+                Ok(HyperextensionExerciseName::WeightedSupermanFromFloor)
+            }
+            "swiss_ball_back_extension" => Ok(HyperextensionExerciseName::SwissBallBackExtension),
+            "weighted_swiss_ball_back_extension" => {
+                Ok(HyperextensionExerciseName::WeightedSwissBallBackExtension)
+            }
+            "swiss_ball_hyperextension" => Ok(HyperextensionExerciseName::SwissBallHyperextension),
+            "weighted_swiss_ball_hyperextension" => {
+                Ok(HyperextensionExerciseName::WeightedSwissBallHyperextension)
+            }
+            "swiss_ball_opposite_arm_and_leg_lift" => {
+                Ok(HyperextensionExerciseName::SwissBallOppositeArmAndLegLift)
+            }
+            "weighted_swiss_ball_opposite_arm_and_leg_lift" => {
+                Ok(HyperextensionExerciseName::WeightedSwissBallOppositeArmAndLegLift)
+            }
+            "superman_on_swiss_ball" => Ok(HyperextensionExerciseName::SupermanOnSwissBall),
+            "cobra" => Ok(HyperextensionExerciseName::Cobra),
+            "supine_floor_barre" => Ok(HyperextensionExerciseName::SupineFloorBarre),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for HyperextensionExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -20630,6 +24620,58 @@ impl convert::From<i64> for LateralRaiseExerciseName {
         LateralRaiseExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for LateralRaiseExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "45_degree_cable_external_rotation" => {
+                Ok(LateralRaiseExerciseName::Name45DegreeCableExternalRotation)
+            }
+            "alternating_lateral_raise_with_static_hold" => {
+                Ok(LateralRaiseExerciseName::AlternatingLateralRaiseWithStaticHold)
+            }
+            "bar_muscle_up" => Ok(LateralRaiseExerciseName::BarMuscleUp),
+            "bent_over_lateral_raise" => Ok(LateralRaiseExerciseName::BentOverLateralRaise),
+            "cable_diagonal_raise" => Ok(LateralRaiseExerciseName::CableDiagonalRaise),
+            "cable_front_raise" => Ok(LateralRaiseExerciseName::CableFrontRaise),
+            "calorie_row" => Ok(LateralRaiseExerciseName::CalorieRow),
+            "combo_shoulder_raise" => Ok(LateralRaiseExerciseName::ComboShoulderRaise),
+            "dumbbell_diagonal_raise" => Ok(LateralRaiseExerciseName::DumbbellDiagonalRaise),
+            "dumbbell_v_raise" => Ok(LateralRaiseExerciseName::DumbbellVRaise),
+            "front_raise" => Ok(LateralRaiseExerciseName::FrontRaise),
+            "leaning_dumbbell_lateral_raise" => {
+                Ok(LateralRaiseExerciseName::LeaningDumbbellLateralRaise)
+            }
+            "lying_dumbbell_raise" => Ok(LateralRaiseExerciseName::LyingDumbbellRaise),
+            "muscle_up" => Ok(LateralRaiseExerciseName::MuscleUp),
+            "one_arm_cable_lateral_raise" => Ok(LateralRaiseExerciseName::OneArmCableLateralRaise),
+            "overhand_grip_rear_lateral_raise" => {
+                Ok(LateralRaiseExerciseName::OverhandGripRearLateralRaise)
+            }
+            "plate_raises" => Ok(LateralRaiseExerciseName::PlateRaises),
+            "ring_dip" => Ok(LateralRaiseExerciseName::RingDip),
+            "weighted_ring_dip" => Ok(LateralRaiseExerciseName::WeightedRingDip),
+            "ring_muscle_up" => Ok(LateralRaiseExerciseName::RingMuscleUp),
+            "weighted_ring_muscle_up" => Ok(LateralRaiseExerciseName::WeightedRingMuscleUp),
+            "rope_climb" => Ok(LateralRaiseExerciseName::RopeClimb),
+            "weighted_rope_climb" => Ok(LateralRaiseExerciseName::WeightedRopeClimb),
+            "scaption" => Ok(LateralRaiseExerciseName::Scaption),
+            "seated_lateral_raise" => Ok(LateralRaiseExerciseName::SeatedLateralRaise),
+            "seated_rear_lateral_raise" => Ok(LateralRaiseExerciseName::SeatedRearLateralRaise),
+            "side_lying_lateral_raise" => Ok(LateralRaiseExerciseName::SideLyingLateralRaise),
+            "standing_lift" => Ok(LateralRaiseExerciseName::StandingLift),
+            "suspended_row" => Ok(LateralRaiseExerciseName::SuspendedRow),
+            "underhand_grip_rear_lateral_raise" => {
+                Ok(LateralRaiseExerciseName::UnderhandGripRearLateralRaise)
+            }
+            "wall_slide" => Ok(LateralRaiseExerciseName::WallSlide),
+            "weighted_wall_slide" => Ok(LateralRaiseExerciseName::WeightedWallSlide),
+            "arm_circles" => Ok(LateralRaiseExerciseName::ArmCircles),
+            "shaving_the_head" => Ok(LateralRaiseExerciseName::ShavingTheHead),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for LateralRaiseExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -20749,6 +24791,30 @@ impl convert::From<i64> for LegCurlExerciseName {
         LegCurlExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for LegCurlExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "leg_curl" => Ok(LegCurlExerciseName::LegCurl),
+            "weighted_leg_curl" => Ok(LegCurlExerciseName::WeightedLegCurl),
+            "good_morning" => Ok(LegCurlExerciseName::GoodMorning),
+            "seated_barbell_good_morning" => Ok(LegCurlExerciseName::SeatedBarbellGoodMorning),
+            "single_leg_barbell_good_morning" => {
+                Ok(LegCurlExerciseName::SingleLegBarbellGoodMorning)
+            }
+            "single_leg_sliding_leg_curl" => Ok(LegCurlExerciseName::SingleLegSlidingLegCurl),
+            "sliding_leg_curl" => Ok(LegCurlExerciseName::SlidingLegCurl),
+            "split_barbell_good_morning" => Ok(LegCurlExerciseName::SplitBarbellGoodMorning),
+            "split_stance_extension" => Ok(LegCurlExerciseName::SplitStanceExtension),
+            "staggered_stance_good_morning" => Ok(LegCurlExerciseName::StaggeredStanceGoodMorning),
+            "swiss_ball_hip_raise_and_leg_curl" => {
+                Ok(LegCurlExerciseName::SwissBallHipRaiseAndLegCurl)
+            }
+            "zercher_good_morning" => Ok(LegCurlExerciseName::ZercherGoodMorning),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for LegCurlExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -20928,6 +24994,46 @@ impl convert::From<i64> for LegRaiseExerciseName {
         LegRaiseExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for LegRaiseExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "hanging_knee_raise" => Ok(LegRaiseExerciseName::HangingKneeRaise),
+            "hanging_leg_raise" => Ok(LegRaiseExerciseName::HangingLegRaise),
+            "weighted_hanging_leg_raise" => Ok(LegRaiseExerciseName::WeightedHangingLegRaise),
+            "hanging_single_leg_raise" => Ok(LegRaiseExerciseName::HangingSingleLegRaise),
+            "weighted_hanging_single_leg_raise" => {
+                Ok(LegRaiseExerciseName::WeightedHangingSingleLegRaise)
+            }
+            "kettlebell_leg_raises" => Ok(LegRaiseExerciseName::KettlebellLegRaises),
+            "leg_lowering_drill" => Ok(LegRaiseExerciseName::LegLoweringDrill),
+            "weighted_leg_lowering_drill" => Ok(LegRaiseExerciseName::WeightedLegLoweringDrill),
+            "lying_straight_leg_raise" => Ok(LegRaiseExerciseName::LyingStraightLegRaise),
+            "weighted_lying_straight_leg_raise" => {
+                Ok(LegRaiseExerciseName::WeightedLyingStraightLegRaise)
+            }
+            "medicine_ball_leg_drops" => Ok(LegRaiseExerciseName::MedicineBallLegDrops),
+            "quadruped_leg_raise" => Ok(LegRaiseExerciseName::QuadrupedLegRaise),
+            "weighted_quadruped_leg_raise" => Ok(LegRaiseExerciseName::WeightedQuadrupedLegRaise),
+            "reverse_leg_raise" => Ok(LegRaiseExerciseName::ReverseLegRaise),
+            "weighted_reverse_leg_raise" => Ok(LegRaiseExerciseName::WeightedReverseLegRaise),
+            "reverse_leg_raise_on_swiss_ball" => {
+                Ok(LegRaiseExerciseName::ReverseLegRaiseOnSwissBall)
+            }
+            "weighted_reverse_leg_raise_on_swiss_ball" => {
+                Ok(LegRaiseExerciseName::WeightedReverseLegRaiseOnSwissBall)
+            }
+            "single_leg_lowering_drill" => Ok(LegRaiseExerciseName::SingleLegLoweringDrill),
+            "weighted_single_leg_lowering_drill" => {
+                Ok(LegRaiseExerciseName::WeightedSingleLegLoweringDrill)
+            }
+            "weighted_hanging_knee_raise" => Ok(LegRaiseExerciseName::WeightedHangingKneeRaise),
+            "lateral_stepover" => Ok(LegRaiseExerciseName::LateralStepover),
+            "weighted_lateral_stepover" => Ok(LegRaiseExerciseName::WeightedLateralStepover),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for LegRaiseExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -21442,6 +25548,137 @@ impl convert::From<i64> for LungeExerciseName {
         LungeExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for LungeExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "overhead_lunge" => Ok(LungeExerciseName::OverheadLunge),
+            "lunge_matrix" => Ok(LungeExerciseName::LungeMatrix),
+            "weighted_lunge_matrix" => Ok(LungeExerciseName::WeightedLungeMatrix),
+            "alternating_barbell_forward_lunge" => {
+                Ok(LungeExerciseName::AlternatingBarbellForwardLunge)
+            }
+            "alternating_dumbbell_lunge_with_reach" => {
+                Ok(LungeExerciseName::AlternatingDumbbellLungeWithReach)
+            }
+            "back_foot_elevated_dumbbell_split_squat" => {
+                Ok(LungeExerciseName::BackFootElevatedDumbbellSplitSquat)
+            }
+            "barbell_box_lunge" => Ok(LungeExerciseName::BarbellBoxLunge),
+            "barbell_bulgarian_split_squat" => Ok(LungeExerciseName::BarbellBulgarianSplitSquat),
+            "barbell_crossover_lunge" => Ok(LungeExerciseName::BarbellCrossoverLunge),
+            "barbell_front_split_squat" => Ok(LungeExerciseName::BarbellFrontSplitSquat),
+            "barbell_lunge" => Ok(LungeExerciseName::BarbellLunge),
+            "barbell_reverse_lunge" => Ok(LungeExerciseName::BarbellReverseLunge),
+            "barbell_side_lunge" => Ok(LungeExerciseName::BarbellSideLunge),
+            "barbell_split_squat" => Ok(LungeExerciseName::BarbellSplitSquat),
+            "core_control_rear_lunge" => Ok(LungeExerciseName::CoreControlRearLunge),
+            "diagonal_lunge" => Ok(LungeExerciseName::DiagonalLunge),
+            "drop_lunge" => Ok(LungeExerciseName::DropLunge),
+            "dumbbell_box_lunge" => Ok(LungeExerciseName::DumbbellBoxLunge),
+            "dumbbell_bulgarian_split_squat" => Ok(LungeExerciseName::DumbbellBulgarianSplitSquat),
+            "dumbbell_crossover_lunge" => Ok(LungeExerciseName::DumbbellCrossoverLunge),
+            "dumbbell_diagonal_lunge" => Ok(LungeExerciseName::DumbbellDiagonalLunge),
+            "dumbbell_lunge" => Ok(LungeExerciseName::DumbbellLunge),
+            "dumbbell_lunge_and_rotation" => Ok(LungeExerciseName::DumbbellLungeAndRotation),
+            "dumbbell_overhead_bulgarian_split_squat" => {
+                Ok(LungeExerciseName::DumbbellOverheadBulgarianSplitSquat)
+            }
+            "dumbbell_reverse_lunge_to_high_knee_and_press" => {
+                Ok(LungeExerciseName::DumbbellReverseLungeToHighKneeAndPress)
+            }
+            "dumbbell_side_lunge" => Ok(LungeExerciseName::DumbbellSideLunge),
+            "elevated_front_foot_barbell_split_squat" => {
+                Ok(LungeExerciseName::ElevatedFrontFootBarbellSplitSquat)
+            }
+            "front_foot_elevated_dumbbell_split_squat" => {
+                Ok(LungeExerciseName::FrontFootElevatedDumbbellSplitSquat)
+            }
+            "gunslinger_lunge" => Ok(LungeExerciseName::GunslingerLunge),
+            "lawnmower_lunge" => Ok(LungeExerciseName::LawnmowerLunge),
+            "low_lunge_with_isometric_adduction" => {
+                Ok(LungeExerciseName::LowLungeWithIsometricAdduction)
+            }
+            "low_side_to_side_lunge" => Ok(LungeExerciseName::LowSideToSideLunge),
+            "lunge" => Ok(LungeExerciseName::Lunge),
+            "weighted_lunge" => Ok(LungeExerciseName::WeightedLunge),
+            "lunge_with_arm_reach" => Ok(LungeExerciseName::LungeWithArmReach),
+            "lunge_with_diagonal_reach" => Ok(LungeExerciseName::LungeWithDiagonalReach),
+            "lunge_with_side_bend" => Ok(LungeExerciseName::LungeWithSideBend),
+            "offset_dumbbell_lunge" => Ok(LungeExerciseName::OffsetDumbbellLunge),
+            "offset_dumbbell_reverse_lunge" => Ok(LungeExerciseName::OffsetDumbbellReverseLunge),
+            "overhead_bulgarian_split_squat" => Ok(LungeExerciseName::OverheadBulgarianSplitSquat),
+            "overhead_dumbbell_reverse_lunge" => {
+                Ok(LungeExerciseName::OverheadDumbbellReverseLunge)
+            }
+            "overhead_dumbbell_split_squat" => Ok(LungeExerciseName::OverheadDumbbellSplitSquat),
+            "overhead_lunge_with_rotation" => Ok(LungeExerciseName::OverheadLungeWithRotation),
+            "reverse_barbell_box_lunge" => Ok(LungeExerciseName::ReverseBarbellBoxLunge),
+            "reverse_box_lunge" => Ok(LungeExerciseName::ReverseBoxLunge),
+            "reverse_dumbbell_box_lunge" => Ok(LungeExerciseName::ReverseDumbbellBoxLunge),
+            "reverse_dumbbell_crossover_lunge" => {
+                Ok(LungeExerciseName::ReverseDumbbellCrossoverLunge)
+            }
+            "reverse_dumbbell_diagonal_lunge" => {
+                Ok(LungeExerciseName::ReverseDumbbellDiagonalLunge)
+            }
+            "reverse_lunge_with_reach_back" => Ok(LungeExerciseName::ReverseLungeWithReachBack),
+            "weighted_reverse_lunge_with_reach_back" => {
+                Ok(LungeExerciseName::WeightedReverseLungeWithReachBack)
+            }
+            "reverse_lunge_with_twist_and_overhead_reach" => {
+                Ok(LungeExerciseName::ReverseLungeWithTwistAndOverheadReach)
+            }
+            "weighted_reverse_lunge_with_twist_and_overhead_reach" => {
+                Ok(LungeExerciseName::WeightedReverseLungeWithTwistAndOverheadReach)
+            }
+            "reverse_sliding_box_lunge" => Ok(LungeExerciseName::ReverseSlidingBoxLunge),
+            "weighted_reverse_sliding_box_lunge" => {
+                Ok(LungeExerciseName::WeightedReverseSlidingBoxLunge)
+            }
+            "reverse_sliding_lunge" => Ok(LungeExerciseName::ReverseSlidingLunge),
+            "weighted_reverse_sliding_lunge" => Ok(LungeExerciseName::WeightedReverseSlidingLunge),
+            "runners_lunge_to_balance" => Ok(LungeExerciseName::RunnersLungeToBalance),
+            "weighted_runners_lunge_to_balance" => {
+                Ok(LungeExerciseName::WeightedRunnersLungeToBalance)
+            }
+            "shifting_side_lunge" => Ok(LungeExerciseName::ShiftingSideLunge),
+            "side_and_crossover_lunge" => Ok(LungeExerciseName::SideAndCrossoverLunge),
+            "weighted_side_and_crossover_lunge" => {
+                Ok(LungeExerciseName::WeightedSideAndCrossoverLunge)
+            }
+            "side_lunge" => Ok(LungeExerciseName::SideLunge),
+            "weighted_side_lunge" => Ok(LungeExerciseName::WeightedSideLunge),
+            "side_lunge_and_press" => Ok(LungeExerciseName::SideLungeAndPress),
+            "side_lunge_jump_off" => Ok(LungeExerciseName::SideLungeJumpOff),
+            "side_lunge_sweep" => Ok(LungeExerciseName::SideLungeSweep),
+            "weighted_side_lunge_sweep" => Ok(LungeExerciseName::WeightedSideLungeSweep),
+            "side_lunge_to_crossover_tap" => Ok(LungeExerciseName::SideLungeToCrossoverTap),
+            "weighted_side_lunge_to_crossover_tap" => {
+                Ok(LungeExerciseName::WeightedSideLungeToCrossoverTap)
+            }
+            "side_to_side_lunge_chops" => Ok(LungeExerciseName::SideToSideLungeChops),
+            "weighted_side_to_side_lunge_chops" => {
+                Ok(LungeExerciseName::WeightedSideToSideLungeChops)
+            }
+            "siff_jump_lunge" => Ok(LungeExerciseName::SiffJumpLunge),
+            "weighted_siff_jump_lunge" => Ok(LungeExerciseName::WeightedSiffJumpLunge),
+            "single_arm_reverse_lunge_and_press" => {
+                Ok(LungeExerciseName::SingleArmReverseLungeAndPress)
+            }
+            "sliding_lateral_lunge" => Ok(LungeExerciseName::SlidingLateralLunge),
+            "weighted_sliding_lateral_lunge" => Ok(LungeExerciseName::WeightedSlidingLateralLunge),
+            "walking_barbell_lunge" => Ok(LungeExerciseName::WalkingBarbellLunge),
+            "walking_dumbbell_lunge" => Ok(LungeExerciseName::WalkingDumbbellLunge),
+            "walking_lunge" => Ok(LungeExerciseName::WalkingLunge),
+            "weighted_walking_lunge" => Ok(LungeExerciseName::WeightedWalkingLunge),
+            "wide_grip_overhead_barbell_split_squat" => {
+                Ok(LungeExerciseName::WideGripOverheadBarbellSplitSquat)
+            }
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for LungeExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -21604,6 +25841,39 @@ impl convert::From<i64> for OlympicLiftExerciseName {
         OlympicLiftExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for OlympicLiftExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "barbell_hang_power_clean" => Ok(OlympicLiftExerciseName::BarbellHangPowerClean),
+            "barbell_hang_squat_clean" => Ok(OlympicLiftExerciseName::BarbellHangSquatClean),
+            "barbell_power_clean" => Ok(OlympicLiftExerciseName::BarbellPowerClean),
+            "barbell_power_snatch" => Ok(OlympicLiftExerciseName::BarbellPowerSnatch),
+            "barbell_squat_clean" => Ok(OlympicLiftExerciseName::BarbellSquatClean),
+            "clean_and_jerk" => Ok(OlympicLiftExerciseName::CleanAndJerk),
+            "barbell_hang_power_snatch" => Ok(OlympicLiftExerciseName::BarbellHangPowerSnatch),
+            "barbell_hang_pull" => Ok(OlympicLiftExerciseName::BarbellHangPull),
+            "barbell_high_pull" => Ok(OlympicLiftExerciseName::BarbellHighPull),
+            "barbell_snatch" => Ok(OlympicLiftExerciseName::BarbellSnatch),
+            "barbell_split_jerk" => Ok(OlympicLiftExerciseName::BarbellSplitJerk),
+            "clean" => Ok(OlympicLiftExerciseName::Clean),
+            "dumbbell_clean" => Ok(OlympicLiftExerciseName::DumbbellClean),
+            "dumbbell_hang_pull" => Ok(OlympicLiftExerciseName::DumbbellHangPull),
+            "one_hand_dumbbell_split_snatch" => {
+                Ok(OlympicLiftExerciseName::OneHandDumbbellSplitSnatch)
+            }
+            "push_jerk" => Ok(OlympicLiftExerciseName::PushJerk),
+            "single_arm_dumbbell_snatch" => Ok(OlympicLiftExerciseName::SingleArmDumbbellSnatch),
+            "single_arm_hang_snatch" => Ok(OlympicLiftExerciseName::SingleArmHangSnatch),
+            "single_arm_kettlebell_snatch" => {
+                Ok(OlympicLiftExerciseName::SingleArmKettlebellSnatch)
+            }
+            "split_jerk" => Ok(OlympicLiftExerciseName::SplitJerk),
+            "squat_clean_and_jerk" => Ok(OlympicLiftExerciseName::SquatCleanAndJerk),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for OlympicLiftExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -22453,6 +26723,239 @@ impl convert::From<i64> for PlankExerciseName {
         PlankExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for PlankExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "45_degree_plank" => Ok(PlankExerciseName::Name45DegreePlank),
+            "weighted_45_degree_plank" => Ok(PlankExerciseName::Weighted45DegreePlank),
+            "90_degree_static_hold" => Ok(PlankExerciseName::Name90DegreeStaticHold),
+            "weighted_90_degree_static_hold" => Ok(PlankExerciseName::Weighted90DegreeStaticHold),
+            "bear_crawl" => Ok(PlankExerciseName::BearCrawl),
+            "weighted_bear_crawl" => Ok(PlankExerciseName::WeightedBearCrawl),
+            "cross_body_mountain_climber" => Ok(PlankExerciseName::CrossBodyMountainClimber),
+            "weighted_cross_body_mountain_climber" => {
+                Ok(PlankExerciseName::WeightedCrossBodyMountainClimber)
+            }
+            "elbow_plank_pike_jacks" => Ok(PlankExerciseName::ElbowPlankPikeJacks),
+            "weighted_elbow_plank_pike_jacks" => Ok(PlankExerciseName::WeightedElbowPlankPikeJacks),
+            "elevated_feet_plank" => Ok(PlankExerciseName::ElevatedFeetPlank),
+            "weighted_elevated_feet_plank" => Ok(PlankExerciseName::WeightedElevatedFeetPlank),
+            "elevator_abs" => Ok(PlankExerciseName::ElevatorAbs),
+            "weighted_elevator_abs" => Ok(PlankExerciseName::WeightedElevatorAbs),
+            "extended_plank" => Ok(PlankExerciseName::ExtendedPlank),
+            "weighted_extended_plank" => Ok(PlankExerciseName::WeightedExtendedPlank),
+            "full_plank_passe_twist" => Ok(PlankExerciseName::FullPlankPasseTwist),
+            "weighted_full_plank_passe_twist" => Ok(PlankExerciseName::WeightedFullPlankPasseTwist),
+            "inching_elbow_plank" => Ok(PlankExerciseName::InchingElbowPlank),
+            "weighted_inching_elbow_plank" => Ok(PlankExerciseName::WeightedInchingElbowPlank),
+            "inchworm_to_side_plank" => Ok(PlankExerciseName::InchwormToSidePlank),
+            "weighted_inchworm_to_side_plank" => Ok(PlankExerciseName::WeightedInchwormToSidePlank),
+            "kneeling_plank" => Ok(PlankExerciseName::KneelingPlank),
+            "weighted_kneeling_plank" => Ok(PlankExerciseName::WeightedKneelingPlank),
+            "kneeling_side_plank_with_leg_lift" => {
+                Ok(PlankExerciseName::KneelingSidePlankWithLegLift)
+            }
+            "weighted_kneeling_side_plank_with_leg_lift" => {
+                Ok(PlankExerciseName::WeightedKneelingSidePlankWithLegLift)
+            }
+            "lateral_roll" => Ok(PlankExerciseName::LateralRoll),
+            "weighted_lateral_roll" => Ok(PlankExerciseName::WeightedLateralRoll),
+            "lying_reverse_plank" => Ok(PlankExerciseName::LyingReversePlank),
+            "weighted_lying_reverse_plank" => Ok(PlankExerciseName::WeightedLyingReversePlank),
+            "medicine_ball_mountain_climber" => Ok(PlankExerciseName::MedicineBallMountainClimber),
+            "weighted_medicine_ball_mountain_climber" => {
+                Ok(PlankExerciseName::WeightedMedicineBallMountainClimber)
+            }
+            "modified_mountain_climber_and_extension" => {
+                Ok(PlankExerciseName::ModifiedMountainClimberAndExtension)
+            }
+            "weighted_modified_mountain_climber_and_extension" => {
+                Ok(PlankExerciseName::WeightedModifiedMountainClimberAndExtension)
+            }
+            "mountain_climber" => Ok(PlankExerciseName::MountainClimber),
+            "weighted_mountain_climber" => Ok(PlankExerciseName::WeightedMountainClimber),
+            "mountain_climber_on_sliding_discs" => {
+                Ok(PlankExerciseName::MountainClimberOnSlidingDiscs)
+            }
+            "weighted_mountain_climber_on_sliding_discs" => {
+                Ok(PlankExerciseName::WeightedMountainClimberOnSlidingDiscs)
+            }
+            "mountain_climber_with_feet_on_bosu_ball" => {
+                Ok(PlankExerciseName::MountainClimberWithFeetOnBosuBall)
+            }
+            "weighted_mountain_climber_with_feet_on_bosu_ball" => {
+                Ok(PlankExerciseName::WeightedMountainClimberWithFeetOnBosuBall)
+            }
+            "mountain_climber_with_hands_on_bench" => {
+                Ok(PlankExerciseName::MountainClimberWithHandsOnBench)
+            }
+            "mountain_climber_with_hands_on_swiss_ball" => {
+                Ok(PlankExerciseName::MountainClimberWithHandsOnSwissBall)
+            }
+            "weighted_mountain_climber_with_hands_on_swiss_ball" => {
+                Ok(PlankExerciseName::WeightedMountainClimberWithHandsOnSwissBall)
+            }
+            "plank" => Ok(PlankExerciseName::Plank),
+            "plank_jacks_with_feet_on_sliding_discs" => {
+                Ok(PlankExerciseName::PlankJacksWithFeetOnSlidingDiscs)
+            }
+            "weighted_plank_jacks_with_feet_on_sliding_discs" => {
+                Ok(PlankExerciseName::WeightedPlankJacksWithFeetOnSlidingDiscs)
+            }
+            "plank_knee_twist" => Ok(PlankExerciseName::PlankKneeTwist),
+            "weighted_plank_knee_twist" => Ok(PlankExerciseName::WeightedPlankKneeTwist),
+            "plank_pike_jumps" => Ok(PlankExerciseName::PlankPikeJumps),
+            "weighted_plank_pike_jumps" => Ok(PlankExerciseName::WeightedPlankPikeJumps),
+            "plank_pikes" => Ok(PlankExerciseName::PlankPikes),
+            "weighted_plank_pikes" => Ok(PlankExerciseName::WeightedPlankPikes),
+            "plank_to_stand_up" => Ok(PlankExerciseName::PlankToStandUp),
+            "weighted_plank_to_stand_up" => Ok(PlankExerciseName::WeightedPlankToStandUp),
+            "plank_with_arm_raise" => Ok(PlankExerciseName::PlankWithArmRaise),
+            "weighted_plank_with_arm_raise" => Ok(PlankExerciseName::WeightedPlankWithArmRaise),
+            "plank_with_knee_to_elbow" => Ok(PlankExerciseName::PlankWithKneeToElbow),
+            "weighted_plank_with_knee_to_elbow" => {
+                Ok(PlankExerciseName::WeightedPlankWithKneeToElbow)
+            }
+            "plank_with_oblique_crunch" => Ok(PlankExerciseName::PlankWithObliqueCrunch),
+            "weighted_plank_with_oblique_crunch" => {
+                Ok(PlankExerciseName::WeightedPlankWithObliqueCrunch)
+            }
+            "plyometric_side_plank" => Ok(PlankExerciseName::PlyometricSidePlank),
+            "weighted_plyometric_side_plank" => Ok(PlankExerciseName::WeightedPlyometricSidePlank),
+            "rolling_side_plank" => Ok(PlankExerciseName::RollingSidePlank),
+            "weighted_rolling_side_plank" => Ok(PlankExerciseName::WeightedRollingSidePlank),
+            "side_kick_plank" => Ok(PlankExerciseName::SideKickPlank),
+            "weighted_side_kick_plank" => Ok(PlankExerciseName::WeightedSideKickPlank),
+            "side_plank" => Ok(PlankExerciseName::SidePlank),
+            "weighted_side_plank" => Ok(PlankExerciseName::WeightedSidePlank),
+            "side_plank_and_row" => Ok(PlankExerciseName::SidePlankAndRow),
+            "weighted_side_plank_and_row" => Ok(PlankExerciseName::WeightedSidePlankAndRow),
+            "side_plank_lift" => Ok(PlankExerciseName::SidePlankLift),
+            "weighted_side_plank_lift" => Ok(PlankExerciseName::WeightedSidePlankLift),
+            "side_plank_with_elbow_on_bosu_ball" => {
+                Ok(PlankExerciseName::SidePlankWithElbowOnBosuBall)
+            }
+            "weighted_side_plank_with_elbow_on_bosu_ball" => {
+                Ok(PlankExerciseName::WeightedSidePlankWithElbowOnBosuBall)
+            }
+            "side_plank_with_feet_on_bench" => Ok(PlankExerciseName::SidePlankWithFeetOnBench),
+            "weighted_side_plank_with_feet_on_bench" => {
+                Ok(PlankExerciseName::WeightedSidePlankWithFeetOnBench)
+            }
+            "side_plank_with_knee_circle" => Ok(PlankExerciseName::SidePlankWithKneeCircle),
+            "weighted_side_plank_with_knee_circle" => {
+                Ok(PlankExerciseName::WeightedSidePlankWithKneeCircle)
+            }
+            "side_plank_with_knee_tuck" => Ok(PlankExerciseName::SidePlankWithKneeTuck),
+            "weighted_side_plank_with_knee_tuck" => {
+                Ok(PlankExerciseName::WeightedSidePlankWithKneeTuck)
+            }
+            "side_plank_with_leg_lift" => Ok(PlankExerciseName::SidePlankWithLegLift),
+            "weighted_side_plank_with_leg_lift" => {
+                Ok(PlankExerciseName::WeightedSidePlankWithLegLift)
+            }
+            "side_plank_with_reach_under" => Ok(PlankExerciseName::SidePlankWithReachUnder),
+            "weighted_side_plank_with_reach_under" => {
+                Ok(PlankExerciseName::WeightedSidePlankWithReachUnder)
+            }
+            "single_leg_elevated_feet_plank" => Ok(PlankExerciseName::SingleLegElevatedFeetPlank),
+            "weighted_single_leg_elevated_feet_plank" => {
+                Ok(PlankExerciseName::WeightedSingleLegElevatedFeetPlank)
+            }
+            "single_leg_flex_and_extend" => Ok(PlankExerciseName::SingleLegFlexAndExtend),
+            "weighted_single_leg_flex_and_extend" => {
+                Ok(PlankExerciseName::WeightedSingleLegFlexAndExtend)
+            }
+            "single_leg_side_plank" => Ok(PlankExerciseName::SingleLegSidePlank),
+            "weighted_single_leg_side_plank" => Ok(PlankExerciseName::WeightedSingleLegSidePlank),
+            "spiderman_plank" => Ok(PlankExerciseName::SpidermanPlank),
+            "weighted_spiderman_plank" => Ok(PlankExerciseName::WeightedSpidermanPlank),
+            "straight_arm_plank" => Ok(PlankExerciseName::StraightArmPlank),
+            "weighted_straight_arm_plank" => Ok(PlankExerciseName::WeightedStraightArmPlank),
+            "straight_arm_plank_with_shoulder_touch" => {
+                Ok(PlankExerciseName::StraightArmPlankWithShoulderTouch)
+            }
+            "weighted_straight_arm_plank_with_shoulder_touch" => {
+                Ok(PlankExerciseName::WeightedStraightArmPlankWithShoulderTouch)
+            }
+            "swiss_ball_plank" => Ok(PlankExerciseName::SwissBallPlank),
+            "weighted_swiss_ball_plank" => Ok(PlankExerciseName::WeightedSwissBallPlank),
+            "swiss_ball_plank_leg_lift" => Ok(PlankExerciseName::SwissBallPlankLegLift),
+            "weighted_swiss_ball_plank_leg_lift" => {
+                Ok(PlankExerciseName::WeightedSwissBallPlankLegLift)
+            }
+            "swiss_ball_plank_leg_lift_and_hold" => {
+                Ok(PlankExerciseName::SwissBallPlankLegLiftAndHold)
+            }
+            "swiss_ball_plank_with_feet_on_bench" => {
+                Ok(PlankExerciseName::SwissBallPlankWithFeetOnBench)
+            }
+            "weighted_swiss_ball_plank_with_feet_on_bench" => {
+                Ok(PlankExerciseName::WeightedSwissBallPlankWithFeetOnBench)
+            }
+            "swiss_ball_prone_jackknife" => Ok(PlankExerciseName::SwissBallProneJackknife),
+            "weighted_swiss_ball_prone_jackknife" => {
+                Ok(PlankExerciseName::WeightedSwissBallProneJackknife)
+            }
+            "swiss_ball_side_plank" => Ok(PlankExerciseName::SwissBallSidePlank),
+            "weighted_swiss_ball_side_plank" => Ok(PlankExerciseName::WeightedSwissBallSidePlank),
+            "three_way_plank" => Ok(PlankExerciseName::ThreeWayPlank),
+            "weighted_three_way_plank" => Ok(PlankExerciseName::WeightedThreeWayPlank),
+            "towel_plank_and_knee_in" => Ok(PlankExerciseName::TowelPlankAndKneeIn),
+            "weighted_towel_plank_and_knee_in" => {
+                Ok(PlankExerciseName::WeightedTowelPlankAndKneeIn)
+            }
+            "t_stabilization" => Ok(PlankExerciseName::TStabilization),
+            "weighted_t_stabilization" => Ok(PlankExerciseName::WeightedTStabilization),
+            "turkish_get_up_to_side_plank" => Ok(PlankExerciseName::TurkishGetUpToSidePlank),
+            "weighted_turkish_get_up_to_side_plank" => {
+                Ok(PlankExerciseName::WeightedTurkishGetUpToSidePlank)
+            }
+            "two_point_plank" => Ok(PlankExerciseName::TwoPointPlank),
+            "weighted_two_point_plank" => Ok(PlankExerciseName::WeightedTwoPointPlank),
+            "weighted_plank" => Ok(PlankExerciseName::WeightedPlank),
+            "wide_stance_plank_with_diagonal_arm_lift" => {
+                Ok(PlankExerciseName::WideStancePlankWithDiagonalArmLift)
+            }
+            "weighted_wide_stance_plank_with_diagonal_arm_lift" => {
+                Ok(PlankExerciseName::WeightedWideStancePlankWithDiagonalArmLift)
+            }
+            "wide_stance_plank_with_diagonal_leg_lift" => {
+                Ok(PlankExerciseName::WideStancePlankWithDiagonalLegLift)
+            }
+            "weighted_wide_stance_plank_with_diagonal_leg_lift" => {
+                Ok(PlankExerciseName::WeightedWideStancePlankWithDiagonalLegLift)
+            }
+            "wide_stance_plank_with_leg_lift" => Ok(PlankExerciseName::WideStancePlankWithLegLift),
+            "weighted_wide_stance_plank_with_leg_lift" => {
+                Ok(PlankExerciseName::WeightedWideStancePlankWithLegLift)
+            }
+            "wide_stance_plank_with_opposite_arm_and_leg_lift" => {
+                Ok(PlankExerciseName::WideStancePlankWithOppositeArmAndLegLift)
+            }
+            "weighted_mountain_climber_with_hands_on_bench" => {
+                Ok(PlankExerciseName::WeightedMountainClimberWithHandsOnBench)
+            }
+            "weighted_swiss_ball_plank_leg_lift_and_hold" => {
+                Ok(PlankExerciseName::WeightedSwissBallPlankLegLiftAndHold)
+            }
+            "weighted_wide_stance_plank_with_opposite_arm_and_leg_lift" => {
+                Ok(PlankExerciseName::WeightedWideStancePlankWithOppositeArmAndLegLift)
+            }
+            "plank_with_feet_on_swiss_ball" => Ok(PlankExerciseName::PlankWithFeetOnSwissBall),
+            "side_plank_to_plank_with_reach_under" => {
+                Ok(PlankExerciseName::SidePlankToPlankWithReachUnder)
+            }
+            "bridge_with_glute_lower_lift" => Ok(PlankExerciseName::BridgeWithGluteLowerLift),
+            "bridge_one_leg_bridge" => Ok(PlankExerciseName::BridgeOneLegBridge),
+            "plank_with_arm_variations" => Ok(PlankExerciseName::PlankWithArmVariations),
+            "plank_with_leg_lift" => Ok(PlankExerciseName::PlankWithLegLift),
+            "reverse_plank_with_leg_pull" => Ok(PlankExerciseName::ReversePlankWithLegPull),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for PlankExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -22685,6 +27188,55 @@ impl convert::From<i64> for PlyoExerciseName {
         PlyoExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for PlyoExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "alternating_jump_lunge" => Ok(PlyoExerciseName::AlternatingJumpLunge),
+            "weighted_alternating_jump_lunge" => Ok(PlyoExerciseName::WeightedAlternatingJumpLunge),
+            "barbell_jump_squat" => Ok(PlyoExerciseName::BarbellJumpSquat),
+            "body_weight_jump_squat" => Ok(PlyoExerciseName::BodyWeightJumpSquat),
+            "weighted_jump_squat" => Ok(PlyoExerciseName::WeightedJumpSquat),
+            "cross_knee_strike" => Ok(PlyoExerciseName::CrossKneeStrike),
+            "weighted_cross_knee_strike" => Ok(PlyoExerciseName::WeightedCrossKneeStrike),
+            "depth_jump" => Ok(PlyoExerciseName::DepthJump),
+            "weighted_depth_jump" => Ok(PlyoExerciseName::WeightedDepthJump),
+            "dumbbell_jump_squat" => Ok(PlyoExerciseName::DumbbellJumpSquat),
+            "dumbbell_split_jump" => Ok(PlyoExerciseName::DumbbellSplitJump),
+            "front_knee_strike" => Ok(PlyoExerciseName::FrontKneeStrike),
+            "weighted_front_knee_strike" => Ok(PlyoExerciseName::WeightedFrontKneeStrike),
+            "high_box_jump" => Ok(PlyoExerciseName::HighBoxJump),
+            "weighted_high_box_jump" => Ok(PlyoExerciseName::WeightedHighBoxJump),
+            "isometric_explosive_body_weight_jump_squat" => {
+                Ok(PlyoExerciseName::IsometricExplosiveBodyWeightJumpSquat)
+            }
+            "weighted_isometric_explosive_jump_squat" => {
+                Ok(PlyoExerciseName::WeightedIsometricExplosiveJumpSquat)
+            }
+            "lateral_leap_and_hop" => Ok(PlyoExerciseName::LateralLeapAndHop),
+            "weighted_lateral_leap_and_hop" => Ok(PlyoExerciseName::WeightedLateralLeapAndHop),
+            "lateral_plyo_squats" => Ok(PlyoExerciseName::LateralPlyoSquats),
+            "weighted_lateral_plyo_squats" => Ok(PlyoExerciseName::WeightedLateralPlyoSquats),
+            "lateral_slide" => Ok(PlyoExerciseName::LateralSlide),
+            "weighted_lateral_slide" => Ok(PlyoExerciseName::WeightedLateralSlide),
+            "medicine_ball_overhead_throws" => Ok(PlyoExerciseName::MedicineBallOverheadThrows),
+            "medicine_ball_side_throw" => Ok(PlyoExerciseName::MedicineBallSideThrow),
+            "medicine_ball_slam" => Ok(PlyoExerciseName::MedicineBallSlam),
+            "side_to_side_medicine_ball_throws" => {
+                Ok(PlyoExerciseName::SideToSideMedicineBallThrows)
+            }
+            "side_to_side_shuffle_jump" => Ok(PlyoExerciseName::SideToSideShuffleJump),
+            "weighted_side_to_side_shuffle_jump" => {
+                Ok(PlyoExerciseName::WeightedSideToSideShuffleJump)
+            }
+            "squat_jump_onto_box" => Ok(PlyoExerciseName::SquatJumpOntoBox),
+            "weighted_squat_jump_onto_box" => Ok(PlyoExerciseName::WeightedSquatJumpOntoBox),
+            "squat_jumps_in_and_out" => Ok(PlyoExerciseName::SquatJumpsInAndOut),
+            "weighted_squat_jumps_in_and_out" => Ok(PlyoExerciseName::WeightedSquatJumpsInAndOut),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for PlyoExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -22931,6 +27483,55 @@ impl convert::From<i64> for PullUpExerciseName {
         PullUpExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for PullUpExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "banded_pull_ups" => Ok(PullUpExerciseName::BandedPullUps),
+            "30_degree_lat_pulldown" => Ok(PullUpExerciseName::Name30DegreeLatPulldown),
+            "band_assisted_chin_up" => Ok(PullUpExerciseName::BandAssistedChinUp),
+            "close_grip_chin_up" => Ok(PullUpExerciseName::CloseGripChinUp),
+            "weighted_close_grip_chin_up" => Ok(PullUpExerciseName::WeightedCloseGripChinUp),
+            "close_grip_lat_pulldown" => Ok(PullUpExerciseName::CloseGripLatPulldown),
+            "crossover_chin_up" => Ok(PullUpExerciseName::CrossoverChinUp),
+            "weighted_crossover_chin_up" => Ok(PullUpExerciseName::WeightedCrossoverChinUp),
+            "ez_bar_pullover" => Ok(PullUpExerciseName::EzBarPullover),
+            "hanging_hurdle" => Ok(PullUpExerciseName::HangingHurdle),
+            "weighted_hanging_hurdle" => Ok(PullUpExerciseName::WeightedHangingHurdle),
+            "kneeling_lat_pulldown" => Ok(PullUpExerciseName::KneelingLatPulldown),
+            "kneeling_underhand_grip_lat_pulldown" => {
+                Ok(PullUpExerciseName::KneelingUnderhandGripLatPulldown)
+            }
+            "lat_pulldown" => Ok(PullUpExerciseName::LatPulldown),
+            "mixed_grip_chin_up" => Ok(PullUpExerciseName::MixedGripChinUp),
+            "weighted_mixed_grip_chin_up" => Ok(PullUpExerciseName::WeightedMixedGripChinUp),
+            "mixed_grip_pull_up" => Ok(PullUpExerciseName::MixedGripPullUp),
+            "weighted_mixed_grip_pull_up" => Ok(PullUpExerciseName::WeightedMixedGripPullUp),
+            "reverse_grip_pulldown" => Ok(PullUpExerciseName::ReverseGripPulldown),
+            "standing_cable_pullover" => Ok(PullUpExerciseName::StandingCablePullover),
+            "straight_arm_pulldown" => Ok(PullUpExerciseName::StraightArmPulldown),
+            "swiss_ball_ez_bar_pullover" => Ok(PullUpExerciseName::SwissBallEzBarPullover),
+            "towel_pull_up" => Ok(PullUpExerciseName::TowelPullUp),
+            "weighted_towel_pull_up" => Ok(PullUpExerciseName::WeightedTowelPullUp),
+            "weighted_pull_up" => Ok(PullUpExerciseName::WeightedPullUp),
+            "wide_grip_lat_pulldown" => Ok(PullUpExerciseName::WideGripLatPulldown),
+            "wide_grip_pull_up" => Ok(PullUpExerciseName::WideGripPullUp),
+            "weighted_wide_grip_pull_up" => Ok(PullUpExerciseName::WeightedWideGripPullUp),
+            "burpee_pull_up" => Ok(PullUpExerciseName::BurpeePullUp),
+            "weighted_burpee_pull_up" => Ok(PullUpExerciseName::WeightedBurpeePullUp),
+            "jumping_pull_ups" => Ok(PullUpExerciseName::JumpingPullUps),
+            "weighted_jumping_pull_ups" => Ok(PullUpExerciseName::WeightedJumpingPullUps),
+            "kipping_pull_up" => Ok(PullUpExerciseName::KippingPullUp),
+            "weighted_kipping_pull_up" => Ok(PullUpExerciseName::WeightedKippingPullUp),
+            "l_pull_up" => Ok(PullUpExerciseName::LPullUp),
+            "weighted_l_pull_up" => Ok(PullUpExerciseName::WeightedLPullUp),
+            "suspended_chin_up" => Ok(PullUpExerciseName::SuspendedChinUp),
+            "weighted_suspended_chin_up" => Ok(PullUpExerciseName::WeightedSuspendedChinUp),
+            "pull_up" => Ok(PullUpExerciseName::PullUp),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for PullUpExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -23431,6 +28032,129 @@ impl convert::From<i64> for PushUpExerciseName {
         PushUpExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for PushUpExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "chest_press_with_band" => Ok(PushUpExerciseName::ChestPressWithBand),
+            "alternating_staggered_push_up" => Ok(PushUpExerciseName::AlternatingStaggeredPushUp),
+            "weighted_alternating_staggered_push_up" => {
+                Ok(PushUpExerciseName::WeightedAlternatingStaggeredPushUp)
+            }
+            "alternating_hands_medicine_ball_push_up" => {
+                Ok(PushUpExerciseName::AlternatingHandsMedicineBallPushUp)
+            }
+            "weighted_alternating_hands_medicine_ball_push_up" => {
+                Ok(PushUpExerciseName::WeightedAlternatingHandsMedicineBallPushUp)
+            }
+            "bosu_ball_push_up" => Ok(PushUpExerciseName::BosuBallPushUp),
+            "weighted_bosu_ball_push_up" => Ok(PushUpExerciseName::WeightedBosuBallPushUp),
+            "clapping_push_up" => Ok(PushUpExerciseName::ClappingPushUp),
+            "weighted_clapping_push_up" => Ok(PushUpExerciseName::WeightedClappingPushUp),
+            "close_grip_medicine_ball_push_up" => {
+                Ok(PushUpExerciseName::CloseGripMedicineBallPushUp)
+            }
+            "weighted_close_grip_medicine_ball_push_up" => {
+                Ok(PushUpExerciseName::WeightedCloseGripMedicineBallPushUp)
+            }
+            "close_hands_push_up" => Ok(PushUpExerciseName::CloseHandsPushUp),
+            "weighted_close_hands_push_up" => Ok(PushUpExerciseName::WeightedCloseHandsPushUp),
+            "decline_push_up" => Ok(PushUpExerciseName::DeclinePushUp),
+            "weighted_decline_push_up" => Ok(PushUpExerciseName::WeightedDeclinePushUp),
+            "diamond_push_up" => Ok(PushUpExerciseName::DiamondPushUp),
+            "weighted_diamond_push_up" => Ok(PushUpExerciseName::WeightedDiamondPushUp),
+            "explosive_crossover_push_up" => Ok(PushUpExerciseName::ExplosiveCrossoverPushUp),
+            "weighted_explosive_crossover_push_up" => {
+                Ok(PushUpExerciseName::WeightedExplosiveCrossoverPushUp)
+            }
+            "explosive_push_up" => Ok(PushUpExerciseName::ExplosivePushUp),
+            "weighted_explosive_push_up" => Ok(PushUpExerciseName::WeightedExplosivePushUp),
+            "feet_elevated_side_to_side_push_up" => {
+                Ok(PushUpExerciseName::FeetElevatedSideToSidePushUp)
+            }
+            "weighted_feet_elevated_side_to_side_push_up" => {
+                Ok(PushUpExerciseName::WeightedFeetElevatedSideToSidePushUp)
+            }
+            "hand_release_push_up" => Ok(PushUpExerciseName::HandReleasePushUp),
+            "weighted_hand_release_push_up" => Ok(PushUpExerciseName::WeightedHandReleasePushUp),
+            "handstand_push_up" => Ok(PushUpExerciseName::HandstandPushUp),
+            "weighted_handstand_push_up" => Ok(PushUpExerciseName::WeightedHandstandPushUp),
+            "incline_push_up" => Ok(PushUpExerciseName::InclinePushUp),
+            "weighted_incline_push_up" => Ok(PushUpExerciseName::WeightedInclinePushUp),
+            "isometric_explosive_push_up" => Ok(PushUpExerciseName::IsometricExplosivePushUp),
+            "weighted_isometric_explosive_push_up" => {
+                Ok(PushUpExerciseName::WeightedIsometricExplosivePushUp)
+            }
+            "judo_push_up" => Ok(PushUpExerciseName::JudoPushUp),
+            "weighted_judo_push_up" => Ok(PushUpExerciseName::WeightedJudoPushUp),
+            "kneeling_push_up" => Ok(PushUpExerciseName::KneelingPushUp),
+            "weighted_kneeling_push_up" => Ok(PushUpExerciseName::WeightedKneelingPushUp),
+            "medicine_ball_chest_pass" => Ok(PushUpExerciseName::MedicineBallChestPass),
+            "medicine_ball_push_up" => Ok(PushUpExerciseName::MedicineBallPushUp),
+            "weighted_medicine_ball_push_up" => Ok(PushUpExerciseName::WeightedMedicineBallPushUp),
+            "one_arm_push_up" => Ok(PushUpExerciseName::OneArmPushUp),
+            "weighted_one_arm_push_up" => Ok(PushUpExerciseName::WeightedOneArmPushUp),
+            "weighted_push_up" => Ok(PushUpExerciseName::WeightedPushUp),
+            "push_up_and_row" => Ok(PushUpExerciseName::PushUpAndRow),
+            "weighted_push_up_and_row" => Ok(PushUpExerciseName::WeightedPushUpAndRow),
+            "push_up_plus" => Ok(PushUpExerciseName::PushUpPlus),
+            "weighted_push_up_plus" => Ok(PushUpExerciseName::WeightedPushUpPlus),
+            "push_up_with_feet_on_swiss_ball" => Ok(PushUpExerciseName::PushUpWithFeetOnSwissBall),
+            "weighted_push_up_with_feet_on_swiss_ball" => {
+                Ok(PushUpExerciseName::WeightedPushUpWithFeetOnSwissBall)
+            }
+            "push_up_with_one_hand_on_medicine_ball" => {
+                Ok(PushUpExerciseName::PushUpWithOneHandOnMedicineBall)
+            }
+            "weighted_push_up_with_one_hand_on_medicine_ball" => {
+                Ok(PushUpExerciseName::WeightedPushUpWithOneHandOnMedicineBall)
+            }
+            "shoulder_push_up" => Ok(PushUpExerciseName::ShoulderPushUp),
+            "weighted_shoulder_push_up" => Ok(PushUpExerciseName::WeightedShoulderPushUp),
+            "single_arm_medicine_ball_push_up" => {
+                Ok(PushUpExerciseName::SingleArmMedicineBallPushUp)
+            }
+            "weighted_single_arm_medicine_ball_push_up" => {
+                Ok(PushUpExerciseName::WeightedSingleArmMedicineBallPushUp)
+            }
+            "spiderman_push_up" => Ok(PushUpExerciseName::SpidermanPushUp),
+            "weighted_spiderman_push_up" => Ok(PushUpExerciseName::WeightedSpidermanPushUp),
+            "stacked_feet_push_up" => Ok(PushUpExerciseName::StackedFeetPushUp),
+            "weighted_stacked_feet_push_up" => Ok(PushUpExerciseName::WeightedStackedFeetPushUp),
+            "staggered_hands_push_up" => Ok(PushUpExerciseName::StaggeredHandsPushUp),
+            "weighted_staggered_hands_push_up" => {
+                Ok(PushUpExerciseName::WeightedStaggeredHandsPushUp)
+            }
+            "suspended_push_up" => Ok(PushUpExerciseName::SuspendedPushUp),
+            "weighted_suspended_push_up" => Ok(PushUpExerciseName::WeightedSuspendedPushUp),
+            "swiss_ball_push_up" => Ok(PushUpExerciseName::SwissBallPushUp),
+            "weighted_swiss_ball_push_up" => Ok(PushUpExerciseName::WeightedSwissBallPushUp),
+            "swiss_ball_push_up_plus" => Ok(PushUpExerciseName::SwissBallPushUpPlus),
+            "weighted_swiss_ball_push_up_plus" => {
+                Ok(PushUpExerciseName::WeightedSwissBallPushUpPlus)
+            }
+            "t_push_up" => Ok(PushUpExerciseName::TPushUp),
+            "weighted_t_push_up" => Ok(PushUpExerciseName::WeightedTPushUp),
+            "triple_stop_push_up" => Ok(PushUpExerciseName::TripleStopPushUp),
+            "weighted_triple_stop_push_up" => Ok(PushUpExerciseName::WeightedTripleStopPushUp),
+            "wide_hands_push_up" => Ok(PushUpExerciseName::WideHandsPushUp),
+            "weighted_wide_hands_push_up" => Ok(PushUpExerciseName::WeightedWideHandsPushUp),
+            "parallette_handstand_push_up" => Ok(PushUpExerciseName::ParalletteHandstandPushUp),
+            "weighted_parallette_handstand_push_up" => {
+                Ok(PushUpExerciseName::WeightedParalletteHandstandPushUp)
+            }
+            "ring_handstand_push_up" => Ok(PushUpExerciseName::RingHandstandPushUp),
+            "weighted_ring_handstand_push_up" => {
+                Ok(PushUpExerciseName::WeightedRingHandstandPushUp)
+            }
+            "ring_push_up" => Ok(PushUpExerciseName::RingPushUp),
+            "weighted_ring_push_up" => Ok(PushUpExerciseName::WeightedRingPushUp),
+            "push_up" => Ok(PushUpExerciseName::PushUp),
+            "pilates_pushup" => Ok(PushUpExerciseName::PilatesPushup),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for PushUpExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -23676,6 +28400,64 @@ impl convert::From<i64> for RowExerciseName {
         RowExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for RowExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "barbell_straight_leg_deadlift_to_row" => {
+                Ok(RowExerciseName::BarbellStraightLegDeadliftToRow)
+            }
+            "cable_row_standing" => Ok(RowExerciseName::CableRowStanding),
+            "dumbbell_row" => Ok(RowExerciseName::DumbbellRow),
+            "elevated_feet_inverted_row" => Ok(RowExerciseName::ElevatedFeetInvertedRow),
+            "weighted_elevated_feet_inverted_row" => {
+                Ok(RowExerciseName::WeightedElevatedFeetInvertedRow)
+            }
+            "face_pull" => Ok(RowExerciseName::FacePull),
+            "face_pull_with_external_rotation" => Ok(RowExerciseName::FacePullWithExternalRotation),
+            "inverted_row_with_feet_on_swiss_ball" => {
+                Ok(RowExerciseName::InvertedRowWithFeetOnSwissBall)
+            }
+            "weighted_inverted_row_with_feet_on_swiss_ball" => {
+                Ok(RowExerciseName::WeightedInvertedRowWithFeetOnSwissBall)
+            }
+            "kettlebell_row" => Ok(RowExerciseName::KettlebellRow),
+            "modified_inverted_row" => Ok(RowExerciseName::ModifiedInvertedRow),
+            "weighted_modified_inverted_row" => Ok(RowExerciseName::WeightedModifiedInvertedRow),
+            "neutral_grip_alternating_dumbbell_row" => {
+                Ok(RowExerciseName::NeutralGripAlternatingDumbbellRow)
+            }
+            "one_arm_bent_over_row" => Ok(RowExerciseName::OneArmBentOverRow),
+            "one_legged_dumbbell_row" => Ok(RowExerciseName::OneLeggedDumbbellRow),
+            "renegade_row" => Ok(RowExerciseName::RenegadeRow),
+            "reverse_grip_barbell_row" => Ok(RowExerciseName::ReverseGripBarbellRow),
+            "rope_handle_cable_row" => Ok(RowExerciseName::RopeHandleCableRow),
+            "seated_cable_row" => Ok(RowExerciseName::SeatedCableRow),
+            "seated_dumbbell_row" => Ok(RowExerciseName::SeatedDumbbellRow),
+            "single_arm_cable_row" => Ok(RowExerciseName::SingleArmCableRow),
+            "single_arm_cable_row_and_rotation" => {
+                Ok(RowExerciseName::SingleArmCableRowAndRotation)
+            }
+            "single_arm_inverted_row" => Ok(RowExerciseName::SingleArmInvertedRow),
+            "weighted_single_arm_inverted_row" => Ok(RowExerciseName::WeightedSingleArmInvertedRow),
+            "single_arm_neutral_grip_dumbbell_row" => {
+                Ok(RowExerciseName::SingleArmNeutralGripDumbbellRow)
+            }
+            "single_arm_neutral_grip_dumbbell_row_and_rotation" => {
+                Ok(RowExerciseName::SingleArmNeutralGripDumbbellRowAndRotation)
+            }
+            "suspended_inverted_row" => Ok(RowExerciseName::SuspendedInvertedRow),
+            "weighted_suspended_inverted_row" => Ok(RowExerciseName::WeightedSuspendedInvertedRow),
+            "t_bar_row" => Ok(RowExerciseName::TBarRow),
+            "towel_grip_inverted_row" => Ok(RowExerciseName::TowelGripInvertedRow),
+            "weighted_towel_grip_inverted_row" => Ok(RowExerciseName::WeightedTowelGripInvertedRow),
+            "underhand_grip_cable_row" => Ok(RowExerciseName::UnderhandGripCableRow),
+            "v_grip_cable_row" => Ok(RowExerciseName::VGripCableRow),
+            "wide_grip_seated_cable_row" => Ok(RowExerciseName::WideGripSeatedCableRow),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for RowExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -23879,6 +28661,66 @@ impl convert::From<i64> for ShoulderPressExerciseName {
         ShoulderPressExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for ShoulderPressExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "alternating_dumbbell_shoulder_press" => {
+                Ok(ShoulderPressExerciseName::AlternatingDumbbellShoulderPress)
+            }
+            "arnold_press" => Ok(ShoulderPressExerciseName::ArnoldPress),
+            "barbell_front_squat_to_push_press" => {
+                Ok(ShoulderPressExerciseName::BarbellFrontSquatToPushPress)
+            }
+            "barbell_push_press" => Ok(ShoulderPressExerciseName::BarbellPushPress),
+            "barbell_shoulder_press" => Ok(ShoulderPressExerciseName::BarbellShoulderPress),
+            "dead_curl_press" => Ok(ShoulderPressExerciseName::DeadCurlPress),
+            "dumbbell_alternating_shoulder_press_and_twist" => {
+                Ok(ShoulderPressExerciseName::DumbbellAlternatingShoulderPressAndTwist)
+            }
+            "dumbbell_hammer_curl_to_lunge_to_press" => {
+                Ok(ShoulderPressExerciseName::DumbbellHammerCurlToLungeToPress)
+            }
+            "dumbbell_push_press" => Ok(ShoulderPressExerciseName::DumbbellPushPress),
+            "floor_inverted_shoulder_press" => {
+                Ok(ShoulderPressExerciseName::FloorInvertedShoulderPress)
+            }
+            "weighted_floor_inverted_shoulder_press" => {
+                Ok(ShoulderPressExerciseName::WeightedFloorInvertedShoulderPress)
+            }
+            "inverted_shoulder_press" => Ok(ShoulderPressExerciseName::InvertedShoulderPress),
+            "weighted_inverted_shoulder_press" => {
+                Ok(ShoulderPressExerciseName::WeightedInvertedShoulderPress)
+            }
+            "one_arm_push_press" => Ok(ShoulderPressExerciseName::OneArmPushPress),
+            "overhead_barbell_press" => Ok(ShoulderPressExerciseName::OverheadBarbellPress),
+            "overhead_dumbbell_press" => Ok(ShoulderPressExerciseName::OverheadDumbbellPress),
+            "seated_barbell_shoulder_press" => {
+                Ok(ShoulderPressExerciseName::SeatedBarbellShoulderPress)
+            }
+            "seated_dumbbell_shoulder_press" => {
+                Ok(ShoulderPressExerciseName::SeatedDumbbellShoulderPress)
+            }
+            "single_arm_dumbbell_shoulder_press" => {
+                Ok(ShoulderPressExerciseName::SingleArmDumbbellShoulderPress)
+            }
+            "single_arm_step_up_and_press" => {
+                Ok(ShoulderPressExerciseName::SingleArmStepUpAndPress)
+            }
+            "smith_machine_overhead_press" => {
+                Ok(ShoulderPressExerciseName::SmithMachineOverheadPress)
+            }
+            "split_stance_hammer_curl_to_press" => {
+                Ok(ShoulderPressExerciseName::SplitStanceHammerCurlToPress)
+            }
+            "swiss_ball_dumbbell_shoulder_press" => {
+                Ok(ShoulderPressExerciseName::SwissBallDumbbellShoulderPress)
+            }
+            "weight_plate_front_raise" => Ok(ShoulderPressExerciseName::WeightPlateFrontRaise),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for ShoulderPressExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -24133,6 +28975,63 @@ impl convert::From<i64> for ShoulderStabilityExerciseName {
         ShoulderStabilityExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for ShoulderStabilityExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "90_degree_cable_external_rotation" => {
+                Ok(ShoulderStabilityExerciseName::Name90DegreeCableExternalRotation)
+            }
+            "band_external_rotation" => Ok(ShoulderStabilityExerciseName::BandExternalRotation),
+            "band_internal_rotation" => Ok(ShoulderStabilityExerciseName::BandInternalRotation),
+            "bent_arm_lateral_raise_and_external_rotation" => {
+                Ok(ShoulderStabilityExerciseName::BentArmLateralRaiseAndExternalRotation)
+            }
+            "cable_external_rotation" => Ok(ShoulderStabilityExerciseName::CableExternalRotation),
+            "dumbbell_face_pull_with_external_rotation" => {
+                Ok(ShoulderStabilityExerciseName::DumbbellFacePullWithExternalRotation)
+            }
+            "floor_i_raise" => Ok(ShoulderStabilityExerciseName::FloorIRaise),
+            "weighted_floor_i_raise" => Ok(ShoulderStabilityExerciseName::WeightedFloorIRaise),
+            "floor_t_raise" => Ok(ShoulderStabilityExerciseName::FloorTRaise),
+            "weighted_floor_t_raise" => Ok(ShoulderStabilityExerciseName::WeightedFloorTRaise),
+            "floor_y_raise" => Ok(ShoulderStabilityExerciseName::FloorYRaise),
+            "weighted_floor_y_raise" => Ok(ShoulderStabilityExerciseName::WeightedFloorYRaise),
+            "incline_i_raise" => Ok(ShoulderStabilityExerciseName::InclineIRaise),
+            "weighted_incline_i_raise" => Ok(ShoulderStabilityExerciseName::WeightedInclineIRaise),
+            "incline_l_raise" => Ok(ShoulderStabilityExerciseName::InclineLRaise),
+            "weighted_incline_l_raise" => Ok(ShoulderStabilityExerciseName::WeightedInclineLRaise),
+            "incline_t_raise" => Ok(ShoulderStabilityExerciseName::InclineTRaise),
+            "weighted_incline_t_raise" => Ok(ShoulderStabilityExerciseName::WeightedInclineTRaise),
+            "incline_w_raise" => Ok(ShoulderStabilityExerciseName::InclineWRaise),
+            "weighted_incline_w_raise" => Ok(ShoulderStabilityExerciseName::WeightedInclineWRaise),
+            "incline_y_raise" => Ok(ShoulderStabilityExerciseName::InclineYRaise),
+            "weighted_incline_y_raise" => Ok(ShoulderStabilityExerciseName::WeightedInclineYRaise),
+            "lying_external_rotation" => Ok(ShoulderStabilityExerciseName::LyingExternalRotation),
+            "seated_dumbbell_external_rotation" => {
+                Ok(ShoulderStabilityExerciseName::SeatedDumbbellExternalRotation)
+            }
+            "standing_l_raise" => Ok(ShoulderStabilityExerciseName::StandingLRaise),
+            "swiss_ball_i_raise" => Ok(ShoulderStabilityExerciseName::SwissBallIRaise),
+            "weighted_swiss_ball_i_raise" => {
+                Ok(ShoulderStabilityExerciseName::WeightedSwissBallIRaise)
+            }
+            "swiss_ball_t_raise" => Ok(ShoulderStabilityExerciseName::SwissBallTRaise),
+            "weighted_swiss_ball_t_raise" => {
+                Ok(ShoulderStabilityExerciseName::WeightedSwissBallTRaise)
+            }
+            "swiss_ball_w_raise" => Ok(ShoulderStabilityExerciseName::SwissBallWRaise),
+            "weighted_swiss_ball_w_raise" => {
+                Ok(ShoulderStabilityExerciseName::WeightedSwissBallWRaise)
+            }
+            "swiss_ball_y_raise" => Ok(ShoulderStabilityExerciseName::SwissBallYRaise),
+            "weighted_swiss_ball_y_raise" => {
+                Ok(ShoulderStabilityExerciseName::WeightedSwissBallYRaise)
+            }
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for ShoulderStabilityExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -24271,6 +29170,33 @@ impl convert::From<i64> for ShrugExerciseName {
         ShrugExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for ShrugExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "barbell_jump_shrug" => Ok(ShrugExerciseName::BarbellJumpShrug),
+            "barbell_shrug" => Ok(ShrugExerciseName::BarbellShrug),
+            "barbell_upright_row" => Ok(ShrugExerciseName::BarbellUprightRow),
+            "behind_the_back_smith_machine_shrug" => {
+                Ok(ShrugExerciseName::BehindTheBackSmithMachineShrug)
+            }
+            "dumbbell_jump_shrug" => Ok(ShrugExerciseName::DumbbellJumpShrug),
+            "dumbbell_shrug" => Ok(ShrugExerciseName::DumbbellShrug),
+            "dumbbell_upright_row" => Ok(ShrugExerciseName::DumbbellUprightRow),
+            "incline_dumbbell_shrug" => Ok(ShrugExerciseName::InclineDumbbellShrug),
+            "overhead_barbell_shrug" => Ok(ShrugExerciseName::OverheadBarbellShrug),
+            "overhead_dumbbell_shrug" => Ok(ShrugExerciseName::OverheadDumbbellShrug),
+            "scaption_and_shrug" => Ok(ShrugExerciseName::ScaptionAndShrug),
+            "scapular_retraction" => Ok(ShrugExerciseName::ScapularRetraction),
+            "serratus_chair_shrug" => Ok(ShrugExerciseName::SerratusChairShrug),
+            "weighted_serratus_chair_shrug" => Ok(ShrugExerciseName::WeightedSerratusChairShrug),
+            "serratus_shrug" => Ok(ShrugExerciseName::SerratusShrug),
+            "weighted_serratus_shrug" => Ok(ShrugExerciseName::WeightedSerratusShrug),
+            "wide_grip_jump_shrug" => Ok(ShrugExerciseName::WideGripJumpShrug),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for ShrugExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -24524,6 +29450,56 @@ impl convert::From<i64> for SitUpExerciseName {
         SitUpExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for SitUpExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "alternating_sit_up" => Ok(SitUpExerciseName::AlternatingSitUp),
+            "weighted_alternating_sit_up" => Ok(SitUpExerciseName::WeightedAlternatingSitUp),
+            "bent_knee_v_up" => Ok(SitUpExerciseName::BentKneeVUp),
+            "weighted_bent_knee_v_up" => Ok(SitUpExerciseName::WeightedBentKneeVUp),
+            "butterfly_sit_up" => Ok(SitUpExerciseName::ButterflySitUp),
+            "weighted_butterfly_situp" => Ok(SitUpExerciseName::WeightedButterflySitup),
+            "cross_punch_roll_up" => Ok(SitUpExerciseName::CrossPunchRollUp),
+            "weighted_cross_punch_roll_up" => Ok(SitUpExerciseName::WeightedCrossPunchRollUp),
+            "crossed_arms_sit_up" => Ok(SitUpExerciseName::CrossedArmsSitUp),
+            "weighted_crossed_arms_sit_up" => Ok(SitUpExerciseName::WeightedCrossedArmsSitUp),
+            "get_up_sit_up" => Ok(SitUpExerciseName::GetUpSitUp),
+            "weighted_get_up_sit_up" => Ok(SitUpExerciseName::WeightedGetUpSitUp),
+            "hovering_sit_up" => Ok(SitUpExerciseName::HoveringSitUp),
+            "weighted_hovering_sit_up" => Ok(SitUpExerciseName::WeightedHoveringSitUp),
+            "kettlebell_sit_up" => Ok(SitUpExerciseName::KettlebellSitUp),
+            "medicine_ball_alternating_v_up" => Ok(SitUpExerciseName::MedicineBallAlternatingVUp),
+            "medicine_ball_sit_up" => Ok(SitUpExerciseName::MedicineBallSitUp),
+            "medicine_ball_v_up" => Ok(SitUpExerciseName::MedicineBallVUp),
+            "modified_sit_up" => Ok(SitUpExerciseName::ModifiedSitUp),
+            "negative_sit_up" => Ok(SitUpExerciseName::NegativeSitUp),
+            "one_arm_full_sit_up" => Ok(SitUpExerciseName::OneArmFullSitUp),
+            "reclining_circle" => Ok(SitUpExerciseName::RecliningCircle),
+            "weighted_reclining_circle" => Ok(SitUpExerciseName::WeightedRecliningCircle),
+            "reverse_curl_up" => Ok(SitUpExerciseName::ReverseCurlUp),
+            "weighted_reverse_curl_up" => Ok(SitUpExerciseName::WeightedReverseCurlUp),
+            "single_leg_swiss_ball_jackknife" => Ok(SitUpExerciseName::SingleLegSwissBallJackknife),
+            "weighted_single_leg_swiss_ball_jackknife" => {
+                Ok(SitUpExerciseName::WeightedSingleLegSwissBallJackknife)
+            }
+            "the_teaser" => Ok(SitUpExerciseName::TheTeaser),
+            "the_teaser_weighted" => Ok(SitUpExerciseName::TheTeaserWeighted),
+            "three_part_roll_down" => Ok(SitUpExerciseName::ThreePartRollDown),
+            "weighted_three_part_roll_down" => Ok(SitUpExerciseName::WeightedThreePartRollDown),
+            "v_up" => Ok(SitUpExerciseName::VUp),
+            "weighted_v_up" => Ok(SitUpExerciseName::WeightedVUp),
+            "weighted_russian_twist_on_swiss_ball" => {
+                Ok(SitUpExerciseName::WeightedRussianTwistOnSwissBall)
+            }
+            "weighted_sit_up" => Ok(SitUpExerciseName::WeightedSitUp),
+            "x_abs" => Ok(SitUpExerciseName::XAbs),
+            "weighted_x_abs" => Ok(SitUpExerciseName::WeightedXAbs),
+            "sit_up" => Ok(SitUpExerciseName::SitUp),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SitUpExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -25071,6 +30047,128 @@ impl convert::From<i64> for SquatExerciseName {
         SquatExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for SquatExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "leg_press" => Ok(SquatExerciseName::LegPress),
+            "back_squat_with_body_bar" => Ok(SquatExerciseName::BackSquatWithBodyBar),
+            "back_squats" => Ok(SquatExerciseName::BackSquats),
+            "weighted_back_squats" => Ok(SquatExerciseName::WeightedBackSquats),
+            "balancing_squat" => Ok(SquatExerciseName::BalancingSquat),
+            "weighted_balancing_squat" => Ok(SquatExerciseName::WeightedBalancingSquat),
+            "barbell_back_squat" => Ok(SquatExerciseName::BarbellBackSquat),
+            "barbell_box_squat" => Ok(SquatExerciseName::BarbellBoxSquat),
+            "barbell_front_squat" => Ok(SquatExerciseName::BarbellFrontSquat),
+            "barbell_hack_squat" => Ok(SquatExerciseName::BarbellHackSquat),
+            "barbell_hang_squat_snatch" => Ok(SquatExerciseName::BarbellHangSquatSnatch),
+            "barbell_lateral_step_up" => Ok(SquatExerciseName::BarbellLateralStepUp),
+            "barbell_quarter_squat" => Ok(SquatExerciseName::BarbellQuarterSquat),
+            "barbell_siff_squat" => Ok(SquatExerciseName::BarbellSiffSquat),
+            "barbell_squat_snatch" => Ok(SquatExerciseName::BarbellSquatSnatch),
+            "barbell_squat_with_heels_raised" => Ok(SquatExerciseName::BarbellSquatWithHeelsRaised),
+            "barbell_stepover" => Ok(SquatExerciseName::BarbellStepover),
+            "barbell_step_up" => Ok(SquatExerciseName::BarbellStepUp),
+            "bench_squat_with_rotational_chop" => {
+                Ok(SquatExerciseName::BenchSquatWithRotationalChop)
+            }
+            "weighted_bench_squat_with_rotational_chop" => {
+                Ok(SquatExerciseName::WeightedBenchSquatWithRotationalChop)
+            }
+            "body_weight_wall_squat" => Ok(SquatExerciseName::BodyWeightWallSquat),
+            "weighted_wall_squat" => Ok(SquatExerciseName::WeightedWallSquat),
+            "box_step_squat" => Ok(SquatExerciseName::BoxStepSquat),
+            "weighted_box_step_squat" => Ok(SquatExerciseName::WeightedBoxStepSquat),
+            "braced_squat" => Ok(SquatExerciseName::BracedSquat),
+            "crossed_arm_barbell_front_squat" => Ok(SquatExerciseName::CrossedArmBarbellFrontSquat),
+            "crossover_dumbbell_step_up" => Ok(SquatExerciseName::CrossoverDumbbellStepUp),
+            "dumbbell_front_squat" => Ok(SquatExerciseName::DumbbellFrontSquat),
+            "dumbbell_split_squat" => Ok(SquatExerciseName::DumbbellSplitSquat),
+            "dumbbell_squat" => Ok(SquatExerciseName::DumbbellSquat),
+            "dumbbell_squat_clean" => Ok(SquatExerciseName::DumbbellSquatClean),
+            "dumbbell_stepover" => Ok(SquatExerciseName::DumbbellStepover),
+            "dumbbell_step_up" => Ok(SquatExerciseName::DumbbellStepUp),
+            "elevated_single_leg_squat" => Ok(SquatExerciseName::ElevatedSingleLegSquat),
+            "weighted_elevated_single_leg_squat" => {
+                Ok(SquatExerciseName::WeightedElevatedSingleLegSquat)
+            }
+            "figure_four_squats" => Ok(SquatExerciseName::FigureFourSquats),
+            "weighted_figure_four_squats" => Ok(SquatExerciseName::WeightedFigureFourSquats),
+            "goblet_squat" => Ok(SquatExerciseName::GobletSquat),
+            "kettlebell_squat" => Ok(SquatExerciseName::KettlebellSquat),
+            "kettlebell_swing_overhead" => Ok(SquatExerciseName::KettlebellSwingOverhead),
+            "kettlebell_swing_with_flip_to_squat" => {
+                Ok(SquatExerciseName::KettlebellSwingWithFlipToSquat)
+            }
+            "lateral_dumbbell_step_up" => Ok(SquatExerciseName::LateralDumbbellStepUp),
+            "one_legged_squat" => Ok(SquatExerciseName::OneLeggedSquat),
+            "overhead_dumbbell_squat" => Ok(SquatExerciseName::OverheadDumbbellSquat),
+            "overhead_squat" => Ok(SquatExerciseName::OverheadSquat),
+            "partial_single_leg_squat" => Ok(SquatExerciseName::PartialSingleLegSquat),
+            "weighted_partial_single_leg_squat" => {
+                Ok(SquatExerciseName::WeightedPartialSingleLegSquat)
+            }
+            "pistol_squat" => Ok(SquatExerciseName::PistolSquat),
+            "weighted_pistol_squat" => Ok(SquatExerciseName::WeightedPistolSquat),
+            "plie_slides" => Ok(SquatExerciseName::PlieSlides),
+            "weighted_plie_slides" => Ok(SquatExerciseName::WeightedPlieSlides),
+            "plie_squat" => Ok(SquatExerciseName::PlieSquat),
+            "weighted_plie_squat" => Ok(SquatExerciseName::WeightedPlieSquat),
+            "prisoner_squat" => Ok(SquatExerciseName::PrisonerSquat),
+            "weighted_prisoner_squat" => Ok(SquatExerciseName::WeightedPrisonerSquat),
+            "single_leg_bench_get_up" => Ok(SquatExerciseName::SingleLegBenchGetUp),
+            "weighted_single_leg_bench_get_up" => {
+                Ok(SquatExerciseName::WeightedSingleLegBenchGetUp)
+            }
+            "single_leg_bench_squat" => Ok(SquatExerciseName::SingleLegBenchSquat),
+            "weighted_single_leg_bench_squat" => Ok(SquatExerciseName::WeightedSingleLegBenchSquat),
+            "single_leg_squat_on_swiss_ball" => Ok(SquatExerciseName::SingleLegSquatOnSwissBall),
+            "weighted_single_leg_squat_on_swiss_ball" => {
+                Ok(SquatExerciseName::WeightedSingleLegSquatOnSwissBall)
+            }
+            "squat" => Ok(SquatExerciseName::Squat),
+            "weighted_squat" => Ok(SquatExerciseName::WeightedSquat),
+            "squats_with_band" => Ok(SquatExerciseName::SquatsWithBand),
+            "staggered_squat" => Ok(SquatExerciseName::StaggeredSquat),
+            "weighted_staggered_squat" => Ok(SquatExerciseName::WeightedStaggeredSquat),
+            "step_up" => Ok(SquatExerciseName::StepUp),
+            "weighted_step_up" => Ok(SquatExerciseName::WeightedStepUp),
+            "suitcase_squats" => Ok(SquatExerciseName::SuitcaseSquats),
+            "sumo_squat" => Ok(SquatExerciseName::SumoSquat),
+            "sumo_squat_slide_in" => Ok(SquatExerciseName::SumoSquatSlideIn),
+            "weighted_sumo_squat_slide_in" => Ok(SquatExerciseName::WeightedSumoSquatSlideIn),
+            "sumo_squat_to_high_pull" => Ok(SquatExerciseName::SumoSquatToHighPull),
+            "sumo_squat_to_stand" => Ok(SquatExerciseName::SumoSquatToStand),
+            "weighted_sumo_squat_to_stand" => Ok(SquatExerciseName::WeightedSumoSquatToStand),
+            "sumo_squat_with_rotation" => Ok(SquatExerciseName::SumoSquatWithRotation),
+            "weighted_sumo_squat_with_rotation" => {
+                Ok(SquatExerciseName::WeightedSumoSquatWithRotation)
+            }
+            "swiss_ball_body_weight_wall_squat" => {
+                Ok(SquatExerciseName::SwissBallBodyWeightWallSquat)
+            }
+            "weighted_swiss_ball_wall_squat" => Ok(SquatExerciseName::WeightedSwissBallWallSquat),
+            "thrusters" => Ok(SquatExerciseName::Thrusters),
+            "uneven_squat" => Ok(SquatExerciseName::UnevenSquat),
+            "weighted_uneven_squat" => Ok(SquatExerciseName::WeightedUnevenSquat),
+            "waist_slimming_squat" => Ok(SquatExerciseName::WaistSlimmingSquat),
+            "wall_ball" => Ok(SquatExerciseName::WallBall),
+            "wide_stance_barbell_squat" => Ok(SquatExerciseName::WideStanceBarbellSquat),
+            "wide_stance_goblet_squat" => Ok(SquatExerciseName::WideStanceGobletSquat),
+            "zercher_squat" => Ok(SquatExerciseName::ZercherSquat),
+            "kbs_overhead" => Ok(SquatExerciseName::KbsOverhead),
+            "squat_and_side_kick" => Ok(SquatExerciseName::SquatAndSideKick),
+            "squat_jumps_in_n_out" => Ok(SquatExerciseName::SquatJumpsInNOut),
+            "pilates_plie_squats_parallel_turned_out_flat_and_heels" => {
+                Ok(SquatExerciseName::PilatesPlieSquatsParallelTurnedOutFlatAndHeels)
+            }
+            "releve_straight_leg_and_knee_bent_with_one_leg_variation" => {
+                Ok(SquatExerciseName::ReleveStraightLegAndKneeBentWithOneLegVariation)
+            }
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SquatExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -25191,6 +30289,29 @@ impl convert::From<i64> for TotalBodyExerciseName {
         TotalBodyExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for TotalBodyExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "burpee" => Ok(TotalBodyExerciseName::Burpee),
+            "weighted_burpee" => Ok(TotalBodyExerciseName::WeightedBurpee),
+            "burpee_box_jump" => Ok(TotalBodyExerciseName::BurpeeBoxJump),
+            "weighted_burpee_box_jump" => Ok(TotalBodyExerciseName::WeightedBurpeeBoxJump),
+            "high_pull_burpee" => Ok(TotalBodyExerciseName::HighPullBurpee),
+            "man_makers" => Ok(TotalBodyExerciseName::ManMakers),
+            "one_arm_burpee" => Ok(TotalBodyExerciseName::OneArmBurpee),
+            "squat_thrusts" => Ok(TotalBodyExerciseName::SquatThrusts),
+            "weighted_squat_thrusts" => Ok(TotalBodyExerciseName::WeightedSquatThrusts),
+            "squat_plank_push_up" => Ok(TotalBodyExerciseName::SquatPlankPushUp),
+            "weighted_squat_plank_push_up" => Ok(TotalBodyExerciseName::WeightedSquatPlankPushUp),
+            "standing_t_rotation_balance" => Ok(TotalBodyExerciseName::StandingTRotationBalance),
+            "weighted_standing_t_rotation_balance" => {
+                Ok(TotalBodyExerciseName::WeightedStandingTRotationBalance)
+            }
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for TotalBodyExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -25499,6 +30620,101 @@ impl convert::From<i64> for TricepsExtensionExerciseName {
         TricepsExtensionExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for TricepsExtensionExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "bench_dip" => Ok(TricepsExtensionExerciseName::BenchDip),
+            "weighted_bench_dip" => Ok(TricepsExtensionExerciseName::WeightedBenchDip),
+            "body_weight_dip" => Ok(TricepsExtensionExerciseName::BodyWeightDip),
+            "cable_kickback" => Ok(TricepsExtensionExerciseName::CableKickback),
+            "cable_lying_triceps_extension" => {
+                Ok(TricepsExtensionExerciseName::CableLyingTricepsExtension)
+            }
+            "cable_overhead_triceps_extension" => {
+                Ok(TricepsExtensionExerciseName::CableOverheadTricepsExtension)
+            }
+            "dumbbell_kickback" => Ok(TricepsExtensionExerciseName::DumbbellKickback),
+            "dumbbell_lying_triceps_extension" => {
+                Ok(TricepsExtensionExerciseName::DumbbellLyingTricepsExtension)
+            }
+            "ez_bar_overhead_triceps_extension" => {
+                Ok(TricepsExtensionExerciseName::EzBarOverheadTricepsExtension)
+            }
+            "incline_dip" => Ok(TricepsExtensionExerciseName::InclineDip),
+            "weighted_incline_dip" => Ok(TricepsExtensionExerciseName::WeightedInclineDip),
+            "incline_ez_bar_lying_triceps_extension" => {
+                Ok(TricepsExtensionExerciseName::InclineEzBarLyingTricepsExtension)
+            }
+            "lying_dumbbell_pullover_to_extension" => {
+                Ok(TricepsExtensionExerciseName::LyingDumbbellPulloverToExtension)
+            }
+            "lying_ez_bar_triceps_extension" => {
+                Ok(TricepsExtensionExerciseName::LyingEzBarTricepsExtension)
+            }
+            "lying_triceps_extension_to_close_grip_bench_press" => {
+                Ok(TricepsExtensionExerciseName::LyingTricepsExtensionToCloseGripBenchPress)
+            }
+            "overhead_dumbbell_triceps_extension" => {
+                Ok(TricepsExtensionExerciseName::OverheadDumbbellTricepsExtension)
+            }
+            "reclining_triceps_press" => Ok(TricepsExtensionExerciseName::RecliningTricepsPress),
+            "reverse_grip_pressdown" => Ok(TricepsExtensionExerciseName::ReverseGripPressdown),
+            "reverse_grip_triceps_pressdown" => {
+                Ok(TricepsExtensionExerciseName::ReverseGripTricepsPressdown)
+            }
+            "rope_pressdown" => Ok(TricepsExtensionExerciseName::RopePressdown),
+            "seated_barbell_overhead_triceps_extension" => {
+                Ok(TricepsExtensionExerciseName::SeatedBarbellOverheadTricepsExtension)
+            }
+            "seated_dumbbell_overhead_triceps_extension" => {
+                Ok(TricepsExtensionExerciseName::SeatedDumbbellOverheadTricepsExtension)
+            }
+            "seated_ez_bar_overhead_triceps_extension" => {
+                Ok(TricepsExtensionExerciseName::SeatedEzBarOverheadTricepsExtension)
+            }
+            "seated_single_arm_overhead_dumbbell_extension" => {
+                Ok(TricepsExtensionExerciseName::SeatedSingleArmOverheadDumbbellExtension)
+            }
+            "single_arm_dumbbell_overhead_triceps_extension" => {
+                Ok(TricepsExtensionExerciseName::SingleArmDumbbellOverheadTricepsExtension)
+            }
+            "single_dumbbell_seated_overhead_triceps_extension" => {
+                Ok(TricepsExtensionExerciseName::SingleDumbbellSeatedOverheadTricepsExtension)
+            }
+            "single_leg_bench_dip_and_kick" => {
+                Ok(TricepsExtensionExerciseName::SingleLegBenchDipAndKick)
+            }
+            "weighted_single_leg_bench_dip_and_kick" => {
+                Ok(TricepsExtensionExerciseName::WeightedSingleLegBenchDipAndKick)
+            }
+            "single_leg_dip" => Ok(TricepsExtensionExerciseName::SingleLegDip),
+            "weighted_single_leg_dip" => Ok(TricepsExtensionExerciseName::WeightedSingleLegDip),
+            "static_lying_triceps_extension" => {
+                Ok(TricepsExtensionExerciseName::StaticLyingTricepsExtension)
+            }
+            "suspended_dip" => Ok(TricepsExtensionExerciseName::SuspendedDip),
+            "weighted_suspended_dip" => Ok(TricepsExtensionExerciseName::WeightedSuspendedDip),
+            "swiss_ball_dumbbell_lying_triceps_extension" => {
+                Ok(TricepsExtensionExerciseName::SwissBallDumbbellLyingTricepsExtension)
+            }
+            "swiss_ball_ez_bar_lying_triceps_extension" => {
+                Ok(TricepsExtensionExerciseName::SwissBallEzBarLyingTricepsExtension)
+            }
+            "swiss_ball_ez_bar_overhead_triceps_extension" => {
+                Ok(TricepsExtensionExerciseName::SwissBallEzBarOverheadTricepsExtension)
+            }
+            "tabletop_dip" => Ok(TricepsExtensionExerciseName::TabletopDip),
+            "weighted_tabletop_dip" => Ok(TricepsExtensionExerciseName::WeightedTabletopDip),
+            "triceps_extension_on_floor" => {
+                Ok(TricepsExtensionExerciseName::TricepsExtensionOnFloor)
+            }
+            "triceps_pressdown" => Ok(TricepsExtensionExerciseName::TricepsPressdown),
+            "weighted_dip" => Ok(TricepsExtensionExerciseName::WeightedDip),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for TricepsExtensionExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -25716,6 +30932,47 @@ impl convert::From<i64> for WarmUpExerciseName {
         WarmUpExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for WarmUpExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "quadruped_rocking" => Ok(WarmUpExerciseName::QuadrupedRocking),
+            "neck_tilts" => Ok(WarmUpExerciseName::NeckTilts),
+            "ankle_circles" => Ok(WarmUpExerciseName::AnkleCircles),
+            "ankle_dorsiflexion_with_band" => Ok(WarmUpExerciseName::AnkleDorsiflexionWithBand),
+            "ankle_internal_rotation" => Ok(WarmUpExerciseName::AnkleInternalRotation),
+            "arm_circles" => Ok(WarmUpExerciseName::ArmCircles),
+            "bent_over_reach_to_sky" => Ok(WarmUpExerciseName::BentOverReachToSky),
+            "cat_camel" => Ok(WarmUpExerciseName::CatCamel),
+            "elbow_to_foot_lunge" => Ok(WarmUpExerciseName::ElbowToFootLunge),
+            "forward_and_backward_leg_swings" => {
+                Ok(WarmUpExerciseName::ForwardAndBackwardLegSwings)
+            }
+            "groiners" => Ok(WarmUpExerciseName::Groiners),
+            "inverted_hamstring_stretch" => Ok(WarmUpExerciseName::InvertedHamstringStretch),
+            "lateral_duck_under" => Ok(WarmUpExerciseName::LateralDuckUnder),
+            "neck_rotations" => Ok(WarmUpExerciseName::NeckRotations),
+            "opposite_arm_and_leg_balance" => Ok(WarmUpExerciseName::OppositeArmAndLegBalance),
+            "reach_roll_and_lift" => Ok(WarmUpExerciseName::ReachRollAndLift),
+            "scorpion" => Ok(WarmUpExerciseName::Scorpion),
+            "shoulder_circles" => Ok(WarmUpExerciseName::ShoulderCircles),
+            "side_to_side_leg_swings" => Ok(WarmUpExerciseName::SideToSideLegSwings),
+            "sleeper_stretch" => Ok(WarmUpExerciseName::SleeperStretch),
+            "slide_out" => Ok(WarmUpExerciseName::SlideOut),
+            "swiss_ball_hip_crossover" => Ok(WarmUpExerciseName::SwissBallHipCrossover),
+            "swiss_ball_reach_roll_and_lift" => Ok(WarmUpExerciseName::SwissBallReachRollAndLift),
+            "swiss_ball_windshield_wipers" => Ok(WarmUpExerciseName::SwissBallWindshieldWipers),
+            "thoracic_rotation" => Ok(WarmUpExerciseName::ThoracicRotation),
+            "walking_high_kicks" => Ok(WarmUpExerciseName::WalkingHighKicks),
+            "walking_high_knees" => Ok(WarmUpExerciseName::WalkingHighKnees),
+            "walking_knee_hugs" => Ok(WarmUpExerciseName::WalkingKneeHugs),
+            "walking_leg_cradles" => Ok(WarmUpExerciseName::WalkingLegCradles),
+            "walkout" => Ok(WarmUpExerciseName::Walkout),
+            "walkout_from_push_up_position" => Ok(WarmUpExerciseName::WalkoutFromPushUpPosition),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for WarmUpExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -25785,6 +31042,18 @@ impl convert::From<i64> for RunExerciseName {
         RunExerciseName::from(value as u16)
     }
 }
+impl str::FromStr for RunExerciseName {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "run" => Ok(RunExerciseName::Run),
+            "walk" => Ok(RunExerciseName::Walk),
+            "jog" => Ok(RunExerciseName::Jog),
+            "sprint" => Ok(RunExerciseName::Sprint),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for RunExerciseName {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -25854,6 +31123,18 @@ impl convert::From<i64> for WaterType {
         WaterType::from(value as u8)
     }
 }
+impl str::FromStr for WaterType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "fresh" => Ok(WaterType::Fresh),
+            "salt" => Ok(WaterType::Salt),
+            "en13319" => Ok(WaterType::En13319),
+            "custom" => Ok(WaterType::Custom),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for WaterType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -25906,6 +31187,15 @@ impl convert::From<i64> for TissueModelType {
         TissueModelType::from(value as u8)
     }
 }
+impl str::FromStr for TissueModelType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "zhl_16c" => Ok(TissueModelType::Zhl16c),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for TissueModelType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -25967,6 +31257,17 @@ impl convert::From<i64> for DiveGasStatus {
         DiveGasStatus::from(value as u8)
     }
 }
+impl str::FromStr for DiveGasStatus {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "disabled" => Ok(DiveGasStatus::Disabled),
+            "enabled" => Ok(DiveGasStatus::Enabled),
+            "backup_only" => Ok(DiveGasStatus::BackupOnly),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for DiveGasStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -26215,6 +31516,53 @@ impl convert::From<i64> for DiveAlert {
         DiveAlert::from(value as u8)
     }
 }
+impl str::FromStr for DiveAlert {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "ndl_reached" => Ok(DiveAlert::NdlReached),
+            "gas_switch_prompted" => Ok(DiveAlert::GasSwitchPrompted),
+            "near_surface" => Ok(DiveAlert::NearSurface),
+            "approaching_ndl" => Ok(DiveAlert::ApproachingNdl),
+            "po2_warn" => Ok(DiveAlert::Po2Warn),
+            "po2_crit_high" => Ok(DiveAlert::Po2CritHigh),
+            "po2_crit_low" => Ok(DiveAlert::Po2CritLow),
+            "time_alert" => Ok(DiveAlert::TimeAlert),
+            "depth_alert" => Ok(DiveAlert::DepthAlert),
+            "deco_ceiling_broken" => Ok(DiveAlert::DecoCeilingBroken),
+            "deco_complete" => Ok(DiveAlert::DecoComplete),
+            "safety_stop_broken" => Ok(DiveAlert::SafetyStopBroken),
+            "safety_stop_complete" => Ok(DiveAlert::SafetyStopComplete),
+            "cns_warning" => Ok(DiveAlert::CnsWarning),
+            "cns_critical" => Ok(DiveAlert::CnsCritical),
+            "otu_warning" => Ok(DiveAlert::OtuWarning),
+            "otu_critical" => Ok(DiveAlert::OtuCritical),
+            "ascent_critical" => Ok(DiveAlert::AscentCritical),
+            "alert_dismissed_by_key" => Ok(DiveAlert::AlertDismissedByKey),
+            "alert_dismissed_by_timeout" => Ok(DiveAlert::AlertDismissedByTimeout),
+            "battery_low" => Ok(DiveAlert::BatteryLow),
+            "battery_critical" => Ok(DiveAlert::BatteryCritical),
+            "safety_stop_started" => Ok(DiveAlert::SafetyStopStarted),
+            "approaching_first_deco_stop" => Ok(DiveAlert::ApproachingFirstDecoStop),
+            "setpoint_switch_auto_low" => Ok(DiveAlert::SetpointSwitchAutoLow),
+            "setpoint_switch_auto_high" => Ok(DiveAlert::SetpointSwitchAutoHigh),
+            "setpoint_switch_manual_low" => Ok(DiveAlert::SetpointSwitchManualLow),
+            "setpoint_switch_manual_high" => Ok(DiveAlert::SetpointSwitchManualHigh),
+            "auto_setpoint_switch_ignored" => Ok(DiveAlert::AutoSetpointSwitchIgnored),
+            "switched_to_open_circuit" => Ok(DiveAlert::SwitchedToOpenCircuit),
+            "switched_to_closed_circuit" => Ok(DiveAlert::SwitchedToClosedCircuit),
+            "tank_battery_low" => Ok(DiveAlert::TankBatteryLow),
+            "po2_ccr_dil_low" => Ok(DiveAlert::Po2CcrDilLow),
+            "deco_stop_cleared" => Ok(DiveAlert::DecoStopCleared),
+            "apnea_neutral_buoyancy" => Ok(DiveAlert::ApneaNeutralBuoyancy),
+            "apnea_target_depth" => Ok(DiveAlert::ApneaTargetDepth),
+            "apnea_surface" => Ok(DiveAlert::ApneaSurface),
+            "apnea_high_speed" => Ok(DiveAlert::ApneaHighSpeed),
+            "apnea_low_speed" => Ok(DiveAlert::ApneaLowSpeed),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for DiveAlert {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -26279,6 +31627,17 @@ impl convert::From<i64> for DiveAlarmType {
         DiveAlarmType::from(value as u8)
     }
 }
+impl str::FromStr for DiveAlarmType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "depth" => Ok(DiveAlarmType::Depth),
+            "time" => Ok(DiveAlarmType::Time),
+            "speed" => Ok(DiveAlarmType::Speed),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for DiveAlarmType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -26335,6 +31694,16 @@ impl convert::From<i64> for DiveBacklightMode {
         DiveBacklightMode::from(value as u8)
     }
 }
+impl str::FromStr for DiveBacklightMode {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "at_depth" => Ok(DiveBacklightMode::AtDepth),
+            "always_on" => Ok(DiveBacklightMode::AlwaysOn),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for DiveBacklightMode {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -26393,6 +31762,16 @@ impl convert::From<i64> for CcrSetpointSwitchMode {
         CcrSetpointSwitchMode::from(value as u8)
     }
 }
+impl str::FromStr for CcrSetpointSwitchMode {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "manual" => Ok(CcrSetpointSwitchMode::Manual),
+            "automatic" => Ok(CcrSetpointSwitchMode::Automatic),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for CcrSetpointSwitchMode {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -26449,6 +31828,16 @@ impl convert::From<i64> for DiveGasMode {
         DiveGasMode::from(value as u8)
     }
 }
+impl str::FromStr for DiveGasMode {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "open_circuit" => Ok(DiveGasMode::OpenCircuit),
+            "closed_circuit_diluent" => Ok(DiveGasMode::ClosedCircuitDiluent),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for DiveGasMode {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -26505,6 +31894,16 @@ impl convert::From<i64> for FaveroProduct {
         FaveroProduct::from(value as u16)
     }
 }
+impl str::FromStr for FaveroProduct {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "assioma_uno" => Ok(FaveroProduct::AssiomaUno),
+            "assioma_duo" => Ok(FaveroProduct::AssiomaDuo),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for FaveroProduct {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -26663,6 +32062,35 @@ impl convert::From<i64> for SplitType {
         SplitType::from(value as u8)
     }
 }
+impl str::FromStr for SplitType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "ascent_split" => Ok(SplitType::AscentSplit),
+            "descent_split" => Ok(SplitType::DescentSplit),
+            "interval_active" => Ok(SplitType::IntervalActive),
+            "interval_rest" => Ok(SplitType::IntervalRest),
+            "interval_warmup" => Ok(SplitType::IntervalWarmup),
+            "interval_cooldown" => Ok(SplitType::IntervalCooldown),
+            "interval_recovery" => Ok(SplitType::IntervalRecovery),
+            "interval_other" => Ok(SplitType::IntervalOther),
+            "climb_active" => Ok(SplitType::ClimbActive),
+            "climb_rest" => Ok(SplitType::ClimbRest),
+            "surf_active" => Ok(SplitType::SurfActive),
+            "run_active" => Ok(SplitType::RunActive),
+            "run_rest" => Ok(SplitType::RunRest),
+            "workout_round" => Ok(SplitType::WorkoutRound),
+            "rwd_run" => Ok(SplitType::RwdRun),
+            "rwd_walk" => Ok(SplitType::RwdWalk),
+            "windsurf_active" => Ok(SplitType::WindsurfActive),
+            "rwd_stand" => Ok(SplitType::RwdStand),
+            "transition" => Ok(SplitType::Transition),
+            "ski_lift_split" => Ok(SplitType::SkiLiftSplit),
+            "ski_run_split" => Ok(SplitType::SkiRunSplit),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for SplitType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -26724,6 +32152,17 @@ impl convert::From<i64> for ClimbProEvent {
         ClimbProEvent::from(value as u8)
     }
 }
+impl str::FromStr for ClimbProEvent {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "approach" => Ok(ClimbProEvent::Approach),
+            "start" => Ok(ClimbProEvent::Start),
+            "complete" => Ok(ClimbProEvent::Complete),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for ClimbProEvent {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -26790,6 +32229,17 @@ impl convert::From<i64> for GasConsumptionRateType {
         GasConsumptionRateType::from(value as u8)
     }
 }
+impl str::FromStr for GasConsumptionRateType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "pressure_sac" => Ok(GasConsumptionRateType::PressureSac),
+            "volume_sac" => Ok(GasConsumptionRateType::VolumeSac),
+            "rmv" => Ok(GasConsumptionRateType::Rmv),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for GasConsumptionRateType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -26851,6 +32301,17 @@ impl convert::From<i64> for TapSensitivity {
         TapSensitivity::from(value as u8)
     }
 }
+impl str::FromStr for TapSensitivity {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "high" => Ok(TapSensitivity::High),
+            "medium" => Ok(TapSensitivity::Medium),
+            "low" => Ok(TapSensitivity::Low),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for TapSensitivity {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -26917,6 +32378,18 @@ impl convert::From<i64> for RadarThreatLevelType {
         RadarThreatLevelType::from(value as u8)
     }
 }
+impl str::FromStr for RadarThreatLevelType {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "threat_unknown" => Ok(RadarThreatLevelType::ThreatUnknown),
+            "threat_none" => Ok(RadarThreatLevelType::ThreatNone),
+            "threat_approaching" => Ok(RadarThreatLevelType::ThreatApproaching),
+            "threat_approaching_fast" => Ok(RadarThreatLevelType::ThreatApproachingFast),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for RadarThreatLevelType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -26975,6 +32448,16 @@ impl convert::From<i64> for NoFlyTimeMode {
         NoFlyTimeMode::from(value as u8)
     }
 }
+impl str::FromStr for NoFlyTimeMode {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "standard" => Ok(NoFlyTimeMode::Standard),
+            "flat_24_hours" => Ok(NoFlyTimeMode::Flat24Hours),
+            _ => Err(()),
+        }
+    }
+}
 impl Serialize for NoFlyTimeMode {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -27180,7 +32663,6 @@ pub enum FieldDataType {
     NoFlyTimeMode,
 }
 impl FieldDataType {
-    #[allow(clippy::match_like_matches_macro)]
     pub fn is_enum_type(self) -> bool {
         match self {
             FieldDataType::File => true,
@@ -27557,6 +33039,199 @@ impl FieldDataType {
             _ => false,
         }
     }
+    pub fn base_type(self) -> BaseType {
+        match self {
+            FieldDataType::Bool => BaseType::Enum,
+            FieldDataType::SInt8 => BaseType::SInt8,
+            FieldDataType::UInt8 => BaseType::UInt8,
+            FieldDataType::SInt16 => BaseType::SInt16,
+            FieldDataType::UInt16 => BaseType::UInt16,
+            FieldDataType::SInt32 => BaseType::SInt32,
+            FieldDataType::UInt32 => BaseType::UInt32,
+            FieldDataType::String => BaseType::String,
+            FieldDataType::Float32 => BaseType::Float32,
+            FieldDataType::Float64 => BaseType::Float64,
+            FieldDataType::UInt8z => BaseType::UInt8z,
+            FieldDataType::UInt16z => BaseType::UInt16z,
+            FieldDataType::UInt32z => BaseType::UInt32z,
+            FieldDataType::Byte => BaseType::Byte,
+            FieldDataType::SInt64 => BaseType::SInt64,
+            FieldDataType::UInt64 => BaseType::UInt64,
+            FieldDataType::UInt64z => BaseType::UInt64z,
+            FieldDataType::File => BaseType::Enum,
+            FieldDataType::MesgNum => BaseType::UInt16,
+            FieldDataType::Checksum => BaseType::UInt8,
+            FieldDataType::FileFlags => BaseType::UInt8z,
+            FieldDataType::MesgCount => BaseType::Enum,
+            FieldDataType::DateTime => BaseType::UInt32,
+            FieldDataType::LocalDateTime => BaseType::UInt32,
+            FieldDataType::MessageIndex => BaseType::UInt16,
+            FieldDataType::DeviceIndex => BaseType::UInt8,
+            FieldDataType::Gender => BaseType::Enum,
+            FieldDataType::Language => BaseType::Enum,
+            FieldDataType::LanguageBits0 => BaseType::UInt8z,
+            FieldDataType::LanguageBits1 => BaseType::UInt8z,
+            FieldDataType::LanguageBits2 => BaseType::UInt8z,
+            FieldDataType::LanguageBits3 => BaseType::UInt8z,
+            FieldDataType::LanguageBits4 => BaseType::UInt8z,
+            FieldDataType::TimeZone => BaseType::Enum,
+            FieldDataType::DisplayMeasure => BaseType::Enum,
+            FieldDataType::DisplayHeart => BaseType::Enum,
+            FieldDataType::DisplayPower => BaseType::Enum,
+            FieldDataType::DisplayPosition => BaseType::Enum,
+            FieldDataType::Switch => BaseType::Enum,
+            FieldDataType::Sport => BaseType::Enum,
+            FieldDataType::SportBits0 => BaseType::UInt8z,
+            FieldDataType::SportBits1 => BaseType::UInt8z,
+            FieldDataType::SportBits2 => BaseType::UInt8z,
+            FieldDataType::SportBits3 => BaseType::UInt8z,
+            FieldDataType::SportBits4 => BaseType::UInt8z,
+            FieldDataType::SportBits5 => BaseType::UInt8z,
+            FieldDataType::SportBits6 => BaseType::UInt8z,
+            FieldDataType::SubSport => BaseType::Enum,
+            FieldDataType::SportEvent => BaseType::Enum,
+            FieldDataType::Activity => BaseType::Enum,
+            FieldDataType::Intensity => BaseType::Enum,
+            FieldDataType::SessionTrigger => BaseType::Enum,
+            FieldDataType::AutolapTrigger => BaseType::Enum,
+            FieldDataType::LapTrigger => BaseType::Enum,
+            FieldDataType::TimeMode => BaseType::Enum,
+            FieldDataType::BacklightMode => BaseType::Enum,
+            FieldDataType::DateMode => BaseType::Enum,
+            FieldDataType::BacklightTimeout => BaseType::UInt8,
+            FieldDataType::Event => BaseType::Enum,
+            FieldDataType::EventType => BaseType::Enum,
+            FieldDataType::TimerTrigger => BaseType::Enum,
+            FieldDataType::FitnessEquipmentState => BaseType::Enum,
+            FieldDataType::Tone => BaseType::Enum,
+            FieldDataType::Autoscroll => BaseType::Enum,
+            FieldDataType::ActivityClass => BaseType::Enum,
+            FieldDataType::HrZoneCalc => BaseType::Enum,
+            FieldDataType::PwrZoneCalc => BaseType::Enum,
+            FieldDataType::WktStepDuration => BaseType::Enum,
+            FieldDataType::WktStepTarget => BaseType::Enum,
+            FieldDataType::Goal => BaseType::Enum,
+            FieldDataType::GoalRecurrence => BaseType::Enum,
+            FieldDataType::GoalSource => BaseType::Enum,
+            FieldDataType::Schedule => BaseType::Enum,
+            FieldDataType::CoursePoint => BaseType::Enum,
+            FieldDataType::Manufacturer => BaseType::UInt16,
+            FieldDataType::GarminProduct => BaseType::UInt16,
+            FieldDataType::AntplusDeviceType => BaseType::UInt8,
+            FieldDataType::AntNetwork => BaseType::Enum,
+            FieldDataType::WorkoutCapabilities => BaseType::UInt32z,
+            FieldDataType::BatteryStatus => BaseType::UInt8,
+            FieldDataType::HrType => BaseType::Enum,
+            FieldDataType::CourseCapabilities => BaseType::UInt32z,
+            FieldDataType::Weight => BaseType::UInt16,
+            FieldDataType::WorkoutHr => BaseType::UInt32,
+            FieldDataType::WorkoutPower => BaseType::UInt32,
+            FieldDataType::BpStatus => BaseType::Enum,
+            FieldDataType::UserLocalId => BaseType::UInt16,
+            FieldDataType::SwimStroke => BaseType::Enum,
+            FieldDataType::ActivityType => BaseType::Enum,
+            FieldDataType::ActivitySubtype => BaseType::Enum,
+            FieldDataType::ActivityLevel => BaseType::Enum,
+            FieldDataType::Side => BaseType::Enum,
+            FieldDataType::LeftRightBalance => BaseType::UInt8,
+            FieldDataType::LeftRightBalance100 => BaseType::UInt16,
+            FieldDataType::LengthType => BaseType::Enum,
+            FieldDataType::DayOfWeek => BaseType::Enum,
+            FieldDataType::ConnectivityCapabilities => BaseType::UInt32z,
+            FieldDataType::WeatherReport => BaseType::Enum,
+            FieldDataType::WeatherStatus => BaseType::Enum,
+            FieldDataType::WeatherSeverity => BaseType::Enum,
+            FieldDataType::WeatherSevereType => BaseType::Enum,
+            FieldDataType::TimeIntoDay => BaseType::UInt32,
+            FieldDataType::LocaltimeIntoDay => BaseType::UInt32,
+            FieldDataType::StrokeType => BaseType::Enum,
+            FieldDataType::BodyLocation => BaseType::Enum,
+            FieldDataType::SegmentLapStatus => BaseType::Enum,
+            FieldDataType::SegmentLeaderboardType => BaseType::Enum,
+            FieldDataType::SegmentDeleteStatus => BaseType::Enum,
+            FieldDataType::SegmentSelectionType => BaseType::Enum,
+            FieldDataType::SourceType => BaseType::Enum,
+            FieldDataType::LocalDeviceType => BaseType::UInt8,
+            FieldDataType::BleDeviceType => BaseType::UInt8,
+            FieldDataType::AntChannelId => BaseType::UInt32z,
+            FieldDataType::DisplayOrientation => BaseType::Enum,
+            FieldDataType::WorkoutEquipment => BaseType::Enum,
+            FieldDataType::WatchfaceMode => BaseType::Enum,
+            FieldDataType::DigitalWatchfaceLayout => BaseType::Enum,
+            FieldDataType::AnalogWatchfaceLayout => BaseType::Enum,
+            FieldDataType::RiderPositionType => BaseType::Enum,
+            FieldDataType::PowerPhaseType => BaseType::Enum,
+            FieldDataType::CameraEventType => BaseType::Enum,
+            FieldDataType::SensorType => BaseType::Enum,
+            FieldDataType::BikeLightNetworkConfigType => BaseType::Enum,
+            FieldDataType::CommTimeoutType => BaseType::UInt16,
+            FieldDataType::CameraOrientationType => BaseType::Enum,
+            FieldDataType::AttitudeStage => BaseType::Enum,
+            FieldDataType::AttitudeValidity => BaseType::UInt16,
+            FieldDataType::AutoSyncFrequency => BaseType::Enum,
+            FieldDataType::ExdLayout => BaseType::Enum,
+            FieldDataType::ExdDisplayType => BaseType::Enum,
+            FieldDataType::ExdDataUnits => BaseType::Enum,
+            FieldDataType::ExdQualifiers => BaseType::Enum,
+            FieldDataType::ExdDescriptors => BaseType::Enum,
+            FieldDataType::AutoActivityDetect => BaseType::UInt32,
+            FieldDataType::SupportedExdScreenLayouts => BaseType::UInt32z,
+            FieldDataType::FitBaseType => BaseType::UInt8,
+            FieldDataType::TurnType => BaseType::Enum,
+            FieldDataType::BikeLightBeamAngleMode => BaseType::UInt8,
+            FieldDataType::FitBaseUnit => BaseType::UInt16,
+            FieldDataType::SetType => BaseType::UInt8,
+            FieldDataType::ExerciseCategory => BaseType::UInt16,
+            FieldDataType::BenchPressExerciseName => BaseType::UInt16,
+            FieldDataType::CalfRaiseExerciseName => BaseType::UInt16,
+            FieldDataType::CardioExerciseName => BaseType::UInt16,
+            FieldDataType::CarryExerciseName => BaseType::UInt16,
+            FieldDataType::ChopExerciseName => BaseType::UInt16,
+            FieldDataType::CoreExerciseName => BaseType::UInt16,
+            FieldDataType::CrunchExerciseName => BaseType::UInt16,
+            FieldDataType::CurlExerciseName => BaseType::UInt16,
+            FieldDataType::DeadliftExerciseName => BaseType::UInt16,
+            FieldDataType::FlyeExerciseName => BaseType::UInt16,
+            FieldDataType::HipRaiseExerciseName => BaseType::UInt16,
+            FieldDataType::HipStabilityExerciseName => BaseType::UInt16,
+            FieldDataType::HipSwingExerciseName => BaseType::UInt16,
+            FieldDataType::HyperextensionExerciseName => BaseType::UInt16,
+            FieldDataType::LateralRaiseExerciseName => BaseType::UInt16,
+            FieldDataType::LegCurlExerciseName => BaseType::UInt16,
+            FieldDataType::LegRaiseExerciseName => BaseType::UInt16,
+            FieldDataType::LungeExerciseName => BaseType::UInt16,
+            FieldDataType::OlympicLiftExerciseName => BaseType::UInt16,
+            FieldDataType::PlankExerciseName => BaseType::UInt16,
+            FieldDataType::PlyoExerciseName => BaseType::UInt16,
+            FieldDataType::PullUpExerciseName => BaseType::UInt16,
+            FieldDataType::PushUpExerciseName => BaseType::UInt16,
+            FieldDataType::RowExerciseName => BaseType::UInt16,
+            FieldDataType::ShoulderPressExerciseName => BaseType::UInt16,
+            FieldDataType::ShoulderStabilityExerciseName => BaseType::UInt16,
+            FieldDataType::ShrugExerciseName => BaseType::UInt16,
+            FieldDataType::SitUpExerciseName => BaseType::UInt16,
+            FieldDataType::SquatExerciseName => BaseType::UInt16,
+            FieldDataType::TotalBodyExerciseName => BaseType::UInt16,
+            FieldDataType::TricepsExtensionExerciseName => BaseType::UInt16,
+            FieldDataType::WarmUpExerciseName => BaseType::UInt16,
+            FieldDataType::RunExerciseName => BaseType::UInt16,
+            FieldDataType::WaterType => BaseType::Enum,
+            FieldDataType::TissueModelType => BaseType::Enum,
+            FieldDataType::DiveGasStatus => BaseType::Enum,
+            FieldDataType::DiveAlert => BaseType::Enum,
+            FieldDataType::DiveAlarmType => BaseType::Enum,
+            FieldDataType::DiveBacklightMode => BaseType::Enum,
+            FieldDataType::CcrSetpointSwitchMode => BaseType::Enum,
+            FieldDataType::DiveGasMode => BaseType::Enum,
+            FieldDataType::FaveroProduct => BaseType::UInt16,
+            FieldDataType::SplitType => BaseType::Enum,
+            FieldDataType::ClimbProEvent => BaseType::Enum,
+            FieldDataType::GasConsumptionRateType => BaseType::Enum,
+            FieldDataType::TapSensitivity => BaseType::Enum,
+            FieldDataType::RadarThreatLevelType => BaseType::Enum,
+            FieldDataType::NoFlyTimeMode => BaseType::Enum,
+        }
+    }
 }
 pub fn get_field_variant_as_string(field_type: FieldDataType, value: i64) -> String {
     match field_type {
@@ -27749,3 +33424,329 @@ pub fn get_field_variant_as_string(field_type: FieldDataType, value: i64) -> Str
         _ => format!("Undefined{}", value),
     }
 }
+pub fn get_field_variant_from_string(field_type: FieldDataType, value: &str) -> Option<i64> {
+    match field_type {
+        FieldDataType::File => value.parse::<File>().ok().map(|v| v.as_i64()),
+        FieldDataType::MesgNum => value.parse::<MesgNum>().ok().map(|v| v.as_i64()),
+        FieldDataType::Checksum => value.parse::<Checksum>().ok().map(|v| v.as_i64()),
+        FieldDataType::FileFlags => value.parse::<FileFlags>().ok().map(|v| v.as_i64()),
+        FieldDataType::MesgCount => value.parse::<MesgCount>().ok().map(|v| v.as_i64()),
+        FieldDataType::MessageIndex => value.parse::<MessageIndex>().ok().map(|v| v.as_i64()),
+        FieldDataType::DeviceIndex => value.parse::<DeviceIndex>().ok().map(|v| v.as_i64()),
+        FieldDataType::Gender => value.parse::<Gender>().ok().map(|v| v.as_i64()),
+        FieldDataType::Language => value.parse::<Language>().ok().map(|v| v.as_i64()),
+        FieldDataType::LanguageBits0 => value.parse::<LanguageBits0>().ok().map(|v| v.as_i64()),
+        FieldDataType::LanguageBits1 => value.parse::<LanguageBits1>().ok().map(|v| v.as_i64()),
+        FieldDataType::LanguageBits2 => value.parse::<LanguageBits2>().ok().map(|v| v.as_i64()),
+        FieldDataType::LanguageBits3 => value.parse::<LanguageBits3>().ok().map(|v| v.as_i64()),
+        FieldDataType::LanguageBits4 => value.parse::<LanguageBits4>().ok().map(|v| v.as_i64()),
+        FieldDataType::TimeZone => value.parse::<TimeZone>().ok().map(|v| v.as_i64()),
+        FieldDataType::DisplayMeasure => value.parse::<DisplayMeasure>().ok().map(|v| v.as_i64()),
+        FieldDataType::DisplayHeart => value.parse::<DisplayHeart>().ok().map(|v| v.as_i64()),
+        FieldDataType::DisplayPower => value.parse::<DisplayPower>().ok().map(|v| v.as_i64()),
+        FieldDataType::DisplayPosition => value.parse::<DisplayPosition>().ok().map(|v| v.as_i64()),
+        FieldDataType::Switch => value.parse::<Switch>().ok().map(|v| v.as_i64()),
+        FieldDataType::Sport => value.parse::<Sport>().ok().map(|v| v.as_i64()),
+        FieldDataType::SportBits0 => value.parse::<SportBits0>().ok().map(|v| v.as_i64()),
+        FieldDataType::SportBits1 => value.parse::<SportBits1>().ok().map(|v| v.as_i64()),
+        FieldDataType::SportBits2 => value.parse::<SportBits2>().ok().map(|v| v.as_i64()),
+        FieldDataType::SportBits3 => value.parse::<SportBits3>().ok().map(|v| v.as_i64()),
+        FieldDataType::SportBits4 => value.parse::<SportBits4>().ok().map(|v| v.as_i64()),
+        FieldDataType::SportBits5 => value.parse::<SportBits5>().ok().map(|v| v.as_i64()),
+        FieldDataType::SportBits6 => value.parse::<SportBits6>().ok().map(|v| v.as_i64()),
+        FieldDataType::SubSport => value.parse::<SubSport>().ok().map(|v| v.as_i64()),
+        FieldDataType::SportEvent => value.parse::<SportEvent>().ok().map(|v| v.as_i64()),
+        FieldDataType::Activity => value.parse::<Activity>().ok().map(|v| v.as_i64()),
+        FieldDataType::Intensity => value.parse::<Intensity>().ok().map(|v| v.as_i64()),
+        FieldDataType::SessionTrigger => value.parse::<SessionTrigger>().ok().map(|v| v.as_i64()),
+        FieldDataType::AutolapTrigger => value.parse::<AutolapTrigger>().ok().map(|v| v.as_i64()),
+        FieldDataType::LapTrigger => value.parse::<LapTrigger>().ok().map(|v| v.as_i64()),
+        FieldDataType::TimeMode => value.parse::<TimeMode>().ok().map(|v| v.as_i64()),
+        FieldDataType::BacklightMode => value.parse::<BacklightMode>().ok().map(|v| v.as_i64()),
+        FieldDataType::DateMode => value.parse::<DateMode>().ok().map(|v| v.as_i64()),
+        FieldDataType::BacklightTimeout => {
+            value.parse::<BacklightTimeout>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::Event => value.parse::<Event>().ok().map(|v| v.as_i64()),
+        FieldDataType::EventType => value.parse::<EventType>().ok().map(|v| v.as_i64()),
+        FieldDataType::TimerTrigger => value.parse::<TimerTrigger>().ok().map(|v| v.as_i64()),
+        FieldDataType::FitnessEquipmentState => value
+            .parse::<FitnessEquipmentState>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::Tone => value.parse::<Tone>().ok().map(|v| v.as_i64()),
+        FieldDataType::Autoscroll => value.parse::<Autoscroll>().ok().map(|v| v.as_i64()),
+        FieldDataType::ActivityClass => value.parse::<ActivityClass>().ok().map(|v| v.as_i64()),
+        FieldDataType::HrZoneCalc => value.parse::<HrZoneCalc>().ok().map(|v| v.as_i64()),
+        FieldDataType::PwrZoneCalc => value.parse::<PwrZoneCalc>().ok().map(|v| v.as_i64()),
+        FieldDataType::WktStepDuration => value.parse::<WktStepDuration>().ok().map(|v| v.as_i64()),
+        FieldDataType::WktStepTarget => value.parse::<WktStepTarget>().ok().map(|v| v.as_i64()),
+        FieldDataType::Goal => value.parse::<Goal>().ok().map(|v| v.as_i64()),
+        FieldDataType::GoalRecurrence => value.parse::<GoalRecurrence>().ok().map(|v| v.as_i64()),
+        FieldDataType::GoalSource => value.parse::<GoalSource>().ok().map(|v| v.as_i64()),
+        FieldDataType::Schedule => value.parse::<Schedule>().ok().map(|v| v.as_i64()),
+        FieldDataType::CoursePoint => value.parse::<CoursePoint>().ok().map(|v| v.as_i64()),
+        FieldDataType::Manufacturer => value.parse::<Manufacturer>().ok().map(|v| v.as_i64()),
+        FieldDataType::GarminProduct => value.parse::<GarminProduct>().ok().map(|v| v.as_i64()),
+        FieldDataType::AntplusDeviceType => {
+            value.parse::<AntplusDeviceType>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::AntNetwork => value.parse::<AntNetwork>().ok().map(|v| v.as_i64()),
+        FieldDataType::WorkoutCapabilities => value
+            .parse::<WorkoutCapabilities>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::BatteryStatus => value.parse::<BatteryStatus>().ok().map(|v| v.as_i64()),
+        FieldDataType::HrType => value.parse::<HrType>().ok().map(|v| v.as_i64()),
+        FieldDataType::CourseCapabilities => {
+            value.parse::<CourseCapabilities>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::Weight => value.parse::<Weight>().ok().map(|v| v.as_i64()),
+        FieldDataType::WorkoutHr => value.parse::<WorkoutHr>().ok().map(|v| v.as_i64()),
+        FieldDataType::WorkoutPower => value.parse::<WorkoutPower>().ok().map(|v| v.as_i64()),
+        FieldDataType::BpStatus => value.parse::<BpStatus>().ok().map(|v| v.as_i64()),
+        FieldDataType::UserLocalId => value.parse::<UserLocalId>().ok().map(|v| v.as_i64()),
+        FieldDataType::SwimStroke => value.parse::<SwimStroke>().ok().map(|v| v.as_i64()),
+        FieldDataType::ActivityType => value.parse::<ActivityType>().ok().map(|v| v.as_i64()),
+        FieldDataType::ActivitySubtype => value.parse::<ActivitySubtype>().ok().map(|v| v.as_i64()),
+        FieldDataType::ActivityLevel => value.parse::<ActivityLevel>().ok().map(|v| v.as_i64()),
+        FieldDataType::Side => value.parse::<Side>().ok().map(|v| v.as_i64()),
+        FieldDataType::LeftRightBalance => {
+            value.parse::<LeftRightBalance>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::LeftRightBalance100 => value
+            .parse::<LeftRightBalance100>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::LengthType => value.parse::<LengthType>().ok().map(|v| v.as_i64()),
+        FieldDataType::DayOfWeek => value.parse::<DayOfWeek>().ok().map(|v| v.as_i64()),
+        FieldDataType::ConnectivityCapabilities => value
+            .parse::<ConnectivityCapabilities>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::WeatherReport => value.parse::<WeatherReport>().ok().map(|v| v.as_i64()),
+        FieldDataType::WeatherStatus => value.parse::<WeatherStatus>().ok().map(|v| v.as_i64()),
+        FieldDataType::WeatherSeverity => value.parse::<WeatherSeverity>().ok().map(|v| v.as_i64()),
+        FieldDataType::WeatherSevereType => {
+            value.parse::<WeatherSevereType>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::StrokeType => value.parse::<StrokeType>().ok().map(|v| v.as_i64()),
+        FieldDataType::BodyLocation => value.parse::<BodyLocation>().ok().map(|v| v.as_i64()),
+        FieldDataType::SegmentLapStatus => {
+            value.parse::<SegmentLapStatus>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::SegmentLeaderboardType => value
+            .parse::<SegmentLeaderboardType>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::SegmentDeleteStatus => value
+            .parse::<SegmentDeleteStatus>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::SegmentSelectionType => value
+            .parse::<SegmentSelectionType>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::SourceType => value.parse::<SourceType>().ok().map(|v| v.as_i64()),
+        FieldDataType::LocalDeviceType => value.parse::<LocalDeviceType>().ok().map(|v| v.as_i64()),
+        FieldDataType::BleDeviceType => value.parse::<BleDeviceType>().ok().map(|v| v.as_i64()),
+        FieldDataType::AntChannelId => value.parse::<AntChannelId>().ok().map(|v| v.as_i64()),
+        FieldDataType::DisplayOrientation => {
+            value.parse::<DisplayOrientation>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::WorkoutEquipment => {
+            value.parse::<WorkoutEquipment>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::WatchfaceMode => value.parse::<WatchfaceMode>().ok().map(|v| v.as_i64()),
+        FieldDataType::DigitalWatchfaceLayout => value
+            .parse::<DigitalWatchfaceLayout>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::AnalogWatchfaceLayout => value
+            .parse::<AnalogWatchfaceLayout>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::RiderPositionType => {
+            value.parse::<RiderPositionType>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::PowerPhaseType => value.parse::<PowerPhaseType>().ok().map(|v| v.as_i64()),
+        FieldDataType::CameraEventType => value.parse::<CameraEventType>().ok().map(|v| v.as_i64()),
+        FieldDataType::SensorType => value.parse::<SensorType>().ok().map(|v| v.as_i64()),
+        FieldDataType::BikeLightNetworkConfigType => value
+            .parse::<BikeLightNetworkConfigType>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::CommTimeoutType => value.parse::<CommTimeoutType>().ok().map(|v| v.as_i64()),
+        FieldDataType::CameraOrientationType => value
+            .parse::<CameraOrientationType>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::AttitudeStage => value.parse::<AttitudeStage>().ok().map(|v| v.as_i64()),
+        FieldDataType::AttitudeValidity => {
+            value.parse::<AttitudeValidity>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::AutoSyncFrequency => {
+            value.parse::<AutoSyncFrequency>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::ExdLayout => value.parse::<ExdLayout>().ok().map(|v| v.as_i64()),
+        FieldDataType::ExdDisplayType => value.parse::<ExdDisplayType>().ok().map(|v| v.as_i64()),
+        FieldDataType::ExdDataUnits => value.parse::<ExdDataUnits>().ok().map(|v| v.as_i64()),
+        FieldDataType::ExdQualifiers => value.parse::<ExdQualifiers>().ok().map(|v| v.as_i64()),
+        FieldDataType::ExdDescriptors => value.parse::<ExdDescriptors>().ok().map(|v| v.as_i64()),
+        FieldDataType::AutoActivityDetect => {
+            value.parse::<AutoActivityDetect>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::SupportedExdScreenLayouts => value
+            .parse::<SupportedExdScreenLayouts>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::FitBaseType => value.parse::<FitBaseType>().ok().map(|v| v.as_i64()),
+        FieldDataType::TurnType => value.parse::<TurnType>().ok().map(|v| v.as_i64()),
+        FieldDataType::BikeLightBeamAngleMode => value
+            .parse::<BikeLightBeamAngleMode>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::FitBaseUnit => value.parse::<FitBaseUnit>().ok().map(|v| v.as_i64()),
+        FieldDataType::SetType => value.parse::<SetType>().ok().map(|v| v.as_i64()),
+        FieldDataType::ExerciseCategory => {
+            value.parse::<ExerciseCategory>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::BenchPressExerciseName => value
+            .parse::<BenchPressExerciseName>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::CalfRaiseExerciseName => value
+            .parse::<CalfRaiseExerciseName>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::CardioExerciseName => {
+            value.parse::<CardioExerciseName>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::CarryExerciseName => {
+            value.parse::<CarryExerciseName>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::ChopExerciseName => {
+            value.parse::<ChopExerciseName>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::CoreExerciseName => {
+            value.parse::<CoreExerciseName>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::CrunchExerciseName => {
+            value.parse::<CrunchExerciseName>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::CurlExerciseName => {
+            value.parse::<CurlExerciseName>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::DeadliftExerciseName => value
+            .parse::<DeadliftExerciseName>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::FlyeExerciseName => {
+            value.parse::<FlyeExerciseName>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::HipRaiseExerciseName => value
+            .parse::<HipRaiseExerciseName>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::HipStabilityExerciseName => value
+            .parse::<HipStabilityExerciseName>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::HipSwingExerciseName => value
+            .parse::<HipSwingExerciseName>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::HyperextensionExerciseName => value
+            .parse::<HyperextensionExerciseName>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::LateralRaiseExerciseName => value
+            .parse::<LateralRaiseExerciseName>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::LegCurlExerciseName => value
+            .parse::<LegCurlExerciseName>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::LegRaiseExerciseName => value
+            .parse::<LegRaiseExerciseName>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::LungeExerciseName => {
+            value.parse::<LungeExerciseName>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::OlympicLiftExerciseName => value
+            .parse::<OlympicLiftExerciseName>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::PlankExerciseName => {
+            value.parse::<PlankExerciseName>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::PlyoExerciseName => {
+            value.parse::<PlyoExerciseName>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::PullUpExerciseName => {
+            value.parse::<PullUpExerciseName>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::PushUpExerciseName => {
+            value.parse::<PushUpExerciseName>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::RowExerciseName => value.parse::<RowExerciseName>().ok().map(|v| v.as_i64()),
+        FieldDataType::ShoulderPressExerciseName => value
+            .parse::<ShoulderPressExerciseName>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::ShoulderStabilityExerciseName => value
+            .parse::<ShoulderStabilityExerciseName>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::ShrugExerciseName => {
+            value.parse::<ShrugExerciseName>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::SitUpExerciseName => {
+            value.parse::<SitUpExerciseName>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::SquatExerciseName => {
+            value.parse::<SquatExerciseName>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::TotalBodyExerciseName => value
+            .parse::<TotalBodyExerciseName>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::TricepsExtensionExerciseName => value
+            .parse::<TricepsExtensionExerciseName>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::WarmUpExerciseName => {
+            value.parse::<WarmUpExerciseName>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::RunExerciseName => value.parse::<RunExerciseName>().ok().map(|v| v.as_i64()),
+        FieldDataType::WaterType => value.parse::<WaterType>().ok().map(|v| v.as_i64()),
+        FieldDataType::TissueModelType => value.parse::<TissueModelType>().ok().map(|v| v.as_i64()),
+        FieldDataType::DiveGasStatus => value.parse::<DiveGasStatus>().ok().map(|v| v.as_i64()),
+        FieldDataType::DiveAlert => value.parse::<DiveAlert>().ok().map(|v| v.as_i64()),
+        FieldDataType::DiveAlarmType => value.parse::<DiveAlarmType>().ok().map(|v| v.as_i64()),
+        FieldDataType::DiveBacklightMode => {
+            value.parse::<DiveBacklightMode>().ok().map(|v| v.as_i64())
+        }
+        FieldDataType::CcrSetpointSwitchMode => value
+            .parse::<CcrSetpointSwitchMode>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::DiveGasMode => value.parse::<DiveGasMode>().ok().map(|v| v.as_i64()),
+        FieldDataType::FaveroProduct => value.parse::<FaveroProduct>().ok().map(|v| v.as_i64()),
+        FieldDataType::SplitType => value.parse::<SplitType>().ok().map(|v| v.as_i64()),
+        FieldDataType::ClimbProEvent => value.parse::<ClimbProEvent>().ok().map(|v| v.as_i64()),
+        FieldDataType::GasConsumptionRateType => value
+            .parse::<GasConsumptionRateType>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::TapSensitivity => value.parse::<TapSensitivity>().ok().map(|v| v.as_i64()),
+        FieldDataType::RadarThreatLevelType => value
+            .parse::<RadarThreatLevelType>()
+            .ok()
+            .map(|v| v.as_i64()),
+        FieldDataType::NoFlyTimeMode => value.parse::<NoFlyTimeMode>().ok().map(|v| v.as_i64()),
+        _ => None,
+    }
+}
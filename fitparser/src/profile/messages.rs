@@ -0,0 +1,13459 @@
+//! Auto generated profile message field definitions from FIT SDK Release: 21.105.00
+#![allow(clippy::unreadable_literal)]
+use super::field_types::{FieldDataType, MesgNum};
+use super::FieldInfo;
+/// Must be first message in file.
+const FILE_ID_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "type",
+        field_type: FieldDataType::File,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "manufacturer",
+        field_type: FieldDataType::Manufacturer,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "product",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "favero_product",
+        field_type: FieldDataType::FaveroProduct,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "garmin_product",
+        field_type: FieldDataType::GarminProduct,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "serial_number",
+        field_type: FieldDataType::UInt32z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "time_created",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "number",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "product_name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const FILE_CREATOR_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "software_version",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "hardware_version",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const TIMESTAMP_CORRELATION_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "fractional_timestamp",
+        field_type: FieldDataType::UInt16,
+        scale: 32768.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "system_timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "fractional_system_timestamp",
+        field_type: FieldDataType::UInt16,
+        scale: 32768.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "local_timestamp",
+        field_type: FieldDataType::LocalDateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "timestamp_ms",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "system_timestamp_ms",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const SOFTWARE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 3,
+        name: "version",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "part_number",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const SLAVE_DEVICE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "manufacturer",
+        field_type: FieldDataType::Manufacturer,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "product",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "favero_product",
+        field_type: FieldDataType::FaveroProduct,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "garmin_product",
+        field_type: FieldDataType::GarminProduct,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+];
+const CAPABILITIES_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "languages",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "sports",
+        field_type: FieldDataType::SportBits0,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 21,
+        name: "workouts_supported",
+        field_type: FieldDataType::WorkoutCapabilities,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 23,
+        name: "connectivity_supported",
+        field_type: FieldDataType::ConnectivityCapabilities,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const FILE_CAPABILITIES_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "type",
+        field_type: FieldDataType::File,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "flags",
+        field_type: FieldDataType::FileFlags,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "directory",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "max_count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "max_size",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bytes",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const MESG_CAPABILITIES_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "file",
+        field_type: FieldDataType::File,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "mesg_num",
+        field_type: FieldDataType::MesgNum,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "count_type",
+        field_type: FieldDataType::MesgCount,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "num_per_file",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "max_per_file",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "max_per_file_type",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const FIELD_CAPABILITIES_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "file",
+        field_type: FieldDataType::File,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "mesg_num",
+        field_type: FieldDataType::MesgNum,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "field_num",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const DEVICE_SETTINGS_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "active_time_zone",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "utc_offset",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "time_offset",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "time_mode",
+        field_type: FieldDataType::TimeMode,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "time_zone_offset",
+        field_type: FieldDataType::SInt8,
+        scale: 4.000000,
+        offset: 0.000000,
+        units: "hr",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 12,
+        name: "backlight_mode",
+        field_type: FieldDataType::BacklightMode,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 36,
+        name: "activity_tracker_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 39,
+        name: "clock_time",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 40,
+        name: "pages_enabled",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 46,
+        name: "move_alert_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 47,
+        name: "date_mode",
+        field_type: FieldDataType::DateMode,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 55,
+        name: "display_orientation",
+        field_type: FieldDataType::DisplayOrientation,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 56,
+        name: "mounting_side",
+        field_type: FieldDataType::Side,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 57,
+        name: "default_page",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 58,
+        name: "autosync_min_steps",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "steps",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 59,
+        name: "autosync_min_time",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "minutes",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 80,
+        name: "lactate_threshold_autodetect_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 86,
+        name: "ble_auto_upload_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 89,
+        name: "auto_sync_frequency",
+        field_type: FieldDataType::AutoSyncFrequency,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 90,
+        name: "auto_activity_detect",
+        field_type: FieldDataType::AutoActivityDetect,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 94,
+        name: "number_of_screens",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 95,
+        name: "smart_notification_display_orientation",
+        field_type: FieldDataType::DisplayOrientation,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 134,
+        name: "tap_interface",
+        field_type: FieldDataType::Switch,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 174,
+        name: "tap_sensitivity",
+        field_type: FieldDataType::TapSensitivity,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const USER_PROFILE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "friendly_name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "gender",
+        field_type: FieldDataType::Gender,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "age",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "years",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "height",
+        field_type: FieldDataType::UInt8,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "weight",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "kg",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "language",
+        field_type: FieldDataType::Language,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "elev_setting",
+        field_type: FieldDataType::DisplayMeasure,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "weight_setting",
+        field_type: FieldDataType::DisplayMeasure,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "resting_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "default_max_running_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "default_max_biking_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "default_max_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 12,
+        name: "hr_setting",
+        field_type: FieldDataType::DisplayHeart,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 13,
+        name: "speed_setting",
+        field_type: FieldDataType::DisplayMeasure,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 14,
+        name: "dist_setting",
+        field_type: FieldDataType::DisplayMeasure,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 16,
+        name: "power_setting",
+        field_type: FieldDataType::DisplayPower,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 17,
+        name: "activity_class",
+        field_type: FieldDataType::ActivityClass,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 18,
+        name: "position_setting",
+        field_type: FieldDataType::DisplayPosition,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 21,
+        name: "temperature_setting",
+        field_type: FieldDataType::DisplayMeasure,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 22,
+        name: "local_id",
+        field_type: FieldDataType::UserLocalId,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 23,
+        name: "global_id",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 28,
+        name: "wake_time",
+        field_type: FieldDataType::LocaltimeIntoDay,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 29,
+        name: "sleep_time",
+        field_type: FieldDataType::LocaltimeIntoDay,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 30,
+        name: "height_setting",
+        field_type: FieldDataType::DisplayMeasure,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 31,
+        name: "user_running_step_length",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 32,
+        name: "user_walking_step_length",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 47,
+        name: "depth_setting",
+        field_type: FieldDataType::DisplayMeasure,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 49,
+        name: "dive_count",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const HRM_PROFILE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "hrm_ant_id",
+        field_type: FieldDataType::UInt16z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "log_hrv",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "hrm_ant_id_trans_type",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const SDM_PROFILE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "sdm_ant_id",
+        field_type: FieldDataType::UInt16z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "sdm_cal_factor",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "odometer",
+        field_type: FieldDataType::UInt32,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "speed_source",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "sdm_ant_id_trans_type",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "odometer_rollover",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const BIKE_PROFILE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "sport",
+        field_type: FieldDataType::Sport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "sub_sport",
+        field_type: FieldDataType::SubSport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "odometer",
+        field_type: FieldDataType::UInt32,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "bike_spd_ant_id",
+        field_type: FieldDataType::UInt16z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "bike_cad_ant_id",
+        field_type: FieldDataType::UInt16z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "bike_spdcad_ant_id",
+        field_type: FieldDataType::UInt16z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "bike_power_ant_id",
+        field_type: FieldDataType::UInt16z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "custom_wheelsize",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "auto_wheelsize",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "bike_weight",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "kg",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "power_cal_factor",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 12,
+        name: "auto_wheel_cal",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 13,
+        name: "auto_power_zero",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 14,
+        name: "id",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 15,
+        name: "spd_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 16,
+        name: "cad_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 17,
+        name: "spdcad_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 18,
+        name: "power_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 19,
+        name: "crank_length",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "mm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 20,
+        name: "enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 21,
+        name: "bike_spd_ant_id_trans_type",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 22,
+        name: "bike_cad_ant_id_trans_type",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 23,
+        name: "bike_spdcad_ant_id_trans_type",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 24,
+        name: "bike_power_ant_id_trans_type",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 37,
+        name: "odometer_rollover",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 38,
+        name: "front_gear_num",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 39,
+        name: "front_gear",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 40,
+        name: "rear_gear_num",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 41,
+        name: "rear_gear",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 44,
+        name: "shimano_di2_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const CONNECTIVITY_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "bluetooth_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "bluetooth_le_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "ant_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "live_tracking_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "weather_conditions_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "weather_alerts_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "auto_activity_upload_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "course_download_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "workout_download_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "gps_ephemeris_download_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "incident_detection_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 12,
+        name: "grouptrack_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const WATCHFACE_SETTINGS_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "mode",
+        field_type: FieldDataType::WatchfaceMode,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "layout",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "digital_layout",
+        field_type: FieldDataType::DigitalWatchfaceLayout,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "analog_layout",
+        field_type: FieldDataType::AnalogWatchfaceLayout,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const OHR_SETTINGS_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "enabled",
+        field_type: FieldDataType::Switch,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const TIME_IN_ZONE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "reference_mesg",
+        field_type: FieldDataType::MesgNum,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "reference_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "time_in_hr_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "time_in_speed_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "time_in_cadence_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "time_in_power_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "hr_zone_high_boundary",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "speed_zone_high_boundary",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "cadence_zone_high_bondary",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "power_zone_high_boundary",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "hr_calc_type",
+        field_type: FieldDataType::HrZoneCalc,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "max_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 12,
+        name: "resting_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 13,
+        name: "threshold_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 14,
+        name: "pwr_calc_type",
+        field_type: FieldDataType::PwrZoneCalc,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 15,
+        name: "functional_threshold_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const ZONES_TARGET_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 1,
+        name: "max_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "threshold_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "functional_threshold_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "hr_calc_type",
+        field_type: FieldDataType::HrZoneCalc,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "pwr_calc_type",
+        field_type: FieldDataType::PwrZoneCalc,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const SPORT_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "sport",
+        field_type: FieldDataType::Sport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "sub_sport",
+        field_type: FieldDataType::SubSport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const HR_ZONE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 1,
+        name: "high_bpm",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const SPEED_ZONE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "high_value",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const CADENCE_ZONE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "high_value",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const POWER_ZONE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 1,
+        name: "high_value",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const MET_ZONE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 1,
+        name: "high_bpm",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "calories",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "kcal / min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "fat_calories",
+        field_type: FieldDataType::UInt8,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "kcal / min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const DIVE_SETTINGS_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "model",
+        field_type: FieldDataType::TissueModelType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "gf_low",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "gf_high",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "water_type",
+        field_type: FieldDataType::WaterType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "water_density",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kg/m^3",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "po2_warn",
+        field_type: FieldDataType::UInt8,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "po2_critical",
+        field_type: FieldDataType::UInt8,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "po2_deco",
+        field_type: FieldDataType::UInt8,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "safety_stop_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "bottom_depth",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "bottom_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 12,
+        name: "apnea_countdown_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 13,
+        name: "apnea_countdown_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 14,
+        name: "backlight_mode",
+        field_type: FieldDataType::DiveBacklightMode,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 15,
+        name: "backlight_brightness",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 16,
+        name: "backlight_timeout",
+        field_type: FieldDataType::BacklightTimeout,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 17,
+        name: "repeat_dive_interval",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 18,
+        name: "safety_stop_time",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 19,
+        name: "heart_rate_source_type",
+        field_type: FieldDataType::SourceType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 20,
+        name: "heart_rate_source",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 20,
+        name: "heart_rate_antplus_device_type",
+        field_type: FieldDataType::AntplusDeviceType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 20,
+        name: "heart_rate_local_device_type",
+        field_type: FieldDataType::LocalDeviceType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 21,
+        name: "travel_gas",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 22,
+        name: "ccr_low_setpoint_switch_mode",
+        field_type: FieldDataType::CcrSetpointSwitchMode,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 23,
+        name: "ccr_low_setpoint",
+        field_type: FieldDataType::UInt8,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 24,
+        name: "ccr_low_setpoint_depth",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 25,
+        name: "ccr_high_setpoint_switch_mode",
+        field_type: FieldDataType::CcrSetpointSwitchMode,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 26,
+        name: "ccr_high_setpoint",
+        field_type: FieldDataType::UInt8,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 27,
+        name: "ccr_high_setpoint_depth",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 29,
+        name: "gas_consumption_display",
+        field_type: FieldDataType::GasConsumptionRateType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 30,
+        name: "up_key_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 35,
+        name: "dive_sounds",
+        field_type: FieldDataType::Tone,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 36,
+        name: "last_stop_multiple",
+        field_type: FieldDataType::UInt8,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 37,
+        name: "no_fly_time_mode",
+        field_type: FieldDataType::NoFlyTimeMode,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const DIVE_ALARM_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "depth",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "time",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "alarm_type",
+        field_type: FieldDataType::DiveAlarmType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "sound",
+        field_type: FieldDataType::Tone,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "dive_types",
+        field_type: FieldDataType::SubSport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "id",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "popup_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "trigger_on_descent",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "trigger_on_ascent",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "repeating",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "speed",
+        field_type: FieldDataType::SInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "mps",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const DIVE_APNEA_ALARM_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "depth",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "time",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "alarm_type",
+        field_type: FieldDataType::DiveAlarmType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "sound",
+        field_type: FieldDataType::Tone,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "dive_types",
+        field_type: FieldDataType::SubSport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "id",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "popup_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "trigger_on_descent",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "trigger_on_ascent",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "repeating",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "speed",
+        field_type: FieldDataType::SInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "mps",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const DIVE_GAS_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "helium_content",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "oxygen_content",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "status",
+        field_type: FieldDataType::DiveGasStatus,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "mode",
+        field_type: FieldDataType::DiveGasMode,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const GOAL_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "sport",
+        field_type: FieldDataType::Sport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "sub_sport",
+        field_type: FieldDataType::SubSport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "start_date",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "end_date",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "type",
+        field_type: FieldDataType::Goal,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "value",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "repeat",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "target_value",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "recurrence",
+        field_type: FieldDataType::GoalRecurrence,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "recurrence_value",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "source",
+        field_type: FieldDataType::GoalSource,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const ACTIVITY_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "total_timer_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "num_sessions",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "type",
+        field_type: FieldDataType::Activity,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "event",
+        field_type: FieldDataType::Event,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "event_type",
+        field_type: FieldDataType::EventType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "local_timestamp",
+        field_type: FieldDataType::LocalDateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "event_group",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const SESSION_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "event",
+        field_type: FieldDataType::Event,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "event_type",
+        field_type: FieldDataType::EventType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "start_time",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "start_position_lat",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "start_position_long",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "sport",
+        field_type: FieldDataType::Sport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "sub_sport",
+        field_type: FieldDataType::SubSport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "total_elapsed_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "total_timer_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "total_distance",
+        field_type: FieldDataType::UInt32,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "total_cycles",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "cycles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "total_strides",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "strides",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "total_strokes",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "strokes",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "total_calories",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kcal",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 13,
+        name: "total_fat_calories",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kcal",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 14,
+        name: "avg_speed",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 15,
+        name: "max_speed",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 16,
+        name: "avg_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 17,
+        name: "max_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 18,
+        name: "avg_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 18,
+        name: "avg_running_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "strides/min",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 19,
+        name: "max_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 19,
+        name: "max_running_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "strides/min",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 20,
+        name: "avg_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 21,
+        name: "max_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 22,
+        name: "total_ascent",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 23,
+        name: "total_descent",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 24,
+        name: "total_training_effect",
+        field_type: FieldDataType::UInt8,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 25,
+        name: "first_lap_index",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 26,
+        name: "num_laps",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 27,
+        name: "event_group",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 28,
+        name: "trigger",
+        field_type: FieldDataType::SessionTrigger,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 29,
+        name: "nec_lat",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 30,
+        name: "nec_long",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 31,
+        name: "swc_lat",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 32,
+        name: "swc_long",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 33,
+        name: "num_lengths",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "lengths",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 34,
+        name: "normalized_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 35,
+        name: "training_stress_score",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "tss",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 36,
+        name: "intensity_factor",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "if",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 37,
+        name: "left_right_balance",
+        field_type: FieldDataType::LeftRightBalance100,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 41,
+        name: "avg_stroke_count",
+        field_type: FieldDataType::UInt32,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "strokes/lap",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 42,
+        name: "avg_stroke_distance",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 43,
+        name: "swim_stroke",
+        field_type: FieldDataType::SwimStroke,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "swim_stroke",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 44,
+        name: "pool_length",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 45,
+        name: "threshold_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 46,
+        name: "pool_length_unit",
+        field_type: FieldDataType::DisplayMeasure,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 47,
+        name: "num_active_lengths",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "lengths",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 48,
+        name: "total_work",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "J",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 49,
+        name: "avg_altitude",
+        field_type: FieldDataType::UInt16,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 50,
+        name: "max_altitude",
+        field_type: FieldDataType::UInt16,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 51,
+        name: "gps_accuracy",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 52,
+        name: "avg_grade",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 53,
+        name: "avg_pos_grade",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 54,
+        name: "avg_neg_grade",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 55,
+        name: "max_pos_grade",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 56,
+        name: "max_neg_grade",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 57,
+        name: "avg_temperature",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 58,
+        name: "max_temperature",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 59,
+        name: "total_moving_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 60,
+        name: "avg_pos_vertical_speed",
+        field_type: FieldDataType::SInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 61,
+        name: "avg_neg_vertical_speed",
+        field_type: FieldDataType::SInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 62,
+        name: "max_pos_vertical_speed",
+        field_type: FieldDataType::SInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 63,
+        name: "max_neg_vertical_speed",
+        field_type: FieldDataType::SInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 64,
+        name: "min_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 65,
+        name: "time_in_hr_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 66,
+        name: "time_in_speed_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 67,
+        name: "time_in_cadence_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 68,
+        name: "time_in_power_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 69,
+        name: "avg_lap_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 70,
+        name: "best_lap_index",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 71,
+        name: "min_altitude",
+        field_type: FieldDataType::UInt16,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 82,
+        name: "player_score",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 83,
+        name: "opponent_score",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 84,
+        name: "opponent_name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 85,
+        name: "stroke_count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 86,
+        name: "zone_count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 87,
+        name: "max_ball_speed",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 88,
+        name: "avg_ball_speed",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 89,
+        name: "avg_vertical_oscillation",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "mm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 90,
+        name: "avg_stance_time_percent",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 91,
+        name: "avg_stance_time",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 92,
+        name: "avg_fractional_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 128.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 93,
+        name: "max_fractional_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 128.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 94,
+        name: "total_fractional_cycles",
+        field_type: FieldDataType::UInt8,
+        scale: 128.000000,
+        offset: 0.000000,
+        units: "cycles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 95,
+        name: "avg_total_hemoglobin_conc",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "g/dL",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 96,
+        name: "min_total_hemoglobin_conc",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "g/dL",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 97,
+        name: "max_total_hemoglobin_conc",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "g/dL",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 98,
+        name: "avg_saturated_hemoglobin_percent",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 99,
+        name: "min_saturated_hemoglobin_percent",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 100,
+        name: "max_saturated_hemoglobin_percent",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 101,
+        name: "avg_left_torque_effectiveness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 102,
+        name: "avg_right_torque_effectiveness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 103,
+        name: "avg_left_pedal_smoothness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 104,
+        name: "avg_right_pedal_smoothness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 105,
+        name: "avg_combined_pedal_smoothness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 111,
+        name: "sport_index",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 112,
+        name: "time_standing",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 113,
+        name: "stand_count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 114,
+        name: "avg_left_pco",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 115,
+        name: "avg_right_pco",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 116,
+        name: "avg_left_power_phase",
+        field_type: FieldDataType::UInt8,
+        scale: 0.711111,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 117,
+        name: "avg_left_power_phase_peak",
+        field_type: FieldDataType::UInt8,
+        scale: 0.711111,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 118,
+        name: "avg_right_power_phase",
+        field_type: FieldDataType::UInt8,
+        scale: 0.711111,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 119,
+        name: "avg_right_power_phase_peak",
+        field_type: FieldDataType::UInt8,
+        scale: 0.711111,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 120,
+        name: "avg_power_position",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 121,
+        name: "max_power_position",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 122,
+        name: "avg_cadence_position",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 123,
+        name: "max_cadence_position",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 124,
+        name: "enhanced_avg_speed",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 125,
+        name: "enhanced_max_speed",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 126,
+        name: "enhanced_avg_altitude",
+        field_type: FieldDataType::UInt32,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 127,
+        name: "enhanced_min_altitude",
+        field_type: FieldDataType::UInt32,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 128,
+        name: "enhanced_max_altitude",
+        field_type: FieldDataType::UInt32,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 129,
+        name: "avg_lev_motor_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 130,
+        name: "max_lev_motor_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 131,
+        name: "lev_battery_consumption",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 132,
+        name: "avg_vertical_ratio",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 133,
+        name: "avg_stance_time_balance",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 134,
+        name: "avg_step_length",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "mm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 137,
+        name: "total_anaerobic_training_effect",
+        field_type: FieldDataType::UInt8,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 139,
+        name: "avg_vam",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 140,
+        name: "avg_depth",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 141,
+        name: "max_depth",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 142,
+        name: "surface_interval",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 143,
+        name: "start_cns",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 144,
+        name: "end_cns",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 145,
+        name: "start_n2",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 146,
+        name: "end_n2",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 147,
+        name: "avg_respiration_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 148,
+        name: "max_respiration_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 149,
+        name: "min_respiration_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 150,
+        name: "min_temperature",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 155,
+        name: "o2_toxicity",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "OTUs",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 156,
+        name: "dive_number",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 168,
+        name: "training_load_peak",
+        field_type: FieldDataType::SInt32,
+        scale: 65536.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 169,
+        name: "enhanced_avg_respiration_rate",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "Breaths/min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 170,
+        name: "enhanced_max_respiration_rate",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "Breaths/min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 180,
+        name: "enhanced_min_respiration_rate",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 181,
+        name: "total_grit",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kGrit",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 182,
+        name: "total_flow",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "Flow",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 183,
+        name: "jump_count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 186,
+        name: "avg_grit",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kGrit",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 187,
+        name: "avg_flow",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "Flow",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 199,
+        name: "total_fractional_ascent",
+        field_type: FieldDataType::UInt8,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 200,
+        name: "total_fractional_descent",
+        field_type: FieldDataType::UInt8,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 208,
+        name: "avg_core_temperature",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 209,
+        name: "min_core_temperature",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 210,
+        name: "max_core_temperature",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const LAP_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "event",
+        field_type: FieldDataType::Event,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "event_type",
+        field_type: FieldDataType::EventType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "start_time",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "start_position_lat",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "start_position_long",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "end_position_lat",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "end_position_long",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "total_elapsed_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "total_timer_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "total_distance",
+        field_type: FieldDataType::UInt32,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "total_cycles",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "cycles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "total_strides",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "strides",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "total_strokes",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "strokes",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "total_calories",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kcal",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 12,
+        name: "total_fat_calories",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kcal",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 13,
+        name: "avg_speed",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 14,
+        name: "max_speed",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 15,
+        name: "avg_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 16,
+        name: "max_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 17,
+        name: "avg_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 17,
+        name: "avg_running_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "strides/min",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 18,
+        name: "max_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 18,
+        name: "max_running_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "strides/min",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 19,
+        name: "avg_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 20,
+        name: "max_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 21,
+        name: "total_ascent",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 22,
+        name: "total_descent",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 23,
+        name: "intensity",
+        field_type: FieldDataType::Intensity,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 24,
+        name: "lap_trigger",
+        field_type: FieldDataType::LapTrigger,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 25,
+        name: "sport",
+        field_type: FieldDataType::Sport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 26,
+        name: "event_group",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 32,
+        name: "num_lengths",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "lengths",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 33,
+        name: "normalized_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 34,
+        name: "left_right_balance",
+        field_type: FieldDataType::LeftRightBalance100,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 35,
+        name: "first_length_index",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 37,
+        name: "avg_stroke_distance",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 38,
+        name: "swim_stroke",
+        field_type: FieldDataType::SwimStroke,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 39,
+        name: "sub_sport",
+        field_type: FieldDataType::SubSport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 40,
+        name: "num_active_lengths",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "lengths",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 41,
+        name: "total_work",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "J",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 42,
+        name: "avg_altitude",
+        field_type: FieldDataType::UInt16,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 43,
+        name: "max_altitude",
+        field_type: FieldDataType::UInt16,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 44,
+        name: "gps_accuracy",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 45,
+        name: "avg_grade",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 46,
+        name: "avg_pos_grade",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 47,
+        name: "avg_neg_grade",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 48,
+        name: "max_pos_grade",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 49,
+        name: "max_neg_grade",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 50,
+        name: "avg_temperature",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 51,
+        name: "max_temperature",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 52,
+        name: "total_moving_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 53,
+        name: "avg_pos_vertical_speed",
+        field_type: FieldDataType::SInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 54,
+        name: "avg_neg_vertical_speed",
+        field_type: FieldDataType::SInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 55,
+        name: "max_pos_vertical_speed",
+        field_type: FieldDataType::SInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 56,
+        name: "max_neg_vertical_speed",
+        field_type: FieldDataType::SInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 57,
+        name: "time_in_hr_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 58,
+        name: "time_in_speed_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 59,
+        name: "time_in_cadence_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 60,
+        name: "time_in_power_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 61,
+        name: "repetition_num",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 62,
+        name: "min_altitude",
+        field_type: FieldDataType::UInt16,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 63,
+        name: "min_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 71,
+        name: "wkt_step_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 74,
+        name: "opponent_score",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 75,
+        name: "stroke_count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 76,
+        name: "zone_count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 77,
+        name: "avg_vertical_oscillation",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "mm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 78,
+        name: "avg_stance_time_percent",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 79,
+        name: "avg_stance_time",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 80,
+        name: "avg_fractional_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 128.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 81,
+        name: "max_fractional_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 128.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 82,
+        name: "total_fractional_cycles",
+        field_type: FieldDataType::UInt8,
+        scale: 128.000000,
+        offset: 0.000000,
+        units: "cycles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 83,
+        name: "player_score",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 84,
+        name: "avg_total_hemoglobin_conc",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "g/dL",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 85,
+        name: "min_total_hemoglobin_conc",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "g/dL",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 86,
+        name: "max_total_hemoglobin_conc",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "g/dL",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 87,
+        name: "avg_saturated_hemoglobin_percent",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 88,
+        name: "min_saturated_hemoglobin_percent",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 89,
+        name: "max_saturated_hemoglobin_percent",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 91,
+        name: "avg_left_torque_effectiveness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 92,
+        name: "avg_right_torque_effectiveness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 93,
+        name: "avg_left_pedal_smoothness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 94,
+        name: "avg_right_pedal_smoothness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 95,
+        name: "avg_combined_pedal_smoothness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 98,
+        name: "time_standing",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 99,
+        name: "stand_count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 100,
+        name: "avg_left_pco",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 101,
+        name: "avg_right_pco",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 102,
+        name: "avg_left_power_phase",
+        field_type: FieldDataType::UInt8,
+        scale: 0.711111,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 103,
+        name: "avg_left_power_phase_peak",
+        field_type: FieldDataType::UInt8,
+        scale: 0.711111,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 104,
+        name: "avg_right_power_phase",
+        field_type: FieldDataType::UInt8,
+        scale: 0.711111,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 105,
+        name: "avg_right_power_phase_peak",
+        field_type: FieldDataType::UInt8,
+        scale: 0.711111,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 106,
+        name: "avg_power_position",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 107,
+        name: "max_power_position",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 108,
+        name: "avg_cadence_position",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 109,
+        name: "max_cadence_position",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 110,
+        name: "enhanced_avg_speed",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 111,
+        name: "enhanced_max_speed",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 112,
+        name: "enhanced_avg_altitude",
+        field_type: FieldDataType::UInt32,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 113,
+        name: "enhanced_min_altitude",
+        field_type: FieldDataType::UInt32,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 114,
+        name: "enhanced_max_altitude",
+        field_type: FieldDataType::UInt32,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 115,
+        name: "avg_lev_motor_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 116,
+        name: "max_lev_motor_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 117,
+        name: "lev_battery_consumption",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 118,
+        name: "avg_vertical_ratio",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 119,
+        name: "avg_stance_time_balance",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 120,
+        name: "avg_step_length",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "mm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 121,
+        name: "avg_vam",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 122,
+        name: "avg_depth",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 123,
+        name: "max_depth",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 124,
+        name: "min_temperature",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 136,
+        name: "enhanced_avg_respiration_rate",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "Breaths/min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 137,
+        name: "enhanced_max_respiration_rate",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "Breaths/min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 147,
+        name: "avg_respiration_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 148,
+        name: "max_respiration_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 149,
+        name: "total_grit",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kGrit",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 150,
+        name: "total_flow",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "Flow",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 151,
+        name: "jump_count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 153,
+        name: "avg_grit",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kGrit",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 154,
+        name: "avg_flow",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "Flow",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 156,
+        name: "total_fractional_ascent",
+        field_type: FieldDataType::UInt8,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 157,
+        name: "total_fractional_descent",
+        field_type: FieldDataType::UInt8,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 158,
+        name: "avg_core_temperature",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 159,
+        name: "min_core_temperature",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 160,
+        name: "max_core_temperature",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const LENGTH_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "event",
+        field_type: FieldDataType::Event,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "event_type",
+        field_type: FieldDataType::EventType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "start_time",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "total_elapsed_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "total_timer_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "total_strokes",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "strokes",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "avg_speed",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "swim_stroke",
+        field_type: FieldDataType::SwimStroke,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "swim_stroke",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "avg_swimming_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "strokes/min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "event_group",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "total_calories",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kcal",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 12,
+        name: "length_type",
+        field_type: FieldDataType::LengthType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 18,
+        name: "player_score",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 19,
+        name: "opponent_score",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 20,
+        name: "stroke_count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 21,
+        name: "zone_count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 22,
+        name: "enhanced_avg_respiration_rate",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "Breaths/min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 23,
+        name: "enhanced_max_respiration_rate",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "Breaths/min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 24,
+        name: "avg_respiration_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 25,
+        name: "max_respiration_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const RECORD_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "position_lat",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "position_long",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "altitude",
+        field_type: FieldDataType::UInt16,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "distance",
+        field_type: FieldDataType::UInt32,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "speed",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "compressed_speed_distance",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "m/s,m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "grade",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "resistance",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "time_from_course",
+        field_type: FieldDataType::SInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 12,
+        name: "cycle_length",
+        field_type: FieldDataType::UInt8,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 13,
+        name: "temperature",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 17,
+        name: "speed_1s",
+        field_type: FieldDataType::UInt8,
+        scale: 16.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 18,
+        name: "cycles",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "cycles",
+        accumulate: true,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 19,
+        name: "total_cycles",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "cycles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 28,
+        name: "compressed_accumulated_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: true,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 29,
+        name: "accumulated_power",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 30,
+        name: "left_right_balance",
+        field_type: FieldDataType::LeftRightBalance,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 31,
+        name: "gps_accuracy",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 32,
+        name: "vertical_speed",
+        field_type: FieldDataType::SInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 33,
+        name: "calories",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kcal",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 39,
+        name: "vertical_oscillation",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "mm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 40,
+        name: "stance_time_percent",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 41,
+        name: "stance_time",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 42,
+        name: "activity_type",
+        field_type: FieldDataType::ActivityType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 43,
+        name: "left_torque_effectiveness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 44,
+        name: "right_torque_effectiveness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 45,
+        name: "left_pedal_smoothness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 46,
+        name: "right_pedal_smoothness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 47,
+        name: "combined_pedal_smoothness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 48,
+        name: "time128",
+        field_type: FieldDataType::UInt8,
+        scale: 128.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 49,
+        name: "stroke_type",
+        field_type: FieldDataType::StrokeType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 50,
+        name: "zone",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 51,
+        name: "ball_speed",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 52,
+        name: "cadence256",
+        field_type: FieldDataType::UInt16,
+        scale: 256.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 53,
+        name: "fractional_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 128.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 54,
+        name: "total_hemoglobin_conc",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "g/dL",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 55,
+        name: "total_hemoglobin_conc_min",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "g/dL",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 56,
+        name: "total_hemoglobin_conc_max",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "g/dL",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 57,
+        name: "saturated_hemoglobin_percent",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 58,
+        name: "saturated_hemoglobin_percent_min",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 59,
+        name: "saturated_hemoglobin_percent_max",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 62,
+        name: "device_index",
+        field_type: FieldDataType::DeviceIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 67,
+        name: "left_pco",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 68,
+        name: "right_pco",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 69,
+        name: "left_power_phase",
+        field_type: FieldDataType::UInt8,
+        scale: 0.711111,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 70,
+        name: "left_power_phase_peak",
+        field_type: FieldDataType::UInt8,
+        scale: 0.711111,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 71,
+        name: "right_power_phase",
+        field_type: FieldDataType::UInt8,
+        scale: 0.711111,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 72,
+        name: "right_power_phase_peak",
+        field_type: FieldDataType::UInt8,
+        scale: 0.711111,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 73,
+        name: "enhanced_speed",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 78,
+        name: "enhanced_altitude",
+        field_type: FieldDataType::UInt32,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 81,
+        name: "battery_soc",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 82,
+        name: "motor_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 83,
+        name: "vertical_ratio",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 84,
+        name: "stance_time_balance",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 85,
+        name: "step_length",
+        field_type: FieldDataType::UInt16,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "mm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 91,
+        name: "absolute_pressure",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "Pa",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 92,
+        name: "depth",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 93,
+        name: "next_stop_depth",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 94,
+        name: "next_stop_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 95,
+        name: "time_to_surface",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 96,
+        name: "ndl_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 97,
+        name: "cns_load",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 98,
+        name: "n2_load",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 99,
+        name: "respiration_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 108,
+        name: "enhanced_respiration_rate",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "Breaths/min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 114,
+        name: "grit",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 115,
+        name: "flow",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 117,
+        name: "ebike_travel_range",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "km",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 118,
+        name: "ebike_battery_level",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 119,
+        name: "ebike_assist_mode",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "depends on sensor",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 120,
+        name: "ebike_assist_level_percent",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 123,
+        name: "air_time_remaining",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 124,
+        name: "pressure_sac",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "bar/min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 125,
+        name: "volume_sac",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "L/min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 126,
+        name: "rmv",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "L/min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 127,
+        name: "ascent_rate",
+        field_type: FieldDataType::SInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 129,
+        name: "po2",
+        field_type: FieldDataType::UInt8,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 139,
+        name: "core_temperature",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const EVENT_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "event",
+        field_type: FieldDataType::Event,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "event_type",
+        field_type: FieldDataType::EventType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "data16",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "data",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "timer_trigger",
+        field_type: FieldDataType::TimerTrigger,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "course_point_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "battery_level",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "V",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "virtual_partner_speed",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "hr_high_alert",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "hr_low_alert",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "speed_high_alert",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "speed_low_alert",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "cad_high_alert",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "cad_low_alert",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "power_high_alert",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "power_low_alert",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "time_duration_alert",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "distance_duration_alert",
+        field_type: FieldDataType::UInt32,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "calorie_duration_alert",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "calories",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "fitness_equipment_state",
+        field_type: FieldDataType::FitnessEquipmentState,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "sport_point",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "gear_change_data",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "rider_position",
+        field_type: FieldDataType::RiderPositionType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "comm_timeout",
+        field_type: FieldDataType::CommTimeoutType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "dive_alert",
+        field_type: FieldDataType::DiveAlert,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "radar_threat_alert",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: ",,m/s,m/s",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "event_group",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "score",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "opponent_score",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "front_gear_num",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "front_gear",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "rear_gear_num",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 12,
+        name: "rear_gear",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 13,
+        name: "device_index",
+        field_type: FieldDataType::DeviceIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 21,
+        name: "radar_threat_level_max",
+        field_type: FieldDataType::RadarThreatLevelType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 22,
+        name: "radar_threat_count",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 23,
+        name: "radar_threat_avg_approach_speed",
+        field_type: FieldDataType::UInt8,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 24,
+        name: "radar_threat_max_approach_speed",
+        field_type: FieldDataType::UInt8,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const DEVICE_INFO_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "device_index",
+        field_type: FieldDataType::DeviceIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "device_type",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "ble_device_type",
+        field_type: FieldDataType::BleDeviceType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "antplus_device_type",
+        field_type: FieldDataType::AntplusDeviceType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "ant_device_type",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "local_device_type",
+        field_type: FieldDataType::LocalDeviceType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "manufacturer",
+        field_type: FieldDataType::Manufacturer,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "serial_number",
+        field_type: FieldDataType::UInt32z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "product",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "favero_product",
+        field_type: FieldDataType::FaveroProduct,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "garmin_product",
+        field_type: FieldDataType::GarminProduct,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "software_version",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "hardware_version",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "cum_operating_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "battery_voltage",
+        field_type: FieldDataType::UInt16,
+        scale: 256.000000,
+        offset: 0.000000,
+        units: "V",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "battery_status",
+        field_type: FieldDataType::BatteryStatus,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 18,
+        name: "sensor_position",
+        field_type: FieldDataType::BodyLocation,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 19,
+        name: "descriptor",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 20,
+        name: "ant_transmission_type",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 21,
+        name: "ant_device_number",
+        field_type: FieldDataType::UInt16z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 22,
+        name: "ant_network",
+        field_type: FieldDataType::AntNetwork,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 25,
+        name: "source_type",
+        field_type: FieldDataType::SourceType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 27,
+        name: "product_name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 32,
+        name: "battery_level",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const DEVICE_AUX_BATTERY_INFO_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "device_index",
+        field_type: FieldDataType::DeviceIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "battery_voltage",
+        field_type: FieldDataType::UInt16,
+        scale: 256.000000,
+        offset: 0.000000,
+        units: "V",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "battery_status",
+        field_type: FieldDataType::BatteryStatus,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "battery_identifier",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+/// Corresponds to file_id of workout or course.
+const TRAINING_FILE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "type",
+        field_type: FieldDataType::File,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "manufacturer",
+        field_type: FieldDataType::Manufacturer,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "product",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "favero_product",
+        field_type: FieldDataType::FaveroProduct,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "garmin_product",
+        field_type: FieldDataType::GarminProduct,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "serial_number",
+        field_type: FieldDataType::UInt32z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "time_created",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const WEATHER_CONDITIONS_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "weather_report",
+        field_type: FieldDataType::WeatherReport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "temperature",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "condition",
+        field_type: FieldDataType::WeatherStatus,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "wind_direction",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "wind_speed",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "precipitation_probability",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "temperature_feels_like",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "relative_humidity",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "location",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "observed_at_time",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "observed_location_lat",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "observed_location_long",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 12,
+        name: "day_of_week",
+        field_type: FieldDataType::DayOfWeek,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 13,
+        name: "high_temperature",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 14,
+        name: "low_temperature",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const WEATHER_ALERT_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "report_id",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "issue_time",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "expire_time",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "severity",
+        field_type: FieldDataType::WeatherSeverity,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "type",
+        field_type: FieldDataType::WeatherSevereType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const GPS_METADATA_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "timestamp_ms",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "position_lat",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "position_long",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "enhanced_altitude",
+        field_type: FieldDataType::UInt32,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "enhanced_speed",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "heading",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "utc_timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "velocity",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const CAMERA_EVENT_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "timestamp_ms",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "camera_event_type",
+        field_type: FieldDataType::CameraEventType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "camera_file_uuid",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "camera_orientation",
+        field_type: FieldDataType::CameraOrientationType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const GYROSCOPE_DATA_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "timestamp_ms",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "sample_time_offset",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "gyro_x",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "gyro_y",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "gyro_z",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "calibrated_gyro_x",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "deg/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "calibrated_gyro_y",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "deg/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "calibrated_gyro_z",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "deg/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const ACCELEROMETER_DATA_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "timestamp_ms",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "sample_time_offset",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "accel_x",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "accel_y",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "accel_z",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "calibrated_accel_x",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "g",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "calibrated_accel_y",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "g",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "calibrated_accel_z",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "g",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "compressed_calibrated_accel_x",
+        field_type: FieldDataType::SInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mG",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "compressed_calibrated_accel_y",
+        field_type: FieldDataType::SInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mG",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "compressed_calibrated_accel_z",
+        field_type: FieldDataType::SInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mG",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const MAGNETOMETER_DATA_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "timestamp_ms",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "sample_time_offset",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "mag_x",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "mag_y",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "mag_z",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "calibrated_mag_x",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "G",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "calibrated_mag_y",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "G",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "calibrated_mag_z",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "G",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const BAROMETER_DATA_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "timestamp_ms",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "sample_time_offset",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "baro_pres",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "Pa",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const THREE_D_SENSOR_CALIBRATION_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "sensor_type",
+        field_type: FieldDataType::SensorType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "calibration_factor",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "accel_cal_factor",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "g",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "gyro_cal_factor",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "deg/s",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "calibration_divisor",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "level_shift",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "offset_cal",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "orientation_matrix",
+        field_type: FieldDataType::SInt32,
+        scale: 65535.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const ONE_D_SENSOR_CALIBRATION_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "sensor_type",
+        field_type: FieldDataType::SensorType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "calibration_factor",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "baro_cal_factor",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "Pa",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "calibration_divisor",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "counts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "level_shift",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "offset_cal",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const VIDEO_FRAME_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "timestamp_ms",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "frame_number",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const OBDII_DATA_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "timestamp_ms",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "time_offset",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "pid",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "raw_data",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "pid_data_size",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "system_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "start_timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "start_timestamp_ms",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const NMEA_SENTENCE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "timestamp_ms",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "sentence",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const AVIATION_ATTITUDE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "timestamp_ms",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "system_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "pitch",
+        field_type: FieldDataType::SInt16,
+        scale: 10430.380000,
+        offset: 0.000000,
+        units: "radians",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "roll",
+        field_type: FieldDataType::SInt16,
+        scale: 10430.380000,
+        offset: 0.000000,
+        units: "radians",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "accel_lateral",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m/s^2",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "accel_normal",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m/s^2",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "turn_rate",
+        field_type: FieldDataType::SInt16,
+        scale: 1024.000000,
+        offset: 0.000000,
+        units: "radians/second",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "stage",
+        field_type: FieldDataType::AttitudeStage,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "attitude_stage_complete",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "track",
+        field_type: FieldDataType::UInt16,
+        scale: 10430.380000,
+        offset: 0.000000,
+        units: "radians",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "validity",
+        field_type: FieldDataType::AttitudeValidity,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const VIDEO_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "url",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "hosting_provider",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "duration",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const VIDEO_TITLE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "message_count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "text",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const VIDEO_DESCRIPTION_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "message_count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "text",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const VIDEO_CLIP_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "clip_number",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "start_timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "start_timestamp_ms",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "end_timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "end_timestamp_ms",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "clip_start",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "clip_end",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "ms",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const SET_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "duration",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "repetitions",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "weight",
+        field_type: FieldDataType::UInt16,
+        scale: 16.000000,
+        offset: 0.000000,
+        units: "kg",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "set_type",
+        field_type: FieldDataType::SetType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "start_time",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "category",
+        field_type: FieldDataType::ExerciseCategory,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "category_subtype",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "weight_display_unit",
+        field_type: FieldDataType::FitBaseUnit,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "wkt_step_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const JUMP_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "distance",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "height",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "rotations",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "hang_time",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "score",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "position_lat",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "position_long",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "speed",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "enhanced_speed",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const SPLIT_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "split_type",
+        field_type: FieldDataType::SplitType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "total_elapsed_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "total_timer_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "total_distance",
+        field_type: FieldDataType::UInt32,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "start_time",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const CLIMB_PRO_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "position_lat",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "position_long",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "climb_pro_event",
+        field_type: FieldDataType::ClimbProEvent,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "climb_number",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "climb_category",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "current_dist",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+/// Must be logged before developer field is used
+const FIELD_DESCRIPTION_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "developer_data_index",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "field_definition_number",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "fit_base_type_id",
+        field_type: FieldDataType::FitBaseType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "field_name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "array",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "components",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "scale",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "offset",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "units",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "bits",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "accumulate",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 13,
+        name: "fit_base_unit_id",
+        field_type: FieldDataType::FitBaseUnit,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 14,
+        name: "native_mesg_num",
+        field_type: FieldDataType::MesgNum,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 15,
+        name: "native_field_num",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+/// Must be logged before field description
+const DEVELOPER_DATA_ID_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "developer_id",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "application_id",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "manufacturer_id",
+        field_type: FieldDataType::Manufacturer,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "developer_data_index",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "application_version",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const COURSE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 4,
+        name: "sport",
+        field_type: FieldDataType::Sport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "capabilities",
+        field_type: FieldDataType::CourseCapabilities,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "sub_sport",
+        field_type: FieldDataType::SubSport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const COURSE_POINT_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 1,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "position_lat",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "position_long",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "distance",
+        field_type: FieldDataType::UInt32,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "type",
+        field_type: FieldDataType::CoursePoint,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "favorite",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+/// Unique Identification data for a segment file
+const SEGMENT_ID_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "uuid",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "sport",
+        field_type: FieldDataType::Sport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "user_profile_primary_key",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "device_id",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "default_race_leader",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "delete_status",
+        field_type: FieldDataType::SegmentDeleteStatus,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "selection_type",
+        field_type: FieldDataType::SegmentSelectionType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+/// Unique Identification data for an individual segment leader within a segment file
+const SEGMENT_LEADERBOARD_ENTRY_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "type",
+        field_type: FieldDataType::SegmentLeaderboardType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "group_primary_key",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "activity_id",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "segment_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "activity_id_string",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+/// Navigation and race evaluation point for a segment decribing a point along the segment path and time it took each segment leader to reach that point
+const SEGMENT_POINT_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 1,
+        name: "position_lat",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "position_long",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "distance",
+        field_type: FieldDataType::UInt32,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "altitude",
+        field_type: FieldDataType::UInt16,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "leader_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "enhanced_altitude",
+        field_type: FieldDataType::UInt32,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const SEGMENT_LAP_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "event",
+        field_type: FieldDataType::Event,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "event_type",
+        field_type: FieldDataType::EventType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "start_time",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "start_position_lat",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "start_position_long",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "end_position_lat",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "end_position_long",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "total_elapsed_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "total_timer_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "total_distance",
+        field_type: FieldDataType::UInt32,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "total_cycles",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "cycles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "total_strokes",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "strokes",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "total_calories",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kcal",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 12,
+        name: "total_fat_calories",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kcal",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 13,
+        name: "avg_speed",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 14,
+        name: "max_speed",
+        field_type: FieldDataType::UInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 15,
+        name: "avg_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 16,
+        name: "max_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 17,
+        name: "avg_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 18,
+        name: "max_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 19,
+        name: "avg_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 20,
+        name: "max_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 21,
+        name: "total_ascent",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 22,
+        name: "total_descent",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 23,
+        name: "sport",
+        field_type: FieldDataType::Sport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 24,
+        name: "event_group",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 25,
+        name: "nec_lat",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 26,
+        name: "nec_long",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 27,
+        name: "swc_lat",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 28,
+        name: "swc_long",
+        field_type: FieldDataType::SInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "semicircles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 29,
+        name: "name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 30,
+        name: "normalized_power",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 31,
+        name: "left_right_balance",
+        field_type: FieldDataType::LeftRightBalance100,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 32,
+        name: "sub_sport",
+        field_type: FieldDataType::SubSport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 33,
+        name: "total_work",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "J",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 34,
+        name: "avg_altitude",
+        field_type: FieldDataType::UInt16,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 35,
+        name: "max_altitude",
+        field_type: FieldDataType::UInt16,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 36,
+        name: "gps_accuracy",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 37,
+        name: "avg_grade",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 38,
+        name: "avg_pos_grade",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 39,
+        name: "avg_neg_grade",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 40,
+        name: "max_pos_grade",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 41,
+        name: "max_neg_grade",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 42,
+        name: "avg_temperature",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 43,
+        name: "max_temperature",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 44,
+        name: "total_moving_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 45,
+        name: "avg_pos_vertical_speed",
+        field_type: FieldDataType::SInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 46,
+        name: "avg_neg_vertical_speed",
+        field_type: FieldDataType::SInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 47,
+        name: "max_pos_vertical_speed",
+        field_type: FieldDataType::SInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 48,
+        name: "max_neg_vertical_speed",
+        field_type: FieldDataType::SInt16,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 49,
+        name: "time_in_hr_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 50,
+        name: "time_in_speed_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 51,
+        name: "time_in_cadence_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 52,
+        name: "time_in_power_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 53,
+        name: "repetition_num",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 54,
+        name: "min_altitude",
+        field_type: FieldDataType::UInt16,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 55,
+        name: "min_heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 56,
+        name: "active_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 57,
+        name: "wkt_step_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 58,
+        name: "sport_event",
+        field_type: FieldDataType::SportEvent,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 59,
+        name: "avg_left_torque_effectiveness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 60,
+        name: "avg_right_torque_effectiveness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 61,
+        name: "avg_left_pedal_smoothness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 62,
+        name: "avg_right_pedal_smoothness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 63,
+        name: "avg_combined_pedal_smoothness",
+        field_type: FieldDataType::UInt8,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 64,
+        name: "status",
+        field_type: FieldDataType::SegmentLapStatus,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 65,
+        name: "uuid",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 66,
+        name: "avg_fractional_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 128.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 67,
+        name: "max_fractional_cadence",
+        field_type: FieldDataType::UInt8,
+        scale: 128.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 68,
+        name: "total_fractional_cycles",
+        field_type: FieldDataType::UInt8,
+        scale: 128.000000,
+        offset: 0.000000,
+        units: "cycles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 69,
+        name: "front_gear_shift_count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 70,
+        name: "rear_gear_shift_count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 71,
+        name: "time_standing",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 72,
+        name: "stand_count",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 73,
+        name: "avg_left_pco",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 74,
+        name: "avg_right_pco",
+        field_type: FieldDataType::SInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 75,
+        name: "avg_left_power_phase",
+        field_type: FieldDataType::UInt8,
+        scale: 0.711111,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 76,
+        name: "avg_left_power_phase_peak",
+        field_type: FieldDataType::UInt8,
+        scale: 0.711111,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 77,
+        name: "avg_right_power_phase",
+        field_type: FieldDataType::UInt8,
+        scale: 0.711111,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 78,
+        name: "avg_right_power_phase_peak",
+        field_type: FieldDataType::UInt8,
+        scale: 0.711111,
+        offset: 0.000000,
+        units: "degrees",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 79,
+        name: "avg_power_position",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 80,
+        name: "max_power_position",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "watts",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 81,
+        name: "avg_cadence_position",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 82,
+        name: "max_cadence_position",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 83,
+        name: "manufacturer",
+        field_type: FieldDataType::Manufacturer,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 84,
+        name: "total_grit",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kGrit",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 85,
+        name: "total_flow",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "Flow",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 86,
+        name: "avg_grit",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kGrit",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 87,
+        name: "avg_flow",
+        field_type: FieldDataType::Float32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "Flow",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 89,
+        name: "total_fractional_ascent",
+        field_type: FieldDataType::UInt8,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 90,
+        name: "total_fractional_descent",
+        field_type: FieldDataType::UInt8,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 91,
+        name: "enhanced_avg_altitude",
+        field_type: FieldDataType::UInt32,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 92,
+        name: "enhanced_max_altitude",
+        field_type: FieldDataType::UInt32,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 93,
+        name: "enhanced_min_altitude",
+        field_type: FieldDataType::UInt32,
+        scale: 5.000000,
+        offset: 500.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+/// Summary of the unique segment and leaderboard information associated with a segment file. This message is used to compile a segment list file describing all segment files on a device. The segment list file is used when refreshing the contents of a segment file with the latest available leaderboard information.
+const SEGMENT_FILE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 1,
+        name: "file_uuid",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "user_profile_primary_key",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "leader_type",
+        field_type: FieldDataType::SegmentLeaderboardType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "leader_group_primary_key",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "leader_activity_id",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "leader_activity_id_string",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "default_race_leader",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const WORKOUT_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 4,
+        name: "sport",
+        field_type: FieldDataType::Sport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "capabilities",
+        field_type: FieldDataType::WorkoutCapabilities,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "num_valid_steps",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "wkt_name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "sub_sport",
+        field_type: FieldDataType::SubSport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 14,
+        name: "pool_length",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 15,
+        name: "pool_length_unit",
+        field_type: FieldDataType::DisplayMeasure,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const WORKOUT_SESSION_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "sport",
+        field_type: FieldDataType::Sport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "sub_sport",
+        field_type: FieldDataType::SubSport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "num_valid_steps",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "first_step_index",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "pool_length",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "pool_length_unit",
+        field_type: FieldDataType::DisplayMeasure,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const WORKOUT_STEP_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "wkt_step_name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "duration_type",
+        field_type: FieldDataType::WktStepDuration,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "duration_value",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "duration_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "duration_distance",
+        field_type: FieldDataType::UInt32,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "duration_hr",
+        field_type: FieldDataType::WorkoutHr,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "% or bpm",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "duration_calories",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "calories",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "duration_step",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "duration_power",
+        field_type: FieldDataType::WorkoutPower,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "% or watts",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "duration_reps",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "target_type",
+        field_type: FieldDataType::WktStepTarget,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "target_value",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "target_speed_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "target_hr_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "target_cadence_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "target_power_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "repeat_steps",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "repeat_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "repeat_distance",
+        field_type: FieldDataType::UInt32,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "repeat_calories",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "calories",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "repeat_hr",
+        field_type: FieldDataType::WorkoutHr,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "% or bpm",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "repeat_power",
+        field_type: FieldDataType::WorkoutPower,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "% or watts",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "target_stroke_type",
+        field_type: FieldDataType::SwimStroke,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "custom_target_value_low",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "custom_target_speed_low",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "custom_target_heart_rate_low",
+        field_type: FieldDataType::WorkoutHr,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "% or bpm",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "custom_target_cadence_low",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "custom_target_power_low",
+        field_type: FieldDataType::WorkoutPower,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "% or watts",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "custom_target_value_high",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "custom_target_speed_high",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "custom_target_heart_rate_high",
+        field_type: FieldDataType::WorkoutHr,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "% or bpm",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "custom_target_cadence_high",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "custom_target_power_high",
+        field_type: FieldDataType::WorkoutPower,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "% or watts",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "intensity",
+        field_type: FieldDataType::Intensity,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "notes",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "equipment",
+        field_type: FieldDataType::WorkoutEquipment,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "exercise_category",
+        field_type: FieldDataType::ExerciseCategory,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "exercise_name",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 12,
+        name: "exercise_weight",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "kg",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 13,
+        name: "weight_display_unit",
+        field_type: FieldDataType::FitBaseUnit,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 19,
+        name: "secondary_target_type",
+        field_type: FieldDataType::WktStepTarget,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 20,
+        name: "secondary_target_value",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 20,
+        name: "secondary_target_speed_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 20,
+        name: "secondary_target_hr_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 20,
+        name: "secondary_target_cadence_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 20,
+        name: "secondary_target_power_zone",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 20,
+        name: "secondary_target_stroke_type",
+        field_type: FieldDataType::SwimStroke,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 21,
+        name: "secondary_custom_target_value_low",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 21,
+        name: "secondary_custom_target_speed_low",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 21,
+        name: "secondary_custom_target_heart_rate_low",
+        field_type: FieldDataType::WorkoutHr,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "% or bpm",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 21,
+        name: "secondary_custom_target_cadence_low",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 21,
+        name: "secondary_custom_target_power_low",
+        field_type: FieldDataType::WorkoutPower,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "% or watts",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 22,
+        name: "secondary_custom_target_value_high",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 22,
+        name: "secondary_custom_target_speed_high",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 22,
+        name: "secondary_custom_target_heart_rate_high",
+        field_type: FieldDataType::WorkoutHr,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "% or bpm",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 22,
+        name: "secondary_custom_target_cadence_high",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "rpm",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 22,
+        name: "secondary_custom_target_power_high",
+        field_type: FieldDataType::WorkoutPower,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "% or watts",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const EXERCISE_TITLE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "exercise_category",
+        field_type: FieldDataType::ExerciseCategory,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "exercise_name",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "wkt_step_name",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const SCHEDULE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "manufacturer",
+        field_type: FieldDataType::Manufacturer,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "product",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "favero_product",
+        field_type: FieldDataType::FaveroProduct,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "garmin_product",
+        field_type: FieldDataType::GarminProduct,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "serial_number",
+        field_type: FieldDataType::UInt32z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "time_created",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "completed",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "type",
+        field_type: FieldDataType::Schedule,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "scheduled_time",
+        field_type: FieldDataType::LocalDateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const TOTALS_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "timer_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "distance",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "calories",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kcal",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "sport",
+        field_type: FieldDataType::Sport,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "elapsed_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "sessions",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "active_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "sport_index",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 254,
+        name: "message_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const WEIGHT_SCALE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "weight",
+        field_type: FieldDataType::Weight,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "kg",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "percent_fat",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "percent_hydration",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "%",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "visceral_fat_mass",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "kg",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "bone_mass",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "kg",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "muscle_mass",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "kg",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "basal_met",
+        field_type: FieldDataType::UInt16,
+        scale: 4.000000,
+        offset: 0.000000,
+        units: "kcal/day",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "physique_rating",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "active_met",
+        field_type: FieldDataType::UInt16,
+        scale: 4.000000,
+        offset: 0.000000,
+        units: "kcal/day",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "metabolic_age",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "years",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "visceral_fat_rating",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 12,
+        name: "user_profile_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const BLOOD_PRESSURE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "systolic_pressure",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mmHg",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "diastolic_pressure",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mmHg",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "mean_arterial_pressure",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mmHg",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "map_3_sample_mean",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mmHg",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "map_morning_values",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mmHg",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "map_evening_values",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "mmHg",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "heart_rate_type",
+        field_type: FieldDataType::HrType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "status",
+        field_type: FieldDataType::BpStatus,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "user_profile_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const MONITORING_INFO_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "local_timestamp",
+        field_type: FieldDataType::LocalDateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "activity_type",
+        field_type: FieldDataType::ActivityType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "cycles_to_distance",
+        field_type: FieldDataType::UInt16,
+        scale: 5000.000000,
+        offset: 0.000000,
+        units: "m/cycle",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "cycles_to_calories",
+        field_type: FieldDataType::UInt16,
+        scale: 5000.000000,
+        offset: 0.000000,
+        units: "kcal/cycle",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "resting_metabolic_rate",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kcal / day",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const MONITORING_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "device_index",
+        field_type: FieldDataType::DeviceIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "calories",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kcal",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "distance",
+        field_type: FieldDataType::UInt32,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "cycles",
+        field_type: FieldDataType::UInt32,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "cycles",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "steps",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "steps",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "strokes",
+        field_type: FieldDataType::UInt32,
+        scale: 2.000000,
+        offset: 0.000000,
+        units: "strokes",
+        accumulate: false,
+        is_subfield: true,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "active_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "activity_type",
+        field_type: FieldDataType::ActivityType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "activity_subtype",
+        field_type: FieldDataType::ActivitySubtype,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "activity_level",
+        field_type: FieldDataType::ActivityLevel,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "distance_16",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "100 * m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "cycles_16",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "2 * cycles (steps)",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "active_time_16",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "local_timestamp",
+        field_type: FieldDataType::LocalDateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 12,
+        name: "temperature",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 14,
+        name: "temperature_min",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 15,
+        name: "temperature_max",
+        field_type: FieldDataType::SInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "C",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 16,
+        name: "activity_time",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "minutes",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 19,
+        name: "active_calories",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "kcal",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 24,
+        name: "current_activity_type_intensity",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 25,
+        name: "timestamp_min_8",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 26,
+        name: "timestamp_16",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 27,
+        name: "heart_rate",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 28,
+        name: "intensity",
+        field_type: FieldDataType::UInt8,
+        scale: 10.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 29,
+        name: "duration_min",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 30,
+        name: "duration",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 31,
+        name: "ascent",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 32,
+        name: "descent",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 33,
+        name: "moderate_activity_minutes",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "minutes",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 34,
+        name: "vigorous_activity_minutes",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "minutes",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const HR_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "fractional_timestamp",
+        field_type: FieldDataType::UInt16,
+        scale: 32768.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "time256",
+        field_type: FieldDataType::UInt8,
+        scale: 256.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "filtered_bpm",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "bpm",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "event_timestamp",
+        field_type: FieldDataType::UInt32,
+        scale: 1024.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "event_timestamp_12",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+/// Value from 1 to 100 calculated by FirstBeat
+const STRESS_LEVEL_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "stress_level_value",
+        field_type: FieldDataType::SInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "stress_level_time",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const MEMO_GLOB_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "memo",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "mesg_num",
+        field_type: FieldDataType::MesgNum,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "parent_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "field_num",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "data",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 250,
+        name: "part_index",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const ANT_CHANNEL_ID_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "channel_number",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "device_type",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "device_number",
+        field_type: FieldDataType::UInt16z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "transmission_type",
+        field_type: FieldDataType::UInt8z,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "device_index",
+        field_type: FieldDataType::DeviceIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const ANT_RX_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "fractional_timestamp",
+        field_type: FieldDataType::UInt16,
+        scale: 32768.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "mesg_id",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "mesg_data",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "channel_number",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "data",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const ANT_TX_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "fractional_timestamp",
+        field_type: FieldDataType::UInt16,
+        scale: 32768.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "mesg_id",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "mesg_data",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "channel_number",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "data",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const EXD_SCREEN_CONFIGURATION_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "screen_index",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "field_count",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "layout",
+        field_type: FieldDataType::ExdLayout,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "screen_enabled",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const EXD_DATA_FIELD_CONFIGURATION_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "screen_index",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "concept_field",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "field_id",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "concept_count",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "display_type",
+        field_type: FieldDataType::ExdDisplayType,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "title",
+        field_type: FieldDataType::String,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const EXD_DATA_CONCEPT_CONFIGURATION_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "screen_index",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "concept_field",
+        field_type: FieldDataType::Byte,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "field_id",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "concept_index",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "data_page",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "concept_key",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "scaling",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "data_units",
+        field_type: FieldDataType::ExdDataUnits,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "qualifier",
+        field_type: FieldDataType::ExdQualifiers,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "descriptor",
+        field_type: FieldDataType::ExdDescriptors,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "is_signed",
+        field_type: FieldDataType::Bool,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const DIVE_SUMMARY_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "reference_mesg",
+        field_type: FieldDataType::MesgNum,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "reference_index",
+        field_type: FieldDataType::MessageIndex,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "avg_depth",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "max_depth",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 4,
+        name: "surface_interval",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 5,
+        name: "start_cns",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 6,
+        name: "end_cns",
+        field_type: FieldDataType::UInt8,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 7,
+        name: "start_n2",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 8,
+        name: "end_n2",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "percent",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 9,
+        name: "o2_toxicity",
+        field_type: FieldDataType::UInt16,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "OTUs",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 10,
+        name: "dive_number",
+        field_type: FieldDataType::UInt32,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 11,
+        name: "bottom_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 12,
+        name: "avg_pressure_sac",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "bar/min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 13,
+        name: "avg_volume_sac",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "L/min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 14,
+        name: "avg_rmv",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "L/min",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 15,
+        name: "descent_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 16,
+        name: "ascent_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 17,
+        name: "avg_ascent_rate",
+        field_type: FieldDataType::SInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 22,
+        name: "avg_descent_rate",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 23,
+        name: "max_ascent_rate",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 24,
+        name: "max_descent_rate",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "m/s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 25,
+        name: "hang_time",
+        field_type: FieldDataType::UInt32,
+        scale: 1000.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+/// Heart rate variability
+const HRV_FIELDS: &[FieldInfo] = &[FieldInfo {
+    def_number: 0,
+    name: "time",
+    field_type: FieldDataType::UInt16,
+    scale: 1000.000000,
+    offset: 0.000000,
+    units: "s",
+    accumulate: false,
+    is_subfield: false,
+}];
+const TANK_UPDATE_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "sensor",
+        field_type: FieldDataType::AntChannelId,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "pressure",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "bar",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+const TANK_SUMMARY_FIELDS: &[FieldInfo] = &[
+    FieldInfo {
+        def_number: 0,
+        name: "sensor",
+        field_type: FieldDataType::AntChannelId,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 1,
+        name: "start_pressure",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "bar",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 2,
+        name: "end_pressure",
+        field_type: FieldDataType::UInt16,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "bar",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 3,
+        name: "volume_used",
+        field_type: FieldDataType::UInt32,
+        scale: 100.000000,
+        offset: 0.000000,
+        units: "L",
+        accumulate: false,
+        is_subfield: false,
+    },
+    FieldInfo {
+        def_number: 253,
+        name: "timestamp",
+        field_type: FieldDataType::DateTime,
+        scale: 1.000000,
+        offset: 0.000000,
+        units: "s",
+        accumulate: false,
+        is_subfield: false,
+    },
+];
+impl MesgNum {
+    /// Return the profile definition of every field in the message, subfields follow their parent field
+    pub fn field_info(self) -> &'static [FieldInfo] {
+        match self {
+            MesgNum::FileId => FILE_ID_FIELDS,
+            MesgNum::FileCreator => FILE_CREATOR_FIELDS,
+            MesgNum::TimestampCorrelation => TIMESTAMP_CORRELATION_FIELDS,
+            MesgNum::Software => SOFTWARE_FIELDS,
+            MesgNum::SlaveDevice => SLAVE_DEVICE_FIELDS,
+            MesgNum::Capabilities => CAPABILITIES_FIELDS,
+            MesgNum::FileCapabilities => FILE_CAPABILITIES_FIELDS,
+            MesgNum::MesgCapabilities => MESG_CAPABILITIES_FIELDS,
+            MesgNum::FieldCapabilities => FIELD_CAPABILITIES_FIELDS,
+            MesgNum::DeviceSettings => DEVICE_SETTINGS_FIELDS,
+            MesgNum::UserProfile => USER_PROFILE_FIELDS,
+            MesgNum::HrmProfile => HRM_PROFILE_FIELDS,
+            MesgNum::SdmProfile => SDM_PROFILE_FIELDS,
+            MesgNum::BikeProfile => BIKE_PROFILE_FIELDS,
+            MesgNum::Connectivity => CONNECTIVITY_FIELDS,
+            MesgNum::WatchfaceSettings => WATCHFACE_SETTINGS_FIELDS,
+            MesgNum::OhrSettings => OHR_SETTINGS_FIELDS,
+            MesgNum::TimeInZone => TIME_IN_ZONE_FIELDS,
+            MesgNum::ZonesTarget => ZONES_TARGET_FIELDS,
+            MesgNum::Sport => SPORT_FIELDS,
+            MesgNum::HrZone => HR_ZONE_FIELDS,
+            MesgNum::SpeedZone => SPEED_ZONE_FIELDS,
+            MesgNum::CadenceZone => CADENCE_ZONE_FIELDS,
+            MesgNum::PowerZone => POWER_ZONE_FIELDS,
+            MesgNum::MetZone => MET_ZONE_FIELDS,
+            MesgNum::DiveSettings => DIVE_SETTINGS_FIELDS,
+            MesgNum::DiveAlarm => DIVE_ALARM_FIELDS,
+            MesgNum::DiveApneaAlarm => DIVE_APNEA_ALARM_FIELDS,
+            MesgNum::DiveGas => DIVE_GAS_FIELDS,
+            MesgNum::Goal => GOAL_FIELDS,
+            MesgNum::Activity => ACTIVITY_FIELDS,
+            MesgNum::Session => SESSION_FIELDS,
+            MesgNum::Lap => LAP_FIELDS,
+            MesgNum::Length => LENGTH_FIELDS,
+            MesgNum::Record => RECORD_FIELDS,
+            MesgNum::Event => EVENT_FIELDS,
+            MesgNum::DeviceInfo => DEVICE_INFO_FIELDS,
+            MesgNum::DeviceAuxBatteryInfo => DEVICE_AUX_BATTERY_INFO_FIELDS,
+            MesgNum::TrainingFile => TRAINING_FILE_FIELDS,
+            MesgNum::WeatherConditions => WEATHER_CONDITIONS_FIELDS,
+            MesgNum::WeatherAlert => WEATHER_ALERT_FIELDS,
+            MesgNum::GpsMetadata => GPS_METADATA_FIELDS,
+            MesgNum::CameraEvent => CAMERA_EVENT_FIELDS,
+            MesgNum::GyroscopeData => GYROSCOPE_DATA_FIELDS,
+            MesgNum::AccelerometerData => ACCELEROMETER_DATA_FIELDS,
+            MesgNum::MagnetometerData => MAGNETOMETER_DATA_FIELDS,
+            MesgNum::BarometerData => BAROMETER_DATA_FIELDS,
+            MesgNum::ThreeDSensorCalibration => THREE_D_SENSOR_CALIBRATION_FIELDS,
+            MesgNum::OneDSensorCalibration => ONE_D_SENSOR_CALIBRATION_FIELDS,
+            MesgNum::VideoFrame => VIDEO_FRAME_FIELDS,
+            MesgNum::ObdiiData => OBDII_DATA_FIELDS,
+            MesgNum::NmeaSentence => NMEA_SENTENCE_FIELDS,
+            MesgNum::AviationAttitude => AVIATION_ATTITUDE_FIELDS,
+            MesgNum::Video => VIDEO_FIELDS,
+            MesgNum::VideoTitle => VIDEO_TITLE_FIELDS,
+            MesgNum::VideoDescription => VIDEO_DESCRIPTION_FIELDS,
+            MesgNum::VideoClip => VIDEO_CLIP_FIELDS,
+            MesgNum::Set => SET_FIELDS,
+            MesgNum::Jump => JUMP_FIELDS,
+            MesgNum::Split => SPLIT_FIELDS,
+            MesgNum::ClimbPro => CLIMB_PRO_FIELDS,
+            MesgNum::FieldDescription => FIELD_DESCRIPTION_FIELDS,
+            MesgNum::DeveloperDataId => DEVELOPER_DATA_ID_FIELDS,
+            MesgNum::Course => COURSE_FIELDS,
+            MesgNum::CoursePoint => COURSE_POINT_FIELDS,
+            MesgNum::SegmentId => SEGMENT_ID_FIELDS,
+            MesgNum::SegmentLeaderboardEntry => SEGMENT_LEADERBOARD_ENTRY_FIELDS,
+            MesgNum::SegmentPoint => SEGMENT_POINT_FIELDS,
+            MesgNum::SegmentLap => SEGMENT_LAP_FIELDS,
+            MesgNum::SegmentFile => SEGMENT_FILE_FIELDS,
+            MesgNum::Workout => WORKOUT_FIELDS,
+            MesgNum::WorkoutSession => WORKOUT_SESSION_FIELDS,
+            MesgNum::WorkoutStep => WORKOUT_STEP_FIELDS,
+            MesgNum::ExerciseTitle => EXERCISE_TITLE_FIELDS,
+            MesgNum::Schedule => SCHEDULE_FIELDS,
+            MesgNum::Totals => TOTALS_FIELDS,
+            MesgNum::WeightScale => WEIGHT_SCALE_FIELDS,
+            MesgNum::BloodPressure => BLOOD_PRESSURE_FIELDS,
+            MesgNum::MonitoringInfo => MONITORING_INFO_FIELDS,
+            MesgNum::Monitoring => MONITORING_FIELDS,
+            MesgNum::Hr => HR_FIELDS,
+            MesgNum::StressLevel => STRESS_LEVEL_FIELDS,
+            MesgNum::MemoGlob => MEMO_GLOB_FIELDS,
+            MesgNum::AntChannelId => ANT_CHANNEL_ID_FIELDS,
+            MesgNum::AntRx => ANT_RX_FIELDS,
+            MesgNum::AntTx => ANT_TX_FIELDS,
+            MesgNum::ExdScreenConfiguration => EXD_SCREEN_CONFIGURATION_FIELDS,
+            MesgNum::ExdDataFieldConfiguration => EXD_DATA_FIELD_CONFIGURATION_FIELDS,
+            MesgNum::ExdDataConceptConfiguration => EXD_DATA_CONCEPT_CONFIGURATION_FIELDS,
+            MesgNum::DiveSummary => DIVE_SUMMARY_FIELDS,
+            MesgNum::Hrv => HRV_FIELDS,
+            MesgNum::TankUpdate => TANK_UPDATE_FIELDS,
+            MesgNum::TankSummary => TANK_SUMMARY_FIELDS,
+            _ => &[],
+        }
+    }
+}
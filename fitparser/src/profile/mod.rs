@@ -1,42 +1,154 @@
 //! Defines the FIT profile used to convert raw parser output into final values that can be
 //! interpreted without using the FIT profile.
+use crate::compat::prelude::*;
+use crate::compat::{HashMap, HashSet};
 use crate::de::DecodeOption;
-use crate::error::{ErrorKind, Result};
-use crate::{FitDataField, Value};
-use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone};
-use std::collections::{HashMap, HashSet};
-use std::convert::TryInto;
-use std::f64::EPSILON;
+use crate::error::{with_context, DecodeContext, ErrorKind, Result};
+use crate::{FitDataField, FitDataRecord, SharedStr, TimestampTz, Value};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone};
+use core::convert::{TryFrom, TryInto};
+use core::fmt;
+use core::str::FromStr;
 
 pub mod field_types;
-pub use field_types::{get_field_variant_as_string, FieldDataType, MesgNum};
+pub use field_types::{
+    get_field_variant_as_string, get_field_variant_from_string, FieldDataType, MesgNum,
+};
 
 pub mod decode;
 pub use decode::VERSION;
 
+mod custom;
+pub use custom::CustomProfile;
+
+mod messages;
+
+pub mod typed;
+
+/// Describes a single message field as defined in the FIT profile
+#[derive(Clone, Copy, Debug)]
+pub struct FieldInfo {
+    def_number: u8,
+    name: &'static str,
+    field_type: FieldDataType,
+    scale: f64,
+    offset: f64,
+    units: &'static str,
+    accumulate: bool,
+    is_subfield: bool,
+}
+
+impl FieldInfo {
+    /// Field definition number within the message
+    pub fn def_number(&self) -> u8 {
+        self.def_number
+    }
+
+    /// Name of the field
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Field type, used to determine the base type and any enum conversions
+    pub fn field_type(&self) -> FieldDataType {
+        self.field_type
+    }
+
+    /// Scale applied to the raw value
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Offset applied to the raw value
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    /// Units of the field value
+    pub fn units(&self) -> &'static str {
+        self.units
+    }
+
+    /// True if the value accumlates across messages
+    pub fn accumulate(&self) -> bool {
+        self.accumulate
+    }
+
+    /// True if the field is a subfield that shares a definition number with its parent
+    pub fn is_subfield(&self) -> bool {
+        self.is_subfield
+    }
+}
+
+impl MesgNum {
+    /// Find the profile definition of a field, the name is preferred when matching so subfields
+    /// can be resolved otherwise the main field definition is returned.
+    pub fn get_field_info(self, def_number: u8, name: &str) -> Option<&'static FieldInfo> {
+        let fields = self.field_info();
+        fields
+            .iter()
+            .find(|f| f.def_number == def_number && f.name == name)
+            .or_else(|| {
+                fields
+                    .iter()
+                    .find(|f| f.def_number == def_number && !f.is_subfield)
+            })
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for MesgNum {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        struct MesgNumVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for MesgNumVisitor {
+            type Value = MesgNum;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("a message name or number")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, val: u64) -> core::result::Result<MesgNum, E> {
+                u16::try_from(val)
+                    .map(MesgNum::from)
+                    .map_err(|_| E::custom(format!("invalid message number {}", val)))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, val: &str) -> core::result::Result<MesgNum, E> {
+                MesgNum::from_str(val).map_err(|_| E::custom(format!("unknown message {}", val)))
+            }
+        }
+
+        deserializer.deserialize_any(MesgNumVisitor)
+    }
+}
+
 impl Value {
-    /// Convert the value into a vector of bytes
-    fn to_ne_bytes(&self) -> Vec<u8> {
+    /// Convert the value into a vector of little endian bytes, components are packed starting
+    /// from the least significant bit of the field
+    fn to_le_bytes(&self) -> Vec<u8> {
         match self {
-            Value::Byte(val) => vec![*val as u8],
-            Value::Enum(val) => vec![*val as u8],
+            Value::Byte(val) => vec![*val],
+            Value::Enum(val) => vec![*val],
             Value::SInt8(val) => vec![*val as u8],
-            Value::UInt8(val) => vec![*val as u8],
-            Value::SInt16(val) => val.to_ne_bytes().to_vec(),
-            Value::UInt16(val) => val.to_ne_bytes().to_vec(),
-            Value::SInt32(val) => val.to_ne_bytes().to_vec(),
-            Value::UInt32(val) => val.to_ne_bytes().to_vec(),
+            Value::UInt8(val) => vec![*val],
+            Value::SInt16(val) => val.to_le_bytes().to_vec(),
+            Value::UInt16(val) => val.to_le_bytes().to_vec(),
+            Value::SInt32(val) => val.to_le_bytes().to_vec(),
+            Value::UInt32(val) => val.to_le_bytes().to_vec(),
             Value::String(val) => val.as_bytes().to_vec(),
-            Value::Timestamp(val) => val.timestamp().to_ne_bytes().to_vec(),
-            Value::Float32(val) => val.to_ne_bytes().to_vec(),
-            Value::Float64(val) => val.to_ne_bytes().to_vec(),
-            Value::UInt8z(val) => vec![*val as u8],
-            Value::UInt16z(val) => val.to_ne_bytes().to_vec(),
-            Value::UInt32z(val) => val.to_ne_bytes().to_vec(),
-            Value::SInt64(val) => val.to_ne_bytes().to_vec(),
-            Value::UInt64(val) => val.to_ne_bytes().to_vec(),
-            Value::UInt64z(val) => val.to_ne_bytes().to_vec(),
-            Value::Array(vals) => vals.iter().flat_map(|v| v.to_ne_bytes()).collect(),
+            Value::Timestamp(val) => val.timestamp().to_le_bytes().to_vec(),
+            Value::LocalTimestamp(val) => val.and_utc().timestamp().to_le_bytes().to_vec(),
+            Value::Float32(val) => val.to_le_bytes().to_vec(),
+            Value::Float64(val) => val.to_le_bytes().to_vec(),
+            Value::UInt8z(val) => vec![*val],
+            Value::UInt16z(val) => val.to_le_bytes().to_vec(),
+            Value::UInt32z(val) => val.to_le_bytes().to_vec(),
+            Value::SInt64(val) => val.to_le_bytes().to_vec(),
+            Value::UInt64(val) => val.to_le_bytes().to_vec(),
+            Value::UInt64z(val) => val.to_le_bytes().to_vec(),
+            Value::Array(vals) => vals.iter().flat_map(|v| v.to_le_bytes()).collect(),
         }
     }
 }
@@ -59,24 +171,46 @@ impl TimestampField {
         }
     }
 
-    /// converts offset value into a proper timestamp
-    fn to_date_time(self) -> DateTime<Local> {
-        // reference date defined in FIT profile, it's either in UTC or local TZ
-        let ref_date = NaiveDate::from_ymd(1989, 12, 31).and_hms(0, 0, 0);
+    /// Create a UTC timestamp offset from a proper timestamp
+    pub fn utc_from_date_time(value: &DateTime<TimestampTz>) -> Self {
+        let ref_date = TimeZone::from_utc_datetime(&TimestampTz, &Self::reference_date());
+        Self::Utc((*value - ref_date).num_seconds())
+    }
+
+    /// Create a local timestamp offset from a proper timestamp, using its wall clock time in the
+    /// time zone of the timestamp
+    pub fn local_from_date_time(value: &DateTime<TimestampTz>) -> Self {
+        Self::local_from_wall_clock(&value.naive_local())
+    }
+
+    /// Create a local timestamp offset from the wall clock time it stores
+    pub fn local_from_wall_clock(value: &NaiveDateTime) -> Self {
+        Self::Local((*value - Self::reference_date()).num_seconds())
+    }
+
+    /// Reference date defined in FIT profile, it's either in UTC or local TZ
+    fn reference_date() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(1989, 12, 31)
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .unwrap()
+    }
+
+    /// converts offset value into a proper timestamp, local timestamps are kept as the wall clock
+    /// time since their time zone isn't known
+    fn to_value(self) -> Value {
+        let ref_date = Self::reference_date();
         match self {
-            Self::Local(value) => {
-                TimeZone::from_local_datetime(&Local, &ref_date).unwrap() + Duration::seconds(value)
-            }
-            Self::Utc(value) => {
-                TimeZone::from_utc_datetime(&Local, &ref_date) + Duration::seconds(value)
-            }
+            Self::Local(value) => Value::LocalTimestamp(ref_date + Duration::seconds(value)),
+            Self::Utc(value) => Value::Timestamp(
+                TimeZone::from_utc_datetime(&TimestampTz, &ref_date) + Duration::seconds(value),
+            ),
         }
     }
 }
 
 impl From<TimestampField> for Value {
     fn from(timestamp: TimestampField) -> Value {
-        Value::Timestamp(timestamp.to_date_time())
+        timestamp.to_value()
     }
 }
 
@@ -133,7 +267,7 @@ pub fn calculate_cumulative_value(
     let key = (msg_num as u32) << 8 | def_num as u32;
     if let Some(stored_value) = accumulate_fields.get(&key) {
         match stored_value {
-            Value::Timestamp(_) => {
+            Value::Timestamp(_) | Value::LocalTimestamp(_) => {
                 // TODO: fix this, probably done as u32 math but I probably need to keep timestamps
                 // as u32 values until the "11th hour" so to speak to deal with them more easily.
                 Err(ErrorKind::ValueError("Cannot accumlate timestamp fields".to_string()).into())
@@ -198,24 +332,50 @@ pub fn calculate_cumulative_value(
     }
 }
 
+/// Expand a component that only stores the least significant bits of an accumulating value,
+/// such as a rolling counter, by adding the change since the last value stored for the field.
+pub fn accumulate_component(
+    accumulate_fields: &mut HashMap<u32, Value>,
+    msg_num: u16,
+    def_num: u8,
+    value: Value,
+    bits: usize,
+) -> Result<Value> {
+    let key = (msg_num as u32) << 8 | def_num as u32;
+    let raw: i64 = value.try_into()?;
+    let value = match accumulate_fields.get(&key) {
+        Some(stored_value) => {
+            let last: i64 = stored_value.try_into()?;
+            let mask = (1i64 << bits) - 1;
+            last + ((raw - last) & mask)
+        }
+        None => raw,
+    };
+    accumulate_fields.insert(key, Value::UInt64(value as u64));
+    Ok(Value::UInt64(value as u64))
+}
+
 /// Build a data field using the provided FIT profile information
+#[allow(clippy::too_many_arguments)]
 pub fn data_field_with_info(
     def_number: u8,
-    name: &str,
+    name: impl Into<SharedStr>,
     data_type: FieldDataType,
     scale: f64,
     offset: f64,
-    units: &str,
+    units: impl Into<SharedStr>,
     value: Value,
     options: &HashSet<DecodeOption>,
 ) -> Result<FitDataField> {
-    let value = convert_value(data_type, scale, offset, value, options)?;
-    Ok(FitDataField::new(
-        name.to_string(),
-        def_number,
-        value,
-        units.to_string(),
-    ))
+    let name = name.into();
+    let value = convert_value(data_type, scale, offset, value, options).map_err(|e| {
+        let context = DecodeContext {
+            field: Some(name.to_string()),
+            ..DecodeContext::default()
+        };
+        with_context(e, context)
+    })?;
+    Ok(FitDataField::new(name, def_number, value, units))
 }
 
 /// Create an "unknown" field as a placeholder if we don't have any field information
@@ -224,7 +384,7 @@ pub fn unknown_field(field_def_num: u8, value: Value) -> FitDataField {
         format!("unknown_field_{}", field_def_num),
         field_def_num,
         value,
-        String::new(),
+        "",
     )
 }
 
@@ -236,13 +396,17 @@ fn convert_value(
     value: Value,
     options: &HashSet<DecodeOption>,
 ) -> Result<Value> {
+    if options.contains(&DecodeOption::ReturnRawValues) {
+        return Ok(value);
+    }
+
     // for array types return inner vector unmodified
     if let Value::Array(vals) = value {
         let vals: Result<Vec<Value>> = vals
             .into_iter()
             .map(|v| apply_scale_and_offset(v, scale, offset))
             .collect();
-        return vals.map(|v| Value::Array(v));
+        return vals.map(Value::Array);
     }
 
     // handle time types specially, if for some reason I can't convert to an integer we will
@@ -277,10 +441,268 @@ fn convert_value(
 }
 
 fn apply_scale_and_offset(value: Value, scale: f64, offset: f64) -> Result<Value> {
-    if ((scale - 1.0).abs() > EPSILON) || ((offset - 0.0).abs() > EPSILON) {
+    if ((scale - 1.0).abs() > f64::EPSILON) || ((offset - 0.0).abs() > f64::EPSILON) {
         let val: f64 = value.try_into()?;
         Ok(Value::Float64(val / scale - offset))
     } else {
         Ok(value)
     }
 }
+
+/// Reverse the conversions applied by the FIT profile to recover the raw value of a field, the
+/// result may still need to be cast into the base type of the field.
+pub fn raw_value_with_info(info: &FieldInfo, value: Value) -> Result<Value> {
+    raw_value(info.name, info.field_type, info.scale, info.offset, value)
+}
+
+/// Reverse the conversions applied to a field of the given type, scale and offset, the name is
+/// only used to describe the field in errors.
+pub(crate) fn raw_value(
+    name: &str,
+    field_type: FieldDataType,
+    scale: f64,
+    offset: f64,
+    value: Value,
+) -> Result<Value> {
+    // array values only have scale and offset applied
+    if let Value::Array(vals) = value {
+        let vals: Result<Vec<Value>> = vals
+            .into_iter()
+            .map(|v| remove_scale_and_offset(v, scale, offset))
+            .collect();
+        return vals.map(Value::Array);
+    }
+
+    match (field_type, &value) {
+        (FieldDataType::DateTime, Value::Timestamp(val)) => {
+            return Ok(Value::SInt64(
+                TimestampField::utc_from_date_time(val).as_i64(),
+            ));
+        }
+        (FieldDataType::LocalDateTime, Value::Timestamp(val)) => {
+            return Ok(Value::SInt64(
+                TimestampField::local_from_date_time(val).as_i64(),
+            ));
+        }
+        (FieldDataType::LocalDateTime, Value::LocalTimestamp(val)) => {
+            return Ok(Value::SInt64(
+                TimestampField::local_from_wall_clock(val).as_i64(),
+            ));
+        }
+        _ => (),
+    }
+
+    // convert enum names back to their value
+    if field_type.is_enum_type() {
+        if let Value::String(variant) = &value {
+            return get_field_variant_from_string(field_type, variant)
+                .map(Value::SInt64)
+                .ok_or_else(|| {
+                    ErrorKind::ValueError(format!(
+                        "{} is not a valid {:?} value for field {}",
+                        variant, field_type, name
+                    ))
+                    .into()
+                });
+        }
+        Ok(value)
+    } else {
+        remove_scale_and_offset(value, scale, offset)
+    }
+}
+
+fn remove_scale_and_offset(value: Value, scale: f64, offset: f64) -> Result<Value> {
+    if ((scale - 1.0).abs() > f64::EPSILON) || ((offset - 0.0).abs() > f64::EPSILON) {
+        let val: f64 = value.try_into()?;
+        Ok(Value::Float64((val + offset) * scale))
+    } else {
+        Ok(value)
+    }
+}
+
+/// Convert between the value of a decoded field and the type used by a typed message struct
+pub trait FieldValue: Sized {
+    /// Convert the value, nothing is returned if it can't be represented by the type
+    fn from_value(value: &Value) -> Option<Self>;
+
+    /// Convert the typed value back into the value of a field
+    fn to_value(&self) -> Value;
+}
+
+macro_rules! integer_field_value {
+    ($($type:ty => $variant:ident),*) => {
+        $(
+            impl FieldValue for $type {
+                fn from_value(value: &Value) -> Option<Self> {
+                    let val: i64 = value.try_into().ok()?;
+                    <$type>::try_from(val).ok()
+                }
+
+                fn to_value(&self) -> Value {
+                    Value::$variant(*self)
+                }
+            }
+        )*
+    };
+}
+
+integer_field_value!(
+    i8 => SInt8,
+    u8 => UInt8,
+    i16 => SInt16,
+    u16 => UInt16,
+    i32 => SInt32,
+    u32 => UInt32,
+    i64 => SInt64,
+    u64 => UInt64
+);
+
+impl FieldValue for bool {
+    fn from_value(value: &Value) -> Option<Self> {
+        let val: i64 = value.try_into().ok()?;
+        Some(val != 0)
+    }
+
+    fn to_value(&self) -> Value {
+        Value::UInt8(u8::from(*self))
+    }
+}
+
+impl FieldValue for f32 {
+    fn from_value(value: &Value) -> Option<Self> {
+        f64::from_value(value).map(|v| v as f32)
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Float32(*self)
+    }
+}
+
+impl FieldValue for f64 {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Timestamp(_) | Value::LocalTimestamp(_) | Value::Array(_) => None,
+            value => value.clone().try_into().ok(),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Float64(*self)
+    }
+}
+
+impl FieldValue for String {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(val) => Some(val.clone()),
+            _ => None,
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.clone())
+    }
+}
+
+impl FieldValue for DateTime<TimestampTz> {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Timestamp(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::Timestamp(*self)
+    }
+}
+
+impl FieldValue for NaiveDateTime {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::LocalTimestamp(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::LocalTimestamp(*self)
+    }
+}
+
+/// Array fields store a single value when only one element is present in the message
+impl<T: FieldValue> FieldValue for Vec<T> {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Array(vals) => vals.iter().map(T::from_value).collect(),
+            value => T::from_value(value).map(|v| vec![v]),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        match self.as_slice() {
+            [val] => val.to_value(),
+            vals => Value::Array(vals.iter().map(T::to_value).collect()),
+        }
+    }
+}
+
+/// Convert an enum field that is either the variant name or the numeric value when the value
+/// isn't a named variant or `DecodeOption::ReturnNumericEnumValues` is used
+fn enum_field_value<T: From<i64> + FromStr>(value: &Value) -> Option<T> {
+    match value {
+        Value::String(val) => val.parse().ok(),
+        Value::Array(_) | Value::Timestamp(_) | Value::LocalTimestamp(_) => None,
+        value => value.try_into().ok().map(T::from),
+    }
+}
+
+/// Convert an enum into the variant name the decoder returns, values that aren't named variants
+/// are stored as numbers
+fn enum_value<T: fmt::Display>(value: &T, number: i64, is_named: bool) -> Value {
+    if is_named {
+        Value::String(value.to_string())
+    } else {
+        Value::SInt64(number)
+    }
+}
+
+/// Add the value of a typed message struct field to a record, using the field number and units
+/// defined by the profile
+fn push_typed_field<T: FieldValue>(record: &mut FitDataRecord, name: &str, value: &Option<T>) {
+    let info = record.kind().field_info().iter().find(|f| f.name() == name);
+    if let (Some(info), Some(value)) = (info, value) {
+        record.push(FitDataField::new(
+            info.name(),
+            info.def_number(),
+            value.to_value(),
+            info.units(),
+        ));
+    }
+}
+
+/// Convert the value of a field for a typed message struct
+fn typed_field_value<T: FieldValue>(field: &FitDataField) -> Result<T> {
+    T::from_value(field.value()).ok_or_else(|| {
+        ErrorKind::ValueError(format!(
+            "cannot convert {} into the type of field {}",
+            field.value(),
+            field.name()
+        ))
+        .into()
+    })
+}
+
+/// Check that a record is the kind of message a typed message struct is converted from
+fn check_message_kind(record: &FitDataRecord, kind: MesgNum) -> Result<()> {
+    if record.kind() == kind {
+        Ok(())
+    } else {
+        Err(ErrorKind::ValueError(format!(
+            "cannot convert a {} message into a {} message",
+            record.kind(),
+            kind
+        ))
+        .into())
+    }
+}
@@ -0,0 +1,9888 @@
+//! Auto generated typed message structs from FIT SDK Release: 21.105.00
+//! Each struct can be converted from a decoded record of the same kind, field values that don't
+//! match the type defined by the profile are returned as an error. Records decoded using
+//! `DecodeOption::ReturnRawValues` or `DecodeOption::UseGenericSubFieldName` may not convert.
+//! Records created from a struct use the field numbers and units of the profile so they can
+//! be encoded.
+#![allow(missing_docs)]
+#![allow(clippy::single_match)]
+use super::field_types::{self, MesgNum};
+use super::{
+    check_message_kind, enum_field_value, enum_value, push_typed_field, typed_field_value,
+    FieldValue,
+};
+use crate::compat::prelude::*;
+use crate::error::{Error, Result};
+use crate::{FitDataRecord, TimestampTz, Value};
+use chrono::{DateTime, NaiveDateTime};
+use core::convert::TryFrom;
+use serde::Serialize;
+impl FieldValue for field_types::File {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::MesgNum {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::Checksum {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::FileFlags {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::MesgCount {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::MessageIndex {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::DeviceIndex {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::Gender {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::Language {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::LanguageBits0 {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::LanguageBits1 {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::LanguageBits2 {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::LanguageBits3 {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::LanguageBits4 {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::TimeZone {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::DisplayMeasure {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::DisplayHeart {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::DisplayPower {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::DisplayPosition {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::Switch {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::Sport {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SportBits0 {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SportBits1 {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SportBits2 {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SportBits3 {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SportBits4 {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SportBits5 {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SportBits6 {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SubSport {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SportEvent {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::Activity {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::Intensity {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SessionTrigger {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::AutolapTrigger {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::LapTrigger {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::TimeMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::BacklightMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::DateMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::BacklightTimeout {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::Event {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::EventType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::TimerTrigger {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::FitnessEquipmentState {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::Tone {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::Autoscroll {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::ActivityClass {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::HrZoneCalc {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::PwrZoneCalc {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::WktStepDuration {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::WktStepTarget {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::Goal {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::GoalRecurrence {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::GoalSource {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::Schedule {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::CoursePoint {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::Manufacturer {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::GarminProduct {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::AntplusDeviceType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::AntNetwork {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::WorkoutCapabilities {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::BatteryStatus {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::HrType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::CourseCapabilities {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::Weight {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::WorkoutHr {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::WorkoutPower {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::BpStatus {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::UserLocalId {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SwimStroke {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::ActivityType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::ActivitySubtype {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::ActivityLevel {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::Side {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::LeftRightBalance {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::LeftRightBalance100 {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::LengthType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::DayOfWeek {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::ConnectivityCapabilities {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::WeatherReport {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::WeatherStatus {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::WeatherSeverity {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::WeatherSevereType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::StrokeType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::BodyLocation {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SegmentLapStatus {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SegmentLeaderboardType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SegmentDeleteStatus {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SegmentSelectionType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SourceType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::LocalDeviceType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::BleDeviceType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::AntChannelId {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::DisplayOrientation {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::WorkoutEquipment {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::WatchfaceMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::DigitalWatchfaceLayout {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::AnalogWatchfaceLayout {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::RiderPositionType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::PowerPhaseType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::CameraEventType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SensorType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::BikeLightNetworkConfigType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::CommTimeoutType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::CameraOrientationType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::AttitudeStage {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::AttitudeValidity {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::AutoSyncFrequency {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::ExdLayout {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::ExdDisplayType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::ExdDataUnits {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::ExdQualifiers {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::ExdDescriptors {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::AutoActivityDetect {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SupportedExdScreenLayouts {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::FitBaseType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::TurnType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::BikeLightBeamAngleMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::FitBaseUnit {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SetType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::ExerciseCategory {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::BenchPressExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::CalfRaiseExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::CardioExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::CarryExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::ChopExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::CoreExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::CrunchExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::CurlExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::DeadliftExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::FlyeExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::HipRaiseExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::HipStabilityExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::HipSwingExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::HyperextensionExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::LateralRaiseExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::LegCurlExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::LegRaiseExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::LungeExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::OlympicLiftExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::PlankExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::PlyoExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::PullUpExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::PushUpExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::RowExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::ShoulderPressExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::ShoulderStabilityExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::ShrugExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SitUpExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SquatExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::TotalBodyExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::TricepsExtensionExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::WarmUpExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::RunExerciseName {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::WaterType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::TissueModelType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::DiveGasStatus {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::DiveAlert {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::DiveAlarmType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::DiveBacklightMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::CcrSetpointSwitchMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::DiveGasMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::FaveroProduct {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::SplitType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::ClimbProEvent {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::GasConsumptionRateType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::TapSensitivity {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::RadarThreatLevelType {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+impl FieldValue for field_types::NoFlyTimeMode {
+    fn from_value(value: &Value) -> Option<Self> {
+        enum_field_value(value)
+    }
+    fn to_value(&self) -> Value {
+        enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))
+    }
+}
+/// Must be first message in file.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct FileId {
+    pub r#type: Option<field_types::File>,
+    pub manufacturer: Option<field_types::Manufacturer>,
+    pub product: Option<u16>,
+    pub favero_product: Option<field_types::FaveroProduct>,
+    pub garmin_product: Option<field_types::GarminProduct>,
+    pub serial_number: Option<u32>,
+    pub time_created: Option<DateTime<TimestampTz>>,
+    pub number: Option<u16>,
+    pub product_name: Option<String>,
+}
+impl TryFrom<&FitDataRecord> for FileId {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::FileId)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "type" => message.r#type = Some(typed_field_value(field)?),
+                "manufacturer" => message.manufacturer = Some(typed_field_value(field)?),
+                "product" => message.product = Some(typed_field_value(field)?),
+                "favero_product" => message.favero_product = Some(typed_field_value(field)?),
+                "garmin_product" => message.garmin_product = Some(typed_field_value(field)?),
+                "serial_number" => message.serial_number = Some(typed_field_value(field)?),
+                "time_created" => message.time_created = Some(typed_field_value(field)?),
+                "number" => message.number = Some(typed_field_value(field)?),
+                "product_name" => message.product_name = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&FileId> for FitDataRecord {
+    fn from(message: &FileId) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::FileId);
+        push_typed_field(&mut record, "type", &message.r#type);
+        push_typed_field(&mut record, "manufacturer", &message.manufacturer);
+        push_typed_field(&mut record, "product", &message.product);
+        push_typed_field(&mut record, "favero_product", &message.favero_product);
+        push_typed_field(&mut record, "garmin_product", &message.garmin_product);
+        push_typed_field(&mut record, "serial_number", &message.serial_number);
+        push_typed_field(&mut record, "time_created", &message.time_created);
+        push_typed_field(&mut record, "number", &message.number);
+        push_typed_field(&mut record, "product_name", &message.product_name);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct FileCreator {
+    pub software_version: Option<u16>,
+    pub hardware_version: Option<u8>,
+}
+impl TryFrom<&FitDataRecord> for FileCreator {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::FileCreator)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "software_version" => message.software_version = Some(typed_field_value(field)?),
+                "hardware_version" => message.hardware_version = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&FileCreator> for FitDataRecord {
+    fn from(message: &FileCreator) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::FileCreator);
+        push_typed_field(&mut record, "software_version", &message.software_version);
+        push_typed_field(&mut record, "hardware_version", &message.hardware_version);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct TimestampCorrelation {
+    pub fractional_timestamp: Option<f64>,
+    pub system_timestamp: Option<DateTime<TimestampTz>>,
+    pub fractional_system_timestamp: Option<f64>,
+    pub local_timestamp: Option<NaiveDateTime>,
+    pub timestamp_ms: Option<u16>,
+    pub system_timestamp_ms: Option<u16>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for TimestampCorrelation {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::TimestampCorrelation)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "fractional_timestamp" => {
+                    message.fractional_timestamp = Some(typed_field_value(field)?)
+                }
+                "system_timestamp" => message.system_timestamp = Some(typed_field_value(field)?),
+                "fractional_system_timestamp" => {
+                    message.fractional_system_timestamp = Some(typed_field_value(field)?)
+                }
+                "local_timestamp" => message.local_timestamp = Some(typed_field_value(field)?),
+                "timestamp_ms" => message.timestamp_ms = Some(typed_field_value(field)?),
+                "system_timestamp_ms" => {
+                    message.system_timestamp_ms = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&TimestampCorrelation> for FitDataRecord {
+    fn from(message: &TimestampCorrelation) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::TimestampCorrelation);
+        push_typed_field(
+            &mut record,
+            "fractional_timestamp",
+            &message.fractional_timestamp,
+        );
+        push_typed_field(&mut record, "system_timestamp", &message.system_timestamp);
+        push_typed_field(
+            &mut record,
+            "fractional_system_timestamp",
+            &message.fractional_system_timestamp,
+        );
+        push_typed_field(&mut record, "local_timestamp", &message.local_timestamp);
+        push_typed_field(&mut record, "timestamp_ms", &message.timestamp_ms);
+        push_typed_field(
+            &mut record,
+            "system_timestamp_ms",
+            &message.system_timestamp_ms,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Software {
+    pub version: Option<f64>,
+    pub part_number: Option<String>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for Software {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Software)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "version" => message.version = Some(typed_field_value(field)?),
+                "part_number" => message.part_number = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Software> for FitDataRecord {
+    fn from(message: &Software) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Software);
+        push_typed_field(&mut record, "version", &message.version);
+        push_typed_field(&mut record, "part_number", &message.part_number);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SlaveDevice {
+    pub manufacturer: Option<field_types::Manufacturer>,
+    pub product: Option<u16>,
+    pub favero_product: Option<field_types::FaveroProduct>,
+    pub garmin_product: Option<field_types::GarminProduct>,
+}
+impl TryFrom<&FitDataRecord> for SlaveDevice {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::SlaveDevice)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "manufacturer" => message.manufacturer = Some(typed_field_value(field)?),
+                "product" => message.product = Some(typed_field_value(field)?),
+                "favero_product" => message.favero_product = Some(typed_field_value(field)?),
+                "garmin_product" => message.garmin_product = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&SlaveDevice> for FitDataRecord {
+    fn from(message: &SlaveDevice) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::SlaveDevice);
+        push_typed_field(&mut record, "manufacturer", &message.manufacturer);
+        push_typed_field(&mut record, "product", &message.product);
+        push_typed_field(&mut record, "favero_product", &message.favero_product);
+        push_typed_field(&mut record, "garmin_product", &message.garmin_product);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Capabilities {
+    pub languages: Option<Vec<u8>>,
+    pub sports: Option<Vec<field_types::SportBits0>>,
+    pub workouts_supported: Option<field_types::WorkoutCapabilities>,
+    pub connectivity_supported: Option<field_types::ConnectivityCapabilities>,
+}
+impl TryFrom<&FitDataRecord> for Capabilities {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Capabilities)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "languages" => message.languages = Some(typed_field_value(field)?),
+                "sports" => message.sports = Some(typed_field_value(field)?),
+                "workouts_supported" => {
+                    message.workouts_supported = Some(typed_field_value(field)?)
+                }
+                "connectivity_supported" => {
+                    message.connectivity_supported = Some(typed_field_value(field)?)
+                }
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Capabilities> for FitDataRecord {
+    fn from(message: &Capabilities) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Capabilities);
+        push_typed_field(&mut record, "languages", &message.languages);
+        push_typed_field(&mut record, "sports", &message.sports);
+        push_typed_field(
+            &mut record,
+            "workouts_supported",
+            &message.workouts_supported,
+        );
+        push_typed_field(
+            &mut record,
+            "connectivity_supported",
+            &message.connectivity_supported,
+        );
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct FileCapabilities {
+    pub r#type: Option<field_types::File>,
+    pub flags: Option<field_types::FileFlags>,
+    pub directory: Option<String>,
+    pub max_count: Option<u16>,
+    pub max_size: Option<u32>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for FileCapabilities {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::FileCapabilities)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "type" => message.r#type = Some(typed_field_value(field)?),
+                "flags" => message.flags = Some(typed_field_value(field)?),
+                "directory" => message.directory = Some(typed_field_value(field)?),
+                "max_count" => message.max_count = Some(typed_field_value(field)?),
+                "max_size" => message.max_size = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&FileCapabilities> for FitDataRecord {
+    fn from(message: &FileCapabilities) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::FileCapabilities);
+        push_typed_field(&mut record, "type", &message.r#type);
+        push_typed_field(&mut record, "flags", &message.flags);
+        push_typed_field(&mut record, "directory", &message.directory);
+        push_typed_field(&mut record, "max_count", &message.max_count);
+        push_typed_field(&mut record, "max_size", &message.max_size);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct MesgCapabilities {
+    pub file: Option<field_types::File>,
+    pub mesg_num: Option<field_types::MesgNum>,
+    pub count_type: Option<field_types::MesgCount>,
+    pub count: Option<u16>,
+    pub num_per_file: Option<u16>,
+    pub max_per_file: Option<u16>,
+    pub max_per_file_type: Option<u16>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for MesgCapabilities {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::MesgCapabilities)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "file" => message.file = Some(typed_field_value(field)?),
+                "mesg_num" => message.mesg_num = Some(typed_field_value(field)?),
+                "count_type" => message.count_type = Some(typed_field_value(field)?),
+                "count" => message.count = Some(typed_field_value(field)?),
+                "num_per_file" => message.num_per_file = Some(typed_field_value(field)?),
+                "max_per_file" => message.max_per_file = Some(typed_field_value(field)?),
+                "max_per_file_type" => message.max_per_file_type = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&MesgCapabilities> for FitDataRecord {
+    fn from(message: &MesgCapabilities) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::MesgCapabilities);
+        push_typed_field(&mut record, "file", &message.file);
+        push_typed_field(&mut record, "mesg_num", &message.mesg_num);
+        push_typed_field(&mut record, "count_type", &message.count_type);
+        push_typed_field(&mut record, "count", &message.count);
+        push_typed_field(&mut record, "num_per_file", &message.num_per_file);
+        push_typed_field(&mut record, "max_per_file", &message.max_per_file);
+        push_typed_field(&mut record, "max_per_file_type", &message.max_per_file_type);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct FieldCapabilities {
+    pub file: Option<field_types::File>,
+    pub mesg_num: Option<field_types::MesgNum>,
+    pub field_num: Option<u8>,
+    pub count: Option<u16>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for FieldCapabilities {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::FieldCapabilities)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "file" => message.file = Some(typed_field_value(field)?),
+                "mesg_num" => message.mesg_num = Some(typed_field_value(field)?),
+                "field_num" => message.field_num = Some(typed_field_value(field)?),
+                "count" => message.count = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&FieldCapabilities> for FitDataRecord {
+    fn from(message: &FieldCapabilities) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::FieldCapabilities);
+        push_typed_field(&mut record, "file", &message.file);
+        push_typed_field(&mut record, "mesg_num", &message.mesg_num);
+        push_typed_field(&mut record, "field_num", &message.field_num);
+        push_typed_field(&mut record, "count", &message.count);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct DeviceSettings {
+    pub active_time_zone: Option<u8>,
+    pub utc_offset: Option<u32>,
+    pub time_offset: Option<Vec<u32>>,
+    pub time_mode: Option<Vec<field_types::TimeMode>>,
+    pub time_zone_offset: Option<Vec<f64>>,
+    pub backlight_mode: Option<field_types::BacklightMode>,
+    pub activity_tracker_enabled: Option<bool>,
+    pub clock_time: Option<DateTime<TimestampTz>>,
+    pub pages_enabled: Option<Vec<u16>>,
+    pub move_alert_enabled: Option<bool>,
+    pub date_mode: Option<field_types::DateMode>,
+    pub display_orientation: Option<field_types::DisplayOrientation>,
+    pub mounting_side: Option<field_types::Side>,
+    pub default_page: Option<Vec<u16>>,
+    pub autosync_min_steps: Option<u16>,
+    pub autosync_min_time: Option<u16>,
+    pub lactate_threshold_autodetect_enabled: Option<bool>,
+    pub ble_auto_upload_enabled: Option<bool>,
+    pub auto_sync_frequency: Option<field_types::AutoSyncFrequency>,
+    pub auto_activity_detect: Option<field_types::AutoActivityDetect>,
+    pub number_of_screens: Option<u8>,
+    pub smart_notification_display_orientation: Option<field_types::DisplayOrientation>,
+    pub tap_interface: Option<field_types::Switch>,
+    pub tap_sensitivity: Option<field_types::TapSensitivity>,
+}
+impl TryFrom<&FitDataRecord> for DeviceSettings {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::DeviceSettings)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "active_time_zone" => message.active_time_zone = Some(typed_field_value(field)?),
+                "utc_offset" => message.utc_offset = Some(typed_field_value(field)?),
+                "time_offset" => message.time_offset = Some(typed_field_value(field)?),
+                "time_mode" => message.time_mode = Some(typed_field_value(field)?),
+                "time_zone_offset" => message.time_zone_offset = Some(typed_field_value(field)?),
+                "backlight_mode" => message.backlight_mode = Some(typed_field_value(field)?),
+                "activity_tracker_enabled" => {
+                    message.activity_tracker_enabled = Some(typed_field_value(field)?)
+                }
+                "clock_time" => message.clock_time = Some(typed_field_value(field)?),
+                "pages_enabled" => message.pages_enabled = Some(typed_field_value(field)?),
+                "move_alert_enabled" => {
+                    message.move_alert_enabled = Some(typed_field_value(field)?)
+                }
+                "date_mode" => message.date_mode = Some(typed_field_value(field)?),
+                "display_orientation" => {
+                    message.display_orientation = Some(typed_field_value(field)?)
+                }
+                "mounting_side" => message.mounting_side = Some(typed_field_value(field)?),
+                "default_page" => message.default_page = Some(typed_field_value(field)?),
+                "autosync_min_steps" => {
+                    message.autosync_min_steps = Some(typed_field_value(field)?)
+                }
+                "autosync_min_time" => message.autosync_min_time = Some(typed_field_value(field)?),
+                "lactate_threshold_autodetect_enabled" => {
+                    message.lactate_threshold_autodetect_enabled = Some(typed_field_value(field)?)
+                }
+                "ble_auto_upload_enabled" => {
+                    message.ble_auto_upload_enabled = Some(typed_field_value(field)?)
+                }
+                "auto_sync_frequency" => {
+                    message.auto_sync_frequency = Some(typed_field_value(field)?)
+                }
+                "auto_activity_detect" => {
+                    message.auto_activity_detect = Some(typed_field_value(field)?)
+                }
+                "number_of_screens" => message.number_of_screens = Some(typed_field_value(field)?),
+                "smart_notification_display_orientation" => {
+                    message.smart_notification_display_orientation = Some(typed_field_value(field)?)
+                }
+                "tap_interface" => message.tap_interface = Some(typed_field_value(field)?),
+                "tap_sensitivity" => message.tap_sensitivity = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&DeviceSettings> for FitDataRecord {
+    fn from(message: &DeviceSettings) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::DeviceSettings);
+        push_typed_field(&mut record, "active_time_zone", &message.active_time_zone);
+        push_typed_field(&mut record, "utc_offset", &message.utc_offset);
+        push_typed_field(&mut record, "time_offset", &message.time_offset);
+        push_typed_field(&mut record, "time_mode", &message.time_mode);
+        push_typed_field(&mut record, "time_zone_offset", &message.time_zone_offset);
+        push_typed_field(&mut record, "backlight_mode", &message.backlight_mode);
+        push_typed_field(
+            &mut record,
+            "activity_tracker_enabled",
+            &message.activity_tracker_enabled,
+        );
+        push_typed_field(&mut record, "clock_time", &message.clock_time);
+        push_typed_field(&mut record, "pages_enabled", &message.pages_enabled);
+        push_typed_field(
+            &mut record,
+            "move_alert_enabled",
+            &message.move_alert_enabled,
+        );
+        push_typed_field(&mut record, "date_mode", &message.date_mode);
+        push_typed_field(
+            &mut record,
+            "display_orientation",
+            &message.display_orientation,
+        );
+        push_typed_field(&mut record, "mounting_side", &message.mounting_side);
+        push_typed_field(&mut record, "default_page", &message.default_page);
+        push_typed_field(
+            &mut record,
+            "autosync_min_steps",
+            &message.autosync_min_steps,
+        );
+        push_typed_field(&mut record, "autosync_min_time", &message.autosync_min_time);
+        push_typed_field(
+            &mut record,
+            "lactate_threshold_autodetect_enabled",
+            &message.lactate_threshold_autodetect_enabled,
+        );
+        push_typed_field(
+            &mut record,
+            "ble_auto_upload_enabled",
+            &message.ble_auto_upload_enabled,
+        );
+        push_typed_field(
+            &mut record,
+            "auto_sync_frequency",
+            &message.auto_sync_frequency,
+        );
+        push_typed_field(
+            &mut record,
+            "auto_activity_detect",
+            &message.auto_activity_detect,
+        );
+        push_typed_field(&mut record, "number_of_screens", &message.number_of_screens);
+        push_typed_field(
+            &mut record,
+            "smart_notification_display_orientation",
+            &message.smart_notification_display_orientation,
+        );
+        push_typed_field(&mut record, "tap_interface", &message.tap_interface);
+        push_typed_field(&mut record, "tap_sensitivity", &message.tap_sensitivity);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct UserProfile {
+    pub friendly_name: Option<String>,
+    pub gender: Option<field_types::Gender>,
+    pub age: Option<u8>,
+    pub height: Option<f64>,
+    pub weight: Option<f64>,
+    pub language: Option<field_types::Language>,
+    pub elev_setting: Option<field_types::DisplayMeasure>,
+    pub weight_setting: Option<field_types::DisplayMeasure>,
+    pub resting_heart_rate: Option<u8>,
+    pub default_max_running_heart_rate: Option<u8>,
+    pub default_max_biking_heart_rate: Option<u8>,
+    pub default_max_heart_rate: Option<u8>,
+    pub hr_setting: Option<field_types::DisplayHeart>,
+    pub speed_setting: Option<field_types::DisplayMeasure>,
+    pub dist_setting: Option<field_types::DisplayMeasure>,
+    pub power_setting: Option<field_types::DisplayPower>,
+    pub activity_class: Option<field_types::ActivityClass>,
+    pub position_setting: Option<field_types::DisplayPosition>,
+    pub temperature_setting: Option<field_types::DisplayMeasure>,
+    pub local_id: Option<field_types::UserLocalId>,
+    pub global_id: Option<Vec<u8>>,
+    pub wake_time: Option<u32>,
+    pub sleep_time: Option<u32>,
+    pub height_setting: Option<field_types::DisplayMeasure>,
+    pub user_running_step_length: Option<f64>,
+    pub user_walking_step_length: Option<f64>,
+    pub depth_setting: Option<field_types::DisplayMeasure>,
+    pub dive_count: Option<u32>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for UserProfile {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::UserProfile)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "friendly_name" => message.friendly_name = Some(typed_field_value(field)?),
+                "gender" => message.gender = Some(typed_field_value(field)?),
+                "age" => message.age = Some(typed_field_value(field)?),
+                "height" => message.height = Some(typed_field_value(field)?),
+                "weight" => message.weight = Some(typed_field_value(field)?),
+                "language" => message.language = Some(typed_field_value(field)?),
+                "elev_setting" => message.elev_setting = Some(typed_field_value(field)?),
+                "weight_setting" => message.weight_setting = Some(typed_field_value(field)?),
+                "resting_heart_rate" => {
+                    message.resting_heart_rate = Some(typed_field_value(field)?)
+                }
+                "default_max_running_heart_rate" => {
+                    message.default_max_running_heart_rate = Some(typed_field_value(field)?)
+                }
+                "default_max_biking_heart_rate" => {
+                    message.default_max_biking_heart_rate = Some(typed_field_value(field)?)
+                }
+                "default_max_heart_rate" => {
+                    message.default_max_heart_rate = Some(typed_field_value(field)?)
+                }
+                "hr_setting" => message.hr_setting = Some(typed_field_value(field)?),
+                "speed_setting" => message.speed_setting = Some(typed_field_value(field)?),
+                "dist_setting" => message.dist_setting = Some(typed_field_value(field)?),
+                "power_setting" => message.power_setting = Some(typed_field_value(field)?),
+                "activity_class" => message.activity_class = Some(typed_field_value(field)?),
+                "position_setting" => message.position_setting = Some(typed_field_value(field)?),
+                "temperature_setting" => {
+                    message.temperature_setting = Some(typed_field_value(field)?)
+                }
+                "local_id" => message.local_id = Some(typed_field_value(field)?),
+                "global_id" => message.global_id = Some(typed_field_value(field)?),
+                "wake_time" => message.wake_time = Some(typed_field_value(field)?),
+                "sleep_time" => message.sleep_time = Some(typed_field_value(field)?),
+                "height_setting" => message.height_setting = Some(typed_field_value(field)?),
+                "user_running_step_length" => {
+                    message.user_running_step_length = Some(typed_field_value(field)?)
+                }
+                "user_walking_step_length" => {
+                    message.user_walking_step_length = Some(typed_field_value(field)?)
+                }
+                "depth_setting" => message.depth_setting = Some(typed_field_value(field)?),
+                "dive_count" => message.dive_count = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&UserProfile> for FitDataRecord {
+    fn from(message: &UserProfile) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::UserProfile);
+        push_typed_field(&mut record, "friendly_name", &message.friendly_name);
+        push_typed_field(&mut record, "gender", &message.gender);
+        push_typed_field(&mut record, "age", &message.age);
+        push_typed_field(&mut record, "height", &message.height);
+        push_typed_field(&mut record, "weight", &message.weight);
+        push_typed_field(&mut record, "language", &message.language);
+        push_typed_field(&mut record, "elev_setting", &message.elev_setting);
+        push_typed_field(&mut record, "weight_setting", &message.weight_setting);
+        push_typed_field(
+            &mut record,
+            "resting_heart_rate",
+            &message.resting_heart_rate,
+        );
+        push_typed_field(
+            &mut record,
+            "default_max_running_heart_rate",
+            &message.default_max_running_heart_rate,
+        );
+        push_typed_field(
+            &mut record,
+            "default_max_biking_heart_rate",
+            &message.default_max_biking_heart_rate,
+        );
+        push_typed_field(
+            &mut record,
+            "default_max_heart_rate",
+            &message.default_max_heart_rate,
+        );
+        push_typed_field(&mut record, "hr_setting", &message.hr_setting);
+        push_typed_field(&mut record, "speed_setting", &message.speed_setting);
+        push_typed_field(&mut record, "dist_setting", &message.dist_setting);
+        push_typed_field(&mut record, "power_setting", &message.power_setting);
+        push_typed_field(&mut record, "activity_class", &message.activity_class);
+        push_typed_field(&mut record, "position_setting", &message.position_setting);
+        push_typed_field(
+            &mut record,
+            "temperature_setting",
+            &message.temperature_setting,
+        );
+        push_typed_field(&mut record, "local_id", &message.local_id);
+        push_typed_field(&mut record, "global_id", &message.global_id);
+        push_typed_field(&mut record, "wake_time", &message.wake_time);
+        push_typed_field(&mut record, "sleep_time", &message.sleep_time);
+        push_typed_field(&mut record, "height_setting", &message.height_setting);
+        push_typed_field(
+            &mut record,
+            "user_running_step_length",
+            &message.user_running_step_length,
+        );
+        push_typed_field(
+            &mut record,
+            "user_walking_step_length",
+            &message.user_walking_step_length,
+        );
+        push_typed_field(&mut record, "depth_setting", &message.depth_setting);
+        push_typed_field(&mut record, "dive_count", &message.dive_count);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct HrmProfile {
+    pub enabled: Option<bool>,
+    pub hrm_ant_id: Option<u16>,
+    pub log_hrv: Option<bool>,
+    pub hrm_ant_id_trans_type: Option<u8>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for HrmProfile {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::HrmProfile)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "enabled" => message.enabled = Some(typed_field_value(field)?),
+                "hrm_ant_id" => message.hrm_ant_id = Some(typed_field_value(field)?),
+                "log_hrv" => message.log_hrv = Some(typed_field_value(field)?),
+                "hrm_ant_id_trans_type" => {
+                    message.hrm_ant_id_trans_type = Some(typed_field_value(field)?)
+                }
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&HrmProfile> for FitDataRecord {
+    fn from(message: &HrmProfile) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::HrmProfile);
+        push_typed_field(&mut record, "enabled", &message.enabled);
+        push_typed_field(&mut record, "hrm_ant_id", &message.hrm_ant_id);
+        push_typed_field(&mut record, "log_hrv", &message.log_hrv);
+        push_typed_field(
+            &mut record,
+            "hrm_ant_id_trans_type",
+            &message.hrm_ant_id_trans_type,
+        );
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SdmProfile {
+    pub enabled: Option<bool>,
+    pub sdm_ant_id: Option<u16>,
+    pub sdm_cal_factor: Option<f64>,
+    pub odometer: Option<f64>,
+    pub speed_source: Option<bool>,
+    pub sdm_ant_id_trans_type: Option<u8>,
+    pub odometer_rollover: Option<u8>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for SdmProfile {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::SdmProfile)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "enabled" => message.enabled = Some(typed_field_value(field)?),
+                "sdm_ant_id" => message.sdm_ant_id = Some(typed_field_value(field)?),
+                "sdm_cal_factor" => message.sdm_cal_factor = Some(typed_field_value(field)?),
+                "odometer" => message.odometer = Some(typed_field_value(field)?),
+                "speed_source" => message.speed_source = Some(typed_field_value(field)?),
+                "sdm_ant_id_trans_type" => {
+                    message.sdm_ant_id_trans_type = Some(typed_field_value(field)?)
+                }
+                "odometer_rollover" => message.odometer_rollover = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&SdmProfile> for FitDataRecord {
+    fn from(message: &SdmProfile) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::SdmProfile);
+        push_typed_field(&mut record, "enabled", &message.enabled);
+        push_typed_field(&mut record, "sdm_ant_id", &message.sdm_ant_id);
+        push_typed_field(&mut record, "sdm_cal_factor", &message.sdm_cal_factor);
+        push_typed_field(&mut record, "odometer", &message.odometer);
+        push_typed_field(&mut record, "speed_source", &message.speed_source);
+        push_typed_field(
+            &mut record,
+            "sdm_ant_id_trans_type",
+            &message.sdm_ant_id_trans_type,
+        );
+        push_typed_field(&mut record, "odometer_rollover", &message.odometer_rollover);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct BikeProfile {
+    pub name: Option<String>,
+    pub sport: Option<field_types::Sport>,
+    pub sub_sport: Option<field_types::SubSport>,
+    pub odometer: Option<f64>,
+    pub bike_spd_ant_id: Option<u16>,
+    pub bike_cad_ant_id: Option<u16>,
+    pub bike_spdcad_ant_id: Option<u16>,
+    pub bike_power_ant_id: Option<u16>,
+    pub custom_wheelsize: Option<f64>,
+    pub auto_wheelsize: Option<f64>,
+    pub bike_weight: Option<f64>,
+    pub power_cal_factor: Option<f64>,
+    pub auto_wheel_cal: Option<bool>,
+    pub auto_power_zero: Option<bool>,
+    pub id: Option<u8>,
+    pub spd_enabled: Option<bool>,
+    pub cad_enabled: Option<bool>,
+    pub spdcad_enabled: Option<bool>,
+    pub power_enabled: Option<bool>,
+    pub crank_length: Option<f64>,
+    pub enabled: Option<bool>,
+    pub bike_spd_ant_id_trans_type: Option<u8>,
+    pub bike_cad_ant_id_trans_type: Option<u8>,
+    pub bike_spdcad_ant_id_trans_type: Option<u8>,
+    pub bike_power_ant_id_trans_type: Option<u8>,
+    pub odometer_rollover: Option<u8>,
+    pub front_gear_num: Option<u8>,
+    pub front_gear: Option<Vec<u8>>,
+    pub rear_gear_num: Option<u8>,
+    pub rear_gear: Option<Vec<u8>>,
+    pub shimano_di2_enabled: Option<bool>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for BikeProfile {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::BikeProfile)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "name" => message.name = Some(typed_field_value(field)?),
+                "sport" => message.sport = Some(typed_field_value(field)?),
+                "sub_sport" => message.sub_sport = Some(typed_field_value(field)?),
+                "odometer" => message.odometer = Some(typed_field_value(field)?),
+                "bike_spd_ant_id" => message.bike_spd_ant_id = Some(typed_field_value(field)?),
+                "bike_cad_ant_id" => message.bike_cad_ant_id = Some(typed_field_value(field)?),
+                "bike_spdcad_ant_id" => {
+                    message.bike_spdcad_ant_id = Some(typed_field_value(field)?)
+                }
+                "bike_power_ant_id" => message.bike_power_ant_id = Some(typed_field_value(field)?),
+                "custom_wheelsize" => message.custom_wheelsize = Some(typed_field_value(field)?),
+                "auto_wheelsize" => message.auto_wheelsize = Some(typed_field_value(field)?),
+                "bike_weight" => message.bike_weight = Some(typed_field_value(field)?),
+                "power_cal_factor" => message.power_cal_factor = Some(typed_field_value(field)?),
+                "auto_wheel_cal" => message.auto_wheel_cal = Some(typed_field_value(field)?),
+                "auto_power_zero" => message.auto_power_zero = Some(typed_field_value(field)?),
+                "id" => message.id = Some(typed_field_value(field)?),
+                "spd_enabled" => message.spd_enabled = Some(typed_field_value(field)?),
+                "cad_enabled" => message.cad_enabled = Some(typed_field_value(field)?),
+                "spdcad_enabled" => message.spdcad_enabled = Some(typed_field_value(field)?),
+                "power_enabled" => message.power_enabled = Some(typed_field_value(field)?),
+                "crank_length" => message.crank_length = Some(typed_field_value(field)?),
+                "enabled" => message.enabled = Some(typed_field_value(field)?),
+                "bike_spd_ant_id_trans_type" => {
+                    message.bike_spd_ant_id_trans_type = Some(typed_field_value(field)?)
+                }
+                "bike_cad_ant_id_trans_type" => {
+                    message.bike_cad_ant_id_trans_type = Some(typed_field_value(field)?)
+                }
+                "bike_spdcad_ant_id_trans_type" => {
+                    message.bike_spdcad_ant_id_trans_type = Some(typed_field_value(field)?)
+                }
+                "bike_power_ant_id_trans_type" => {
+                    message.bike_power_ant_id_trans_type = Some(typed_field_value(field)?)
+                }
+                "odometer_rollover" => message.odometer_rollover = Some(typed_field_value(field)?),
+                "front_gear_num" => message.front_gear_num = Some(typed_field_value(field)?),
+                "front_gear" => message.front_gear = Some(typed_field_value(field)?),
+                "rear_gear_num" => message.rear_gear_num = Some(typed_field_value(field)?),
+                "rear_gear" => message.rear_gear = Some(typed_field_value(field)?),
+                "shimano_di2_enabled" => {
+                    message.shimano_di2_enabled = Some(typed_field_value(field)?)
+                }
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&BikeProfile> for FitDataRecord {
+    fn from(message: &BikeProfile) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::BikeProfile);
+        push_typed_field(&mut record, "name", &message.name);
+        push_typed_field(&mut record, "sport", &message.sport);
+        push_typed_field(&mut record, "sub_sport", &message.sub_sport);
+        push_typed_field(&mut record, "odometer", &message.odometer);
+        push_typed_field(&mut record, "bike_spd_ant_id", &message.bike_spd_ant_id);
+        push_typed_field(&mut record, "bike_cad_ant_id", &message.bike_cad_ant_id);
+        push_typed_field(
+            &mut record,
+            "bike_spdcad_ant_id",
+            &message.bike_spdcad_ant_id,
+        );
+        push_typed_field(&mut record, "bike_power_ant_id", &message.bike_power_ant_id);
+        push_typed_field(&mut record, "custom_wheelsize", &message.custom_wheelsize);
+        push_typed_field(&mut record, "auto_wheelsize", &message.auto_wheelsize);
+        push_typed_field(&mut record, "bike_weight", &message.bike_weight);
+        push_typed_field(&mut record, "power_cal_factor", &message.power_cal_factor);
+        push_typed_field(&mut record, "auto_wheel_cal", &message.auto_wheel_cal);
+        push_typed_field(&mut record, "auto_power_zero", &message.auto_power_zero);
+        push_typed_field(&mut record, "id", &message.id);
+        push_typed_field(&mut record, "spd_enabled", &message.spd_enabled);
+        push_typed_field(&mut record, "cad_enabled", &message.cad_enabled);
+        push_typed_field(&mut record, "spdcad_enabled", &message.spdcad_enabled);
+        push_typed_field(&mut record, "power_enabled", &message.power_enabled);
+        push_typed_field(&mut record, "crank_length", &message.crank_length);
+        push_typed_field(&mut record, "enabled", &message.enabled);
+        push_typed_field(
+            &mut record,
+            "bike_spd_ant_id_trans_type",
+            &message.bike_spd_ant_id_trans_type,
+        );
+        push_typed_field(
+            &mut record,
+            "bike_cad_ant_id_trans_type",
+            &message.bike_cad_ant_id_trans_type,
+        );
+        push_typed_field(
+            &mut record,
+            "bike_spdcad_ant_id_trans_type",
+            &message.bike_spdcad_ant_id_trans_type,
+        );
+        push_typed_field(
+            &mut record,
+            "bike_power_ant_id_trans_type",
+            &message.bike_power_ant_id_trans_type,
+        );
+        push_typed_field(&mut record, "odometer_rollover", &message.odometer_rollover);
+        push_typed_field(&mut record, "front_gear_num", &message.front_gear_num);
+        push_typed_field(&mut record, "front_gear", &message.front_gear);
+        push_typed_field(&mut record, "rear_gear_num", &message.rear_gear_num);
+        push_typed_field(&mut record, "rear_gear", &message.rear_gear);
+        push_typed_field(
+            &mut record,
+            "shimano_di2_enabled",
+            &message.shimano_di2_enabled,
+        );
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Connectivity {
+    pub bluetooth_enabled: Option<bool>,
+    pub bluetooth_le_enabled: Option<bool>,
+    pub ant_enabled: Option<bool>,
+    pub name: Option<String>,
+    pub live_tracking_enabled: Option<bool>,
+    pub weather_conditions_enabled: Option<bool>,
+    pub weather_alerts_enabled: Option<bool>,
+    pub auto_activity_upload_enabled: Option<bool>,
+    pub course_download_enabled: Option<bool>,
+    pub workout_download_enabled: Option<bool>,
+    pub gps_ephemeris_download_enabled: Option<bool>,
+    pub incident_detection_enabled: Option<bool>,
+    pub grouptrack_enabled: Option<bool>,
+}
+impl TryFrom<&FitDataRecord> for Connectivity {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Connectivity)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "bluetooth_enabled" => message.bluetooth_enabled = Some(typed_field_value(field)?),
+                "bluetooth_le_enabled" => {
+                    message.bluetooth_le_enabled = Some(typed_field_value(field)?)
+                }
+                "ant_enabled" => message.ant_enabled = Some(typed_field_value(field)?),
+                "name" => message.name = Some(typed_field_value(field)?),
+                "live_tracking_enabled" => {
+                    message.live_tracking_enabled = Some(typed_field_value(field)?)
+                }
+                "weather_conditions_enabled" => {
+                    message.weather_conditions_enabled = Some(typed_field_value(field)?)
+                }
+                "weather_alerts_enabled" => {
+                    message.weather_alerts_enabled = Some(typed_field_value(field)?)
+                }
+                "auto_activity_upload_enabled" => {
+                    message.auto_activity_upload_enabled = Some(typed_field_value(field)?)
+                }
+                "course_download_enabled" => {
+                    message.course_download_enabled = Some(typed_field_value(field)?)
+                }
+                "workout_download_enabled" => {
+                    message.workout_download_enabled = Some(typed_field_value(field)?)
+                }
+                "gps_ephemeris_download_enabled" => {
+                    message.gps_ephemeris_download_enabled = Some(typed_field_value(field)?)
+                }
+                "incident_detection_enabled" => {
+                    message.incident_detection_enabled = Some(typed_field_value(field)?)
+                }
+                "grouptrack_enabled" => {
+                    message.grouptrack_enabled = Some(typed_field_value(field)?)
+                }
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Connectivity> for FitDataRecord {
+    fn from(message: &Connectivity) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Connectivity);
+        push_typed_field(&mut record, "bluetooth_enabled", &message.bluetooth_enabled);
+        push_typed_field(
+            &mut record,
+            "bluetooth_le_enabled",
+            &message.bluetooth_le_enabled,
+        );
+        push_typed_field(&mut record, "ant_enabled", &message.ant_enabled);
+        push_typed_field(&mut record, "name", &message.name);
+        push_typed_field(
+            &mut record,
+            "live_tracking_enabled",
+            &message.live_tracking_enabled,
+        );
+        push_typed_field(
+            &mut record,
+            "weather_conditions_enabled",
+            &message.weather_conditions_enabled,
+        );
+        push_typed_field(
+            &mut record,
+            "weather_alerts_enabled",
+            &message.weather_alerts_enabled,
+        );
+        push_typed_field(
+            &mut record,
+            "auto_activity_upload_enabled",
+            &message.auto_activity_upload_enabled,
+        );
+        push_typed_field(
+            &mut record,
+            "course_download_enabled",
+            &message.course_download_enabled,
+        );
+        push_typed_field(
+            &mut record,
+            "workout_download_enabled",
+            &message.workout_download_enabled,
+        );
+        push_typed_field(
+            &mut record,
+            "gps_ephemeris_download_enabled",
+            &message.gps_ephemeris_download_enabled,
+        );
+        push_typed_field(
+            &mut record,
+            "incident_detection_enabled",
+            &message.incident_detection_enabled,
+        );
+        push_typed_field(
+            &mut record,
+            "grouptrack_enabled",
+            &message.grouptrack_enabled,
+        );
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct WatchfaceSettings {
+    pub mode: Option<field_types::WatchfaceMode>,
+    pub layout: Option<u8>,
+    pub digital_layout: Option<field_types::DigitalWatchfaceLayout>,
+    pub analog_layout: Option<field_types::AnalogWatchfaceLayout>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for WatchfaceSettings {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::WatchfaceSettings)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "mode" => message.mode = Some(typed_field_value(field)?),
+                "layout" => message.layout = Some(typed_field_value(field)?),
+                "digital_layout" => message.digital_layout = Some(typed_field_value(field)?),
+                "analog_layout" => message.analog_layout = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&WatchfaceSettings> for FitDataRecord {
+    fn from(message: &WatchfaceSettings) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::WatchfaceSettings);
+        push_typed_field(&mut record, "mode", &message.mode);
+        push_typed_field(&mut record, "layout", &message.layout);
+        push_typed_field(&mut record, "digital_layout", &message.digital_layout);
+        push_typed_field(&mut record, "analog_layout", &message.analog_layout);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct OhrSettings {
+    pub enabled: Option<field_types::Switch>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for OhrSettings {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::OhrSettings)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "enabled" => message.enabled = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&OhrSettings> for FitDataRecord {
+    fn from(message: &OhrSettings) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::OhrSettings);
+        push_typed_field(&mut record, "enabled", &message.enabled);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct TimeInZone {
+    pub reference_mesg: Option<field_types::MesgNum>,
+    pub reference_index: Option<field_types::MessageIndex>,
+    pub time_in_hr_zone: Option<Vec<f64>>,
+    pub time_in_speed_zone: Option<Vec<f64>>,
+    pub time_in_cadence_zone: Option<Vec<f64>>,
+    pub time_in_power_zone: Option<Vec<f64>>,
+    pub hr_zone_high_boundary: Option<Vec<u8>>,
+    pub speed_zone_high_boundary: Option<Vec<f64>>,
+    pub cadence_zone_high_bondary: Option<Vec<u8>>,
+    pub power_zone_high_boundary: Option<Vec<u16>>,
+    pub hr_calc_type: Option<field_types::HrZoneCalc>,
+    pub max_heart_rate: Option<u8>,
+    pub resting_heart_rate: Option<u8>,
+    pub threshold_heart_rate: Option<u8>,
+    pub pwr_calc_type: Option<field_types::PwrZoneCalc>,
+    pub functional_threshold_power: Option<u16>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for TimeInZone {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::TimeInZone)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "reference_mesg" => message.reference_mesg = Some(typed_field_value(field)?),
+                "reference_index" => message.reference_index = Some(typed_field_value(field)?),
+                "time_in_hr_zone" => message.time_in_hr_zone = Some(typed_field_value(field)?),
+                "time_in_speed_zone" => {
+                    message.time_in_speed_zone = Some(typed_field_value(field)?)
+                }
+                "time_in_cadence_zone" => {
+                    message.time_in_cadence_zone = Some(typed_field_value(field)?)
+                }
+                "time_in_power_zone" => {
+                    message.time_in_power_zone = Some(typed_field_value(field)?)
+                }
+                "hr_zone_high_boundary" => {
+                    message.hr_zone_high_boundary = Some(typed_field_value(field)?)
+                }
+                "speed_zone_high_boundary" => {
+                    message.speed_zone_high_boundary = Some(typed_field_value(field)?)
+                }
+                "cadence_zone_high_bondary" => {
+                    message.cadence_zone_high_bondary = Some(typed_field_value(field)?)
+                }
+                "power_zone_high_boundary" => {
+                    message.power_zone_high_boundary = Some(typed_field_value(field)?)
+                }
+                "hr_calc_type" => message.hr_calc_type = Some(typed_field_value(field)?),
+                "max_heart_rate" => message.max_heart_rate = Some(typed_field_value(field)?),
+                "resting_heart_rate" => {
+                    message.resting_heart_rate = Some(typed_field_value(field)?)
+                }
+                "threshold_heart_rate" => {
+                    message.threshold_heart_rate = Some(typed_field_value(field)?)
+                }
+                "pwr_calc_type" => message.pwr_calc_type = Some(typed_field_value(field)?),
+                "functional_threshold_power" => {
+                    message.functional_threshold_power = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&TimeInZone> for FitDataRecord {
+    fn from(message: &TimeInZone) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::TimeInZone);
+        push_typed_field(&mut record, "reference_mesg", &message.reference_mesg);
+        push_typed_field(&mut record, "reference_index", &message.reference_index);
+        push_typed_field(&mut record, "time_in_hr_zone", &message.time_in_hr_zone);
+        push_typed_field(
+            &mut record,
+            "time_in_speed_zone",
+            &message.time_in_speed_zone,
+        );
+        push_typed_field(
+            &mut record,
+            "time_in_cadence_zone",
+            &message.time_in_cadence_zone,
+        );
+        push_typed_field(
+            &mut record,
+            "time_in_power_zone",
+            &message.time_in_power_zone,
+        );
+        push_typed_field(
+            &mut record,
+            "hr_zone_high_boundary",
+            &message.hr_zone_high_boundary,
+        );
+        push_typed_field(
+            &mut record,
+            "speed_zone_high_boundary",
+            &message.speed_zone_high_boundary,
+        );
+        push_typed_field(
+            &mut record,
+            "cadence_zone_high_bondary",
+            &message.cadence_zone_high_bondary,
+        );
+        push_typed_field(
+            &mut record,
+            "power_zone_high_boundary",
+            &message.power_zone_high_boundary,
+        );
+        push_typed_field(&mut record, "hr_calc_type", &message.hr_calc_type);
+        push_typed_field(&mut record, "max_heart_rate", &message.max_heart_rate);
+        push_typed_field(
+            &mut record,
+            "resting_heart_rate",
+            &message.resting_heart_rate,
+        );
+        push_typed_field(
+            &mut record,
+            "threshold_heart_rate",
+            &message.threshold_heart_rate,
+        );
+        push_typed_field(&mut record, "pwr_calc_type", &message.pwr_calc_type);
+        push_typed_field(
+            &mut record,
+            "functional_threshold_power",
+            &message.functional_threshold_power,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ZonesTarget {
+    pub max_heart_rate: Option<u8>,
+    pub threshold_heart_rate: Option<u8>,
+    pub functional_threshold_power: Option<u16>,
+    pub hr_calc_type: Option<field_types::HrZoneCalc>,
+    pub pwr_calc_type: Option<field_types::PwrZoneCalc>,
+}
+impl TryFrom<&FitDataRecord> for ZonesTarget {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::ZonesTarget)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "max_heart_rate" => message.max_heart_rate = Some(typed_field_value(field)?),
+                "threshold_heart_rate" => {
+                    message.threshold_heart_rate = Some(typed_field_value(field)?)
+                }
+                "functional_threshold_power" => {
+                    message.functional_threshold_power = Some(typed_field_value(field)?)
+                }
+                "hr_calc_type" => message.hr_calc_type = Some(typed_field_value(field)?),
+                "pwr_calc_type" => message.pwr_calc_type = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&ZonesTarget> for FitDataRecord {
+    fn from(message: &ZonesTarget) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::ZonesTarget);
+        push_typed_field(&mut record, "max_heart_rate", &message.max_heart_rate);
+        push_typed_field(
+            &mut record,
+            "threshold_heart_rate",
+            &message.threshold_heart_rate,
+        );
+        push_typed_field(
+            &mut record,
+            "functional_threshold_power",
+            &message.functional_threshold_power,
+        );
+        push_typed_field(&mut record, "hr_calc_type", &message.hr_calc_type);
+        push_typed_field(&mut record, "pwr_calc_type", &message.pwr_calc_type);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Sport {
+    pub sport: Option<field_types::Sport>,
+    pub sub_sport: Option<field_types::SubSport>,
+    pub name: Option<String>,
+}
+impl TryFrom<&FitDataRecord> for Sport {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Sport)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "sport" => message.sport = Some(typed_field_value(field)?),
+                "sub_sport" => message.sub_sport = Some(typed_field_value(field)?),
+                "name" => message.name = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Sport> for FitDataRecord {
+    fn from(message: &Sport) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Sport);
+        push_typed_field(&mut record, "sport", &message.sport);
+        push_typed_field(&mut record, "sub_sport", &message.sub_sport);
+        push_typed_field(&mut record, "name", &message.name);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct HrZone {
+    pub high_bpm: Option<u8>,
+    pub name: Option<String>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for HrZone {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::HrZone)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "high_bpm" => message.high_bpm = Some(typed_field_value(field)?),
+                "name" => message.name = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&HrZone> for FitDataRecord {
+    fn from(message: &HrZone) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::HrZone);
+        push_typed_field(&mut record, "high_bpm", &message.high_bpm);
+        push_typed_field(&mut record, "name", &message.name);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SpeedZone {
+    pub high_value: Option<f64>,
+    pub name: Option<String>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for SpeedZone {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::SpeedZone)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "high_value" => message.high_value = Some(typed_field_value(field)?),
+                "name" => message.name = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&SpeedZone> for FitDataRecord {
+    fn from(message: &SpeedZone) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::SpeedZone);
+        push_typed_field(&mut record, "high_value", &message.high_value);
+        push_typed_field(&mut record, "name", &message.name);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct CadenceZone {
+    pub high_value: Option<u8>,
+    pub name: Option<String>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for CadenceZone {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::CadenceZone)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "high_value" => message.high_value = Some(typed_field_value(field)?),
+                "name" => message.name = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&CadenceZone> for FitDataRecord {
+    fn from(message: &CadenceZone) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::CadenceZone);
+        push_typed_field(&mut record, "high_value", &message.high_value);
+        push_typed_field(&mut record, "name", &message.name);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct PowerZone {
+    pub high_value: Option<u16>,
+    pub name: Option<String>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for PowerZone {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::PowerZone)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "high_value" => message.high_value = Some(typed_field_value(field)?),
+                "name" => message.name = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&PowerZone> for FitDataRecord {
+    fn from(message: &PowerZone) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::PowerZone);
+        push_typed_field(&mut record, "high_value", &message.high_value);
+        push_typed_field(&mut record, "name", &message.name);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct MetZone {
+    pub high_bpm: Option<u8>,
+    pub calories: Option<f64>,
+    pub fat_calories: Option<f64>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for MetZone {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::MetZone)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "high_bpm" => message.high_bpm = Some(typed_field_value(field)?),
+                "calories" => message.calories = Some(typed_field_value(field)?),
+                "fat_calories" => message.fat_calories = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&MetZone> for FitDataRecord {
+    fn from(message: &MetZone) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::MetZone);
+        push_typed_field(&mut record, "high_bpm", &message.high_bpm);
+        push_typed_field(&mut record, "calories", &message.calories);
+        push_typed_field(&mut record, "fat_calories", &message.fat_calories);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct DiveSettings {
+    pub name: Option<String>,
+    pub model: Option<field_types::TissueModelType>,
+    pub gf_low: Option<u8>,
+    pub gf_high: Option<u8>,
+    pub water_type: Option<field_types::WaterType>,
+    pub water_density: Option<f32>,
+    pub po2_warn: Option<f64>,
+    pub po2_critical: Option<f64>,
+    pub po2_deco: Option<f64>,
+    pub safety_stop_enabled: Option<bool>,
+    pub bottom_depth: Option<f32>,
+    pub bottom_time: Option<u32>,
+    pub apnea_countdown_enabled: Option<bool>,
+    pub apnea_countdown_time: Option<u32>,
+    pub backlight_mode: Option<field_types::DiveBacklightMode>,
+    pub backlight_brightness: Option<u8>,
+    pub backlight_timeout: Option<field_types::BacklightTimeout>,
+    pub repeat_dive_interval: Option<u16>,
+    pub safety_stop_time: Option<u16>,
+    pub heart_rate_source_type: Option<field_types::SourceType>,
+    pub heart_rate_source: Option<u8>,
+    pub heart_rate_antplus_device_type: Option<field_types::AntplusDeviceType>,
+    pub heart_rate_local_device_type: Option<field_types::LocalDeviceType>,
+    pub travel_gas: Option<field_types::MessageIndex>,
+    pub ccr_low_setpoint_switch_mode: Option<field_types::CcrSetpointSwitchMode>,
+    pub ccr_low_setpoint: Option<f64>,
+    pub ccr_low_setpoint_depth: Option<f64>,
+    pub ccr_high_setpoint_switch_mode: Option<field_types::CcrSetpointSwitchMode>,
+    pub ccr_high_setpoint: Option<f64>,
+    pub ccr_high_setpoint_depth: Option<f64>,
+    pub gas_consumption_display: Option<field_types::GasConsumptionRateType>,
+    pub up_key_enabled: Option<bool>,
+    pub dive_sounds: Option<field_types::Tone>,
+    pub last_stop_multiple: Option<f64>,
+    pub no_fly_time_mode: Option<field_types::NoFlyTimeMode>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for DiveSettings {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::DiveSettings)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "name" => message.name = Some(typed_field_value(field)?),
+                "model" => message.model = Some(typed_field_value(field)?),
+                "gf_low" => message.gf_low = Some(typed_field_value(field)?),
+                "gf_high" => message.gf_high = Some(typed_field_value(field)?),
+                "water_type" => message.water_type = Some(typed_field_value(field)?),
+                "water_density" => message.water_density = Some(typed_field_value(field)?),
+                "po2_warn" => message.po2_warn = Some(typed_field_value(field)?),
+                "po2_critical" => message.po2_critical = Some(typed_field_value(field)?),
+                "po2_deco" => message.po2_deco = Some(typed_field_value(field)?),
+                "safety_stop_enabled" => {
+                    message.safety_stop_enabled = Some(typed_field_value(field)?)
+                }
+                "bottom_depth" => message.bottom_depth = Some(typed_field_value(field)?),
+                "bottom_time" => message.bottom_time = Some(typed_field_value(field)?),
+                "apnea_countdown_enabled" => {
+                    message.apnea_countdown_enabled = Some(typed_field_value(field)?)
+                }
+                "apnea_countdown_time" => {
+                    message.apnea_countdown_time = Some(typed_field_value(field)?)
+                }
+                "backlight_mode" => message.backlight_mode = Some(typed_field_value(field)?),
+                "backlight_brightness" => {
+                    message.backlight_brightness = Some(typed_field_value(field)?)
+                }
+                "backlight_timeout" => message.backlight_timeout = Some(typed_field_value(field)?),
+                "repeat_dive_interval" => {
+                    message.repeat_dive_interval = Some(typed_field_value(field)?)
+                }
+                "safety_stop_time" => message.safety_stop_time = Some(typed_field_value(field)?),
+                "heart_rate_source_type" => {
+                    message.heart_rate_source_type = Some(typed_field_value(field)?)
+                }
+                "heart_rate_source" => message.heart_rate_source = Some(typed_field_value(field)?),
+                "heart_rate_antplus_device_type" => {
+                    message.heart_rate_antplus_device_type = Some(typed_field_value(field)?)
+                }
+                "heart_rate_local_device_type" => {
+                    message.heart_rate_local_device_type = Some(typed_field_value(field)?)
+                }
+                "travel_gas" => message.travel_gas = Some(typed_field_value(field)?),
+                "ccr_low_setpoint_switch_mode" => {
+                    message.ccr_low_setpoint_switch_mode = Some(typed_field_value(field)?)
+                }
+                "ccr_low_setpoint" => message.ccr_low_setpoint = Some(typed_field_value(field)?),
+                "ccr_low_setpoint_depth" => {
+                    message.ccr_low_setpoint_depth = Some(typed_field_value(field)?)
+                }
+                "ccr_high_setpoint_switch_mode" => {
+                    message.ccr_high_setpoint_switch_mode = Some(typed_field_value(field)?)
+                }
+                "ccr_high_setpoint" => message.ccr_high_setpoint = Some(typed_field_value(field)?),
+                "ccr_high_setpoint_depth" => {
+                    message.ccr_high_setpoint_depth = Some(typed_field_value(field)?)
+                }
+                "gas_consumption_display" => {
+                    message.gas_consumption_display = Some(typed_field_value(field)?)
+                }
+                "up_key_enabled" => message.up_key_enabled = Some(typed_field_value(field)?),
+                "dive_sounds" => message.dive_sounds = Some(typed_field_value(field)?),
+                "last_stop_multiple" => {
+                    message.last_stop_multiple = Some(typed_field_value(field)?)
+                }
+                "no_fly_time_mode" => message.no_fly_time_mode = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&DiveSettings> for FitDataRecord {
+    fn from(message: &DiveSettings) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::DiveSettings);
+        push_typed_field(&mut record, "name", &message.name);
+        push_typed_field(&mut record, "model", &message.model);
+        push_typed_field(&mut record, "gf_low", &message.gf_low);
+        push_typed_field(&mut record, "gf_high", &message.gf_high);
+        push_typed_field(&mut record, "water_type", &message.water_type);
+        push_typed_field(&mut record, "water_density", &message.water_density);
+        push_typed_field(&mut record, "po2_warn", &message.po2_warn);
+        push_typed_field(&mut record, "po2_critical", &message.po2_critical);
+        push_typed_field(&mut record, "po2_deco", &message.po2_deco);
+        push_typed_field(
+            &mut record,
+            "safety_stop_enabled",
+            &message.safety_stop_enabled,
+        );
+        push_typed_field(&mut record, "bottom_depth", &message.bottom_depth);
+        push_typed_field(&mut record, "bottom_time", &message.bottom_time);
+        push_typed_field(
+            &mut record,
+            "apnea_countdown_enabled",
+            &message.apnea_countdown_enabled,
+        );
+        push_typed_field(
+            &mut record,
+            "apnea_countdown_time",
+            &message.apnea_countdown_time,
+        );
+        push_typed_field(&mut record, "backlight_mode", &message.backlight_mode);
+        push_typed_field(
+            &mut record,
+            "backlight_brightness",
+            &message.backlight_brightness,
+        );
+        push_typed_field(&mut record, "backlight_timeout", &message.backlight_timeout);
+        push_typed_field(
+            &mut record,
+            "repeat_dive_interval",
+            &message.repeat_dive_interval,
+        );
+        push_typed_field(&mut record, "safety_stop_time", &message.safety_stop_time);
+        push_typed_field(
+            &mut record,
+            "heart_rate_source_type",
+            &message.heart_rate_source_type,
+        );
+        push_typed_field(&mut record, "heart_rate_source", &message.heart_rate_source);
+        push_typed_field(
+            &mut record,
+            "heart_rate_antplus_device_type",
+            &message.heart_rate_antplus_device_type,
+        );
+        push_typed_field(
+            &mut record,
+            "heart_rate_local_device_type",
+            &message.heart_rate_local_device_type,
+        );
+        push_typed_field(&mut record, "travel_gas", &message.travel_gas);
+        push_typed_field(
+            &mut record,
+            "ccr_low_setpoint_switch_mode",
+            &message.ccr_low_setpoint_switch_mode,
+        );
+        push_typed_field(&mut record, "ccr_low_setpoint", &message.ccr_low_setpoint);
+        push_typed_field(
+            &mut record,
+            "ccr_low_setpoint_depth",
+            &message.ccr_low_setpoint_depth,
+        );
+        push_typed_field(
+            &mut record,
+            "ccr_high_setpoint_switch_mode",
+            &message.ccr_high_setpoint_switch_mode,
+        );
+        push_typed_field(&mut record, "ccr_high_setpoint", &message.ccr_high_setpoint);
+        push_typed_field(
+            &mut record,
+            "ccr_high_setpoint_depth",
+            &message.ccr_high_setpoint_depth,
+        );
+        push_typed_field(
+            &mut record,
+            "gas_consumption_display",
+            &message.gas_consumption_display,
+        );
+        push_typed_field(&mut record, "up_key_enabled", &message.up_key_enabled);
+        push_typed_field(&mut record, "dive_sounds", &message.dive_sounds);
+        push_typed_field(
+            &mut record,
+            "last_stop_multiple",
+            &message.last_stop_multiple,
+        );
+        push_typed_field(&mut record, "no_fly_time_mode", &message.no_fly_time_mode);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct DiveAlarm {
+    pub depth: Option<f64>,
+    pub time: Option<i32>,
+    pub enabled: Option<bool>,
+    pub alarm_type: Option<field_types::DiveAlarmType>,
+    pub sound: Option<field_types::Tone>,
+    pub dive_types: Option<Vec<field_types::SubSport>>,
+    pub id: Option<u32>,
+    pub popup_enabled: Option<bool>,
+    pub trigger_on_descent: Option<bool>,
+    pub trigger_on_ascent: Option<bool>,
+    pub repeating: Option<bool>,
+    pub speed: Option<f64>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for DiveAlarm {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::DiveAlarm)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "depth" => message.depth = Some(typed_field_value(field)?),
+                "time" => message.time = Some(typed_field_value(field)?),
+                "enabled" => message.enabled = Some(typed_field_value(field)?),
+                "alarm_type" => message.alarm_type = Some(typed_field_value(field)?),
+                "sound" => message.sound = Some(typed_field_value(field)?),
+                "dive_types" => message.dive_types = Some(typed_field_value(field)?),
+                "id" => message.id = Some(typed_field_value(field)?),
+                "popup_enabled" => message.popup_enabled = Some(typed_field_value(field)?),
+                "trigger_on_descent" => {
+                    message.trigger_on_descent = Some(typed_field_value(field)?)
+                }
+                "trigger_on_ascent" => message.trigger_on_ascent = Some(typed_field_value(field)?),
+                "repeating" => message.repeating = Some(typed_field_value(field)?),
+                "speed" => message.speed = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&DiveAlarm> for FitDataRecord {
+    fn from(message: &DiveAlarm) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::DiveAlarm);
+        push_typed_field(&mut record, "depth", &message.depth);
+        push_typed_field(&mut record, "time", &message.time);
+        push_typed_field(&mut record, "enabled", &message.enabled);
+        push_typed_field(&mut record, "alarm_type", &message.alarm_type);
+        push_typed_field(&mut record, "sound", &message.sound);
+        push_typed_field(&mut record, "dive_types", &message.dive_types);
+        push_typed_field(&mut record, "id", &message.id);
+        push_typed_field(&mut record, "popup_enabled", &message.popup_enabled);
+        push_typed_field(
+            &mut record,
+            "trigger_on_descent",
+            &message.trigger_on_descent,
+        );
+        push_typed_field(&mut record, "trigger_on_ascent", &message.trigger_on_ascent);
+        push_typed_field(&mut record, "repeating", &message.repeating);
+        push_typed_field(&mut record, "speed", &message.speed);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct DiveApneaAlarm {
+    pub depth: Option<f64>,
+    pub time: Option<i32>,
+    pub enabled: Option<bool>,
+    pub alarm_type: Option<field_types::DiveAlarmType>,
+    pub sound: Option<field_types::Tone>,
+    pub dive_types: Option<Vec<field_types::SubSport>>,
+    pub id: Option<u32>,
+    pub popup_enabled: Option<bool>,
+    pub trigger_on_descent: Option<bool>,
+    pub trigger_on_ascent: Option<bool>,
+    pub repeating: Option<bool>,
+    pub speed: Option<f64>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for DiveApneaAlarm {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::DiveApneaAlarm)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "depth" => message.depth = Some(typed_field_value(field)?),
+                "time" => message.time = Some(typed_field_value(field)?),
+                "enabled" => message.enabled = Some(typed_field_value(field)?),
+                "alarm_type" => message.alarm_type = Some(typed_field_value(field)?),
+                "sound" => message.sound = Some(typed_field_value(field)?),
+                "dive_types" => message.dive_types = Some(typed_field_value(field)?),
+                "id" => message.id = Some(typed_field_value(field)?),
+                "popup_enabled" => message.popup_enabled = Some(typed_field_value(field)?),
+                "trigger_on_descent" => {
+                    message.trigger_on_descent = Some(typed_field_value(field)?)
+                }
+                "trigger_on_ascent" => message.trigger_on_ascent = Some(typed_field_value(field)?),
+                "repeating" => message.repeating = Some(typed_field_value(field)?),
+                "speed" => message.speed = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&DiveApneaAlarm> for FitDataRecord {
+    fn from(message: &DiveApneaAlarm) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::DiveApneaAlarm);
+        push_typed_field(&mut record, "depth", &message.depth);
+        push_typed_field(&mut record, "time", &message.time);
+        push_typed_field(&mut record, "enabled", &message.enabled);
+        push_typed_field(&mut record, "alarm_type", &message.alarm_type);
+        push_typed_field(&mut record, "sound", &message.sound);
+        push_typed_field(&mut record, "dive_types", &message.dive_types);
+        push_typed_field(&mut record, "id", &message.id);
+        push_typed_field(&mut record, "popup_enabled", &message.popup_enabled);
+        push_typed_field(
+            &mut record,
+            "trigger_on_descent",
+            &message.trigger_on_descent,
+        );
+        push_typed_field(&mut record, "trigger_on_ascent", &message.trigger_on_ascent);
+        push_typed_field(&mut record, "repeating", &message.repeating);
+        push_typed_field(&mut record, "speed", &message.speed);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct DiveGas {
+    pub helium_content: Option<u8>,
+    pub oxygen_content: Option<u8>,
+    pub status: Option<field_types::DiveGasStatus>,
+    pub mode: Option<field_types::DiveGasMode>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for DiveGas {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::DiveGas)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "helium_content" => message.helium_content = Some(typed_field_value(field)?),
+                "oxygen_content" => message.oxygen_content = Some(typed_field_value(field)?),
+                "status" => message.status = Some(typed_field_value(field)?),
+                "mode" => message.mode = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&DiveGas> for FitDataRecord {
+    fn from(message: &DiveGas) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::DiveGas);
+        push_typed_field(&mut record, "helium_content", &message.helium_content);
+        push_typed_field(&mut record, "oxygen_content", &message.oxygen_content);
+        push_typed_field(&mut record, "status", &message.status);
+        push_typed_field(&mut record, "mode", &message.mode);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Goal {
+    pub sport: Option<field_types::Sport>,
+    pub sub_sport: Option<field_types::SubSport>,
+    pub start_date: Option<DateTime<TimestampTz>>,
+    pub end_date: Option<DateTime<TimestampTz>>,
+    pub r#type: Option<field_types::Goal>,
+    pub value: Option<u32>,
+    pub repeat: Option<bool>,
+    pub target_value: Option<u32>,
+    pub recurrence: Option<field_types::GoalRecurrence>,
+    pub recurrence_value: Option<u16>,
+    pub enabled: Option<bool>,
+    pub source: Option<field_types::GoalSource>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for Goal {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Goal)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "sport" => message.sport = Some(typed_field_value(field)?),
+                "sub_sport" => message.sub_sport = Some(typed_field_value(field)?),
+                "start_date" => message.start_date = Some(typed_field_value(field)?),
+                "end_date" => message.end_date = Some(typed_field_value(field)?),
+                "type" => message.r#type = Some(typed_field_value(field)?),
+                "value" => message.value = Some(typed_field_value(field)?),
+                "repeat" => message.repeat = Some(typed_field_value(field)?),
+                "target_value" => message.target_value = Some(typed_field_value(field)?),
+                "recurrence" => message.recurrence = Some(typed_field_value(field)?),
+                "recurrence_value" => message.recurrence_value = Some(typed_field_value(field)?),
+                "enabled" => message.enabled = Some(typed_field_value(field)?),
+                "source" => message.source = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Goal> for FitDataRecord {
+    fn from(message: &Goal) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Goal);
+        push_typed_field(&mut record, "sport", &message.sport);
+        push_typed_field(&mut record, "sub_sport", &message.sub_sport);
+        push_typed_field(&mut record, "start_date", &message.start_date);
+        push_typed_field(&mut record, "end_date", &message.end_date);
+        push_typed_field(&mut record, "type", &message.r#type);
+        push_typed_field(&mut record, "value", &message.value);
+        push_typed_field(&mut record, "repeat", &message.repeat);
+        push_typed_field(&mut record, "target_value", &message.target_value);
+        push_typed_field(&mut record, "recurrence", &message.recurrence);
+        push_typed_field(&mut record, "recurrence_value", &message.recurrence_value);
+        push_typed_field(&mut record, "enabled", &message.enabled);
+        push_typed_field(&mut record, "source", &message.source);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Activity {
+    pub total_timer_time: Option<f64>,
+    pub num_sessions: Option<u16>,
+    pub r#type: Option<field_types::Activity>,
+    pub event: Option<field_types::Event>,
+    pub event_type: Option<field_types::EventType>,
+    pub local_timestamp: Option<NaiveDateTime>,
+    pub event_group: Option<u8>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for Activity {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Activity)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "total_timer_time" => message.total_timer_time = Some(typed_field_value(field)?),
+                "num_sessions" => message.num_sessions = Some(typed_field_value(field)?),
+                "type" => message.r#type = Some(typed_field_value(field)?),
+                "event" => message.event = Some(typed_field_value(field)?),
+                "event_type" => message.event_type = Some(typed_field_value(field)?),
+                "local_timestamp" => message.local_timestamp = Some(typed_field_value(field)?),
+                "event_group" => message.event_group = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Activity> for FitDataRecord {
+    fn from(message: &Activity) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Activity);
+        push_typed_field(&mut record, "total_timer_time", &message.total_timer_time);
+        push_typed_field(&mut record, "num_sessions", &message.num_sessions);
+        push_typed_field(&mut record, "type", &message.r#type);
+        push_typed_field(&mut record, "event", &message.event);
+        push_typed_field(&mut record, "event_type", &message.event_type);
+        push_typed_field(&mut record, "local_timestamp", &message.local_timestamp);
+        push_typed_field(&mut record, "event_group", &message.event_group);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Session {
+    pub event: Option<field_types::Event>,
+    pub event_type: Option<field_types::EventType>,
+    pub start_time: Option<DateTime<TimestampTz>>,
+    pub start_position_lat: Option<i32>,
+    pub start_position_long: Option<i32>,
+    pub sport: Option<field_types::Sport>,
+    pub sub_sport: Option<field_types::SubSport>,
+    pub total_elapsed_time: Option<f64>,
+    pub total_timer_time: Option<f64>,
+    pub total_distance: Option<f64>,
+    pub total_cycles: Option<u32>,
+    pub total_strides: Option<u32>,
+    pub total_strokes: Option<u32>,
+    pub total_calories: Option<u16>,
+    pub total_fat_calories: Option<u16>,
+    pub avg_speed: Option<f64>,
+    pub max_speed: Option<f64>,
+    pub avg_heart_rate: Option<u8>,
+    pub max_heart_rate: Option<u8>,
+    pub avg_cadence: Option<u8>,
+    pub avg_running_cadence: Option<u8>,
+    pub max_cadence: Option<u8>,
+    pub max_running_cadence: Option<u8>,
+    pub avg_power: Option<u16>,
+    pub max_power: Option<u16>,
+    pub total_ascent: Option<u16>,
+    pub total_descent: Option<u16>,
+    pub total_training_effect: Option<f64>,
+    pub first_lap_index: Option<u16>,
+    pub num_laps: Option<u16>,
+    pub event_group: Option<u8>,
+    pub trigger: Option<field_types::SessionTrigger>,
+    pub nec_lat: Option<i32>,
+    pub nec_long: Option<i32>,
+    pub swc_lat: Option<i32>,
+    pub swc_long: Option<i32>,
+    pub num_lengths: Option<u16>,
+    pub normalized_power: Option<u16>,
+    pub training_stress_score: Option<f64>,
+    pub intensity_factor: Option<f64>,
+    pub left_right_balance: Option<field_types::LeftRightBalance100>,
+    pub avg_stroke_count: Option<f64>,
+    pub avg_stroke_distance: Option<f64>,
+    pub swim_stroke: Option<field_types::SwimStroke>,
+    pub pool_length: Option<f64>,
+    pub threshold_power: Option<u16>,
+    pub pool_length_unit: Option<field_types::DisplayMeasure>,
+    pub num_active_lengths: Option<u16>,
+    pub total_work: Option<u32>,
+    pub avg_altitude: Option<f64>,
+    pub max_altitude: Option<f64>,
+    pub gps_accuracy: Option<u8>,
+    pub avg_grade: Option<f64>,
+    pub avg_pos_grade: Option<f64>,
+    pub avg_neg_grade: Option<f64>,
+    pub max_pos_grade: Option<f64>,
+    pub max_neg_grade: Option<f64>,
+    pub avg_temperature: Option<i8>,
+    pub max_temperature: Option<i8>,
+    pub total_moving_time: Option<f64>,
+    pub avg_pos_vertical_speed: Option<f64>,
+    pub avg_neg_vertical_speed: Option<f64>,
+    pub max_pos_vertical_speed: Option<f64>,
+    pub max_neg_vertical_speed: Option<f64>,
+    pub min_heart_rate: Option<u8>,
+    pub time_in_hr_zone: Option<Vec<f64>>,
+    pub time_in_speed_zone: Option<Vec<f64>>,
+    pub time_in_cadence_zone: Option<Vec<f64>>,
+    pub time_in_power_zone: Option<Vec<f64>>,
+    pub avg_lap_time: Option<f64>,
+    pub best_lap_index: Option<u16>,
+    pub min_altitude: Option<f64>,
+    pub player_score: Option<u16>,
+    pub opponent_score: Option<u16>,
+    pub opponent_name: Option<String>,
+    pub stroke_count: Option<Vec<u16>>,
+    pub zone_count: Option<Vec<u16>>,
+    pub max_ball_speed: Option<f64>,
+    pub avg_ball_speed: Option<f64>,
+    pub avg_vertical_oscillation: Option<f64>,
+    pub avg_stance_time_percent: Option<f64>,
+    pub avg_stance_time: Option<f64>,
+    pub avg_fractional_cadence: Option<f64>,
+    pub max_fractional_cadence: Option<f64>,
+    pub total_fractional_cycles: Option<f64>,
+    pub avg_total_hemoglobin_conc: Option<f64>,
+    pub min_total_hemoglobin_conc: Option<f64>,
+    pub max_total_hemoglobin_conc: Option<f64>,
+    pub avg_saturated_hemoglobin_percent: Option<f64>,
+    pub min_saturated_hemoglobin_percent: Option<f64>,
+    pub max_saturated_hemoglobin_percent: Option<f64>,
+    pub avg_left_torque_effectiveness: Option<f64>,
+    pub avg_right_torque_effectiveness: Option<f64>,
+    pub avg_left_pedal_smoothness: Option<f64>,
+    pub avg_right_pedal_smoothness: Option<f64>,
+    pub avg_combined_pedal_smoothness: Option<f64>,
+    pub sport_index: Option<u8>,
+    pub time_standing: Option<f64>,
+    pub stand_count: Option<u16>,
+    pub avg_left_pco: Option<i8>,
+    pub avg_right_pco: Option<i8>,
+    pub avg_left_power_phase: Option<Vec<f64>>,
+    pub avg_left_power_phase_peak: Option<Vec<f64>>,
+    pub avg_right_power_phase: Option<Vec<f64>>,
+    pub avg_right_power_phase_peak: Option<Vec<f64>>,
+    pub avg_power_position: Option<Vec<u16>>,
+    pub max_power_position: Option<Vec<u16>>,
+    pub avg_cadence_position: Option<Vec<u8>>,
+    pub max_cadence_position: Option<Vec<u8>>,
+    pub enhanced_avg_speed: Option<f64>,
+    pub enhanced_max_speed: Option<f64>,
+    pub enhanced_avg_altitude: Option<f64>,
+    pub enhanced_min_altitude: Option<f64>,
+    pub enhanced_max_altitude: Option<f64>,
+    pub avg_lev_motor_power: Option<u16>,
+    pub max_lev_motor_power: Option<u16>,
+    pub lev_battery_consumption: Option<f64>,
+    pub avg_vertical_ratio: Option<f64>,
+    pub avg_stance_time_balance: Option<f64>,
+    pub avg_step_length: Option<f64>,
+    pub total_anaerobic_training_effect: Option<f64>,
+    pub avg_vam: Option<f64>,
+    pub avg_depth: Option<f64>,
+    pub max_depth: Option<f64>,
+    pub surface_interval: Option<u32>,
+    pub start_cns: Option<u8>,
+    pub end_cns: Option<u8>,
+    pub start_n2: Option<u16>,
+    pub end_n2: Option<u16>,
+    pub avg_respiration_rate: Option<u8>,
+    pub max_respiration_rate: Option<u8>,
+    pub min_respiration_rate: Option<u8>,
+    pub min_temperature: Option<i8>,
+    pub o2_toxicity: Option<u16>,
+    pub dive_number: Option<u32>,
+    pub training_load_peak: Option<f64>,
+    pub enhanced_avg_respiration_rate: Option<f64>,
+    pub enhanced_max_respiration_rate: Option<f64>,
+    pub enhanced_min_respiration_rate: Option<f64>,
+    pub total_grit: Option<f32>,
+    pub total_flow: Option<f32>,
+    pub jump_count: Option<u16>,
+    pub avg_grit: Option<f32>,
+    pub avg_flow: Option<f32>,
+    pub total_fractional_ascent: Option<f64>,
+    pub total_fractional_descent: Option<f64>,
+    pub avg_core_temperature: Option<f64>,
+    pub min_core_temperature: Option<f64>,
+    pub max_core_temperature: Option<f64>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for Session {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Session)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "event" => message.event = Some(typed_field_value(field)?),
+                "event_type" => message.event_type = Some(typed_field_value(field)?),
+                "start_time" => message.start_time = Some(typed_field_value(field)?),
+                "start_position_lat" => {
+                    message.start_position_lat = Some(typed_field_value(field)?)
+                }
+                "start_position_long" => {
+                    message.start_position_long = Some(typed_field_value(field)?)
+                }
+                "sport" => message.sport = Some(typed_field_value(field)?),
+                "sub_sport" => message.sub_sport = Some(typed_field_value(field)?),
+                "total_elapsed_time" => {
+                    message.total_elapsed_time = Some(typed_field_value(field)?)
+                }
+                "total_timer_time" => message.total_timer_time = Some(typed_field_value(field)?),
+                "total_distance" => message.total_distance = Some(typed_field_value(field)?),
+                "total_cycles" => message.total_cycles = Some(typed_field_value(field)?),
+                "total_strides" => message.total_strides = Some(typed_field_value(field)?),
+                "total_strokes" => message.total_strokes = Some(typed_field_value(field)?),
+                "total_calories" => message.total_calories = Some(typed_field_value(field)?),
+                "total_fat_calories" => {
+                    message.total_fat_calories = Some(typed_field_value(field)?)
+                }
+                "avg_speed" => message.avg_speed = Some(typed_field_value(field)?),
+                "max_speed" => message.max_speed = Some(typed_field_value(field)?),
+                "avg_heart_rate" => message.avg_heart_rate = Some(typed_field_value(field)?),
+                "max_heart_rate" => message.max_heart_rate = Some(typed_field_value(field)?),
+                "avg_cadence" => message.avg_cadence = Some(typed_field_value(field)?),
+                "avg_running_cadence" => {
+                    message.avg_running_cadence = Some(typed_field_value(field)?)
+                }
+                "max_cadence" => message.max_cadence = Some(typed_field_value(field)?),
+                "max_running_cadence" => {
+                    message.max_running_cadence = Some(typed_field_value(field)?)
+                }
+                "avg_power" => message.avg_power = Some(typed_field_value(field)?),
+                "max_power" => message.max_power = Some(typed_field_value(field)?),
+                "total_ascent" => message.total_ascent = Some(typed_field_value(field)?),
+                "total_descent" => message.total_descent = Some(typed_field_value(field)?),
+                "total_training_effect" => {
+                    message.total_training_effect = Some(typed_field_value(field)?)
+                }
+                "first_lap_index" => message.first_lap_index = Some(typed_field_value(field)?),
+                "num_laps" => message.num_laps = Some(typed_field_value(field)?),
+                "event_group" => message.event_group = Some(typed_field_value(field)?),
+                "trigger" => message.trigger = Some(typed_field_value(field)?),
+                "nec_lat" => message.nec_lat = Some(typed_field_value(field)?),
+                "nec_long" => message.nec_long = Some(typed_field_value(field)?),
+                "swc_lat" => message.swc_lat = Some(typed_field_value(field)?),
+                "swc_long" => message.swc_long = Some(typed_field_value(field)?),
+                "num_lengths" => message.num_lengths = Some(typed_field_value(field)?),
+                "normalized_power" => message.normalized_power = Some(typed_field_value(field)?),
+                "training_stress_score" => {
+                    message.training_stress_score = Some(typed_field_value(field)?)
+                }
+                "intensity_factor" => message.intensity_factor = Some(typed_field_value(field)?),
+                "left_right_balance" => {
+                    message.left_right_balance = Some(typed_field_value(field)?)
+                }
+                "avg_stroke_count" => message.avg_stroke_count = Some(typed_field_value(field)?),
+                "avg_stroke_distance" => {
+                    message.avg_stroke_distance = Some(typed_field_value(field)?)
+                }
+                "swim_stroke" => message.swim_stroke = Some(typed_field_value(field)?),
+                "pool_length" => message.pool_length = Some(typed_field_value(field)?),
+                "threshold_power" => message.threshold_power = Some(typed_field_value(field)?),
+                "pool_length_unit" => message.pool_length_unit = Some(typed_field_value(field)?),
+                "num_active_lengths" => {
+                    message.num_active_lengths = Some(typed_field_value(field)?)
+                }
+                "total_work" => message.total_work = Some(typed_field_value(field)?),
+                "avg_altitude" => message.avg_altitude = Some(typed_field_value(field)?),
+                "max_altitude" => message.max_altitude = Some(typed_field_value(field)?),
+                "gps_accuracy" => message.gps_accuracy = Some(typed_field_value(field)?),
+                "avg_grade" => message.avg_grade = Some(typed_field_value(field)?),
+                "avg_pos_grade" => message.avg_pos_grade = Some(typed_field_value(field)?),
+                "avg_neg_grade" => message.avg_neg_grade = Some(typed_field_value(field)?),
+                "max_pos_grade" => message.max_pos_grade = Some(typed_field_value(field)?),
+                "max_neg_grade" => message.max_neg_grade = Some(typed_field_value(field)?),
+                "avg_temperature" => message.avg_temperature = Some(typed_field_value(field)?),
+                "max_temperature" => message.max_temperature = Some(typed_field_value(field)?),
+                "total_moving_time" => message.total_moving_time = Some(typed_field_value(field)?),
+                "avg_pos_vertical_speed" => {
+                    message.avg_pos_vertical_speed = Some(typed_field_value(field)?)
+                }
+                "avg_neg_vertical_speed" => {
+                    message.avg_neg_vertical_speed = Some(typed_field_value(field)?)
+                }
+                "max_pos_vertical_speed" => {
+                    message.max_pos_vertical_speed = Some(typed_field_value(field)?)
+                }
+                "max_neg_vertical_speed" => {
+                    message.max_neg_vertical_speed = Some(typed_field_value(field)?)
+                }
+                "min_heart_rate" => message.min_heart_rate = Some(typed_field_value(field)?),
+                "time_in_hr_zone" => message.time_in_hr_zone = Some(typed_field_value(field)?),
+                "time_in_speed_zone" => {
+                    message.time_in_speed_zone = Some(typed_field_value(field)?)
+                }
+                "time_in_cadence_zone" => {
+                    message.time_in_cadence_zone = Some(typed_field_value(field)?)
+                }
+                "time_in_power_zone" => {
+                    message.time_in_power_zone = Some(typed_field_value(field)?)
+                }
+                "avg_lap_time" => message.avg_lap_time = Some(typed_field_value(field)?),
+                "best_lap_index" => message.best_lap_index = Some(typed_field_value(field)?),
+                "min_altitude" => message.min_altitude = Some(typed_field_value(field)?),
+                "player_score" => message.player_score = Some(typed_field_value(field)?),
+                "opponent_score" => message.opponent_score = Some(typed_field_value(field)?),
+                "opponent_name" => message.opponent_name = Some(typed_field_value(field)?),
+                "stroke_count" => message.stroke_count = Some(typed_field_value(field)?),
+                "zone_count" => message.zone_count = Some(typed_field_value(field)?),
+                "max_ball_speed" => message.max_ball_speed = Some(typed_field_value(field)?),
+                "avg_ball_speed" => message.avg_ball_speed = Some(typed_field_value(field)?),
+                "avg_vertical_oscillation" => {
+                    message.avg_vertical_oscillation = Some(typed_field_value(field)?)
+                }
+                "avg_stance_time_percent" => {
+                    message.avg_stance_time_percent = Some(typed_field_value(field)?)
+                }
+                "avg_stance_time" => message.avg_stance_time = Some(typed_field_value(field)?),
+                "avg_fractional_cadence" => {
+                    message.avg_fractional_cadence = Some(typed_field_value(field)?)
+                }
+                "max_fractional_cadence" => {
+                    message.max_fractional_cadence = Some(typed_field_value(field)?)
+                }
+                "total_fractional_cycles" => {
+                    message.total_fractional_cycles = Some(typed_field_value(field)?)
+                }
+                "avg_total_hemoglobin_conc" => {
+                    message.avg_total_hemoglobin_conc = Some(typed_field_value(field)?)
+                }
+                "min_total_hemoglobin_conc" => {
+                    message.min_total_hemoglobin_conc = Some(typed_field_value(field)?)
+                }
+                "max_total_hemoglobin_conc" => {
+                    message.max_total_hemoglobin_conc = Some(typed_field_value(field)?)
+                }
+                "avg_saturated_hemoglobin_percent" => {
+                    message.avg_saturated_hemoglobin_percent = Some(typed_field_value(field)?)
+                }
+                "min_saturated_hemoglobin_percent" => {
+                    message.min_saturated_hemoglobin_percent = Some(typed_field_value(field)?)
+                }
+                "max_saturated_hemoglobin_percent" => {
+                    message.max_saturated_hemoglobin_percent = Some(typed_field_value(field)?)
+                }
+                "avg_left_torque_effectiveness" => {
+                    message.avg_left_torque_effectiveness = Some(typed_field_value(field)?)
+                }
+                "avg_right_torque_effectiveness" => {
+                    message.avg_right_torque_effectiveness = Some(typed_field_value(field)?)
+                }
+                "avg_left_pedal_smoothness" => {
+                    message.avg_left_pedal_smoothness = Some(typed_field_value(field)?)
+                }
+                "avg_right_pedal_smoothness" => {
+                    message.avg_right_pedal_smoothness = Some(typed_field_value(field)?)
+                }
+                "avg_combined_pedal_smoothness" => {
+                    message.avg_combined_pedal_smoothness = Some(typed_field_value(field)?)
+                }
+                "sport_index" => message.sport_index = Some(typed_field_value(field)?),
+                "time_standing" => message.time_standing = Some(typed_field_value(field)?),
+                "stand_count" => message.stand_count = Some(typed_field_value(field)?),
+                "avg_left_pco" => message.avg_left_pco = Some(typed_field_value(field)?),
+                "avg_right_pco" => message.avg_right_pco = Some(typed_field_value(field)?),
+                "avg_left_power_phase" => {
+                    message.avg_left_power_phase = Some(typed_field_value(field)?)
+                }
+                "avg_left_power_phase_peak" => {
+                    message.avg_left_power_phase_peak = Some(typed_field_value(field)?)
+                }
+                "avg_right_power_phase" => {
+                    message.avg_right_power_phase = Some(typed_field_value(field)?)
+                }
+                "avg_right_power_phase_peak" => {
+                    message.avg_right_power_phase_peak = Some(typed_field_value(field)?)
+                }
+                "avg_power_position" => {
+                    message.avg_power_position = Some(typed_field_value(field)?)
+                }
+                "max_power_position" => {
+                    message.max_power_position = Some(typed_field_value(field)?)
+                }
+                "avg_cadence_position" => {
+                    message.avg_cadence_position = Some(typed_field_value(field)?)
+                }
+                "max_cadence_position" => {
+                    message.max_cadence_position = Some(typed_field_value(field)?)
+                }
+                "enhanced_avg_speed" => {
+                    message.enhanced_avg_speed = Some(typed_field_value(field)?)
+                }
+                "enhanced_max_speed" => {
+                    message.enhanced_max_speed = Some(typed_field_value(field)?)
+                }
+                "enhanced_avg_altitude" => {
+                    message.enhanced_avg_altitude = Some(typed_field_value(field)?)
+                }
+                "enhanced_min_altitude" => {
+                    message.enhanced_min_altitude = Some(typed_field_value(field)?)
+                }
+                "enhanced_max_altitude" => {
+                    message.enhanced_max_altitude = Some(typed_field_value(field)?)
+                }
+                "avg_lev_motor_power" => {
+                    message.avg_lev_motor_power = Some(typed_field_value(field)?)
+                }
+                "max_lev_motor_power" => {
+                    message.max_lev_motor_power = Some(typed_field_value(field)?)
+                }
+                "lev_battery_consumption" => {
+                    message.lev_battery_consumption = Some(typed_field_value(field)?)
+                }
+                "avg_vertical_ratio" => {
+                    message.avg_vertical_ratio = Some(typed_field_value(field)?)
+                }
+                "avg_stance_time_balance" => {
+                    message.avg_stance_time_balance = Some(typed_field_value(field)?)
+                }
+                "avg_step_length" => message.avg_step_length = Some(typed_field_value(field)?),
+                "total_anaerobic_training_effect" => {
+                    message.total_anaerobic_training_effect = Some(typed_field_value(field)?)
+                }
+                "avg_vam" => message.avg_vam = Some(typed_field_value(field)?),
+                "avg_depth" => message.avg_depth = Some(typed_field_value(field)?),
+                "max_depth" => message.max_depth = Some(typed_field_value(field)?),
+                "surface_interval" => message.surface_interval = Some(typed_field_value(field)?),
+                "start_cns" => message.start_cns = Some(typed_field_value(field)?),
+                "end_cns" => message.end_cns = Some(typed_field_value(field)?),
+                "start_n2" => message.start_n2 = Some(typed_field_value(field)?),
+                "end_n2" => message.end_n2 = Some(typed_field_value(field)?),
+                "avg_respiration_rate" => {
+                    message.avg_respiration_rate = Some(typed_field_value(field)?)
+                }
+                "max_respiration_rate" => {
+                    message.max_respiration_rate = Some(typed_field_value(field)?)
+                }
+                "min_respiration_rate" => {
+                    message.min_respiration_rate = Some(typed_field_value(field)?)
+                }
+                "min_temperature" => message.min_temperature = Some(typed_field_value(field)?),
+                "o2_toxicity" => message.o2_toxicity = Some(typed_field_value(field)?),
+                "dive_number" => message.dive_number = Some(typed_field_value(field)?),
+                "training_load_peak" => {
+                    message.training_load_peak = Some(typed_field_value(field)?)
+                }
+                "enhanced_avg_respiration_rate" => {
+                    message.enhanced_avg_respiration_rate = Some(typed_field_value(field)?)
+                }
+                "enhanced_max_respiration_rate" => {
+                    message.enhanced_max_respiration_rate = Some(typed_field_value(field)?)
+                }
+                "enhanced_min_respiration_rate" => {
+                    message.enhanced_min_respiration_rate = Some(typed_field_value(field)?)
+                }
+                "total_grit" => message.total_grit = Some(typed_field_value(field)?),
+                "total_flow" => message.total_flow = Some(typed_field_value(field)?),
+                "jump_count" => message.jump_count = Some(typed_field_value(field)?),
+                "avg_grit" => message.avg_grit = Some(typed_field_value(field)?),
+                "avg_flow" => message.avg_flow = Some(typed_field_value(field)?),
+                "total_fractional_ascent" => {
+                    message.total_fractional_ascent = Some(typed_field_value(field)?)
+                }
+                "total_fractional_descent" => {
+                    message.total_fractional_descent = Some(typed_field_value(field)?)
+                }
+                "avg_core_temperature" => {
+                    message.avg_core_temperature = Some(typed_field_value(field)?)
+                }
+                "min_core_temperature" => {
+                    message.min_core_temperature = Some(typed_field_value(field)?)
+                }
+                "max_core_temperature" => {
+                    message.max_core_temperature = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Session> for FitDataRecord {
+    fn from(message: &Session) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Session);
+        push_typed_field(&mut record, "event", &message.event);
+        push_typed_field(&mut record, "event_type", &message.event_type);
+        push_typed_field(&mut record, "start_time", &message.start_time);
+        push_typed_field(
+            &mut record,
+            "start_position_lat",
+            &message.start_position_lat,
+        );
+        push_typed_field(
+            &mut record,
+            "start_position_long",
+            &message.start_position_long,
+        );
+        push_typed_field(&mut record, "sport", &message.sport);
+        push_typed_field(&mut record, "sub_sport", &message.sub_sport);
+        push_typed_field(
+            &mut record,
+            "total_elapsed_time",
+            &message.total_elapsed_time,
+        );
+        push_typed_field(&mut record, "total_timer_time", &message.total_timer_time);
+        push_typed_field(&mut record, "total_distance", &message.total_distance);
+        push_typed_field(&mut record, "total_cycles", &message.total_cycles);
+        push_typed_field(&mut record, "total_strides", &message.total_strides);
+        push_typed_field(&mut record, "total_strokes", &message.total_strokes);
+        push_typed_field(&mut record, "total_calories", &message.total_calories);
+        push_typed_field(
+            &mut record,
+            "total_fat_calories",
+            &message.total_fat_calories,
+        );
+        push_typed_field(&mut record, "avg_speed", &message.avg_speed);
+        push_typed_field(&mut record, "max_speed", &message.max_speed);
+        push_typed_field(&mut record, "avg_heart_rate", &message.avg_heart_rate);
+        push_typed_field(&mut record, "max_heart_rate", &message.max_heart_rate);
+        push_typed_field(&mut record, "avg_cadence", &message.avg_cadence);
+        push_typed_field(
+            &mut record,
+            "avg_running_cadence",
+            &message.avg_running_cadence,
+        );
+        push_typed_field(&mut record, "max_cadence", &message.max_cadence);
+        push_typed_field(
+            &mut record,
+            "max_running_cadence",
+            &message.max_running_cadence,
+        );
+        push_typed_field(&mut record, "avg_power", &message.avg_power);
+        push_typed_field(&mut record, "max_power", &message.max_power);
+        push_typed_field(&mut record, "total_ascent", &message.total_ascent);
+        push_typed_field(&mut record, "total_descent", &message.total_descent);
+        push_typed_field(
+            &mut record,
+            "total_training_effect",
+            &message.total_training_effect,
+        );
+        push_typed_field(&mut record, "first_lap_index", &message.first_lap_index);
+        push_typed_field(&mut record, "num_laps", &message.num_laps);
+        push_typed_field(&mut record, "event_group", &message.event_group);
+        push_typed_field(&mut record, "trigger", &message.trigger);
+        push_typed_field(&mut record, "nec_lat", &message.nec_lat);
+        push_typed_field(&mut record, "nec_long", &message.nec_long);
+        push_typed_field(&mut record, "swc_lat", &message.swc_lat);
+        push_typed_field(&mut record, "swc_long", &message.swc_long);
+        push_typed_field(&mut record, "num_lengths", &message.num_lengths);
+        push_typed_field(&mut record, "normalized_power", &message.normalized_power);
+        push_typed_field(
+            &mut record,
+            "training_stress_score",
+            &message.training_stress_score,
+        );
+        push_typed_field(&mut record, "intensity_factor", &message.intensity_factor);
+        push_typed_field(
+            &mut record,
+            "left_right_balance",
+            &message.left_right_balance,
+        );
+        push_typed_field(&mut record, "avg_stroke_count", &message.avg_stroke_count);
+        push_typed_field(
+            &mut record,
+            "avg_stroke_distance",
+            &message.avg_stroke_distance,
+        );
+        push_typed_field(&mut record, "swim_stroke", &message.swim_stroke);
+        push_typed_field(&mut record, "pool_length", &message.pool_length);
+        push_typed_field(&mut record, "threshold_power", &message.threshold_power);
+        push_typed_field(&mut record, "pool_length_unit", &message.pool_length_unit);
+        push_typed_field(
+            &mut record,
+            "num_active_lengths",
+            &message.num_active_lengths,
+        );
+        push_typed_field(&mut record, "total_work", &message.total_work);
+        push_typed_field(&mut record, "avg_altitude", &message.avg_altitude);
+        push_typed_field(&mut record, "max_altitude", &message.max_altitude);
+        push_typed_field(&mut record, "gps_accuracy", &message.gps_accuracy);
+        push_typed_field(&mut record, "avg_grade", &message.avg_grade);
+        push_typed_field(&mut record, "avg_pos_grade", &message.avg_pos_grade);
+        push_typed_field(&mut record, "avg_neg_grade", &message.avg_neg_grade);
+        push_typed_field(&mut record, "max_pos_grade", &message.max_pos_grade);
+        push_typed_field(&mut record, "max_neg_grade", &message.max_neg_grade);
+        push_typed_field(&mut record, "avg_temperature", &message.avg_temperature);
+        push_typed_field(&mut record, "max_temperature", &message.max_temperature);
+        push_typed_field(&mut record, "total_moving_time", &message.total_moving_time);
+        push_typed_field(
+            &mut record,
+            "avg_pos_vertical_speed",
+            &message.avg_pos_vertical_speed,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_neg_vertical_speed",
+            &message.avg_neg_vertical_speed,
+        );
+        push_typed_field(
+            &mut record,
+            "max_pos_vertical_speed",
+            &message.max_pos_vertical_speed,
+        );
+        push_typed_field(
+            &mut record,
+            "max_neg_vertical_speed",
+            &message.max_neg_vertical_speed,
+        );
+        push_typed_field(&mut record, "min_heart_rate", &message.min_heart_rate);
+        push_typed_field(&mut record, "time_in_hr_zone", &message.time_in_hr_zone);
+        push_typed_field(
+            &mut record,
+            "time_in_speed_zone",
+            &message.time_in_speed_zone,
+        );
+        push_typed_field(
+            &mut record,
+            "time_in_cadence_zone",
+            &message.time_in_cadence_zone,
+        );
+        push_typed_field(
+            &mut record,
+            "time_in_power_zone",
+            &message.time_in_power_zone,
+        );
+        push_typed_field(&mut record, "avg_lap_time", &message.avg_lap_time);
+        push_typed_field(&mut record, "best_lap_index", &message.best_lap_index);
+        push_typed_field(&mut record, "min_altitude", &message.min_altitude);
+        push_typed_field(&mut record, "player_score", &message.player_score);
+        push_typed_field(&mut record, "opponent_score", &message.opponent_score);
+        push_typed_field(&mut record, "opponent_name", &message.opponent_name);
+        push_typed_field(&mut record, "stroke_count", &message.stroke_count);
+        push_typed_field(&mut record, "zone_count", &message.zone_count);
+        push_typed_field(&mut record, "max_ball_speed", &message.max_ball_speed);
+        push_typed_field(&mut record, "avg_ball_speed", &message.avg_ball_speed);
+        push_typed_field(
+            &mut record,
+            "avg_vertical_oscillation",
+            &message.avg_vertical_oscillation,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_stance_time_percent",
+            &message.avg_stance_time_percent,
+        );
+        push_typed_field(&mut record, "avg_stance_time", &message.avg_stance_time);
+        push_typed_field(
+            &mut record,
+            "avg_fractional_cadence",
+            &message.avg_fractional_cadence,
+        );
+        push_typed_field(
+            &mut record,
+            "max_fractional_cadence",
+            &message.max_fractional_cadence,
+        );
+        push_typed_field(
+            &mut record,
+            "total_fractional_cycles",
+            &message.total_fractional_cycles,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_total_hemoglobin_conc",
+            &message.avg_total_hemoglobin_conc,
+        );
+        push_typed_field(
+            &mut record,
+            "min_total_hemoglobin_conc",
+            &message.min_total_hemoglobin_conc,
+        );
+        push_typed_field(
+            &mut record,
+            "max_total_hemoglobin_conc",
+            &message.max_total_hemoglobin_conc,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_saturated_hemoglobin_percent",
+            &message.avg_saturated_hemoglobin_percent,
+        );
+        push_typed_field(
+            &mut record,
+            "min_saturated_hemoglobin_percent",
+            &message.min_saturated_hemoglobin_percent,
+        );
+        push_typed_field(
+            &mut record,
+            "max_saturated_hemoglobin_percent",
+            &message.max_saturated_hemoglobin_percent,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_left_torque_effectiveness",
+            &message.avg_left_torque_effectiveness,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_right_torque_effectiveness",
+            &message.avg_right_torque_effectiveness,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_left_pedal_smoothness",
+            &message.avg_left_pedal_smoothness,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_right_pedal_smoothness",
+            &message.avg_right_pedal_smoothness,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_combined_pedal_smoothness",
+            &message.avg_combined_pedal_smoothness,
+        );
+        push_typed_field(&mut record, "sport_index", &message.sport_index);
+        push_typed_field(&mut record, "time_standing", &message.time_standing);
+        push_typed_field(&mut record, "stand_count", &message.stand_count);
+        push_typed_field(&mut record, "avg_left_pco", &message.avg_left_pco);
+        push_typed_field(&mut record, "avg_right_pco", &message.avg_right_pco);
+        push_typed_field(
+            &mut record,
+            "avg_left_power_phase",
+            &message.avg_left_power_phase,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_left_power_phase_peak",
+            &message.avg_left_power_phase_peak,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_right_power_phase",
+            &message.avg_right_power_phase,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_right_power_phase_peak",
+            &message.avg_right_power_phase_peak,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_power_position",
+            &message.avg_power_position,
+        );
+        push_typed_field(
+            &mut record,
+            "max_power_position",
+            &message.max_power_position,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_cadence_position",
+            &message.avg_cadence_position,
+        );
+        push_typed_field(
+            &mut record,
+            "max_cadence_position",
+            &message.max_cadence_position,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_avg_speed",
+            &message.enhanced_avg_speed,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_max_speed",
+            &message.enhanced_max_speed,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_avg_altitude",
+            &message.enhanced_avg_altitude,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_min_altitude",
+            &message.enhanced_min_altitude,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_max_altitude",
+            &message.enhanced_max_altitude,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_lev_motor_power",
+            &message.avg_lev_motor_power,
+        );
+        push_typed_field(
+            &mut record,
+            "max_lev_motor_power",
+            &message.max_lev_motor_power,
+        );
+        push_typed_field(
+            &mut record,
+            "lev_battery_consumption",
+            &message.lev_battery_consumption,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_vertical_ratio",
+            &message.avg_vertical_ratio,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_stance_time_balance",
+            &message.avg_stance_time_balance,
+        );
+        push_typed_field(&mut record, "avg_step_length", &message.avg_step_length);
+        push_typed_field(
+            &mut record,
+            "total_anaerobic_training_effect",
+            &message.total_anaerobic_training_effect,
+        );
+        push_typed_field(&mut record, "avg_vam", &message.avg_vam);
+        push_typed_field(&mut record, "avg_depth", &message.avg_depth);
+        push_typed_field(&mut record, "max_depth", &message.max_depth);
+        push_typed_field(&mut record, "surface_interval", &message.surface_interval);
+        push_typed_field(&mut record, "start_cns", &message.start_cns);
+        push_typed_field(&mut record, "end_cns", &message.end_cns);
+        push_typed_field(&mut record, "start_n2", &message.start_n2);
+        push_typed_field(&mut record, "end_n2", &message.end_n2);
+        push_typed_field(
+            &mut record,
+            "avg_respiration_rate",
+            &message.avg_respiration_rate,
+        );
+        push_typed_field(
+            &mut record,
+            "max_respiration_rate",
+            &message.max_respiration_rate,
+        );
+        push_typed_field(
+            &mut record,
+            "min_respiration_rate",
+            &message.min_respiration_rate,
+        );
+        push_typed_field(&mut record, "min_temperature", &message.min_temperature);
+        push_typed_field(&mut record, "o2_toxicity", &message.o2_toxicity);
+        push_typed_field(&mut record, "dive_number", &message.dive_number);
+        push_typed_field(
+            &mut record,
+            "training_load_peak",
+            &message.training_load_peak,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_avg_respiration_rate",
+            &message.enhanced_avg_respiration_rate,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_max_respiration_rate",
+            &message.enhanced_max_respiration_rate,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_min_respiration_rate",
+            &message.enhanced_min_respiration_rate,
+        );
+        push_typed_field(&mut record, "total_grit", &message.total_grit);
+        push_typed_field(&mut record, "total_flow", &message.total_flow);
+        push_typed_field(&mut record, "jump_count", &message.jump_count);
+        push_typed_field(&mut record, "avg_grit", &message.avg_grit);
+        push_typed_field(&mut record, "avg_flow", &message.avg_flow);
+        push_typed_field(
+            &mut record,
+            "total_fractional_ascent",
+            &message.total_fractional_ascent,
+        );
+        push_typed_field(
+            &mut record,
+            "total_fractional_descent",
+            &message.total_fractional_descent,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_core_temperature",
+            &message.avg_core_temperature,
+        );
+        push_typed_field(
+            &mut record,
+            "min_core_temperature",
+            &message.min_core_temperature,
+        );
+        push_typed_field(
+            &mut record,
+            "max_core_temperature",
+            &message.max_core_temperature,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Lap {
+    pub event: Option<field_types::Event>,
+    pub event_type: Option<field_types::EventType>,
+    pub start_time: Option<DateTime<TimestampTz>>,
+    pub start_position_lat: Option<i32>,
+    pub start_position_long: Option<i32>,
+    pub end_position_lat: Option<i32>,
+    pub end_position_long: Option<i32>,
+    pub total_elapsed_time: Option<f64>,
+    pub total_timer_time: Option<f64>,
+    pub total_distance: Option<f64>,
+    pub total_cycles: Option<u32>,
+    pub total_strides: Option<u32>,
+    pub total_strokes: Option<u32>,
+    pub total_calories: Option<u16>,
+    pub total_fat_calories: Option<u16>,
+    pub avg_speed: Option<f64>,
+    pub max_speed: Option<f64>,
+    pub avg_heart_rate: Option<u8>,
+    pub max_heart_rate: Option<u8>,
+    pub avg_cadence: Option<u8>,
+    pub avg_running_cadence: Option<u8>,
+    pub max_cadence: Option<u8>,
+    pub max_running_cadence: Option<u8>,
+    pub avg_power: Option<u16>,
+    pub max_power: Option<u16>,
+    pub total_ascent: Option<u16>,
+    pub total_descent: Option<u16>,
+    pub intensity: Option<field_types::Intensity>,
+    pub lap_trigger: Option<field_types::LapTrigger>,
+    pub sport: Option<field_types::Sport>,
+    pub event_group: Option<u8>,
+    pub num_lengths: Option<u16>,
+    pub normalized_power: Option<u16>,
+    pub left_right_balance: Option<field_types::LeftRightBalance100>,
+    pub first_length_index: Option<u16>,
+    pub avg_stroke_distance: Option<f64>,
+    pub swim_stroke: Option<field_types::SwimStroke>,
+    pub sub_sport: Option<field_types::SubSport>,
+    pub num_active_lengths: Option<u16>,
+    pub total_work: Option<u32>,
+    pub avg_altitude: Option<f64>,
+    pub max_altitude: Option<f64>,
+    pub gps_accuracy: Option<u8>,
+    pub avg_grade: Option<f64>,
+    pub avg_pos_grade: Option<f64>,
+    pub avg_neg_grade: Option<f64>,
+    pub max_pos_grade: Option<f64>,
+    pub max_neg_grade: Option<f64>,
+    pub avg_temperature: Option<i8>,
+    pub max_temperature: Option<i8>,
+    pub total_moving_time: Option<f64>,
+    pub avg_pos_vertical_speed: Option<f64>,
+    pub avg_neg_vertical_speed: Option<f64>,
+    pub max_pos_vertical_speed: Option<f64>,
+    pub max_neg_vertical_speed: Option<f64>,
+    pub time_in_hr_zone: Option<Vec<f64>>,
+    pub time_in_speed_zone: Option<Vec<f64>>,
+    pub time_in_cadence_zone: Option<Vec<f64>>,
+    pub time_in_power_zone: Option<Vec<f64>>,
+    pub repetition_num: Option<u16>,
+    pub min_altitude: Option<f64>,
+    pub min_heart_rate: Option<u8>,
+    pub wkt_step_index: Option<field_types::MessageIndex>,
+    pub opponent_score: Option<u16>,
+    pub stroke_count: Option<Vec<u16>>,
+    pub zone_count: Option<Vec<u16>>,
+    pub avg_vertical_oscillation: Option<f64>,
+    pub avg_stance_time_percent: Option<f64>,
+    pub avg_stance_time: Option<f64>,
+    pub avg_fractional_cadence: Option<f64>,
+    pub max_fractional_cadence: Option<f64>,
+    pub total_fractional_cycles: Option<f64>,
+    pub player_score: Option<u16>,
+    pub avg_total_hemoglobin_conc: Option<f64>,
+    pub min_total_hemoglobin_conc: Option<f64>,
+    pub max_total_hemoglobin_conc: Option<f64>,
+    pub avg_saturated_hemoglobin_percent: Option<f64>,
+    pub min_saturated_hemoglobin_percent: Option<f64>,
+    pub max_saturated_hemoglobin_percent: Option<f64>,
+    pub avg_left_torque_effectiveness: Option<f64>,
+    pub avg_right_torque_effectiveness: Option<f64>,
+    pub avg_left_pedal_smoothness: Option<f64>,
+    pub avg_right_pedal_smoothness: Option<f64>,
+    pub avg_combined_pedal_smoothness: Option<f64>,
+    pub time_standing: Option<f64>,
+    pub stand_count: Option<u16>,
+    pub avg_left_pco: Option<i8>,
+    pub avg_right_pco: Option<i8>,
+    pub avg_left_power_phase: Option<Vec<f64>>,
+    pub avg_left_power_phase_peak: Option<Vec<f64>>,
+    pub avg_right_power_phase: Option<Vec<f64>>,
+    pub avg_right_power_phase_peak: Option<Vec<f64>>,
+    pub avg_power_position: Option<Vec<u16>>,
+    pub max_power_position: Option<Vec<u16>>,
+    pub avg_cadence_position: Option<Vec<u8>>,
+    pub max_cadence_position: Option<Vec<u8>>,
+    pub enhanced_avg_speed: Option<f64>,
+    pub enhanced_max_speed: Option<f64>,
+    pub enhanced_avg_altitude: Option<f64>,
+    pub enhanced_min_altitude: Option<f64>,
+    pub enhanced_max_altitude: Option<f64>,
+    pub avg_lev_motor_power: Option<u16>,
+    pub max_lev_motor_power: Option<u16>,
+    pub lev_battery_consumption: Option<f64>,
+    pub avg_vertical_ratio: Option<f64>,
+    pub avg_stance_time_balance: Option<f64>,
+    pub avg_step_length: Option<f64>,
+    pub avg_vam: Option<f64>,
+    pub avg_depth: Option<f64>,
+    pub max_depth: Option<f64>,
+    pub min_temperature: Option<i8>,
+    pub enhanced_avg_respiration_rate: Option<f64>,
+    pub enhanced_max_respiration_rate: Option<f64>,
+    pub avg_respiration_rate: Option<u8>,
+    pub max_respiration_rate: Option<u8>,
+    pub total_grit: Option<f32>,
+    pub total_flow: Option<f32>,
+    pub jump_count: Option<u16>,
+    pub avg_grit: Option<f32>,
+    pub avg_flow: Option<f32>,
+    pub total_fractional_ascent: Option<f64>,
+    pub total_fractional_descent: Option<f64>,
+    pub avg_core_temperature: Option<f64>,
+    pub min_core_temperature: Option<f64>,
+    pub max_core_temperature: Option<f64>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for Lap {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Lap)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "event" => message.event = Some(typed_field_value(field)?),
+                "event_type" => message.event_type = Some(typed_field_value(field)?),
+                "start_time" => message.start_time = Some(typed_field_value(field)?),
+                "start_position_lat" => {
+                    message.start_position_lat = Some(typed_field_value(field)?)
+                }
+                "start_position_long" => {
+                    message.start_position_long = Some(typed_field_value(field)?)
+                }
+                "end_position_lat" => message.end_position_lat = Some(typed_field_value(field)?),
+                "end_position_long" => message.end_position_long = Some(typed_field_value(field)?),
+                "total_elapsed_time" => {
+                    message.total_elapsed_time = Some(typed_field_value(field)?)
+                }
+                "total_timer_time" => message.total_timer_time = Some(typed_field_value(field)?),
+                "total_distance" => message.total_distance = Some(typed_field_value(field)?),
+                "total_cycles" => message.total_cycles = Some(typed_field_value(field)?),
+                "total_strides" => message.total_strides = Some(typed_field_value(field)?),
+                "total_strokes" => message.total_strokes = Some(typed_field_value(field)?),
+                "total_calories" => message.total_calories = Some(typed_field_value(field)?),
+                "total_fat_calories" => {
+                    message.total_fat_calories = Some(typed_field_value(field)?)
+                }
+                "avg_speed" => message.avg_speed = Some(typed_field_value(field)?),
+                "max_speed" => message.max_speed = Some(typed_field_value(field)?),
+                "avg_heart_rate" => message.avg_heart_rate = Some(typed_field_value(field)?),
+                "max_heart_rate" => message.max_heart_rate = Some(typed_field_value(field)?),
+                "avg_cadence" => message.avg_cadence = Some(typed_field_value(field)?),
+                "avg_running_cadence" => {
+                    message.avg_running_cadence = Some(typed_field_value(field)?)
+                }
+                "max_cadence" => message.max_cadence = Some(typed_field_value(field)?),
+                "max_running_cadence" => {
+                    message.max_running_cadence = Some(typed_field_value(field)?)
+                }
+                "avg_power" => message.avg_power = Some(typed_field_value(field)?),
+                "max_power" => message.max_power = Some(typed_field_value(field)?),
+                "total_ascent" => message.total_ascent = Some(typed_field_value(field)?),
+                "total_descent" => message.total_descent = Some(typed_field_value(field)?),
+                "intensity" => message.intensity = Some(typed_field_value(field)?),
+                "lap_trigger" => message.lap_trigger = Some(typed_field_value(field)?),
+                "sport" => message.sport = Some(typed_field_value(field)?),
+                "event_group" => message.event_group = Some(typed_field_value(field)?),
+                "num_lengths" => message.num_lengths = Some(typed_field_value(field)?),
+                "normalized_power" => message.normalized_power = Some(typed_field_value(field)?),
+                "left_right_balance" => {
+                    message.left_right_balance = Some(typed_field_value(field)?)
+                }
+                "first_length_index" => {
+                    message.first_length_index = Some(typed_field_value(field)?)
+                }
+                "avg_stroke_distance" => {
+                    message.avg_stroke_distance = Some(typed_field_value(field)?)
+                }
+                "swim_stroke" => message.swim_stroke = Some(typed_field_value(field)?),
+                "sub_sport" => message.sub_sport = Some(typed_field_value(field)?),
+                "num_active_lengths" => {
+                    message.num_active_lengths = Some(typed_field_value(field)?)
+                }
+                "total_work" => message.total_work = Some(typed_field_value(field)?),
+                "avg_altitude" => message.avg_altitude = Some(typed_field_value(field)?),
+                "max_altitude" => message.max_altitude = Some(typed_field_value(field)?),
+                "gps_accuracy" => message.gps_accuracy = Some(typed_field_value(field)?),
+                "avg_grade" => message.avg_grade = Some(typed_field_value(field)?),
+                "avg_pos_grade" => message.avg_pos_grade = Some(typed_field_value(field)?),
+                "avg_neg_grade" => message.avg_neg_grade = Some(typed_field_value(field)?),
+                "max_pos_grade" => message.max_pos_grade = Some(typed_field_value(field)?),
+                "max_neg_grade" => message.max_neg_grade = Some(typed_field_value(field)?),
+                "avg_temperature" => message.avg_temperature = Some(typed_field_value(field)?),
+                "max_temperature" => message.max_temperature = Some(typed_field_value(field)?),
+                "total_moving_time" => message.total_moving_time = Some(typed_field_value(field)?),
+                "avg_pos_vertical_speed" => {
+                    message.avg_pos_vertical_speed = Some(typed_field_value(field)?)
+                }
+                "avg_neg_vertical_speed" => {
+                    message.avg_neg_vertical_speed = Some(typed_field_value(field)?)
+                }
+                "max_pos_vertical_speed" => {
+                    message.max_pos_vertical_speed = Some(typed_field_value(field)?)
+                }
+                "max_neg_vertical_speed" => {
+                    message.max_neg_vertical_speed = Some(typed_field_value(field)?)
+                }
+                "time_in_hr_zone" => message.time_in_hr_zone = Some(typed_field_value(field)?),
+                "time_in_speed_zone" => {
+                    message.time_in_speed_zone = Some(typed_field_value(field)?)
+                }
+                "time_in_cadence_zone" => {
+                    message.time_in_cadence_zone = Some(typed_field_value(field)?)
+                }
+                "time_in_power_zone" => {
+                    message.time_in_power_zone = Some(typed_field_value(field)?)
+                }
+                "repetition_num" => message.repetition_num = Some(typed_field_value(field)?),
+                "min_altitude" => message.min_altitude = Some(typed_field_value(field)?),
+                "min_heart_rate" => message.min_heart_rate = Some(typed_field_value(field)?),
+                "wkt_step_index" => message.wkt_step_index = Some(typed_field_value(field)?),
+                "opponent_score" => message.opponent_score = Some(typed_field_value(field)?),
+                "stroke_count" => message.stroke_count = Some(typed_field_value(field)?),
+                "zone_count" => message.zone_count = Some(typed_field_value(field)?),
+                "avg_vertical_oscillation" => {
+                    message.avg_vertical_oscillation = Some(typed_field_value(field)?)
+                }
+                "avg_stance_time_percent" => {
+                    message.avg_stance_time_percent = Some(typed_field_value(field)?)
+                }
+                "avg_stance_time" => message.avg_stance_time = Some(typed_field_value(field)?),
+                "avg_fractional_cadence" => {
+                    message.avg_fractional_cadence = Some(typed_field_value(field)?)
+                }
+                "max_fractional_cadence" => {
+                    message.max_fractional_cadence = Some(typed_field_value(field)?)
+                }
+                "total_fractional_cycles" => {
+                    message.total_fractional_cycles = Some(typed_field_value(field)?)
+                }
+                "player_score" => message.player_score = Some(typed_field_value(field)?),
+                "avg_total_hemoglobin_conc" => {
+                    message.avg_total_hemoglobin_conc = Some(typed_field_value(field)?)
+                }
+                "min_total_hemoglobin_conc" => {
+                    message.min_total_hemoglobin_conc = Some(typed_field_value(field)?)
+                }
+                "max_total_hemoglobin_conc" => {
+                    message.max_total_hemoglobin_conc = Some(typed_field_value(field)?)
+                }
+                "avg_saturated_hemoglobin_percent" => {
+                    message.avg_saturated_hemoglobin_percent = Some(typed_field_value(field)?)
+                }
+                "min_saturated_hemoglobin_percent" => {
+                    message.min_saturated_hemoglobin_percent = Some(typed_field_value(field)?)
+                }
+                "max_saturated_hemoglobin_percent" => {
+                    message.max_saturated_hemoglobin_percent = Some(typed_field_value(field)?)
+                }
+                "avg_left_torque_effectiveness" => {
+                    message.avg_left_torque_effectiveness = Some(typed_field_value(field)?)
+                }
+                "avg_right_torque_effectiveness" => {
+                    message.avg_right_torque_effectiveness = Some(typed_field_value(field)?)
+                }
+                "avg_left_pedal_smoothness" => {
+                    message.avg_left_pedal_smoothness = Some(typed_field_value(field)?)
+                }
+                "avg_right_pedal_smoothness" => {
+                    message.avg_right_pedal_smoothness = Some(typed_field_value(field)?)
+                }
+                "avg_combined_pedal_smoothness" => {
+                    message.avg_combined_pedal_smoothness = Some(typed_field_value(field)?)
+                }
+                "time_standing" => message.time_standing = Some(typed_field_value(field)?),
+                "stand_count" => message.stand_count = Some(typed_field_value(field)?),
+                "avg_left_pco" => message.avg_left_pco = Some(typed_field_value(field)?),
+                "avg_right_pco" => message.avg_right_pco = Some(typed_field_value(field)?),
+                "avg_left_power_phase" => {
+                    message.avg_left_power_phase = Some(typed_field_value(field)?)
+                }
+                "avg_left_power_phase_peak" => {
+                    message.avg_left_power_phase_peak = Some(typed_field_value(field)?)
+                }
+                "avg_right_power_phase" => {
+                    message.avg_right_power_phase = Some(typed_field_value(field)?)
+                }
+                "avg_right_power_phase_peak" => {
+                    message.avg_right_power_phase_peak = Some(typed_field_value(field)?)
+                }
+                "avg_power_position" => {
+                    message.avg_power_position = Some(typed_field_value(field)?)
+                }
+                "max_power_position" => {
+                    message.max_power_position = Some(typed_field_value(field)?)
+                }
+                "avg_cadence_position" => {
+                    message.avg_cadence_position = Some(typed_field_value(field)?)
+                }
+                "max_cadence_position" => {
+                    message.max_cadence_position = Some(typed_field_value(field)?)
+                }
+                "enhanced_avg_speed" => {
+                    message.enhanced_avg_speed = Some(typed_field_value(field)?)
+                }
+                "enhanced_max_speed" => {
+                    message.enhanced_max_speed = Some(typed_field_value(field)?)
+                }
+                "enhanced_avg_altitude" => {
+                    message.enhanced_avg_altitude = Some(typed_field_value(field)?)
+                }
+                "enhanced_min_altitude" => {
+                    message.enhanced_min_altitude = Some(typed_field_value(field)?)
+                }
+                "enhanced_max_altitude" => {
+                    message.enhanced_max_altitude = Some(typed_field_value(field)?)
+                }
+                "avg_lev_motor_power" => {
+                    message.avg_lev_motor_power = Some(typed_field_value(field)?)
+                }
+                "max_lev_motor_power" => {
+                    message.max_lev_motor_power = Some(typed_field_value(field)?)
+                }
+                "lev_battery_consumption" => {
+                    message.lev_battery_consumption = Some(typed_field_value(field)?)
+                }
+                "avg_vertical_ratio" => {
+                    message.avg_vertical_ratio = Some(typed_field_value(field)?)
+                }
+                "avg_stance_time_balance" => {
+                    message.avg_stance_time_balance = Some(typed_field_value(field)?)
+                }
+                "avg_step_length" => message.avg_step_length = Some(typed_field_value(field)?),
+                "avg_vam" => message.avg_vam = Some(typed_field_value(field)?),
+                "avg_depth" => message.avg_depth = Some(typed_field_value(field)?),
+                "max_depth" => message.max_depth = Some(typed_field_value(field)?),
+                "min_temperature" => message.min_temperature = Some(typed_field_value(field)?),
+                "enhanced_avg_respiration_rate" => {
+                    message.enhanced_avg_respiration_rate = Some(typed_field_value(field)?)
+                }
+                "enhanced_max_respiration_rate" => {
+                    message.enhanced_max_respiration_rate = Some(typed_field_value(field)?)
+                }
+                "avg_respiration_rate" => {
+                    message.avg_respiration_rate = Some(typed_field_value(field)?)
+                }
+                "max_respiration_rate" => {
+                    message.max_respiration_rate = Some(typed_field_value(field)?)
+                }
+                "total_grit" => message.total_grit = Some(typed_field_value(field)?),
+                "total_flow" => message.total_flow = Some(typed_field_value(field)?),
+                "jump_count" => message.jump_count = Some(typed_field_value(field)?),
+                "avg_grit" => message.avg_grit = Some(typed_field_value(field)?),
+                "avg_flow" => message.avg_flow = Some(typed_field_value(field)?),
+                "total_fractional_ascent" => {
+                    message.total_fractional_ascent = Some(typed_field_value(field)?)
+                }
+                "total_fractional_descent" => {
+                    message.total_fractional_descent = Some(typed_field_value(field)?)
+                }
+                "avg_core_temperature" => {
+                    message.avg_core_temperature = Some(typed_field_value(field)?)
+                }
+                "min_core_temperature" => {
+                    message.min_core_temperature = Some(typed_field_value(field)?)
+                }
+                "max_core_temperature" => {
+                    message.max_core_temperature = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Lap> for FitDataRecord {
+    fn from(message: &Lap) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Lap);
+        push_typed_field(&mut record, "event", &message.event);
+        push_typed_field(&mut record, "event_type", &message.event_type);
+        push_typed_field(&mut record, "start_time", &message.start_time);
+        push_typed_field(
+            &mut record,
+            "start_position_lat",
+            &message.start_position_lat,
+        );
+        push_typed_field(
+            &mut record,
+            "start_position_long",
+            &message.start_position_long,
+        );
+        push_typed_field(&mut record, "end_position_lat", &message.end_position_lat);
+        push_typed_field(&mut record, "end_position_long", &message.end_position_long);
+        push_typed_field(
+            &mut record,
+            "total_elapsed_time",
+            &message.total_elapsed_time,
+        );
+        push_typed_field(&mut record, "total_timer_time", &message.total_timer_time);
+        push_typed_field(&mut record, "total_distance", &message.total_distance);
+        push_typed_field(&mut record, "total_cycles", &message.total_cycles);
+        push_typed_field(&mut record, "total_strides", &message.total_strides);
+        push_typed_field(&mut record, "total_strokes", &message.total_strokes);
+        push_typed_field(&mut record, "total_calories", &message.total_calories);
+        push_typed_field(
+            &mut record,
+            "total_fat_calories",
+            &message.total_fat_calories,
+        );
+        push_typed_field(&mut record, "avg_speed", &message.avg_speed);
+        push_typed_field(&mut record, "max_speed", &message.max_speed);
+        push_typed_field(&mut record, "avg_heart_rate", &message.avg_heart_rate);
+        push_typed_field(&mut record, "max_heart_rate", &message.max_heart_rate);
+        push_typed_field(&mut record, "avg_cadence", &message.avg_cadence);
+        push_typed_field(
+            &mut record,
+            "avg_running_cadence",
+            &message.avg_running_cadence,
+        );
+        push_typed_field(&mut record, "max_cadence", &message.max_cadence);
+        push_typed_field(
+            &mut record,
+            "max_running_cadence",
+            &message.max_running_cadence,
+        );
+        push_typed_field(&mut record, "avg_power", &message.avg_power);
+        push_typed_field(&mut record, "max_power", &message.max_power);
+        push_typed_field(&mut record, "total_ascent", &message.total_ascent);
+        push_typed_field(&mut record, "total_descent", &message.total_descent);
+        push_typed_field(&mut record, "intensity", &message.intensity);
+        push_typed_field(&mut record, "lap_trigger", &message.lap_trigger);
+        push_typed_field(&mut record, "sport", &message.sport);
+        push_typed_field(&mut record, "event_group", &message.event_group);
+        push_typed_field(&mut record, "num_lengths", &message.num_lengths);
+        push_typed_field(&mut record, "normalized_power", &message.normalized_power);
+        push_typed_field(
+            &mut record,
+            "left_right_balance",
+            &message.left_right_balance,
+        );
+        push_typed_field(
+            &mut record,
+            "first_length_index",
+            &message.first_length_index,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_stroke_distance",
+            &message.avg_stroke_distance,
+        );
+        push_typed_field(&mut record, "swim_stroke", &message.swim_stroke);
+        push_typed_field(&mut record, "sub_sport", &message.sub_sport);
+        push_typed_field(
+            &mut record,
+            "num_active_lengths",
+            &message.num_active_lengths,
+        );
+        push_typed_field(&mut record, "total_work", &message.total_work);
+        push_typed_field(&mut record, "avg_altitude", &message.avg_altitude);
+        push_typed_field(&mut record, "max_altitude", &message.max_altitude);
+        push_typed_field(&mut record, "gps_accuracy", &message.gps_accuracy);
+        push_typed_field(&mut record, "avg_grade", &message.avg_grade);
+        push_typed_field(&mut record, "avg_pos_grade", &message.avg_pos_grade);
+        push_typed_field(&mut record, "avg_neg_grade", &message.avg_neg_grade);
+        push_typed_field(&mut record, "max_pos_grade", &message.max_pos_grade);
+        push_typed_field(&mut record, "max_neg_grade", &message.max_neg_grade);
+        push_typed_field(&mut record, "avg_temperature", &message.avg_temperature);
+        push_typed_field(&mut record, "max_temperature", &message.max_temperature);
+        push_typed_field(&mut record, "total_moving_time", &message.total_moving_time);
+        push_typed_field(
+            &mut record,
+            "avg_pos_vertical_speed",
+            &message.avg_pos_vertical_speed,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_neg_vertical_speed",
+            &message.avg_neg_vertical_speed,
+        );
+        push_typed_field(
+            &mut record,
+            "max_pos_vertical_speed",
+            &message.max_pos_vertical_speed,
+        );
+        push_typed_field(
+            &mut record,
+            "max_neg_vertical_speed",
+            &message.max_neg_vertical_speed,
+        );
+        push_typed_field(&mut record, "time_in_hr_zone", &message.time_in_hr_zone);
+        push_typed_field(
+            &mut record,
+            "time_in_speed_zone",
+            &message.time_in_speed_zone,
+        );
+        push_typed_field(
+            &mut record,
+            "time_in_cadence_zone",
+            &message.time_in_cadence_zone,
+        );
+        push_typed_field(
+            &mut record,
+            "time_in_power_zone",
+            &message.time_in_power_zone,
+        );
+        push_typed_field(&mut record, "repetition_num", &message.repetition_num);
+        push_typed_field(&mut record, "min_altitude", &message.min_altitude);
+        push_typed_field(&mut record, "min_heart_rate", &message.min_heart_rate);
+        push_typed_field(&mut record, "wkt_step_index", &message.wkt_step_index);
+        push_typed_field(&mut record, "opponent_score", &message.opponent_score);
+        push_typed_field(&mut record, "stroke_count", &message.stroke_count);
+        push_typed_field(&mut record, "zone_count", &message.zone_count);
+        push_typed_field(
+            &mut record,
+            "avg_vertical_oscillation",
+            &message.avg_vertical_oscillation,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_stance_time_percent",
+            &message.avg_stance_time_percent,
+        );
+        push_typed_field(&mut record, "avg_stance_time", &message.avg_stance_time);
+        push_typed_field(
+            &mut record,
+            "avg_fractional_cadence",
+            &message.avg_fractional_cadence,
+        );
+        push_typed_field(
+            &mut record,
+            "max_fractional_cadence",
+            &message.max_fractional_cadence,
+        );
+        push_typed_field(
+            &mut record,
+            "total_fractional_cycles",
+            &message.total_fractional_cycles,
+        );
+        push_typed_field(&mut record, "player_score", &message.player_score);
+        push_typed_field(
+            &mut record,
+            "avg_total_hemoglobin_conc",
+            &message.avg_total_hemoglobin_conc,
+        );
+        push_typed_field(
+            &mut record,
+            "min_total_hemoglobin_conc",
+            &message.min_total_hemoglobin_conc,
+        );
+        push_typed_field(
+            &mut record,
+            "max_total_hemoglobin_conc",
+            &message.max_total_hemoglobin_conc,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_saturated_hemoglobin_percent",
+            &message.avg_saturated_hemoglobin_percent,
+        );
+        push_typed_field(
+            &mut record,
+            "min_saturated_hemoglobin_percent",
+            &message.min_saturated_hemoglobin_percent,
+        );
+        push_typed_field(
+            &mut record,
+            "max_saturated_hemoglobin_percent",
+            &message.max_saturated_hemoglobin_percent,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_left_torque_effectiveness",
+            &message.avg_left_torque_effectiveness,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_right_torque_effectiveness",
+            &message.avg_right_torque_effectiveness,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_left_pedal_smoothness",
+            &message.avg_left_pedal_smoothness,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_right_pedal_smoothness",
+            &message.avg_right_pedal_smoothness,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_combined_pedal_smoothness",
+            &message.avg_combined_pedal_smoothness,
+        );
+        push_typed_field(&mut record, "time_standing", &message.time_standing);
+        push_typed_field(&mut record, "stand_count", &message.stand_count);
+        push_typed_field(&mut record, "avg_left_pco", &message.avg_left_pco);
+        push_typed_field(&mut record, "avg_right_pco", &message.avg_right_pco);
+        push_typed_field(
+            &mut record,
+            "avg_left_power_phase",
+            &message.avg_left_power_phase,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_left_power_phase_peak",
+            &message.avg_left_power_phase_peak,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_right_power_phase",
+            &message.avg_right_power_phase,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_right_power_phase_peak",
+            &message.avg_right_power_phase_peak,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_power_position",
+            &message.avg_power_position,
+        );
+        push_typed_field(
+            &mut record,
+            "max_power_position",
+            &message.max_power_position,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_cadence_position",
+            &message.avg_cadence_position,
+        );
+        push_typed_field(
+            &mut record,
+            "max_cadence_position",
+            &message.max_cadence_position,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_avg_speed",
+            &message.enhanced_avg_speed,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_max_speed",
+            &message.enhanced_max_speed,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_avg_altitude",
+            &message.enhanced_avg_altitude,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_min_altitude",
+            &message.enhanced_min_altitude,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_max_altitude",
+            &message.enhanced_max_altitude,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_lev_motor_power",
+            &message.avg_lev_motor_power,
+        );
+        push_typed_field(
+            &mut record,
+            "max_lev_motor_power",
+            &message.max_lev_motor_power,
+        );
+        push_typed_field(
+            &mut record,
+            "lev_battery_consumption",
+            &message.lev_battery_consumption,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_vertical_ratio",
+            &message.avg_vertical_ratio,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_stance_time_balance",
+            &message.avg_stance_time_balance,
+        );
+        push_typed_field(&mut record, "avg_step_length", &message.avg_step_length);
+        push_typed_field(&mut record, "avg_vam", &message.avg_vam);
+        push_typed_field(&mut record, "avg_depth", &message.avg_depth);
+        push_typed_field(&mut record, "max_depth", &message.max_depth);
+        push_typed_field(&mut record, "min_temperature", &message.min_temperature);
+        push_typed_field(
+            &mut record,
+            "enhanced_avg_respiration_rate",
+            &message.enhanced_avg_respiration_rate,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_max_respiration_rate",
+            &message.enhanced_max_respiration_rate,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_respiration_rate",
+            &message.avg_respiration_rate,
+        );
+        push_typed_field(
+            &mut record,
+            "max_respiration_rate",
+            &message.max_respiration_rate,
+        );
+        push_typed_field(&mut record, "total_grit", &message.total_grit);
+        push_typed_field(&mut record, "total_flow", &message.total_flow);
+        push_typed_field(&mut record, "jump_count", &message.jump_count);
+        push_typed_field(&mut record, "avg_grit", &message.avg_grit);
+        push_typed_field(&mut record, "avg_flow", &message.avg_flow);
+        push_typed_field(
+            &mut record,
+            "total_fractional_ascent",
+            &message.total_fractional_ascent,
+        );
+        push_typed_field(
+            &mut record,
+            "total_fractional_descent",
+            &message.total_fractional_descent,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_core_temperature",
+            &message.avg_core_temperature,
+        );
+        push_typed_field(
+            &mut record,
+            "min_core_temperature",
+            &message.min_core_temperature,
+        );
+        push_typed_field(
+            &mut record,
+            "max_core_temperature",
+            &message.max_core_temperature,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Length {
+    pub event: Option<field_types::Event>,
+    pub event_type: Option<field_types::EventType>,
+    pub start_time: Option<DateTime<TimestampTz>>,
+    pub total_elapsed_time: Option<f64>,
+    pub total_timer_time: Option<f64>,
+    pub total_strokes: Option<u16>,
+    pub avg_speed: Option<f64>,
+    pub swim_stroke: Option<field_types::SwimStroke>,
+    pub avg_swimming_cadence: Option<u8>,
+    pub event_group: Option<u8>,
+    pub total_calories: Option<u16>,
+    pub length_type: Option<field_types::LengthType>,
+    pub player_score: Option<u16>,
+    pub opponent_score: Option<u16>,
+    pub stroke_count: Option<Vec<u16>>,
+    pub zone_count: Option<Vec<u16>>,
+    pub enhanced_avg_respiration_rate: Option<f64>,
+    pub enhanced_max_respiration_rate: Option<f64>,
+    pub avg_respiration_rate: Option<u8>,
+    pub max_respiration_rate: Option<u8>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for Length {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Length)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "event" => message.event = Some(typed_field_value(field)?),
+                "event_type" => message.event_type = Some(typed_field_value(field)?),
+                "start_time" => message.start_time = Some(typed_field_value(field)?),
+                "total_elapsed_time" => {
+                    message.total_elapsed_time = Some(typed_field_value(field)?)
+                }
+                "total_timer_time" => message.total_timer_time = Some(typed_field_value(field)?),
+                "total_strokes" => message.total_strokes = Some(typed_field_value(field)?),
+                "avg_speed" => message.avg_speed = Some(typed_field_value(field)?),
+                "swim_stroke" => message.swim_stroke = Some(typed_field_value(field)?),
+                "avg_swimming_cadence" => {
+                    message.avg_swimming_cadence = Some(typed_field_value(field)?)
+                }
+                "event_group" => message.event_group = Some(typed_field_value(field)?),
+                "total_calories" => message.total_calories = Some(typed_field_value(field)?),
+                "length_type" => message.length_type = Some(typed_field_value(field)?),
+                "player_score" => message.player_score = Some(typed_field_value(field)?),
+                "opponent_score" => message.opponent_score = Some(typed_field_value(field)?),
+                "stroke_count" => message.stroke_count = Some(typed_field_value(field)?),
+                "zone_count" => message.zone_count = Some(typed_field_value(field)?),
+                "enhanced_avg_respiration_rate" => {
+                    message.enhanced_avg_respiration_rate = Some(typed_field_value(field)?)
+                }
+                "enhanced_max_respiration_rate" => {
+                    message.enhanced_max_respiration_rate = Some(typed_field_value(field)?)
+                }
+                "avg_respiration_rate" => {
+                    message.avg_respiration_rate = Some(typed_field_value(field)?)
+                }
+                "max_respiration_rate" => {
+                    message.max_respiration_rate = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Length> for FitDataRecord {
+    fn from(message: &Length) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Length);
+        push_typed_field(&mut record, "event", &message.event);
+        push_typed_field(&mut record, "event_type", &message.event_type);
+        push_typed_field(&mut record, "start_time", &message.start_time);
+        push_typed_field(
+            &mut record,
+            "total_elapsed_time",
+            &message.total_elapsed_time,
+        );
+        push_typed_field(&mut record, "total_timer_time", &message.total_timer_time);
+        push_typed_field(&mut record, "total_strokes", &message.total_strokes);
+        push_typed_field(&mut record, "avg_speed", &message.avg_speed);
+        push_typed_field(&mut record, "swim_stroke", &message.swim_stroke);
+        push_typed_field(
+            &mut record,
+            "avg_swimming_cadence",
+            &message.avg_swimming_cadence,
+        );
+        push_typed_field(&mut record, "event_group", &message.event_group);
+        push_typed_field(&mut record, "total_calories", &message.total_calories);
+        push_typed_field(&mut record, "length_type", &message.length_type);
+        push_typed_field(&mut record, "player_score", &message.player_score);
+        push_typed_field(&mut record, "opponent_score", &message.opponent_score);
+        push_typed_field(&mut record, "stroke_count", &message.stroke_count);
+        push_typed_field(&mut record, "zone_count", &message.zone_count);
+        push_typed_field(
+            &mut record,
+            "enhanced_avg_respiration_rate",
+            &message.enhanced_avg_respiration_rate,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_max_respiration_rate",
+            &message.enhanced_max_respiration_rate,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_respiration_rate",
+            &message.avg_respiration_rate,
+        );
+        push_typed_field(
+            &mut record,
+            "max_respiration_rate",
+            &message.max_respiration_rate,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Record {
+    pub position_lat: Option<i32>,
+    pub position_long: Option<i32>,
+    pub altitude: Option<f64>,
+    pub heart_rate: Option<u8>,
+    pub cadence: Option<u8>,
+    pub distance: Option<f64>,
+    pub speed: Option<f64>,
+    pub power: Option<u16>,
+    pub compressed_speed_distance: Option<Vec<u8>>,
+    pub grade: Option<f64>,
+    pub resistance: Option<u8>,
+    pub time_from_course: Option<f64>,
+    pub cycle_length: Option<f64>,
+    pub temperature: Option<i8>,
+    pub speed_1s: Option<Vec<f64>>,
+    pub cycles: Option<u8>,
+    pub total_cycles: Option<u32>,
+    pub compressed_accumulated_power: Option<u16>,
+    pub accumulated_power: Option<u32>,
+    pub left_right_balance: Option<field_types::LeftRightBalance>,
+    pub gps_accuracy: Option<u8>,
+    pub vertical_speed: Option<f64>,
+    pub calories: Option<u16>,
+    pub vertical_oscillation: Option<f64>,
+    pub stance_time_percent: Option<f64>,
+    pub stance_time: Option<f64>,
+    pub activity_type: Option<field_types::ActivityType>,
+    pub left_torque_effectiveness: Option<f64>,
+    pub right_torque_effectiveness: Option<f64>,
+    pub left_pedal_smoothness: Option<f64>,
+    pub right_pedal_smoothness: Option<f64>,
+    pub combined_pedal_smoothness: Option<f64>,
+    pub time128: Option<f64>,
+    pub stroke_type: Option<field_types::StrokeType>,
+    pub zone: Option<u8>,
+    pub ball_speed: Option<f64>,
+    pub cadence256: Option<f64>,
+    pub fractional_cadence: Option<f64>,
+    pub total_hemoglobin_conc: Option<f64>,
+    pub total_hemoglobin_conc_min: Option<f64>,
+    pub total_hemoglobin_conc_max: Option<f64>,
+    pub saturated_hemoglobin_percent: Option<f64>,
+    pub saturated_hemoglobin_percent_min: Option<f64>,
+    pub saturated_hemoglobin_percent_max: Option<f64>,
+    pub device_index: Option<field_types::DeviceIndex>,
+    pub left_pco: Option<i8>,
+    pub right_pco: Option<i8>,
+    pub left_power_phase: Option<Vec<f64>>,
+    pub left_power_phase_peak: Option<Vec<f64>>,
+    pub right_power_phase: Option<Vec<f64>>,
+    pub right_power_phase_peak: Option<Vec<f64>>,
+    pub enhanced_speed: Option<f64>,
+    pub enhanced_altitude: Option<f64>,
+    pub battery_soc: Option<f64>,
+    pub motor_power: Option<u16>,
+    pub vertical_ratio: Option<f64>,
+    pub stance_time_balance: Option<f64>,
+    pub step_length: Option<f64>,
+    pub absolute_pressure: Option<u32>,
+    pub depth: Option<f64>,
+    pub next_stop_depth: Option<f64>,
+    pub next_stop_time: Option<u32>,
+    pub time_to_surface: Option<u32>,
+    pub ndl_time: Option<u32>,
+    pub cns_load: Option<u8>,
+    pub n2_load: Option<u16>,
+    pub respiration_rate: Option<u8>,
+    pub enhanced_respiration_rate: Option<f64>,
+    pub grit: Option<f32>,
+    pub flow: Option<f32>,
+    pub ebike_travel_range: Option<u16>,
+    pub ebike_battery_level: Option<u8>,
+    pub ebike_assist_mode: Option<u8>,
+    pub ebike_assist_level_percent: Option<u8>,
+    pub air_time_remaining: Option<u32>,
+    pub pressure_sac: Option<f64>,
+    pub volume_sac: Option<f64>,
+    pub rmv: Option<f64>,
+    pub ascent_rate: Option<f64>,
+    pub po2: Option<f64>,
+    pub core_temperature: Option<f64>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for Record {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Record)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "position_lat" => message.position_lat = Some(typed_field_value(field)?),
+                "position_long" => message.position_long = Some(typed_field_value(field)?),
+                "altitude" => message.altitude = Some(typed_field_value(field)?),
+                "heart_rate" => message.heart_rate = Some(typed_field_value(field)?),
+                "cadence" => message.cadence = Some(typed_field_value(field)?),
+                "distance" => message.distance = Some(typed_field_value(field)?),
+                "speed" => message.speed = Some(typed_field_value(field)?),
+                "power" => message.power = Some(typed_field_value(field)?),
+                "compressed_speed_distance" => {
+                    message.compressed_speed_distance = Some(typed_field_value(field)?)
+                }
+                "grade" => message.grade = Some(typed_field_value(field)?),
+                "resistance" => message.resistance = Some(typed_field_value(field)?),
+                "time_from_course" => message.time_from_course = Some(typed_field_value(field)?),
+                "cycle_length" => message.cycle_length = Some(typed_field_value(field)?),
+                "temperature" => message.temperature = Some(typed_field_value(field)?),
+                "speed_1s" => message.speed_1s = Some(typed_field_value(field)?),
+                "cycles" => message.cycles = Some(typed_field_value(field)?),
+                "total_cycles" => message.total_cycles = Some(typed_field_value(field)?),
+                "compressed_accumulated_power" => {
+                    message.compressed_accumulated_power = Some(typed_field_value(field)?)
+                }
+                "accumulated_power" => message.accumulated_power = Some(typed_field_value(field)?),
+                "left_right_balance" => {
+                    message.left_right_balance = Some(typed_field_value(field)?)
+                }
+                "gps_accuracy" => message.gps_accuracy = Some(typed_field_value(field)?),
+                "vertical_speed" => message.vertical_speed = Some(typed_field_value(field)?),
+                "calories" => message.calories = Some(typed_field_value(field)?),
+                "vertical_oscillation" => {
+                    message.vertical_oscillation = Some(typed_field_value(field)?)
+                }
+                "stance_time_percent" => {
+                    message.stance_time_percent = Some(typed_field_value(field)?)
+                }
+                "stance_time" => message.stance_time = Some(typed_field_value(field)?),
+                "activity_type" => message.activity_type = Some(typed_field_value(field)?),
+                "left_torque_effectiveness" => {
+                    message.left_torque_effectiveness = Some(typed_field_value(field)?)
+                }
+                "right_torque_effectiveness" => {
+                    message.right_torque_effectiveness = Some(typed_field_value(field)?)
+                }
+                "left_pedal_smoothness" => {
+                    message.left_pedal_smoothness = Some(typed_field_value(field)?)
+                }
+                "right_pedal_smoothness" => {
+                    message.right_pedal_smoothness = Some(typed_field_value(field)?)
+                }
+                "combined_pedal_smoothness" => {
+                    message.combined_pedal_smoothness = Some(typed_field_value(field)?)
+                }
+                "time128" => message.time128 = Some(typed_field_value(field)?),
+                "stroke_type" => message.stroke_type = Some(typed_field_value(field)?),
+                "zone" => message.zone = Some(typed_field_value(field)?),
+                "ball_speed" => message.ball_speed = Some(typed_field_value(field)?),
+                "cadence256" => message.cadence256 = Some(typed_field_value(field)?),
+                "fractional_cadence" => {
+                    message.fractional_cadence = Some(typed_field_value(field)?)
+                }
+                "total_hemoglobin_conc" => {
+                    message.total_hemoglobin_conc = Some(typed_field_value(field)?)
+                }
+                "total_hemoglobin_conc_min" => {
+                    message.total_hemoglobin_conc_min = Some(typed_field_value(field)?)
+                }
+                "total_hemoglobin_conc_max" => {
+                    message.total_hemoglobin_conc_max = Some(typed_field_value(field)?)
+                }
+                "saturated_hemoglobin_percent" => {
+                    message.saturated_hemoglobin_percent = Some(typed_field_value(field)?)
+                }
+                "saturated_hemoglobin_percent_min" => {
+                    message.saturated_hemoglobin_percent_min = Some(typed_field_value(field)?)
+                }
+                "saturated_hemoglobin_percent_max" => {
+                    message.saturated_hemoglobin_percent_max = Some(typed_field_value(field)?)
+                }
+                "device_index" => message.device_index = Some(typed_field_value(field)?),
+                "left_pco" => message.left_pco = Some(typed_field_value(field)?),
+                "right_pco" => message.right_pco = Some(typed_field_value(field)?),
+                "left_power_phase" => message.left_power_phase = Some(typed_field_value(field)?),
+                "left_power_phase_peak" => {
+                    message.left_power_phase_peak = Some(typed_field_value(field)?)
+                }
+                "right_power_phase" => message.right_power_phase = Some(typed_field_value(field)?),
+                "right_power_phase_peak" => {
+                    message.right_power_phase_peak = Some(typed_field_value(field)?)
+                }
+                "enhanced_speed" => message.enhanced_speed = Some(typed_field_value(field)?),
+                "enhanced_altitude" => message.enhanced_altitude = Some(typed_field_value(field)?),
+                "battery_soc" => message.battery_soc = Some(typed_field_value(field)?),
+                "motor_power" => message.motor_power = Some(typed_field_value(field)?),
+                "vertical_ratio" => message.vertical_ratio = Some(typed_field_value(field)?),
+                "stance_time_balance" => {
+                    message.stance_time_balance = Some(typed_field_value(field)?)
+                }
+                "step_length" => message.step_length = Some(typed_field_value(field)?),
+                "absolute_pressure" => message.absolute_pressure = Some(typed_field_value(field)?),
+                "depth" => message.depth = Some(typed_field_value(field)?),
+                "next_stop_depth" => message.next_stop_depth = Some(typed_field_value(field)?),
+                "next_stop_time" => message.next_stop_time = Some(typed_field_value(field)?),
+                "time_to_surface" => message.time_to_surface = Some(typed_field_value(field)?),
+                "ndl_time" => message.ndl_time = Some(typed_field_value(field)?),
+                "cns_load" => message.cns_load = Some(typed_field_value(field)?),
+                "n2_load" => message.n2_load = Some(typed_field_value(field)?),
+                "respiration_rate" => message.respiration_rate = Some(typed_field_value(field)?),
+                "enhanced_respiration_rate" => {
+                    message.enhanced_respiration_rate = Some(typed_field_value(field)?)
+                }
+                "grit" => message.grit = Some(typed_field_value(field)?),
+                "flow" => message.flow = Some(typed_field_value(field)?),
+                "ebike_travel_range" => {
+                    message.ebike_travel_range = Some(typed_field_value(field)?)
+                }
+                "ebike_battery_level" => {
+                    message.ebike_battery_level = Some(typed_field_value(field)?)
+                }
+                "ebike_assist_mode" => message.ebike_assist_mode = Some(typed_field_value(field)?),
+                "ebike_assist_level_percent" => {
+                    message.ebike_assist_level_percent = Some(typed_field_value(field)?)
+                }
+                "air_time_remaining" => {
+                    message.air_time_remaining = Some(typed_field_value(field)?)
+                }
+                "pressure_sac" => message.pressure_sac = Some(typed_field_value(field)?),
+                "volume_sac" => message.volume_sac = Some(typed_field_value(field)?),
+                "rmv" => message.rmv = Some(typed_field_value(field)?),
+                "ascent_rate" => message.ascent_rate = Some(typed_field_value(field)?),
+                "po2" => message.po2 = Some(typed_field_value(field)?),
+                "core_temperature" => message.core_temperature = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Record> for FitDataRecord {
+    fn from(message: &Record) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Record);
+        push_typed_field(&mut record, "position_lat", &message.position_lat);
+        push_typed_field(&mut record, "position_long", &message.position_long);
+        push_typed_field(&mut record, "altitude", &message.altitude);
+        push_typed_field(&mut record, "heart_rate", &message.heart_rate);
+        push_typed_field(&mut record, "cadence", &message.cadence);
+        push_typed_field(&mut record, "distance", &message.distance);
+        push_typed_field(&mut record, "speed", &message.speed);
+        push_typed_field(&mut record, "power", &message.power);
+        push_typed_field(
+            &mut record,
+            "compressed_speed_distance",
+            &message.compressed_speed_distance,
+        );
+        push_typed_field(&mut record, "grade", &message.grade);
+        push_typed_field(&mut record, "resistance", &message.resistance);
+        push_typed_field(&mut record, "time_from_course", &message.time_from_course);
+        push_typed_field(&mut record, "cycle_length", &message.cycle_length);
+        push_typed_field(&mut record, "temperature", &message.temperature);
+        push_typed_field(&mut record, "speed_1s", &message.speed_1s);
+        push_typed_field(&mut record, "cycles", &message.cycles);
+        push_typed_field(&mut record, "total_cycles", &message.total_cycles);
+        push_typed_field(
+            &mut record,
+            "compressed_accumulated_power",
+            &message.compressed_accumulated_power,
+        );
+        push_typed_field(&mut record, "accumulated_power", &message.accumulated_power);
+        push_typed_field(
+            &mut record,
+            "left_right_balance",
+            &message.left_right_balance,
+        );
+        push_typed_field(&mut record, "gps_accuracy", &message.gps_accuracy);
+        push_typed_field(&mut record, "vertical_speed", &message.vertical_speed);
+        push_typed_field(&mut record, "calories", &message.calories);
+        push_typed_field(
+            &mut record,
+            "vertical_oscillation",
+            &message.vertical_oscillation,
+        );
+        push_typed_field(
+            &mut record,
+            "stance_time_percent",
+            &message.stance_time_percent,
+        );
+        push_typed_field(&mut record, "stance_time", &message.stance_time);
+        push_typed_field(&mut record, "activity_type", &message.activity_type);
+        push_typed_field(
+            &mut record,
+            "left_torque_effectiveness",
+            &message.left_torque_effectiveness,
+        );
+        push_typed_field(
+            &mut record,
+            "right_torque_effectiveness",
+            &message.right_torque_effectiveness,
+        );
+        push_typed_field(
+            &mut record,
+            "left_pedal_smoothness",
+            &message.left_pedal_smoothness,
+        );
+        push_typed_field(
+            &mut record,
+            "right_pedal_smoothness",
+            &message.right_pedal_smoothness,
+        );
+        push_typed_field(
+            &mut record,
+            "combined_pedal_smoothness",
+            &message.combined_pedal_smoothness,
+        );
+        push_typed_field(&mut record, "time128", &message.time128);
+        push_typed_field(&mut record, "stroke_type", &message.stroke_type);
+        push_typed_field(&mut record, "zone", &message.zone);
+        push_typed_field(&mut record, "ball_speed", &message.ball_speed);
+        push_typed_field(&mut record, "cadence256", &message.cadence256);
+        push_typed_field(
+            &mut record,
+            "fractional_cadence",
+            &message.fractional_cadence,
+        );
+        push_typed_field(
+            &mut record,
+            "total_hemoglobin_conc",
+            &message.total_hemoglobin_conc,
+        );
+        push_typed_field(
+            &mut record,
+            "total_hemoglobin_conc_min",
+            &message.total_hemoglobin_conc_min,
+        );
+        push_typed_field(
+            &mut record,
+            "total_hemoglobin_conc_max",
+            &message.total_hemoglobin_conc_max,
+        );
+        push_typed_field(
+            &mut record,
+            "saturated_hemoglobin_percent",
+            &message.saturated_hemoglobin_percent,
+        );
+        push_typed_field(
+            &mut record,
+            "saturated_hemoglobin_percent_min",
+            &message.saturated_hemoglobin_percent_min,
+        );
+        push_typed_field(
+            &mut record,
+            "saturated_hemoglobin_percent_max",
+            &message.saturated_hemoglobin_percent_max,
+        );
+        push_typed_field(&mut record, "device_index", &message.device_index);
+        push_typed_field(&mut record, "left_pco", &message.left_pco);
+        push_typed_field(&mut record, "right_pco", &message.right_pco);
+        push_typed_field(&mut record, "left_power_phase", &message.left_power_phase);
+        push_typed_field(
+            &mut record,
+            "left_power_phase_peak",
+            &message.left_power_phase_peak,
+        );
+        push_typed_field(&mut record, "right_power_phase", &message.right_power_phase);
+        push_typed_field(
+            &mut record,
+            "right_power_phase_peak",
+            &message.right_power_phase_peak,
+        );
+        push_typed_field(&mut record, "enhanced_speed", &message.enhanced_speed);
+        push_typed_field(&mut record, "enhanced_altitude", &message.enhanced_altitude);
+        push_typed_field(&mut record, "battery_soc", &message.battery_soc);
+        push_typed_field(&mut record, "motor_power", &message.motor_power);
+        push_typed_field(&mut record, "vertical_ratio", &message.vertical_ratio);
+        push_typed_field(
+            &mut record,
+            "stance_time_balance",
+            &message.stance_time_balance,
+        );
+        push_typed_field(&mut record, "step_length", &message.step_length);
+        push_typed_field(&mut record, "absolute_pressure", &message.absolute_pressure);
+        push_typed_field(&mut record, "depth", &message.depth);
+        push_typed_field(&mut record, "next_stop_depth", &message.next_stop_depth);
+        push_typed_field(&mut record, "next_stop_time", &message.next_stop_time);
+        push_typed_field(&mut record, "time_to_surface", &message.time_to_surface);
+        push_typed_field(&mut record, "ndl_time", &message.ndl_time);
+        push_typed_field(&mut record, "cns_load", &message.cns_load);
+        push_typed_field(&mut record, "n2_load", &message.n2_load);
+        push_typed_field(&mut record, "respiration_rate", &message.respiration_rate);
+        push_typed_field(
+            &mut record,
+            "enhanced_respiration_rate",
+            &message.enhanced_respiration_rate,
+        );
+        push_typed_field(&mut record, "grit", &message.grit);
+        push_typed_field(&mut record, "flow", &message.flow);
+        push_typed_field(
+            &mut record,
+            "ebike_travel_range",
+            &message.ebike_travel_range,
+        );
+        push_typed_field(
+            &mut record,
+            "ebike_battery_level",
+            &message.ebike_battery_level,
+        );
+        push_typed_field(&mut record, "ebike_assist_mode", &message.ebike_assist_mode);
+        push_typed_field(
+            &mut record,
+            "ebike_assist_level_percent",
+            &message.ebike_assist_level_percent,
+        );
+        push_typed_field(
+            &mut record,
+            "air_time_remaining",
+            &message.air_time_remaining,
+        );
+        push_typed_field(&mut record, "pressure_sac", &message.pressure_sac);
+        push_typed_field(&mut record, "volume_sac", &message.volume_sac);
+        push_typed_field(&mut record, "rmv", &message.rmv);
+        push_typed_field(&mut record, "ascent_rate", &message.ascent_rate);
+        push_typed_field(&mut record, "po2", &message.po2);
+        push_typed_field(&mut record, "core_temperature", &message.core_temperature);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Event {
+    pub event: Option<field_types::Event>,
+    pub event_type: Option<field_types::EventType>,
+    pub data16: Option<u16>,
+    pub data: Option<u32>,
+    pub timer_trigger: Option<field_types::TimerTrigger>,
+    pub course_point_index: Option<field_types::MessageIndex>,
+    pub battery_level: Option<f64>,
+    pub virtual_partner_speed: Option<f64>,
+    pub hr_high_alert: Option<u8>,
+    pub hr_low_alert: Option<u8>,
+    pub speed_high_alert: Option<f64>,
+    pub speed_low_alert: Option<f64>,
+    pub cad_high_alert: Option<u16>,
+    pub cad_low_alert: Option<u16>,
+    pub power_high_alert: Option<u16>,
+    pub power_low_alert: Option<u16>,
+    pub time_duration_alert: Option<f64>,
+    pub distance_duration_alert: Option<f64>,
+    pub calorie_duration_alert: Option<u32>,
+    pub fitness_equipment_state: Option<field_types::FitnessEquipmentState>,
+    pub sport_point: Option<u32>,
+    pub gear_change_data: Option<u32>,
+    pub rider_position: Option<field_types::RiderPositionType>,
+    pub comm_timeout: Option<field_types::CommTimeoutType>,
+    pub dive_alert: Option<field_types::DiveAlert>,
+    pub radar_threat_alert: Option<u32>,
+    pub event_group: Option<u8>,
+    pub score: Option<u16>,
+    pub opponent_score: Option<u16>,
+    pub front_gear_num: Option<u8>,
+    pub front_gear: Option<u8>,
+    pub rear_gear_num: Option<u8>,
+    pub rear_gear: Option<u8>,
+    pub device_index: Option<field_types::DeviceIndex>,
+    pub radar_threat_level_max: Option<field_types::RadarThreatLevelType>,
+    pub radar_threat_count: Option<u8>,
+    pub radar_threat_avg_approach_speed: Option<f64>,
+    pub radar_threat_max_approach_speed: Option<f64>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for Event {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Event)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "event" => message.event = Some(typed_field_value(field)?),
+                "event_type" => message.event_type = Some(typed_field_value(field)?),
+                "data16" => message.data16 = Some(typed_field_value(field)?),
+                "data" => message.data = Some(typed_field_value(field)?),
+                "timer_trigger" => message.timer_trigger = Some(typed_field_value(field)?),
+                "course_point_index" => {
+                    message.course_point_index = Some(typed_field_value(field)?)
+                }
+                "battery_level" => message.battery_level = Some(typed_field_value(field)?),
+                "virtual_partner_speed" => {
+                    message.virtual_partner_speed = Some(typed_field_value(field)?)
+                }
+                "hr_high_alert" => message.hr_high_alert = Some(typed_field_value(field)?),
+                "hr_low_alert" => message.hr_low_alert = Some(typed_field_value(field)?),
+                "speed_high_alert" => message.speed_high_alert = Some(typed_field_value(field)?),
+                "speed_low_alert" => message.speed_low_alert = Some(typed_field_value(field)?),
+                "cad_high_alert" => message.cad_high_alert = Some(typed_field_value(field)?),
+                "cad_low_alert" => message.cad_low_alert = Some(typed_field_value(field)?),
+                "power_high_alert" => message.power_high_alert = Some(typed_field_value(field)?),
+                "power_low_alert" => message.power_low_alert = Some(typed_field_value(field)?),
+                "time_duration_alert" => {
+                    message.time_duration_alert = Some(typed_field_value(field)?)
+                }
+                "distance_duration_alert" => {
+                    message.distance_duration_alert = Some(typed_field_value(field)?)
+                }
+                "calorie_duration_alert" => {
+                    message.calorie_duration_alert = Some(typed_field_value(field)?)
+                }
+                "fitness_equipment_state" => {
+                    message.fitness_equipment_state = Some(typed_field_value(field)?)
+                }
+                "sport_point" => message.sport_point = Some(typed_field_value(field)?),
+                "gear_change_data" => message.gear_change_data = Some(typed_field_value(field)?),
+                "rider_position" => message.rider_position = Some(typed_field_value(field)?),
+                "comm_timeout" => message.comm_timeout = Some(typed_field_value(field)?),
+                "dive_alert" => message.dive_alert = Some(typed_field_value(field)?),
+                "radar_threat_alert" => {
+                    message.radar_threat_alert = Some(typed_field_value(field)?)
+                }
+                "event_group" => message.event_group = Some(typed_field_value(field)?),
+                "score" => message.score = Some(typed_field_value(field)?),
+                "opponent_score" => message.opponent_score = Some(typed_field_value(field)?),
+                "front_gear_num" => message.front_gear_num = Some(typed_field_value(field)?),
+                "front_gear" => message.front_gear = Some(typed_field_value(field)?),
+                "rear_gear_num" => message.rear_gear_num = Some(typed_field_value(field)?),
+                "rear_gear" => message.rear_gear = Some(typed_field_value(field)?),
+                "device_index" => message.device_index = Some(typed_field_value(field)?),
+                "radar_threat_level_max" => {
+                    message.radar_threat_level_max = Some(typed_field_value(field)?)
+                }
+                "radar_threat_count" => {
+                    message.radar_threat_count = Some(typed_field_value(field)?)
+                }
+                "radar_threat_avg_approach_speed" => {
+                    message.radar_threat_avg_approach_speed = Some(typed_field_value(field)?)
+                }
+                "radar_threat_max_approach_speed" => {
+                    message.radar_threat_max_approach_speed = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Event> for FitDataRecord {
+    fn from(message: &Event) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Event);
+        push_typed_field(&mut record, "event", &message.event);
+        push_typed_field(&mut record, "event_type", &message.event_type);
+        push_typed_field(&mut record, "data16", &message.data16);
+        push_typed_field(&mut record, "data", &message.data);
+        push_typed_field(&mut record, "timer_trigger", &message.timer_trigger);
+        push_typed_field(
+            &mut record,
+            "course_point_index",
+            &message.course_point_index,
+        );
+        push_typed_field(&mut record, "battery_level", &message.battery_level);
+        push_typed_field(
+            &mut record,
+            "virtual_partner_speed",
+            &message.virtual_partner_speed,
+        );
+        push_typed_field(&mut record, "hr_high_alert", &message.hr_high_alert);
+        push_typed_field(&mut record, "hr_low_alert", &message.hr_low_alert);
+        push_typed_field(&mut record, "speed_high_alert", &message.speed_high_alert);
+        push_typed_field(&mut record, "speed_low_alert", &message.speed_low_alert);
+        push_typed_field(&mut record, "cad_high_alert", &message.cad_high_alert);
+        push_typed_field(&mut record, "cad_low_alert", &message.cad_low_alert);
+        push_typed_field(&mut record, "power_high_alert", &message.power_high_alert);
+        push_typed_field(&mut record, "power_low_alert", &message.power_low_alert);
+        push_typed_field(
+            &mut record,
+            "time_duration_alert",
+            &message.time_duration_alert,
+        );
+        push_typed_field(
+            &mut record,
+            "distance_duration_alert",
+            &message.distance_duration_alert,
+        );
+        push_typed_field(
+            &mut record,
+            "calorie_duration_alert",
+            &message.calorie_duration_alert,
+        );
+        push_typed_field(
+            &mut record,
+            "fitness_equipment_state",
+            &message.fitness_equipment_state,
+        );
+        push_typed_field(&mut record, "sport_point", &message.sport_point);
+        push_typed_field(&mut record, "gear_change_data", &message.gear_change_data);
+        push_typed_field(&mut record, "rider_position", &message.rider_position);
+        push_typed_field(&mut record, "comm_timeout", &message.comm_timeout);
+        push_typed_field(&mut record, "dive_alert", &message.dive_alert);
+        push_typed_field(
+            &mut record,
+            "radar_threat_alert",
+            &message.radar_threat_alert,
+        );
+        push_typed_field(&mut record, "event_group", &message.event_group);
+        push_typed_field(&mut record, "score", &message.score);
+        push_typed_field(&mut record, "opponent_score", &message.opponent_score);
+        push_typed_field(&mut record, "front_gear_num", &message.front_gear_num);
+        push_typed_field(&mut record, "front_gear", &message.front_gear);
+        push_typed_field(&mut record, "rear_gear_num", &message.rear_gear_num);
+        push_typed_field(&mut record, "rear_gear", &message.rear_gear);
+        push_typed_field(&mut record, "device_index", &message.device_index);
+        push_typed_field(
+            &mut record,
+            "radar_threat_level_max",
+            &message.radar_threat_level_max,
+        );
+        push_typed_field(
+            &mut record,
+            "radar_threat_count",
+            &message.radar_threat_count,
+        );
+        push_typed_field(
+            &mut record,
+            "radar_threat_avg_approach_speed",
+            &message.radar_threat_avg_approach_speed,
+        );
+        push_typed_field(
+            &mut record,
+            "radar_threat_max_approach_speed",
+            &message.radar_threat_max_approach_speed,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct DeviceInfo {
+    pub device_index: Option<field_types::DeviceIndex>,
+    pub device_type: Option<u8>,
+    pub ble_device_type: Option<field_types::BleDeviceType>,
+    pub antplus_device_type: Option<field_types::AntplusDeviceType>,
+    pub ant_device_type: Option<u8>,
+    pub local_device_type: Option<field_types::LocalDeviceType>,
+    pub manufacturer: Option<field_types::Manufacturer>,
+    pub serial_number: Option<u32>,
+    pub product: Option<u16>,
+    pub favero_product: Option<field_types::FaveroProduct>,
+    pub garmin_product: Option<field_types::GarminProduct>,
+    pub software_version: Option<f64>,
+    pub hardware_version: Option<u8>,
+    pub cum_operating_time: Option<u32>,
+    pub battery_voltage: Option<f64>,
+    pub battery_status: Option<field_types::BatteryStatus>,
+    pub sensor_position: Option<field_types::BodyLocation>,
+    pub descriptor: Option<String>,
+    pub ant_transmission_type: Option<u8>,
+    pub ant_device_number: Option<u16>,
+    pub ant_network: Option<field_types::AntNetwork>,
+    pub source_type: Option<field_types::SourceType>,
+    pub product_name: Option<String>,
+    pub battery_level: Option<u8>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for DeviceInfo {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::DeviceInfo)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "device_index" => message.device_index = Some(typed_field_value(field)?),
+                "device_type" => message.device_type = Some(typed_field_value(field)?),
+                "ble_device_type" => message.ble_device_type = Some(typed_field_value(field)?),
+                "antplus_device_type" => {
+                    message.antplus_device_type = Some(typed_field_value(field)?)
+                }
+                "ant_device_type" => message.ant_device_type = Some(typed_field_value(field)?),
+                "local_device_type" => message.local_device_type = Some(typed_field_value(field)?),
+                "manufacturer" => message.manufacturer = Some(typed_field_value(field)?),
+                "serial_number" => message.serial_number = Some(typed_field_value(field)?),
+                "product" => message.product = Some(typed_field_value(field)?),
+                "favero_product" => message.favero_product = Some(typed_field_value(field)?),
+                "garmin_product" => message.garmin_product = Some(typed_field_value(field)?),
+                "software_version" => message.software_version = Some(typed_field_value(field)?),
+                "hardware_version" => message.hardware_version = Some(typed_field_value(field)?),
+                "cum_operating_time" => {
+                    message.cum_operating_time = Some(typed_field_value(field)?)
+                }
+                "battery_voltage" => message.battery_voltage = Some(typed_field_value(field)?),
+                "battery_status" => message.battery_status = Some(typed_field_value(field)?),
+                "sensor_position" => message.sensor_position = Some(typed_field_value(field)?),
+                "descriptor" => message.descriptor = Some(typed_field_value(field)?),
+                "ant_transmission_type" => {
+                    message.ant_transmission_type = Some(typed_field_value(field)?)
+                }
+                "ant_device_number" => message.ant_device_number = Some(typed_field_value(field)?),
+                "ant_network" => message.ant_network = Some(typed_field_value(field)?),
+                "source_type" => message.source_type = Some(typed_field_value(field)?),
+                "product_name" => message.product_name = Some(typed_field_value(field)?),
+                "battery_level" => message.battery_level = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&DeviceInfo> for FitDataRecord {
+    fn from(message: &DeviceInfo) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::DeviceInfo);
+        push_typed_field(&mut record, "device_index", &message.device_index);
+        push_typed_field(&mut record, "device_type", &message.device_type);
+        push_typed_field(&mut record, "ble_device_type", &message.ble_device_type);
+        push_typed_field(
+            &mut record,
+            "antplus_device_type",
+            &message.antplus_device_type,
+        );
+        push_typed_field(&mut record, "ant_device_type", &message.ant_device_type);
+        push_typed_field(&mut record, "local_device_type", &message.local_device_type);
+        push_typed_field(&mut record, "manufacturer", &message.manufacturer);
+        push_typed_field(&mut record, "serial_number", &message.serial_number);
+        push_typed_field(&mut record, "product", &message.product);
+        push_typed_field(&mut record, "favero_product", &message.favero_product);
+        push_typed_field(&mut record, "garmin_product", &message.garmin_product);
+        push_typed_field(&mut record, "software_version", &message.software_version);
+        push_typed_field(&mut record, "hardware_version", &message.hardware_version);
+        push_typed_field(
+            &mut record,
+            "cum_operating_time",
+            &message.cum_operating_time,
+        );
+        push_typed_field(&mut record, "battery_voltage", &message.battery_voltage);
+        push_typed_field(&mut record, "battery_status", &message.battery_status);
+        push_typed_field(&mut record, "sensor_position", &message.sensor_position);
+        push_typed_field(&mut record, "descriptor", &message.descriptor);
+        push_typed_field(
+            &mut record,
+            "ant_transmission_type",
+            &message.ant_transmission_type,
+        );
+        push_typed_field(&mut record, "ant_device_number", &message.ant_device_number);
+        push_typed_field(&mut record, "ant_network", &message.ant_network);
+        push_typed_field(&mut record, "source_type", &message.source_type);
+        push_typed_field(&mut record, "product_name", &message.product_name);
+        push_typed_field(&mut record, "battery_level", &message.battery_level);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct DeviceAuxBatteryInfo {
+    pub device_index: Option<field_types::DeviceIndex>,
+    pub battery_voltage: Option<f64>,
+    pub battery_status: Option<field_types::BatteryStatus>,
+    pub battery_identifier: Option<u8>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for DeviceAuxBatteryInfo {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::DeviceAuxBatteryInfo)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "device_index" => message.device_index = Some(typed_field_value(field)?),
+                "battery_voltage" => message.battery_voltage = Some(typed_field_value(field)?),
+                "battery_status" => message.battery_status = Some(typed_field_value(field)?),
+                "battery_identifier" => {
+                    message.battery_identifier = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&DeviceAuxBatteryInfo> for FitDataRecord {
+    fn from(message: &DeviceAuxBatteryInfo) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::DeviceAuxBatteryInfo);
+        push_typed_field(&mut record, "device_index", &message.device_index);
+        push_typed_field(&mut record, "battery_voltage", &message.battery_voltage);
+        push_typed_field(&mut record, "battery_status", &message.battery_status);
+        push_typed_field(
+            &mut record,
+            "battery_identifier",
+            &message.battery_identifier,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+/// Corresponds to file_id of workout or course.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct TrainingFile {
+    pub r#type: Option<field_types::File>,
+    pub manufacturer: Option<field_types::Manufacturer>,
+    pub product: Option<u16>,
+    pub favero_product: Option<field_types::FaveroProduct>,
+    pub garmin_product: Option<field_types::GarminProduct>,
+    pub serial_number: Option<u32>,
+    pub time_created: Option<DateTime<TimestampTz>>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for TrainingFile {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::TrainingFile)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "type" => message.r#type = Some(typed_field_value(field)?),
+                "manufacturer" => message.manufacturer = Some(typed_field_value(field)?),
+                "product" => message.product = Some(typed_field_value(field)?),
+                "favero_product" => message.favero_product = Some(typed_field_value(field)?),
+                "garmin_product" => message.garmin_product = Some(typed_field_value(field)?),
+                "serial_number" => message.serial_number = Some(typed_field_value(field)?),
+                "time_created" => message.time_created = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&TrainingFile> for FitDataRecord {
+    fn from(message: &TrainingFile) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::TrainingFile);
+        push_typed_field(&mut record, "type", &message.r#type);
+        push_typed_field(&mut record, "manufacturer", &message.manufacturer);
+        push_typed_field(&mut record, "product", &message.product);
+        push_typed_field(&mut record, "favero_product", &message.favero_product);
+        push_typed_field(&mut record, "garmin_product", &message.garmin_product);
+        push_typed_field(&mut record, "serial_number", &message.serial_number);
+        push_typed_field(&mut record, "time_created", &message.time_created);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct WeatherConditions {
+    pub weather_report: Option<field_types::WeatherReport>,
+    pub temperature: Option<i8>,
+    pub condition: Option<field_types::WeatherStatus>,
+    pub wind_direction: Option<u16>,
+    pub wind_speed: Option<f64>,
+    pub precipitation_probability: Option<u8>,
+    pub temperature_feels_like: Option<i8>,
+    pub relative_humidity: Option<u8>,
+    pub location: Option<String>,
+    pub observed_at_time: Option<DateTime<TimestampTz>>,
+    pub observed_location_lat: Option<i32>,
+    pub observed_location_long: Option<i32>,
+    pub day_of_week: Option<field_types::DayOfWeek>,
+    pub high_temperature: Option<i8>,
+    pub low_temperature: Option<i8>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for WeatherConditions {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::WeatherConditions)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "weather_report" => message.weather_report = Some(typed_field_value(field)?),
+                "temperature" => message.temperature = Some(typed_field_value(field)?),
+                "condition" => message.condition = Some(typed_field_value(field)?),
+                "wind_direction" => message.wind_direction = Some(typed_field_value(field)?),
+                "wind_speed" => message.wind_speed = Some(typed_field_value(field)?),
+                "precipitation_probability" => {
+                    message.precipitation_probability = Some(typed_field_value(field)?)
+                }
+                "temperature_feels_like" => {
+                    message.temperature_feels_like = Some(typed_field_value(field)?)
+                }
+                "relative_humidity" => message.relative_humidity = Some(typed_field_value(field)?),
+                "location" => message.location = Some(typed_field_value(field)?),
+                "observed_at_time" => message.observed_at_time = Some(typed_field_value(field)?),
+                "observed_location_lat" => {
+                    message.observed_location_lat = Some(typed_field_value(field)?)
+                }
+                "observed_location_long" => {
+                    message.observed_location_long = Some(typed_field_value(field)?)
+                }
+                "day_of_week" => message.day_of_week = Some(typed_field_value(field)?),
+                "high_temperature" => message.high_temperature = Some(typed_field_value(field)?),
+                "low_temperature" => message.low_temperature = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&WeatherConditions> for FitDataRecord {
+    fn from(message: &WeatherConditions) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::WeatherConditions);
+        push_typed_field(&mut record, "weather_report", &message.weather_report);
+        push_typed_field(&mut record, "temperature", &message.temperature);
+        push_typed_field(&mut record, "condition", &message.condition);
+        push_typed_field(&mut record, "wind_direction", &message.wind_direction);
+        push_typed_field(&mut record, "wind_speed", &message.wind_speed);
+        push_typed_field(
+            &mut record,
+            "precipitation_probability",
+            &message.precipitation_probability,
+        );
+        push_typed_field(
+            &mut record,
+            "temperature_feels_like",
+            &message.temperature_feels_like,
+        );
+        push_typed_field(&mut record, "relative_humidity", &message.relative_humidity);
+        push_typed_field(&mut record, "location", &message.location);
+        push_typed_field(&mut record, "observed_at_time", &message.observed_at_time);
+        push_typed_field(
+            &mut record,
+            "observed_location_lat",
+            &message.observed_location_lat,
+        );
+        push_typed_field(
+            &mut record,
+            "observed_location_long",
+            &message.observed_location_long,
+        );
+        push_typed_field(&mut record, "day_of_week", &message.day_of_week);
+        push_typed_field(&mut record, "high_temperature", &message.high_temperature);
+        push_typed_field(&mut record, "low_temperature", &message.low_temperature);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct WeatherAlert {
+    pub report_id: Option<String>,
+    pub issue_time: Option<DateTime<TimestampTz>>,
+    pub expire_time: Option<DateTime<TimestampTz>>,
+    pub severity: Option<field_types::WeatherSeverity>,
+    pub r#type: Option<field_types::WeatherSevereType>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for WeatherAlert {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::WeatherAlert)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "report_id" => message.report_id = Some(typed_field_value(field)?),
+                "issue_time" => message.issue_time = Some(typed_field_value(field)?),
+                "expire_time" => message.expire_time = Some(typed_field_value(field)?),
+                "severity" => message.severity = Some(typed_field_value(field)?),
+                "type" => message.r#type = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&WeatherAlert> for FitDataRecord {
+    fn from(message: &WeatherAlert) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::WeatherAlert);
+        push_typed_field(&mut record, "report_id", &message.report_id);
+        push_typed_field(&mut record, "issue_time", &message.issue_time);
+        push_typed_field(&mut record, "expire_time", &message.expire_time);
+        push_typed_field(&mut record, "severity", &message.severity);
+        push_typed_field(&mut record, "type", &message.r#type);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct GpsMetadata {
+    pub timestamp_ms: Option<u16>,
+    pub position_lat: Option<i32>,
+    pub position_long: Option<i32>,
+    pub enhanced_altitude: Option<f64>,
+    pub enhanced_speed: Option<f64>,
+    pub heading: Option<f64>,
+    pub utc_timestamp: Option<DateTime<TimestampTz>>,
+    pub velocity: Option<Vec<f64>>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for GpsMetadata {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::GpsMetadata)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = Some(typed_field_value(field)?),
+                "position_lat" => message.position_lat = Some(typed_field_value(field)?),
+                "position_long" => message.position_long = Some(typed_field_value(field)?),
+                "enhanced_altitude" => message.enhanced_altitude = Some(typed_field_value(field)?),
+                "enhanced_speed" => message.enhanced_speed = Some(typed_field_value(field)?),
+                "heading" => message.heading = Some(typed_field_value(field)?),
+                "utc_timestamp" => message.utc_timestamp = Some(typed_field_value(field)?),
+                "velocity" => message.velocity = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&GpsMetadata> for FitDataRecord {
+    fn from(message: &GpsMetadata) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::GpsMetadata);
+        push_typed_field(&mut record, "timestamp_ms", &message.timestamp_ms);
+        push_typed_field(&mut record, "position_lat", &message.position_lat);
+        push_typed_field(&mut record, "position_long", &message.position_long);
+        push_typed_field(&mut record, "enhanced_altitude", &message.enhanced_altitude);
+        push_typed_field(&mut record, "enhanced_speed", &message.enhanced_speed);
+        push_typed_field(&mut record, "heading", &message.heading);
+        push_typed_field(&mut record, "utc_timestamp", &message.utc_timestamp);
+        push_typed_field(&mut record, "velocity", &message.velocity);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct CameraEvent {
+    pub timestamp_ms: Option<u16>,
+    pub camera_event_type: Option<field_types::CameraEventType>,
+    pub camera_file_uuid: Option<String>,
+    pub camera_orientation: Option<field_types::CameraOrientationType>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for CameraEvent {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::CameraEvent)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = Some(typed_field_value(field)?),
+                "camera_event_type" => message.camera_event_type = Some(typed_field_value(field)?),
+                "camera_file_uuid" => message.camera_file_uuid = Some(typed_field_value(field)?),
+                "camera_orientation" => {
+                    message.camera_orientation = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&CameraEvent> for FitDataRecord {
+    fn from(message: &CameraEvent) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::CameraEvent);
+        push_typed_field(&mut record, "timestamp_ms", &message.timestamp_ms);
+        push_typed_field(&mut record, "camera_event_type", &message.camera_event_type);
+        push_typed_field(&mut record, "camera_file_uuid", &message.camera_file_uuid);
+        push_typed_field(
+            &mut record,
+            "camera_orientation",
+            &message.camera_orientation,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct GyroscopeData {
+    pub timestamp_ms: Option<u16>,
+    pub sample_time_offset: Option<Vec<u16>>,
+    pub gyro_x: Option<Vec<u16>>,
+    pub gyro_y: Option<Vec<u16>>,
+    pub gyro_z: Option<Vec<u16>>,
+    pub calibrated_gyro_x: Option<Vec<f32>>,
+    pub calibrated_gyro_y: Option<Vec<f32>>,
+    pub calibrated_gyro_z: Option<Vec<f32>>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for GyroscopeData {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::GyroscopeData)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = Some(typed_field_value(field)?),
+                "sample_time_offset" => {
+                    message.sample_time_offset = Some(typed_field_value(field)?)
+                }
+                "gyro_x" => message.gyro_x = Some(typed_field_value(field)?),
+                "gyro_y" => message.gyro_y = Some(typed_field_value(field)?),
+                "gyro_z" => message.gyro_z = Some(typed_field_value(field)?),
+                "calibrated_gyro_x" => message.calibrated_gyro_x = Some(typed_field_value(field)?),
+                "calibrated_gyro_y" => message.calibrated_gyro_y = Some(typed_field_value(field)?),
+                "calibrated_gyro_z" => message.calibrated_gyro_z = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&GyroscopeData> for FitDataRecord {
+    fn from(message: &GyroscopeData) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::GyroscopeData);
+        push_typed_field(&mut record, "timestamp_ms", &message.timestamp_ms);
+        push_typed_field(
+            &mut record,
+            "sample_time_offset",
+            &message.sample_time_offset,
+        );
+        push_typed_field(&mut record, "gyro_x", &message.gyro_x);
+        push_typed_field(&mut record, "gyro_y", &message.gyro_y);
+        push_typed_field(&mut record, "gyro_z", &message.gyro_z);
+        push_typed_field(&mut record, "calibrated_gyro_x", &message.calibrated_gyro_x);
+        push_typed_field(&mut record, "calibrated_gyro_y", &message.calibrated_gyro_y);
+        push_typed_field(&mut record, "calibrated_gyro_z", &message.calibrated_gyro_z);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct AccelerometerData {
+    pub timestamp_ms: Option<u16>,
+    pub sample_time_offset: Option<Vec<u16>>,
+    pub accel_x: Option<Vec<u16>>,
+    pub accel_y: Option<Vec<u16>>,
+    pub accel_z: Option<Vec<u16>>,
+    pub calibrated_accel_x: Option<Vec<f32>>,
+    pub calibrated_accel_y: Option<Vec<f32>>,
+    pub calibrated_accel_z: Option<Vec<f32>>,
+    pub compressed_calibrated_accel_x: Option<Vec<i16>>,
+    pub compressed_calibrated_accel_y: Option<Vec<i16>>,
+    pub compressed_calibrated_accel_z: Option<Vec<i16>>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for AccelerometerData {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::AccelerometerData)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = Some(typed_field_value(field)?),
+                "sample_time_offset" => {
+                    message.sample_time_offset = Some(typed_field_value(field)?)
+                }
+                "accel_x" => message.accel_x = Some(typed_field_value(field)?),
+                "accel_y" => message.accel_y = Some(typed_field_value(field)?),
+                "accel_z" => message.accel_z = Some(typed_field_value(field)?),
+                "calibrated_accel_x" => {
+                    message.calibrated_accel_x = Some(typed_field_value(field)?)
+                }
+                "calibrated_accel_y" => {
+                    message.calibrated_accel_y = Some(typed_field_value(field)?)
+                }
+                "calibrated_accel_z" => {
+                    message.calibrated_accel_z = Some(typed_field_value(field)?)
+                }
+                "compressed_calibrated_accel_x" => {
+                    message.compressed_calibrated_accel_x = Some(typed_field_value(field)?)
+                }
+                "compressed_calibrated_accel_y" => {
+                    message.compressed_calibrated_accel_y = Some(typed_field_value(field)?)
+                }
+                "compressed_calibrated_accel_z" => {
+                    message.compressed_calibrated_accel_z = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&AccelerometerData> for FitDataRecord {
+    fn from(message: &AccelerometerData) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::AccelerometerData);
+        push_typed_field(&mut record, "timestamp_ms", &message.timestamp_ms);
+        push_typed_field(
+            &mut record,
+            "sample_time_offset",
+            &message.sample_time_offset,
+        );
+        push_typed_field(&mut record, "accel_x", &message.accel_x);
+        push_typed_field(&mut record, "accel_y", &message.accel_y);
+        push_typed_field(&mut record, "accel_z", &message.accel_z);
+        push_typed_field(
+            &mut record,
+            "calibrated_accel_x",
+            &message.calibrated_accel_x,
+        );
+        push_typed_field(
+            &mut record,
+            "calibrated_accel_y",
+            &message.calibrated_accel_y,
+        );
+        push_typed_field(
+            &mut record,
+            "calibrated_accel_z",
+            &message.calibrated_accel_z,
+        );
+        push_typed_field(
+            &mut record,
+            "compressed_calibrated_accel_x",
+            &message.compressed_calibrated_accel_x,
+        );
+        push_typed_field(
+            &mut record,
+            "compressed_calibrated_accel_y",
+            &message.compressed_calibrated_accel_y,
+        );
+        push_typed_field(
+            &mut record,
+            "compressed_calibrated_accel_z",
+            &message.compressed_calibrated_accel_z,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct MagnetometerData {
+    pub timestamp_ms: Option<u16>,
+    pub sample_time_offset: Option<Vec<u16>>,
+    pub mag_x: Option<Vec<u16>>,
+    pub mag_y: Option<Vec<u16>>,
+    pub mag_z: Option<Vec<u16>>,
+    pub calibrated_mag_x: Option<Vec<f32>>,
+    pub calibrated_mag_y: Option<Vec<f32>>,
+    pub calibrated_mag_z: Option<Vec<f32>>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for MagnetometerData {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::MagnetometerData)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = Some(typed_field_value(field)?),
+                "sample_time_offset" => {
+                    message.sample_time_offset = Some(typed_field_value(field)?)
+                }
+                "mag_x" => message.mag_x = Some(typed_field_value(field)?),
+                "mag_y" => message.mag_y = Some(typed_field_value(field)?),
+                "mag_z" => message.mag_z = Some(typed_field_value(field)?),
+                "calibrated_mag_x" => message.calibrated_mag_x = Some(typed_field_value(field)?),
+                "calibrated_mag_y" => message.calibrated_mag_y = Some(typed_field_value(field)?),
+                "calibrated_mag_z" => message.calibrated_mag_z = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&MagnetometerData> for FitDataRecord {
+    fn from(message: &MagnetometerData) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::MagnetometerData);
+        push_typed_field(&mut record, "timestamp_ms", &message.timestamp_ms);
+        push_typed_field(
+            &mut record,
+            "sample_time_offset",
+            &message.sample_time_offset,
+        );
+        push_typed_field(&mut record, "mag_x", &message.mag_x);
+        push_typed_field(&mut record, "mag_y", &message.mag_y);
+        push_typed_field(&mut record, "mag_z", &message.mag_z);
+        push_typed_field(&mut record, "calibrated_mag_x", &message.calibrated_mag_x);
+        push_typed_field(&mut record, "calibrated_mag_y", &message.calibrated_mag_y);
+        push_typed_field(&mut record, "calibrated_mag_z", &message.calibrated_mag_z);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct BarometerData {
+    pub timestamp_ms: Option<u16>,
+    pub sample_time_offset: Option<Vec<u16>>,
+    pub baro_pres: Option<Vec<u32>>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for BarometerData {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::BarometerData)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = Some(typed_field_value(field)?),
+                "sample_time_offset" => {
+                    message.sample_time_offset = Some(typed_field_value(field)?)
+                }
+                "baro_pres" => message.baro_pres = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&BarometerData> for FitDataRecord {
+    fn from(message: &BarometerData) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::BarometerData);
+        push_typed_field(&mut record, "timestamp_ms", &message.timestamp_ms);
+        push_typed_field(
+            &mut record,
+            "sample_time_offset",
+            &message.sample_time_offset,
+        );
+        push_typed_field(&mut record, "baro_pres", &message.baro_pres);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ThreeDSensorCalibration {
+    pub sensor_type: Option<field_types::SensorType>,
+    pub calibration_factor: Option<u32>,
+    pub accel_cal_factor: Option<u32>,
+    pub gyro_cal_factor: Option<u32>,
+    pub calibration_divisor: Option<u32>,
+    pub level_shift: Option<u32>,
+    pub offset_cal: Option<Vec<i32>>,
+    pub orientation_matrix: Option<Vec<f64>>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for ThreeDSensorCalibration {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::ThreeDSensorCalibration)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "sensor_type" => message.sensor_type = Some(typed_field_value(field)?),
+                "calibration_factor" => {
+                    message.calibration_factor = Some(typed_field_value(field)?)
+                }
+                "accel_cal_factor" => message.accel_cal_factor = Some(typed_field_value(field)?),
+                "gyro_cal_factor" => message.gyro_cal_factor = Some(typed_field_value(field)?),
+                "calibration_divisor" => {
+                    message.calibration_divisor = Some(typed_field_value(field)?)
+                }
+                "level_shift" => message.level_shift = Some(typed_field_value(field)?),
+                "offset_cal" => message.offset_cal = Some(typed_field_value(field)?),
+                "orientation_matrix" => {
+                    message.orientation_matrix = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&ThreeDSensorCalibration> for FitDataRecord {
+    fn from(message: &ThreeDSensorCalibration) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::ThreeDSensorCalibration);
+        push_typed_field(&mut record, "sensor_type", &message.sensor_type);
+        push_typed_field(
+            &mut record,
+            "calibration_factor",
+            &message.calibration_factor,
+        );
+        push_typed_field(&mut record, "accel_cal_factor", &message.accel_cal_factor);
+        push_typed_field(&mut record, "gyro_cal_factor", &message.gyro_cal_factor);
+        push_typed_field(
+            &mut record,
+            "calibration_divisor",
+            &message.calibration_divisor,
+        );
+        push_typed_field(&mut record, "level_shift", &message.level_shift);
+        push_typed_field(&mut record, "offset_cal", &message.offset_cal);
+        push_typed_field(
+            &mut record,
+            "orientation_matrix",
+            &message.orientation_matrix,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct OneDSensorCalibration {
+    pub sensor_type: Option<field_types::SensorType>,
+    pub calibration_factor: Option<u32>,
+    pub baro_cal_factor: Option<u32>,
+    pub calibration_divisor: Option<u32>,
+    pub level_shift: Option<u32>,
+    pub offset_cal: Option<i32>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for OneDSensorCalibration {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::OneDSensorCalibration)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "sensor_type" => message.sensor_type = Some(typed_field_value(field)?),
+                "calibration_factor" => {
+                    message.calibration_factor = Some(typed_field_value(field)?)
+                }
+                "baro_cal_factor" => message.baro_cal_factor = Some(typed_field_value(field)?),
+                "calibration_divisor" => {
+                    message.calibration_divisor = Some(typed_field_value(field)?)
+                }
+                "level_shift" => message.level_shift = Some(typed_field_value(field)?),
+                "offset_cal" => message.offset_cal = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&OneDSensorCalibration> for FitDataRecord {
+    fn from(message: &OneDSensorCalibration) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::OneDSensorCalibration);
+        push_typed_field(&mut record, "sensor_type", &message.sensor_type);
+        push_typed_field(
+            &mut record,
+            "calibration_factor",
+            &message.calibration_factor,
+        );
+        push_typed_field(&mut record, "baro_cal_factor", &message.baro_cal_factor);
+        push_typed_field(
+            &mut record,
+            "calibration_divisor",
+            &message.calibration_divisor,
+        );
+        push_typed_field(&mut record, "level_shift", &message.level_shift);
+        push_typed_field(&mut record, "offset_cal", &message.offset_cal);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct VideoFrame {
+    pub timestamp_ms: Option<u16>,
+    pub frame_number: Option<u32>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for VideoFrame {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::VideoFrame)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = Some(typed_field_value(field)?),
+                "frame_number" => message.frame_number = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&VideoFrame> for FitDataRecord {
+    fn from(message: &VideoFrame) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::VideoFrame);
+        push_typed_field(&mut record, "timestamp_ms", &message.timestamp_ms);
+        push_typed_field(&mut record, "frame_number", &message.frame_number);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ObdiiData {
+    pub timestamp_ms: Option<u16>,
+    pub time_offset: Option<Vec<u16>>,
+    pub pid: Option<u8>,
+    pub raw_data: Option<Vec<u8>>,
+    pub pid_data_size: Option<Vec<u8>>,
+    pub system_time: Option<Vec<u32>>,
+    pub start_timestamp: Option<DateTime<TimestampTz>>,
+    pub start_timestamp_ms: Option<u16>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for ObdiiData {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::ObdiiData)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = Some(typed_field_value(field)?),
+                "time_offset" => message.time_offset = Some(typed_field_value(field)?),
+                "pid" => message.pid = Some(typed_field_value(field)?),
+                "raw_data" => message.raw_data = Some(typed_field_value(field)?),
+                "pid_data_size" => message.pid_data_size = Some(typed_field_value(field)?),
+                "system_time" => message.system_time = Some(typed_field_value(field)?),
+                "start_timestamp" => message.start_timestamp = Some(typed_field_value(field)?),
+                "start_timestamp_ms" => {
+                    message.start_timestamp_ms = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&ObdiiData> for FitDataRecord {
+    fn from(message: &ObdiiData) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::ObdiiData);
+        push_typed_field(&mut record, "timestamp_ms", &message.timestamp_ms);
+        push_typed_field(&mut record, "time_offset", &message.time_offset);
+        push_typed_field(&mut record, "pid", &message.pid);
+        push_typed_field(&mut record, "raw_data", &message.raw_data);
+        push_typed_field(&mut record, "pid_data_size", &message.pid_data_size);
+        push_typed_field(&mut record, "system_time", &message.system_time);
+        push_typed_field(&mut record, "start_timestamp", &message.start_timestamp);
+        push_typed_field(
+            &mut record,
+            "start_timestamp_ms",
+            &message.start_timestamp_ms,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct NmeaSentence {
+    pub timestamp_ms: Option<u16>,
+    pub sentence: Option<String>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for NmeaSentence {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::NmeaSentence)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = Some(typed_field_value(field)?),
+                "sentence" => message.sentence = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&NmeaSentence> for FitDataRecord {
+    fn from(message: &NmeaSentence) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::NmeaSentence);
+        push_typed_field(&mut record, "timestamp_ms", &message.timestamp_ms);
+        push_typed_field(&mut record, "sentence", &message.sentence);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct AviationAttitude {
+    pub timestamp_ms: Option<u16>,
+    pub system_time: Option<Vec<u32>>,
+    pub pitch: Option<Vec<f64>>,
+    pub roll: Option<Vec<f64>>,
+    pub accel_lateral: Option<Vec<f64>>,
+    pub accel_normal: Option<Vec<f64>>,
+    pub turn_rate: Option<Vec<f64>>,
+    pub stage: Option<Vec<field_types::AttitudeStage>>,
+    pub attitude_stage_complete: Option<Vec<u8>>,
+    pub track: Option<Vec<f64>>,
+    pub validity: Option<Vec<field_types::AttitudeValidity>>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for AviationAttitude {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::AviationAttitude)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "timestamp_ms" => message.timestamp_ms = Some(typed_field_value(field)?),
+                "system_time" => message.system_time = Some(typed_field_value(field)?),
+                "pitch" => message.pitch = Some(typed_field_value(field)?),
+                "roll" => message.roll = Some(typed_field_value(field)?),
+                "accel_lateral" => message.accel_lateral = Some(typed_field_value(field)?),
+                "accel_normal" => message.accel_normal = Some(typed_field_value(field)?),
+                "turn_rate" => message.turn_rate = Some(typed_field_value(field)?),
+                "stage" => message.stage = Some(typed_field_value(field)?),
+                "attitude_stage_complete" => {
+                    message.attitude_stage_complete = Some(typed_field_value(field)?)
+                }
+                "track" => message.track = Some(typed_field_value(field)?),
+                "validity" => message.validity = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&AviationAttitude> for FitDataRecord {
+    fn from(message: &AviationAttitude) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::AviationAttitude);
+        push_typed_field(&mut record, "timestamp_ms", &message.timestamp_ms);
+        push_typed_field(&mut record, "system_time", &message.system_time);
+        push_typed_field(&mut record, "pitch", &message.pitch);
+        push_typed_field(&mut record, "roll", &message.roll);
+        push_typed_field(&mut record, "accel_lateral", &message.accel_lateral);
+        push_typed_field(&mut record, "accel_normal", &message.accel_normal);
+        push_typed_field(&mut record, "turn_rate", &message.turn_rate);
+        push_typed_field(&mut record, "stage", &message.stage);
+        push_typed_field(
+            &mut record,
+            "attitude_stage_complete",
+            &message.attitude_stage_complete,
+        );
+        push_typed_field(&mut record, "track", &message.track);
+        push_typed_field(&mut record, "validity", &message.validity);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Video {
+    pub url: Option<String>,
+    pub hosting_provider: Option<String>,
+    pub duration: Option<u32>,
+}
+impl TryFrom<&FitDataRecord> for Video {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Video)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "url" => message.url = Some(typed_field_value(field)?),
+                "hosting_provider" => message.hosting_provider = Some(typed_field_value(field)?),
+                "duration" => message.duration = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Video> for FitDataRecord {
+    fn from(message: &Video) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Video);
+        push_typed_field(&mut record, "url", &message.url);
+        push_typed_field(&mut record, "hosting_provider", &message.hosting_provider);
+        push_typed_field(&mut record, "duration", &message.duration);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct VideoTitle {
+    pub message_count: Option<u16>,
+    pub text: Option<String>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for VideoTitle {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::VideoTitle)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "message_count" => message.message_count = Some(typed_field_value(field)?),
+                "text" => message.text = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&VideoTitle> for FitDataRecord {
+    fn from(message: &VideoTitle) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::VideoTitle);
+        push_typed_field(&mut record, "message_count", &message.message_count);
+        push_typed_field(&mut record, "text", &message.text);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct VideoDescription {
+    pub message_count: Option<u16>,
+    pub text: Option<String>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for VideoDescription {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::VideoDescription)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "message_count" => message.message_count = Some(typed_field_value(field)?),
+                "text" => message.text = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&VideoDescription> for FitDataRecord {
+    fn from(message: &VideoDescription) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::VideoDescription);
+        push_typed_field(&mut record, "message_count", &message.message_count);
+        push_typed_field(&mut record, "text", &message.text);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct VideoClip {
+    pub clip_number: Option<u16>,
+    pub start_timestamp: Option<DateTime<TimestampTz>>,
+    pub start_timestamp_ms: Option<u16>,
+    pub end_timestamp: Option<DateTime<TimestampTz>>,
+    pub end_timestamp_ms: Option<u16>,
+    pub clip_start: Option<u32>,
+    pub clip_end: Option<u32>,
+}
+impl TryFrom<&FitDataRecord> for VideoClip {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::VideoClip)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "clip_number" => message.clip_number = Some(typed_field_value(field)?),
+                "start_timestamp" => message.start_timestamp = Some(typed_field_value(field)?),
+                "start_timestamp_ms" => {
+                    message.start_timestamp_ms = Some(typed_field_value(field)?)
+                }
+                "end_timestamp" => message.end_timestamp = Some(typed_field_value(field)?),
+                "end_timestamp_ms" => message.end_timestamp_ms = Some(typed_field_value(field)?),
+                "clip_start" => message.clip_start = Some(typed_field_value(field)?),
+                "clip_end" => message.clip_end = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&VideoClip> for FitDataRecord {
+    fn from(message: &VideoClip) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::VideoClip);
+        push_typed_field(&mut record, "clip_number", &message.clip_number);
+        push_typed_field(&mut record, "start_timestamp", &message.start_timestamp);
+        push_typed_field(
+            &mut record,
+            "start_timestamp_ms",
+            &message.start_timestamp_ms,
+        );
+        push_typed_field(&mut record, "end_timestamp", &message.end_timestamp);
+        push_typed_field(&mut record, "end_timestamp_ms", &message.end_timestamp_ms);
+        push_typed_field(&mut record, "clip_start", &message.clip_start);
+        push_typed_field(&mut record, "clip_end", &message.clip_end);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Set {
+    pub duration: Option<f64>,
+    pub repetitions: Option<u16>,
+    pub weight: Option<f64>,
+    pub set_type: Option<field_types::SetType>,
+    pub start_time: Option<DateTime<TimestampTz>>,
+    pub category: Option<field_types::ExerciseCategory>,
+    pub category_subtype: Option<u16>,
+    pub weight_display_unit: Option<field_types::FitBaseUnit>,
+    pub message_index: Option<field_types::MessageIndex>,
+    pub wkt_step_index: Option<field_types::MessageIndex>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for Set {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Set)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "duration" => message.duration = Some(typed_field_value(field)?),
+                "repetitions" => message.repetitions = Some(typed_field_value(field)?),
+                "weight" => message.weight = Some(typed_field_value(field)?),
+                "set_type" => message.set_type = Some(typed_field_value(field)?),
+                "start_time" => message.start_time = Some(typed_field_value(field)?),
+                "category" => message.category = Some(typed_field_value(field)?),
+                "category_subtype" => message.category_subtype = Some(typed_field_value(field)?),
+                "weight_display_unit" => {
+                    message.weight_display_unit = Some(typed_field_value(field)?)
+                }
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                "wkt_step_index" => message.wkt_step_index = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Set> for FitDataRecord {
+    fn from(message: &Set) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Set);
+        push_typed_field(&mut record, "duration", &message.duration);
+        push_typed_field(&mut record, "repetitions", &message.repetitions);
+        push_typed_field(&mut record, "weight", &message.weight);
+        push_typed_field(&mut record, "set_type", &message.set_type);
+        push_typed_field(&mut record, "start_time", &message.start_time);
+        push_typed_field(&mut record, "category", &message.category);
+        push_typed_field(&mut record, "category_subtype", &message.category_subtype);
+        push_typed_field(
+            &mut record,
+            "weight_display_unit",
+            &message.weight_display_unit,
+        );
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        push_typed_field(&mut record, "wkt_step_index", &message.wkt_step_index);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Jump {
+    pub distance: Option<f32>,
+    pub height: Option<f32>,
+    pub rotations: Option<u8>,
+    pub hang_time: Option<f32>,
+    pub score: Option<f32>,
+    pub position_lat: Option<i32>,
+    pub position_long: Option<i32>,
+    pub speed: Option<f64>,
+    pub enhanced_speed: Option<f64>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for Jump {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Jump)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "distance" => message.distance = Some(typed_field_value(field)?),
+                "height" => message.height = Some(typed_field_value(field)?),
+                "rotations" => message.rotations = Some(typed_field_value(field)?),
+                "hang_time" => message.hang_time = Some(typed_field_value(field)?),
+                "score" => message.score = Some(typed_field_value(field)?),
+                "position_lat" => message.position_lat = Some(typed_field_value(field)?),
+                "position_long" => message.position_long = Some(typed_field_value(field)?),
+                "speed" => message.speed = Some(typed_field_value(field)?),
+                "enhanced_speed" => message.enhanced_speed = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Jump> for FitDataRecord {
+    fn from(message: &Jump) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Jump);
+        push_typed_field(&mut record, "distance", &message.distance);
+        push_typed_field(&mut record, "height", &message.height);
+        push_typed_field(&mut record, "rotations", &message.rotations);
+        push_typed_field(&mut record, "hang_time", &message.hang_time);
+        push_typed_field(&mut record, "score", &message.score);
+        push_typed_field(&mut record, "position_lat", &message.position_lat);
+        push_typed_field(&mut record, "position_long", &message.position_long);
+        push_typed_field(&mut record, "speed", &message.speed);
+        push_typed_field(&mut record, "enhanced_speed", &message.enhanced_speed);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Split {
+    pub split_type: Option<field_types::SplitType>,
+    pub total_elapsed_time: Option<f64>,
+    pub total_timer_time: Option<f64>,
+    pub total_distance: Option<f64>,
+    pub start_time: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for Split {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Split)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "split_type" => message.split_type = Some(typed_field_value(field)?),
+                "total_elapsed_time" => {
+                    message.total_elapsed_time = Some(typed_field_value(field)?)
+                }
+                "total_timer_time" => message.total_timer_time = Some(typed_field_value(field)?),
+                "total_distance" => message.total_distance = Some(typed_field_value(field)?),
+                "start_time" => message.start_time = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Split> for FitDataRecord {
+    fn from(message: &Split) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Split);
+        push_typed_field(&mut record, "split_type", &message.split_type);
+        push_typed_field(
+            &mut record,
+            "total_elapsed_time",
+            &message.total_elapsed_time,
+        );
+        push_typed_field(&mut record, "total_timer_time", &message.total_timer_time);
+        push_typed_field(&mut record, "total_distance", &message.total_distance);
+        push_typed_field(&mut record, "start_time", &message.start_time);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ClimbPro {
+    pub position_lat: Option<i32>,
+    pub position_long: Option<i32>,
+    pub climb_pro_event: Option<field_types::ClimbProEvent>,
+    pub climb_number: Option<u16>,
+    pub climb_category: Option<u8>,
+    pub current_dist: Option<f32>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for ClimbPro {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::ClimbPro)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "position_lat" => message.position_lat = Some(typed_field_value(field)?),
+                "position_long" => message.position_long = Some(typed_field_value(field)?),
+                "climb_pro_event" => message.climb_pro_event = Some(typed_field_value(field)?),
+                "climb_number" => message.climb_number = Some(typed_field_value(field)?),
+                "climb_category" => message.climb_category = Some(typed_field_value(field)?),
+                "current_dist" => message.current_dist = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&ClimbPro> for FitDataRecord {
+    fn from(message: &ClimbPro) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::ClimbPro);
+        push_typed_field(&mut record, "position_lat", &message.position_lat);
+        push_typed_field(&mut record, "position_long", &message.position_long);
+        push_typed_field(&mut record, "climb_pro_event", &message.climb_pro_event);
+        push_typed_field(&mut record, "climb_number", &message.climb_number);
+        push_typed_field(&mut record, "climb_category", &message.climb_category);
+        push_typed_field(&mut record, "current_dist", &message.current_dist);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+/// Must be logged before developer field is used
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct FieldDescription {
+    pub developer_data_index: Option<u8>,
+    pub field_definition_number: Option<u8>,
+    pub fit_base_type_id: Option<field_types::FitBaseType>,
+    pub field_name: Option<Vec<String>>,
+    pub array: Option<u8>,
+    pub components: Option<String>,
+    pub scale: Option<u8>,
+    pub offset: Option<i8>,
+    pub units: Option<Vec<String>>,
+    pub bits: Option<String>,
+    pub accumulate: Option<String>,
+    pub fit_base_unit_id: Option<field_types::FitBaseUnit>,
+    pub native_mesg_num: Option<field_types::MesgNum>,
+    pub native_field_num: Option<u8>,
+}
+impl TryFrom<&FitDataRecord> for FieldDescription {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::FieldDescription)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "developer_data_index" => {
+                    message.developer_data_index = Some(typed_field_value(field)?)
+                }
+                "field_definition_number" => {
+                    message.field_definition_number = Some(typed_field_value(field)?)
+                }
+                "fit_base_type_id" => message.fit_base_type_id = Some(typed_field_value(field)?),
+                "field_name" => message.field_name = Some(typed_field_value(field)?),
+                "array" => message.array = Some(typed_field_value(field)?),
+                "components" => message.components = Some(typed_field_value(field)?),
+                "scale" => message.scale = Some(typed_field_value(field)?),
+                "offset" => message.offset = Some(typed_field_value(field)?),
+                "units" => message.units = Some(typed_field_value(field)?),
+                "bits" => message.bits = Some(typed_field_value(field)?),
+                "accumulate" => message.accumulate = Some(typed_field_value(field)?),
+                "fit_base_unit_id" => message.fit_base_unit_id = Some(typed_field_value(field)?),
+                "native_mesg_num" => message.native_mesg_num = Some(typed_field_value(field)?),
+                "native_field_num" => message.native_field_num = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&FieldDescription> for FitDataRecord {
+    fn from(message: &FieldDescription) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::FieldDescription);
+        push_typed_field(
+            &mut record,
+            "developer_data_index",
+            &message.developer_data_index,
+        );
+        push_typed_field(
+            &mut record,
+            "field_definition_number",
+            &message.field_definition_number,
+        );
+        push_typed_field(&mut record, "fit_base_type_id", &message.fit_base_type_id);
+        push_typed_field(&mut record, "field_name", &message.field_name);
+        push_typed_field(&mut record, "array", &message.array);
+        push_typed_field(&mut record, "components", &message.components);
+        push_typed_field(&mut record, "scale", &message.scale);
+        push_typed_field(&mut record, "offset", &message.offset);
+        push_typed_field(&mut record, "units", &message.units);
+        push_typed_field(&mut record, "bits", &message.bits);
+        push_typed_field(&mut record, "accumulate", &message.accumulate);
+        push_typed_field(&mut record, "fit_base_unit_id", &message.fit_base_unit_id);
+        push_typed_field(&mut record, "native_mesg_num", &message.native_mesg_num);
+        push_typed_field(&mut record, "native_field_num", &message.native_field_num);
+        record
+    }
+}
+/// Must be logged before field description
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct DeveloperDataId {
+    pub developer_id: Option<Vec<u8>>,
+    pub application_id: Option<Vec<u8>>,
+    pub manufacturer_id: Option<field_types::Manufacturer>,
+    pub developer_data_index: Option<u8>,
+    pub application_version: Option<u32>,
+}
+impl TryFrom<&FitDataRecord> for DeveloperDataId {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::DeveloperDataId)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "developer_id" => message.developer_id = Some(typed_field_value(field)?),
+                "application_id" => message.application_id = Some(typed_field_value(field)?),
+                "manufacturer_id" => message.manufacturer_id = Some(typed_field_value(field)?),
+                "developer_data_index" => {
+                    message.developer_data_index = Some(typed_field_value(field)?)
+                }
+                "application_version" => {
+                    message.application_version = Some(typed_field_value(field)?)
+                }
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&DeveloperDataId> for FitDataRecord {
+    fn from(message: &DeveloperDataId) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::DeveloperDataId);
+        push_typed_field(&mut record, "developer_id", &message.developer_id);
+        push_typed_field(&mut record, "application_id", &message.application_id);
+        push_typed_field(&mut record, "manufacturer_id", &message.manufacturer_id);
+        push_typed_field(
+            &mut record,
+            "developer_data_index",
+            &message.developer_data_index,
+        );
+        push_typed_field(
+            &mut record,
+            "application_version",
+            &message.application_version,
+        );
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Course {
+    pub sport: Option<field_types::Sport>,
+    pub name: Option<String>,
+    pub capabilities: Option<field_types::CourseCapabilities>,
+    pub sub_sport: Option<field_types::SubSport>,
+}
+impl TryFrom<&FitDataRecord> for Course {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Course)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "sport" => message.sport = Some(typed_field_value(field)?),
+                "name" => message.name = Some(typed_field_value(field)?),
+                "capabilities" => message.capabilities = Some(typed_field_value(field)?),
+                "sub_sport" => message.sub_sport = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Course> for FitDataRecord {
+    fn from(message: &Course) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Course);
+        push_typed_field(&mut record, "sport", &message.sport);
+        push_typed_field(&mut record, "name", &message.name);
+        push_typed_field(&mut record, "capabilities", &message.capabilities);
+        push_typed_field(&mut record, "sub_sport", &message.sub_sport);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct CoursePoint {
+    pub timestamp: Option<DateTime<TimestampTz>>,
+    pub position_lat: Option<i32>,
+    pub position_long: Option<i32>,
+    pub distance: Option<f64>,
+    pub r#type: Option<field_types::CoursePoint>,
+    pub name: Option<String>,
+    pub favorite: Option<bool>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for CoursePoint {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::CoursePoint)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                "position_lat" => message.position_lat = Some(typed_field_value(field)?),
+                "position_long" => message.position_long = Some(typed_field_value(field)?),
+                "distance" => message.distance = Some(typed_field_value(field)?),
+                "type" => message.r#type = Some(typed_field_value(field)?),
+                "name" => message.name = Some(typed_field_value(field)?),
+                "favorite" => message.favorite = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&CoursePoint> for FitDataRecord {
+    fn from(message: &CoursePoint) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::CoursePoint);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        push_typed_field(&mut record, "position_lat", &message.position_lat);
+        push_typed_field(&mut record, "position_long", &message.position_long);
+        push_typed_field(&mut record, "distance", &message.distance);
+        push_typed_field(&mut record, "type", &message.r#type);
+        push_typed_field(&mut record, "name", &message.name);
+        push_typed_field(&mut record, "favorite", &message.favorite);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+/// Unique Identification data for a segment file
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SegmentId {
+    pub name: Option<String>,
+    pub uuid: Option<String>,
+    pub sport: Option<field_types::Sport>,
+    pub enabled: Option<bool>,
+    pub user_profile_primary_key: Option<u32>,
+    pub device_id: Option<u32>,
+    pub default_race_leader: Option<u8>,
+    pub delete_status: Option<field_types::SegmentDeleteStatus>,
+    pub selection_type: Option<field_types::SegmentSelectionType>,
+}
+impl TryFrom<&FitDataRecord> for SegmentId {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::SegmentId)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "name" => message.name = Some(typed_field_value(field)?),
+                "uuid" => message.uuid = Some(typed_field_value(field)?),
+                "sport" => message.sport = Some(typed_field_value(field)?),
+                "enabled" => message.enabled = Some(typed_field_value(field)?),
+                "user_profile_primary_key" => {
+                    message.user_profile_primary_key = Some(typed_field_value(field)?)
+                }
+                "device_id" => message.device_id = Some(typed_field_value(field)?),
+                "default_race_leader" => {
+                    message.default_race_leader = Some(typed_field_value(field)?)
+                }
+                "delete_status" => message.delete_status = Some(typed_field_value(field)?),
+                "selection_type" => message.selection_type = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&SegmentId> for FitDataRecord {
+    fn from(message: &SegmentId) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::SegmentId);
+        push_typed_field(&mut record, "name", &message.name);
+        push_typed_field(&mut record, "uuid", &message.uuid);
+        push_typed_field(&mut record, "sport", &message.sport);
+        push_typed_field(&mut record, "enabled", &message.enabled);
+        push_typed_field(
+            &mut record,
+            "user_profile_primary_key",
+            &message.user_profile_primary_key,
+        );
+        push_typed_field(&mut record, "device_id", &message.device_id);
+        push_typed_field(
+            &mut record,
+            "default_race_leader",
+            &message.default_race_leader,
+        );
+        push_typed_field(&mut record, "delete_status", &message.delete_status);
+        push_typed_field(&mut record, "selection_type", &message.selection_type);
+        record
+    }
+}
+/// Unique Identification data for an individual segment leader within a segment file
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SegmentLeaderboardEntry {
+    pub name: Option<String>,
+    pub r#type: Option<field_types::SegmentLeaderboardType>,
+    pub group_primary_key: Option<u32>,
+    pub activity_id: Option<u32>,
+    pub segment_time: Option<f64>,
+    pub activity_id_string: Option<String>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for SegmentLeaderboardEntry {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::SegmentLeaderboardEntry)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "name" => message.name = Some(typed_field_value(field)?),
+                "type" => message.r#type = Some(typed_field_value(field)?),
+                "group_primary_key" => message.group_primary_key = Some(typed_field_value(field)?),
+                "activity_id" => message.activity_id = Some(typed_field_value(field)?),
+                "segment_time" => message.segment_time = Some(typed_field_value(field)?),
+                "activity_id_string" => {
+                    message.activity_id_string = Some(typed_field_value(field)?)
+                }
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&SegmentLeaderboardEntry> for FitDataRecord {
+    fn from(message: &SegmentLeaderboardEntry) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::SegmentLeaderboardEntry);
+        push_typed_field(&mut record, "name", &message.name);
+        push_typed_field(&mut record, "type", &message.r#type);
+        push_typed_field(&mut record, "group_primary_key", &message.group_primary_key);
+        push_typed_field(&mut record, "activity_id", &message.activity_id);
+        push_typed_field(&mut record, "segment_time", &message.segment_time);
+        push_typed_field(
+            &mut record,
+            "activity_id_string",
+            &message.activity_id_string,
+        );
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+/// Navigation and race evaluation point for a segment decribing a point along the segment path and time it took each segment leader to reach that point
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SegmentPoint {
+    pub position_lat: Option<i32>,
+    pub position_long: Option<i32>,
+    pub distance: Option<f64>,
+    pub altitude: Option<f64>,
+    pub leader_time: Option<Vec<f64>>,
+    pub enhanced_altitude: Option<f64>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for SegmentPoint {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::SegmentPoint)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "position_lat" => message.position_lat = Some(typed_field_value(field)?),
+                "position_long" => message.position_long = Some(typed_field_value(field)?),
+                "distance" => message.distance = Some(typed_field_value(field)?),
+                "altitude" => message.altitude = Some(typed_field_value(field)?),
+                "leader_time" => message.leader_time = Some(typed_field_value(field)?),
+                "enhanced_altitude" => message.enhanced_altitude = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&SegmentPoint> for FitDataRecord {
+    fn from(message: &SegmentPoint) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::SegmentPoint);
+        push_typed_field(&mut record, "position_lat", &message.position_lat);
+        push_typed_field(&mut record, "position_long", &message.position_long);
+        push_typed_field(&mut record, "distance", &message.distance);
+        push_typed_field(&mut record, "altitude", &message.altitude);
+        push_typed_field(&mut record, "leader_time", &message.leader_time);
+        push_typed_field(&mut record, "enhanced_altitude", &message.enhanced_altitude);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SegmentLap {
+    pub event: Option<field_types::Event>,
+    pub event_type: Option<field_types::EventType>,
+    pub start_time: Option<DateTime<TimestampTz>>,
+    pub start_position_lat: Option<i32>,
+    pub start_position_long: Option<i32>,
+    pub end_position_lat: Option<i32>,
+    pub end_position_long: Option<i32>,
+    pub total_elapsed_time: Option<f64>,
+    pub total_timer_time: Option<f64>,
+    pub total_distance: Option<f64>,
+    pub total_cycles: Option<u32>,
+    pub total_strokes: Option<u32>,
+    pub total_calories: Option<u16>,
+    pub total_fat_calories: Option<u16>,
+    pub avg_speed: Option<f64>,
+    pub max_speed: Option<f64>,
+    pub avg_heart_rate: Option<u8>,
+    pub max_heart_rate: Option<u8>,
+    pub avg_cadence: Option<u8>,
+    pub max_cadence: Option<u8>,
+    pub avg_power: Option<u16>,
+    pub max_power: Option<u16>,
+    pub total_ascent: Option<u16>,
+    pub total_descent: Option<u16>,
+    pub sport: Option<field_types::Sport>,
+    pub event_group: Option<u8>,
+    pub nec_lat: Option<i32>,
+    pub nec_long: Option<i32>,
+    pub swc_lat: Option<i32>,
+    pub swc_long: Option<i32>,
+    pub name: Option<String>,
+    pub normalized_power: Option<u16>,
+    pub left_right_balance: Option<field_types::LeftRightBalance100>,
+    pub sub_sport: Option<field_types::SubSport>,
+    pub total_work: Option<u32>,
+    pub avg_altitude: Option<f64>,
+    pub max_altitude: Option<f64>,
+    pub gps_accuracy: Option<u8>,
+    pub avg_grade: Option<f64>,
+    pub avg_pos_grade: Option<f64>,
+    pub avg_neg_grade: Option<f64>,
+    pub max_pos_grade: Option<f64>,
+    pub max_neg_grade: Option<f64>,
+    pub avg_temperature: Option<i8>,
+    pub max_temperature: Option<i8>,
+    pub total_moving_time: Option<f64>,
+    pub avg_pos_vertical_speed: Option<f64>,
+    pub avg_neg_vertical_speed: Option<f64>,
+    pub max_pos_vertical_speed: Option<f64>,
+    pub max_neg_vertical_speed: Option<f64>,
+    pub time_in_hr_zone: Option<Vec<f64>>,
+    pub time_in_speed_zone: Option<Vec<f64>>,
+    pub time_in_cadence_zone: Option<Vec<f64>>,
+    pub time_in_power_zone: Option<Vec<f64>>,
+    pub repetition_num: Option<u16>,
+    pub min_altitude: Option<f64>,
+    pub min_heart_rate: Option<u8>,
+    pub active_time: Option<f64>,
+    pub wkt_step_index: Option<field_types::MessageIndex>,
+    pub sport_event: Option<field_types::SportEvent>,
+    pub avg_left_torque_effectiveness: Option<f64>,
+    pub avg_right_torque_effectiveness: Option<f64>,
+    pub avg_left_pedal_smoothness: Option<f64>,
+    pub avg_right_pedal_smoothness: Option<f64>,
+    pub avg_combined_pedal_smoothness: Option<f64>,
+    pub status: Option<field_types::SegmentLapStatus>,
+    pub uuid: Option<String>,
+    pub avg_fractional_cadence: Option<f64>,
+    pub max_fractional_cadence: Option<f64>,
+    pub total_fractional_cycles: Option<f64>,
+    pub front_gear_shift_count: Option<u16>,
+    pub rear_gear_shift_count: Option<u16>,
+    pub time_standing: Option<f64>,
+    pub stand_count: Option<u16>,
+    pub avg_left_pco: Option<i8>,
+    pub avg_right_pco: Option<i8>,
+    pub avg_left_power_phase: Option<Vec<f64>>,
+    pub avg_left_power_phase_peak: Option<Vec<f64>>,
+    pub avg_right_power_phase: Option<Vec<f64>>,
+    pub avg_right_power_phase_peak: Option<Vec<f64>>,
+    pub avg_power_position: Option<Vec<u16>>,
+    pub max_power_position: Option<Vec<u16>>,
+    pub avg_cadence_position: Option<Vec<u8>>,
+    pub max_cadence_position: Option<Vec<u8>>,
+    pub manufacturer: Option<field_types::Manufacturer>,
+    pub total_grit: Option<f32>,
+    pub total_flow: Option<f32>,
+    pub avg_grit: Option<f32>,
+    pub avg_flow: Option<f32>,
+    pub total_fractional_ascent: Option<f64>,
+    pub total_fractional_descent: Option<f64>,
+    pub enhanced_avg_altitude: Option<f64>,
+    pub enhanced_max_altitude: Option<f64>,
+    pub enhanced_min_altitude: Option<f64>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for SegmentLap {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::SegmentLap)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "event" => message.event = Some(typed_field_value(field)?),
+                "event_type" => message.event_type = Some(typed_field_value(field)?),
+                "start_time" => message.start_time = Some(typed_field_value(field)?),
+                "start_position_lat" => {
+                    message.start_position_lat = Some(typed_field_value(field)?)
+                }
+                "start_position_long" => {
+                    message.start_position_long = Some(typed_field_value(field)?)
+                }
+                "end_position_lat" => message.end_position_lat = Some(typed_field_value(field)?),
+                "end_position_long" => message.end_position_long = Some(typed_field_value(field)?),
+                "total_elapsed_time" => {
+                    message.total_elapsed_time = Some(typed_field_value(field)?)
+                }
+                "total_timer_time" => message.total_timer_time = Some(typed_field_value(field)?),
+                "total_distance" => message.total_distance = Some(typed_field_value(field)?),
+                "total_cycles" => message.total_cycles = Some(typed_field_value(field)?),
+                "total_strokes" => message.total_strokes = Some(typed_field_value(field)?),
+                "total_calories" => message.total_calories = Some(typed_field_value(field)?),
+                "total_fat_calories" => {
+                    message.total_fat_calories = Some(typed_field_value(field)?)
+                }
+                "avg_speed" => message.avg_speed = Some(typed_field_value(field)?),
+                "max_speed" => message.max_speed = Some(typed_field_value(field)?),
+                "avg_heart_rate" => message.avg_heart_rate = Some(typed_field_value(field)?),
+                "max_heart_rate" => message.max_heart_rate = Some(typed_field_value(field)?),
+                "avg_cadence" => message.avg_cadence = Some(typed_field_value(field)?),
+                "max_cadence" => message.max_cadence = Some(typed_field_value(field)?),
+                "avg_power" => message.avg_power = Some(typed_field_value(field)?),
+                "max_power" => message.max_power = Some(typed_field_value(field)?),
+                "total_ascent" => message.total_ascent = Some(typed_field_value(field)?),
+                "total_descent" => message.total_descent = Some(typed_field_value(field)?),
+                "sport" => message.sport = Some(typed_field_value(field)?),
+                "event_group" => message.event_group = Some(typed_field_value(field)?),
+                "nec_lat" => message.nec_lat = Some(typed_field_value(field)?),
+                "nec_long" => message.nec_long = Some(typed_field_value(field)?),
+                "swc_lat" => message.swc_lat = Some(typed_field_value(field)?),
+                "swc_long" => message.swc_long = Some(typed_field_value(field)?),
+                "name" => message.name = Some(typed_field_value(field)?),
+                "normalized_power" => message.normalized_power = Some(typed_field_value(field)?),
+                "left_right_balance" => {
+                    message.left_right_balance = Some(typed_field_value(field)?)
+                }
+                "sub_sport" => message.sub_sport = Some(typed_field_value(field)?),
+                "total_work" => message.total_work = Some(typed_field_value(field)?),
+                "avg_altitude" => message.avg_altitude = Some(typed_field_value(field)?),
+                "max_altitude" => message.max_altitude = Some(typed_field_value(field)?),
+                "gps_accuracy" => message.gps_accuracy = Some(typed_field_value(field)?),
+                "avg_grade" => message.avg_grade = Some(typed_field_value(field)?),
+                "avg_pos_grade" => message.avg_pos_grade = Some(typed_field_value(field)?),
+                "avg_neg_grade" => message.avg_neg_grade = Some(typed_field_value(field)?),
+                "max_pos_grade" => message.max_pos_grade = Some(typed_field_value(field)?),
+                "max_neg_grade" => message.max_neg_grade = Some(typed_field_value(field)?),
+                "avg_temperature" => message.avg_temperature = Some(typed_field_value(field)?),
+                "max_temperature" => message.max_temperature = Some(typed_field_value(field)?),
+                "total_moving_time" => message.total_moving_time = Some(typed_field_value(field)?),
+                "avg_pos_vertical_speed" => {
+                    message.avg_pos_vertical_speed = Some(typed_field_value(field)?)
+                }
+                "avg_neg_vertical_speed" => {
+                    message.avg_neg_vertical_speed = Some(typed_field_value(field)?)
+                }
+                "max_pos_vertical_speed" => {
+                    message.max_pos_vertical_speed = Some(typed_field_value(field)?)
+                }
+                "max_neg_vertical_speed" => {
+                    message.max_neg_vertical_speed = Some(typed_field_value(field)?)
+                }
+                "time_in_hr_zone" => message.time_in_hr_zone = Some(typed_field_value(field)?),
+                "time_in_speed_zone" => {
+                    message.time_in_speed_zone = Some(typed_field_value(field)?)
+                }
+                "time_in_cadence_zone" => {
+                    message.time_in_cadence_zone = Some(typed_field_value(field)?)
+                }
+                "time_in_power_zone" => {
+                    message.time_in_power_zone = Some(typed_field_value(field)?)
+                }
+                "repetition_num" => message.repetition_num = Some(typed_field_value(field)?),
+                "min_altitude" => message.min_altitude = Some(typed_field_value(field)?),
+                "min_heart_rate" => message.min_heart_rate = Some(typed_field_value(field)?),
+                "active_time" => message.active_time = Some(typed_field_value(field)?),
+                "wkt_step_index" => message.wkt_step_index = Some(typed_field_value(field)?),
+                "sport_event" => message.sport_event = Some(typed_field_value(field)?),
+                "avg_left_torque_effectiveness" => {
+                    message.avg_left_torque_effectiveness = Some(typed_field_value(field)?)
+                }
+                "avg_right_torque_effectiveness" => {
+                    message.avg_right_torque_effectiveness = Some(typed_field_value(field)?)
+                }
+                "avg_left_pedal_smoothness" => {
+                    message.avg_left_pedal_smoothness = Some(typed_field_value(field)?)
+                }
+                "avg_right_pedal_smoothness" => {
+                    message.avg_right_pedal_smoothness = Some(typed_field_value(field)?)
+                }
+                "avg_combined_pedal_smoothness" => {
+                    message.avg_combined_pedal_smoothness = Some(typed_field_value(field)?)
+                }
+                "status" => message.status = Some(typed_field_value(field)?),
+                "uuid" => message.uuid = Some(typed_field_value(field)?),
+                "avg_fractional_cadence" => {
+                    message.avg_fractional_cadence = Some(typed_field_value(field)?)
+                }
+                "max_fractional_cadence" => {
+                    message.max_fractional_cadence = Some(typed_field_value(field)?)
+                }
+                "total_fractional_cycles" => {
+                    message.total_fractional_cycles = Some(typed_field_value(field)?)
+                }
+                "front_gear_shift_count" => {
+                    message.front_gear_shift_count = Some(typed_field_value(field)?)
+                }
+                "rear_gear_shift_count" => {
+                    message.rear_gear_shift_count = Some(typed_field_value(field)?)
+                }
+                "time_standing" => message.time_standing = Some(typed_field_value(field)?),
+                "stand_count" => message.stand_count = Some(typed_field_value(field)?),
+                "avg_left_pco" => message.avg_left_pco = Some(typed_field_value(field)?),
+                "avg_right_pco" => message.avg_right_pco = Some(typed_field_value(field)?),
+                "avg_left_power_phase" => {
+                    message.avg_left_power_phase = Some(typed_field_value(field)?)
+                }
+                "avg_left_power_phase_peak" => {
+                    message.avg_left_power_phase_peak = Some(typed_field_value(field)?)
+                }
+                "avg_right_power_phase" => {
+                    message.avg_right_power_phase = Some(typed_field_value(field)?)
+                }
+                "avg_right_power_phase_peak" => {
+                    message.avg_right_power_phase_peak = Some(typed_field_value(field)?)
+                }
+                "avg_power_position" => {
+                    message.avg_power_position = Some(typed_field_value(field)?)
+                }
+                "max_power_position" => {
+                    message.max_power_position = Some(typed_field_value(field)?)
+                }
+                "avg_cadence_position" => {
+                    message.avg_cadence_position = Some(typed_field_value(field)?)
+                }
+                "max_cadence_position" => {
+                    message.max_cadence_position = Some(typed_field_value(field)?)
+                }
+                "manufacturer" => message.manufacturer = Some(typed_field_value(field)?),
+                "total_grit" => message.total_grit = Some(typed_field_value(field)?),
+                "total_flow" => message.total_flow = Some(typed_field_value(field)?),
+                "avg_grit" => message.avg_grit = Some(typed_field_value(field)?),
+                "avg_flow" => message.avg_flow = Some(typed_field_value(field)?),
+                "total_fractional_ascent" => {
+                    message.total_fractional_ascent = Some(typed_field_value(field)?)
+                }
+                "total_fractional_descent" => {
+                    message.total_fractional_descent = Some(typed_field_value(field)?)
+                }
+                "enhanced_avg_altitude" => {
+                    message.enhanced_avg_altitude = Some(typed_field_value(field)?)
+                }
+                "enhanced_max_altitude" => {
+                    message.enhanced_max_altitude = Some(typed_field_value(field)?)
+                }
+                "enhanced_min_altitude" => {
+                    message.enhanced_min_altitude = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&SegmentLap> for FitDataRecord {
+    fn from(message: &SegmentLap) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::SegmentLap);
+        push_typed_field(&mut record, "event", &message.event);
+        push_typed_field(&mut record, "event_type", &message.event_type);
+        push_typed_field(&mut record, "start_time", &message.start_time);
+        push_typed_field(
+            &mut record,
+            "start_position_lat",
+            &message.start_position_lat,
+        );
+        push_typed_field(
+            &mut record,
+            "start_position_long",
+            &message.start_position_long,
+        );
+        push_typed_field(&mut record, "end_position_lat", &message.end_position_lat);
+        push_typed_field(&mut record, "end_position_long", &message.end_position_long);
+        push_typed_field(
+            &mut record,
+            "total_elapsed_time",
+            &message.total_elapsed_time,
+        );
+        push_typed_field(&mut record, "total_timer_time", &message.total_timer_time);
+        push_typed_field(&mut record, "total_distance", &message.total_distance);
+        push_typed_field(&mut record, "total_cycles", &message.total_cycles);
+        push_typed_field(&mut record, "total_strokes", &message.total_strokes);
+        push_typed_field(&mut record, "total_calories", &message.total_calories);
+        push_typed_field(
+            &mut record,
+            "total_fat_calories",
+            &message.total_fat_calories,
+        );
+        push_typed_field(&mut record, "avg_speed", &message.avg_speed);
+        push_typed_field(&mut record, "max_speed", &message.max_speed);
+        push_typed_field(&mut record, "avg_heart_rate", &message.avg_heart_rate);
+        push_typed_field(&mut record, "max_heart_rate", &message.max_heart_rate);
+        push_typed_field(&mut record, "avg_cadence", &message.avg_cadence);
+        push_typed_field(&mut record, "max_cadence", &message.max_cadence);
+        push_typed_field(&mut record, "avg_power", &message.avg_power);
+        push_typed_field(&mut record, "max_power", &message.max_power);
+        push_typed_field(&mut record, "total_ascent", &message.total_ascent);
+        push_typed_field(&mut record, "total_descent", &message.total_descent);
+        push_typed_field(&mut record, "sport", &message.sport);
+        push_typed_field(&mut record, "event_group", &message.event_group);
+        push_typed_field(&mut record, "nec_lat", &message.nec_lat);
+        push_typed_field(&mut record, "nec_long", &message.nec_long);
+        push_typed_field(&mut record, "swc_lat", &message.swc_lat);
+        push_typed_field(&mut record, "swc_long", &message.swc_long);
+        push_typed_field(&mut record, "name", &message.name);
+        push_typed_field(&mut record, "normalized_power", &message.normalized_power);
+        push_typed_field(
+            &mut record,
+            "left_right_balance",
+            &message.left_right_balance,
+        );
+        push_typed_field(&mut record, "sub_sport", &message.sub_sport);
+        push_typed_field(&mut record, "total_work", &message.total_work);
+        push_typed_field(&mut record, "avg_altitude", &message.avg_altitude);
+        push_typed_field(&mut record, "max_altitude", &message.max_altitude);
+        push_typed_field(&mut record, "gps_accuracy", &message.gps_accuracy);
+        push_typed_field(&mut record, "avg_grade", &message.avg_grade);
+        push_typed_field(&mut record, "avg_pos_grade", &message.avg_pos_grade);
+        push_typed_field(&mut record, "avg_neg_grade", &message.avg_neg_grade);
+        push_typed_field(&mut record, "max_pos_grade", &message.max_pos_grade);
+        push_typed_field(&mut record, "max_neg_grade", &message.max_neg_grade);
+        push_typed_field(&mut record, "avg_temperature", &message.avg_temperature);
+        push_typed_field(&mut record, "max_temperature", &message.max_temperature);
+        push_typed_field(&mut record, "total_moving_time", &message.total_moving_time);
+        push_typed_field(
+            &mut record,
+            "avg_pos_vertical_speed",
+            &message.avg_pos_vertical_speed,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_neg_vertical_speed",
+            &message.avg_neg_vertical_speed,
+        );
+        push_typed_field(
+            &mut record,
+            "max_pos_vertical_speed",
+            &message.max_pos_vertical_speed,
+        );
+        push_typed_field(
+            &mut record,
+            "max_neg_vertical_speed",
+            &message.max_neg_vertical_speed,
+        );
+        push_typed_field(&mut record, "time_in_hr_zone", &message.time_in_hr_zone);
+        push_typed_field(
+            &mut record,
+            "time_in_speed_zone",
+            &message.time_in_speed_zone,
+        );
+        push_typed_field(
+            &mut record,
+            "time_in_cadence_zone",
+            &message.time_in_cadence_zone,
+        );
+        push_typed_field(
+            &mut record,
+            "time_in_power_zone",
+            &message.time_in_power_zone,
+        );
+        push_typed_field(&mut record, "repetition_num", &message.repetition_num);
+        push_typed_field(&mut record, "min_altitude", &message.min_altitude);
+        push_typed_field(&mut record, "min_heart_rate", &message.min_heart_rate);
+        push_typed_field(&mut record, "active_time", &message.active_time);
+        push_typed_field(&mut record, "wkt_step_index", &message.wkt_step_index);
+        push_typed_field(&mut record, "sport_event", &message.sport_event);
+        push_typed_field(
+            &mut record,
+            "avg_left_torque_effectiveness",
+            &message.avg_left_torque_effectiveness,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_right_torque_effectiveness",
+            &message.avg_right_torque_effectiveness,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_left_pedal_smoothness",
+            &message.avg_left_pedal_smoothness,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_right_pedal_smoothness",
+            &message.avg_right_pedal_smoothness,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_combined_pedal_smoothness",
+            &message.avg_combined_pedal_smoothness,
+        );
+        push_typed_field(&mut record, "status", &message.status);
+        push_typed_field(&mut record, "uuid", &message.uuid);
+        push_typed_field(
+            &mut record,
+            "avg_fractional_cadence",
+            &message.avg_fractional_cadence,
+        );
+        push_typed_field(
+            &mut record,
+            "max_fractional_cadence",
+            &message.max_fractional_cadence,
+        );
+        push_typed_field(
+            &mut record,
+            "total_fractional_cycles",
+            &message.total_fractional_cycles,
+        );
+        push_typed_field(
+            &mut record,
+            "front_gear_shift_count",
+            &message.front_gear_shift_count,
+        );
+        push_typed_field(
+            &mut record,
+            "rear_gear_shift_count",
+            &message.rear_gear_shift_count,
+        );
+        push_typed_field(&mut record, "time_standing", &message.time_standing);
+        push_typed_field(&mut record, "stand_count", &message.stand_count);
+        push_typed_field(&mut record, "avg_left_pco", &message.avg_left_pco);
+        push_typed_field(&mut record, "avg_right_pco", &message.avg_right_pco);
+        push_typed_field(
+            &mut record,
+            "avg_left_power_phase",
+            &message.avg_left_power_phase,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_left_power_phase_peak",
+            &message.avg_left_power_phase_peak,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_right_power_phase",
+            &message.avg_right_power_phase,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_right_power_phase_peak",
+            &message.avg_right_power_phase_peak,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_power_position",
+            &message.avg_power_position,
+        );
+        push_typed_field(
+            &mut record,
+            "max_power_position",
+            &message.max_power_position,
+        );
+        push_typed_field(
+            &mut record,
+            "avg_cadence_position",
+            &message.avg_cadence_position,
+        );
+        push_typed_field(
+            &mut record,
+            "max_cadence_position",
+            &message.max_cadence_position,
+        );
+        push_typed_field(&mut record, "manufacturer", &message.manufacturer);
+        push_typed_field(&mut record, "total_grit", &message.total_grit);
+        push_typed_field(&mut record, "total_flow", &message.total_flow);
+        push_typed_field(&mut record, "avg_grit", &message.avg_grit);
+        push_typed_field(&mut record, "avg_flow", &message.avg_flow);
+        push_typed_field(
+            &mut record,
+            "total_fractional_ascent",
+            &message.total_fractional_ascent,
+        );
+        push_typed_field(
+            &mut record,
+            "total_fractional_descent",
+            &message.total_fractional_descent,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_avg_altitude",
+            &message.enhanced_avg_altitude,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_max_altitude",
+            &message.enhanced_max_altitude,
+        );
+        push_typed_field(
+            &mut record,
+            "enhanced_min_altitude",
+            &message.enhanced_min_altitude,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+/// Summary of the unique segment and leaderboard information associated with a segment file. This message is used to compile a segment list file describing all segment files on a device. The segment list file is used when refreshing the contents of a segment file with the latest available leaderboard information.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SegmentFile {
+    pub file_uuid: Option<String>,
+    pub enabled: Option<bool>,
+    pub user_profile_primary_key: Option<u32>,
+    pub leader_type: Option<Vec<field_types::SegmentLeaderboardType>>,
+    pub leader_group_primary_key: Option<Vec<u32>>,
+    pub leader_activity_id: Option<Vec<u32>>,
+    pub leader_activity_id_string: Option<Vec<String>>,
+    pub default_race_leader: Option<u8>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for SegmentFile {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::SegmentFile)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "file_uuid" => message.file_uuid = Some(typed_field_value(field)?),
+                "enabled" => message.enabled = Some(typed_field_value(field)?),
+                "user_profile_primary_key" => {
+                    message.user_profile_primary_key = Some(typed_field_value(field)?)
+                }
+                "leader_type" => message.leader_type = Some(typed_field_value(field)?),
+                "leader_group_primary_key" => {
+                    message.leader_group_primary_key = Some(typed_field_value(field)?)
+                }
+                "leader_activity_id" => {
+                    message.leader_activity_id = Some(typed_field_value(field)?)
+                }
+                "leader_activity_id_string" => {
+                    message.leader_activity_id_string = Some(typed_field_value(field)?)
+                }
+                "default_race_leader" => {
+                    message.default_race_leader = Some(typed_field_value(field)?)
+                }
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&SegmentFile> for FitDataRecord {
+    fn from(message: &SegmentFile) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::SegmentFile);
+        push_typed_field(&mut record, "file_uuid", &message.file_uuid);
+        push_typed_field(&mut record, "enabled", &message.enabled);
+        push_typed_field(
+            &mut record,
+            "user_profile_primary_key",
+            &message.user_profile_primary_key,
+        );
+        push_typed_field(&mut record, "leader_type", &message.leader_type);
+        push_typed_field(
+            &mut record,
+            "leader_group_primary_key",
+            &message.leader_group_primary_key,
+        );
+        push_typed_field(
+            &mut record,
+            "leader_activity_id",
+            &message.leader_activity_id,
+        );
+        push_typed_field(
+            &mut record,
+            "leader_activity_id_string",
+            &message.leader_activity_id_string,
+        );
+        push_typed_field(
+            &mut record,
+            "default_race_leader",
+            &message.default_race_leader,
+        );
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Workout {
+    pub sport: Option<field_types::Sport>,
+    pub capabilities: Option<field_types::WorkoutCapabilities>,
+    pub num_valid_steps: Option<u16>,
+    pub wkt_name: Option<String>,
+    pub sub_sport: Option<field_types::SubSport>,
+    pub pool_length: Option<f64>,
+    pub pool_length_unit: Option<field_types::DisplayMeasure>,
+}
+impl TryFrom<&FitDataRecord> for Workout {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Workout)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "sport" => message.sport = Some(typed_field_value(field)?),
+                "capabilities" => message.capabilities = Some(typed_field_value(field)?),
+                "num_valid_steps" => message.num_valid_steps = Some(typed_field_value(field)?),
+                "wkt_name" => message.wkt_name = Some(typed_field_value(field)?),
+                "sub_sport" => message.sub_sport = Some(typed_field_value(field)?),
+                "pool_length" => message.pool_length = Some(typed_field_value(field)?),
+                "pool_length_unit" => message.pool_length_unit = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Workout> for FitDataRecord {
+    fn from(message: &Workout) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Workout);
+        push_typed_field(&mut record, "sport", &message.sport);
+        push_typed_field(&mut record, "capabilities", &message.capabilities);
+        push_typed_field(&mut record, "num_valid_steps", &message.num_valid_steps);
+        push_typed_field(&mut record, "wkt_name", &message.wkt_name);
+        push_typed_field(&mut record, "sub_sport", &message.sub_sport);
+        push_typed_field(&mut record, "pool_length", &message.pool_length);
+        push_typed_field(&mut record, "pool_length_unit", &message.pool_length_unit);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct WorkoutSession {
+    pub sport: Option<field_types::Sport>,
+    pub sub_sport: Option<field_types::SubSport>,
+    pub num_valid_steps: Option<u16>,
+    pub first_step_index: Option<u16>,
+    pub pool_length: Option<f64>,
+    pub pool_length_unit: Option<field_types::DisplayMeasure>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for WorkoutSession {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::WorkoutSession)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "sport" => message.sport = Some(typed_field_value(field)?),
+                "sub_sport" => message.sub_sport = Some(typed_field_value(field)?),
+                "num_valid_steps" => message.num_valid_steps = Some(typed_field_value(field)?),
+                "first_step_index" => message.first_step_index = Some(typed_field_value(field)?),
+                "pool_length" => message.pool_length = Some(typed_field_value(field)?),
+                "pool_length_unit" => message.pool_length_unit = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&WorkoutSession> for FitDataRecord {
+    fn from(message: &WorkoutSession) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::WorkoutSession);
+        push_typed_field(&mut record, "sport", &message.sport);
+        push_typed_field(&mut record, "sub_sport", &message.sub_sport);
+        push_typed_field(&mut record, "num_valid_steps", &message.num_valid_steps);
+        push_typed_field(&mut record, "first_step_index", &message.first_step_index);
+        push_typed_field(&mut record, "pool_length", &message.pool_length);
+        push_typed_field(&mut record, "pool_length_unit", &message.pool_length_unit);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct WorkoutStep {
+    pub wkt_step_name: Option<String>,
+    pub duration_type: Option<field_types::WktStepDuration>,
+    pub duration_value: Option<u32>,
+    pub duration_time: Option<f64>,
+    pub duration_distance: Option<f64>,
+    pub duration_hr: Option<field_types::WorkoutHr>,
+    pub duration_calories: Option<u32>,
+    pub duration_step: Option<u32>,
+    pub duration_power: Option<field_types::WorkoutPower>,
+    pub duration_reps: Option<u32>,
+    pub target_type: Option<field_types::WktStepTarget>,
+    pub target_value: Option<u32>,
+    pub target_speed_zone: Option<u32>,
+    pub target_hr_zone: Option<u32>,
+    pub target_cadence_zone: Option<u32>,
+    pub target_power_zone: Option<u32>,
+    pub repeat_steps: Option<u32>,
+    pub repeat_time: Option<f64>,
+    pub repeat_distance: Option<f64>,
+    pub repeat_calories: Option<u32>,
+    pub repeat_hr: Option<field_types::WorkoutHr>,
+    pub repeat_power: Option<field_types::WorkoutPower>,
+    pub target_stroke_type: Option<field_types::SwimStroke>,
+    pub custom_target_value_low: Option<u32>,
+    pub custom_target_speed_low: Option<f64>,
+    pub custom_target_heart_rate_low: Option<field_types::WorkoutHr>,
+    pub custom_target_cadence_low: Option<u32>,
+    pub custom_target_power_low: Option<field_types::WorkoutPower>,
+    pub custom_target_value_high: Option<u32>,
+    pub custom_target_speed_high: Option<f64>,
+    pub custom_target_heart_rate_high: Option<field_types::WorkoutHr>,
+    pub custom_target_cadence_high: Option<u32>,
+    pub custom_target_power_high: Option<field_types::WorkoutPower>,
+    pub intensity: Option<field_types::Intensity>,
+    pub notes: Option<String>,
+    pub equipment: Option<field_types::WorkoutEquipment>,
+    pub exercise_category: Option<field_types::ExerciseCategory>,
+    pub exercise_name: Option<u16>,
+    pub exercise_weight: Option<f64>,
+    pub weight_display_unit: Option<field_types::FitBaseUnit>,
+    pub secondary_target_type: Option<field_types::WktStepTarget>,
+    pub secondary_target_value: Option<u32>,
+    pub secondary_target_speed_zone: Option<u32>,
+    pub secondary_target_hr_zone: Option<u32>,
+    pub secondary_target_cadence_zone: Option<u32>,
+    pub secondary_target_power_zone: Option<u32>,
+    pub secondary_target_stroke_type: Option<field_types::SwimStroke>,
+    pub secondary_custom_target_value_low: Option<u32>,
+    pub secondary_custom_target_speed_low: Option<f64>,
+    pub secondary_custom_target_heart_rate_low: Option<field_types::WorkoutHr>,
+    pub secondary_custom_target_cadence_low: Option<u32>,
+    pub secondary_custom_target_power_low: Option<field_types::WorkoutPower>,
+    pub secondary_custom_target_value_high: Option<u32>,
+    pub secondary_custom_target_speed_high: Option<f64>,
+    pub secondary_custom_target_heart_rate_high: Option<field_types::WorkoutHr>,
+    pub secondary_custom_target_cadence_high: Option<u32>,
+    pub secondary_custom_target_power_high: Option<field_types::WorkoutPower>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for WorkoutStep {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::WorkoutStep)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "wkt_step_name" => message.wkt_step_name = Some(typed_field_value(field)?),
+                "duration_type" => message.duration_type = Some(typed_field_value(field)?),
+                "duration_value" => message.duration_value = Some(typed_field_value(field)?),
+                "duration_time" => message.duration_time = Some(typed_field_value(field)?),
+                "duration_distance" => message.duration_distance = Some(typed_field_value(field)?),
+                "duration_hr" => message.duration_hr = Some(typed_field_value(field)?),
+                "duration_calories" => message.duration_calories = Some(typed_field_value(field)?),
+                "duration_step" => message.duration_step = Some(typed_field_value(field)?),
+                "duration_power" => message.duration_power = Some(typed_field_value(field)?),
+                "duration_reps" => message.duration_reps = Some(typed_field_value(field)?),
+                "target_type" => message.target_type = Some(typed_field_value(field)?),
+                "target_value" => message.target_value = Some(typed_field_value(field)?),
+                "target_speed_zone" => message.target_speed_zone = Some(typed_field_value(field)?),
+                "target_hr_zone" => message.target_hr_zone = Some(typed_field_value(field)?),
+                "target_cadence_zone" => {
+                    message.target_cadence_zone = Some(typed_field_value(field)?)
+                }
+                "target_power_zone" => message.target_power_zone = Some(typed_field_value(field)?),
+                "repeat_steps" => message.repeat_steps = Some(typed_field_value(field)?),
+                "repeat_time" => message.repeat_time = Some(typed_field_value(field)?),
+                "repeat_distance" => message.repeat_distance = Some(typed_field_value(field)?),
+                "repeat_calories" => message.repeat_calories = Some(typed_field_value(field)?),
+                "repeat_hr" => message.repeat_hr = Some(typed_field_value(field)?),
+                "repeat_power" => message.repeat_power = Some(typed_field_value(field)?),
+                "target_stroke_type" => {
+                    message.target_stroke_type = Some(typed_field_value(field)?)
+                }
+                "custom_target_value_low" => {
+                    message.custom_target_value_low = Some(typed_field_value(field)?)
+                }
+                "custom_target_speed_low" => {
+                    message.custom_target_speed_low = Some(typed_field_value(field)?)
+                }
+                "custom_target_heart_rate_low" => {
+                    message.custom_target_heart_rate_low = Some(typed_field_value(field)?)
+                }
+                "custom_target_cadence_low" => {
+                    message.custom_target_cadence_low = Some(typed_field_value(field)?)
+                }
+                "custom_target_power_low" => {
+                    message.custom_target_power_low = Some(typed_field_value(field)?)
+                }
+                "custom_target_value_high" => {
+                    message.custom_target_value_high = Some(typed_field_value(field)?)
+                }
+                "custom_target_speed_high" => {
+                    message.custom_target_speed_high = Some(typed_field_value(field)?)
+                }
+                "custom_target_heart_rate_high" => {
+                    message.custom_target_heart_rate_high = Some(typed_field_value(field)?)
+                }
+                "custom_target_cadence_high" => {
+                    message.custom_target_cadence_high = Some(typed_field_value(field)?)
+                }
+                "custom_target_power_high" => {
+                    message.custom_target_power_high = Some(typed_field_value(field)?)
+                }
+                "intensity" => message.intensity = Some(typed_field_value(field)?),
+                "notes" => message.notes = Some(typed_field_value(field)?),
+                "equipment" => message.equipment = Some(typed_field_value(field)?),
+                "exercise_category" => message.exercise_category = Some(typed_field_value(field)?),
+                "exercise_name" => message.exercise_name = Some(typed_field_value(field)?),
+                "exercise_weight" => message.exercise_weight = Some(typed_field_value(field)?),
+                "weight_display_unit" => {
+                    message.weight_display_unit = Some(typed_field_value(field)?)
+                }
+                "secondary_target_type" => {
+                    message.secondary_target_type = Some(typed_field_value(field)?)
+                }
+                "secondary_target_value" => {
+                    message.secondary_target_value = Some(typed_field_value(field)?)
+                }
+                "secondary_target_speed_zone" => {
+                    message.secondary_target_speed_zone = Some(typed_field_value(field)?)
+                }
+                "secondary_target_hr_zone" => {
+                    message.secondary_target_hr_zone = Some(typed_field_value(field)?)
+                }
+                "secondary_target_cadence_zone" => {
+                    message.secondary_target_cadence_zone = Some(typed_field_value(field)?)
+                }
+                "secondary_target_power_zone" => {
+                    message.secondary_target_power_zone = Some(typed_field_value(field)?)
+                }
+                "secondary_target_stroke_type" => {
+                    message.secondary_target_stroke_type = Some(typed_field_value(field)?)
+                }
+                "secondary_custom_target_value_low" => {
+                    message.secondary_custom_target_value_low = Some(typed_field_value(field)?)
+                }
+                "secondary_custom_target_speed_low" => {
+                    message.secondary_custom_target_speed_low = Some(typed_field_value(field)?)
+                }
+                "secondary_custom_target_heart_rate_low" => {
+                    message.secondary_custom_target_heart_rate_low = Some(typed_field_value(field)?)
+                }
+                "secondary_custom_target_cadence_low" => {
+                    message.secondary_custom_target_cadence_low = Some(typed_field_value(field)?)
+                }
+                "secondary_custom_target_power_low" => {
+                    message.secondary_custom_target_power_low = Some(typed_field_value(field)?)
+                }
+                "secondary_custom_target_value_high" => {
+                    message.secondary_custom_target_value_high = Some(typed_field_value(field)?)
+                }
+                "secondary_custom_target_speed_high" => {
+                    message.secondary_custom_target_speed_high = Some(typed_field_value(field)?)
+                }
+                "secondary_custom_target_heart_rate_high" => {
+                    message.secondary_custom_target_heart_rate_high =
+                        Some(typed_field_value(field)?)
+                }
+                "secondary_custom_target_cadence_high" => {
+                    message.secondary_custom_target_cadence_high = Some(typed_field_value(field)?)
+                }
+                "secondary_custom_target_power_high" => {
+                    message.secondary_custom_target_power_high = Some(typed_field_value(field)?)
+                }
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&WorkoutStep> for FitDataRecord {
+    fn from(message: &WorkoutStep) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::WorkoutStep);
+        push_typed_field(&mut record, "wkt_step_name", &message.wkt_step_name);
+        push_typed_field(&mut record, "duration_type", &message.duration_type);
+        push_typed_field(&mut record, "duration_value", &message.duration_value);
+        push_typed_field(&mut record, "duration_time", &message.duration_time);
+        push_typed_field(&mut record, "duration_distance", &message.duration_distance);
+        push_typed_field(&mut record, "duration_hr", &message.duration_hr);
+        push_typed_field(&mut record, "duration_calories", &message.duration_calories);
+        push_typed_field(&mut record, "duration_step", &message.duration_step);
+        push_typed_field(&mut record, "duration_power", &message.duration_power);
+        push_typed_field(&mut record, "duration_reps", &message.duration_reps);
+        push_typed_field(&mut record, "target_type", &message.target_type);
+        push_typed_field(&mut record, "target_value", &message.target_value);
+        push_typed_field(&mut record, "target_speed_zone", &message.target_speed_zone);
+        push_typed_field(&mut record, "target_hr_zone", &message.target_hr_zone);
+        push_typed_field(
+            &mut record,
+            "target_cadence_zone",
+            &message.target_cadence_zone,
+        );
+        push_typed_field(&mut record, "target_power_zone", &message.target_power_zone);
+        push_typed_field(&mut record, "repeat_steps", &message.repeat_steps);
+        push_typed_field(&mut record, "repeat_time", &message.repeat_time);
+        push_typed_field(&mut record, "repeat_distance", &message.repeat_distance);
+        push_typed_field(&mut record, "repeat_calories", &message.repeat_calories);
+        push_typed_field(&mut record, "repeat_hr", &message.repeat_hr);
+        push_typed_field(&mut record, "repeat_power", &message.repeat_power);
+        push_typed_field(
+            &mut record,
+            "target_stroke_type",
+            &message.target_stroke_type,
+        );
+        push_typed_field(
+            &mut record,
+            "custom_target_value_low",
+            &message.custom_target_value_low,
+        );
+        push_typed_field(
+            &mut record,
+            "custom_target_speed_low",
+            &message.custom_target_speed_low,
+        );
+        push_typed_field(
+            &mut record,
+            "custom_target_heart_rate_low",
+            &message.custom_target_heart_rate_low,
+        );
+        push_typed_field(
+            &mut record,
+            "custom_target_cadence_low",
+            &message.custom_target_cadence_low,
+        );
+        push_typed_field(
+            &mut record,
+            "custom_target_power_low",
+            &message.custom_target_power_low,
+        );
+        push_typed_field(
+            &mut record,
+            "custom_target_value_high",
+            &message.custom_target_value_high,
+        );
+        push_typed_field(
+            &mut record,
+            "custom_target_speed_high",
+            &message.custom_target_speed_high,
+        );
+        push_typed_field(
+            &mut record,
+            "custom_target_heart_rate_high",
+            &message.custom_target_heart_rate_high,
+        );
+        push_typed_field(
+            &mut record,
+            "custom_target_cadence_high",
+            &message.custom_target_cadence_high,
+        );
+        push_typed_field(
+            &mut record,
+            "custom_target_power_high",
+            &message.custom_target_power_high,
+        );
+        push_typed_field(&mut record, "intensity", &message.intensity);
+        push_typed_field(&mut record, "notes", &message.notes);
+        push_typed_field(&mut record, "equipment", &message.equipment);
+        push_typed_field(&mut record, "exercise_category", &message.exercise_category);
+        push_typed_field(&mut record, "exercise_name", &message.exercise_name);
+        push_typed_field(&mut record, "exercise_weight", &message.exercise_weight);
+        push_typed_field(
+            &mut record,
+            "weight_display_unit",
+            &message.weight_display_unit,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_target_type",
+            &message.secondary_target_type,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_target_value",
+            &message.secondary_target_value,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_target_speed_zone",
+            &message.secondary_target_speed_zone,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_target_hr_zone",
+            &message.secondary_target_hr_zone,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_target_cadence_zone",
+            &message.secondary_target_cadence_zone,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_target_power_zone",
+            &message.secondary_target_power_zone,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_target_stroke_type",
+            &message.secondary_target_stroke_type,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_custom_target_value_low",
+            &message.secondary_custom_target_value_low,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_custom_target_speed_low",
+            &message.secondary_custom_target_speed_low,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_custom_target_heart_rate_low",
+            &message.secondary_custom_target_heart_rate_low,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_custom_target_cadence_low",
+            &message.secondary_custom_target_cadence_low,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_custom_target_power_low",
+            &message.secondary_custom_target_power_low,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_custom_target_value_high",
+            &message.secondary_custom_target_value_high,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_custom_target_speed_high",
+            &message.secondary_custom_target_speed_high,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_custom_target_heart_rate_high",
+            &message.secondary_custom_target_heart_rate_high,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_custom_target_cadence_high",
+            &message.secondary_custom_target_cadence_high,
+        );
+        push_typed_field(
+            &mut record,
+            "secondary_custom_target_power_high",
+            &message.secondary_custom_target_power_high,
+        );
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ExerciseTitle {
+    pub exercise_category: Option<field_types::ExerciseCategory>,
+    pub exercise_name: Option<u16>,
+    pub wkt_step_name: Option<Vec<String>>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for ExerciseTitle {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::ExerciseTitle)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "exercise_category" => message.exercise_category = Some(typed_field_value(field)?),
+                "exercise_name" => message.exercise_name = Some(typed_field_value(field)?),
+                "wkt_step_name" => message.wkt_step_name = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&ExerciseTitle> for FitDataRecord {
+    fn from(message: &ExerciseTitle) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::ExerciseTitle);
+        push_typed_field(&mut record, "exercise_category", &message.exercise_category);
+        push_typed_field(&mut record, "exercise_name", &message.exercise_name);
+        push_typed_field(&mut record, "wkt_step_name", &message.wkt_step_name);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Schedule {
+    pub manufacturer: Option<field_types::Manufacturer>,
+    pub product: Option<u16>,
+    pub favero_product: Option<field_types::FaveroProduct>,
+    pub garmin_product: Option<field_types::GarminProduct>,
+    pub serial_number: Option<u32>,
+    pub time_created: Option<DateTime<TimestampTz>>,
+    pub completed: Option<bool>,
+    pub r#type: Option<field_types::Schedule>,
+    pub scheduled_time: Option<NaiveDateTime>,
+}
+impl TryFrom<&FitDataRecord> for Schedule {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Schedule)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "manufacturer" => message.manufacturer = Some(typed_field_value(field)?),
+                "product" => message.product = Some(typed_field_value(field)?),
+                "favero_product" => message.favero_product = Some(typed_field_value(field)?),
+                "garmin_product" => message.garmin_product = Some(typed_field_value(field)?),
+                "serial_number" => message.serial_number = Some(typed_field_value(field)?),
+                "time_created" => message.time_created = Some(typed_field_value(field)?),
+                "completed" => message.completed = Some(typed_field_value(field)?),
+                "type" => message.r#type = Some(typed_field_value(field)?),
+                "scheduled_time" => message.scheduled_time = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Schedule> for FitDataRecord {
+    fn from(message: &Schedule) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Schedule);
+        push_typed_field(&mut record, "manufacturer", &message.manufacturer);
+        push_typed_field(&mut record, "product", &message.product);
+        push_typed_field(&mut record, "favero_product", &message.favero_product);
+        push_typed_field(&mut record, "garmin_product", &message.garmin_product);
+        push_typed_field(&mut record, "serial_number", &message.serial_number);
+        push_typed_field(&mut record, "time_created", &message.time_created);
+        push_typed_field(&mut record, "completed", &message.completed);
+        push_typed_field(&mut record, "type", &message.r#type);
+        push_typed_field(&mut record, "scheduled_time", &message.scheduled_time);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Totals {
+    pub timer_time: Option<u32>,
+    pub distance: Option<u32>,
+    pub calories: Option<u32>,
+    pub sport: Option<field_types::Sport>,
+    pub elapsed_time: Option<u32>,
+    pub sessions: Option<u16>,
+    pub active_time: Option<u32>,
+    pub sport_index: Option<u8>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+    pub message_index: Option<field_types::MessageIndex>,
+}
+impl TryFrom<&FitDataRecord> for Totals {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Totals)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "timer_time" => message.timer_time = Some(typed_field_value(field)?),
+                "distance" => message.distance = Some(typed_field_value(field)?),
+                "calories" => message.calories = Some(typed_field_value(field)?),
+                "sport" => message.sport = Some(typed_field_value(field)?),
+                "elapsed_time" => message.elapsed_time = Some(typed_field_value(field)?),
+                "sessions" => message.sessions = Some(typed_field_value(field)?),
+                "active_time" => message.active_time = Some(typed_field_value(field)?),
+                "sport_index" => message.sport_index = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                "message_index" => message.message_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Totals> for FitDataRecord {
+    fn from(message: &Totals) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Totals);
+        push_typed_field(&mut record, "timer_time", &message.timer_time);
+        push_typed_field(&mut record, "distance", &message.distance);
+        push_typed_field(&mut record, "calories", &message.calories);
+        push_typed_field(&mut record, "sport", &message.sport);
+        push_typed_field(&mut record, "elapsed_time", &message.elapsed_time);
+        push_typed_field(&mut record, "sessions", &message.sessions);
+        push_typed_field(&mut record, "active_time", &message.active_time);
+        push_typed_field(&mut record, "sport_index", &message.sport_index);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        push_typed_field(&mut record, "message_index", &message.message_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct WeightScale {
+    pub weight: Option<field_types::Weight>,
+    pub percent_fat: Option<f64>,
+    pub percent_hydration: Option<f64>,
+    pub visceral_fat_mass: Option<f64>,
+    pub bone_mass: Option<f64>,
+    pub muscle_mass: Option<f64>,
+    pub basal_met: Option<f64>,
+    pub physique_rating: Option<u8>,
+    pub active_met: Option<f64>,
+    pub metabolic_age: Option<u8>,
+    pub visceral_fat_rating: Option<u8>,
+    pub user_profile_index: Option<field_types::MessageIndex>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for WeightScale {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::WeightScale)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "weight" => message.weight = Some(typed_field_value(field)?),
+                "percent_fat" => message.percent_fat = Some(typed_field_value(field)?),
+                "percent_hydration" => message.percent_hydration = Some(typed_field_value(field)?),
+                "visceral_fat_mass" => message.visceral_fat_mass = Some(typed_field_value(field)?),
+                "bone_mass" => message.bone_mass = Some(typed_field_value(field)?),
+                "muscle_mass" => message.muscle_mass = Some(typed_field_value(field)?),
+                "basal_met" => message.basal_met = Some(typed_field_value(field)?),
+                "physique_rating" => message.physique_rating = Some(typed_field_value(field)?),
+                "active_met" => message.active_met = Some(typed_field_value(field)?),
+                "metabolic_age" => message.metabolic_age = Some(typed_field_value(field)?),
+                "visceral_fat_rating" => {
+                    message.visceral_fat_rating = Some(typed_field_value(field)?)
+                }
+                "user_profile_index" => {
+                    message.user_profile_index = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&WeightScale> for FitDataRecord {
+    fn from(message: &WeightScale) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::WeightScale);
+        push_typed_field(&mut record, "weight", &message.weight);
+        push_typed_field(&mut record, "percent_fat", &message.percent_fat);
+        push_typed_field(&mut record, "percent_hydration", &message.percent_hydration);
+        push_typed_field(&mut record, "visceral_fat_mass", &message.visceral_fat_mass);
+        push_typed_field(&mut record, "bone_mass", &message.bone_mass);
+        push_typed_field(&mut record, "muscle_mass", &message.muscle_mass);
+        push_typed_field(&mut record, "basal_met", &message.basal_met);
+        push_typed_field(&mut record, "physique_rating", &message.physique_rating);
+        push_typed_field(&mut record, "active_met", &message.active_met);
+        push_typed_field(&mut record, "metabolic_age", &message.metabolic_age);
+        push_typed_field(
+            &mut record,
+            "visceral_fat_rating",
+            &message.visceral_fat_rating,
+        );
+        push_typed_field(
+            &mut record,
+            "user_profile_index",
+            &message.user_profile_index,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct BloodPressure {
+    pub systolic_pressure: Option<u16>,
+    pub diastolic_pressure: Option<u16>,
+    pub mean_arterial_pressure: Option<u16>,
+    pub map_3_sample_mean: Option<u16>,
+    pub map_morning_values: Option<u16>,
+    pub map_evening_values: Option<u16>,
+    pub heart_rate: Option<u8>,
+    pub heart_rate_type: Option<field_types::HrType>,
+    pub status: Option<field_types::BpStatus>,
+    pub user_profile_index: Option<field_types::MessageIndex>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for BloodPressure {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::BloodPressure)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "systolic_pressure" => message.systolic_pressure = Some(typed_field_value(field)?),
+                "diastolic_pressure" => {
+                    message.diastolic_pressure = Some(typed_field_value(field)?)
+                }
+                "mean_arterial_pressure" => {
+                    message.mean_arterial_pressure = Some(typed_field_value(field)?)
+                }
+                "map_3_sample_mean" => message.map_3_sample_mean = Some(typed_field_value(field)?),
+                "map_morning_values" => {
+                    message.map_morning_values = Some(typed_field_value(field)?)
+                }
+                "map_evening_values" => {
+                    message.map_evening_values = Some(typed_field_value(field)?)
+                }
+                "heart_rate" => message.heart_rate = Some(typed_field_value(field)?),
+                "heart_rate_type" => message.heart_rate_type = Some(typed_field_value(field)?),
+                "status" => message.status = Some(typed_field_value(field)?),
+                "user_profile_index" => {
+                    message.user_profile_index = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&BloodPressure> for FitDataRecord {
+    fn from(message: &BloodPressure) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::BloodPressure);
+        push_typed_field(&mut record, "systolic_pressure", &message.systolic_pressure);
+        push_typed_field(
+            &mut record,
+            "diastolic_pressure",
+            &message.diastolic_pressure,
+        );
+        push_typed_field(
+            &mut record,
+            "mean_arterial_pressure",
+            &message.mean_arterial_pressure,
+        );
+        push_typed_field(&mut record, "map_3_sample_mean", &message.map_3_sample_mean);
+        push_typed_field(
+            &mut record,
+            "map_morning_values",
+            &message.map_morning_values,
+        );
+        push_typed_field(
+            &mut record,
+            "map_evening_values",
+            &message.map_evening_values,
+        );
+        push_typed_field(&mut record, "heart_rate", &message.heart_rate);
+        push_typed_field(&mut record, "heart_rate_type", &message.heart_rate_type);
+        push_typed_field(&mut record, "status", &message.status);
+        push_typed_field(
+            &mut record,
+            "user_profile_index",
+            &message.user_profile_index,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct MonitoringInfo {
+    pub local_timestamp: Option<NaiveDateTime>,
+    pub activity_type: Option<Vec<field_types::ActivityType>>,
+    pub cycles_to_distance: Option<Vec<f64>>,
+    pub cycles_to_calories: Option<Vec<f64>>,
+    pub resting_metabolic_rate: Option<u16>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for MonitoringInfo {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::MonitoringInfo)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "local_timestamp" => message.local_timestamp = Some(typed_field_value(field)?),
+                "activity_type" => message.activity_type = Some(typed_field_value(field)?),
+                "cycles_to_distance" => {
+                    message.cycles_to_distance = Some(typed_field_value(field)?)
+                }
+                "cycles_to_calories" => {
+                    message.cycles_to_calories = Some(typed_field_value(field)?)
+                }
+                "resting_metabolic_rate" => {
+                    message.resting_metabolic_rate = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&MonitoringInfo> for FitDataRecord {
+    fn from(message: &MonitoringInfo) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::MonitoringInfo);
+        push_typed_field(&mut record, "local_timestamp", &message.local_timestamp);
+        push_typed_field(&mut record, "activity_type", &message.activity_type);
+        push_typed_field(
+            &mut record,
+            "cycles_to_distance",
+            &message.cycles_to_distance,
+        );
+        push_typed_field(
+            &mut record,
+            "cycles_to_calories",
+            &message.cycles_to_calories,
+        );
+        push_typed_field(
+            &mut record,
+            "resting_metabolic_rate",
+            &message.resting_metabolic_rate,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Monitoring {
+    pub device_index: Option<field_types::DeviceIndex>,
+    pub calories: Option<u16>,
+    pub distance: Option<f64>,
+    pub cycles: Option<f64>,
+    pub steps: Option<u32>,
+    pub strokes: Option<f64>,
+    pub active_time: Option<f64>,
+    pub activity_type: Option<field_types::ActivityType>,
+    pub activity_subtype: Option<field_types::ActivitySubtype>,
+    pub activity_level: Option<field_types::ActivityLevel>,
+    pub distance_16: Option<u16>,
+    pub cycles_16: Option<u16>,
+    pub active_time_16: Option<u16>,
+    pub local_timestamp: Option<NaiveDateTime>,
+    pub temperature: Option<f64>,
+    pub temperature_min: Option<f64>,
+    pub temperature_max: Option<f64>,
+    pub activity_time: Option<u16>,
+    pub active_calories: Option<u16>,
+    pub current_activity_type_intensity: Option<u8>,
+    pub timestamp_min_8: Option<u8>,
+    pub timestamp_16: Option<u16>,
+    pub heart_rate: Option<u8>,
+    pub intensity: Option<f64>,
+    pub duration_min: Option<u16>,
+    pub duration: Option<u32>,
+    pub ascent: Option<f64>,
+    pub descent: Option<f64>,
+    pub moderate_activity_minutes: Option<u16>,
+    pub vigorous_activity_minutes: Option<u16>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for Monitoring {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Monitoring)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "device_index" => message.device_index = Some(typed_field_value(field)?),
+                "calories" => message.calories = Some(typed_field_value(field)?),
+                "distance" => message.distance = Some(typed_field_value(field)?),
+                "cycles" => message.cycles = Some(typed_field_value(field)?),
+                "steps" => message.steps = Some(typed_field_value(field)?),
+                "strokes" => message.strokes = Some(typed_field_value(field)?),
+                "active_time" => message.active_time = Some(typed_field_value(field)?),
+                "activity_type" => message.activity_type = Some(typed_field_value(field)?),
+                "activity_subtype" => message.activity_subtype = Some(typed_field_value(field)?),
+                "activity_level" => message.activity_level = Some(typed_field_value(field)?),
+                "distance_16" => message.distance_16 = Some(typed_field_value(field)?),
+                "cycles_16" => message.cycles_16 = Some(typed_field_value(field)?),
+                "active_time_16" => message.active_time_16 = Some(typed_field_value(field)?),
+                "local_timestamp" => message.local_timestamp = Some(typed_field_value(field)?),
+                "temperature" => message.temperature = Some(typed_field_value(field)?),
+                "temperature_min" => message.temperature_min = Some(typed_field_value(field)?),
+                "temperature_max" => message.temperature_max = Some(typed_field_value(field)?),
+                "activity_time" => message.activity_time = Some(typed_field_value(field)?),
+                "active_calories" => message.active_calories = Some(typed_field_value(field)?),
+                "current_activity_type_intensity" => {
+                    message.current_activity_type_intensity = Some(typed_field_value(field)?)
+                }
+                "timestamp_min_8" => message.timestamp_min_8 = Some(typed_field_value(field)?),
+                "timestamp_16" => message.timestamp_16 = Some(typed_field_value(field)?),
+                "heart_rate" => message.heart_rate = Some(typed_field_value(field)?),
+                "intensity" => message.intensity = Some(typed_field_value(field)?),
+                "duration_min" => message.duration_min = Some(typed_field_value(field)?),
+                "duration" => message.duration = Some(typed_field_value(field)?),
+                "ascent" => message.ascent = Some(typed_field_value(field)?),
+                "descent" => message.descent = Some(typed_field_value(field)?),
+                "moderate_activity_minutes" => {
+                    message.moderate_activity_minutes = Some(typed_field_value(field)?)
+                }
+                "vigorous_activity_minutes" => {
+                    message.vigorous_activity_minutes = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Monitoring> for FitDataRecord {
+    fn from(message: &Monitoring) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Monitoring);
+        push_typed_field(&mut record, "device_index", &message.device_index);
+        push_typed_field(&mut record, "calories", &message.calories);
+        push_typed_field(&mut record, "distance", &message.distance);
+        push_typed_field(&mut record, "cycles", &message.cycles);
+        push_typed_field(&mut record, "steps", &message.steps);
+        push_typed_field(&mut record, "strokes", &message.strokes);
+        push_typed_field(&mut record, "active_time", &message.active_time);
+        push_typed_field(&mut record, "activity_type", &message.activity_type);
+        push_typed_field(&mut record, "activity_subtype", &message.activity_subtype);
+        push_typed_field(&mut record, "activity_level", &message.activity_level);
+        push_typed_field(&mut record, "distance_16", &message.distance_16);
+        push_typed_field(&mut record, "cycles_16", &message.cycles_16);
+        push_typed_field(&mut record, "active_time_16", &message.active_time_16);
+        push_typed_field(&mut record, "local_timestamp", &message.local_timestamp);
+        push_typed_field(&mut record, "temperature", &message.temperature);
+        push_typed_field(&mut record, "temperature_min", &message.temperature_min);
+        push_typed_field(&mut record, "temperature_max", &message.temperature_max);
+        push_typed_field(&mut record, "activity_time", &message.activity_time);
+        push_typed_field(&mut record, "active_calories", &message.active_calories);
+        push_typed_field(
+            &mut record,
+            "current_activity_type_intensity",
+            &message.current_activity_type_intensity,
+        );
+        push_typed_field(&mut record, "timestamp_min_8", &message.timestamp_min_8);
+        push_typed_field(&mut record, "timestamp_16", &message.timestamp_16);
+        push_typed_field(&mut record, "heart_rate", &message.heart_rate);
+        push_typed_field(&mut record, "intensity", &message.intensity);
+        push_typed_field(&mut record, "duration_min", &message.duration_min);
+        push_typed_field(&mut record, "duration", &message.duration);
+        push_typed_field(&mut record, "ascent", &message.ascent);
+        push_typed_field(&mut record, "descent", &message.descent);
+        push_typed_field(
+            &mut record,
+            "moderate_activity_minutes",
+            &message.moderate_activity_minutes,
+        );
+        push_typed_field(
+            &mut record,
+            "vigorous_activity_minutes",
+            &message.vigorous_activity_minutes,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Hr {
+    pub fractional_timestamp: Option<f64>,
+    pub time256: Option<f64>,
+    pub filtered_bpm: Option<Vec<u8>>,
+    pub event_timestamp: Option<Vec<f64>>,
+    pub event_timestamp_12: Option<Vec<u8>>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for Hr {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Hr)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "fractional_timestamp" => {
+                    message.fractional_timestamp = Some(typed_field_value(field)?)
+                }
+                "time256" => message.time256 = Some(typed_field_value(field)?),
+                "filtered_bpm" => message.filtered_bpm = Some(typed_field_value(field)?),
+                "event_timestamp" => message.event_timestamp = Some(typed_field_value(field)?),
+                "event_timestamp_12" => {
+                    message.event_timestamp_12 = Some(typed_field_value(field)?)
+                }
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Hr> for FitDataRecord {
+    fn from(message: &Hr) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Hr);
+        push_typed_field(
+            &mut record,
+            "fractional_timestamp",
+            &message.fractional_timestamp,
+        );
+        push_typed_field(&mut record, "time256", &message.time256);
+        push_typed_field(&mut record, "filtered_bpm", &message.filtered_bpm);
+        push_typed_field(&mut record, "event_timestamp", &message.event_timestamp);
+        push_typed_field(
+            &mut record,
+            "event_timestamp_12",
+            &message.event_timestamp_12,
+        );
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+/// Value from 1 to 100 calculated by FirstBeat
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct StressLevel {
+    pub stress_level_value: Option<i16>,
+    pub stress_level_time: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for StressLevel {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::StressLevel)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "stress_level_value" => {
+                    message.stress_level_value = Some(typed_field_value(field)?)
+                }
+                "stress_level_time" => message.stress_level_time = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&StressLevel> for FitDataRecord {
+    fn from(message: &StressLevel) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::StressLevel);
+        push_typed_field(
+            &mut record,
+            "stress_level_value",
+            &message.stress_level_value,
+        );
+        push_typed_field(&mut record, "stress_level_time", &message.stress_level_time);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct MemoGlob {
+    pub memo: Option<Vec<u8>>,
+    pub mesg_num: Option<field_types::MesgNum>,
+    pub parent_index: Option<field_types::MessageIndex>,
+    pub field_num: Option<u8>,
+    pub data: Option<u8>,
+    pub part_index: Option<u32>,
+}
+impl TryFrom<&FitDataRecord> for MemoGlob {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::MemoGlob)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "memo" => message.memo = Some(typed_field_value(field)?),
+                "mesg_num" => message.mesg_num = Some(typed_field_value(field)?),
+                "parent_index" => message.parent_index = Some(typed_field_value(field)?),
+                "field_num" => message.field_num = Some(typed_field_value(field)?),
+                "data" => message.data = Some(typed_field_value(field)?),
+                "part_index" => message.part_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&MemoGlob> for FitDataRecord {
+    fn from(message: &MemoGlob) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::MemoGlob);
+        push_typed_field(&mut record, "memo", &message.memo);
+        push_typed_field(&mut record, "mesg_num", &message.mesg_num);
+        push_typed_field(&mut record, "parent_index", &message.parent_index);
+        push_typed_field(&mut record, "field_num", &message.field_num);
+        push_typed_field(&mut record, "data", &message.data);
+        push_typed_field(&mut record, "part_index", &message.part_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct AntChannelId {
+    pub channel_number: Option<u8>,
+    pub device_type: Option<u8>,
+    pub device_number: Option<u16>,
+    pub transmission_type: Option<u8>,
+    pub device_index: Option<field_types::DeviceIndex>,
+}
+impl TryFrom<&FitDataRecord> for AntChannelId {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::AntChannelId)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "channel_number" => message.channel_number = Some(typed_field_value(field)?),
+                "device_type" => message.device_type = Some(typed_field_value(field)?),
+                "device_number" => message.device_number = Some(typed_field_value(field)?),
+                "transmission_type" => message.transmission_type = Some(typed_field_value(field)?),
+                "device_index" => message.device_index = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&AntChannelId> for FitDataRecord {
+    fn from(message: &AntChannelId) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::AntChannelId);
+        push_typed_field(&mut record, "channel_number", &message.channel_number);
+        push_typed_field(&mut record, "device_type", &message.device_type);
+        push_typed_field(&mut record, "device_number", &message.device_number);
+        push_typed_field(&mut record, "transmission_type", &message.transmission_type);
+        push_typed_field(&mut record, "device_index", &message.device_index);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct AntRx {
+    pub fractional_timestamp: Option<f64>,
+    pub mesg_id: Option<u8>,
+    pub mesg_data: Option<Vec<u8>>,
+    pub channel_number: Option<u8>,
+    pub data: Option<Vec<u8>>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for AntRx {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::AntRx)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "fractional_timestamp" => {
+                    message.fractional_timestamp = Some(typed_field_value(field)?)
+                }
+                "mesg_id" => message.mesg_id = Some(typed_field_value(field)?),
+                "mesg_data" => message.mesg_data = Some(typed_field_value(field)?),
+                "channel_number" => message.channel_number = Some(typed_field_value(field)?),
+                "data" => message.data = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&AntRx> for FitDataRecord {
+    fn from(message: &AntRx) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::AntRx);
+        push_typed_field(
+            &mut record,
+            "fractional_timestamp",
+            &message.fractional_timestamp,
+        );
+        push_typed_field(&mut record, "mesg_id", &message.mesg_id);
+        push_typed_field(&mut record, "mesg_data", &message.mesg_data);
+        push_typed_field(&mut record, "channel_number", &message.channel_number);
+        push_typed_field(&mut record, "data", &message.data);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct AntTx {
+    pub fractional_timestamp: Option<f64>,
+    pub mesg_id: Option<u8>,
+    pub mesg_data: Option<Vec<u8>>,
+    pub channel_number: Option<u8>,
+    pub data: Option<Vec<u8>>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for AntTx {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::AntTx)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "fractional_timestamp" => {
+                    message.fractional_timestamp = Some(typed_field_value(field)?)
+                }
+                "mesg_id" => message.mesg_id = Some(typed_field_value(field)?),
+                "mesg_data" => message.mesg_data = Some(typed_field_value(field)?),
+                "channel_number" => message.channel_number = Some(typed_field_value(field)?),
+                "data" => message.data = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&AntTx> for FitDataRecord {
+    fn from(message: &AntTx) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::AntTx);
+        push_typed_field(
+            &mut record,
+            "fractional_timestamp",
+            &message.fractional_timestamp,
+        );
+        push_typed_field(&mut record, "mesg_id", &message.mesg_id);
+        push_typed_field(&mut record, "mesg_data", &message.mesg_data);
+        push_typed_field(&mut record, "channel_number", &message.channel_number);
+        push_typed_field(&mut record, "data", &message.data);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ExdScreenConfiguration {
+    pub screen_index: Option<u8>,
+    pub field_count: Option<u8>,
+    pub layout: Option<field_types::ExdLayout>,
+    pub screen_enabled: Option<bool>,
+}
+impl TryFrom<&FitDataRecord> for ExdScreenConfiguration {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::ExdScreenConfiguration)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "screen_index" => message.screen_index = Some(typed_field_value(field)?),
+                "field_count" => message.field_count = Some(typed_field_value(field)?),
+                "layout" => message.layout = Some(typed_field_value(field)?),
+                "screen_enabled" => message.screen_enabled = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&ExdScreenConfiguration> for FitDataRecord {
+    fn from(message: &ExdScreenConfiguration) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::ExdScreenConfiguration);
+        push_typed_field(&mut record, "screen_index", &message.screen_index);
+        push_typed_field(&mut record, "field_count", &message.field_count);
+        push_typed_field(&mut record, "layout", &message.layout);
+        push_typed_field(&mut record, "screen_enabled", &message.screen_enabled);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ExdDataFieldConfiguration {
+    pub screen_index: Option<u8>,
+    pub concept_field: Option<u8>,
+    pub field_id: Option<u8>,
+    pub concept_count: Option<u8>,
+    pub display_type: Option<field_types::ExdDisplayType>,
+    pub title: Option<Vec<String>>,
+}
+impl TryFrom<&FitDataRecord> for ExdDataFieldConfiguration {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::ExdDataFieldConfiguration)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "screen_index" => message.screen_index = Some(typed_field_value(field)?),
+                "concept_field" => message.concept_field = Some(typed_field_value(field)?),
+                "field_id" => message.field_id = Some(typed_field_value(field)?),
+                "concept_count" => message.concept_count = Some(typed_field_value(field)?),
+                "display_type" => message.display_type = Some(typed_field_value(field)?),
+                "title" => message.title = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&ExdDataFieldConfiguration> for FitDataRecord {
+    fn from(message: &ExdDataFieldConfiguration) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::ExdDataFieldConfiguration);
+        push_typed_field(&mut record, "screen_index", &message.screen_index);
+        push_typed_field(&mut record, "concept_field", &message.concept_field);
+        push_typed_field(&mut record, "field_id", &message.field_id);
+        push_typed_field(&mut record, "concept_count", &message.concept_count);
+        push_typed_field(&mut record, "display_type", &message.display_type);
+        push_typed_field(&mut record, "title", &message.title);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ExdDataConceptConfiguration {
+    pub screen_index: Option<u8>,
+    pub concept_field: Option<u8>,
+    pub field_id: Option<u8>,
+    pub concept_index: Option<u8>,
+    pub data_page: Option<u8>,
+    pub concept_key: Option<u8>,
+    pub scaling: Option<u8>,
+    pub data_units: Option<field_types::ExdDataUnits>,
+    pub qualifier: Option<field_types::ExdQualifiers>,
+    pub descriptor: Option<field_types::ExdDescriptors>,
+    pub is_signed: Option<bool>,
+}
+impl TryFrom<&FitDataRecord> for ExdDataConceptConfiguration {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::ExdDataConceptConfiguration)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "screen_index" => message.screen_index = Some(typed_field_value(field)?),
+                "concept_field" => message.concept_field = Some(typed_field_value(field)?),
+                "field_id" => message.field_id = Some(typed_field_value(field)?),
+                "concept_index" => message.concept_index = Some(typed_field_value(field)?),
+                "data_page" => message.data_page = Some(typed_field_value(field)?),
+                "concept_key" => message.concept_key = Some(typed_field_value(field)?),
+                "scaling" => message.scaling = Some(typed_field_value(field)?),
+                "data_units" => message.data_units = Some(typed_field_value(field)?),
+                "qualifier" => message.qualifier = Some(typed_field_value(field)?),
+                "descriptor" => message.descriptor = Some(typed_field_value(field)?),
+                "is_signed" => message.is_signed = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&ExdDataConceptConfiguration> for FitDataRecord {
+    fn from(message: &ExdDataConceptConfiguration) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::ExdDataConceptConfiguration);
+        push_typed_field(&mut record, "screen_index", &message.screen_index);
+        push_typed_field(&mut record, "concept_field", &message.concept_field);
+        push_typed_field(&mut record, "field_id", &message.field_id);
+        push_typed_field(&mut record, "concept_index", &message.concept_index);
+        push_typed_field(&mut record, "data_page", &message.data_page);
+        push_typed_field(&mut record, "concept_key", &message.concept_key);
+        push_typed_field(&mut record, "scaling", &message.scaling);
+        push_typed_field(&mut record, "data_units", &message.data_units);
+        push_typed_field(&mut record, "qualifier", &message.qualifier);
+        push_typed_field(&mut record, "descriptor", &message.descriptor);
+        push_typed_field(&mut record, "is_signed", &message.is_signed);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct DiveSummary {
+    pub reference_mesg: Option<field_types::MesgNum>,
+    pub reference_index: Option<field_types::MessageIndex>,
+    pub avg_depth: Option<f64>,
+    pub max_depth: Option<f64>,
+    pub surface_interval: Option<u32>,
+    pub start_cns: Option<u8>,
+    pub end_cns: Option<u8>,
+    pub start_n2: Option<u16>,
+    pub end_n2: Option<u16>,
+    pub o2_toxicity: Option<u16>,
+    pub dive_number: Option<u32>,
+    pub bottom_time: Option<f64>,
+    pub avg_pressure_sac: Option<f64>,
+    pub avg_volume_sac: Option<f64>,
+    pub avg_rmv: Option<f64>,
+    pub descent_time: Option<f64>,
+    pub ascent_time: Option<f64>,
+    pub avg_ascent_rate: Option<f64>,
+    pub avg_descent_rate: Option<f64>,
+    pub max_ascent_rate: Option<f64>,
+    pub max_descent_rate: Option<f64>,
+    pub hang_time: Option<f64>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for DiveSummary {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::DiveSummary)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "reference_mesg" => message.reference_mesg = Some(typed_field_value(field)?),
+                "reference_index" => message.reference_index = Some(typed_field_value(field)?),
+                "avg_depth" => message.avg_depth = Some(typed_field_value(field)?),
+                "max_depth" => message.max_depth = Some(typed_field_value(field)?),
+                "surface_interval" => message.surface_interval = Some(typed_field_value(field)?),
+                "start_cns" => message.start_cns = Some(typed_field_value(field)?),
+                "end_cns" => message.end_cns = Some(typed_field_value(field)?),
+                "start_n2" => message.start_n2 = Some(typed_field_value(field)?),
+                "end_n2" => message.end_n2 = Some(typed_field_value(field)?),
+                "o2_toxicity" => message.o2_toxicity = Some(typed_field_value(field)?),
+                "dive_number" => message.dive_number = Some(typed_field_value(field)?),
+                "bottom_time" => message.bottom_time = Some(typed_field_value(field)?),
+                "avg_pressure_sac" => message.avg_pressure_sac = Some(typed_field_value(field)?),
+                "avg_volume_sac" => message.avg_volume_sac = Some(typed_field_value(field)?),
+                "avg_rmv" => message.avg_rmv = Some(typed_field_value(field)?),
+                "descent_time" => message.descent_time = Some(typed_field_value(field)?),
+                "ascent_time" => message.ascent_time = Some(typed_field_value(field)?),
+                "avg_ascent_rate" => message.avg_ascent_rate = Some(typed_field_value(field)?),
+                "avg_descent_rate" => message.avg_descent_rate = Some(typed_field_value(field)?),
+                "max_ascent_rate" => message.max_ascent_rate = Some(typed_field_value(field)?),
+                "max_descent_rate" => message.max_descent_rate = Some(typed_field_value(field)?),
+                "hang_time" => message.hang_time = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&DiveSummary> for FitDataRecord {
+    fn from(message: &DiveSummary) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::DiveSummary);
+        push_typed_field(&mut record, "reference_mesg", &message.reference_mesg);
+        push_typed_field(&mut record, "reference_index", &message.reference_index);
+        push_typed_field(&mut record, "avg_depth", &message.avg_depth);
+        push_typed_field(&mut record, "max_depth", &message.max_depth);
+        push_typed_field(&mut record, "surface_interval", &message.surface_interval);
+        push_typed_field(&mut record, "start_cns", &message.start_cns);
+        push_typed_field(&mut record, "end_cns", &message.end_cns);
+        push_typed_field(&mut record, "start_n2", &message.start_n2);
+        push_typed_field(&mut record, "end_n2", &message.end_n2);
+        push_typed_field(&mut record, "o2_toxicity", &message.o2_toxicity);
+        push_typed_field(&mut record, "dive_number", &message.dive_number);
+        push_typed_field(&mut record, "bottom_time", &message.bottom_time);
+        push_typed_field(&mut record, "avg_pressure_sac", &message.avg_pressure_sac);
+        push_typed_field(&mut record, "avg_volume_sac", &message.avg_volume_sac);
+        push_typed_field(&mut record, "avg_rmv", &message.avg_rmv);
+        push_typed_field(&mut record, "descent_time", &message.descent_time);
+        push_typed_field(&mut record, "ascent_time", &message.ascent_time);
+        push_typed_field(&mut record, "avg_ascent_rate", &message.avg_ascent_rate);
+        push_typed_field(&mut record, "avg_descent_rate", &message.avg_descent_rate);
+        push_typed_field(&mut record, "max_ascent_rate", &message.max_ascent_rate);
+        push_typed_field(&mut record, "max_descent_rate", &message.max_descent_rate);
+        push_typed_field(&mut record, "hang_time", &message.hang_time);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+/// Heart rate variability
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Hrv {
+    pub time: Option<Vec<f64>>,
+}
+impl TryFrom<&FitDataRecord> for Hrv {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::Hrv)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "time" => message.time = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&Hrv> for FitDataRecord {
+    fn from(message: &Hrv) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::Hrv);
+        push_typed_field(&mut record, "time", &message.time);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct TankUpdate {
+    pub sensor: Option<field_types::AntChannelId>,
+    pub pressure: Option<f64>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for TankUpdate {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::TankUpdate)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "sensor" => message.sensor = Some(typed_field_value(field)?),
+                "pressure" => message.pressure = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&TankUpdate> for FitDataRecord {
+    fn from(message: &TankUpdate) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::TankUpdate);
+        push_typed_field(&mut record, "sensor", &message.sensor);
+        push_typed_field(&mut record, "pressure", &message.pressure);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct TankSummary {
+    pub sensor: Option<field_types::AntChannelId>,
+    pub start_pressure: Option<f64>,
+    pub end_pressure: Option<f64>,
+    pub volume_used: Option<f64>,
+    pub timestamp: Option<DateTime<TimestampTz>>,
+}
+impl TryFrom<&FitDataRecord> for TankSummary {
+    type Error = Error;
+    fn try_from(record: &FitDataRecord) -> Result<Self> {
+        check_message_kind(record, MesgNum::TankSummary)?;
+        let mut message = Self::default();
+        for field in record
+            .fields()
+            .iter()
+            .filter(|f| f.developer_data_index().is_none())
+        {
+            match field.name() {
+                "sensor" => message.sensor = Some(typed_field_value(field)?),
+                "start_pressure" => message.start_pressure = Some(typed_field_value(field)?),
+                "end_pressure" => message.end_pressure = Some(typed_field_value(field)?),
+                "volume_used" => message.volume_used = Some(typed_field_value(field)?),
+                "timestamp" => message.timestamp = Some(typed_field_value(field)?),
+                _ => (),
+            }
+        }
+        Ok(message)
+    }
+}
+impl From<&TankSummary> for FitDataRecord {
+    fn from(message: &TankSummary) -> Self {
+        let mut record = FitDataRecord::new(MesgNum::TankSummary);
+        push_typed_field(&mut record, "sensor", &message.sensor);
+        push_typed_field(&mut record, "start_pressure", &message.start_pressure);
+        push_typed_field(&mut record, "end_pressure", &message.end_pressure);
+        push_typed_field(&mut record, "volume_used", &message.volume_used);
+        push_typed_field(&mut record, "timestamp", &message.timestamp);
+        record
+    }
+}
@@ -0,0 +1,136 @@
+//! Python bindings used to parse FIT files from python, this module requires the `python`
+//! feature. The extension module can be built using `maturin` which will enable the
+//! `pyo3/extension-module` feature as needed.
+//!
+//! Records are returned as dictionaries using the same layout as the `fit_to_json` example,
+//! `{"kind": name, "fields": {name: {"value": value, "units": units}}}`.
+use crate::de::{from_bytes, from_reader};
+use crate::profile::VERSION;
+use crate::{ErrorKind, FitDataRecord, Value};
+use pyo3::exceptions::{PyIOError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use std::fs::File;
+use std::path::PathBuf;
+
+/// Convert a parser error into the matching python exception
+fn to_py_err(err: ErrorKind) -> PyErr {
+    match err {
+        ErrorKind::Io(e) => PyIOError::new_err(e.to_string()),
+        e => PyValueError::new_err(e.to_string()),
+    }
+}
+
+/// Convert a field value into the equivalent python object
+fn value_to_py<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    let obj = match value {
+        Value::Timestamp(val) => val.fixed_offset().into_pyobject(py)?.into_any(),
+        Value::LocalTimestamp(val) => val.into_pyobject(py)?.into_any(),
+        Value::Byte(val) => val.into_pyobject(py)?.into_any(),
+        Value::Enum(val) => val.into_pyobject(py)?.into_any(),
+        Value::SInt8(val) => val.into_pyobject(py)?.into_any(),
+        Value::UInt8(val) => val.into_pyobject(py)?.into_any(),
+        Value::UInt8z(val) => val.into_pyobject(py)?.into_any(),
+        Value::SInt16(val) => val.into_pyobject(py)?.into_any(),
+        Value::UInt16(val) => val.into_pyobject(py)?.into_any(),
+        Value::UInt16z(val) => val.into_pyobject(py)?.into_any(),
+        Value::SInt32(val) => val.into_pyobject(py)?.into_any(),
+        Value::UInt32(val) => val.into_pyobject(py)?.into_any(),
+        Value::UInt32z(val) => val.into_pyobject(py)?.into_any(),
+        Value::SInt64(val) => val.into_pyobject(py)?.into_any(),
+        Value::UInt64(val) => val.into_pyobject(py)?.into_any(),
+        Value::UInt64z(val) => val.into_pyobject(py)?.into_any(),
+        Value::Float32(val) => (*val as f64).into_pyobject(py)?.into_any(),
+        Value::Float64(val) => val.into_pyobject(py)?.into_any(),
+        Value::String(val) => val.into_pyobject(py)?.into_any(),
+        Value::Array(vals) => {
+            let vals = vals
+                .iter()
+                .map(|v| value_to_py(py, v))
+                .collect::<PyResult<Vec<_>>>()?;
+            PyList::new(py, vals)?.into_any()
+        }
+    };
+    Ok(obj)
+}
+
+/// Convert a data record into a python dictionary
+fn record_to_py<'py>(py: Python<'py>, record: &FitDataRecord) -> PyResult<Bound<'py, PyDict>> {
+    let fields = PyDict::new(py);
+    for field in record.fields() {
+        let entry = PyDict::new(py);
+        entry.set_item("value", value_to_py(py, field.value())?)?;
+        entry.set_item("units", field.units())?;
+        fields.set_item(field.name(), entry)?;
+    }
+    let dict = PyDict::new(py);
+    dict.set_item("kind", record.kind().to_string())?;
+    dict.set_item("fields", fields)?;
+    Ok(dict)
+}
+
+/// Convert data records into a python list of dictionaries
+fn records_to_py<'py>(py: Python<'py>, records: &[FitDataRecord]) -> PyResult<Bound<'py, PyList>> {
+    let records = records
+        .iter()
+        .map(|r| record_to_py(py, r))
+        .collect::<PyResult<Vec<_>>>()?;
+    PyList::new(py, records)
+}
+
+/// Parse the bytes of a FIT file into a list of records
+#[pyfunction]
+fn parse_bytes<'py>(py: Python<'py>, data: &[u8]) -> PyResult<Bound<'py, PyList>> {
+    let records = from_bytes(data).map_err(|e| to_py_err(*e))?;
+    records_to_py(py, &records)
+}
+
+/// Parse a FIT file stored at the provided path into a list of records
+#[pyfunction]
+fn parse_path(py: Python<'_>, path: PathBuf) -> PyResult<Bound<'_, PyList>> {
+    let mut fp = File::open(path)?;
+    let records = from_reader(&mut fp).map_err(|e| to_py_err(*e))?;
+    records_to_py(py, &records)
+}
+
+/// Python extension module definition
+#[pymodule]
+fn fitparser(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("PROFILE_VERSION", VERSION)?;
+    m.add_function(wrap_pyfunction!(parse_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_path, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_bytes_to_dicts() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        Python::initialize();
+        Python::attach(|py| {
+            let records = parse_bytes(py, data).unwrap();
+            assert_eq!(records.len(), 22);
+            let record = records.get_item(0).unwrap();
+            let record = record.cast::<PyDict>().unwrap();
+            let kind: String = record.get_item("kind").unwrap().unwrap().extract().unwrap();
+            assert_eq!(kind, "file_id");
+            let fields = record.get_item("fields").unwrap().unwrap();
+            let manufacturer = fields.get_item("manufacturer").unwrap();
+            let value: String = manufacturer.get_item("value").unwrap().extract().unwrap();
+            assert_eq!(value, "dynastream");
+        });
+    }
+
+    #[test]
+    fn parse_bytes_error() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        Python::initialize();
+        Python::attach(|py| {
+            let err = parse_bytes(py, &data[..data.len() - 10]).unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+    }
+}
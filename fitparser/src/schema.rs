@@ -0,0 +1,235 @@
+//! Generate a JSON Schema describing the records serialized using `ser::SerializationOptions`,
+//! so consumers of the JSON output can validate it and generate code from the bundled profile.
+//! Each message kind is described under `$defs` with the type and profile units of its fields,
+//! fields that aren't defined by the profile such as developer fields are allowed with any value.
+use crate::de::BaseType;
+use crate::profile::{FieldDataType, FieldInfo, MesgNum};
+use crate::ser::{SerializationOptions, TimestampFormat};
+use serde_json::{json, Map, Value};
+
+/// Version of JSON Schema used by the generated schemas
+const SCHEMA_DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Return the schema of an array of records serialized using the options
+pub fn json_schema(options: &SerializationOptions) -> Value {
+    let mut defs = Map::new();
+    let mut kinds = Vec::new();
+    for kind in (0..=u16::MAX).map(MesgNum::from) {
+        if kind.field_info().is_empty() {
+            continue;
+        }
+        kinds.push(json!({
+            "if": {"properties": {"kind": {"const": kind.to_string()}}, "required": ["kind"]},
+            "then": {"$ref": format!("#/$defs/{}", kind)}
+        }));
+        defs.insert(kind.to_string(), record_schema(kind, options));
+    }
+    json!({
+        "$schema": SCHEMA_DRAFT,
+        "title": "FIT records",
+        "type": "array",
+        "items": {
+            "type": "object",
+            "properties": {
+                "kind": {"type": ["string", "integer"]},
+                "fields": fields_type(options)
+            },
+            "required": ["kind", "fields"],
+            "allOf": kinds
+        },
+        "$defs": defs
+    })
+}
+
+/// Return the schema of a single record of the kind serialized using the options
+pub fn record_schema(kind: MesgNum, options: &SerializationOptions) -> Value {
+    let mut fields = Vec::new();
+    let mut properties = Map::new();
+    for info in kind.field_info() {
+        let name = options.field_case.convert(info.name()).into_owned();
+        if options.flatten_fields {
+            properties.insert(name, flattened_field(info, options));
+        } else {
+            fields.push(json!({
+                "if": {"properties": {"name": {"const": name}}, "required": ["name"]},
+                "then": {"properties": {
+                    "number": {"const": info.def_number()},
+                    "value": value_schema(info, options),
+                    "units": units_schema(info)
+                }}
+            }));
+        }
+    }
+    let mut schema = fields_type(options);
+    if options.flatten_fields {
+        schema["properties"] = Value::Object(properties);
+    } else {
+        schema["items"]["allOf"] = Value::Array(fields);
+    }
+    json!({
+        "title": kind.to_string(),
+        "type": "object",
+        "properties": {
+            "kind": {"const": kind.to_string()},
+            "fields": schema
+        },
+        "required": ["kind", "fields"]
+    })
+}
+
+/// Return the schema of the fields of any record, fields are described by each message kind
+fn fields_type(options: &SerializationOptions) -> Value {
+    if options.flatten_fields {
+        return json!({"type": "object"});
+    }
+    let mut required = vec!["name", "number", "value"];
+    if options.include_units {
+        required.push("units");
+    }
+    json!({
+        "type": "array",
+        "items": {
+            "type": "object",
+            "properties": {
+                "name": {"type": "string"},
+                "number": {"type": "integer", "minimum": 0, "maximum": 255},
+                "developer_data_index": {"type": "integer", "minimum": 0, "maximum": 255},
+                "units": {"type": "string"},
+                "enum_name": {"type": "string"}
+            },
+            "required": required
+        }
+    })
+}
+
+/// Return the schema of a field of the field map, fields are serialized as a map when they have
+/// units or an enum name
+fn flattened_field(info: &FieldInfo, options: &SerializationOptions) -> Value {
+    let value = value_schema(info, options);
+    let has_enum_name = options.enum_names && info.field_type().is_enum_type();
+    if !options.include_units && !has_enum_name {
+        return value;
+    }
+    let mut properties = json!({"value": value});
+    let mut required = vec!["value"];
+    if options.include_units {
+        properties["units"] = units_schema(info);
+        required.push("units");
+    }
+    if has_enum_name {
+        properties["enum_name"] = json!({"type": "string"});
+    }
+    let object = json!({"type": "object", "properties": properties, "required": required});
+    if options.include_units {
+        object
+    } else {
+        // values without a named variant are serialized without a map
+        json!({"anyOf": [value, object]})
+    }
+}
+
+/// Return the schema of a field value, any field may store an array of values
+fn value_schema(info: &FieldInfo, options: &SerializationOptions) -> Value {
+    let field_type = info.field_type();
+    let numeric_timestamps = options.timestamp_format != TimestampFormat::Rfc3339;
+    let mut schema = match field_type {
+        FieldDataType::DateTime | FieldDataType::LocalDateTime if numeric_timestamps => {
+            json!({"type": ["integer"]})
+        }
+        FieldDataType::DateTime => json!({"type": ["string"], "format": "date-time"}),
+        FieldDataType::LocalDateTime => json!({"type": ["string"]}),
+        _ if field_type.is_enum_type() && options.numeric_enums => json!({"type": ["integer"]}),
+        // enum values that aren't named variants are serialized as numbers
+        _ if field_type.is_enum_type() => json!({"type": ["string", "integer"]}),
+        _ => json!({"type": [scalar_type(info)]}),
+    };
+    let scalar = schema.clone();
+    if let Some(types) = schema["type"].as_array_mut() {
+        types.push(json!("array"));
+        if options.include_invalid_fields {
+            types.push(json!("null"));
+        }
+    }
+    schema["items"] = scalar;
+    schema
+}
+
+/// Return the JSON type of a field that isn't an enum or timestamp
+fn scalar_type(info: &FieldInfo) -> &'static str {
+    match info.field_type().base_type() {
+        BaseType::String => "string",
+        BaseType::Float32 | BaseType::Float64 => "number",
+        _ if (info.scale() - 1.0).abs() > f64::EPSILON || info.offset().abs() > f64::EPSILON => {
+            "number"
+        }
+        _ => "integer",
+    }
+}
+
+/// Return the schema of the units of a field, the profile units are given as the default since
+/// they change when converting the units while decoding
+fn units_schema(info: &FieldInfo) -> Value {
+    json!({"type": "string", "default": info.units()})
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+    use crate::ser::FieldCase;
+
+    #[test]
+    fn generate_json_schema() {
+        let options = SerializationOptions::default();
+        let schema = json_schema(&options);
+        assert_eq!(schema["$schema"], SCHEMA_DRAFT);
+        let defs = schema["$defs"].as_object().unwrap();
+        assert!(defs.contains_key("file_id"));
+        assert!(defs.contains_key("record"));
+        let kinds = schema["items"]["allOf"].as_array().unwrap();
+        assert_eq!(kinds.len(), defs.len());
+
+        // every field of a decoded record is described by the schema of its kind
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+        let options = SerializationOptions {
+            flatten_fields: true,
+            field_case: FieldCase::Camel,
+            ..SerializationOptions::default()
+        };
+        for record in &records {
+            let schema = record_schema(record.kind(), &options);
+            let properties = &schema["properties"]["fields"]["properties"];
+            let json = serde_json::to_value(options.record(record)).unwrap();
+            for (name, field) in json["fields"].as_object().unwrap() {
+                let property = &properties[name];
+                assert!(property.is_object(), "{} {}", record.kind(), name);
+                let expected = &property["properties"]["value"]["type"];
+                let value_type = match &field["value"] {
+                    Value::String(_) => "string",
+                    Value::Number(num) if num.is_f64() => "number",
+                    Value::Number(_) => "integer",
+                    Value::Array(_) => "array",
+                    _ => "null",
+                };
+                let types = expected.as_array().unwrap();
+                assert!(
+                    types.contains(&json!(value_type))
+                        || value_type == "integer" && types.contains(&json!("number")),
+                    "{} {} {}",
+                    record.kind(),
+                    name,
+                    field
+                );
+            }
+        }
+
+        let schema = record_schema(MesgNum::Record, &options);
+        let heart_rate = &schema["properties"]["fields"]["properties"]["heartRate"];
+        assert_eq!(heart_rate["properties"]["units"]["default"], "bpm");
+        assert_eq!(
+            heart_rate["properties"]["value"]["items"]["type"][0],
+            "integer"
+        );
+    }
+}
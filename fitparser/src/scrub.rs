@@ -0,0 +1,172 @@
+//! Remove personal data from the records of a FIT file, such as serial numbers, the user profile
+//! and the positions near the start of an activity, before sharing it publicly.
+use crate::export::semicircles_field;
+use crate::profile::MesgNum;
+use crate::{FitDataField, FitDataRecord, TimestampTz, Value};
+use chrono::{DateTime, Duration, TimeZone};
+
+/// Unix timestamp of 2000-01-01T00:00:00Z, FIT timestamps before mid 1998 are treated as the
+/// time since the device was powered on so earlier dates are avoided
+const DEFAULT_EPOCH: i64 = 946_684_800;
+
+/// Default radius in meters around the starting position where positions are removed
+const DEFAULT_PRIVACY_RADIUS: f64 = 500.0;
+
+/// Mean radius of the earth in meters
+const EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// Fields identifying the device that recorded the file
+const IDENTIFYING_FIELDS: &[&str] = &["serial_number"];
+
+/// Options controlling how the records are scrubbed
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScrubOptions {
+    /// Positions closer than this distance in meters to the starting position are removed
+    pub privacy_radius: f64,
+    /// Timestamps are shifted so the first timestamp of the file, usually the `time_created`
+    /// field of the `file_id` message, is at this date. `None` keeps the original timestamps.
+    pub epoch: Option<DateTime<TimestampTz>>,
+}
+
+impl Default for ScrubOptions {
+    fn default() -> Self {
+        ScrubOptions {
+            privacy_radius: DEFAULT_PRIVACY_RADIUS,
+            epoch: TimestampTz.timestamp_opt(DEFAULT_EPOCH, 0).single(),
+        }
+    }
+}
+
+/// Return a copy of the records with personal data removed. Serial numbers and `user_profile`
+/// messages are dropped along with any position within the privacy radius of the first position
+/// in the `record` messages, including the start and end positions of laps and sessions. All
+/// timestamps are shifted by the same amount so the durations between them are kept.
+pub fn scrub(records: &[FitDataRecord], options: &ScrubOptions) -> Vec<FitDataRecord> {
+    let start = records
+        .iter()
+        .filter(|r| r.kind() == MesgNum::Record)
+        .find_map(|r| position(r, "position"));
+    let offset = options.epoch.and_then(|epoch| {
+        let first = records
+            .iter()
+            .flat_map(|r| r.fields())
+            .find_map(|f| match f.value() {
+                Value::Timestamp(val) => Some(*val),
+                _ => None,
+            })?;
+        Some(epoch - first)
+    });
+
+    records
+        .iter()
+        .filter(|r| r.kind() != MesgNum::UserProfile)
+        .map(|r| scrub_record(r, start, offset, options.privacy_radius))
+        .collect()
+}
+
+/// Remove the identifying fields and private positions of a record and shift its timestamps
+fn scrub_record(
+    record: &FitDataRecord,
+    start: Option<(f64, f64)>,
+    offset: Option<Duration>,
+    privacy_radius: f64,
+) -> FitDataRecord {
+    let mut scrubbed = FitDataRecord::new(record.kind());
+    for field in record.fields() {
+        if IDENTIFYING_FIELDS.contains(&field.name()) {
+            continue;
+        }
+        // positions are stored as a pair of latitude and longitude fields sharing a prefix
+        let prefix = field
+            .name()
+            .strip_suffix("_lat")
+            .or_else(|| field.name().strip_suffix("_long"));
+        if let (Some(prefix), Some(start)) = (prefix, start) {
+            if position(record, prefix).is_some_and(|p| distance(start, p) < privacy_radius) {
+                continue;
+            }
+        }
+
+        let value = match (field.value(), offset) {
+            (Value::Timestamp(val), Some(offset)) => Value::Timestamp(*val + offset),
+            (Value::LocalTimestamp(val), Some(offset)) => Value::LocalTimestamp(*val + offset),
+            (value, _) => value.clone(),
+        };
+        let name = field.name.clone();
+        let units = field.units.clone();
+        scrubbed.push(match field.developer_data_index() {
+            Some(index) => {
+                FitDataField::new_developer_field(name, field.number(), index, value, units)
+            }
+            None => FitDataField::new(name, field.number(), value, units),
+        });
+    }
+    scrubbed
+}
+
+/// Find the latitude and longitude in degrees of a position stored in the record
+fn position(record: &FitDataRecord, prefix: &str) -> Option<(f64, f64)> {
+    let latitude = semicircles_field(record, &format!("{}_lat", prefix))?;
+    let longitude = semicircles_field(record, &format!("{}_long", prefix))?;
+    Some((latitude, longitude))
+}
+
+/// Great circle distance in meters between two positions using the haversine formula
+pub(crate) fn distance(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (lat1, lat2) = (from.0.to_radians(), to.0.to_radians());
+    let dlat = lat2 - lat1;
+    let dlong = (to.1 - from.1).to_radians();
+    let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlong / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS * a.sqrt().asin()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::timestamp_field;
+    use crate::from_bytes;
+
+    #[test]
+    fn scrub_activity() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+        let options = ScrubOptions::default();
+        let scrubbed = scrub(&records, &options);
+        assert_eq!(scrubbed.len(), records.len());
+        assert!(scrubbed
+            .iter()
+            .flat_map(|r| r.fields())
+            .all(|f| f.name() != "serial_number"));
+        assert_eq!(timestamp_field(&scrubbed[0], "time_created"), options.epoch);
+
+        // every position of the short activity is within the privacy radius
+        let positions = |records: &[FitDataRecord]| {
+            records
+                .iter()
+                .filter(|r| position(r, "position").is_some())
+                .count()
+        };
+        assert!(positions(&records) > 0);
+        assert_eq!(positions(&scrubbed), 0);
+
+        let options = ScrubOptions {
+            privacy_radius: 0.0,
+            epoch: None,
+        };
+        let scrubbed = scrub(&records, &options);
+        assert_eq!(positions(&scrubbed), positions(&records));
+        assert_eq!(
+            timestamp_field(&scrubbed[0], "time_created"),
+            timestamp_field(&records[0], "time_created")
+        );
+        assert!(crate::to_bytes(&scrubbed).is_ok());
+    }
+
+    #[test]
+    fn haversine_distance() {
+        // one degree of latitude is roughly 111 km
+        let d = distance((45.0, 7.0), (46.0, 7.0));
+        assert!((d - 111_195.0).abs() < 10.0);
+        assert_eq!(distance((45.0, 7.0), (45.0, 7.0)), 0.0);
+    }
+}
@@ -0,0 +1,820 @@
+//! Control the shape of the serde output of decoded records and FIT files, so consumers can get
+//! the structure they need without post-processing the serialized data. Records can be
+//! deserialized from any of the shapes, so serialized files can be edited and encoded again.
+use crate::compat::prelude::*;
+use crate::de::{invalid_field_numbers, FitFile};
+use crate::profile::{
+    get_field_variant_as_string, get_field_variant_from_string, FieldDataType, FieldInfo, MesgNum,
+};
+use crate::{FitDataField, FitDataRecord, TimestampTz, Value};
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use chrono::{DateTime, Duration, FixedOffset, NaiveDateTime, TimeZone, Utc};
+use core::convert::TryFrom;
+use serde::de::Error as _;
+use serde::ser::{SerializeSeq, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Options controlling how records are serialized, the default matches the serialization of
+/// `FitDataRecord`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SerializationOptions {
+    /// Serialize the fields of a record as a map keyed by field name instead of an array, when
+    /// several fields share a name the last one is kept
+    pub flatten_fields: bool,
+    /// Include the units of each field, flattened fields are then serialized as a map with a
+    /// `value` and `units` key instead of the bare value
+    pub include_units: bool,
+    /// Include fields whose value is invalid, such as `0xFF` for a `uint8`, with a null value.
+    /// These are dropped while decoding so they're only known for records decoded with
+    /// `DecodeOption::PreserveLayout` or `DecodeOption::KeepRawBytes`.
+    pub include_invalid_fields: bool,
+    /// Serialize enum values using their number instead of their name
+    pub numeric_enums: bool,
+    /// Include the variant name of enum values serialized as numbers under an `enum_name` key,
+    /// this applies to `numeric_enums` and records decoded with
+    /// `DecodeOption::ReturnNumericEnumValues`. Flattened fields are serialized as a map even
+    /// when units aren't included.
+    pub enum_names: bool,
+    /// Time zone used to serialize timestamps
+    pub timestamp_zone: TimestampZone,
+    /// Format of serialized timestamps
+    pub timestamp_format: TimestampFormat,
+    /// Casing of the serialized field names, the message kind is always snake_case
+    pub field_case: FieldCase,
+}
+
+/// Casing of serialized field names
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldCase {
+    /// Names as defined in the profile, such as `heart_rate`
+    Snake,
+    /// Names such as `heartRate`, matching the field names of the FIT SDK for JavaScript
+    Camel,
+    /// Names such as `HeartRate`, matching the field names of the FIT SDK for C# and Java
+    Pascal,
+}
+
+impl FieldCase {
+    /// Convert a snake_case name into the casing, names are split on underscores
+    pub fn convert<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        if *self == FieldCase::Snake {
+            return Cow::Borrowed(name);
+        }
+        let mut converted = String::with_capacity(name.len());
+        for (idx, word) in name.split('_').enumerate() {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                if idx == 0 && *self == FieldCase::Camel {
+                    converted.push(first);
+                } else {
+                    converted.extend(first.to_uppercase());
+                }
+                converted.push_str(chars.as_str());
+            }
+        }
+        Cow::Owned(converted)
+    }
+}
+
+/// Time zone used to serialize timestamps, the serialized timestamps refer to the same instant
+/// regardless of the zone. Local timestamps are serialized without an offset by default since
+/// their time zone isn't stored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimestampZone {
+    /// Keep the time zone timestamps are decoded with, this is the system time zone when the
+    /// `std` feature is enabled and UTC otherwise
+    Decoded,
+    /// Serialize timestamps in UTC
+    Utc,
+    /// Serialize timestamps using a fixed offset from UTC, such as the time zone an activity was
+    /// recorded in found using `analysis::utc_offset`. Local timestamps are given the offset
+    /// instead of being serialized without one, offsets of a day or more are ignored.
+    UtcOffset(Duration),
+}
+
+/// Format of serialized timestamps. Local timestamps without an offset are serialized as numbers
+/// counting from the epoch in their own time zone, the same way they are stored in FIT files.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// RFC 3339 strings such as `2012-04-09T21:22:26Z`
+    Rfc3339,
+    /// Seconds since the Unix epoch
+    UnixSeconds,
+    /// Milliseconds since the Unix epoch
+    UnixMillis,
+    /// Seconds since the FIT epoch of 1989-12-31T00:00:00Z, which is the value stored in FIT
+    /// files
+    FitSeconds,
+}
+
+/// Seconds between the Unix epoch and the FIT epoch
+const FIT_EPOCH_OFFSET: i64 = 631_065_600;
+
+impl TimestampFormat {
+    /// Convert seconds and milliseconds since the Unix epoch into the number serialized
+    fn number(self, seconds: i64, millis: i64) -> Option<i64> {
+        match self {
+            TimestampFormat::Rfc3339 => None,
+            TimestampFormat::UnixSeconds => Some(seconds),
+            TimestampFormat::UnixMillis => Some(millis),
+            TimestampFormat::FitSeconds => Some(seconds - FIT_EPOCH_OFFSET),
+        }
+    }
+
+    /// Convert a timestamp into the format, strings are left unchanged
+    fn apply(self, output: ValueOutput) -> ValueOutput {
+        let number = match &output {
+            ValueOutput::Value(Value::Timestamp(val)) => {
+                self.number(val.timestamp(), val.timestamp_millis())
+            }
+            ValueOutput::Value(Value::LocalTimestamp(val)) => {
+                let val = val.and_utc();
+                self.number(val.timestamp(), val.timestamp_millis())
+            }
+            ValueOutput::Utc(val) => self.number(val.timestamp(), val.timestamp_millis()),
+            ValueOutput::Offset(val) => self.number(val.timestamp(), val.timestamp_millis()),
+            _ => None,
+        };
+        number.map_or(output, ValueOutput::Number)
+    }
+}
+
+impl Default for SerializationOptions {
+    fn default() -> Self {
+        SerializationOptions {
+            flatten_fields: false,
+            include_units: true,
+            include_invalid_fields: false,
+            numeric_enums: false,
+            enum_names: false,
+            timestamp_zone: TimestampZone::Decoded,
+            timestamp_format: TimestampFormat::Rfc3339,
+            field_case: FieldCase::Snake,
+        }
+    }
+}
+
+impl SerializationOptions {
+    /// Wrap a record so it is serialized using the options
+    pub fn record<'a>(&'a self, record: &'a FitDataRecord) -> SerializeRecord<'a> {
+        SerializeRecord {
+            record,
+            options: self,
+        }
+    }
+
+    /// Wrap a list of records so they are serialized as a sequence using the options
+    pub fn records<'a>(&'a self, records: &'a [FitDataRecord]) -> SerializeRecords<'a> {
+        SerializeRecords {
+            records,
+            options: self,
+        }
+    }
+
+    /// Wrap a FIT file so its header, records and CRC are serialized using the options
+    pub fn file<'a>(&'a self, file: &'a FitFile) -> SerializeFile<'a> {
+        SerializeFile {
+            file,
+            options: self,
+        }
+    }
+}
+
+/// Record serialized using `SerializationOptions`
+#[derive(Clone, Copy, Debug)]
+pub struct SerializeRecord<'a> {
+    record: &'a FitDataRecord,
+    options: &'a SerializationOptions,
+}
+
+/// Records serialized using `SerializationOptions`
+#[derive(Clone, Copy, Debug)]
+pub struct SerializeRecords<'a> {
+    records: &'a [FitDataRecord],
+    options: &'a SerializationOptions,
+}
+
+/// FIT file serialized using `SerializationOptions`
+#[derive(Clone, Copy, Debug)]
+pub struct SerializeFile<'a> {
+    file: &'a FitFile,
+    options: &'a SerializationOptions,
+}
+
+/// Field serialized as an element of the field array
+#[derive(Serialize)]
+struct FieldEntry<'a> {
+    name: Cow<'a, str>,
+    number: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    developer_data_index: Option<u8>,
+    value: Option<ValueOutput>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    units: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enum_name: Option<String>,
+}
+
+/// Field serialized as a value of the field map
+#[derive(Serialize)]
+#[serde(untagged)]
+enum FieldValue<'a> {
+    Value(Option<ValueOutput>),
+    WithUnits {
+        value: Option<ValueOutput>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        units: Option<&'a str>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        enum_name: Option<String>,
+    },
+}
+
+/// Value of a field, timestamps are converted into the requested time zone and format
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ValueOutput {
+    Value(Value),
+    Utc(DateTime<Utc>),
+    Offset(DateTime<FixedOffset>),
+    Number(i64),
+}
+
+impl<'a> SerializeRecord<'a> {
+    /// Return the fields to serialize, invalid fields have no value
+    fn fields(&self) -> Vec<FieldEntry<'a>> {
+        let record = self.record;
+        let options = self.options;
+        let units = |units: &'a str| Some(units).filter(|_| options.include_units);
+        let mut fields: Vec<FieldEntry<'a>> = record
+            .fields()
+            .iter()
+            .map(|field| FieldEntry {
+                name: options.field_case.convert(field.name()),
+                number: field.number(),
+                developer_data_index: field.developer_data_index(),
+                value: Some(self.value(field)),
+                units: units(field.units()),
+                enum_name: self.enum_name(field),
+            })
+            .collect();
+        if options.include_invalid_fields {
+            for number in invalid_field_numbers(record) {
+                if let Some(info) = record.kind().get_field_info(number, "") {
+                    fields.push(FieldEntry {
+                        name: options.field_case.convert(info.name()),
+                        number,
+                        developer_data_index: None,
+                        value: None,
+                        units: units(info.units()),
+                        enum_name: None,
+                    });
+                }
+            }
+        }
+        fields
+    }
+
+    /// Return the value of a field, converting enum names back into numbers and timestamps into
+    /// the time zone if requested
+    fn value(&self, field: &FitDataField) -> ValueOutput {
+        let name = match field.value() {
+            Value::String(name) if self.options.numeric_enums => name,
+            Value::Timestamp(_) | Value::LocalTimestamp(_) => {
+                let timestamp = self.timestamp(field.value());
+                return self.options.timestamp_format.apply(timestamp);
+            }
+            value => return ValueOutput::Value(value.clone()),
+        };
+        let value = self
+            .record
+            .kind()
+            .get_field_info(field.number(), field.name())
+            .filter(|info| {
+                field.developer_data_index().is_none() && info.field_type().is_enum_type()
+            })
+            .and_then(|info| get_field_variant_from_string(info.field_type(), name))
+            .map_or_else(|| field.value().clone(), Value::SInt64);
+        ValueOutput::Value(value)
+    }
+
+    /// Return the variant name of an enum field serialized as a number if requested, values that
+    /// aren't named variants have no name
+    fn enum_name(&self, field: &FitDataField) -> Option<String> {
+        if !self.options.enum_names || field.developer_data_index().is_some() {
+            return None;
+        }
+        let field_type = self
+            .record
+            .kind()
+            .get_field_info(field.number(), field.name())
+            .map(FieldInfo::field_type)
+            .filter(|field_type| field_type.is_enum_type())?;
+        match field.value() {
+            Value::String(name) if self.options.numeric_enums => Some(name.to_string()),
+            Value::String(_) => None,
+            value => i64::try_from(value)
+                .ok()
+                .filter(|val| field_type.is_named_variant(*val))
+                .map(|val| get_field_variant_as_string(field_type, val)),
+        }
+    }
+
+    /// Convert a timestamp into the time zone of the options, local timestamps are only given
+    /// an offset when serializing using a fixed offset
+    fn timestamp(&self, value: &Value) -> ValueOutput {
+        let offset = match self.options.timestamp_zone {
+            TimestampZone::Decoded => return ValueOutput::Value(value.clone()),
+            TimestampZone::Utc => None,
+            TimestampZone::UtcOffset(offset) => i32::try_from(offset.num_seconds())
+                .ok()
+                .and_then(FixedOffset::east_opt),
+        };
+        match (value, offset) {
+            (Value::Timestamp(val), Some(offset)) => {
+                ValueOutput::Offset(val.with_timezone(&offset))
+            }
+            (Value::Timestamp(val), None) => ValueOutput::Utc(val.with_timezone(&Utc)),
+            (Value::LocalTimestamp(val), Some(offset)) => offset
+                .from_local_datetime(val)
+                .single()
+                .map_or_else(|| ValueOutput::Value(value.clone()), ValueOutput::Offset),
+            _ => ValueOutput::Value(value.clone()),
+        }
+    }
+}
+
+impl<'a> Serialize for SerializeRecord<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let fields = self.fields();
+        let mut state = serializer.serialize_struct("FitDataRecord", 2)?;
+        state.serialize_field("kind", &self.record.kind())?;
+        if self.options.flatten_fields {
+            let map: BTreeMap<Cow<str>, FieldValue> = fields
+                .into_iter()
+                .map(|f| {
+                    let value = if f.units.is_some() || f.enum_name.is_some() {
+                        FieldValue::WithUnits {
+                            value: f.value,
+                            units: f.units,
+                            enum_name: f.enum_name,
+                        }
+                    } else {
+                        FieldValue::Value(f.value)
+                    };
+                    (f.name, value)
+                })
+                .collect();
+            state.serialize_field("fields", &map)?;
+        } else {
+            state.serialize_field("fields", &fields)?;
+        }
+        state.end()
+    }
+}
+
+impl<'a> Serialize for SerializeRecords<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.records.len()))?;
+        for record in self.records {
+            seq.serialize_element(&self.options.record(record))?;
+        }
+        seq.end()
+    }
+}
+
+impl<'a> Serialize for SerializeFile<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("FitFile", 3)?;
+        state.serialize_field("header", self.file.header())?;
+        state.serialize_field("records", &self.options.records(self.file.records()))?;
+        state.serialize_field("crc", &self.file.crc())?;
+        state.end()
+    }
+}
+
+/// Field read from the field array, invalid fields without a value are skipped
+#[derive(Deserialize)]
+struct FieldInput {
+    name: String,
+    number: u8,
+    #[serde(default)]
+    developer_data_index: Option<u8>,
+    #[serde(default)]
+    value: Option<Value>,
+    #[serde(default)]
+    units: String,
+}
+
+/// Field read from the field map, with or without its units
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FieldValueInput {
+    WithUnits {
+        #[serde(default)]
+        value: Option<Value>,
+    },
+    Value(Option<Value>),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FieldsInput {
+    Array(Vec<FieldInput>),
+    Map(BTreeMap<String, FieldValueInput>),
+}
+
+#[derive(Deserialize)]
+struct RecordInput {
+    kind: MesgNum,
+    fields: FieldsInput,
+}
+
+/// Records are deserialized from any shape written by `SerializationOptions`. The field map
+/// doesn't store field numbers so its fields are matched to the profile by name, fields that
+/// aren't defined by the profile such as developer fields are skipped.
+impl<'de> Deserialize<'de> for FitDataRecord {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let input = RecordInput::deserialize(deserializer)?;
+        let kind = input.kind;
+        let mut fields = Vec::new();
+        match input.fields {
+            FieldsInput::Array(entries) => {
+                for entry in entries {
+                    let value = match entry.value {
+                        Some(value) => value,
+                        None => continue,
+                    };
+                    fields.push(match entry.developer_data_index {
+                        Some(index) => FitDataField::new_developer_field(
+                            entry.name,
+                            entry.number,
+                            index,
+                            value,
+                            entry.units,
+                        ),
+                        None => match find_field_info(kind, Some(entry.number), &entry.name) {
+                            Some(info) => {
+                                let value =
+                                    timestamp_value(Some(info), value).map_err(D::Error::custom)?;
+                                FitDataField::new(info.name(), entry.number, value, entry.units)
+                            }
+                            None => {
+                                let info = kind.get_field_info(entry.number, &entry.name);
+                                let value =
+                                    timestamp_value(info, value).map_err(D::Error::custom)?;
+                                FitDataField::new(entry.name, entry.number, value, entry.units)
+                            }
+                        },
+                    });
+                }
+            }
+            FieldsInput::Map(entries) => {
+                for (name, entry) in entries {
+                    let value = match entry {
+                        FieldValueInput::WithUnits { value } | FieldValueInput::Value(value) => {
+                            value
+                        }
+                    };
+                    let value = match value {
+                        Some(value) => value,
+                        None => continue,
+                    };
+                    if let Some(info) = find_field_info(kind, None, &name) {
+                        let value = timestamp_value(Some(info), value).map_err(D::Error::custom)?;
+                        fields.push(FitDataField::new(
+                            info.name(),
+                            info.def_number(),
+                            value,
+                            info.units(),
+                        ));
+                    } else if let Some(number) = ["unknown_field_", "unknownField", "UnknownField"]
+                        .iter()
+                        .find_map(|prefix| name.strip_prefix(prefix))
+                        .and_then(|n| n.parse::<u8>().ok())
+                    {
+                        let name = format!("unknown_field_{}", number);
+                        fields.push(FitDataField::new(name, number, value, ""));
+                    }
+                }
+            }
+        }
+        let mut record = FitDataRecord::new(kind);
+        record.extend(fields);
+        Ok(record)
+    }
+}
+
+/// Find the profile field with a name serialized using any of the field casings, optionally
+/// limited to fields with the definition number
+fn find_field_info(kind: MesgNum, number: Option<u8>, name: &str) -> Option<&'static FieldInfo> {
+    let cases = [FieldCase::Snake, FieldCase::Camel, FieldCase::Pascal];
+    kind.field_info().iter().find(|f| {
+        number.is_none_or(|n| n == f.def_number())
+            && cases.iter().any(|case| case.convert(f.name()) == name)
+    })
+}
+
+/// Convert the serialized string of a date time field back into a timestamp, local date time
+/// fields are read from the wall clock time of the string and may not have an offset
+fn timestamp_value(info: Option<&FieldInfo>, value: Value) -> Result<Value, String> {
+    let err = |val: &str, e: chrono::ParseError| format!("invalid timestamp {}: {}", val, e);
+    match (info.map(FieldInfo::field_type), value) {
+        (Some(FieldDataType::DateTime), Value::String(val)) => DateTime::parse_from_rfc3339(&val)
+            .map(|t| Value::Timestamp(t.with_timezone(&TimestampTz)))
+            .map_err(|e| err(&val, e)),
+        (Some(FieldDataType::LocalDateTime), Value::String(val)) => val
+            .parse::<NaiveDateTime>()
+            .or_else(|_| DateTime::parse_from_rfc3339(&val).map(|t| t.naive_local()))
+            .map(Value::LocalTimestamp)
+            .map_err(|e| err(&val, e)),
+        (_, value) => Ok(value),
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::{from_bytes, from_bytes_with_options, parse_all, DecodeOption};
+
+    fn to_json<T: Serialize>(value: &T) -> serde_json::Value {
+        serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn serialize_with_options() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+
+        // the default options match the record serialization
+        let options = SerializationOptions::default();
+        assert_eq!(to_json(&options.records(&records)), to_json(&records));
+
+        let options = SerializationOptions {
+            flatten_fields: true,
+            numeric_enums: true,
+            ..SerializationOptions::default()
+        };
+        let json = to_json(&options.record(&records[0]));
+        assert_eq!(json["kind"], "file_id");
+        assert_eq!(json["fields"]["manufacturer"]["value"], 15);
+        assert_eq!(json["fields"]["manufacturer"]["units"], "");
+
+        // enum names can be included next to the numbers, even without units
+        let options = SerializationOptions {
+            flatten_fields: true,
+            include_units: false,
+            numeric_enums: true,
+            enum_names: true,
+            ..SerializationOptions::default()
+        };
+        let json = to_json(&options.record(&records[0]));
+        assert_eq!(json["fields"]["manufacturer"]["value"], 15);
+        assert_eq!(json["fields"]["manufacturer"]["enum_name"], "dynastream");
+        assert_eq!(json["fields"]["serial_number"], 2147483647_u32);
+        let loaded: FitDataRecord = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            loaded.field("manufacturer").unwrap().value(),
+            &Value::UInt64(15)
+        );
+        let options = [DecodeOption::ReturnNumericEnumValues]
+            .iter()
+            .copied()
+            .collect();
+        let numeric = from_bytes_with_options(data, &options).unwrap();
+        let options = SerializationOptions {
+            enum_names: true,
+            ..SerializationOptions::default()
+        };
+        let json = to_json(&options.record(&numeric[0]));
+        let field = &json["fields"][0];
+        assert_eq!(field["name"], "type");
+        assert_eq!(field["value"], 4);
+        assert_eq!(field["enum_name"], "activity");
+
+        let options = SerializationOptions {
+            flatten_fields: true,
+            include_units: false,
+            ..SerializationOptions::default()
+        };
+        let json = to_json(&options.record(&records[0]));
+        assert_eq!(json["fields"]["manufacturer"], "dynastream");
+
+        let files = parse_all(data).unwrap();
+        let json = to_json(&options.file(&files[0]));
+        assert_eq!(json["header"]["data_size"], 757);
+        assert_eq!(json["records"].as_array().unwrap().len(), records.len());
+
+        // developer fields use the units of their field description
+        let data = include_bytes!("../tests/fixtures/DeveloperData.fit");
+        let records = from_bytes(data).unwrap();
+        let json = to_json(&SerializationOptions::default().record(&records[3]));
+        let field = json["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|f| f["name"] == "doughnuts_earned")
+            .unwrap();
+        assert_eq!(field["units"], "doughnuts");
+    }
+
+    #[test]
+    fn serialize_timestamp_zones() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+        let session = records
+            .iter()
+            .find(|r| r.kind() == MesgNum::Session)
+            .unwrap();
+        let start_time = |zone| {
+            let options = SerializationOptions {
+                flatten_fields: true,
+                include_units: false,
+                timestamp_zone: zone,
+                ..SerializationOptions::default()
+            };
+            to_json(&options.record(session))["fields"]["start_time"].clone()
+        };
+        assert_eq!(start_time(TimestampZone::Utc), "2012-04-09T21:22:26Z");
+        let offset = crate::analysis::utc_offset(&records).unwrap();
+        assert_eq!(
+            start_time(TimestampZone::UtcOffset(offset)),
+            "2012-04-09T17:22:26-04:00"
+        );
+
+        // local timestamps keep their wall clock time and are read back unchanged
+        let activity = records.last().unwrap();
+        assert_eq!(activity.kind(), MesgNum::Activity);
+        let json = to_json(&activity);
+        let local = json["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|f| f["name"] == "local_timestamp")
+            .unwrap();
+        assert_eq!(local["value"], "2012-04-09T17:24:51");
+        let options = SerializationOptions {
+            flatten_fields: true,
+            timestamp_zone: TimestampZone::UtcOffset(offset),
+            ..SerializationOptions::default()
+        };
+        let json = to_json(&options.record(activity));
+        assert_eq!(
+            json["fields"]["timestamp"]["value"],
+            json["fields"]["local_timestamp"]["value"]
+        );
+        let loaded: FitDataRecord = serde_json::from_value(json).unwrap();
+        for name in ["timestamp", "local_timestamp"] {
+            assert_eq!(
+                loaded.field(name).unwrap().value(),
+                activity.field(name).unwrap().value()
+            );
+        }
+        // timestamps can be serialized as numbers, local timestamps count from the epoch in
+        // their own time zone
+        let timestamp = |format, record| {
+            let options = SerializationOptions {
+                flatten_fields: true,
+                include_units: false,
+                timestamp_format: format,
+                ..SerializationOptions::default()
+            };
+            to_json(&options.record(record))["fields"].clone()
+        };
+        let fields = timestamp(TimestampFormat::UnixSeconds, session);
+        assert_eq!(fields["start_time"], 1334006546);
+        let fields = timestamp(TimestampFormat::UnixMillis, session);
+        assert_eq!(fields["start_time"], 1334006546000_i64);
+        let fields = timestamp(TimestampFormat::FitSeconds, session);
+        assert_eq!(fields["start_time"], 702940946);
+        let fields = timestamp(TimestampFormat::FitSeconds, activity);
+        assert_eq!(fields["local_timestamp"], 702926691);
+        let fields = timestamp(TimestampFormat::Rfc3339, session);
+        assert!(fields["start_time"].is_string());
+    }
+
+    #[test]
+    fn deserialize_records() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+
+        let json = serde_json::to_string(&records).unwrap();
+        let loaded: Vec<FitDataRecord> = serde_json::from_str(&json).unwrap();
+        assert_eq!(to_json(&loaded), to_json(&records));
+        assert!(matches!(
+            loaded[0].field("time_created").unwrap().value(),
+            Value::Timestamp(_)
+        ));
+
+        // the flattened form can be encoded again
+        let options = SerializationOptions {
+            flatten_fields: true,
+            ..SerializationOptions::default()
+        };
+        let json = serde_json::to_string(&options.records(&records)).unwrap();
+        let loaded: Vec<FitDataRecord> = serde_json::from_str(&json).unwrap();
+        let encoded = from_bytes(&crate::to_bytes(&loaded).unwrap()).unwrap();
+        assert_eq!(
+            to_json(&options.records(&encoded)),
+            to_json(&options.records(&records))
+        );
+
+        let files = parse_all(data).unwrap();
+        let json = serde_json::to_string(&options.file(&files[0])).unwrap();
+        let file: FitFile = serde_json::from_str(&json).unwrap();
+        assert_eq!(file.records().len(), records.len());
+        assert_eq!(file.crc(), files[0].crc());
+    }
+
+    #[test]
+    fn serialize_field_case() {
+        assert_eq!(FieldCase::Camel.convert("heart_rate"), "heartRate");
+        assert_eq!(FieldCase::Pascal.convert("heart_rate"), "HeartRate");
+        assert_eq!(
+            FieldCase::Pascal.convert("unknown_field_5"),
+            "UnknownField5"
+        );
+        assert_eq!(FieldCase::Snake.convert("heart_rate"), "heart_rate");
+
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let records = from_bytes(data).unwrap();
+        for flatten_fields in [false, true] {
+            let options = SerializationOptions {
+                flatten_fields,
+                field_case: FieldCase::Pascal,
+                ..SerializationOptions::default()
+            };
+            let json = to_json(&options.records(&records));
+            assert_eq!(json[0]["kind"], "file_id");
+            if flatten_fields {
+                assert!(json[0]["fields"]["TimeCreated"].is_object());
+                assert!(json[0]["fields"]["time_created"].is_null());
+            } else {
+                assert_eq!(json[0]["fields"][0]["name"], "Type");
+            }
+
+            // records are read back using the profile names
+            let loaded: Vec<FitDataRecord> = serde_json::from_value(json).unwrap();
+            let options = SerializationOptions {
+                flatten_fields,
+                ..SerializationOptions::default()
+            };
+            assert_eq!(
+                to_json(&options.records(&loaded)),
+                to_json(&options.records(&records))
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_invalid_fields() {
+        let data = include_bytes!("../tests/fixtures/garmin-fenix-5-bike.fit");
+        let options = [DecodeOption::PreserveLayout].iter().copied().collect();
+        let records = from_bytes_with_options(data, &options).unwrap();
+        let options = SerializationOptions {
+            include_invalid_fields: true,
+            ..SerializationOptions::default()
+        };
+        let invalid: Vec<serde_json::Value> = records
+            .iter()
+            .flat_map(|r| {
+                let json = to_json(&options.record(r));
+                json["fields"].as_array().unwrap().clone()
+            })
+            .filter(|f| f["value"].is_null())
+            .collect();
+        assert!(!invalid.is_empty());
+        assert!(invalid.iter().all(|f| f["name"].is_string()));
+
+        // the raw bytes are enough to find the invalid fields, fields set after decoding are
+        // serialized with their value
+        let options = [DecodeOption::KeepRawBytes].iter().copied().collect();
+        let mut raw_records = from_bytes_with_options(data, &options).unwrap();
+        let options = SerializationOptions {
+            flatten_fields: true,
+            include_units: false,
+            include_invalid_fields: true,
+            ..SerializationOptions::default()
+        };
+        let count = raw_records
+            .iter()
+            .flat_map(|r| {
+                let json = to_json(&options.record(r));
+                json["fields"].as_object().unwrap().clone()
+            })
+            .filter(|(_, v)| v.is_null())
+            .count();
+        assert_eq!(count, invalid.len());
+        let (record, name) = raw_records
+            .iter_mut()
+            .find_map(|r| {
+                let json = to_json(&options.record(r));
+                let fields = json["fields"].as_object().unwrap().clone();
+                let name = fields.into_iter().find(|(_, v)| v.is_null())?.0;
+                Some((r, name))
+            })
+            .unwrap();
+        record.set_field_value(&name, Value::UInt8(1)).unwrap();
+        assert!(!to_json(&options.record(record))["fields"][&name].is_null());
+    }
+}
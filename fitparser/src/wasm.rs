@@ -0,0 +1,67 @@
+//! WebAssembly bindings used to parse FIT files from javascript, this module requires the
+//! `wasm` feature. The crate needs to be built as a cdylib for the `wasm32-unknown-unknown`
+//! target and processed with `wasm-bindgen` to generate the javascript glue code.
+use crate::de::{from_bytes_with_options, DecodeOption};
+use crate::ser::SerializationOptions;
+use crate::FitDataRecord;
+use js_sys::{Uint8Array, JSON};
+use std::collections::HashSet;
+use wasm_bindgen::prelude::*;
+
+/// Serialize decoded records into a JSON array of name: {value, units} maps, this matches the
+/// `fit_to_json` example
+fn records_to_json(records: Vec<FitDataRecord>) -> serde_json::Result<String> {
+    let options = SerializationOptions {
+        flatten_fields: true,
+        ..SerializationOptions::default()
+    };
+    serde_json::to_string(&options.records(&records))
+}
+
+/// Parse the bytes of a FIT file into an array of javascript objects, an exception is thrown
+/// if the file can't be parsed.
+#[wasm_bindgen]
+pub fn parse_bytes(data: &Uint8Array) -> Result<JsValue, JsValue> {
+    parse_bytes_with_options(data, false, false)
+}
+
+/// Parse the bytes of a FIT file into an array of javascript objects, optionally dropping
+/// unknown fields and messages and skipping CRC validation.
+#[wasm_bindgen]
+pub fn parse_bytes_with_options(
+    data: &Uint8Array,
+    drop_unknown: bool,
+    no_crc_check: bool,
+) -> Result<JsValue, JsValue> {
+    let mut options = HashSet::new();
+    if drop_unknown {
+        options.insert(DecodeOption::DropUnknownFields);
+        options.insert(DecodeOption::DropUnknownMessages);
+    }
+    if no_crc_check {
+        options.insert(DecodeOption::SkipHeaderCrcValidation);
+        options.insert(DecodeOption::SkipDataCrcValidation);
+    }
+    let records = from_bytes_with_options(&data.to_vec(), &options)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let json = records_to_json(records).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    JSON::parse(&json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+
+    #[test]
+    fn records_to_json_layout() {
+        let data = include_bytes!("../tests/fixtures/Activity.fit");
+        let json = records_to_json(from_bytes(data).unwrap()).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let records = value.as_array().unwrap();
+        assert_eq!(records.len(), 22);
+        assert_eq!(records[0]["kind"], "file_id");
+        assert_eq!(records[0]["fields"]["manufacturer"]["value"], "dynastream");
+        assert_eq!(records[0]["fields"]["manufacturer"]["units"], "");
+    }
+}
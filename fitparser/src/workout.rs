@@ -0,0 +1,387 @@
+//! Build structured workout files from a list of steps. The records contain the `file_id`,
+//! `workout` and `workout_step` messages and can be encoded using `to_bytes` to load the workout
+//! onto a device.
+use crate::compat::prelude::*;
+use crate::error::{ErrorKind, Result};
+use crate::profile::field_types::{File, Intensity, Manufacturer, Sport};
+use crate::profile::MesgNum;
+use crate::{FitDataRecord, TimestampTz, Value};
+use chrono::DateTime;
+
+/// Heart rate values above this are in bpm, values up to it are a percentage of the max
+const HEART_RATE_OFFSET: u32 = 100;
+
+/// Power values above this are in watts, values up to it are a percentage of the FTP
+const POWER_OFFSET: u32 = 1000;
+
+/// Condition that ends a workout step
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StepDuration {
+    /// The step lasts until the lap button is pressed
+    Open,
+    /// Time in seconds
+    Time(f64),
+    /// Distance in meters
+    Distance(f64),
+    /// Energy in kcal
+    Calories(u32),
+    /// Until the heart rate drops below the value in bpm
+    HeartRateLessThan(u32),
+    /// Until the heart rate rises above the value in bpm
+    HeartRateGreaterThan(u32),
+    /// Until the power drops below the value in watts
+    PowerLessThan(u32),
+    /// Until the power rises above the value in watts
+    PowerGreaterThan(u32),
+}
+
+/// Intensity the athlete should hold during a workout step, ranges are the low and high value
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StepTarget {
+    /// No target
+    Open,
+    /// Heart rate zone from 1 to 5
+    HeartRateZone(u32),
+    /// Heart rate range in bpm
+    HeartRate(u32, u32),
+    /// Power zone from 1 to 7
+    PowerZone(u32),
+    /// Power range in watts
+    Power(u32, u32),
+    /// Speed zone from 1 to 10
+    SpeedZone(u32),
+    /// Speed range in m/s
+    Speed(f64, f64),
+    /// Cadence range in rpm
+    Cadence(u32, u32),
+}
+
+/// Single step of a workout
+#[derive(Clone, Debug, PartialEq)]
+pub struct WorkoutStep {
+    name: Option<String>,
+    notes: Option<String>,
+    intensity: Intensity,
+    duration: StepDuration,
+    target: StepTarget,
+}
+
+impl WorkoutStep {
+    /// Create an active step with a duration and target
+    pub fn new(duration: StepDuration, target: StepTarget) -> Self {
+        WorkoutStep {
+            name: None,
+            notes: None,
+            intensity: Intensity::Active,
+            duration,
+            target,
+        }
+    }
+
+    /// Set the name displayed for the step
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(String::from(name));
+        self
+    }
+
+    /// Set the notes displayed for the step
+    pub fn with_notes(mut self, notes: &str) -> Self {
+        self.notes = Some(String::from(notes));
+        self
+    }
+
+    /// Set the intensity of the step, e.g. warmup, rest or cooldown
+    pub fn with_intensity(mut self, intensity: Intensity) -> Self {
+        self.intensity = intensity;
+        self
+    }
+
+    /// Return the duration of the step
+    pub fn duration(&self) -> StepDuration {
+        self.duration
+    }
+
+    /// Return the target of the step
+    pub fn target(&self) -> StepTarget {
+        self.target
+    }
+}
+
+/// Step of the workout as it is written, repeats jump back to an earlier step
+#[derive(Clone, Debug, PartialEq)]
+enum Step {
+    Step(WorkoutStep),
+    Repeat { from: usize, count: u32 },
+}
+
+/// Builds the records of a workout file. Steps are numbered from 0 in the order they are
+/// added, repeat steps count towards the numbering.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WorkoutBuilder {
+    name: String,
+    sport: Sport,
+    time_created: Option<DateTime<TimestampTz>>,
+    steps: Vec<Step>,
+}
+
+impl WorkoutBuilder {
+    /// Create a workout without any steps
+    pub fn new(name: &str, sport: Sport) -> Self {
+        WorkoutBuilder {
+            name: String::from(name),
+            sport,
+            time_created: None,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Set the time the file was created, this is left out of the `file_id` message otherwise
+    pub fn with_time_created(mut self, time_created: DateTime<TimestampTz>) -> Self {
+        self.time_created = Some(time_created);
+        self
+    }
+
+    /// Add a step to the end of the workout
+    pub fn step(mut self, step: WorkoutStep) -> Self {
+        self.steps.push(Step::Step(step));
+        self
+    }
+
+    /// Add a step that repeats the steps starting at the `from` step up to this one `count`
+    /// times in total
+    pub fn repeat(mut self, from: usize, count: u32) -> Self {
+        self.steps.push(Step::Repeat { from, count });
+        self
+    }
+
+    /// Return the number of steps, including repeat steps
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Check if the workout has no steps
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Build the `file_id`, `workout` and `workout_step` records. An error is returned when a
+    /// repeat doesn't refer to an earlier step or a value can't be stored in its field.
+    pub fn build(&self) -> Result<Vec<FitDataRecord>> {
+        let mut file_id = FitDataRecord::new(MesgNum::FileId);
+        file_id.set_field_value("type", enum_value(File::Workout))?;
+        file_id.set_field_value("manufacturer", enum_value(Manufacturer::Development))?;
+        file_id.set_field_value("product", Value::UInt16(0))?;
+        if let Some(time_created) = self.time_created {
+            file_id.set_field_value("time_created", Value::Timestamp(time_created))?;
+        }
+
+        let mut workout = FitDataRecord::new(MesgNum::Workout);
+        workout.set_field_value("wkt_name", Value::String(self.name.clone()))?;
+        workout.set_field_value("sport", enum_value(self.sport))?;
+        workout.set_field_value("num_valid_steps", Value::UInt16(self.steps.len() as u16))?;
+
+        let mut records = vec![file_id, workout];
+        for (index, step) in self.steps.iter().enumerate() {
+            let mut record = FitDataRecord::new(MesgNum::WorkoutStep);
+            record.set_field_value("message_index", Value::UInt16(index as u16))?;
+            match step {
+                Step::Step(step) => set_step_values(&mut record, step)?,
+                Step::Repeat { from, .. } if *from >= index => {
+                    return Err(ErrorKind::ValueError(format!(
+                        "step {} can't repeat from step {}",
+                        index, from
+                    ))
+                    .into());
+                }
+                Step::Repeat { from, count } => {
+                    record
+                        .set_field_value("wkt_step_name", Value::String(String::from("Repeat")))?;
+                    record.set_field_value(
+                        "duration_type",
+                        Value::String("repeat_until_steps_cmplt".into()),
+                    )?;
+                    record.set_field_value("duration_step", Value::UInt32(*from as u32))?;
+                    record.set_field_value("target_type", Value::String("open".into()))?;
+                    record.set_field_value("repeat_steps", Value::UInt32(*count))?;
+                }
+            }
+            records.push(record);
+        }
+        Ok(records)
+    }
+
+    /// Build the records and encode them into a FIT file
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        crate::encode::to_bytes(&self.build()?)
+    }
+}
+
+/// Convert an enum into the variant name used by the decoder
+fn enum_value<T: ToString>(value: T) -> Value {
+    Value::String(value.to_string())
+}
+
+/// Set the name, intensity, duration and target fields of a step
+fn set_step_values(record: &mut FitDataRecord, step: &WorkoutStep) -> Result<()> {
+    if let Some(name) = &step.name {
+        record.set_field_value("wkt_step_name", Value::String(name.clone()))?;
+    }
+    if let Some(notes) = &step.notes {
+        record.set_field_value("notes", Value::String(notes.clone()))?;
+    }
+    record.set_field_value("intensity", enum_value(step.intensity))?;
+
+    let (duration_type, duration) = match step.duration {
+        StepDuration::Open => ("open", None),
+        StepDuration::Time(secs) => ("time", Some(("duration_time", Value::Float64(secs)))),
+        StepDuration::Distance(meters) => (
+            "distance",
+            Some(("duration_distance", Value::Float64(meters))),
+        ),
+        StepDuration::Calories(kcal) => {
+            ("calories", Some(("duration_calories", Value::UInt32(kcal))))
+        }
+        StepDuration::HeartRateLessThan(bpm) => (
+            "hr_less_than",
+            Some(("duration_hr", Value::UInt32(bpm + HEART_RATE_OFFSET))),
+        ),
+        StepDuration::HeartRateGreaterThan(bpm) => (
+            "hr_greater_than",
+            Some(("duration_hr", Value::UInt32(bpm + HEART_RATE_OFFSET))),
+        ),
+        StepDuration::PowerLessThan(watts) => (
+            "power_less_than",
+            Some(("duration_power", Value::UInt32(watts + POWER_OFFSET))),
+        ),
+        StepDuration::PowerGreaterThan(watts) => (
+            "power_greater_than",
+            Some(("duration_power", Value::UInt32(watts + POWER_OFFSET))),
+        ),
+    };
+    record.set_field_value("duration_type", Value::String(duration_type.into()))?;
+    if let Some((name, value)) = duration {
+        record.set_field_value(name, value)?;
+    }
+
+    // custom ranges are stored with a zone of 0
+    let (target_type, zone, range) = match step.target {
+        StepTarget::Open => ("open", None, None),
+        StepTarget::HeartRateZone(zone) => ("heart_rate", Some(("target_hr_zone", zone)), None),
+        StepTarget::HeartRate(low, high) => (
+            "heart_rate",
+            Some(("target_hr_zone", 0)),
+            Some((
+                "custom_target_heart_rate",
+                Value::UInt32(low + HEART_RATE_OFFSET),
+                Value::UInt32(high + HEART_RATE_OFFSET),
+            )),
+        ),
+        StepTarget::PowerZone(zone) => ("power", Some(("target_power_zone", zone)), None),
+        StepTarget::Power(low, high) => (
+            "power",
+            Some(("target_power_zone", 0)),
+            Some((
+                "custom_target_power",
+                Value::UInt32(low + POWER_OFFSET),
+                Value::UInt32(high + POWER_OFFSET),
+            )),
+        ),
+        StepTarget::SpeedZone(zone) => ("speed", Some(("target_speed_zone", zone)), None),
+        StepTarget::Speed(low, high) => (
+            "speed",
+            Some(("target_speed_zone", 0)),
+            Some((
+                "custom_target_speed",
+                Value::Float64(low),
+                Value::Float64(high),
+            )),
+        ),
+        StepTarget::Cadence(low, high) => (
+            "cadence",
+            Some(("target_cadence_zone", 0)),
+            Some((
+                "custom_target_cadence",
+                Value::UInt32(low),
+                Value::UInt32(high),
+            )),
+        ),
+    };
+    record.set_field_value("target_type", Value::String(target_type.into()))?;
+    if let Some((name, zone)) = zone {
+        record.set_field_value(name, Value::UInt32(zone))?;
+    }
+    if let Some((name, low, high)) = range {
+        record.set_field_value(&format!("{}_low", name), low)?;
+        record.set_field_value(&format!("{}_high", name), high)?;
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::de::from_bytes;
+    use core::convert::TryFrom;
+
+    #[test]
+    fn build_workout() {
+        let data = WorkoutBuilder::new("Intervals", Sport::Cycling)
+            .step(
+                WorkoutStep::new(StepDuration::Time(600.0), StepTarget::HeartRateZone(2))
+                    .with_name("Warm up")
+                    .with_intensity(Intensity::Warmup),
+            )
+            .step(WorkoutStep::new(
+                StepDuration::Distance(2000.0),
+                StepTarget::Power(250, 280),
+            ))
+            .step(
+                WorkoutStep::new(StepDuration::HeartRateLessThan(120), StepTarget::Open)
+                    .with_intensity(Intensity::Rest),
+            )
+            .repeat(1, 4)
+            .step(
+                WorkoutStep::new(StepDuration::Open, StepTarget::Cadence(85, 95))
+                    .with_notes("Spin easy")
+                    .with_intensity(Intensity::Cooldown),
+            )
+            .to_bytes()
+            .unwrap();
+
+        let records = from_bytes(&data).unwrap();
+        let value =
+            |record: &FitDataRecord, name: &str| record.field(name).unwrap().value().clone();
+        assert_eq!(value(&records[0], "type"), Value::String("workout".into()));
+        assert_eq!(
+            value(&records[1], "wkt_name"),
+            Value::String("Intervals".into())
+        );
+        assert_eq!(value(&records[1], "num_valid_steps"), Value::UInt16(5));
+
+        let number =
+            |record: &FitDataRecord, name: &str| f64::try_from(value(record, name)).unwrap();
+        let steps = &records[2..];
+        assert_eq!(steps.len(), 5);
+        assert_eq!(number(&steps[0], "duration_time"), 600.0);
+        assert_eq!(number(&steps[0], "target_hr_zone"), 2.0);
+        assert_eq!(
+            value(&steps[0], "intensity"),
+            Value::String("warmup".into())
+        );
+        assert_eq!(number(&steps[1], "duration_distance"), 2000.0);
+        assert_eq!(number(&steps[1], "custom_target_power_low"), 1250.0);
+        assert_eq!(number(&steps[1], "custom_target_power_high"), 1280.0);
+        assert_eq!(number(&steps[2], "duration_hr"), 220.0);
+        assert_eq!(
+            value(&steps[3], "duration_type"),
+            Value::String("repeat_until_steps_cmplt".into())
+        );
+        assert_eq!(number(&steps[3], "duration_step"), 1.0);
+        assert_eq!(number(&steps[3], "repeat_steps"), 4.0);
+        assert_eq!(number(&steps[4], "custom_target_cadence_high"), 95.0);
+        assert_eq!(value(&steps[4], "notes"), Value::String("Spin easy".into()));
+
+        let repeat_ahead = WorkoutBuilder::new("Invalid", Sport::Running).repeat(0, 2);
+        assert!(repeat_ahead.build().is_err());
+    }
+}
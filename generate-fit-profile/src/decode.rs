@@ -28,7 +28,7 @@ impl MessageDefinition {
         writeln!(out, "match def_num {{")?;
         for field in self.field_map().values() {
             writeln!(out, "{0} => {{", field.def_number())?;
-            field.write_field_decode_block(out, &self, "value", None, None)?;
+            field.write_field_decode_block(out, self, "value", None, None)?;
             writeln!(out, "}}")?;
         }
         writeln!(out, "_ => {{")?;
@@ -47,13 +47,13 @@ impl MessageDefinition {
         writeln!(out, "}}")?;
 
         for field in self.field_map().values() {
-            field.write_create_fn_def(out, &self)?;
+            field.write_create_fn_def(out, self)?;
             let mut created_subfield_fns = HashSet::new();
             for (_, _, sub_field_info) in field.subfields() {
                 if !created_subfield_fns.contains(sub_field_info.name()) {
                     // only create the function once, even if multiple values reference
                     // the subfield
-                    sub_field_info.write_create_fn_def(out, &self)?;
+                    sub_field_info.write_create_fn_def(out, self)?;
                 }
                 created_subfield_fns.insert(sub_field_info.name());
             }
@@ -142,7 +142,7 @@ impl MessageFieldDefinition {
         writeln!(out, "}}")?;
 
         // convert value to byte array and extract out components
-        writeln!(out, "let input = {}.to_ne_bytes();", val_str)?;
+        writeln!(out, "let input = {}.to_le_bytes();", val_str)?;
         let mut inp_str = "&input";
         let mut offset_str = "0usize";
         for (vn, csize) in var_names
@@ -158,6 +158,20 @@ impl MessageFieldDefinition {
             offset_str = "offset";
         }
 
+        // accumulated components only store the least significant bits of the value so they
+        // are expanded here where the component size is known
+        for (vn, (csize, comp)) in var_names.iter().zip(self.components().iter()) {
+            if comp.accumulate() {
+                writeln!(
+                    out,
+                    "let {0} = accumulate_component(accumlators, mesg_num.as_u16(), {1}, {0}, {2})?;",
+                    vn,
+                    comp.def_number(),
+                    csize,
+                )?;
+            }
+        }
+
         let mut comps_decoded = HashSet::new();
         for (_, comp) in self.components().iter() {
             // array components show up more than once, but we collect those
@@ -173,7 +187,6 @@ impl MessageFieldDefinition {
                     "let {} = Value::Array(vec![{}]);",
                     comp.name(),
                     (0..*array_flds.get(&comp.def_number()).unwrap())
-                        .into_iter()
                         .map(|i| format!("{}_{}", comp.name(), i + 1))
                         .collect::<Vec<String>>()
                         .join(",")
@@ -231,7 +244,7 @@ impl MessageFieldDefinition {
             } else {
                 writeln!(out, "else if")?;
             }
-            writeln!(out, "{}::{}.as_i64() == data_map.get(&{}).map(|v| v.try_into().ok()).flatten().unwrap_or(-1i64) {{", ref_field.field_type(), ref_val_str, ref_field.def_number())?;
+            writeln!(out, "{}::{}.as_i64() == data_map.get(&{}).and_then(|v| v.try_into().ok()).unwrap_or(-1i64) {{", ref_field.field_type(), ref_val_str, ref_field.def_number())?;
 
             // if the field is "terminal", i.e. has no components generate a create_fn call
             if sub_field_info.components().is_empty() {
@@ -316,11 +329,13 @@ impl MessageFieldDefinition {
         alt_scale: Option<f64>,
         alt_offset: Option<f64>,
     ) -> String {
+        // the accumulate flag of a field applies to its components which are accumulated during
+        // component expansion, so a field's own value is never accumulated
         format!(
             "{0}_{1}_field(mesg_num, accumlators, options, data_map, {2}, {3:.6}, {4:.6}, \"{5}\", {6})",
             mesg_def.function_name(),
             self.name(),
-            self.accumulate(),
+            false,
             alt_scale.unwrap_or(self.scale()),
             alt_offset.unwrap_or(self.offset()),
             self.units(),
@@ -332,12 +347,11 @@ impl MessageFieldDefinition {
 fn write_unknown_mesg_fn(out: &mut File) -> Result<(), std::io::Error> {
     writeln!(
         out,
-        "{}",
         "
         fn unknown_message(
         data_map: &HashMap<u8, Value>,
         options: &HashSet<DecodeOption>,
-    ) -> Result<Vec<FitDataField>> {
+    ) -> Result<Vec<FitDataField>> {{
         // since it's an unknown message all the fields are unknown
         if options.contains(&DecodeOption::DropUnknownFields) {{
             return Ok(Vec::new());
@@ -346,7 +360,7 @@ fn write_unknown_mesg_fn(out: &mut File) -> Result<(), std::io::Error> {
             .map(|(k, v)| unknown_field(*k, v.clone()))
             .collect();
         Ok(fields)
-    }
+    }}
     "
     )
 }
@@ -385,14 +399,17 @@ pub fn write_decode_file(profile: &FitProfile, out: &mut File) -> Result<(), std
         profile.version()
     )?;
     writeln!(out, "#![allow(unused_variables)]")?;
-    writeln!(out, "use std::collections::{{HashMap, HashSet, VecDeque}};")?;
-    writeln!(out, "use std::convert::TryInto;")?;
+    writeln!(out, "#![allow(clippy::too_many_arguments)]")?;
+    writeln!(out, "#![allow(clippy::if_same_then_else)]")?;
+    writeln!(out, "use crate::compat::prelude::*;")?;
+    writeln!(out, "use crate::compat::{{HashMap, HashSet, VecDeque}};")?;
+    writeln!(out, "use core::convert::TryInto;")?;
     writeln!(out, "use crate::{{FitDataField, Value}};")?;
     writeln!(out, "use crate::de::{{DecodeOption}};")?;
     writeln!(out, "use crate::error::{{Result}};")?;
     writeln!(
         out,
-        "use super::{{calculate_cumulative_value, data_field_with_info, extract_component, unknown_field}};"
+        "use super::{{accumulate_component, calculate_cumulative_value, data_field_with_info, extract_component, unknown_field}};"
     )?;
     writeln!(out, "use super::field_types::*;")?;
     writeln!(out, "/// FIT SDK version used to generate profile decoder")?;
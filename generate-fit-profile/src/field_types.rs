@@ -145,6 +145,27 @@ impl FieldTypeDefintion {
         writeln!(out, "}}")?;
         writeln!(out, "}}")?;
 
+        writeln!(out, "impl str::FromStr for {} {{", self.titlized_name())?;
+        writeln!(out, "type Err = ();")?;
+        writeln!(
+            out,
+            "fn from_str(value: &str) -> Result<Self, Self::Err> {{"
+        )?;
+        writeln!(out, "match value {{")?;
+        for variant in self.variant_map().values() {
+            writeln!(
+                out,
+                "\"{}\" => Ok({}::{}),",
+                variant.name(),
+                self.titlized_name(),
+                variant.titlized_name()
+            )?;
+        }
+        writeln!(out, "_ => Err(()),")?;
+        writeln!(out, "}}")?;
+        writeln!(out, "}}")?;
+        writeln!(out, "}}")?;
+
         writeln!(out, "impl Serialize for {} {{", self.titlized_name())?;
         writeln!(
             out,
@@ -206,7 +227,7 @@ fn generate_main_field_type_enum(
 pub enum FieldDataType {{
 "
     )?;
-    for type_name in base_types {
+    for type_name in &base_types {
         writeln!(out, " {},", type_name)?;
     }
     for field_type in field_types {
@@ -216,7 +237,6 @@ pub enum FieldDataType {{
     writeln!(out, "}}")?;
 
     writeln!(out, "impl FieldDataType {{")?;
-    writeln!(out, "    #[allow(clippy::match_like_matches_macro)]")?;
     writeln!(out, "    pub fn is_enum_type(self) -> bool {{")?;
     writeln!(out, "        match self {{")?;
     for field_type in field_types {
@@ -251,6 +271,32 @@ pub enum FieldDataType {{
     writeln!(out, "            _ => false,")?;
     writeln!(out, "        }}")?;
     writeln!(out, "    }}")?;
+
+    writeln!(out, "    pub fn base_type(self) -> BaseType {{")?;
+    writeln!(out, "        match self {{")?;
+    for type_name in &base_types {
+        // booleans are stored as enums in the FIT protocol
+        let base_type = if *type_name == "Bool" {
+            "Enum"
+        } else {
+            type_name
+        };
+        writeln!(
+            out,
+            "FieldDataType::{} => BaseType::{},",
+            type_name, base_type
+        )?;
+    }
+    for field_type in field_types {
+        writeln!(
+            out,
+            "FieldDataType::{} => BaseType::{},",
+            field_type.titlized_name(),
+            field_type.fit_base_type()
+        )?;
+    }
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
     writeln!(out, "}}")?;
     writeln!(
         out,
@@ -270,6 +316,24 @@ pub enum FieldDataType {{
     writeln!(out, "        _ => format!(\"Undefined{{}}\", value),")?;
     writeln!(out, "    }}")?;
     writeln!(out, "}}")?;
+    writeln!(
+        out,
+        "pub fn get_field_variant_from_string(field_type: FieldDataType , value: &str) -> Option<i64> {{"
+    )?;
+    writeln!(out, "    match field_type {{")?;
+    for field_type in field_types {
+        if !field_type.variant_map().is_empty() && !is_enum_force_false.contains(field_type.name())
+        {
+            writeln!(
+                out,
+                "        FieldDataType::{0} => value.parse::<{0}>().ok().map(|v| v.as_i64()),",
+                field_type.titlized_name()
+            )?;
+        }
+    }
+    writeln!(out, "        _ => None,")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
 
     Ok(())
 }
@@ -287,10 +351,14 @@ pub fn write_types_file(profile: &FitProfile, out: &mut File) -> Result<(), std:
     writeln!(out, "#![allow(missing_docs)]")?;
     writeln!(out, "#![allow(dead_code)]")?;
     writeln!(out, "#![allow(clippy::unreadable_literal)]")?;
+    writeln!(out, "#![allow(clippy::match_like_matches_macro)]")?;
+    writeln!(out, "use crate::compat::prelude::*;")?;
+    writeln!(out, "use crate::de::BaseType;")?;
+    writeln!(out, "use core::convert;")?;
+    writeln!(out, "use core::fmt;")?;
+    writeln!(out, "use core::str;")?;
     writeln!(out, "use serde::Serialize;")?;
     writeln!(out, "use serde::ser::Serializer;")?;
-    writeln!(out, "use std::convert;")?;
-    writeln!(out, "use std::fmt;")?;
 
     // output enums and implementations
     for field_type in profile.field_types() {
@@ -1,5 +1,5 @@
 //! Parse the FIT SDK profile excel workbook to generate the decoding module.
-use std::fs::File;
+use std::fs::{self, File};
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::Command;
@@ -9,14 +9,22 @@ mod decode;
 use crate::decode::write_decode_file;
 mod field_types;
 use crate::field_types::write_types_file;
+mod messages;
+use crate::messages::write_messages_file;
 mod parse;
 use crate::parse::parse_profile;
+mod typed;
+use crate::typed::write_typed_file;
+
+/// Modules generated from the profile
+const GENERATED_FILES: &[&str] = &["field_types.rs", "decode.rs", "messages.rs", "typed.rs"];
 
 /// Parse the Profile.xlsx included in the FIT SDK and update the related modules
 #[derive(Debug, StructOpt)]
 #[structopt(name = "update_profile")]
 struct Cli {
-    /// Path to Profile.xlsx file
+    /// Path to Profile.xlsx file, or a directory storing the "Types" and "Messages" sheets
+    /// exported as Types.csv and Messages.csv
     #[structopt(name = "FILE", parse(from_os_str))]
     profile_path: PathBuf,
 
@@ -24,22 +32,51 @@ struct Cli {
     /// file unless it's been moved
     #[structopt(long)]
     sdk_version: Option<String>,
+
+    /// Generate the modules into a temporary directory and compare them to the committed
+    /// modules instead of replacing them, exiting with an error if any of them differ
+    #[structopt(long)]
+    check: bool,
 }
 
 /// call rustfmt on a generated file to cleanup auto-gen code
 fn rustfmt(fname: &PathBuf) {
     Command::new("rustfmt")
-        .arg(&fname)
+        .arg(fname)
         .status()
         .unwrap_or_else(|_| panic!("failed to execute rustfmt on {:?}", fname));
 }
 
+/// Return the directory named after the SDK release, a directory of CSV files may be named after
+/// the release itself or stored in the release directory
+fn version_dir(profile_fname: &Path) -> Option<&Path> {
+    let is_release = profile_fname
+        .file_name()
+        .is_some_and(|n| n.to_string_lossy().starts_with("FitSDKRelease_"));
+    if profile_fname.is_dir() && is_release {
+        Some(profile_fname)
+    } else {
+        profile_fname.parent()
+    }
+}
+
+/// Compare the generated modules to the committed modules, returning the names that differ
+fn changed_files(generated: &Path, committed: &Path) -> std::io::Result<Vec<String>> {
+    let mut changed = Vec::new();
+    for name in GENERATED_FILES {
+        if fs::read(generated.join(name))? != fs::read(committed.join(name)).unwrap_or_default() {
+            changed.push(name.to_string());
+        }
+    }
+    Ok(changed)
+}
+
 fn run() -> Result<(), Box<dyn std::error::Error>> {
     let opt = Cli::from_args();
     let profile_fname = opt.profile_path;
     let profile_vers = match opt.sdk_version {
         Some(vers) => vers,
-        None => match profile_fname.parent().and_then(|p| p.file_name()) {
+        None => match version_dir(&profile_fname).and_then(|p| p.file_name()) {
             Some(dirname) => dirname.to_str().unwrap().replace("FitSDKRelease_", ""),
             None => String::from("unknown"),
         },
@@ -54,7 +91,15 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
     // process excel file and output
     let profile = parse_profile(&profile_fname, profile_vers).unwrap();
 
-    let dest_dir = Path::new("./fitparser/src/profile");
+    // resolve the modules relative to this crate so the generator can be run from any directory
+    let profile_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../fitparser/src/profile");
+    let check_dir = std::env::temp_dir().join("fitparser-profile-check");
+    let dest_dir = if opt.check {
+        fs::create_dir_all(&check_dir)?;
+        check_dir.as_path()
+    } else {
+        profile_dir.as_path()
+    };
     let types_fname = dest_dir.join("field_types.rs");
     eprintln!("Generating file: {:?}", &types_fname);
     let mut out = File::create(&types_fname)?;
@@ -67,6 +112,30 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
     write_decode_file(&profile, &mut out)?;
     rustfmt(&decode_fname);
 
+    let messages_fname = dest_dir.join("messages.rs");
+    eprintln!("Generating file: {:?}", &messages_fname);
+    let mut out = File::create(&messages_fname)?;
+    write_messages_file(&profile, &mut out)?;
+    rustfmt(&messages_fname);
+
+    let typed_fname = dest_dir.join("typed.rs");
+    eprintln!("Generating file: {:?}", &typed_fname);
+    let mut out = File::create(&typed_fname)?;
+    write_typed_file(&profile, &mut out)?;
+    rustfmt(&typed_fname);
+
+    if opt.check {
+        let changed = changed_files(dest_dir, &profile_dir)?;
+        if !changed.is_empty() {
+            return Err(format!(
+                "generated profile modules are out of date: {}",
+                changed.join(", ")
+            )
+            .into());
+        }
+        eprintln!("Generated profile modules are up to date");
+    }
+
     Ok(())
 }
 
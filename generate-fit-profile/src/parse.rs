@@ -1,7 +1,9 @@
-//! Code used to parse the Profile.xlsx file into useful data structures
+//! Code used to parse the Profile.xlsx file, or the CSV export of its sheets, into useful data
+//! structures
 use calamine::{open_workbook, DataType, Range, Reader, Xlsx};
 use std::collections::{BTreeMap, HashMap};
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 // the fields in these structs are mostly duplicated from code in src/profile/parser.rs
 #[derive(Clone, Debug)]
@@ -30,13 +32,19 @@ pub struct FieldTypeDefintion {
     name: String,
     titlized_name: String,
     base_type: &'static str,
+    fit_base_type: &'static str,
     is_true_enum: bool,
     comment: Option<String>,
     variant_map: BTreeMap<i64, FieldTypeVariant>,
 }
 
 impl FieldTypeDefintion {
-    fn new(name: String, base_type: &'static str, comment: Option<String>) -> Self {
+    fn new(
+        name: String,
+        base_type: &'static str,
+        fit_base_type: &'static str,
+        comment: Option<String>,
+    ) -> Self {
         let is_true_enum = base_type == "enum";
         let base_type = if is_true_enum { "u8" } else { base_type };
 
@@ -44,6 +52,7 @@ impl FieldTypeDefintion {
             name: name.clone(),
             titlized_name: titlecase_string(&name),
             base_type,
+            fit_base_type,
             is_true_enum,
             comment,
             variant_map: BTreeMap::new(),
@@ -59,7 +68,11 @@ impl FieldTypeDefintion {
     }
 
     pub fn base_type(&self) -> &'static str {
-        &self.base_type
+        self.base_type
+    }
+
+    pub fn fit_base_type(&self) -> &'static str {
+        self.fit_base_type
     }
 
     pub fn is_true_enum(&self) -> bool {
@@ -102,7 +115,7 @@ impl FieldTypeVariant {
 
         FieldTypeVariant {
             name: name.clone(),
-            titlized_name: titlized_name,
+            titlized_name,
             value,
             comment,
         }
@@ -137,7 +150,7 @@ impl MessageDefinition {
     fn new(name: &str, comment: Option<String>) -> Self {
         MessageDefinition {
             name: name.to_string(),
-            titlized_name: titlecase_string(&name),
+            titlized_name: titlecase_string(name),
             comment,
             field_map: BTreeMap::new(),
         }
@@ -185,6 +198,7 @@ pub struct MessageFieldDefinition {
 }
 
 impl MessageFieldDefinition {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         def_number: u8,
         name: &str,
@@ -330,6 +344,23 @@ fn base_type_to_rust_type(base_type_str: &str) -> &'static str {
     }
 }
 
+/// Match a base type string to the BaseType variant used when encoding the value
+fn base_type_to_fit_base_type(base_type_str: &str) -> &'static str {
+    match base_type_str {
+        "enum" => "Enum",
+        "sint8" => "SInt8",
+        "uint8" => "UInt8",
+        "uint8z" => "UInt8z",
+        "sint16" => "SInt16",
+        "uint16" => "UInt16",
+        "uint16z" => "UInt16z",
+        "sint32" => "SInt32",
+        "uint32" => "UInt32",
+        "uint32z" => "UInt32z",
+        _ => panic!("unsupported base_type for enum field: {}", base_type_str),
+    }
+}
+
 /// match the field type string to a simple type or an enum
 fn field_type_str_to_field_type(field_type_str: &str) -> String {
     match field_type_str {
@@ -377,12 +408,17 @@ fn process_types(sheet: Range<DataType>) -> Vec<FieldTypeDefintion> {
             };
 
             // extract base type and convert to its rust equivalent
-            let rust_type = match row[1].get_string() {
-                Some(v) => base_type_to_rust_type(v),
+            let (rust_type, fit_base_type) = match row[1].get_string() {
+                Some(v) => (base_type_to_rust_type(v), base_type_to_fit_base_type(v)),
                 None => panic!("Base type name must be a string row={:?}.", row),
             };
             let comment = row[4].get_string().map(|v| v.to_string());
-            field_types.push(FieldTypeDefintion::new(enum_name, rust_type, comment));
+            field_types.push(FieldTypeDefintion::new(
+                enum_name,
+                rust_type,
+                fit_base_type,
+                comment,
+            ));
         } else if !row[2].is_empty() {
             let field_type = match field_types.last_mut() {
                 Some(v) => v,
@@ -477,6 +513,10 @@ fn post_process_message(msg: MessageDefinition) -> MessageDefinition {
         let extra_fld_def = field_def.clone();
         for (_, _, sub_fld) in field_def.subfields_mut() {
             sub_fld.set_parent_field(extra_fld_def.clone());
+            // subfields can define their own components, e.g. event.gear_change_data
+            if !sub_fld.raw_components().is_empty() {
+                sub_fld.components = process_components(sub_fld, &name_to_field);
+            }
         }
         updated_field_map.insert(def_num, field_def);
     }
@@ -496,6 +536,14 @@ fn process_components(
     let mut components = Vec::new();
     for comp_info in field.raw_components() {
         let dest_field = field_lookup.get(comp_info.name()).unwrap();
+        // the lookup holds the fields prior to post processing so the components of the
+        // subfields still need to be resolved
+        let mut subfields = dest_field.subfields().to_vec();
+        for (_, _, sub_fld) in &mut subfields {
+            if !sub_fld.raw_components().is_empty() {
+                sub_fld.components = process_components(sub_fld, field_lookup);
+            }
+        }
         let comp_fld = MessageFieldDefinition {
             def_number: dest_field.def_number(),
             name: dest_field.name().to_owned(),
@@ -506,7 +554,7 @@ fn process_components(
             units: comp_info.units().to_owned(),
             accumulate: comp_info.accumulate(),
             parent_field: dest_field.parent_field().clone(),
-            subfields: dest_field.subfields().to_vec(),
+            subfields,
             components: process_components(dest_field, field_lookup),
             raw_components: Vec::new(),
             comment: dest_field.comment().map(|s| s.to_owned()),
@@ -535,7 +583,7 @@ fn new_message_field_definition(row: &[DataType]) -> MessageFieldDefinition {
         def_number,
         name,
         ftype,
-        row[4].is_empty(),
+        !row[4].is_empty(),
         row[6].get_float().unwrap_or(1.0),
         row[7].get_float().unwrap_or(0.0),
         row[8].get_string().unwrap_or(""),
@@ -600,17 +648,92 @@ fn process_messages(sheet: Range<DataType>) -> Vec<MessageDefinition> {
     messages.push(msg);
     // post process messages once we have "all" the
     // information present
-    messages
-        .into_iter()
-        .map(|m| post_process_message(m))
-        .collect()
+    messages.into_iter().map(post_process_message).collect()
+}
+
+/// Minimum number of columns of a sheet read from CSV, trailing empty cells are often left out
+/// of exported rows but the comment column is always read
+const MIN_CSV_COLUMNS: usize = 16;
+
+/// Split CSV content into rows of cells, quoted cells may contain commas, newlines and
+/// escaped quotes.
+fn csv_rows(csv: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut chars = csv.chars().peekable();
+    while chars.peek().is_some() {
+        let mut row = Vec::new();
+        let mut cell = String::new();
+        let mut quoted = false;
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if quoted && chars.peek() == Some(&'"') => {
+                    chars.next();
+                    cell.push('"');
+                }
+                '"' => quoted = !quoted,
+                ',' if !quoted => row.push(std::mem::take(&mut cell)),
+                '\r' if !quoted => {}
+                '\n' if !quoted => break,
+                c => cell.push(c),
+            }
+        }
+        row.push(cell);
+        rows.push(row);
+    }
+    rows
+}
+
+/// Read the CSV export of a sheet into the cells read from the workbook, numbers are stored as
+/// floats since that is how the workbook stores them
+fn read_csv_sheet(path: &Path) -> Result<Range<DataType>, Box<dyn std::error::Error>> {
+    let rows = csv_rows(&fs::read_to_string(path)?);
+    let width = rows
+        .iter()
+        .map(Vec::len)
+        .max()
+        .unwrap_or(0)
+        .max(MIN_CSV_COLUMNS);
+    let height = rows.len().max(1);
+    let mut sheet = Range::new((0, 0), (height as u32 - 1, width as u32 - 1));
+    for (row_idx, row) in rows.iter().enumerate() {
+        for (col_idx, cell) in row.iter().enumerate() {
+            let value = if cell.is_empty() {
+                DataType::Empty
+            } else if let Ok(v) = cell.parse::<f64>() {
+                DataType::Float(v)
+            } else {
+                DataType::String(cell.clone())
+            };
+            sheet.set_value((row_idx as u32, col_idx as u32), value);
+        }
+    }
+    Ok(sheet)
+}
+
+/// Parse the "Types" and "Messages" sheets exported as `Types.csv` and `Messages.csv` into the
+/// directory
+fn parse_csv_profile(
+    profile_dir: &Path,
+    version: String,
+) -> Result<FitProfile, Box<dyn std::error::Error>> {
+    let field_types = process_types(read_csv_sheet(&profile_dir.join("Types.csv"))?);
+    let messages = process_messages(read_csv_sheet(&profile_dir.join("Messages.csv"))?);
+    Ok(FitProfile {
+        version,
+        field_types,
+        messages,
+    })
 }
 
+/// Parse the Profile.xlsx file, or a directory storing the CSV export of its sheets
 pub fn parse_profile(
     profile_fname: &PathBuf,
     version: String,
 ) -> Result<FitProfile, Box<dyn std::error::Error>> {
-    let mut excel: Xlsx<_> = open_workbook(&profile_fname)?;
+    if profile_fname.is_dir() {
+        return parse_csv_profile(profile_fname, version);
+    }
+    let mut excel: Xlsx<_> = open_workbook(profile_fname)?;
 
     // process Types sheet
     let field_types = if let Some(Ok(sheet)) = excel.worksheet_range("Types") {
@@ -0,0 +1,256 @@
+//! Functions to generate the typed message structs from the fit profile.
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::prelude::*;
+
+use crate::parse::{FieldTypeDefintion, FitProfile, MessageDefinition, MessageFieldDefinition};
+
+/// Field names that can't be used as an identifier without the raw prefix
+const RESERVED_NAMES: &[&str] = &["type", "match", "ref", "move", "box", "mod", "use"];
+
+/// Match a base type to the rust type of the value after decoding
+fn base_type_to_rust_type(field_type: &str) -> Option<&'static str> {
+    match field_type {
+        "Bool" => Some("bool"),
+        "SInt8" => Some("i8"),
+        "UInt8" | "UInt8z" | "Byte" => Some("u8"),
+        "SInt16" => Some("i16"),
+        "UInt16" | "UInt16z" => Some("u16"),
+        "SInt32" => Some("i32"),
+        "UInt32" | "UInt32z" => Some("u32"),
+        "SInt64" => Some("i64"),
+        "UInt64" | "UInt64z" => Some("u64"),
+        "Float32" => Some("f32"),
+        "Float64" => Some("f64"),
+        "String" => Some("String"),
+        _ => None,
+    }
+}
+
+/// Check if a field type is decoded into one of the generated enums
+fn is_enum_type(field_type: &FieldTypeDefintion) -> bool {
+    !field_type.variant_map().is_empty()
+        && field_type.name() != "date_time"
+        && field_type.name() != "local_date_time"
+}
+
+impl MessageFieldDefinition {
+    fn struct_field_name(&self) -> String {
+        if RESERVED_NAMES.contains(&self.name()) {
+            format!("r#{}", self.name())
+        } else {
+            self.name().to_string()
+        }
+    }
+
+    /// Rust type of the field value after the profile conversions have been applied
+    fn struct_field_type(&self, field_types: &HashMap<&str, &FieldTypeDefintion>) -> String {
+        let field_type = field_types.get(self.field_type());
+        let value_type = if self.field_type() == "DateTime" {
+            String::from("DateTime<TimestampTz>")
+        } else if self.field_type() == "LocalDateTime" {
+            String::from("NaiveDateTime")
+        } else if let Some(field_type) = field_type.filter(|t| is_enum_type(t)) {
+            format!("field_types::{}", field_type.titlized_name())
+        } else if (self.scale() - 1.0).abs() > f64::EPSILON || self.offset().abs() > f64::EPSILON {
+            String::from("f64")
+        } else if let Some(rust_type) = base_type_to_rust_type(self.field_type()) {
+            rust_type.to_string()
+        } else {
+            field_type
+                .map(|t| t.base_type())
+                .expect("field type is not defined in the profile")
+                .to_string()
+        };
+        if self.is_array() {
+            format!("Vec<{}>", value_type)
+        } else {
+            value_type
+        }
+    }
+}
+
+impl MessageDefinition {
+    /// Fields of the typed struct, subfields follow their parent field
+    fn struct_fields(&self) -> Vec<&MessageFieldDefinition> {
+        let mut fields = Vec::new();
+        for field in self.field_map().values() {
+            fields.push(field);
+            let mut written_subfields = HashSet::new();
+            for (_, _, sub_field_info) in field.subfields() {
+                if written_subfields.insert(sub_field_info.name()) {
+                    fields.push(sub_field_info);
+                }
+            }
+        }
+        fields
+    }
+
+    fn write_struct(
+        &self,
+        field_types: &HashMap<&str, &FieldTypeDefintion>,
+        out: &mut File,
+    ) -> Result<(), std::io::Error> {
+        if let Some(v) = self.comment() {
+            writeln!(out, "/// {}", v)?;
+        }
+        writeln!(
+            out,
+            "#[derive(Clone, Debug, Default, PartialEq, Serialize)]"
+        )?;
+        writeln!(out, "pub struct {} {{", self.titlized_name())?;
+        for field in self.struct_fields() {
+            writeln!(
+                out,
+                "pub {}: Option<{}>,",
+                field.struct_field_name(),
+                field.struct_field_type(field_types)
+            )?;
+        }
+        writeln!(out, "}}")?;
+
+        writeln!(
+            out,
+            "impl TryFrom<&FitDataRecord> for {} {{",
+            self.titlized_name()
+        )?;
+        writeln!(out, "type Error = Error;")?;
+        writeln!(
+            out,
+            "fn try_from(record: &FitDataRecord) -> Result<Self> {{"
+        )?;
+        writeln!(
+            out,
+            "check_message_kind(record, MesgNum::{})?;",
+            self.titlized_name()
+        )?;
+        writeln!(out, "let mut message = Self::default();")?;
+        writeln!(
+            out,
+            "for field in record.fields().iter().filter(|f| f.developer_data_index().is_none()) {{"
+        )?;
+        writeln!(out, "match field.name() {{")?;
+        for field in self.struct_fields() {
+            writeln!(
+                out,
+                "\"{}\" => message.{} = Some(typed_field_value(field)?),",
+                field.name(),
+                field.struct_field_name()
+            )?;
+        }
+        writeln!(out, "_ => (),")?;
+        writeln!(out, "}}")?;
+        writeln!(out, "}}")?;
+        writeln!(out, "Ok(message)")?;
+        writeln!(out, "}}")?;
+        writeln!(out, "}}")?;
+
+        writeln!(
+            out,
+            "impl From<&{}> for FitDataRecord {{",
+            self.titlized_name()
+        )?;
+        writeln!(
+            out,
+            "fn from(message: &{}) -> Self {{",
+            self.titlized_name()
+        )?;
+        writeln!(
+            out,
+            "let mut record = FitDataRecord::new(MesgNum::{});",
+            self.titlized_name()
+        )?;
+        for field in self.struct_fields() {
+            writeln!(
+                out,
+                "push_typed_field(&mut record, \"{}\", &message.{});",
+                field.name(),
+                field.struct_field_name()
+            )?;
+        }
+        writeln!(out, "record")?;
+        writeln!(out, "}}")?;
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+}
+
+fn write_enum_field_value_impl(
+    field_type: &FieldTypeDefintion,
+    out: &mut File,
+) -> Result<(), std::io::Error> {
+    writeln!(
+        out,
+        "impl FieldValue for field_types::{} {{",
+        field_type.titlized_name()
+    )?;
+    writeln!(out, "fn from_value(value: &Value) -> Option<Self> {{")?;
+    writeln!(out, "enum_field_value(value)")?;
+    writeln!(out, "}}")?;
+    writeln!(out, "fn to_value(&self) -> Value {{")?;
+    writeln!(
+        out,
+        "enum_value(self, self.as_i64(), Self::is_named_variant(self.as_i64()))"
+    )?;
+    writeln!(out, "}}")?;
+    writeln!(out, "}}")?;
+
+    Ok(())
+}
+
+pub fn write_typed_file(profile: &FitProfile, out: &mut File) -> Result<(), std::io::Error> {
+    writeln!(
+        out,
+        "//! Auto generated typed message structs from FIT SDK Release: {}",
+        profile.version()
+    )?;
+    writeln!(
+        out,
+        "//! Each struct can be converted from a decoded record of the same kind, field values that don't"
+    )?;
+    writeln!(
+        out,
+        "//! match the type defined by the profile are returned as an error. Records decoded using"
+    )?;
+    writeln!(
+        out,
+        "//! `DecodeOption::ReturnRawValues` or `DecodeOption::UseGenericSubFieldName` may not convert."
+    )?;
+    writeln!(
+        out,
+        "//! Records created from a struct use the field numbers and units of the profile so they can"
+    )?;
+    writeln!(out, "//! be encoded.")?;
+    writeln!(out, "#![allow(missing_docs)]")?;
+    writeln!(out, "#![allow(clippy::single_match)]")?;
+    writeln!(out, "use super::field_types::{{self, MesgNum}};")?;
+    writeln!(
+        out,
+        "use super::{{check_message_kind, enum_field_value, enum_value, push_typed_field, typed_field_value, FieldValue}};"
+    )?;
+    writeln!(out, "use crate::compat::prelude::*;")?;
+    writeln!(out, "use crate::error::{{Error, Result}};")?;
+    writeln!(out, "use crate::{{FitDataRecord, TimestampTz, Value}};")?;
+    writeln!(out, "use chrono::{{DateTime, NaiveDateTime}};")?;
+    writeln!(out, "use core::convert::TryFrom;")?;
+    writeln!(out, "use serde::Serialize;")?;
+
+    let field_types: HashMap<&str, &FieldTypeDefintion> = profile
+        .field_types()
+        .iter()
+        .map(|t| (t.titlized_name(), t))
+        .collect();
+    for field_type in profile.field_types() {
+        if is_enum_type(field_type) {
+            write_enum_field_value_impl(field_type, out)?;
+        }
+    }
+
+    // output a struct for every message
+    for msg in profile.messages() {
+        msg.write_struct(&field_types, out)?;
+    }
+
+    Ok(())
+}